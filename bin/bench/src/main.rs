@@ -1,22 +1,25 @@
-use ark_std::{collections::BTreeMap, path::PathBuf, UniformRand};
-use ark_groth16::Groth16;
+use ark_std::{collections::BTreeMap, path::{Path, PathBuf}, UniformRand};
+use ark_ec::AffineCurve;
+use ark_groth16::{Groth16, prepare_verifying_key, verify_proof};
 use clap::Parser;
-use soda_maze_lib::proof::{scheme::{DepositProof, WithdrawProof}, ProofScheme};
+use soda_maze_lib::proof::{scheme::{DepositProof, BatchDepositProof, WithdrawProof, TransferProof}, ProofScheme, ConstraintReport, R1CSExport};
 use soda_maze_lib::vanilla::{hasher::FieldHasher, VanillaProof};
-use soda_maze_lib::vanilla::withdraw::{WithdrawVanillaProof, WithdrawOriginInputs, WithdrawPublicInputs};
+use soda_maze_lib::vanilla::withdraw::{WithdrawVanillaProof, gen_owner_key, gen_nullifier_key, WithdrawOriginInputs, WithdrawPublicInputs};
 use soda_maze_lib::vanilla::deposit::{DepositVanillaProof, DepositOriginInputs, DepositPublicInputs};
-use soda_maze_lib::vanilla::commit::{CommitOriginInputs, CommitPublicInputs};
-use soda_maze_utils::{convert::{MazeProvingKey, MazeVerifyingKey}, parser::to_hex_string};
-use soda_maze_utils::params::{gen_deposit_const_params, gen_withdraw_const_params};
+use soda_maze_lib::vanilla::batch_deposit::{BatchDepositVanillaProof, BatchDepositOriginInputs};
+use soda_maze_lib::vanilla::transfer::{TransferVanillaProof, TransferOriginInputs};
+use soda_maze_lib::vanilla::commit::{CommitOriginInputs, CommitPublicInputs, EscrowOriginInputs, gen_escrow, gen_note_owner};
+use soda_maze_lib::vanilla::index_set::{IndexSetTree, KeySetTree};
+use soda_maze_utils::{convert::{MazeVerifyingKey, from_maze_proof, from_proof_json}, parser::to_hex_string};
+use soda_maze_utils::convert::{to_maze_proof, to_proof_json, from_public_json, to_public_json, from_verification_key_json, to_verification_key_json};
+use soda_maze_utils::params::{gen_deposit_const_params, gen_batch_deposit_const_params, gen_withdraw_const_params, gen_transfer_const_params, Hasher, HasherGadget};
+use soda_maze_utils::pk::read_pk_from_file;
+use soda_maze_utils::artifact::{CircuitInfo, CircuitKind};
 use soda_maze_utils::parser::{JsonParser, from_hex_string, borsh_de_from_file};
+use soda_maze_utils::parser::{R1CSJson, WitnessJson, ProofJson, PublicJson, VerificationKeyJson, write_r1cs_to_file, write_wtns_to_file};
+use std::fs::create_dir_all;
 use rand_core::OsRng;
 use serde::{Serialize, Deserialize};
-#[cfg(feature = "poseidon")]
-use arkworks_utils::poseidon::PoseidonParameters;
-#[cfg(feature = "poseidon")]
-use soda_maze_lib::vanilla::hasher::poseidon::PoseidonHasher;
-#[cfg(feature = "poseidon")]
-use soda_maze_lib::circuits::poseidon::PoseidonHasherGadget;
 
 #[cfg(feature = "bn254")]
 use ark_bn254::Bn254;
@@ -27,69 +30,193 @@ use ark_bls12_381::Bls12_381;
 #[cfg(feature = "bls12-381")]
 use ark_ed_on_bls12_381::{EdwardsParameters, EdwardsAffine, Fq as Fr, Fr as Frr};
 
-#[cfg(all(feature = "bn254", feature = "poseidon"))]
-type DepositInstant = DepositProof::<EdwardsParameters, PoseidonHasher<Fr>, PoseidonHasherGadget<Fr>, Groth16<Bn254>>;
-#[cfg(all(feature = "bls12-381", feature = "poseidon"))]
-type DepositInstant = DepositProof::<EdwardsParameters, PoseidonHasher<Fr>, PoseidonHasherGadget<Fr>, Groth16<Bls12_381>>;
+#[cfg(feature = "bn254")]
+type DepositInstant = DepositProof::<EdwardsParameters, Hasher<Fr>, HasherGadget<Fr>, Groth16<Bn254>>;
+#[cfg(feature = "bls12-381")]
+type DepositInstant = DepositProof::<EdwardsParameters, Hasher<Fr>, HasherGadget<Fr>, Groth16<Bls12_381>>;
 
-#[cfg(all(feature = "bn254", feature = "poseidon"))]
-type WithdrawInstant = WithdrawProof::<EdwardsParameters, PoseidonHasher<Fr>, PoseidonHasherGadget<Fr>, Groth16<Bn254>>;
-#[cfg(all(feature = "bls12-381", feature = "poseidon"))]
-type WithdrawInstant = WithdrawProof::<EdwardsParameters, PoseidonHasher<Fr>, PoseidonHasherGadget<Fr>, Groth16<Bls12_381>>;
+#[cfg(feature = "bn254")]
+type WithdrawInstant = WithdrawProof::<EdwardsParameters, Hasher<Fr>, HasherGadget<Fr>, Groth16<Bn254>>;
+#[cfg(feature = "bls12-381")]
+type WithdrawInstant = WithdrawProof::<EdwardsParameters, Hasher<Fr>, HasherGadget<Fr>, Groth16<Bls12_381>>;
 
-#[cfg(feature = "poseidon")]
-type DepositVanillaInstant = DepositVanillaProof::<EdwardsParameters, PoseidonHasher<Fr>>;
+#[cfg(feature = "bn254")]
+type BatchDepositInstant = BatchDepositProof::<EdwardsParameters, Hasher<Fr>, HasherGadget<Fr>, Groth16<Bn254>>;
+#[cfg(feature = "bls12-381")]
+type BatchDepositInstant = BatchDepositProof::<EdwardsParameters, Hasher<Fr>, HasherGadget<Fr>, Groth16<Bls12_381>>;
 
-#[cfg(feature = "poseidon")]
-type WithdrawVanillaInstant = WithdrawVanillaProof::<EdwardsParameters, PoseidonHasher<Fr>>;
+#[cfg(feature = "bn254")]
+type TransferInstant = TransferProof::<EdwardsParameters, Hasher<Fr>, HasherGadget<Fr>, Groth16<Bn254>>;
+#[cfg(feature = "bls12-381")]
+type TransferInstant = TransferProof::<EdwardsParameters, Hasher<Fr>, HasherGadget<Fr>, Groth16<Bls12_381>>;
+
+type DepositVanillaInstant = DepositVanillaProof::<EdwardsParameters, Hasher<Fr>>;
+
+type WithdrawVanillaInstant = WithdrawVanillaProof::<EdwardsParameters, Hasher<Fr>>;
+
+type BatchDepositVanillaInstant = BatchDepositVanillaProof::<EdwardsParameters, Hasher<Fr>>;
+
+type TransferVanillaInstant = TransferVanillaProof::<EdwardsParameters, Hasher<Fr>>;
 
 #[derive(Serialize, Deserialize)]
 struct DepositProofData {
     deposit_amount: u64,
-    prev_root: String,
+    asset_id: String,
     leaf_index: u64,
     leaf: String,
-    update_nodes: Vec<String>,
-    commitment: Option<String>,
+    height: usize,
+    auditor_pubkeys: Vec<String>,
+    commitments: Option<Vec<String>>,
     proof: String,
 }
 
 impl JsonParser for DepositProofData {}
 
+impl DepositProofData {
+    fn circuit_info(&self) -> CircuitInfo {
+        CircuitInfo {
+            circuit: CircuitKind::Deposit,
+            height: self.height,
+            auditors: self.auditor_pubkeys.len(),
+        }
+    }
+
+    fn public_inputs(&self) -> DepositPublicInputs<EdwardsParameters> {
+        DepositPublicInputs {
+            leaf_index: self.leaf_index,
+            deposit_amount: self.deposit_amount,
+            asset_id: from_hex_string(self.asset_id.clone()).expect("invalid asset id string"),
+            leaf: from_hex_string(self.leaf.clone()).expect("invalid leaf string"),
+            auditor_pubkeys: self.auditor_pubkeys.iter().cloned().map(|p| {
+                from_hex_string(p).expect("invalid auditor pubkey string")
+            }).collect(),
+            commit: self.commitments.as_ref().map(|commitments| {
+                CommitPublicInputs {
+                    commitments: commitments.iter().cloned().map(|c| {
+                        from_hex_string(c).expect("invalid commitment string")
+                    }).collect(),
+                }
+            }),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize)]
 struct WithdrawProofData {
     withdraw_amount: u64,
+    asset_id: String,
     receiver: String,
+    relayer: String,
+    fee: u64,
     nullifier_point: String,
-    prev_root: String,
+    src_root: String,
     dst_leaf_index: u64,
+    min_anonymity_gap: u64,
     dst_leaf: String,
-    update_nodes: Vec<String>,
-    commitment: Option<String>,
+    height: usize,
+    auditor_pubkeys: Vec<String>,
+    commitments: Option<Vec<String>>,
+    exclusion_root: Option<String>,
+    allowlist_root: Option<String>,
     proof: String,
 }
 
 impl JsonParser for WithdrawProofData {}
 
-#[cfg(feature = "poseidon")]
+impl WithdrawProofData {
+    fn circuit_info(&self) -> CircuitInfo {
+        CircuitInfo {
+            circuit: CircuitKind::withdraw(self.exclusion_root.is_some(), self.allowlist_root.is_some()),
+            height: self.height,
+            auditors: self.auditor_pubkeys.len(),
+        }
+    }
+
+    fn public_inputs(&self) -> WithdrawPublicInputs<EdwardsParameters> {
+        WithdrawPublicInputs {
+            withdraw_amount: self.withdraw_amount,
+            asset_id: from_hex_string(self.asset_id.clone()).expect("invalid asset id string"),
+            receiver: from_hex_string(self.receiver.clone()).expect("invalid receiver string"),
+            relayer: from_hex_string(self.relayer.clone()).expect("invalid relayer string"),
+            fee: self.fee,
+            src_root: from_hex_string(self.src_root.clone()).expect("invalid src root string"),
+            dst_leaf_index: self.dst_leaf_index,
+            min_anonymity_gap: self.min_anonymity_gap,
+            dst_leaf: from_hex_string(self.dst_leaf.clone()).expect("invalid dst leaf string"),
+            nullifier_point: from_hex_string(self.nullifier_point.clone()).expect("invalid nullifier string"),
+            auditor_pubkeys: self.auditor_pubkeys.iter().cloned().map(|p| {
+                from_hex_string(p).expect("invalid auditor pubkey string")
+            }).collect(),
+            commit: self.commitments.as_ref().map(|commitments| {
+                CommitPublicInputs {
+                    commitments: commitments.iter().cloned().map(|c| {
+                        from_hex_string(c).expect("invalid commitment string")
+                    }).collect(),
+                }
+            }),
+            exclusion_root: self.exclusion_root.clone().map(|r| {
+                from_hex_string(r).expect("invalid exclusion root string")
+            }),
+            allowlist_root: self.allowlist_root.clone().map(|r| {
+                from_hex_string(r).expect("invalid allowlist root string")
+            }),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+struct NamespaceData {
+    namespace: String,
+    constraints: usize,
+    witnesses: usize,
+    inputs: usize,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ConstraintReportData {
+    circuit: String,
+    constraints: usize,
+    witnesses: usize,
+    inputs: usize,
+    namespaces: Vec<NamespaceData>,
+}
+
+impl JsonParser for ConstraintReportData {}
+
+impl ConstraintReportData {
+    fn new(circuit: String, report: ConstraintReport) -> Self {
+        Self {
+            circuit,
+            constraints: report.constraints,
+            witnesses: report.witnesses,
+            inputs: report.inputs,
+            namespaces: report.namespaces.into_iter().map(|count| NamespaceData {
+                namespace: count.namespace,
+                constraints: count.constraints,
+                witnesses: count.witnesses,
+                inputs: count.inputs,
+            }).collect(),
+        }
+    }
+}
+
 struct MerkleTree<'a> {
-    params: &'a PoseidonParameters<Fr>,
+    params: &'a <Hasher<Fr> as FieldHasher<Fr>>::Parameters,
     height: usize,
     tree: BTreeMap<(usize, u64), Fr>,
     blank: Vec<Fr>,
 }
 
-#[cfg(feature = "poseidon")]
 impl<'a> MerkleTree<'a> {
-    fn new(height: usize, params: &'a PoseidonParameters<Fr>) -> Self {
+    fn new(height: usize, params: &'a <Hasher<Fr> as FieldHasher<Fr>>::Parameters) -> Self {
         let mut nodes = Vec::with_capacity(height);
-        let mut hash: Fr = PoseidonHasher::empty_hash();
+        let mut hash: Fr = Hasher::empty_hash();
 
         (0..height)
             .into_iter()
             .for_each(|_| {
                 nodes.push(hash);
-                hash = PoseidonHasher::hash_two(params, hash, hash).unwrap();
+                hash = Hasher::hash_two(params, hash, hash).unwrap();
             });
 
         Self {
@@ -125,10 +252,10 @@ impl<'a> MerkleTree<'a> {
 
                 if (index & 1) == 1 {
                     let neighbor = self.tree.get(&(layer, index - 1)).unwrap_or_else(|| &self.blank[layer]);
-                    hash = PoseidonHasher::hash_two(self.params, *neighbor, hash).expect("poseidon hash error");
+                    hash = Hasher::hash_two(self.params, *neighbor, hash).expect("hash error");
                 } else {
                     let neighbor = self.tree.get(&(layer, index + 1)).unwrap_or_else(|| &self.blank[layer]);
-                    hash = PoseidonHasher::hash_two(self.params, hash, *neighbor).expect("poseidon hash error");
+                    hash = Hasher::hash_two(self.params, hash, *neighbor).expect("hash error");
                 }
             });
     }
@@ -144,12 +271,19 @@ enum Opt {
         deposit_amount: u64,
         #[clap(long = "leaf-index", value_parser, default_value = "0")]
         leaf_index: u64,
+        /// viewing pubkey of an auditor, repeat it for several auditors
         #[clap(long = "viewing-pubkey", value_parser)]
-        pubkey: Option<String>,
+        pubkeys: Vec<String>,
         #[clap(long = "pk-path", parse(from_os_str), default_value = "pk-deposit")]
         pk_path: PathBuf,
+        /// skip the point checks when loading an uncompressed proving key
+        #[clap(long)]
+        trusted: bool,
         #[clap(long = "proof-path", parse(from_os_str), default_value = "proof.json")]
         proof_path: PathBuf,
+        /// also export the r1cs and the witness to this directory
+        #[clap(long = "export-dir", parse(from_os_str))]
+        export_dir: Option<PathBuf>,
     },
     ProveWithdraw {
         #[clap(long, value_parser, default_value = "21")]
@@ -158,16 +292,87 @@ enum Opt {
         balance: u64,
         #[clap(long = "withdraw-amount", value_parser, default_value = "1")]
         withdraw_amount: u64,
+        #[clap(long = "fee", value_parser, default_value = "0")]
+        fee: u64,
         #[clap(long = "src-index", value_parser, default_value = "0")]
         src_index: u64,
         #[clap(long = "dst-index", value_parser, default_value = "1")]
         dst_index: u64,
+        /// minimum leaves between the src and dst leaf required by the vault
+        #[clap(long = "min-anonymity-gap", value_parser, default_value = "0")]
+        min_anonymity_gap: u64,
+        /// prove the src leaf index is not in the exclusion set
+        #[clap(long)]
+        exclusion: bool,
+        /// leaf index in the exclusion set, repeat it for several indexes
+        #[clap(long = "blocked-index", value_parser)]
+        blocked: Vec<u64>,
+        /// prove the owner key is in an allowlist
+        #[clap(long)]
+        allowlist: bool,
+        /// number of other owner keys in the allowlist besides the prover's own
+        #[clap(long = "allowed-keys", value_parser, default_value = "0")]
+        allowed: usize,
+        /// viewing pubkey of an auditor, repeat it for several auditors
         #[clap(long = "viewing-pubkey", value_parser)]
-        pubkey: Option<String>,
+        pubkeys: Vec<String>,
         #[clap(long = "pk-path", parse(from_os_str), default_value = "pk-withdraw")]
         pk_path: PathBuf,
+        /// skip the point checks when loading an uncompressed proving key
+        #[clap(long)]
+        trusted: bool,
         #[clap(long = "proof-path", parse(from_os_str), default_value = "proof.json")]
         proof_path: PathBuf,
+        /// also export the r1cs and the witness to this directory
+        #[clap(long = "export-dir", parse(from_os_str))]
+        export_dir: Option<PathBuf>,
+    },
+    /// Prove a batch deposit, the proof and the public inputs are written in the snarkjs layout
+    ProveBatchDeposit {
+        #[clap(long, value_parser, default_value = "21")]
+        height: usize,
+        /// depth of the batch deposit subtree
+        #[clap(long, value_parser, default_value = "3")]
+        depth: usize,
+        #[clap(long = "deposit-amount", value_parser, default_value = "1")]
+        deposit_amount: u64,
+        /// first leaf index of the subtree, aligned to the subtree size
+        #[clap(long = "leaf-index", value_parser, default_value = "0")]
+        leaf_index: u64,
+        /// viewing pubkey of an auditor, repeat it for several auditors
+        #[clap(long = "viewing-pubkey", value_parser)]
+        pubkeys: Vec<String>,
+        #[clap(long = "pk-path", parse(from_os_str), default_value = "pk-batch-deposit")]
+        pk_path: PathBuf,
+        /// skip the point checks when loading an uncompressed proving key
+        #[clap(long)]
+        trusted: bool,
+        #[clap(long = "out-dir", parse(from_os_str), default_value = "snarkjs")]
+        out_dir: PathBuf,
+    },
+    /// Prove a transfer paid to self, the proof and the public inputs are written in the snarkjs layout
+    ProveTransfer {
+        #[clap(long, value_parser, default_value = "21")]
+        height: usize,
+        #[clap(long = "balance", value_parser, default_value = "1")]
+        balance: u64,
+        #[clap(long = "withdraw-amount", value_parser, default_value = "0")]
+        withdraw_amount: u64,
+        /// leaf index of the first src note, the second one is next to it
+        #[clap(long = "src-index", value_parser, default_value = "0")]
+        src_index: u64,
+        #[clap(long = "dst-index", value_parser, default_value = "2")]
+        dst_index: u64,
+        /// viewing pubkey of an auditor, repeat it for several auditors
+        #[clap(long = "viewing-pubkey", value_parser)]
+        pubkeys: Vec<String>,
+        #[clap(long = "pk-path", parse(from_os_str), default_value = "pk-transfer")]
+        pk_path: PathBuf,
+        /// skip the point checks when loading an uncompressed proving key
+        #[clap(long)]
+        trusted: bool,
+        #[clap(long = "out-dir", parse(from_os_str), default_value = "snarkjs")]
+        out_dir: PathBuf,
     },
     VerifyDeposit {
         #[clap(long = "vk-path", parse(from_os_str), default_value = "vk-deposit")]
@@ -180,7 +385,59 @@ enum Opt {
         vk_path: PathBuf,
         #[clap(long = "proof-path", parse(from_os_str), default_value = "proof.json")]
         proof_path: PathBuf,
-    }
+    },
+    /// Replace the proof in the proof data with one from an external prover
+    ImportProof {
+        #[clap(long, value_parser = ["deposit", "withdraw"], default_value = "withdraw")]
+        circuit: String,
+        #[clap(long = "external-proof-path", parse(from_os_str))]
+        external_proof_path: PathBuf,
+        #[clap(long = "proof-path", parse(from_os_str), default_value = "proof.json")]
+        proof_path: PathBuf,
+    },
+    /// Write the proof and the verifying key in the snarkjs layout
+    ExportSnarkjs {
+        #[clap(long, value_parser = ["deposit", "withdraw"], default_value = "withdraw")]
+        circuit: String,
+        #[clap(long = "vk-path", parse(from_os_str))]
+        vk_path: Option<PathBuf>,
+        #[clap(long = "proof-path", parse(from_os_str), default_value = "proof.json")]
+        proof_path: PathBuf,
+        #[clap(long = "out-dir", parse(from_os_str), default_value = "snarkjs")]
+        out_dir: PathBuf,
+    },
+    /// Verify a proof given in the snarkjs layout
+    VerifySnarkjs {
+        #[clap(long = "vk-path", parse(from_os_str), default_value = "verification_key.json")]
+        vk_path: PathBuf,
+        #[clap(long = "proof-path", parse(from_os_str), default_value = "proof.json")]
+        proof_path: PathBuf,
+        #[clap(long = "public-path", parse(from_os_str), default_value = "public.json")]
+        public_path: PathBuf,
+    },
+    /// Count constraints of a circuit per namespace
+    Profile {
+        #[clap(long, value_parser = ["deposit", "batch-deposit", "withdraw", "transfer"], default_value = "withdraw")]
+        circuit: String,
+        #[clap(long, value_parser, default_value = "21")]
+        height: usize,
+        /// depth of the batch deposit subtree
+        #[clap(long, value_parser, default_value = "3")]
+        depth: usize,
+        #[clap(long, value_parser, default_value = "1")]
+        auditors: usize,
+        /// print the JSON report if not given
+        #[clap(long = "report-path", parse(from_os_str))]
+        report_path: Option<PathBuf>,
+    },
+}
+
+fn export_r1cs(r1cs: &R1CSExport<Fr>, dir: &Path) {
+    create_dir_all(dir).expect("create export dir error");
+    write_r1cs_to_file(r1cs, &dir.join("circuit.r1cs")).expect("write r1cs to file error");
+    write_wtns_to_file(r1cs, &dir.join("witness.wtns")).expect("write witness to file error");
+    R1CSJson::from(r1cs).to_file(&dir.join("circuit.r1cs.json")).expect("write r1cs json to file error");
+    WitnessJson::from(r1cs).to_file(&dir.join("witness.json")).expect("write witness json to file error");
 }
 
 fn main() {
@@ -191,51 +448,69 @@ fn main() {
             height,
             deposit_amount,
             leaf_index,
-            pubkey,
+            pubkeys,
             pk_path,
+            trusted,
             proof_path,
+            export_dir,
         } => {
+            let load_time = std::time::SystemTime::now();
+            let info = CircuitInfo { circuit: CircuitKind::Deposit, height, auditors: pubkeys.len() };
+            let pk = read_pk_from_file(&pk_path, &info, trusted).expect("invalid proving key file");
+            let duration = std::time::SystemTime::now().duration_since(load_time).unwrap();
+            println!("load time: {:?}", duration);
+
             let start_time = std::time::SystemTime::now();
             let rng = &mut OsRng;
 
-            let pubkey = pubkey.map(|pubkey| {
-                from_hex_string::<EdwardsAffine>(pubkey).expect("invalid viewing pubkey")
+            let pubkeys = (!pubkeys.is_empty()).then(|| {
+                pubkeys.into_iter().map(|pubkey| {
+                    from_hex_string::<EdwardsAffine>(pubkey).expect("invalid viewing pubkey")
+                }).collect::<Vec<_>>()
             });
             let const_params = gen_deposit_const_params(
                 height,
-                pubkey,
+                pubkeys.clone(),
             );
             
-            let secret = Fr::rand(rng);
-            let merkle_tree = MerkleTree::new(height, &const_params.inner_params);
-            let neighbor_nodes = merkle_tree.blank.clone();
+            let asset_id = Fr::rand(rng);
+            let owner = Fr::rand(rng);
 
             let origin_inputs = DepositOriginInputs {
                 leaf_index,
                 deposit_amount,
-                secret,
-                neighbor_nodes,
-                commit: pubkey.and(Some(CommitOriginInputs { nonce: Frr::rand(rng) })),
+                asset_id,
+                owner,
+                commit: pubkeys.as_ref().map(|pubkeys| CommitOriginInputs {
+                    nonces: pubkeys.iter().map(|_| Frr::rand(rng)).collect(),
+                    // escrow of a random nullifier key, as published with a shielded address
+                    escrow: gen_escrow(
+                        pubkeys,
+                        Fr::rand(rng),
+                        &pubkeys.iter().map(|_| Frr::rand(rng)).collect::<Vec<_>>(),
+                    ).unwrap(),
+                }),
             };
 
-            let pk = borsh_de_from_file::<MazeProvingKey>(&pk_path).expect("invalid proving key file");
-            let pk = pk.into();
             let (pub_in, priv_in) =
                 DepositVanillaInstant::generate_vanilla_proof(&const_params, &origin_inputs)
                     .expect("generate vanilla proof failed");
+            if let Some(export_dir) = export_dir {
+                let r1cs = DepositInstant::export_r1cs(&const_params, &pub_in, &priv_in).expect("export r1cs failed");
+                export_r1cs(&r1cs, &export_dir);
+            }
             let proof =
                 DepositInstant::generate_snark_proof(rng, &const_params, &pub_in, &priv_in, &pk).expect("generate snark proof failed");
 
             let proof_data = DepositProofData {
                 deposit_amount,
-                prev_root: to_hex_string(&pub_in.prev_root).unwrap(),
+                asset_id: to_hex_string(&pub_in.asset_id).unwrap(),
                 leaf: to_hex_string(&pub_in.leaf).unwrap(),
                 leaf_index: pub_in.leaf_index,
-                update_nodes: pub_in.update_nodes.iter().map(|n| {
-                    to_hex_string(n).unwrap()
-                }).collect(),
-                commitment: pub_in.commit.as_ref().map(|commit| {
-                    to_hex_string(&commit.commitment).unwrap()
+                height,
+                auditor_pubkeys: pub_in.auditor_pubkeys.iter().map(|p| to_hex_string(p).unwrap()).collect(),
+                commitments: pub_in.commit.as_ref().map(|commit| {
+                    commit.commitments.iter().map(|c| to_hex_string(c).unwrap()).collect()
                 }),
                 proof: to_hex_string(&proof).unwrap(),
             };
@@ -248,66 +523,128 @@ fn main() {
             height,
             balance,
             withdraw_amount,
+            fee,
             src_index,
             dst_index,
-            pubkey,
+            min_anonymity_gap,
+            exclusion,
+            blocked,
+            allowlist,
+            allowed,
+            pubkeys,
             pk_path,
+            trusted,
             proof_path,
+            export_dir,
         } => {
+            let load_time = std::time::SystemTime::now();
+            let info = CircuitInfo { circuit: CircuitKind::withdraw(exclusion, allowlist), height, auditors: pubkeys.len() };
+            let pk = read_pk_from_file(&pk_path, &info, trusted).expect("invalid proving key file");
+            let duration = std::time::SystemTime::now().duration_since(load_time).unwrap();
+            println!("load time: {:?}", duration);
+
             let start_time = std::time::SystemTime::now();
             let rng = &mut OsRng;
 
-            let pubkey = pubkey.map(|pubkey| {
-                from_hex_string::<EdwardsAffine>(pubkey).expect("invalid viewing pubkey")
+            let pubkeys = (!pubkeys.is_empty()).then(|| {
+                pubkeys.into_iter().map(|pubkey| {
+                    from_hex_string::<EdwardsAffine>(pubkey).expect("invalid viewing pubkey")
+                }).collect::<Vec<_>>()
             });
             let const_params = gen_withdraw_const_params(
                 height,
-                pubkey,
+                pubkeys.clone(),
+                exclusion,
+                allowlist,
             );
 
             let mut merkle_tree = MerkleTree::new(height, &const_params.inner_params);
+            let asset_id = Fr::rand(rng);
             let receiver = Fr::rand(rng);
+            let relayer = Fr::rand(rng);
             let secret = Fr::rand(rng);
-            let src_leaf = PoseidonHasher::hash(
+            let owner = gen_owner_key::<_, Hasher<Fr>>(&const_params.nullifier_params, secret).unwrap();
+            let nullifier_key = gen_nullifier_key::<_, Hasher<Fr>>(&const_params.nullifier_params, secret).unwrap();
+            // the src note is committed with the escrow of the owner, the rest note reuses it
+            let escrow_nonces = pubkeys.iter().flatten().map(|_| Frr::rand(rng)).collect::<Vec<_>>();
+            let escrow = gen_escrow(pubkeys.as_deref().unwrap_or_default(), nullifier_key, &escrow_nonces).unwrap();
+            let note_owner = gen_note_owner::<_, Hasher<Fr>>(
+                &const_params.nullifier_params,
+                owner,
+                pubkeys.as_deref().unwrap_or_default(),
+                &escrow,
+            ).unwrap();
+            let src_leaf = Hasher::hash(
                 &const_params.leaf_params,
-                &[Fr::from(src_index), Fr::from(balance), secret],
+                &[Fr::from(src_index), asset_id, Fr::from(balance), note_owner],
             ).unwrap();
             merkle_tree.add_leaf(src_index, src_leaf);
             let src_neighbor_nodes = merkle_tree.get_neighbors(src_index);
-            let dst_neighbor_nodes = merkle_tree.get_neighbors(dst_index);
+            let exclusion_neighbor_nodes = exclusion.then(|| {
+                let mut tree = IndexSetTree::<_, Hasher<Fr>>::new(const_params.inner_params.clone(), height)
+                    .expect("build index set tree failed");
+                blocked.iter().for_each(|&index| tree.insert(index).expect("invalid index in index set"));
+                tree.gen_non_membership_neighbor_nodes(src_index).expect("src index is in the exclusion set")
+            });
+            let allowlist_neighbor_nodes = allowlist.then(|| {
+                let mut tree = KeySetTree::<_, Hasher<Fr>>::new(const_params.inner_params.clone(), height)
+                    .expect("build key set tree failed");
+                (0..allowed).for_each(|_| { tree.insert(Fr::rand(rng)).expect("invalid key in key set"); });
+                tree.insert(owner).expect("owner key is in the allowlist");
+                tree.gen_membership_neighbor_nodes(&owner).expect("owner key is not in the allowlist")
+            });
 
             let origin_inputs = WithdrawOriginInputs {
                 balance,
                 withdraw_amount,
+                asset_id,
                 src_leaf_index: src_index,
                 dst_leaf_index: dst_index,
+                min_anonymity_gap,
                 receiver,
+                relayer,
+                fee,
                 secret,
                 src_neighbor_nodes,
-                dst_neighbor_nodes,
-                commit: pubkey.and(Some(CommitOriginInputs { nonce: Frr::rand(rng) })),
+                exclusion_neighbor_nodes,
+                allowlist_neighbor_nodes,
+                commit: pubkeys.as_ref().map(|pubkeys| CommitOriginInputs {
+                    nonces: pubkeys.iter().map(|_| Frr::rand(rng)).collect(),
+                    escrow: escrow.clone(),
+                }),
+                src_escrow: pubkeys.as_ref().map(|pubkeys| EscrowOriginInputs {
+                    pubkeys: pubkeys.clone(),
+                    nonces: escrow_nonces,
+                }),
             };
 
-            let pk = borsh_de_from_file::<MazeProvingKey>(&pk_path).expect("invalid proving key file");
-            let pk = pk.into();
             let (pub_in, priv_in)
                 = WithdrawVanillaInstant::generate_vanilla_proof(&const_params, &origin_inputs).expect("generate vanilla proof failed");
+            if let Some(export_dir) = export_dir {
+                let r1cs = WithdrawInstant::export_r1cs(&const_params, &pub_in, &priv_in).expect("export r1cs failed");
+                export_r1cs(&r1cs, &export_dir);
+            }
             let proof =
                 WithdrawInstant::generate_snark_proof(rng, &const_params, &pub_in, &priv_in, &pk).expect("generate snark proof failed");
             
             let proof_data = WithdrawProofData {
                 withdraw_amount: pub_in.withdraw_amount,
+                asset_id: to_hex_string(&pub_in.asset_id).unwrap(),
                 receiver: to_hex_string(&pub_in.receiver).unwrap(), 
-                prev_root: to_hex_string(&pub_in.prev_root).unwrap(),
+                relayer: to_hex_string(&pub_in.relayer).unwrap(),
+                fee: pub_in.fee,
+                src_root: to_hex_string(&pub_in.src_root).unwrap(),
                 dst_leaf_index: pub_in.dst_leaf_index,
+                min_anonymity_gap: pub_in.min_anonymity_gap,
                 dst_leaf: to_hex_string(&pub_in.dst_leaf).unwrap(),
                 nullifier_point: to_hex_string(&pub_in.nullifier_point).unwrap(),
-                update_nodes: pub_in.update_nodes.iter().map(|n| {
-                    to_hex_string(n).unwrap()
-                }).collect(),
-                commitment: pub_in.commit.as_ref().map(|commit| {
-                    to_hex_string(&commit.commitment).unwrap()
+                height,
+                auditor_pubkeys: pub_in.auditor_pubkeys.iter().map(|p| to_hex_string(p).unwrap()).collect(),
+                commitments: pub_in.commit.as_ref().map(|commit| {
+                    commit.commitments.iter().map(|c| to_hex_string(c).unwrap()).collect()
                 }),
+                exclusion_root: pub_in.exclusion_root.as_ref().map(|r| to_hex_string(r).unwrap()),
+                allowlist_root: pub_in.allowlist_root.as_ref().map(|r| to_hex_string(r).unwrap()),
                 proof: to_hex_string(&proof).unwrap(),
             };
             proof_data.to_file(&proof_path).expect("write proof data to file error");
@@ -315,30 +652,174 @@ fn main() {
             let duration = std::time::SystemTime::now().duration_since(start_time).unwrap();
             println!("prove time: {:?}", duration);
         },
+        Opt::ProveBatchDeposit {
+            height,
+            depth,
+            deposit_amount,
+            leaf_index,
+            pubkeys,
+            pk_path,
+            trusted,
+            out_dir,
+        } => {
+            let load_time = std::time::SystemTime::now();
+            let info = CircuitInfo { circuit: CircuitKind::BatchDeposit, height, auditors: pubkeys.len() };
+            let pk = read_pk_from_file(&pk_path, &info, trusted).expect("invalid proving key file");
+            let duration = std::time::SystemTime::now().duration_since(load_time).unwrap();
+            println!("load time: {:?}", duration);
+
+            let start_time = std::time::SystemTime::now();
+            let rng = &mut OsRng;
+
+            let pubkeys = (!pubkeys.is_empty()).then(|| {
+                pubkeys.into_iter().map(|pubkey| {
+                    from_hex_string::<EdwardsAffine>(pubkey).expect("invalid viewing pubkey")
+                }).collect::<Vec<_>>()
+            });
+            let const_params = gen_batch_deposit_const_params(
+                height,
+                depth,
+                pubkeys.clone(),
+            );
+
+            let size = 1usize << depth;
+            let origin_inputs = BatchDepositOriginInputs {
+                leaf_index,
+                deposit_amounts: vec![deposit_amount; size],
+                asset_id: Fr::rand(rng),
+                owners: (0..size).map(|_| Fr::rand(rng)).collect(),
+                commit: pubkeys.as_ref().map(|pubkeys| {
+                    (0..size).map(|_| CommitOriginInputs {
+                        nonces: pubkeys.iter().map(|_| Frr::rand(rng)).collect(),
+                        escrow: gen_escrow(
+                            pubkeys,
+                            Fr::rand(rng),
+                            &pubkeys.iter().map(|_| Frr::rand(rng)).collect::<Vec<_>>(),
+                        ).unwrap(),
+                    }).collect()
+                }),
+            };
+
+            let (pub_in, priv_in) =
+                BatchDepositVanillaInstant::generate_vanilla_proof(&const_params, &origin_inputs)
+                    .expect("generate vanilla proof failed");
+            let proof =
+                BatchDepositInstant::generate_snark_proof(rng, &const_params, &pub_in, &priv_in, &pk).expect("generate snark proof failed");
+
+            create_dir_all(&out_dir).expect("create out dir error");
+            let inputs = BatchDepositInstant::generate_public_inputs(&pub_in);
+            to_proof_json(to_maze_proof(proof)).to_file(&out_dir.join("proof.json")).expect("write proof to file error");
+            to_public_json(&inputs).to_file(&out_dir.join("public.json")).expect("write public inputs to file error");
+
+            let duration = std::time::SystemTime::now().duration_since(start_time).unwrap();
+            println!("prove time: {:?}", duration);
+        }
+        Opt::ProveTransfer {
+            height,
+            balance,
+            withdraw_amount,
+            src_index,
+            dst_index,
+            pubkeys,
+            pk_path,
+            trusted,
+            out_dir,
+        } => {
+            let load_time = std::time::SystemTime::now();
+            let info = CircuitInfo { circuit: CircuitKind::Transfer, height, auditors: pubkeys.len() };
+            let pk = read_pk_from_file(&pk_path, &info, trusted).expect("invalid proving key file");
+            let duration = std::time::SystemTime::now().duration_since(load_time).unwrap();
+            println!("load time: {:?}", duration);
+
+            let start_time = std::time::SystemTime::now();
+            let rng = &mut OsRng;
+
+            let pubkeys = (!pubkeys.is_empty()).then(|| {
+                pubkeys.into_iter().map(|pubkey| {
+                    from_hex_string::<EdwardsAffine>(pubkey).expect("invalid viewing pubkey")
+                }).collect::<Vec<_>>()
+            });
+            let const_params = gen_transfer_const_params(
+                height,
+                pubkeys.clone(),
+            );
+
+            let mut merkle_tree = MerkleTree::new(height, &const_params.inner_params);
+            let asset_id = Fr::rand(rng);
+            let secret = Fr::rand(rng);
+            let owner = gen_owner_key::<_, Hasher<Fr>>(&const_params.nullifier_params, secret).unwrap();
+            let nullifier_key = gen_nullifier_key::<_, Hasher<Fr>>(&const_params.nullifier_params, secret).unwrap();
+            // both src notes and both dst notes are committed with the escrow of the owner
+            let escrow_nonces = pubkeys.iter().flatten().map(|_| Frr::rand(rng)).collect::<Vec<_>>();
+            let escrow = gen_escrow(pubkeys.as_deref().unwrap_or_default(), nullifier_key, &escrow_nonces).unwrap();
+            let note_owner = gen_note_owner::<_, Hasher<Fr>>(
+                &const_params.nullifier_params,
+                owner,
+                pubkeys.as_deref().unwrap_or_default(),
+                &escrow,
+            ).unwrap();
+            let src_indexes = [src_index, src_index + 1];
+            src_indexes.iter().for_each(|&index| {
+                let leaf = Hasher::hash(
+                    &const_params.leaf_params,
+                    &[Fr::from(index), asset_id, Fr::from(balance), note_owner],
+                ).unwrap();
+                merkle_tree.add_leaf(index, leaf);
+            });
+            let src_neighbor_nodes = src_indexes.map(|index| merkle_tree.get_neighbors(index));
+
+            let total = balance.checked_mul(2).expect("balance overflow");
+            let rest = total.checked_sub(withdraw_amount).expect("withdraw amount exceeds balances");
+            let origin_inputs = TransferOriginInputs {
+                src_balances: [balance; 2],
+                src_leaf_indexes: src_indexes,
+                dst_amounts: [rest / 2, rest - rest / 2],
+                withdraw_amount,
+                asset_id,
+                dst_leaf_index: dst_index,
+                receiver: Fr::rand(rng),
+                secret,
+                recipient: owner,
+                src_neighbor_nodes,
+                commit: pubkeys.as_ref().map(|pubkeys| {
+                    [(), ()].map(|_| CommitOriginInputs {
+                        nonces: pubkeys.iter().map(|_| Frr::rand(rng)).collect(),
+                        escrow: escrow.clone(),
+                    })
+                }),
+                src_escrow: pubkeys.as_ref().map(|pubkeys| {
+                    [(), ()].map(|_| EscrowOriginInputs {
+                        pubkeys: pubkeys.clone(),
+                        nonces: escrow_nonces.clone(),
+                    })
+                }),
+            };
+
+            let (pub_in, priv_in) =
+                TransferVanillaInstant::generate_vanilla_proof(&const_params, &origin_inputs)
+                    .expect("generate vanilla proof failed");
+            let proof =
+                TransferInstant::generate_snark_proof(rng, &const_params, &pub_in, &priv_in, &pk).expect("generate snark proof failed");
+
+            create_dir_all(&out_dir).expect("create out dir error");
+            let inputs = TransferInstant::generate_public_inputs(&pub_in);
+            to_proof_json(to_maze_proof(proof)).to_file(&out_dir.join("proof.json")).expect("write proof to file error");
+            to_public_json(&inputs).to_file(&out_dir.join("public.json")).expect("write public inputs to file error");
+
+            let duration = std::time::SystemTime::now().duration_since(start_time).unwrap();
+            println!("prove time: {:?}", duration);
+        }
         Opt::VerifyDeposit {
             vk_path,
             proof_path,
         } => {
             let start_time = std::time::SystemTime::now();
 
-            let vk = borsh_de_from_file::<MazeVerifyingKey>(&vk_path).expect("invalid verifying key file");
-            let vk = vk.into();
             let proof_data = DepositProofData::from_file(&proof_path).expect("read proof data from file error");
+            let vk = borsh_de_from_file::<MazeVerifyingKey>(&vk_path, &proof_data.circuit_info()).expect("invalid verifying key file");
+            let vk = vk.into();
 
-            let pub_in = DepositPublicInputs {
-                leaf_index: proof_data.leaf_index,
-                deposit_amount: proof_data.deposit_amount,
-                leaf: from_hex_string(proof_data.leaf).expect("invalid leaf string"),
-                prev_root: from_hex_string(proof_data.prev_root).expect("invalid prev root string"),
-                update_nodes: proof_data.update_nodes.into_iter().map(|n| {
-                    from_hex_string(n).expect("invalid node string")
-                }).collect(),
-                commit: proof_data.commitment.map(|commitment| {
-                    CommitPublicInputs {
-                        commitment: from_hex_string(commitment).expect("invalid commitment string"),
-                    }
-                }),
-            };
+            let pub_in = proof_data.public_inputs();
             let proof = from_hex_string(proof_data.proof).expect("invalid proof string");
 
             let result = DepositInstant::verify_snark_proof(&pub_in, &proof, &vk)
@@ -391,31 +872,28 @@ fn main() {
             println!("BigInteger::new({:?})", pub_in.leaf.into_repr().0);
             println!("-----------------------------------------------------");
 
-            println!("prev_root");
-            println!("-----------------------------------------------------");
-            println!("BigInteger::new({:?})", pub_in.prev_root.into_repr().0);
+            println!("auditor_keys");
             println!("-----------------------------------------------------");
-
-            println!("update_nodes");
-            println!("-----------------------------------------------------");
-            println!("[");
-            pub_in.update_nodes.iter().for_each(|p| {
-                println!("    BigInteger::new({:?}),", p.into_repr().0);
+            pub_in.auditor_pubkeys.iter().for_each(|pubkey| {
+                println!("EdwardsAffine {{");
+                println!("    x: BigInteger::new({:?})", pubkey.x.into_repr().0);
+                println!("    y: BigInteger::new({:?})", pubkey.y.into_repr().0);
+                println!("}},");
             });
-            println!("]");
             println!("-----------------------------------------------------");
 
             println!("commitment");
             println!("-----------------------------------------------------");
-            let commitment = pub_in.commit.as_ref().unwrap().commitment;
-            println!("GroupAffine {{");
-            println!("    x: BigInteger::new({:?})", commitment.0.x.into_repr().0);
-            println!("    y: BigInteger::new({:?})", commitment.0.y.into_repr().0);
-            println!("}},");
-            println!("GroupAffine {{");
-            println!("    x: BigInteger::new({:?})", commitment.1.x.into_repr().0);
-            println!("    y: BigInteger::new({:?})", commitment.1.y.into_repr().0);
-            println!("}},");
+            pub_in.commit.as_ref().unwrap().commitments.iter().for_each(|commitment| {
+                println!("GroupAffine {{");
+                println!("    x: BigInteger::new({:?})", commitment.0.x.into_repr().0);
+                println!("    y: BigInteger::new({:?})", commitment.0.y.into_repr().0);
+                println!("}},");
+                println!("GroupAffine {{");
+                println!("    x: BigInteger::new({:?})", commitment.1.x.into_repr().0);
+                println!("    y: BigInteger::new({:?})", commitment.1.y.into_repr().0);
+                println!("}},");
+            });
             println!("-----------------------------------------------------");
         },
         Opt::VerifyWithdraw {
@@ -424,26 +902,11 @@ fn main() {
         } => {
             let start_time = std::time::SystemTime::now();
 
-            let vk = borsh_de_from_file::<MazeVerifyingKey>(&vk_path).expect("invalid verifying key file");
-            let vk = vk.into();
             let proof_data = WithdrawProofData::from_file(&proof_path).expect("read proof data from file error");
+            let vk = borsh_de_from_file::<MazeVerifyingKey>(&vk_path, &proof_data.circuit_info()).expect("invalid verifying key file");
+            let vk = vk.into();
 
-            let pub_in = WithdrawPublicInputs {
-                withdraw_amount: proof_data.withdraw_amount,
-                receiver: from_hex_string(proof_data.receiver).expect("invalid receiver string"),
-                prev_root: from_hex_string(proof_data.prev_root).expect("invalid prev root string"),
-                dst_leaf_index: proof_data.dst_leaf_index,
-                dst_leaf: from_hex_string(proof_data.dst_leaf).expect("invalid dst leaf string"),
-                nullifier_point: from_hex_string(proof_data.nullifier_point).expect("invalid nullifier string"),
-                update_nodes: proof_data.update_nodes.into_iter().map(|n| {
-                    from_hex_string(n).expect("invalid node string")
-                }).collect(),
-                commit: proof_data.commitment.map(|commitment| {
-                    CommitPublicInputs {
-                        commitment: from_hex_string(commitment).expect("invalid commitment string"),
-                    }
-                }),
-            };
+            let pub_in = proof_data.public_inputs();
             let proof = from_hex_string(proof_data.proof).expect("invalid proof string");
 
             let result = WithdrawInstant::verify_snark_proof(&pub_in, &proof, &vk)
@@ -457,5 +920,105 @@ fn main() {
             let duration = std::time::SystemTime::now().duration_since(start_time).unwrap();
             println!("verify time: {:?}", duration);
         },
+        Opt::ImportProof {
+            circuit,
+            external_proof_path,
+            proof_path,
+        } => {
+            let proof = ProofJson::from_file(&external_proof_path).expect("read external proof from file error");
+            let proof = from_proof_json(&proof).expect("invalid external proof");
+            let proof = to_hex_string(&from_maze_proof(proof)).unwrap();
+
+            match circuit.as_str() {
+                "deposit" => {
+                    let mut proof_data = DepositProofData::from_file(&proof_path).expect("read proof data from file error");
+                    proof_data.proof = proof;
+                    proof_data.to_file(&proof_path).expect("write proof data to file error");
+                }
+                "withdraw" => {
+                    let mut proof_data = WithdrawProofData::from_file(&proof_path).expect("read proof data from file error");
+                    proof_data.proof = proof;
+                    proof_data.to_file(&proof_path).expect("write proof data to file error");
+                }
+                _ => unreachable!(),
+            }
+        }
+        Opt::ExportSnarkjs {
+            circuit,
+            vk_path,
+            proof_path,
+            out_dir,
+        } => {
+            let vk_path = vk_path.unwrap_or_else(|| PathBuf::from(format!("vk-{}", circuit)));
+            let (info, inputs, proof) = match circuit.as_str() {
+                "deposit" => {
+                    let proof_data = DepositProofData::from_file(&proof_path).expect("read proof data from file error");
+                    let inputs = DepositInstant::generate_public_inputs(&proof_data.public_inputs());
+                    (proof_data.circuit_info(), inputs, proof_data.proof)
+                }
+                "withdraw" => {
+                    let proof_data = WithdrawProofData::from_file(&proof_path).expect("read proof data from file error");
+                    let inputs = WithdrawInstant::generate_public_inputs(&proof_data.public_inputs());
+                    (proof_data.circuit_info(), inputs, proof_data.proof)
+                }
+                _ => unreachable!(),
+            };
+            let vk = borsh_de_from_file::<MazeVerifyingKey>(&vk_path, &info).expect("invalid verifying key file");
+            let proof = from_hex_string(proof).expect("invalid proof string");
+
+            create_dir_all(&out_dir).expect("create out dir error");
+            to_proof_json(to_maze_proof(proof)).to_file(&out_dir.join("proof.json")).expect("write proof to file error");
+            to_public_json(&inputs).to_file(&out_dir.join("public.json")).expect("write public inputs to file error");
+            to_verification_key_json(vk).to_file(&out_dir.join("verification_key.json")).expect("write verifying key to file error");
+        }
+        Opt::VerifySnarkjs {
+            vk_path,
+            proof_path,
+            public_path,
+        } => {
+            let start_time = std::time::SystemTime::now();
+
+            let vk = VerificationKeyJson::from_file(&vk_path).expect("read verifying key from file error");
+            let vk = from_verification_key_json(&vk).expect("invalid verifying key").into();
+            let proof = ProofJson::from_file(&proof_path).expect("read proof from file error");
+            let proof = from_maze_proof(from_proof_json(&proof).expect("invalid proof"));
+            let inputs = PublicJson::from_file(&public_path).expect("read public inputs from file error");
+            let inputs = from_public_json(&inputs).expect("invalid public inputs");
+
+            let result = verify_proof(&prepare_verifying_key(&vk), &proof, &inputs)
+                .expect("verify snark proof failed");
+            if result {
+                println!("verify proof passed");
+            } else {
+                println!("verify proof failed");
+            }
+
+            let duration = std::time::SystemTime::now().duration_since(start_time).unwrap();
+            println!("verify time: {:?}", duration);
+        }
+        Opt::Profile {
+            circuit,
+            height,
+            depth,
+            auditors,
+            report_path,
+        } => {
+            // auditor keys are public inputs, any key gives the same counts
+            let pubkeys = (auditors > 0).then(|| vec![EdwardsAffine::prime_subgroup_generator(); auditors]);
+            let report = match circuit.as_str() {
+                "deposit" => DepositInstant::constraint_report(&gen_deposit_const_params(height, pubkeys)),
+                "batch-deposit" => BatchDepositInstant::constraint_report(&gen_batch_deposit_const_params(height, depth, pubkeys)),
+                "withdraw" => WithdrawInstant::constraint_report(&gen_withdraw_const_params(height, pubkeys, false, false)),
+                "transfer" => TransferInstant::constraint_report(&gen_transfer_const_params(height, pubkeys)),
+                _ => unreachable!(),
+            }.expect("generate constraint report failed");
+
+            let report = ConstraintReportData::new(circuit, report);
+            if let Some(report_path) = report_path {
+                report.to_file(&report_path).expect("write report to file error");
+            } else {
+                println!("{}", serde_json::to_string_pretty(&report).unwrap());
+            }
+        }
     }
 }
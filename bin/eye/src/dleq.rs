@@ -0,0 +1,95 @@
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{to_bytes, PrimeField};
+use solana_sdk::hash::hashv;
+
+#[cfg(feature = "bn254")]
+use ark_ed_on_bn254::{EdwardsAffine, Fr};
+#[cfg(feature = "bls12-381")]
+use ark_ed_on_bls12_381::{EdwardsAffine, Fr};
+
+/// Chaum–Pedersen proof that log_G(pubkey) = log_C0(state),
+/// i.e. the state is decrypted with the private key of the auditor share.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DleqProof {
+    pub challenge: Fr,
+    pub response: Fr,
+}
+
+fn hash_to_scalar(data: &[&[u8]]) -> Fr {
+    Fr::from_le_bytes_mod_order(&hashv(data).to_bytes())
+}
+
+fn challenge(
+    pubkey: &EdwardsAffine,
+    c0: &EdwardsAffine,
+    state: &EdwardsAffine,
+    a0: &EdwardsAffine,
+    a1: &EdwardsAffine,
+) -> Fr {
+    let data = to_bytes![EdwardsAffine::prime_subgroup_generator(), pubkey, c0, state, a0, a1].unwrap();
+    hash_to_scalar(&[b"soda-maze-dleq", &data])
+}
+
+impl DleqProof {
+    pub fn prove(privkey: Fr, c0: EdwardsAffine) -> (EdwardsAffine, Self) {
+        let generator = EdwardsAffine::prime_subgroup_generator();
+        let pubkey = generator.mul(privkey).into_affine();
+        let state = c0.mul(privkey).into_affine();
+
+        // nonce is derived from the private key and C0, so no randomness is needed
+        let nonce = hash_to_scalar(&[b"soda-maze-dleq-nonce", &to_bytes![privkey, c0].unwrap()]);
+        let a0 = generator.mul(nonce).into_affine();
+        let a1 = c0.mul(nonce).into_affine();
+
+        let challenge = challenge(&pubkey, &c0, &state, &a0, &a1);
+        let response = nonce + challenge * privkey;
+
+        (state, Self { challenge, response })
+    }
+
+    pub fn verify(&self, pubkey: EdwardsAffine, c0: EdwardsAffine, state: EdwardsAffine) -> bool {
+        let generator = EdwardsAffine::prime_subgroup_generator();
+        // z * G - e * P = k * G, z * C0 - e * S = k * C0
+        let a0 = generator.mul(self.response) - pubkey.mul(self.challenge);
+        let a1 = c0.mul(self.response) - state.mul(self.challenge);
+
+        challenge(&pubkey, &c0, &state, &a0.into_affine(), &a1.into_affine()) == self.challenge
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ec::{AffineCurve, ProjectiveCurve};
+    use ark_ff::UniformRand;
+    use rand_core::OsRng;
+
+    #[cfg(feature = "bn254")]
+    use ark_ed_on_bn254::{EdwardsAffine, Fr};
+    #[cfg(feature = "bls12-381")]
+    use ark_ed_on_bls12_381::{EdwardsAffine, Fr};
+
+    use super::DleqProof;
+
+    #[test]
+    fn test_dleq() {
+        let rng = &mut OsRng;
+        let generator = EdwardsAffine::prime_subgroup_generator();
+        let privkey = Fr::rand(rng);
+        let pubkey = generator.mul(privkey).into_affine();
+        let c0 = generator.mul(Fr::rand(rng)).into_affine();
+
+        let (state, proof) = DleqProof::prove(privkey, c0);
+        assert_eq!(state, c0.mul(privkey).into_affine());
+        assert!(proof.verify(pubkey, c0, state));
+
+        // a state decrypted with another key is rejected
+        let fake_state = c0.mul(Fr::rand(rng)).into_affine();
+        assert!(!proof.verify(pubkey, c0, fake_state));
+        // a proof for another share is rejected
+        let other_pubkey = generator.mul(Fr::rand(rng)).into_affine();
+        assert!(!proof.verify(other_pubkey, c0, state));
+        // a proof for another commitment is rejected
+        let other_c0 = generator.mul(Fr::rand(rng)).into_affine();
+        assert!(!proof.verify(pubkey, other_c0, state));
+    }
+}
@@ -1,37 +1,119 @@
-use std::str::FromStr;
+mod dleq;
+
+use std::{path::PathBuf, str::FromStr};
 use ark_ec::{AffineCurve, ProjectiveCurve};
-use clap::Parser;
+use ark_ff::{One, PrimeField};
+use clap::{CommandFactory, ErrorKind, Parser};
 use num_traits::Zero;
+use serde::{Serialize, Deserialize};
 use solana_client::rpc_client::RpcClient;
 use solana_sdk::{commitment_config::CommitmentConfig, signature::Signature, pubkey::Pubkey};
 use solana_transaction_status::{UiTransactionEncoding, EncodedTransaction, UiMessage, UiInstruction, UiParsedInstruction};
-use soda_maze_program::{core::{commitment::Commitment, nullifier::{get_nullifier_pda, Nullifier}}, Packer, ID};
-use soda_maze_utils::{parser::{from_hex_string, to_hex_string}, convert::{from_maze_edwards_affine, to_maze_edwards_affine}};
+use soda_maze_program::{core::{commitment::Commitment, nullifier::{get_nullifier_pda, Nullifier}}, params::BATCH_DEPOSIT_SIZE, Packer, ID};
+use soda_maze_utils::{parser::{from_hex_string, to_hex_string, JsonParser}, convert::{from_maze_edwards_affine, to_maze_edwards_affine}};
+use soda_maze_utils::dkg::{KeyShare, lagrange_coefficients};
 
 #[cfg(feature = "bn254")]
 use ark_ed_on_bn254::{EdwardsAffine, EdwardsProjective, Fr};
 #[cfg(feature = "bls12-381")]
-use ark_ed_on_bls12_381::{EdwardsAffine, Fr};
+use ark_ed_on_bls12_381::{EdwardsAffine, EdwardsProjective, Fr};
+
+use dleq::DleqProof;
 
-#[inline]
-fn decrypt(c0: EdwardsAffine, privkey: Fr) -> EdwardsAffine {
-    // pk * C0
-    c0.mul(privkey).into_affine()
+/// Partial decryption of an auditor share, verifiable by anyone with the share public key.
+/// `index` is set for threshold key shares from DKG and left out for additive shares.
+#[derive(Serialize, Deserialize)]
+struct DecryptEvidence {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    index: Option<u64>,
+    pubkey: String,
+    commitment_0: String,
+    state: String,
+    challenge: String,
+    response: String,
 }
 
-fn reveal_commitment<I: Iterator<Item = EdwardsAffine>>(c1: EdwardsAffine, states: I) -> EdwardsAffine {
-    // ∑state_i = ∑pk_i * C0 = r * P
-    let sum = states
-        .into_iter()
-        .fold(EdwardsProjective::zero(), |sum, state| {
-            sum.add_mixed(&state)
-        });
+impl JsonParser for DecryptEvidence {}
+
+struct DecryptShare {
+    index: Option<u64>,
+    pubkey: EdwardsAffine,
+    c0: EdwardsAffine,
+    state: EdwardsAffine,
+    proof: DleqProof,
+}
+
+impl DecryptEvidence {
+    fn new(index: Option<u64>, privkey: Fr, c0: EdwardsAffine) -> Self {
+        // pk * C0, with a proof that it shares the discrete log of the public key
+        let (state, proof) = DleqProof::prove(privkey, c0);
+        let pubkey = EdwardsAffine::prime_subgroup_generator().mul(privkey).into_affine();
+
+        Self {
+            index,
+            pubkey: to_hex_string(&pubkey).unwrap(),
+            commitment_0: to_hex_string(&c0).unwrap(),
+            state: to_hex_string(&state).unwrap(),
+            challenge: to_hex_string(&proof.challenge).unwrap(),
+            response: to_hex_string(&proof.response).unwrap(),
+        }
+    }
+
+    fn parse(self) -> DecryptShare {
+        DecryptShare {
+            index: self.index,
+            pubkey: from_hex_string(self.pubkey).expect("invalid evidence pubkey"),
+            c0: from_hex_string(self.commitment_0).expect("invalid evidence commitment 0"),
+            state: from_hex_string(self.state).expect("invalid evidence state"),
+            proof: DleqProof {
+                challenge: from_hex_string(self.challenge).expect("invalid evidence challenge"),
+                response: from_hex_string(self.response).expect("invalid evidence response"),
+            },
+        }
+    }
+}
+
+fn combine_shares(indexes: Option<&[u64]>, points: &[EdwardsAffine]) -> EdwardsProjective {
+    // additive shares are summed up, threshold shares are interpolated at 0
+    let coeffs = indexes
+        .map(lagrange_coefficients)
+        .unwrap_or_else(|| vec![Fr::one(); points.len()]);
+    points
+        .iter()
+        .zip(coeffs)
+        .fold(EdwardsProjective::zero(), |sum, (point, coeff)| {
+            sum + point.mul(coeff.into_repr())
+        })
+}
+
+fn reveal_commitment(c1: EdwardsAffine, indexes: Option<&[u64]>, states: &[EdwardsAffine]) -> EdwardsAffine {
+    // ∑λ_i * state_i = ∑λ_i * pk_i * C0 = r * P
+    let sum = combine_shares(indexes, states);
     // C1 - r * P = nullifier * G + r * P - r * P = nullifier * G
     (c1.into_projective() - sum).into_affine()
 }
 
+/// Commitment accounts of a finalize instruction, at the positions of the instruction builders of the program.
+fn get_commitment_keys(data: &[u8], accounts: &[String]) -> Result<Vec<Pubkey>, String> {
+    let keys = match data.first() {
+        // deposit
+        Some(3) => &accounts[6..7],
+        // withdraw
+        Some(7) => &accounts[9..10],
+        // transfer, after the two nullifiers
+        Some(11) => &accounts[10..12],
+        // batch deposit, one for each leaf of the batch
+        Some(15) => &accounts[9..9 + BATCH_DEPOSIT_SIZE],
+        _ => return Err("instruction should be finalize deposit, withdraw, transfer or batch deposit".into()),
+    };
+
+    keys.iter()
+        .map(|key| Pubkey::from_str(key).map_err(|e| e.to_string()))
+        .collect()
+}
+
 #[derive(Parser, Debug)]
-#[clap(name = "Soda Maze Eye", version = "0.0.1", about = "Reveal illegal receiver address of deposit/withdraw finalize transaction")]
+#[clap(name = "Soda Maze Eye", version = "0.0.1", about = "Reveal illegal receiver address of finalize transactions")]
 enum Opt {
     GetCommitment {
         #[clap(short = 'u', long, value_parser, default_value = "https://api.devnet.solana.com")]
@@ -40,16 +122,22 @@ enum Opt {
         signature: String,
     },
     Decrypt {
-        #[clap(short = 'p', long = "private-key")]
-        privkey: String,
+        #[clap(short = 'p', long = "private-key", required_unless_present = "key-share")]
+        privkey: Option<String>,
+        #[clap(short = 'k', long = "key-share", parse(from_os_str), conflicts_with = "privkey")]
+        key_share: Option<PathBuf>,
         #[clap(short = 'c', long = "commitment-0", value_parser)]
         c0: String,
+        #[clap(short = 'e', long = "evidence-path", parse(from_os_str), default_value = "evidence.json")]
+        evidence_path: PathBuf,
     },
     Reveal {
         #[clap(short = 'u', long, value_parser, default_value = "https://api.devnet.solana.com")]
         url: String,
-        #[clap(short = 's', long, value_parser)]
-        state: Vec<String>,
+        #[clap(short = 'e', long = "evidence", parse(from_os_str))]
+        evidence: Vec<PathBuf>,
+        #[clap(short = 'v', long = "viewing-pubkey", value_parser)]
+        viewing_pubkey: String,
         #[clap(short = 'c', long = "commitment-1", value_parser)]
         c1: String,
     }
@@ -71,7 +159,7 @@ fn main() {
 
             let tx = client.get_transaction(&sig, UiTransactionEncoding::JsonParsed)
                 .expect("get transaction error");
-            let commitment_key = match tx.transaction.transaction {
+            let commitment_keys = match tx.transaction.transaction {
                 EncodedTransaction::Json(tx_data) => {
                     match tx_data.message {
                         UiMessage::Parsed(ref message) => {
@@ -82,13 +170,8 @@ fn main() {
                                     match instruction {
                                         UiParsedInstruction::PartiallyDecoded(instruction) => {
                                             let data = bs58::decode(&instruction.data).into_vec().unwrap();
-                                            match data[0] {
-                                                // deposit
-                                                3 => Pubkey::from_str(&instruction.accounts[6]).unwrap(),
-                                                // withdraw
-                                                7 => Pubkey::from_str(&instruction.accounts[9]).unwrap(),
-                                                _ => unreachable!("instruction should be deposit or withdraw"),
-                                            }
+                                            get_commitment_keys(&data, &instruction.accounts)
+                                                .unwrap_or_else(|e| Opt::command().error(ErrorKind::InvalidValue, e).exit())
                                         }
                                         _ => unreachable!("parsed instruction should be partially decoded"),
                                     }
@@ -102,39 +185,87 @@ fn main() {
                 _ => unreachable!("transaction type should be json"),
             };
 
-            let commitment_data = client.get_account_data(&commitment_key).expect("get commitment data failed");
-            let commitment = Commitment::unpack(&commitment_data).expect("unpack commitment error");
-            let commitment_0 = from_maze_edwards_affine(commitment.inner.0).expect("invalid commitment inner 0");
-            let commitment_1 = from_maze_edwards_affine(commitment.inner.1).expect("invalid commitment inner 1");
+            // one commitment for each new note of the instruction
+            commitment_keys.into_iter().enumerate().for_each(|(n, commitment_key)| {
+                let commitment_data = client.get_account_data(&commitment_key).expect("get commitment data failed");
+                let commitment = Commitment::unpack(&commitment_data).expect("unpack commitment error");
+                println!("note {}: {}", n, commitment_key);
+                // one ciphertext for each auditor key, in the order of the keys
+                commitment.inner.into_iter().enumerate().for_each(|(i, inner)| {
+                    let commitment_0 = from_maze_edwards_affine(inner.0).expect("invalid commitment inner 0");
+                    let commitment_1 = from_maze_edwards_affine(inner.1).expect("invalid commitment inner 1");
 
-            println!("commitment 0: {}", to_hex_string(&commitment_0).unwrap());
-            println!("commitment 1: {}", to_hex_string(&commitment_1).unwrap());
+                    println!("auditor {}", i);
+                    println!("commitment 0: {}", to_hex_string(&commitment_0).unwrap());
+                    println!("commitment 1: {}", to_hex_string(&commitment_1).unwrap());
+                });
+            });
         }
         Opt::Decrypt {
             privkey,
+            key_share,
             c0,
+            evidence_path,
         } => {
+            let (index, privkey) = if let Some(key_share) = key_share {
+                let key_share = KeyShare::from_file(&key_share).expect("read key share from file error");
+                (Some(key_share.index), key_share.private_share)
+            } else {
+                (None, privkey.unwrap())
+            };
             let privkey = from_hex_string::<Fr>(privkey).expect("invalid private key");
             let c0 = from_hex_string(c0).expect("invalid commitment 0");
-            let state = decrypt(c0, privkey);
+            let evidence = DecryptEvidence::new(index, privkey, c0);
 
-            println!("output state is {}", to_hex_string(&state).unwrap());
+            println!("output state is {}", evidence.state);
+            evidence.to_file(&evidence_path).expect("write evidence to file error");
         }
         Opt::Reveal {
             url,
-            state,
+            evidence,
+            viewing_pubkey,
             c1,
         } => {
-            let states = state.into_iter().enumerate().map(|(i, s)| {
-                from_hex_string(s).expect(format!("invalid state at {}", i).as_str())
+            assert!(!evidence.is_empty(), "no evidence is supplied");
+            let shares = evidence.iter().enumerate().map(|(i, path)| {
+                DecryptEvidence::from_file(path)
+                    .unwrap_or_else(|_| panic!("read evidence {} from file error", i))
+                    .parse()
+            }).collect::<Vec<_>>();
+
+            // every share must decrypt the same commitment with the key it claims
+            let c0 = shares[0].c0;
+            shares.iter().enumerate().for_each(|(i, share)| {
+                assert_eq!(share.c0, c0, "evidence {} decrypts another commitment", i);
+                assert!(share.proof.verify(share.pubkey, c0, share.state), "invalid decryption proof in evidence {}", i);
             });
+            let indexes = shares.iter().map(|share| share.index).collect::<Option<Vec<_>>>();
+            if let Some(indexes) = &indexes {
+                let mut sorted = indexes.clone();
+                sorted.sort_unstable();
+                sorted.dedup();
+                assert_eq!(sorted.len(), indexes.len(), "duplicated evidence index");
+            } else {
+                assert!(shares.iter().all(|share| share.index.is_none()), "threshold and additive evidences are mixed");
+            }
+
+            // and the shares must combine to the viewing key of the vault
+            let viewing_pubkey = from_hex_string::<EdwardsAffine>(viewing_pubkey).expect("invalid viewing pubkey");
+            let pubkeys = shares.iter().map(|share| share.pubkey).collect::<Vec<_>>();
+            assert_eq!(
+                combine_shares(indexes.as_deref(), &pubkeys).into_affine(),
+                viewing_pubkey,
+                "evidence shares do not match the viewing pubkey, or not enough shares are supplied",
+            );
+
+            let states = shares.iter().map(|share| share.state).collect::<Vec<_>>();
             let c1 = from_hex_string(c1).expect("invalid commitment 1");
             let client = RpcClient::new_with_commitment(
                 &url,
                 CommitmentConfig::finalized(),
             );
 
-            let nullifier_point = reveal_commitment(c1, states);
+            let nullifier_point = reveal_commitment(c1, indexes.as_deref(), &states);
             let nullifier_point = to_maze_edwards_affine(nullifier_point);
             let (nullifier, _) = get_nullifier_pda(&nullifier_point, &ID);
             if let Ok(data) = client.get_account_data(&nullifier) {
@@ -149,3 +280,46 @@ fn main() {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use ark_ec::{AffineCurve, ProjectiveCurve};
+    use ark_ff::UniformRand;
+    use rand_core::OsRng;
+    use soda_maze_utils::dkg::{gen_polynomial, eval_polynomial};
+
+    #[cfg(feature = "bn254")]
+    use ark_ed_on_bn254::{EdwardsAffine, Fr};
+    #[cfg(feature = "bls12-381")]
+    use ark_ed_on_bls12_381::{EdwardsAffine, Fr};
+
+    use super::reveal_commitment;
+
+    #[test]
+    fn test_threshold_reveal() {
+        let rng = &mut OsRng;
+        let generator = EdwardsAffine::prime_subgroup_generator();
+        // 2-of-3 sharing of the viewing key
+        let coeffs = gen_polynomial(rng, 2);
+        let pubkey = generator.mul(coeffs[0]);
+
+        let nullifier_point = generator.mul(Fr::rand(rng));
+        let nonce = Fr::rand(rng);
+        let c0 = generator.mul(nonce).into_affine();
+        let c1 = (nullifier_point + pubkey.into_affine().mul(nonce)).into_affine();
+
+        [[1u64, 2], [1, 3], [3, 2]].iter().for_each(|indexes| {
+            let states = indexes.iter().map(|&i| {
+                c0.mul(eval_polynomial(&coeffs, i)).into_affine()
+            }).collect::<Vec<_>>();
+            assert_eq!(reveal_commitment(c1, Some(indexes), &states), nullifier_point.into_affine());
+        });
+
+        // a single share reveals nothing
+        let states = [c0.mul(eval_polynomial(&coeffs, 1)).into_affine()];
+        assert_ne!(reveal_commitment(c1, Some(&[1]), &states), nullifier_point.into_affine());
+        // additive combination of threshold shares is wrong
+        let states = [1u64, 2].map(|i| c0.mul(eval_polynomial(&coeffs, i)).into_affine());
+        assert_ne!(reveal_commitment(c1, None, &states), nullifier_point.into_affine());
+    }
+}
@@ -1,22 +1,20 @@
 use std::{fs::OpenOptions, path::PathBuf, io::{Write, Result}};
 use ark_ec::{AffineCurve, ProjectiveCurve};
-use ark_ff::{PrimeField, UniformRand};
+use ark_ff::{BigInteger, PrimeField, UniformRand, Zero};
 use ark_crypto_primitives::snark::*;
 use ark_groth16::{Groth16, PreparedVerifyingKey};
 use clap::Parser;
-use soda_maze_lib::proof::{ProofScheme, scheme::{DepositProof, WithdrawProof}};
+use soda_maze_lib::proof::{ProofScheme, scheme::{DepositProof, BatchDepositProof, WithdrawProof, TransferProof}};
 use soda_maze_lib::vanilla::hasher::FieldHasher;
-use soda_maze_utils::convert::{MazeProvingKey, MazeVerifyingKey};
-use soda_maze_utils::parser::{to_hex_string, from_hex_string, borsh_se_to_file};
-use soda_maze_utils::params::{gen_deposit_const_params, gen_withdraw_const_params};
+use soda_maze_lib::params::poseidon::gen_poseidon_parameters;
+use soda_maze_utils::convert::MazeVerifyingKey;
+use soda_maze_utils::pk::write_pk_to_file;
+use soda_maze_utils::artifact::{CircuitInfo, CircuitKind};
+use soda_maze_utils::parser::{to_hex_string, borsh_se_to_file, JsonParser};
+use soda_maze_utils::params::{gen_deposit_const_params, gen_batch_deposit_const_params, gen_withdraw_const_params, gen_transfer_const_params};
+use soda_maze_utils::params::{Hasher, HasherGadget, get_bn254_for_merkle};
 use soda_maze_utils::rand::get_xorshift_rng;
-
-#[cfg(feature = "poseidon")]
-use soda_maze_lib::circuits::poseidon::PoseidonHasherGadget;
-#[cfg(feature = "poseidon")]
-use soda_maze_lib::vanilla::hasher::poseidon::PoseidonHasher;
-#[cfg(feature = "poseidon")]
-use soda_maze_lib::params::poseidon::get_poseidon_bn254_for_merkle;
+use soda_maze_utils::dkg::*;
 
 #[cfg(feature = "bn254")]
 use ark_bn254::{Bn254, Fr};
@@ -27,15 +25,25 @@ use ark_bls12_381::{Bls12_381, Fr};
 #[cfg(feature = "bls12-381")]
 use ark_ed_on_bls12_381::{EdwardsParameters, EdwardsAffine, EdwardsProjective, Fr as Frr};
 
-#[cfg(all(feature = "bn254", feature = "poseidon"))]
-type DepositInstant = DepositProof::<EdwardsParameters, PoseidonHasher<Fr>, PoseidonHasherGadget<Fr>, Groth16<Bn254>>;
-#[cfg(all(feature = "bls12-381", feature = "poseidon"))]
-type DepositInstant = DepositProof::<EdwardsParameters, PoseidonHasher<Fr>, PoseidonHasherGadget<Fr>, Groth16<Bls12_381>>;
+#[cfg(feature = "bn254")]
+type DepositInstant = DepositProof::<EdwardsParameters, Hasher<Fr>, HasherGadget<Fr>, Groth16<Bn254>>;
+#[cfg(feature = "bls12-381")]
+type DepositInstant = DepositProof::<EdwardsParameters, Hasher<Fr>, HasherGadget<Fr>, Groth16<Bls12_381>>;
 
-#[cfg(all(feature = "bn254", feature = "poseidon"))]
-type WithdrawInstant = WithdrawProof::<EdwardsParameters, PoseidonHasher<Fr>, PoseidonHasherGadget<Fr>, Groth16<Bn254>>;
-#[cfg(all(feature = "bls12-381", feature = "poseidon"))]
-type WithdrawInstant = WithdrawProof::<EdwardsParameters, PoseidonHasher<Fr>, PoseidonHasherGadget<Fr>, Groth16<Bls12_381>>;
+#[cfg(feature = "bn254")]
+type BatchDepositInstant = BatchDepositProof::<EdwardsParameters, Hasher<Fr>, HasherGadget<Fr>, Groth16<Bn254>>;
+#[cfg(feature = "bls12-381")]
+type BatchDepositInstant = BatchDepositProof::<EdwardsParameters, Hasher<Fr>, HasherGadget<Fr>, Groth16<Bls12_381>>;
+
+#[cfg(feature = "bn254")]
+type WithdrawInstant = WithdrawProof::<EdwardsParameters, Hasher<Fr>, HasherGadget<Fr>, Groth16<Bn254>>;
+#[cfg(feature = "bls12-381")]
+type WithdrawInstant = WithdrawProof::<EdwardsParameters, Hasher<Fr>, HasherGadget<Fr>, Groth16<Bls12_381>>;
+
+#[cfg(feature = "bn254")]
+type TransferInstant = TransferProof::<EdwardsParameters, Hasher<Fr>, HasherGadget<Fr>, Groth16<Bn254>>;
+#[cfg(feature = "bls12-381")]
+type TransferInstant = TransferProof::<EdwardsParameters, Hasher<Fr>, HasherGadget<Fr>, Groth16<Bls12_381>>;
 
 #[cfg(feature = "bn254")]
 fn write_pvk_to_rust_file(path: &PathBuf, pvk: &PreparedVerifyingKey<Bn254>) -> Result<()> {
@@ -141,6 +149,106 @@ fn write_pvk_to_rust_file(path: &PathBuf, pvk: &PreparedVerifyingKey<Bn254>) ->
     file.flush()
 }
 
+fn write_poseidon_params_to_rust_file(
+    path: &PathBuf,
+    name: &str,
+    width: u8,
+    alpha: u64,
+    security_level: usize,
+) -> Result<()> {
+    let params = gen_poseidon_parameters::<Fr>(width, alpha, security_level);
+    let to_hex = |x: &Fr| format!("\"0x{}\"", hex::encode(x.into_repr().to_bytes_be()));
+
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(path)?;
+
+    writeln!(&mut file, "use ark_ff::PrimeField;")?;
+    writeln!(&mut file, "use arkworks_utils::poseidon::{{sbox::PoseidonSbox, PoseidonParameters}};")?;
+    writeln!(&mut file, "use arkworks_utils::utils::{{parse_matrix, parse_vec}};\n")?;
+
+    writeln!(&mut file, "// generated by `soda-maze-gen-params gen-poseidon-params` with security level {}", security_level)?;
+    writeln!(&mut file, "const FULL_ROUNDS: u8 = {};", params.full_rounds)?;
+    writeln!(&mut file, "const PARTIAL_ROUNDS: u8 = {};", params.partial_rounds)?;
+    writeln!(&mut file, "const WIDTH: u8 = {};", params.width)?;
+    writeln!(&mut file, "const SBOX: PoseidonSbox = PoseidonSbox({});\n", alpha)?;
+
+    writeln!(&mut file, "const ROUND_CONSTS: [&str; {}] = [", params.round_keys.len())?;
+    for rk in params.round_keys.iter() {
+        writeln!(&mut file, "\t{},", to_hex(rk))?;
+    }
+    writeln!(&mut file, "];\n")?;
+
+    writeln!(&mut file, "const MDS_ENTRIES: [[&str; {}]; {}] = [", width, width)?;
+    for row in params.mds_matrix.iter() {
+        writeln!(&mut file, "\t[")?;
+        for entry in row.iter() {
+            writeln!(&mut file, "\t\t{},", to_hex(entry))?;
+        }
+        writeln!(&mut file, "\t],")?;
+    }
+    writeln!(&mut file, "];\n")?;
+
+    writeln!(&mut file, "pub fn get_poseidon_bn254_for_{}<F: PrimeField>() -> PoseidonParameters<F> {{", name)?;
+    writeln!(&mut file, "\tlet rounds = parse_vec(ROUND_CONSTS.to_vec());")?;
+    writeln!(&mut file, "\tlet mds = parse_matrix(MDS_ENTRIES.iter().map(|x| x.to_vec()).collect::<Vec<_>>());")?;
+    writeln!(&mut file, "\tPoseidonParameters::<F>::new(rounds, mds, FULL_ROUNDS, PARTIAL_ROUNDS, WIDTH, SBOX)")?;
+    writeln!(&mut file, "}}")?;
+
+    file.flush()
+}
+
+/// Write the merkle hasher of the contract, which inserts leaves by hashing the nodes on chain.
+#[cfg(all(feature = "bn254", feature = "poseidon"))]
+fn write_merkle_hasher_to_rust_file(path: &PathBuf, height: usize) -> Result<()> {
+    let ref params = get_bn254_for_merkle::<Fr>();
+    // the contract implements the power S-box only
+    assert!(params.sbox.0 > 0, "unsupported S-box");
+
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(path)?;
+
+    writeln!(&mut file, "use crate::{{params::bn::Fr, bn::BigInteger256 as BigInteger}};\n")?;
+
+    writeln!(&mut file, "// generated by `soda-maze-gen-params gen-merkle-hasher` with height {}", height)?;
+    writeln!(&mut file, "pub const WIDTH: usize = {};", params.width)?;
+    writeln!(&mut file, "pub const FULL_ROUNDS: usize = {};", params.full_rounds)?;
+    writeln!(&mut file, "pub const PARTIAL_ROUNDS: usize = {};", params.partial_rounds)?;
+    writeln!(&mut file, "pub const ALPHA: u64 = {};\n", params.sbox.0)?;
+
+    writeln!(&mut file, "pub const ROUND_KEYS: &[Fr] = &[")?;
+    for rk in params.round_keys.iter() {
+        writeln!(&mut file, "    Fr::new(BigInteger::new({:?})),", rk.0.0)?;
+    }
+    writeln!(&mut file, "];\n")?;
+
+    writeln!(&mut file, "pub const MDS_MATRIX: &[[Fr; WIDTH]] = &[")?;
+    for row in params.mds_matrix.iter() {
+        writeln!(&mut file, "    [")?;
+        for entry in row.iter() {
+            writeln!(&mut file, "        Fr::new(BigInteger::new({:?})),", entry.0.0)?;
+        }
+        writeln!(&mut file, "    ],")?;
+    }
+    writeln!(&mut file, "];\n")?;
+
+    writeln!(&mut file, "/// hashes of empty nodes from the leaves up to the layer below the root")?;
+    writeln!(&mut file, "pub const DEFAULT_NODE_HASHES: &[BigInteger] = &[")?;
+    let mut hash: Fr = Hasher::empty_hash();
+    for _ in 0..height {
+        writeln!(&mut file, "    BigInteger::new({:?}),", hash.into_repr().0)?;
+        hash = Hasher::hash_two(params, hash, hash).unwrap();
+    }
+    writeln!(&mut file, "];")?;
+
+    file.flush()
+}
+
 #[derive(Parser, Debug)]
 #[clap(name = "Soda Maze Gen Parameters", version = "0.0.1", about = "Soda Maze Gen Parameters Benchmark.", long_about = "")]
 enum Opt {
@@ -148,38 +256,155 @@ enum Opt {
         #[clap(long, short = 's', value_parser)]
         seed: Option<String>,
     },
+    /// DKG round 1: publish the dealing and write the share of every auditor
+    DkgDeal {
+        #[clap(long, short = 's', value_parser)]
+        seed: Option<String>,
+        #[clap(long, value_parser)]
+        index: u64,
+        #[clap(long, value_parser)]
+        threshold: u64,
+        #[clap(long, value_parser)]
+        parties: u64,
+        #[clap(long, parse(from_os_str), default_value = "dkg")]
+        dir: PathBuf,
+    },
+    /// DKG round 2: check the received shares and complain against bad dealers
+    DkgComplain {
+        #[clap(long, value_parser)]
+        index: u64,
+        #[clap(long, value_parser)]
+        parties: u64,
+        #[clap(long, parse(from_os_str), default_value = "dkg")]
+        dir: PathBuf,
+    },
+    /// DKG round 3: reveal the shares complained about
+    DkgRespond {
+        #[clap(long, value_parser)]
+        index: u64,
+        #[clap(long, value_parser)]
+        parties: u64,
+        #[clap(long, parse(from_os_str), default_value = "dkg")]
+        dir: PathBuf,
+    },
+    /// Sum up the shares of qualified dealers into the key share of the auditor
+    DkgFinalize {
+        #[clap(long, value_parser)]
+        index: u64,
+        #[clap(long, value_parser)]
+        threshold: u64,
+        #[clap(long, value_parser)]
+        parties: u64,
+        #[clap(long, parse(from_os_str), default_value = "dkg")]
+        dir: PathBuf,
+        #[clap(long = "key-path", parse(from_os_str), default_value = "key-share.json")]
+        key_path: PathBuf,
+    },
     GenMerkleRoot {
         #[clap(long, value_parser, default_value = "21")]
         height: usize,
     },
+    /// Write the merkle hasher and the empty nodes of the contract
+    #[cfg(all(feature = "bn254", feature = "poseidon"))]
+    GenMerkleHasher {
+        #[clap(long, value_parser, default_value = "21")]
+        height: usize,
+        #[clap(long, parse(from_os_str), default_value = "merkle.rs")]
+        path: PathBuf,
+    },
+    GenPoseidonParams {
+        #[clap(long, value_parser)]
+        width: u8,
+        #[clap(long, value_parser, default_value = "5")]
+        alpha: u64,
+        #[clap(long = "security-level", value_parser, default_value = "128")]
+        security_level: usize,
+        /// suffix of the generated function `get_poseidon_bn254_for_{name}`
+        #[clap(long, value_parser)]
+        name: String,
+        #[clap(long, parse(from_os_str), default_value = "poseidon_params.rs")]
+        path: PathBuf,
+    },
     SetupDeposit {
         #[clap(long, short = 's', value_parser)]
         seed: Option<String>,
         #[clap(long, value_parser, default_value = "21")]
         height: usize,
-        #[clap(long = "viewing-pubkey", value_parser)]
-        pubkey: Option<String>,
+        /// number of auditor keys, the keys are public inputs and set by the vault
+        #[clap(long, value_parser, default_value = "0")]
+        auditors: usize,
         #[clap(long = "pk-path", parse(from_os_str), default_value = "pk-deposit")]
         pk_path: PathBuf,
+        /// compress the points of the proving key, smaller but slower to load
+        #[clap(long)]
+        compress: bool,
         #[clap(long = "vk-path", parse(from_os_str), default_value = "vk-deposit")]
         vk_path: PathBuf,
         #[clap(long = "pvk-path", parse(from_os_str), default_value = "pvk_deposit.rs")]
         pvk_path: PathBuf,
     },
+    SetupBatchDeposit {
+        #[clap(long, short = 's', value_parser)]
+        seed: Option<String>,
+        #[clap(long, value_parser, default_value = "21")]
+        height: usize,
+        #[clap(long, value_parser, default_value = "3")]
+        depth: usize,
+        /// number of auditor keys, the keys are public inputs and set by the vault
+        #[clap(long, value_parser, default_value = "0")]
+        auditors: usize,
+        #[clap(long = "pk-path", parse(from_os_str), default_value = "pk-batch-deposit")]
+        pk_path: PathBuf,
+        /// compress the points of the proving key, smaller but slower to load
+        #[clap(long)]
+        compress: bool,
+        #[clap(long = "vk-path", parse(from_os_str), default_value = "vk-batch-deposit")]
+        vk_path: PathBuf,
+        #[clap(long = "pvk-path", parse(from_os_str), default_value = "pvk_batch_deposit.rs")]
+        pvk_path: PathBuf,
+    },
     SetupWithdraw {
         #[clap(long, short = 's', value_parser)]
         seed: Option<String>,
         #[clap(long, value_parser, default_value = "21")]
         height: usize,
-        #[clap(long = "viewing-pubkey", value_parser)]
-        pubkey: Option<String>,
+        /// number of auditor keys, the keys are public inputs and set by the vault
+        #[clap(long, value_parser, default_value = "0")]
+        auditors: usize,
+        /// prove the src leaf index is not in the exclusion set of the vault curator
+        #[clap(long)]
+        exclusion: bool,
+        /// prove the owner key is in an allowlist accepted by the vault
+        #[clap(long)]
+        allowlist: bool,
         #[clap(long = "pk-path", parse(from_os_str), default_value = "pk-withdraw")]
         pk_path: PathBuf,
+        /// compress the points of the proving key, smaller but slower to load
+        #[clap(long)]
+        compress: bool,
         #[clap(long = "vk-path", parse(from_os_str), default_value = "vk-withdraw")]
         vk_path: PathBuf,
         #[clap(long = "pvk-path", parse(from_os_str), default_value = "pvk_withdraw.rs")]
         pvk_path: PathBuf,
     },
+    SetupTransfer {
+        #[clap(long, short = 's', value_parser)]
+        seed: Option<String>,
+        #[clap(long, value_parser, default_value = "21")]
+        height: usize,
+        /// number of auditor keys, the keys are public inputs and set by the vault
+        #[clap(long, value_parser, default_value = "0")]
+        auditors: usize,
+        #[clap(long = "pk-path", parse(from_os_str), default_value = "pk-transfer")]
+        pk_path: PathBuf,
+        /// compress the points of the proving key, smaller but slower to load
+        #[clap(long)]
+        compress: bool,
+        #[clap(long = "vk-path", parse(from_os_str), default_value = "vk-transfer")]
+        vk_path: PathBuf,
+        #[clap(long = "pvk-path", parse(from_os_str), default_value = "pvk_transfer.rs")]
+        pvk_path: PathBuf,
+    },
 }
 
 fn main() {
@@ -196,35 +421,169 @@ fn main() {
             println!("private key: {}", to_hex_string(&privkey).unwrap());
             println!("public key: {}", to_hex_string(&pubkey).unwrap());
         },
+        Opt::DkgDeal {
+            seed,
+            index,
+            threshold,
+            parties,
+            dir,
+        } => {
+            assert!(index >= 1 && index <= parties, "index should be in 1..={}", parties);
+            assert!(threshold >= 1 && threshold <= parties, "threshold should be in 1..={}", parties);
+            let rng = &mut get_xorshift_rng(seed);
+
+            let coeffs = gen_polynomial(rng, threshold as usize);
+            let dealing = Dealing::new(index, &commit_polynomial(&coeffs));
+            dealing.to_file(&dealing_path(&dir, index)).expect("write dealing to file error");
+            (1..=parties).for_each(|receiver| {
+                let share = DealtShare::new(index, receiver, eval_polynomial(&coeffs, receiver));
+                share.to_file(&share_path(&dir, index, receiver)).expect("write share to file error");
+            });
+
+            println!("dealing of auditor {} is published, send share-{}-j.json to auditor j privately", index, index);
+        }
+        Opt::DkgComplain {
+            index,
+            parties,
+            dir,
+        } => {
+            (1..=parties).for_each(|dealer| {
+                // a missing dealing disqualifies the dealer by itself
+                let commitments = match Dealing::from_file(&dealing_path(&dir, dealer)) {
+                    Ok(dealing) => dealing.parse().expect("invalid dealing commitments"),
+                    Err(_) => return,
+                };
+                let valid = DealtShare::from_file(&share_path(&dir, dealer, index))
+                    .and_then(|share| share.parse())
+                    .map(|share| verify_share(&commitments, index, share))
+                    .unwrap_or(false);
+                if !valid {
+                    let complaint = Complaint { dealer, complainer: index };
+                    complaint.to_file(&complaint_path(&dir, dealer, index)).expect("write complaint to file error");
+                    println!("complain against dealer {}", dealer);
+                }
+            });
+        }
+        Opt::DkgRespond {
+            index,
+            parties,
+            dir,
+        } => {
+            (1..=parties).for_each(|complainer| {
+                if complaint_path(&dir, index, complainer).exists() {
+                    let share = DealtShare::from_file(&share_path(&dir, index, complainer))
+                        .expect("read share from file error");
+                    share.to_file(&response_path(&dir, index, complainer)).expect("write response to file error");
+                    println!("reveal share of auditor {}", complainer);
+                }
+            });
+        }
+        Opt::DkgFinalize {
+            index,
+            threshold,
+            parties,
+            dir,
+            key_path,
+        } => {
+            let mut private_share = Frr::zero();
+            let mut pubkey = EdwardsProjective::zero();
+            let mut public_share = EdwardsProjective::zero();
+            let mut qualified = Vec::new();
+            for dealer in 1..=parties {
+                let commitments = match Dealing::from_file(&dealing_path(&dir, dealer)) {
+                    Ok(dealing) => dealing.parse().expect("invalid dealing commitments"),
+                    Err(_) => continue,
+                };
+                if commitments.len() as u64 != threshold {
+                    continue;
+                }
+                // every complaint must be answered with a share matching the dealing
+                let revealed = (1..=parties)
+                    .filter(|&complainer| complaint_path(&dir, dealer, complainer).exists())
+                    .map(|complainer| {
+                        DealtShare::from_file(&response_path(&dir, dealer, complainer))
+                            .and_then(|share| share.parse())
+                            .ok()
+                            .filter(|share| verify_share(&commitments, complainer, *share))
+                            .map(|share| (complainer, share))
+                    })
+                    .collect::<Option<Vec<_>>>();
+                let revealed = match revealed {
+                    Some(revealed) => revealed,
+                    None => continue,
+                };
+                let share = match revealed.iter().find(|(complainer, _)| *complainer == index) {
+                    Some((_, share)) => *share,
+                    None => DealtShare::from_file(&share_path(&dir, dealer, index))
+                        .and_then(|share| share.parse())
+                        .expect("read share from file error"),
+                };
+                assert!(verify_share(&commitments, index, share), "share from dealer {} is invalid, complain first", dealer);
+
+                private_share += share;
+                pubkey.add_assign_mixed(&commitments[0]);
+                public_share.add_assign_mixed(&eval_commitments(&commitments, index));
+                qualified.push(dealer);
+            }
+            assert!(qualified.len() as u64 >= threshold, "not enough qualified dealers");
+
+            let pubkey = pubkey.into_affine();
+            let key_share = KeyShare {
+                index,
+                threshold,
+                private_share: to_hex_string(&private_share).unwrap(),
+                public_share: to_hex_string(&public_share.into_affine()).unwrap(),
+                pubkey: to_hex_string(&pubkey).unwrap(),
+            };
+            key_share.to_file(&key_path).expect("write key share to file error");
+
+            println!("qualified dealers: {:?}", qualified);
+            println!("public key: {}", to_hex_string(&pubkey).unwrap());
+        }
         Opt::GenMerkleRoot { height } => {
             let mut nodes = Vec::with_capacity(height);
-            let mut hash: Fr = PoseidonHasher::empty_hash();
-            let ref params = get_poseidon_bn254_for_merkle();
+            let mut hash: Fr = Hasher::empty_hash();
+            let ref params = get_bn254_for_merkle();
 
             (0..height)
                 .into_iter()
                 .for_each(|_| {
                     nodes.push(hash);
-                    hash = PoseidonHasher::hash_two(params, hash, hash).unwrap()
+                    hash = Hasher::hash_two(params, hash, hash).unwrap()
                 });
 
             let hash = hash.into_repr();
             println!("Merkle root: BigInteger::new({:?})", &hash.0);
         },
+        #[cfg(all(feature = "bn254", feature = "poseidon"))]
+        Opt::GenMerkleHasher { height, path } => {
+            write_merkle_hasher_to_rust_file(&path, height)
+                .expect("write merkle hasher to file error");
+        }
+        Opt::GenPoseidonParams {
+            width,
+            alpha,
+            security_level,
+            name,
+            path,
+        } => {
+            write_poseidon_params_to_rust_file(&path, &name, width, alpha, security_level)
+                .expect("write poseidon params to file error");
+        }
         Opt::SetupDeposit {
             seed,
             height,
-            pubkey,
+            auditors,
             pk_path,
+            compress,
             vk_path,
             pvk_path,
         } => {
-            let pubkey = pubkey.map(|pubkey| {
-                from_hex_string::<EdwardsAffine>(pubkey).expect("invalid viewing pubkey")
-            });
+            // any key works for setup, only the number of auditors is fixed by the circuit
+            let pubkeys = (auditors > 0).then(|| vec![EdwardsAffine::prime_subgroup_generator(); auditors]);
             let const_params = gen_deposit_const_params(
                 height,
-                pubkey,
+                pubkeys,
             );
 
             let rng = &mut get_xorshift_rng(seed);
@@ -234,26 +593,61 @@ fn main() {
             let pvk = <Groth16<Bn254> as SNARK<Fr>>::process_vk(&vk).unwrap();
             write_pvk_to_rust_file(&pvk_path, &pvk).expect("write pvk to file error");
 
-            let pk = MazeProvingKey::from(pk);
             let vk = MazeVerifyingKey::from(vk);
+            let info = CircuitInfo { circuit: CircuitKind::Deposit, height, auditors };
 
-            borsh_se_to_file(&pk, &pk_path).unwrap();
-            borsh_se_to_file(&vk, &vk_path).unwrap();
+            write_pk_to_file(&pk, &info, &pk_path, compress).expect("write pk to file error");
+            borsh_se_to_file(&vk, &info, &vk_path).expect("write vk to file error");
+        }
+        Opt::SetupBatchDeposit {
+            seed,
+            height,
+            depth,
+            auditors,
+            pk_path,
+            compress,
+            vk_path,
+            pvk_path,
+        } => {
+            // any key works for setup, only the number of auditors is fixed by the circuit
+            let pubkeys = (auditors > 0).then(|| vec![EdwardsAffine::prime_subgroup_generator(); auditors]);
+            let const_params = gen_batch_deposit_const_params(
+                height,
+                depth,
+                pubkeys,
+            );
+
+            let rng = &mut get_xorshift_rng(seed);
+            let (pk, vk) =
+                BatchDepositInstant::parameters_setup(rng, &const_params).expect("parameters setup failed");
+
+            let pvk = <Groth16<Bn254> as SNARK<Fr>>::process_vk(&vk).unwrap();
+            write_pvk_to_rust_file(&pvk_path, &pvk).expect("write pvk to file error");
+
+            let vk = MazeVerifyingKey::from(vk);
+            let info = CircuitInfo { circuit: CircuitKind::BatchDeposit, height, auditors };
+
+            write_pk_to_file(&pk, &info, &pk_path, compress).expect("write pk to file error");
+            borsh_se_to_file(&vk, &info, &vk_path).expect("write vk to file error");
         }
         Opt::SetupWithdraw {
             height,
             seed,
-            pubkey,
+            auditors,
+            exclusion,
+            allowlist,
             pk_path,
+            compress,
             vk_path,
             pvk_path,
         } => {
-            let pubkey = pubkey.map(|pubkey| {
-                from_hex_string::<EdwardsAffine>(pubkey).expect("invalid viewing pubkey")
-            });
+            // any key works for setup, only the number of auditors is fixed by the circuit
+            let pubkeys = (auditors > 0).then(|| vec![EdwardsAffine::prime_subgroup_generator(); auditors]);
             let const_params = gen_withdraw_const_params(
                 height,
-                pubkey,
+                pubkeys,
+                exclusion,
+                allowlist,
             );
             
             let rng = &mut get_xorshift_rng(seed);
@@ -263,11 +657,40 @@ fn main() {
             let pvk = <Groth16<Bn254> as SNARK<Fr>>::process_vk(&vk).unwrap();
             write_pvk_to_rust_file(&pvk_path, &pvk).expect("write pvk to file error");
 
-            let pk = MazeProvingKey::from(pk);
             let vk = MazeVerifyingKey::from(vk);
+            let info = CircuitInfo { circuit: CircuitKind::withdraw(exclusion, allowlist), height, auditors };
             
-            borsh_se_to_file(&pk, &pk_path).unwrap();
-            borsh_se_to_file(&vk, &vk_path).unwrap();
+            write_pk_to_file(&pk, &info, &pk_path, compress).expect("write pk to file error");
+            borsh_se_to_file(&vk, &info, &vk_path).expect("write vk to file error");
+        }
+        Opt::SetupTransfer {
+            height,
+            seed,
+            auditors,
+            pk_path,
+            compress,
+            vk_path,
+            pvk_path,
+        } => {
+            // any key works for setup, only the number of auditors is fixed by the circuit
+            let pubkeys = (auditors > 0).then(|| vec![EdwardsAffine::prime_subgroup_generator(); auditors]);
+            let const_params = gen_transfer_const_params(
+                height,
+                pubkeys,
+            );
+
+            let rng = &mut get_xorshift_rng(seed);
+            let (pk, vk) =
+                TransferInstant::parameters_setup(rng, &const_params).expect("parameters setup failed");
+
+            let pvk = <Groth16<Bn254> as SNARK<Fr>>::process_vk(&vk).unwrap();
+            write_pvk_to_rust_file(&pvk_path, &pvk).expect("write pvk to file error");
+
+            let vk = MazeVerifyingKey::from(vk);
+            let info = CircuitInfo { circuit: CircuitKind::Transfer, height, auditors };
+
+            write_pk_to_file(&pk, &info, &pk_path, compress).expect("write pk to file error");
+            borsh_se_to_file(&vk, &info, &vk_path).expect("write vk to file error");
         }
     }
 }
@@ -0,0 +1,219 @@
+use ark_ec::{TEModelParameters, twisted_edwards_extended::GroupAffine};
+use ark_std::rc::Rc;
+use ark_ff::PrimeField;
+use ark_r1cs_std::{fields::fp::FpVar, prelude::EqGadget, alloc::AllocVar};
+use ark_relations::r1cs::{ConstraintSystemRef, ConstraintSynthesizer, Result};
+
+use crate::error::{self, MazeLibError};
+use crate::vanilla::{hasher::FieldHasher, check_length};
+use super::merkle::gen_subtree_gadget;
+use super::{FieldHasherGadget, Commit, alloc_auditor_pubkeys, namespace};
+
+pub struct BatchDepositCircuit<P, FH, FHG>
+where
+    P: TEModelParameters,
+    FH: FieldHasher<P::BaseField>,
+    FHG: FieldHasherGadget<P::BaseField, FH>,
+    P::BaseField: PrimeField,
+{
+    leaf_params: Rc<FH::Parameters>,
+    inner_params: Rc<FH::Parameters>,
+    deposit_amounts: Vec<u64>,
+    asset_id: P::BaseField,
+    leaf_index: u64,
+    leaves: Vec<P::BaseField>,
+    subtree_nodes: Vec<P::BaseField>,
+    owners: Vec<P::BaseField>,
+    auditor_pubkeys: Vec<GroupAffine<P>>,
+    commits: Option<Vec<Commit<P, FH, FHG>>>,
+}
+
+impl<P, FH, FHG> ConstraintSynthesizer<P::BaseField> for BatchDepositCircuit<P, FH, FHG>
+where
+    P: TEModelParameters,
+    FH: FieldHasher<P::BaseField>,
+    FHG: FieldHasherGadget<P::BaseField, FH>,
+    P::BaseField: PrimeField,
+{
+    fn generate_constraints(self, cs: ConstraintSystemRef<P::BaseField>) -> Result<()> {
+        let size = self.leaves.len() as u64;
+
+        // alloc constant
+        let leaf_params = FHG::ParametersVar::new_constant(cs.clone(), self.leaf_params)?;
+        let inner_params = FHG::ParametersVar::new_constant(cs.clone(), self.inner_params)?;
+
+        // alloc input
+        // amount bit size of 64 can verify in contract, so no need constrain in circuit
+        let deposit_amounts = self.deposit_amounts
+            .into_iter()
+            .map(|amount| FpVar::new_input(cs.clone(), || Ok(P::BaseField::from(amount))))
+            .collect::<Result<Vec<_>>>()?;
+        // asset id is checked against the deposited mint in contract
+        let asset_id = FpVar::new_input(cs.clone(), || Ok(self.asset_id))?;
+        let leaf_index = FpVar::new_input(cs.clone(), || Ok(P::BaseField::from(self.leaf_index)))?;
+        let leaves_input = self.leaves
+            .into_iter()
+            .map(|leaf| FpVar::new_input(cs.clone(), || Ok(leaf)))
+            .collect::<Result<Vec<_>>>()?;
+        let subtree_nodes_input = self.subtree_nodes
+            .into_iter()
+            .map(|node| FpVar::new_input(cs.clone(), || Ok(node)))
+            .collect::<Result<Vec<_>>>()?;
+
+        // alloc witness
+        let owners = self.owners
+            .into_iter()
+            .map(|owner| FpVar::new_witness(cs.clone(), || Ok(owner)))
+            .collect::<Result<Vec<_>>>()?;
+
+        let leaf_indexes = (0..size)
+            .map(|i| &leaf_index + P::BaseField::from(i))
+            .collect::<Vec<_>>();
+
+        // hash leaves into the subtree, the leaves are proved to be hashes below. The contract stores
+        // the subtree at the aligned leaf index reserved for the batch, the path above it is updated on chain
+        namespace(&cs, "subtree", || {
+            let subtree_nodes = gen_subtree_gadget::<_, _, FHG>(&inner_params, &leaves_input)?;
+            subtree_nodes_input
+                .iter()
+                .zip(subtree_nodes)
+                .try_for_each(|(input, node)| input.enforce_equal(&node))
+        })?;
+
+        // commit commitments, every note is bound to the escrow it is committed with
+        let owners = match self.commits {
+            Some(commits) => namespace(&cs, "commit", || {
+                // all commitments are encrypted to the same auditor keys
+                let auditor_pubkeys = alloc_auditor_pubkeys(cs.clone(), &self.auditor_pubkeys)?;
+                commits
+                    .into_iter()
+                    .zip(leaf_indexes.iter())
+                    .zip(owners)
+                    .map(|((commit, index), owner)| commit.synthesize(cs.clone(), &auditor_pubkeys, index.clone(), owner))
+                    .collect::<Result<Vec<_>>>()
+            })?,
+            None => owners,
+        };
+
+        // hash leaves: hash(leaf_index + i | asset_id | deposit_amount | note owner)
+        namespace(&cs, "leaves", || {
+            leaf_indexes
+                .into_iter()
+                .zip(deposit_amounts)
+                .zip(owners)
+                .zip(leaves_input.iter())
+                .try_for_each(|(((index, amount), owner), input)| {
+                    let leaf = FHG::hash_gadget(&leaf_params, &[index, asset_id.clone(), amount, owner])?;
+                    input.enforce_equal(&leaf)
+                })
+        })?;
+
+        Ok(())
+    }
+}
+
+impl<P, FH, FHG> BatchDepositCircuit<P, FH, FHG>
+where
+    P: TEModelParameters,
+    FH: FieldHasher<P::BaseField>,
+    FHG: FieldHasherGadget<P::BaseField, FH>,
+    P::BaseField: PrimeField,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        leaf_params: Rc<FH::Parameters>,
+        inner_params: Rc<FH::Parameters>,
+        deposit_amounts: Vec<u64>,
+        asset_id: P::BaseField,
+        leaf_index: u64,
+        leaves: Vec<P::BaseField>,
+        subtree_nodes: Vec<P::BaseField>,
+        owners: Vec<P::BaseField>,
+        auditor_pubkeys: Vec<GroupAffine<P>>,
+        commits: Option<Vec<Commit<P, FH, FHG>>>,
+    ) -> error::Result<Self> {
+        if leaves.len() < 2 || !leaves.len().is_power_of_two() {
+            return Err(MazeLibError::InvalidInputs(format!("batch size {} is not a power of 2", leaves.len())));
+        }
+        check_length("deposit amounts", deposit_amounts.len(), leaves.len())?;
+        check_length("owners", owners.len(), leaves.len())?;
+        check_length("subtree nodes", subtree_nodes.len(), leaves.len() - 1)?;
+
+        Ok(Self {
+            leaf_params,
+            inner_params,
+            deposit_amounts,
+            asset_id,
+            leaf_index,
+            leaves,
+            subtree_nodes,
+            owners,
+            auditor_pubkeys,
+            commits,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ed_on_bn254::{Fq as Fr, EdwardsParameters};
+    use ark_std::{rc::Rc, test_rng, UniformRand};
+    use ark_relations::r1cs::{ConstraintSystem, ConstraintSynthesizer};
+	use arkworks_utils::utils::common::{Curve, setup_params_x5_3, setup_params_x5_5};
+
+    use crate::circuits::hasher::poseidon::PoseidonHasherGadget;
+    use crate::vanilla::VanillaProof;
+    use crate::vanilla::batch_deposit::{BatchDepositConstParams, BatchDepositOriginInputs, BatchDepositVanillaProof};
+    use crate::vanilla::hasher::poseidon::PoseidonHasher;
+    use super::BatchDepositCircuit;
+
+    const HEIGHT: u8 = 24;
+    const DEPTH: u8 = 3;
+
+    #[test]
+    fn test_batch_deposit() {
+        let rng = &mut test_rng();
+        let leaf_params = setup_params_x5_5::<Fr>(Curve::Bn254);
+        let inner_params = setup_params_x5_3::<Fr>(Curve::Bn254);
+        let size = 1usize << DEPTH;
+
+        let params = BatchDepositConstParams::<EdwardsParameters, _> {
+            leaf_params: Rc::new(leaf_params),
+            inner_params: Rc::new(inner_params),
+            height: HEIGHT as usize,
+            depth: DEPTH as usize,
+            commit: None,
+        };
+        let orig_in = BatchDepositOriginInputs {
+            leaf_index: size as u64,
+            deposit_amounts: (0..size).map(|_| u64::rand(rng)).collect(),
+            asset_id: Fr::rand(rng),
+            owners: (0..size).map(|_| Fr::rand(rng)).collect(),
+            commit: None,
+        };
+        // generate vanilla proof
+        let (pub_in, priv_in) = BatchDepositVanillaProof::<_, PoseidonHasher<_>>::generate_vanilla_proof(
+            &params,
+            &orig_in,
+        ).unwrap();
+
+        let deposit = BatchDepositCircuit::<EdwardsParameters, _, PoseidonHasherGadget<_>>::new(
+            params.leaf_params,
+            params.inner_params,
+            pub_in.deposit_amounts,
+            pub_in.asset_id,
+            pub_in.leaf_index,
+            pub_in.leaves,
+            pub_in.subtree_nodes,
+            priv_in.owners,
+            pub_in.auditor_pubkeys,
+            None,
+        ).unwrap();
+        // generate snark proof
+        let cs = ConstraintSystem::new_ref();
+        deposit.generate_constraints(cs.clone()).unwrap();
+
+        assert!(cs.is_satisfied().unwrap());
+        println!("constraints: {}", cs.num_constraints());
+    }
+}
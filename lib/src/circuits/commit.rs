@@ -1,7 +1,7 @@
 use ark_std::{marker::PhantomData, rc::Rc};
 use ark_ff::{PrimeField, FpParameters};
-use ark_ec::{ProjectiveCurve, AffineCurve};
-use ark_ec::{TEModelParameters, twisted_edwards_extended::{GroupAffine, GroupProjective}};
+use ark_ec::ProjectiveCurve;
+use ark_ec::{ModelParameters, TEModelParameters, twisted_edwards_extended::{GroupAffine, GroupProjective}};
 use ark_r1cs_std::ToBitsGadget;
 use ark_r1cs_std::fields::fp::FpVar;
 use ark_r1cs_std::groups::{curves::twisted_edwards::AffineVar, CurveVar};
@@ -10,8 +10,95 @@ use ark_r1cs_std::alloc::AllocVar;
 use ark_r1cs_std::prelude::EqGadget;
 use ark_relations::r1cs::{ConstraintSystemRef, SynthesisError};
 
-use crate::vanilla::hasher::FieldHasher;
-use super::FieldHasherGadget;
+use crate::vanilla::{hasher::FieldHasher, commit::Ciphertext};
+use super::{FieldHasherGadget, namespace};
+
+pub type PubkeyVar<P> = AffineVar<P, FpVar<<P as ModelParameters>::BaseField>>;
+
+type CiphertextVar<P> = (PubkeyVar<P>, PubkeyVar<P>);
+
+// doublings of the generator, for scalar multiplications with a fixed base
+pub(super) fn generator_bases<P: TEModelParameters>() -> Vec<GroupProjective<P>> {
+    let scalar_bits = <<P::ScalarField as PrimeField>::Params as FpParameters>::CAPACITY as usize;
+
+    let mut base = GroupProjective::prime_subgroup_generator();
+    let mut bases = Vec::with_capacity(scalar_bits);
+    for _ in 0..scalar_bits {
+        bases.push(base);
+        base.double_in_place();
+    }
+
+    bases
+}
+
+// truncate a field element to the bits of a scalar
+pub(super) fn to_scalar_bits<P>(value: &FpVar<P::BaseField>) -> Result<Vec<Boolean<P::BaseField>>, SynthesisError>
+where
+    P: TEModelParameters,
+    P::BaseField: PrimeField,
+{
+    let scalar_bits = <<P::ScalarField as PrimeField>::Params as FpParameters>::CAPACITY as usize;
+    let mut bits = value.to_bits_le()?;
+    bits.truncate(scalar_bits);
+
+    Ok(bits)
+}
+
+fn alloc_bits<F: PrimeField>(
+    cs: ConstraintSystemRef<F>,
+    bits: Vec<Vec<bool>>,
+) -> Result<Vec<Vec<Boolean<F>>>, SynthesisError> {
+    bits
+        .into_iter()
+        .map(|bits| {
+            bits
+                .into_iter()
+                .map(|bit| Boolean::new_witness(cs.clone(), || Ok(bit)))
+                .collect::<Result<Vec<_>, _>>()
+        })
+        .collect()
+}
+
+fn alloc_ciphertexts<P>(
+    cs: ConstraintSystemRef<P::BaseField>,
+    ciphertexts: Vec<Ciphertext<P>>,
+    input: bool,
+) -> Result<Vec<CiphertextVar<P>>, SynthesisError>
+where
+    P: TEModelParameters,
+    P::BaseField: PrimeField,
+{
+    let alloc = |point: GroupAffine<P>| if input {
+        AffineVar::new_input(cs.clone(), || Ok(point))
+    } else {
+        AffineVar::new_witness(cs.clone(), || Ok(point))
+    };
+
+    ciphertexts
+        .into_iter()
+        .map(|(c0, c1)| Ok((alloc(c0)?, alloc(c1)?)))
+        .collect()
+}
+
+// chain the owner key with the auditor keys and the escrow: hash(acc | coordinate)
+fn gen_note_owner_gadget<P, FH, FHG>(
+    nullifier_params: &FHG::ParametersVar,
+    owner: FpVar<P::BaseField>,
+    pubkeys: &[PubkeyVar<P>],
+    escrow: &[CiphertextVar<P>],
+) -> Result<FpVar<P::BaseField>, SynthesisError>
+where
+    P: TEModelParameters,
+    FH: FieldHasher<P::BaseField>,
+    FHG: FieldHasherGadget<P::BaseField, FH>,
+    P::BaseField: PrimeField,
+{
+    pubkeys.iter().zip(escrow.iter()).try_fold(owner, |acc, (pubkey, (escrow_0, escrow_1))| {
+        [&pubkey.x, &pubkey.y, &escrow_0.x, &escrow_0.y, &escrow_1.x, &escrow_1.y]
+            .into_iter()
+            .try_fold(acc, |acc, coordinate| FHG::hash_gadget(nullifier_params, &[acc, coordinate.clone()]))
+    })
+}
 
 pub struct Commit<P, FH, FHG>
 where
@@ -21,9 +108,9 @@ where
     P::BaseField: PrimeField,
 {
     nullifier_params: Rc<FH::Parameters>,
-    pubkey: GroupAffine<P>,
-    nonce_bits: Vec<bool>,
-    commitment: (GroupAffine<P>, GroupAffine<P>),
+    nonce_bits: Vec<Vec<bool>>,
+    escrow: Vec<Ciphertext<P>>,
+    commitments: Vec<Ciphertext<P>>,
     _p: PhantomData<FHG>,
 }
 
@@ -36,126 +123,244 @@ where
 {
     pub fn new(
         nullifier_params: Rc<FH::Parameters>,
-        pubkey: GroupAffine<P>,
-        nonce_bits: Vec<bool>,
-        commitment: (GroupAffine<P>, GroupAffine<P>),
+        nonce_bits: Vec<Vec<bool>>,
+        escrow: Vec<Ciphertext<P>>,
+        commitments: Vec<Ciphertext<P>>,
     ) -> Self {
         Self {
             nullifier_params,
-            pubkey,
             nonce_bits,
-            commitment,
+            escrow,
+            commitments,
             _p: Default::default(),
         }
     }
 
+    /// Commit the nullifier point of the note and return the owner bound into its leaf.
     pub fn synthesize(
         self,
         cs: ConstraintSystemRef<P::BaseField>,
+        pubkeys: &[PubkeyVar<P>],
         leaf_index: FpVar<P::BaseField>,
-        secret: FpVar<P::BaseField>,
-    ) -> Result<(), SynthesisError>
+        owner: FpVar<P::BaseField>,
+    ) -> Result<FpVar<P::BaseField>, SynthesisError>
     where
         P::BaseField: PrimeField,
     {
-        let scalar_bits = <<P::ScalarField as PrimeField>::Params as FpParameters>::CAPACITY as usize;
+        // every auditor key has its own nonce, escrow and commitment
+        if pubkeys.len() != self.commitments.len()
+            || self.nonce_bits.len() != self.commitments.len()
+            || self.escrow.len() != self.commitments.len()
+        {
+            return Err(SynthesisError::Unsatisfiable);
+        }
 
         // alloc constant
         let nullifier_params = FHG::ParametersVar::new_constant(
             cs.clone(),
             self.nullifier_params,
         )?;
-        // Note: generator and pubkey is no need to define constant here.
+        // Note: generator is no need to define constant here.
 
         // allocate public inputs
-        let commitment_0 = AffineVar::<_, FpVar<P::BaseField>>::new_input(
-            cs.clone(),
-            || Ok(self.commitment.0),
-        )?;
-        let commitment_1 = AffineVar::<_, FpVar<P::BaseField>>::new_input(
+        let commitments = alloc_ciphertexts(cs.clone(), self.commitments, true)?;
+
+        // allocate witness
+        let nonces = alloc_bits(cs.clone(), self.nonce_bits)?;
+        // escrow of the owner is taken from the shielded address, it is checked when the note is spent
+        let escrow = alloc_ciphertexts(cs.clone(), self.escrow, false)?;
+
+        let generator_bases = generator_bases::<P>();
+
+        // the note is bound to the escrow, so that the owner can only spend it with a valid one
+        let note_owner = namespace(&cs, "note_owner", || {
+            gen_note_owner_gadget::<P, FH, FHG>(&nullifier_params, owner.clone(), pubkeys, &escrow)
+        })?;
+
+        // hash for the nullifier scalar: hash(leaf_index | owner)
+        let nullifier = namespace(&cs, "nullifier", || {
+            let nullifier = FHG::hash_gadget(&nullifier_params, &[leaf_index, owner])?;
+            to_scalar_bits::<P>(&nullifier)
+        })?;
+
+        // scale the escrow by the nullifier scalar and rerandomize it for each auditor key,
+        // which decrypts to nullifier * nullifier_key * G
+        for (((pubkey, nonce), (escrow_0, escrow_1)), (commitment_0, commitment_1)) in pubkeys
+            .iter()
+            .zip(nonces.iter())
+            .zip(escrow.iter())
+            .zip(commitments.iter())
+        {
+            namespace(&cs, "ciphertext", || {
+                // compute commitment_0 = nullifier * E0 + nonce * G
+                let mut point = AffineVar::zero();
+                point.precomputed_base_scalar_mul_le(nonce.iter().zip(generator_bases.iter()))?;
+                point += escrow_0.scalar_mul_le(nullifier.iter())?;
+                // constrain point = commitment_0
+                point.enforce_equal(commitment_0)?;
+
+                // compute commitment_1 = nullifier * E1 + nonce * P
+                // pubkey is a public input, so it can not be precomputed
+                point = pubkey.scalar_mul_le(nonce.iter())? + escrow_1.scalar_mul_le(nullifier.iter())?;
+                // constrain point = commitment_1
+                point.enforce_equal(commitment_1)
+            })?;
+        }
+
+        Ok(note_owner)
+    }
+}
+
+/// Open the escrow a spent note was committed with, it must encrypt the nullifier key point of
+/// the spender to the auditor keys at that time: (nonce * G, nullifier_key * G + nonce * P).
+pub struct Escrow<P, FH, FHG>
+where
+    P: TEModelParameters,
+    FH: FieldHasher<P::BaseField>,
+    FHG: FieldHasherGadget<P::BaseField, FH>,
+    P::BaseField: PrimeField,
+{
+    nullifier_params: Rc<FH::Parameters>,
+    pubkeys: Vec<GroupAffine<P>>,
+    nonce_bits: Vec<Vec<bool>>,
+    escrow: Vec<Ciphertext<P>>,
+    _p: PhantomData<FHG>,
+}
+
+impl<P, FH, FHG> Escrow<P, FH, FHG>
+where
+    P: TEModelParameters,
+    FH: FieldHasher<P::BaseField>,
+    FHG: FieldHasherGadget<P::BaseField, FH>,
+    P::BaseField: PrimeField,
+{
+    pub fn new(
+        nullifier_params: Rc<FH::Parameters>,
+        pubkeys: Vec<GroupAffine<P>>,
+        nonce_bits: Vec<Vec<bool>>,
+        escrow: Vec<Ciphertext<P>>,
+    ) -> Self {
+        Self {
+            nullifier_params,
+            pubkeys,
+            nonce_bits,
+            escrow,
+            _p: Default::default(),
+        }
+    }
+
+    /// Check the escrow against the nullifier key point and return the owner bound into the leaf.
+    pub fn synthesize(
+        self,
+        cs: ConstraintSystemRef<P::BaseField>,
+        nullifier_key_point: &PubkeyVar<P>,
+        owner: FpVar<P::BaseField>,
+    ) -> Result<FpVar<P::BaseField>, SynthesisError> {
+        if self.pubkeys.len() != self.escrow.len() || self.nonce_bits.len() != self.escrow.len() {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+
+        // alloc constant
+        let nullifier_params = FHG::ParametersVar::new_constant(
             cs.clone(),
-            || Ok(self.commitment.1),
+            self.nullifier_params,
         )?;
 
-        // allocate witness
-        let nonce = self.nonce_bits
+        // allocate witness, the auditor keys may be rotated since the note was committed
+        let pubkeys = self.pubkeys
             .into_iter()
-            .map(|bit| Boolean::new_witness(cs.clone(), || Ok(bit)))
+            .map(|pubkey| AffineVar::new_witness(cs.clone(), || Ok(pubkey)))
             .collect::<Result<Vec<_>, _>>()?;
+        let nonces = alloc_bits(cs.clone(), self.nonce_bits)?;
+        let escrow = alloc_ciphertexts(cs.clone(), self.escrow, false)?;
 
-        // hash for nullifier
-        let nullifier = FHG::hash_gadget(&nullifier_params, &[leaf_index, secret])?;
-        let mut nullifier_bits = nullifier.to_bits_le()?;
-        nullifier_bits.truncate(scalar_bits);
-
-        // encrypt nullifier by `Elgamal` algorithm.
-        // compute commitment_0 = nonce * G
-        let mut base = GroupProjective::prime_subgroup_generator();
-        let mut bases = Vec::with_capacity(scalar_bits);
-        for _ in 0..scalar_bits {
-            bases.push(base);
-            base.double_in_place();
-        }
-
-        let mut point = AffineVar::zero();
-        point.precomputed_base_scalar_mul_le(nonce.iter().zip(bases.iter()))?;
-        // constrain point = commitment_0
-        point.enforce_equal(&commitment_0)?;
+        let generator_bases = generator_bases::<P>();
 
-        // compute commitment_1 = nullifier * G + nonce * P
-        point = AffineVar::zero();
-        point.precomputed_base_scalar_mul_le(nullifier_bits.into_iter().zip(bases.iter()))?;
+        for ((pubkey, nonce), (escrow_0, escrow_1)) in pubkeys
+            .iter()
+            .zip(nonces.iter())
+            .zip(escrow.iter())
+        {
+            namespace(&cs, "ciphertext", || {
+                // constrain escrow_0 = nonce * G
+                let mut point = AffineVar::zero();
+                point.precomputed_base_scalar_mul_le(nonce.iter().zip(generator_bases.iter()))?;
+                point.enforce_equal(escrow_0)?;
 
-        base = self.pubkey.into_projective();
-        for b in bases.iter_mut() {
-            *b = base;
-            base.double_in_place();
+                // constrain escrow_1 = nullifier_key * G + nonce * P
+                point = pubkey.scalar_mul_le(nonce.iter())? + nullifier_key_point;
+                point.enforce_equal(escrow_1)
+            })?;
         }
-        point.precomputed_base_scalar_mul_le(nonce.iter().zip(bases.iter()))?;
-        // constrain point = commitment_1
-        point.enforce_equal(&commitment_1)?;
 
-        Ok(())
+        namespace(&cs, "note_owner", || {
+            gen_note_owner_gadget::<P, FH, FHG>(&nullifier_params, owner, &pubkeys, &escrow)
+        })
     }
-} 
+}
+
+/// Allocate auditor keys as public inputs, so the vault can rotate them without a new setup.
+pub fn alloc_auditor_pubkeys<P>(
+    cs: ConstraintSystemRef<P::BaseField>,
+    pubkeys: &[GroupAffine<P>],
+) -> Result<Vec<PubkeyVar<P>>, SynthesisError>
+where
+    P: TEModelParameters,
+    P::BaseField: PrimeField,
+{
+    pubkeys
+        .iter()
+        .map(|pubkey| AffineVar::new_input(cs.clone(), || Ok(*pubkey)))
+        .collect()
+}
 
 #[cfg(test)]
 mod tests {
-    use ark_ec::{twisted_edwards_extended::GroupProjective, ProjectiveCurve};
+    use ark_ec::{twisted_edwards_extended::GroupProjective, ProjectiveCurve, AffineCurve};
     use ark_ed_on_bn254::{Fq, Fr, EdwardsParameters};
     use ark_ff::PrimeField;
-    use ark_r1cs_std::{fields::fp::FpVar, alloc::AllocVar};
+    use ark_r1cs_std::{R1CSVar, fields::fp::FpVar, alloc::AllocVar, groups::curves::twisted_edwards::AffineVar};
     use ark_relations::r1cs::ConstraintSystem;
     use arkworks_utils::utils::common::{Curve, setup_params_x5_3};
     use ark_std::{rc::Rc, test_rng, UniformRand};
 
     use crate::vanilla::commit::*;
+    use crate::vanilla::withdraw::{gen_owner_key, gen_nullifier_key, gen_nullifier_point};
     use crate::vanilla::hasher::poseidon::PoseidonHasher;
     use crate::circuits::poseidon::PoseidonHasherGadget;
-    use super::Commit;
+    use super::{Commit, Escrow, alloc_auditor_pubkeys};
 
-    #[test]
-    fn test_commit() {
+    fn test_commit_inner(num_auditors: usize, other_escrow: bool) -> bool {
         let rng = &mut test_rng();
-        // gen keypair
+        // gen keypairs of independent auditors
         let generator = GroupProjective::<EdwardsParameters>::prime_subgroup_generator();
-        let private: <Fr as PrimeField>::BigInt = Fr::rand(rng).into();
-        let pubkey = generator.mul(private);
+        let (privkeys, pubkeys): (Vec<_>, Vec<_>) = (0..num_auditors).map(|_| {
+            let private = Fr::rand(rng);
+            (private, generator.mul(private.into_repr()).into_affine())
+        }).unzip();
 
-        // nullifier
+        // keys of the note owner
         let nullifier_params = setup_params_x5_3::<Fq>(Curve::Bn254);
-        let leaf_index = u64::rand(rng);
         let secret = Fq::rand(rng);
+        let owner = gen_owner_key::<_, PoseidonHasher<_>>(&nullifier_params, secret).unwrap();
+        let nullifier_key = gen_nullifier_key::<_, PoseidonHasher<_>>(&nullifier_params, secret).unwrap();
+        let leaf_index = u64::rand(rng);
+
+        // escrow published with the shielded address of the owner
+        let escrow_nonces = (0..num_auditors).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+        let escrow_key = if other_escrow { Fq::rand(rng) } else { nullifier_key };
+        let escrow = gen_escrow(&pubkeys, escrow_key, &escrow_nonces).unwrap();
 
         // gen params
         let params = CommitConstParams::<_, PoseidonHasher<_>> {
             nullifier_params: Rc::new(nullifier_params),
-            pubkey: pubkey.into_affine(),
+            pubkeys,
         };
 
         // gen origin inputs
         let orig_in = CommitOriginInputs {
-            nonce: Fr::rand(rng),
+            nonces: (0..num_auditors).map(|_| Fr::rand(rng)).collect(),
+            escrow,
         };
 
         // gen vanilla proof
@@ -163,22 +368,69 @@ mod tests {
             &params,
             &orig_in,
             leaf_index,
-            secret,
+            owner,
         ).unwrap();
-        
-        // gen snark proof
+        let note_owner = gen_note_owner::<_, PoseidonHasher<_>>(&params.nullifier_params, owner, &params.pubkeys, &orig_in.escrow).unwrap();
+
+        // every auditor decrypts the nullifier point the owner publishes when the note is spent
+        let nullifier_point = gen_nullifier_point::<EdwardsParameters, PoseidonHasher<_>>(
+            &params.nullifier_params,
+            leaf_index,
+            owner,
+            nullifier_key,
+        ).unwrap();
+        let decrypted = privkeys.iter().zip(pub_in.commitments.iter()).all(|(privkey, (c0, c1))| {
+            c1.into_projective() - c0.mul(privkey.into_repr()) == nullifier_point.into_projective()
+        });
+        assert_eq!(decrypted, !other_escrow);
+
+        // gen snark proof of the commitment
         let cs = ConstraintSystem::new_ref();
         let leaf_index = FpVar::new_input(cs.clone(), || Ok(Fq::from(leaf_index))).unwrap();
-        let secret = FpVar::new_witness(cs.clone(), || Ok(secret)).unwrap();
+        let owner = FpVar::new_witness(cs.clone(), || Ok(owner)).unwrap();
+        let pubkeys = alloc_auditor_pubkeys(cs.clone(), &params.pubkeys).unwrap();
         let commit = Commit::<_, _, PoseidonHasherGadget<_>>::new(
-            params.nullifier_params,
-            params.pubkey,
+            params.nullifier_params.clone(),
             priv_in.nonce_bits,
-            pub_in.commitment,
+            priv_in.escrow,
+            pub_in.commitments,
         );
-        commit.synthesize(cs.clone(), leaf_index, secret).unwrap();
-        
-        assert!(cs.is_satisfied().unwrap());
+        let note_owner_var = commit.synthesize(cs.clone(), &pubkeys, leaf_index, owner.clone()).unwrap();
+        assert_eq!(note_owner_var.value().unwrap(), note_owner);
+
+        // gen snark proof of the escrow when the note is spent
+        let escrow_orig_in = EscrowOriginInputs {
+            pubkeys: params.pubkeys.clone(),
+            nonces: escrow_nonces,
+        };
+        let escrow_priv_in = generate_escrow_proof(&escrow_orig_in, nullifier_key).unwrap();
+        let nullifier_key_point = AffineVar::new_witness(cs.clone(), || Ok(gen_nullifier_key_point::<EdwardsParameters>(nullifier_key))).unwrap();
+        let escrow = Escrow::<_, _, PoseidonHasherGadget<_>>::new(
+            params.nullifier_params,
+            escrow_priv_in.pubkeys,
+            escrow_priv_in.nonce_bits,
+            escrow_priv_in.escrow,
+        );
+        let spent_owner = escrow.synthesize(cs.clone(), &nullifier_key_point, owner).unwrap();
         println!("{}", cs.num_constraints());
+
+        // the note is spendable only if it is bound to the escrow of the nullifier key
+        cs.is_satisfied().unwrap() && spent_owner.value().unwrap() == note_owner
+    }
+
+    #[test]
+    fn test_commit() {
+        assert!(test_commit_inner(1, false));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_commit_multiple_auditors() {
+        assert!(test_commit_inner(3, false));
+    }
+
+    #[test]
+    fn test_commit_other_escrow() {
+        // a note committed with an escrow of another nullifier key can not be spent
+        assert!(!test_commit_inner(1, true));
+    }
+}
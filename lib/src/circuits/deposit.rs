@@ -1,12 +1,11 @@
-use ark_ec::TEModelParameters;
+use ark_ec::{TEModelParameters, twisted_edwards_extended::GroupAffine};
 use ark_std::rc::Rc;
 use ark_ff::PrimeField;
 use ark_r1cs_std::{fields::fp::FpVar, prelude::EqGadget, alloc::AllocVar};
 use ark_relations::r1cs::{ConstraintSystemRef, ConstraintSynthesizer, Result};
 
 use crate::vanilla::hasher::FieldHasher;
-use super::merkle::AddNewLeaf;
-use super::{FieldHasherGadget, Commit};
+use super::{FieldHasherGadget, Commit, alloc_auditor_pubkeys, namespace};
 
 pub struct DepositCircuit<P, FH, FHG>
 where
@@ -17,11 +16,11 @@ where
 {
     leaf_params: Rc<FH::Parameters>,
     deposit_amount: u64,
+    asset_id: P::BaseField,
     leaf_index: u64,
     leaf: P::BaseField,
-    prev_root: P::BaseField,
-    secret: P::BaseField,
-    proof: AddNewLeaf<P::BaseField, FH, FHG>,
+    owner: P::BaseField,
+    auditor_pubkeys: Vec<GroupAffine<P>>,
     commit: Option<Commit<P, FH, FHG>>,
 }
 
@@ -39,26 +38,32 @@ where
         // alloc input
         // amount bit size of 64 can verify in contract, so no need constrain in circuit
         let deposit_amount = FpVar::new_input(cs.clone(), || Ok(P::BaseField::from(self.deposit_amount)))?;
+        // asset id is checked against the deposited mint in contract
+        let asset_id = FpVar::new_input(cs.clone(), || Ok(self.asset_id))?;
         let leaf_index = FpVar::new_input(cs.clone(), || Ok(P::BaseField::from(self.leaf_index)))?;
+        // the leaf is inserted into the tree by the contract at the leaf index reserved for the deposit
         let leaf_input = FpVar::new_input(cs.clone(), || Ok(self.leaf))?;
-        let prev_root = FpVar::new_input(cs.clone(), || Ok(self.prev_root))?;
 
         // alloc witness
-        let secret = FpVar::new_witness(cs.clone(), || Ok(self.secret))?;
-
-        // hash leaf: hash(leaf_index | deposit_amount | secret)
-        let leaf = FHG::hash_gadget(
-            &leaf_params,
-            &[leaf_index.clone(), deposit_amount, secret.clone()],
-        )?;
-        leaf_input.enforce_equal(&leaf)?;
-        // add new leaf proof
-        self.proof.synthesize(cs.clone(), leaf_index.clone(), leaf, prev_root)?;
-
-        // commit commitment
-        if let Some(commit) = self.commit {
-            commit.synthesize(cs, leaf_index, secret)?;
-        }
+        let owner = FpVar::new_witness(cs.clone(), || Ok(self.owner))?;
+
+        // commit commitment, the note is bound to the escrow it is committed with
+        let owner = match self.commit {
+            Some(commit) => namespace(&cs, "commit", || {
+                let auditor_pubkeys = alloc_auditor_pubkeys(cs.clone(), &self.auditor_pubkeys)?;
+                commit.synthesize(cs.clone(), &auditor_pubkeys, leaf_index.clone(), owner)
+            })?,
+            None => owner,
+        };
+
+        namespace(&cs, "leaf", || {
+            // hash leaf: hash(leaf_index | asset_id | deposit_amount | note owner)
+            let leaf = FHG::hash_gadget(
+                &leaf_params,
+                &[leaf_index, asset_id, deposit_amount, owner],
+            )?;
+            leaf_input.enforce_equal(&leaf)
+        })?;
 
         Ok(())
     }
@@ -74,28 +79,22 @@ where
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         leaf_params: Rc<FH::Parameters>,
-        inner_params: Rc<FH::Parameters>,
         deposit_amount: u64,
+        asset_id: P::BaseField,
         leaf_index: u64,
         leaf: P::BaseField,
-        prev_root: P::BaseField,
-        update_nodes: Vec<P::BaseField>,
-        secret: P::BaseField,
-        neighbor_nodes: Vec<(bool, P::BaseField)>,
+        owner: P::BaseField,
+        auditor_pubkeys: Vec<GroupAffine<P>>,
         commit: Option<Commit<P, FH, FHG>>,
     ) -> Self {
         Self {
             leaf_params,
             deposit_amount,
+            asset_id,
             leaf_index,
             leaf,
-            prev_root,
-            secret,
-            proof: AddNewLeaf::new(
-                neighbor_nodes,
-                update_nodes,
-                inner_params,
-            ),
+            owner,
+            auditor_pubkeys,
             commit,
         }
     }
@@ -106,8 +105,7 @@ mod tests {
     use ark_ed_on_bn254::{Fq as Fr, EdwardsParameters};
     use ark_std::{rc::Rc, test_rng, UniformRand, rand::Rng};
     use ark_relations::r1cs::{ConstraintSystem, ConstraintSynthesizer};
-	use arkworks_utils::utils::common::{Curve, setup_params_x5_3, setup_params_x5_4};
-    use bitvec::{prelude::BitVec, field::BitField};
+	use arkworks_utils::utils::common::{Curve, setup_params_x5_5};
 
     use crate::circuits::hasher::poseidon::PoseidonHasherGadget;
     use crate::vanilla::VanillaProof;
@@ -117,40 +115,27 @@ mod tests {
 
     const HEIGHT: u8 = 24;
 
-    fn get_random_merkle_neighbors<R: Rng + ?Sized>(rng: &mut R) -> (Vec<bool>, Vec<Fr>) {
-        let mut neighbor_nodes = vec![Fr::rand(rng)];
-        let mut indexes = vec![bool::rand(rng)];
-        for _ in 0..(HEIGHT - 1) {
-            indexes.push(bool::rand(rng));
-            neighbor_nodes.push(Fr::rand(rng));
-        }
-
-        (indexes, neighbor_nodes)
-    }
-
     #[test]
     fn test_deposit() {
         let rng = &mut test_rng();
-        let leaf_params = setup_params_x5_4::<Fr>(Curve::Bn254);
-        let inner_params = setup_params_x5_3::<Fr>(Curve::Bn254);
+        let leaf_params = setup_params_x5_5::<Fr>(Curve::Bn254);
         // deposit data
         let deposit_amount = u64::rand(rng);
-        let secret = Fr::rand(rng);
+        let asset_id = Fr::rand(rng);
+        let owner = Fr::rand(rng);
+
+        let leaf_index = rng.gen_range(0..1 << HEIGHT);
 
-        let (indexes, neighbor_nodes) = get_random_merkle_neighbors(rng);
-        let leaf_index = BitVec::<u8>::from_iter(indexes).load_le::<u64>();
-        
         let params = DepositConstParams::<EdwardsParameters, _> {
             leaf_params: Rc::new(leaf_params),
-            inner_params: Rc::new(inner_params),
             height: HEIGHT as usize,
             commit: None,
         };
         let orig_in = DepositOriginInputs {
             leaf_index,
             deposit_amount,
-            secret,
-            neighbor_nodes,
+            asset_id,
+            owner,
             commit: None,
         };
         // generate vanilla proof
@@ -161,14 +146,12 @@ mod tests {
 
         let deposit = DepositCircuit::<EdwardsParameters, _, PoseidonHasherGadget<_>>::new(
             params.leaf_params,
-            params.inner_params,
             pub_in.deposit_amount,
+            pub_in.asset_id,
             pub_in.leaf_index,
             pub_in.leaf,
-            pub_in.prev_root,
-            pub_in.update_nodes,
-            priv_in.secret,
-            priv_in.neighbor_nodes,
+            priv_in.owner,
+            pub_in.auditor_pubkeys,
             None,
         );
         // generate snark proof
@@ -13,12 +13,9 @@ impl<F: PrimeField> FieldHasherGadget<F, MIMCHasher<F>> for MIMCHasherGadget<F>
     type ParametersVar = MiMCParametersVar<F>;
 
     fn hash_gadget(params: &Self::ParametersVar, inputs: &[FpVar<F>]) -> Result<FpVar<F>, SynthesisError> {
-        assert!(
-            inputs.len() < params.num_inputs,
-            "incorrect input length {:?} for width {:?}",
-            inputs.len(),
-            params.num_inputs,
-        );
+        if inputs.len() >= params.num_inputs {
+            return Err(SynthesisError::Unsatisfiable);
+        }
 
         let mut buffer = inputs.to_vec();
         buffer.resize(params.num_inputs, FpVar::zero());
@@ -33,7 +30,9 @@ impl<F: PrimeField> MIMCHasherGadget<F> {
 		parameters: &MiMCParametersVar<F>,
 		state: Vec<FpVar<F>>,
 	) -> Result<Vec<FpVar<F>>, SynthesisError> {
-		assert!(state.len() == parameters.num_inputs);
+		if state.len() != parameters.num_inputs {
+			return Err(SynthesisError::Unsatisfiable);
+		}
 		let mut l_out: FpVar<F> = FpVar::<F>::zero();
 		let mut r_out: FpVar<F> = FpVar::<F>::zero();
 
@@ -106,3 +105,37 @@ impl<F: PrimeField> MIMCHasherGadget<F> {
 		Ok([x_l, x_r])
 	}
 }
+
+#[cfg(test)]
+mod tests {
+    use ark_ed_on_bn254::Fq as Fr;
+    use ark_std::{test_rng, UniformRand};
+    use ark_r1cs_std::{alloc::AllocVar, fields::fp::FpVar, R1CSVar};
+    use ark_relations::r1cs::ConstraintSystem;
+
+    use crate::params::mimc::get_mimc_bn254_for_leaf;
+    use crate::vanilla::hasher::{FieldHasher, mimc::MIMCHasher};
+    use crate::circuits::FieldHasherGadget;
+    use super::MIMCHasherGadget;
+
+    #[test]
+    fn test_mimc_gadget() {
+        let rng = &mut test_rng();
+        let params = get_mimc_bn254_for_leaf::<Fr>();
+        let inputs = (0..4).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+        let hash = MIMCHasher::hash(&params, &inputs).unwrap();
+
+        let cs = ConstraintSystem::new_ref();
+        let params_var = AllocVar::new_constant(cs.clone(), &params).unwrap();
+        let inputs_var = inputs
+            .into_iter()
+            .map(|input| FpVar::new_witness(cs.clone(), || Ok(input)))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let hash_var = MIMCHasherGadget::hash_gadget(&params_var, &inputs_var).unwrap();
+
+        assert_eq!(hash_var.value().unwrap(), hash);
+        assert!(cs.is_satisfied().unwrap());
+        println!("constraints: {}", cs.num_constraints());
+    }
+}
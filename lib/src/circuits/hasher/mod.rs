@@ -1,4 +1,6 @@
 pub mod poseidon;
+pub mod poseidon2;
+pub mod rescue;
 pub mod mimc;
 
 use ark_ff::PrimeField;
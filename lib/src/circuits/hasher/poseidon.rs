@@ -13,12 +13,9 @@ impl<F: PrimeField> FieldHasherGadget<F, PoseidonHasher<F>> for PoseidonHasherGa
     type ParametersVar = PoseidonParametersVar<F>;
 
     fn hash_gadget(params: &Self::ParametersVar, inputs: &[FpVar<F>]) -> Result<FpVar<F>, SynthesisError> {
-        assert!(
-            inputs.len() < params.width.into(),
-            "incorrect input length {:?} for width {:?}",
-            inputs.len(),
-            params.width,
-        );
+        if inputs.len() >= params.width.into() {
+            return Err(SynthesisError::Unsatisfiable);
+        }
 
         let mut buffer = Vec::with_capacity(params.width as usize);
         buffer.push(FpVar::zero());
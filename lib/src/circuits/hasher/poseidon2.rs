@@ -0,0 +1,176 @@
+use ark_std::{marker::PhantomData, borrow::Borrow};
+use ark_ff::PrimeField;
+use ark_r1cs_std::{alloc::{AllocVar, AllocationMode}, fields::{fp::FpVar, FieldVar}};
+use ark_relations::r1cs::{Namespace, SynthesisError};
+
+use crate::vanilla::hasher::poseidon2::{Poseidon2Hasher, Poseidon2Parameters};
+use super::FieldHasherGadget;
+
+#[derive(Default, Clone)]
+pub struct Poseidon2ParametersVar<F: PrimeField> {
+    pub width: usize,
+    pub full_rounds: usize,
+    pub partial_rounds: usize,
+    pub alpha: u64,
+    pub external_rc: Vec<Vec<FpVar<F>>>,
+    pub internal_rc: Vec<FpVar<F>>,
+    pub internal_diag_m_1: Vec<FpVar<F>>,
+    pub domain: F,
+}
+
+impl<F: PrimeField> AllocVar<Poseidon2Parameters<F>, F> for Poseidon2ParametersVar<F> {
+    fn new_variable<T: Borrow<Poseidon2Parameters<F>>>(
+        _cs: impl Into<Namespace<F>>,
+        f: impl FnOnce() -> Result<T, SynthesisError>,
+        _mode: AllocationMode,
+    ) -> Result<Self, SynthesisError> {
+        let params = f()?.borrow().clone();
+
+        Ok(Self {
+            width: params.width,
+            full_rounds: params.full_rounds,
+            partial_rounds: params.partial_rounds,
+            alpha: params.alpha,
+            external_rc: params.external_rc
+                .into_iter()
+                .map(|rc| rc.into_iter().map(FpVar::Constant).collect())
+                .collect(),
+            internal_rc: params.internal_rc.into_iter().map(FpVar::Constant).collect(),
+            internal_diag_m_1: params.internal_diag_m_1.into_iter().map(FpVar::Constant).collect(),
+            domain: params.domain,
+        })
+    }
+}
+
+pub struct Poseidon2HasherGadget<F>(PhantomData<F>);
+
+impl<F: PrimeField> FieldHasherGadget<F, Poseidon2Hasher<F>> for Poseidon2HasherGadget<F> {
+    type ParametersVar = Poseidon2ParametersVar<F>;
+
+    fn hash_gadget(params: &Self::ParametersVar, inputs: &[FpVar<F>]) -> Result<FpVar<F>, SynthesisError> {
+        if inputs.len() >= params.width {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+
+        let mut buffer = Vec::with_capacity(params.width);
+        buffer.push(FpVar::Constant(params.domain));
+        buffer.extend_from_slice(inputs);
+        buffer.resize(params.width, FpVar::zero());
+
+        Self::permute(params, buffer).map(|x| x[0].clone())
+    }
+}
+
+impl<F: PrimeField> Poseidon2HasherGadget<F> {
+    pub fn permute(
+        params: &Poseidon2ParametersVar<F>,
+        mut state: Vec<FpVar<F>>,
+    ) -> Result<Vec<FpVar<F>>, SynthesisError> {
+        if state.len() != params.width {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+        let half_rounds = params.full_rounds / 2;
+
+        Self::matmul_external(&mut state)?;
+        for rc in params.external_rc[..half_rounds].iter() {
+            state = state
+                .iter()
+                .zip(rc)
+                .map(|(s, c)| (s + c).pow_by_constant([params.alpha]))
+                .collect::<Result<Vec<_>, _>>()?;
+            Self::matmul_external(&mut state)?;
+        }
+        for rc in params.internal_rc.iter() {
+            state[0] = (&state[0] + rc).pow_by_constant([params.alpha])?;
+            Self::matmul_internal(&mut state, &params.internal_diag_m_1);
+        }
+        for rc in params.external_rc[half_rounds..].iter() {
+            state = state
+                .iter()
+                .zip(rc)
+                .map(|(s, c)| (s + c).pow_by_constant([params.alpha]))
+                .collect::<Result<Vec<_>, _>>()?;
+            Self::matmul_external(&mut state)?;
+        }
+
+        Ok(state)
+    }
+
+    fn matmul_m4(state: &mut [FpVar<F>]) -> Result<(), SynthesisError> {
+        let t0 = &state[0] + &state[1];
+        let t1 = &state[2] + &state[3];
+        let t2 = state[1].double()? + &t1;
+        let t3 = state[3].double()? + &t0;
+        let t4 = t1.double()?.double()? + &t3;
+        let t5 = t0.double()?.double()? + &t2;
+        let t6 = &t3 + &t5;
+        let t7 = &t2 + &t4;
+        state[0] = t6;
+        state[1] = t5;
+        state[2] = t7;
+        state[3] = t4;
+
+        Ok(())
+    }
+
+    fn matmul_external(state: &mut [FpVar<F>]) -> Result<(), SynthesisError> {
+        match state.len() {
+            2 | 3 => {
+                let sum = state.iter().fold(FpVar::zero(), |acc, s| acc + s);
+                state.iter_mut().for_each(|s| *s += &sum);
+            }
+            4 => Self::matmul_m4(state)?,
+            width => {
+                if !width.is_multiple_of(4) {
+                    return Err(SynthesisError::Unsatisfiable);
+                }
+                state.chunks_mut(4).try_for_each(Self::matmul_m4)?;
+                let sums = (0..4)
+                    .map(|i| state.iter().skip(i).step_by(4).fold(FpVar::zero(), |acc, s| acc + s))
+                    .collect::<Vec<_>>();
+                state.iter_mut().enumerate().for_each(|(i, s)| *s += &sums[i % 4]);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn matmul_internal(state: &mut [FpVar<F>], diag_m_1: &[FpVar<F>]) {
+        let sum = state.iter().fold(FpVar::zero(), |acc, s| acc + s);
+        state.iter_mut().zip(diag_m_1).for_each(|(s, d)| *s = &*s * d + &sum);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ed_on_bn254::Fq as Fr;
+    use ark_std::{test_rng, UniformRand};
+    use ark_r1cs_std::{alloc::AllocVar, fields::fp::FpVar, R1CSVar};
+    use ark_relations::r1cs::ConstraintSystem;
+
+    use crate::params::poseidon2::get_poseidon2_bn254_for_leaf;
+    use crate::vanilla::hasher::{FieldHasher, poseidon2::Poseidon2Hasher};
+    use crate::circuits::FieldHasherGadget;
+    use super::Poseidon2HasherGadget;
+
+    #[test]
+    fn test_poseidon2_gadget() {
+        let rng = &mut test_rng();
+        let params = get_poseidon2_bn254_for_leaf::<Fr>();
+        let inputs = (0..4).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+        let hash = Poseidon2Hasher::hash(&params, &inputs).unwrap();
+
+        let cs = ConstraintSystem::new_ref();
+        let params_var = AllocVar::new_constant(cs.clone(), &params).unwrap();
+        let inputs_var = inputs
+            .into_iter()
+            .map(|input| FpVar::new_witness(cs.clone(), || Ok(input)))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let hash_var = Poseidon2HasherGadget::hash_gadget(&params_var, &inputs_var).unwrap();
+
+        assert_eq!(hash_var.value().unwrap(), hash);
+        assert!(cs.is_satisfied().unwrap());
+        println!("constraints: {}", cs.num_constraints());
+    }
+}
@@ -0,0 +1,145 @@
+use ark_std::{marker::PhantomData, borrow::Borrow};
+use ark_ff::PrimeField;
+use ark_r1cs_std::{R1CSVar, eq::EqGadget, alloc::{AllocVar, AllocationMode}, fields::{fp::FpVar, FieldVar}};
+use ark_relations::r1cs::{Namespace, SynthesisError};
+
+use crate::vanilla::hasher::rescue::{RescueHasher, RescueParameters};
+use super::FieldHasherGadget;
+
+#[derive(Default, Clone)]
+pub struct RescueParametersVar<F: PrimeField> {
+    pub width: usize,
+    pub rounds: usize,
+    pub alpha: u64,
+    pub alpha_inv: Vec<u64>,
+    pub mds: Vec<Vec<FpVar<F>>>,
+    pub round_constants: Vec<Vec<FpVar<F>>>,
+    pub domain: F,
+}
+
+impl<F: PrimeField> AllocVar<RescueParameters<F>, F> for RescueParametersVar<F> {
+    fn new_variable<T: Borrow<RescueParameters<F>>>(
+        _cs: impl Into<Namespace<F>>,
+        f: impl FnOnce() -> Result<T, SynthesisError>,
+        _mode: AllocationMode,
+    ) -> Result<Self, SynthesisError> {
+        let params = f()?.borrow().clone();
+
+        Ok(Self {
+            width: params.width,
+            rounds: params.rounds,
+            alpha: params.alpha,
+            alpha_inv: params.alpha_inv,
+            mds: params.mds
+                .into_iter()
+                .map(|row| row.into_iter().map(FpVar::Constant).collect())
+                .collect(),
+            round_constants: params.round_constants
+                .into_iter()
+                .map(|rc| rc.into_iter().map(FpVar::Constant).collect())
+                .collect(),
+            domain: params.domain,
+        })
+    }
+}
+
+pub struct RescueHasherGadget<F>(PhantomData<F>);
+
+impl<F: PrimeField> FieldHasherGadget<F, RescueHasher<F>> for RescueHasherGadget<F> {
+    type ParametersVar = RescueParametersVar<F>;
+
+    fn hash_gadget(params: &Self::ParametersVar, inputs: &[FpVar<F>]) -> Result<FpVar<F>, SynthesisError> {
+        if inputs.len() >= params.width {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+
+        let mut buffer = Vec::with_capacity(params.width);
+        buffer.push(FpVar::Constant(params.domain));
+        buffer.extend_from_slice(inputs);
+        buffer.resize(params.width, FpVar::zero());
+
+        Self::permute(params, buffer).map(|x| x[0].clone())
+    }
+}
+
+impl<F: PrimeField> RescueHasherGadget<F> {
+    pub fn permute(
+        params: &RescueParametersVar<F>,
+        mut state: Vec<FpVar<F>>,
+    ) -> Result<Vec<FpVar<F>>, SynthesisError> {
+        if state.len() != params.width {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+
+        for rc in params.round_constants.chunks(2) {
+            state = state
+                .iter()
+                .map(|s| s.pow_by_constant([params.alpha]))
+                .collect::<Result<Vec<_>, _>>()?;
+            state = Self::mix(&params.mds, &state, &rc[0]);
+
+            state = state
+                .iter()
+                .map(|s| Self::inverse_sbox(params, s))
+                .collect::<Result<Vec<_>, _>>()?;
+            state = Self::mix(&params.mds, &state, &rc[1]);
+        }
+
+        Ok(state)
+    }
+
+    // y = x^(1/alpha) is a witness constrained by y^alpha = x, which is as cheap as the forward S-box
+    fn inverse_sbox(params: &RescueParametersVar<F>, x: &FpVar<F>) -> Result<FpVar<F>, SynthesisError> {
+        if let FpVar::Constant(x) = x {
+            return Ok(FpVar::Constant(x.pow(&params.alpha_inv)));
+        }
+
+        let y = FpVar::new_witness(x.cs(), || x.value().map(|x| x.pow(&params.alpha_inv)))?;
+        y.pow_by_constant([params.alpha])?.enforce_equal(x)?;
+
+        Ok(y)
+    }
+
+    fn mix(mds: &[Vec<FpVar<F>>], state: &[FpVar<F>], rc: &[FpVar<F>]) -> Vec<FpVar<F>> {
+        mds.iter()
+            .zip(rc)
+            .map(|(row, c)| {
+                row.iter().zip(state).fold(c.clone(), |acc, (m, s)| acc + m * s)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ed_on_bn254::Fq as Fr;
+    use ark_std::{test_rng, UniformRand};
+    use ark_r1cs_std::{alloc::AllocVar, fields::fp::FpVar, R1CSVar};
+    use ark_relations::r1cs::ConstraintSystem;
+
+    use crate::params::rescue::get_rescue_bn254_for_leaf;
+    use crate::vanilla::hasher::{FieldHasher, rescue::RescueHasher};
+    use crate::circuits::FieldHasherGadget;
+    use super::RescueHasherGadget;
+
+    #[test]
+    fn test_rescue_gadget() {
+        let rng = &mut test_rng();
+        let params = get_rescue_bn254_for_leaf::<Fr>();
+        let inputs = (0..4).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+        let hash = RescueHasher::hash(&params, &inputs).unwrap();
+
+        let cs = ConstraintSystem::new_ref();
+        let params_var = AllocVar::new_constant(cs.clone(), &params).unwrap();
+        let inputs_var = inputs
+            .into_iter()
+            .map(|input| FpVar::new_witness(cs.clone(), || Ok(input)))
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        let hash_var = RescueHasherGadget::hash_gadget(&params_var, &inputs_var).unwrap();
+
+        assert_eq!(hash_var.value().unwrap(), hash);
+        assert!(cs.is_satisfied().unwrap());
+        println!("constraints: {}", cs.num_constraints());
+    }
+}
@@ -1,7 +1,7 @@
 use ark_std::{rc::Rc, marker::PhantomData};
 use ark_ff::PrimeField;
 use ark_r1cs_std::{eq::EqGadget, boolean::Boolean, alloc::AllocVar, select::CondSelectGadget, fields::fp::FpVar};
-use ark_relations::r1cs::{ConstraintSystemRef, Result};
+use ark_relations::r1cs::{ConstraintSystemRef, Result, SynthesisError};
 
 use crate::vanilla::hasher::FieldHasher;
 use super::FieldHasherGadget;
@@ -39,39 +39,61 @@ where
         .collect()
 }
 
-pub struct AddNewLeaf<F, FH, FHG>
+/// Hash `leaves` into a subtree, returns nodes from layer 1 up to the subtree root.
+pub(super) fn gen_subtree_gadget<F, FH, FHG>(
+    inner_params: &FHG::ParametersVar,
+    leaves: &[FpVar<F>],
+) -> Result<Vec<FpVar<F>>>
+where
+    F: PrimeField,
+    FH: FieldHasher<F>,
+    FHG: FieldHasherGadget<F, FH>,
+{
+    if !leaves.len().is_power_of_two() {
+        return Err(SynthesisError::Unsatisfiable);
+    }
+
+    let mut nodes = Vec::with_capacity(leaves.len() - 1);
+    let mut layer = leaves.to_vec();
+    while layer.len() > 1 {
+        layer = layer
+            .chunks(2)
+            .map(|pair| FHG::hash_two_gadget(inner_params, pair[0].clone(), pair[1].clone()))
+            .collect::<Result<Vec<_>>>()?;
+        nodes.extend_from_slice(&layer);
+    }
+
+    Ok(nodes)
+}
+
+pub struct LeafExistance<F, FH, FHG>
 where
     F: PrimeField,
     FH: FieldHasher<F>,
     FHG: FieldHasherGadget<F, FH>,
 {
     neighbor_nodes: Vec<(bool, F)>,
-    update_nodes: Vec<F>,
 	inner_params: Rc<FH::Parameters>,
     _h: PhantomData<FHG>,
 }
 
-impl<F, FH, FHG> AddNewLeaf<F, FH, FHG>
+impl<F, FH, FHG> LeafExistance<F, FH, FHG>
 where
     F: PrimeField,
     FH: FieldHasher<F>,
-    FHG: FieldHasherGadget<F, FH>, 
+    FHG: FieldHasherGadget<F, FH>,
 {
     pub fn new(
         neighbor_nodes: Vec<(bool, F)>,
-        update_nodes: Vec<F>,
         inner_params: Rc<FH::Parameters>,
     ) -> Self {
-        assert_eq!(neighbor_nodes.len(), update_nodes.len(), "neighbor nodes length should equals to update nodes length");
-
         Self {
             neighbor_nodes,
-            update_nodes,
             inner_params,
             _h: Default::default(),
         }
     }
-    
+
     pub fn synthesize(
         self,
         cs: ConstraintSystemRef<F>,
@@ -82,64 +104,83 @@ where
         let ref cs = cs;
         // alloc constants
         let inner_params = FHG::ParametersVar::new_constant(cs.clone(), self.inner_params)?;
-        // alloc public var
-        let update_nodes = self.update_nodes
-            .into_iter()
-            .map(|node| FpVar::new_input(cs.clone(), || Ok(node)))
-            .collect::<Result<Vec<_>>>()?;
         // alloc witness var
         let neighbors = self.neighbor_nodes
             .into_iter()
             .map(|(is_left, node)| {
                 let is_left = Boolean::new_witness(cs.clone(), || Ok(is_left))?;
-                // neighbor node can be alloc as public, but no need to do it here
                 let node = FpVar::new_witness(cs.clone(), || Ok(node))?;
 
                 Ok((is_left, node))
             })
             .collect::<Result<Vec<_>>>()?;
 
-        // leaf index constrain
+        // leaf index
         let index_array = neighbors
             .iter()
             .map(|(is_left, _)| is_left.clone())
             .collect::<Vec<_>>();
         leaf_index.enforce_equal(&Boolean::le_bits_to_fp_var(&index_array)?)?;
-        
-        // old root constrain
-        let merkle_paths = gen_merkle_path_gadget::<_, _, FHG>(
-            &inner_params,
-            &neighbors,
-            FHG::empty_hash_var(),
-        )?;
-        merkle_paths.last().unwrap().enforce_equal(&root)?;
 
-        // leaf change
         let merkle_paths = gen_merkle_path_gadget::<_, _, FHG>(
             &inner_params,
             &neighbors,
             leaf,
         )?;
-        // new paths should restrain to input
-        update_nodes
-            .into_iter()
-            .zip(merkle_paths)
-            .try_for_each(|(input_node, node)| input_node.enforce_equal(&node))
+        // old root should restrain to input
+        merkle_paths.last().unwrap().enforce_equal(&root)
     }
 }
 
-pub struct LeafExistance<F, FH, FHG>
+/// Prove the slot at `key` of a sparse merkle tree is empty, members of the tree are non-empty leaves.
+pub struct NonMembership<F, FH, FHG>
+where
+    F: PrimeField,
+    FH: FieldHasher<F>,
+    FHG: FieldHasherGadget<F, FH>,
+{
+    existance: LeafExistance<F, FH, FHG>,
+}
+
+impl<F, FH, FHG> NonMembership<F, FH, FHG>
+where
+    F: PrimeField,
+    FH: FieldHasher<F>,
+    FHG: FieldHasherGadget<F, FH>,
+{
+    pub fn new(
+        neighbor_nodes: Vec<(bool, F)>,
+        inner_params: Rc<FH::Parameters>,
+    ) -> Self {
+        Self {
+            existance: LeafExistance::new(neighbor_nodes, inner_params),
+        }
+    }
+
+    pub fn synthesize(
+        self,
+        cs: ConstraintSystemRef<F>,
+        key: FpVar<F>,
+        root: FpVar<F>,
+    ) -> Result<()> {
+        // the empty leaf exists at key
+        self.existance.synthesize(cs, key, FHG::empty_hash_var(), root)
+    }
+}
+
+/// Prove `key` is the leaf of some slot of a merkle tree, the slot is private.
+pub struct Membership<F, FH, FHG>
 where
     F: PrimeField,
     FH: FieldHasher<F>,
     FHG: FieldHasherGadget<F, FH>,
 {
     neighbor_nodes: Vec<(bool, F)>,
-	inner_params: Rc<FH::Parameters>,
+    inner_params: Rc<FH::Parameters>,
     _h: PhantomData<FHG>,
 }
 
-impl<F, FH, FHG> LeafExistance<F, FH, FHG>
+impl<F, FH, FHG> Membership<F, FH, FHG>
 where
     F: PrimeField,
     FH: FieldHasher<F>,
@@ -159,14 +200,11 @@ where
     pub fn synthesize(
         self,
         cs: ConstraintSystemRef<F>,
-        leaf_index: FpVar<F>,
-        leaf: FpVar<F>,
+        key: FpVar<F>,
         root: FpVar<F>,
     ) -> Result<()> {
-        let ref cs = cs;
-        // alloc constants
         let inner_params = FHG::ParametersVar::new_constant(cs.clone(), self.inner_params)?;
-        // alloc witness var
+        // the position bits are free, any slot holding the key opens the root
         let neighbors = self.neighbor_nodes
             .into_iter()
             .map(|(is_left, node)| {
@@ -177,19 +215,11 @@ where
             })
             .collect::<Result<Vec<_>>>()?;
 
-        // leaf index
-        let index_array = neighbors
-            .iter()
-            .map(|(is_left, _)| is_left.clone())
-            .collect::<Vec<_>>();
-        leaf_index.enforce_equal(&Boolean::le_bits_to_fp_var(&index_array)?)?;
-
         let merkle_paths = gen_merkle_path_gadget::<_, _, FHG>(
             &inner_params,
             &neighbors,
-            leaf,
+            key,
         )?;
-        // old root should restrain to input
         merkle_paths.last().unwrap().enforce_equal(&root)
     }
 }
@@ -204,9 +234,10 @@ mod tests {
     use bitvec::field::BitField;
     use bitvec::prelude::BitVec;
 
-    use crate::{circuits::poseidon::PoseidonHasherGadget, vanilla::hasher::FieldHasher};
+    use crate::circuits::poseidon::PoseidonHasherGadget;
     use crate::vanilla::{hasher::poseidon::PoseidonHasher, merkle::gen_merkle_path};
-    use super::{LeafExistance, AddNewLeaf};
+    use crate::vanilla::index_set::{IndexSetTree, KeySetTree};
+    use super::{LeafExistance, NonMembership, Membership};
 
     const HEIGHT: u8 = 27;
 
@@ -250,38 +281,62 @@ mod tests {
     }
 
     #[test]
-    fn test_add_new_leaf() {
+    fn test_non_membership() {
+        let inner_params = Rc::new(setup_params_x3_3::<Fr>(Curve::Bn254));
+        let mut tree = IndexSetTree::<_, PoseidonHasher<Fr>>::new(inner_params.clone(), HEIGHT as usize).unwrap();
+        tree.insert(5).unwrap();
+        tree.insert(1 << 20).unwrap();
+        assert!(tree.gen_non_membership_neighbor_nodes(5).is_err());
+
+        let prove = |index: u64, neighbor_nodes: Vec<Fr>| {
+            let neighbor_nodes = neighbor_nodes
+                .into_iter()
+                .enumerate()
+                .map(|(layer, node)| ((index >> layer) & 1 == 1, node))
+                .collect();
+            let non_membership = NonMembership::<_, _, PoseidonHasherGadget<Fr>>::new(neighbor_nodes, inner_params.clone());
+
+            let cs = ConstraintSystem::<Fr>::new_ref();
+            let key_var = FpVar::new_witness(cs.clone(), || Ok(Fr::from(index))).unwrap();
+            let root_var = FpVar::new_input(cs.clone(), || Ok(tree.root())).unwrap();
+            non_membership.synthesize(cs.clone(), key_var, root_var).unwrap();
+
+            cs.is_satisfied().unwrap()
+        };
+        assert!(prove(4, tree.gen_non_membership_neighbor_nodes(4).unwrap()));
+        assert!(prove(6, tree.gen_non_membership_neighbor_nodes(6).unwrap()));
+        // neighbor nodes of 4 are those of its sibling 5 but the slot of 5 is not empty
+        assert!(!prove(5, tree.gen_non_membership_neighbor_nodes(4).unwrap()));
+    }
+
+    #[test]
+    fn test_membership() {
         let rng = &mut test_rng();
-        let inner_params = setup_params_x3_3(Curve::Bn254);
-        let neighbor_nodes = get_random_merkle_neighbors(rng);
-        let index_iter = neighbor_nodes.iter().map(|(is_left, _)| is_left).collect::<Vec<_>>();
-        let index = BitVec::<u8>::from_iter(index_iter)
-            .load_le::<u64>();
-        let update_nodes = gen_merkle_path::<_, PoseidonHasher<Fr>>(
-            &inner_params,
-            &neighbor_nodes,
-            PoseidonHasher::empty_hash(),
-        ).unwrap();
-        let prev_root = update_nodes.last().unwrap().clone();
-        let new_leaf = Fr::rand(rng);
-        let update_nodes = gen_merkle_path::<_, PoseidonHasher<Fr>>(
-            &inner_params,
-            &neighbor_nodes,
-            new_leaf.clone(),
-        ).unwrap();
-        let add_new_leaf = AddNewLeaf::<_, _, PoseidonHasherGadget<Fr>>::new(
-            neighbor_nodes,
-            update_nodes,
-            Rc::new(inner_params),
-        );
+        let inner_params = Rc::new(setup_params_x3_3::<Fr>(Curve::Bn254));
+        let mut tree = KeySetTree::<_, PoseidonHasher<Fr>>::new(inner_params.clone(), HEIGHT as usize).unwrap();
+        let keys = (0..3).map(|_| Fr::rand(rng)).collect::<Vec<_>>();
+        keys.iter().for_each(|key| { tree.insert(*key).unwrap(); });
+        let other = Fr::rand(rng);
+        assert!(tree.gen_membership_neighbor_nodes(&other).is_err());
+        assert!(tree.insert(keys[0]).is_err());
 
-        let cs = ConstraintSystem::<Fr>::new_ref();
-        let prev_root_var = FpVar::new_input(cs.clone(), || Ok(prev_root)).unwrap();
-        let leaf_index_var = FpVar::new_input(cs.clone(), || Ok(Fr::from(index))).unwrap();
-        let leaf_var = FpVar::new_witness(cs.clone(), || Ok(new_leaf)).unwrap();
-        _ = add_new_leaf.synthesize(cs.clone(), leaf_index_var, leaf_var, prev_root_var).unwrap();
+        let prove = |key: Fr, (slot, neighbor_nodes): (u64, Vec<Fr>)| {
+            let neighbor_nodes = neighbor_nodes
+                .into_iter()
+                .enumerate()
+                .map(|(layer, node)| ((slot >> layer) & 1 == 1, node))
+                .collect();
+            let membership = Membership::<_, _, PoseidonHasherGadget<Fr>>::new(neighbor_nodes, inner_params.clone());
 
-        assert!(cs.is_satisfied().unwrap());
-        println!("constraints: {}", cs.num_constraints());
+            let cs = ConstraintSystem::<Fr>::new_ref();
+            let key_var = FpVar::new_witness(cs.clone(), || Ok(key)).unwrap();
+            let root_var = FpVar::new_input(cs.clone(), || Ok(tree.root())).unwrap();
+            membership.synthesize(cs.clone(), key_var, root_var).unwrap();
+
+            cs.is_satisfied().unwrap()
+        };
+        keys.iter().for_each(|key| assert!(prove(*key, tree.gen_membership_neighbor_nodes(key).unwrap())));
+        // neighbor nodes of a member do not open the root for another key
+        assert!(!prove(other, tree.gen_membership_neighbor_nodes(&keys[1]).unwrap()));
     }
-}
\ No newline at end of file
+}
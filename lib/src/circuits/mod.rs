@@ -1,11 +1,17 @@
 mod uint64;
 mod merkle;
 mod deposit;
+mod batch_deposit;
 mod withdraw;
+mod transfer;
 mod hasher;
 mod commit;
+mod profile;
 
 pub use hasher::*;
 pub use withdraw::*;
+pub use transfer::*;
 pub use deposit::*;
-pub use commit::*;
\ No newline at end of file
+pub use batch_deposit::*;
+pub use commit::*;
+pub use profile::*;
\ No newline at end of file
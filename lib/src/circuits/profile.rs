@@ -0,0 +1,81 @@
+use ark_std::cell::RefCell;
+use ark_ff::Field;
+use ark_relations::r1cs::{ConstraintSystemRef, Result};
+
+/// Constraints and variables allocated inside a namespace, nested namespaces are joined by `/`.
+#[derive(Debug, Clone, Default)]
+pub struct ConstraintCount {
+    pub namespace: String,
+    pub constraints: usize,
+    pub witnesses: usize,
+    pub inputs: usize,
+}
+
+#[derive(Default)]
+struct Profiler {
+    path: Vec<&'static str>,
+    counts: Vec<ConstraintCount>,
+}
+
+thread_local! {
+    static PROFILER: RefCell<Option<Profiler>> = const { RefCell::new(None) };
+}
+
+#[inline]
+fn counts<F: Field>(cs: &ConstraintSystemRef<F>) -> (usize, usize, usize) {
+    (cs.num_constraints(), cs.num_witness_variables(), cs.num_instance_variables())
+}
+
+/// Synthesize a gadget in a named namespace, counts are only recorded inside `profile`.
+pub fn namespace<F, T, Func>(cs: &ConstraintSystemRef<F>, name: &'static str, f: Func) -> Result<T>
+where
+    F: Field,
+    Func: FnOnce() -> Result<T>,
+{
+    // keep the namespaces in the order they are first entered, a namespace entered again in a loop is summed up
+    let slot = PROFILER.with(|profiler| {
+        profiler.borrow_mut().as_mut().map(|profiler| {
+            profiler.path.push(name);
+            let namespace = profiler.path.join("/");
+            profiler.counts
+                .iter()
+                .position(|count| count.namespace == namespace)
+                .unwrap_or_else(|| {
+                    profiler.counts.push(ConstraintCount { namespace, ..Default::default() });
+                    profiler.counts.len() - 1
+                })
+        })
+    });
+    let slot = match slot {
+        Some(slot) => slot,
+        None => return f(),
+    };
+
+    let (constraints, witnesses, inputs) = counts(cs);
+    let result = f();
+    let (end_constraints, end_witnesses, end_inputs) = counts(cs);
+
+    PROFILER.with(|profiler| {
+        if let Some(profiler) = profiler.borrow_mut().as_mut() {
+            profiler.path.pop();
+            let count = &mut profiler.counts[slot];
+            count.constraints += end_constraints - constraints;
+            count.witnesses += end_witnesses - witnesses;
+            count.inputs += end_inputs - inputs;
+        }
+    });
+
+    result
+}
+
+/// Run the synthesis and collect the counts of every namespace entered.
+pub fn profile<Func>(f: Func) -> Result<Vec<ConstraintCount>>
+where
+    Func: FnOnce() -> Result<()>,
+{
+    PROFILER.with(|profiler| *profiler.borrow_mut() = Some(Profiler::default()));
+    let result = f();
+    let profiler = PROFILER.with(|profiler| profiler.borrow_mut().take()).unwrap_or_default();
+
+    result.map(|_| profiler.counts)
+}
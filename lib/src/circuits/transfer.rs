@@ -0,0 +1,403 @@
+use ark_ec::{twisted_edwards_extended::GroupAffine, TEModelParameters};
+use ark_std::rc::Rc;
+use ark_ff::PrimeField;
+use ark_r1cs_std::groups::curves::twisted_edwards::AffineVar;
+use ark_r1cs_std::{fields::{fp::FpVar, FieldVar}, eq::EqGadget, alloc::AllocVar};
+use ark_relations::r1cs::{ConstraintSystemRef, ConstraintSynthesizer, Result};
+
+use crate::vanilla::hasher::FieldHasher;
+use super::{FieldHasherGadget, Commit, Escrow, alloc_auditor_pubkeys, namespace};
+use super::merkle::LeafExistance;
+use super::withdraw::{gen_nullifier_key_point_gadget, gen_nullifier_point_gadget};
+use super::uint64::Uint64;
+
+pub struct TransferCircuit<P, FH, FHG>
+where
+    P: TEModelParameters,
+    FH: FieldHasher<P::BaseField>,
+    FHG: FieldHasherGadget<P::BaseField, FH>,
+    P::BaseField: PrimeField,
+{
+    nullifier_params: Rc<FH::Parameters>,
+    leaf_params: Rc<FH::Parameters>,
+    withdraw_amount: u64,
+    asset_id: P::BaseField,
+    receiver: P::BaseField,
+    src_root: P::BaseField,
+    nullifier_points: [GroupAffine<P>; 2],
+    dst_leaf_index: u64,
+    dst_leaves: [P::BaseField; 2],
+    src_leaf_indexes: [u64; 2],
+    src_balances: [u64; 2],
+    dst_amounts: [u64; 2],
+    secret: P::BaseField,
+    /// owner key of the first dst leaf, the second one goes back to the owner of src leaves
+    recipient: P::BaseField,
+    src_proofs: [LeafExistance<P::BaseField, FH, FHG>; 2],
+    auditor_pubkeys: Vec<GroupAffine<P>>,
+    commit: Option<[Commit<P, FH, FHG>; 2]>,
+    src_escrow: Option<[Escrow<P, FH, FHG>; 2]>,
+}
+
+impl<P, FH, FHG> ConstraintSynthesizer<P::BaseField> for TransferCircuit<P, FH, FHG>
+where
+    P: TEModelParameters,
+    FH: FieldHasher<P::BaseField>,
+    FHG: FieldHasherGadget<P::BaseField, FH>,
+    P::BaseField: PrimeField,
+{
+    fn generate_constraints(self, cs: ConstraintSystemRef<P::BaseField>) -> Result<()> {
+        // alloc constant
+        let nullifier_params = FHG::ParametersVar::new_constant(cs.clone(), self.nullifier_params)?;
+        let leaf_params = FHG::ParametersVar::new_constant(cs.clone(), self.leaf_params)?;
+
+        // alloc input
+        // withdraw amount bit size of 64 can verify in contract, so no need constrain in circuit
+        let withdraw_amount = FpVar::new_input(cs.clone(), || Ok(P::BaseField::from(self.withdraw_amount)))?;
+        // asset id is checked against the withdrawn mint in contract
+        let asset_id = FpVar::new_input(cs.clone(), || Ok(self.asset_id))?;
+        let _receiver_input = FpVar::new_input(cs.clone(), || Ok(self.receiver))?;
+        let src_root = FpVar::new_input(cs.clone(), || Ok(self.src_root))?;
+        let nullifier_points = self.nullifier_points
+            .iter()
+            .map(|point| AffineVar::<_, FpVar<P::BaseField>>::new_input(cs.clone(), || Ok(*point)))
+            .collect::<Result<Vec<_>>>()?;
+        let dst_leaf_index = FpVar::new_input(cs.clone(), || Ok(P::BaseField::from(self.dst_leaf_index)))?;
+        let dst_leaf_inputs = self.dst_leaves
+            .iter()
+            .map(|leaf| FpVar::new_input(cs.clone(), || Ok(*leaf)))
+            .collect::<Result<Vec<_>>>()?;
+
+        // alloc witness
+        let src_leaf_indexes = self.src_leaf_indexes
+            .iter()
+            .map(|index| FpVar::new_witness(cs.clone(), || Ok(P::BaseField::from(*index))))
+            .collect::<Result<Vec<_>>>()?;
+        let src_balances = self.src_balances
+            .iter()
+            .map(|balance| Uint64::new_witness(cs.clone(), || Ok(*balance)))
+            .collect::<Result<Vec<_>>>()?;
+        // dst amounts are constrained to 64 bits, so that no amount can be negative
+        let dst_amounts = self.dst_amounts
+            .iter()
+            .map(|amount| Uint64::new_witness(cs.clone(), || Ok(*amount)))
+            .collect::<Result<Vec<_>>>()?;
+        let secret = FpVar::new_witness(cs.clone(), || Ok(self.secret))?;
+        let recipient = FpVar::new_witness(cs.clone(), || Ok(self.recipient))?;
+
+        // owner key: hash(secret), only the holder of secret can spend notes of the owner
+        let owner = namespace(&cs, "owner", || FHG::hash_gadget(&nullifier_params, ark_std::slice::from_ref(&secret)))?;
+        // nullifier key: hash(secret | 1), nobody else can compute nullifiers of the owner
+        let nullifier_key_point = namespace(&cs, "nullifier_key", || {
+            let nullifier_key = FHG::hash_gadget(&nullifier_params, &[secret, FpVar::one()])?;
+            gen_nullifier_key_point_gadget::<P>(&nullifier_key)
+        })?;
+
+        // restrain sum of src balances equals to sum of dst amounts and withdraw amount
+        namespace(&cs, "amounts", || {
+            let src_amount = src_balances[0].fp_var() + src_balances[1].fp_var();
+            let dst_amount = dst_amounts[0].fp_var() + dst_amounts[1].fp_var() + &withdraw_amount;
+            src_amount.enforce_equal(&dst_amount)
+        })?;
+
+        // committed src notes are bound to the escrows of the nullifier key
+        let src_owners = match self.src_escrow {
+            Some(src_escrow) => {
+                let [owner_0, owner_1] = src_escrow.map(|escrow| namespace(&cs, "src_escrow", || {
+                    escrow.synthesize(cs.clone(), &nullifier_key_point, owner.clone())
+                }));
+                [owner_0?, owner_1?]
+            }
+            None => [owner.clone(), owner.clone()],
+        };
+
+        // prove src leaves existance and compute nullifiers
+        for ((((src_proof, src_leaf_index), balance), nullifier_point), src_owner) in self.src_proofs
+            .into_iter()
+            .zip(src_leaf_indexes)
+            .zip(src_balances.iter())
+            .zip(nullifier_points.iter())
+            .zip(src_owners)
+        {
+            // compute nullifier and mapping nullfier to curve point
+            namespace(&cs, "nullifier", || {
+                let point = gen_nullifier_point_gadget::<P, FH, FHG>(
+                    &nullifier_params,
+                    src_leaf_index.clone(),
+                    owner.clone(),
+                    &nullifier_key_point,
+                )?;
+                // constrain point = nullifier_point
+                point.enforce_equal(nullifier_point)
+            })?;
+
+            namespace(&cs, "src_leaf", || {
+                // hash leaf: hash(leaf_index | asset_id | balance | note owner)
+                let src_leaf = FHG::hash_gadget(
+                    &leaf_params,
+                    &[src_leaf_index.clone(), asset_id.clone(), balance.fp_var().clone(), src_owner],
+                )?;
+                // gen existance proof
+                src_proof.synthesize(
+                    cs.clone(),
+                    src_leaf_index,
+                    src_leaf,
+                    src_root.clone(),
+                )
+            })?;
+        }
+
+        // the leaves are inserted into the tree by the contract at the leaf indexes reserved for the transfer
+        let dst_leaf_indexes = [
+            dst_leaf_index.clone(),
+            dst_leaf_index + P::BaseField::from(1u64),
+        ];
+
+        // commit commitments, dst notes are bound to the escrows they are committed with
+        let dst_owners = [recipient, owner];
+        let dst_owners = match self.commit {
+            Some(commit) => namespace(&cs, "commit", || {
+                // both commitments are encrypted to the same auditor keys
+                let auditor_pubkeys = alloc_auditor_pubkeys(cs.clone(), &self.auditor_pubkeys)?;
+                let mut note_owners = Vec::with_capacity(2);
+                for ((commit, dst_leaf_index), dst_owner) in commit.into_iter().zip(dst_leaf_indexes.iter()).zip(dst_owners) {
+                    note_owners.push(commit.synthesize(cs.clone(), &auditor_pubkeys, dst_leaf_index.clone(), dst_owner)?);
+                }
+                Ok(note_owners)
+            })?,
+            None => dst_owners.to_vec(),
+        };
+
+        for (((dst_leaf_index, amount), dst_owner), dst_leaf_input) in dst_leaf_indexes
+            .into_iter()
+            .zip(dst_amounts.iter())
+            .zip(dst_owners)
+            .zip(dst_leaf_inputs.iter())
+        {
+            namespace(&cs, "dst_leaf", || {
+                // hash new leaf: hash(leaf_index | asset_id | amount | note owner)
+                let dst_leaf = FHG::hash_gadget(
+                    &leaf_params,
+                    &[dst_leaf_index, asset_id.clone(), amount.fp_var().clone(), dst_owner],
+                )?;
+                dst_leaf_input.enforce_equal(&dst_leaf)
+            })?;
+        }
+
+        Ok(())
+    }
+}
+
+impl<P, FH, FHG> TransferCircuit<P, FH, FHG>
+where
+    P: TEModelParameters,
+    FH: FieldHasher<P::BaseField>,
+    FHG: FieldHasherGadget<P::BaseField, FH>,
+    P::BaseField: PrimeField,
+{
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        nullifier_params: Rc<FH::Parameters>,
+        leaf_params: Rc<FH::Parameters>,
+        inner_params: Rc<FH::Parameters>,
+        withdraw_amount: u64,
+        asset_id: P::BaseField,
+        receiver: P::BaseField,
+        src_root: P::BaseField,
+        nullifier_points: [GroupAffine<P>; 2],
+        dst_leaf_index: u64,
+        dst_leaves: [P::BaseField; 2],
+        src_leaf_indexes: [u64; 2],
+        src_balances: [u64; 2],
+        dst_amounts: [u64; 2],
+        secret: P::BaseField,
+        recipient: P::BaseField,
+        src_neighbor_nodes: [Vec<(bool, P::BaseField)>; 2],
+        auditor_pubkeys: Vec<GroupAffine<P>>,
+        commit: Option<[Commit<P, FH, FHG>; 2]>,
+        src_escrow: Option<[Escrow<P, FH, FHG>; 2]>,
+    ) -> Self {
+        let [src_neighbor_nodes_0, src_neighbor_nodes_1] = src_neighbor_nodes;
+
+        Self {
+            nullifier_params,
+            leaf_params,
+            withdraw_amount,
+            asset_id,
+            receiver,
+            src_root,
+            nullifier_points,
+            dst_leaf_index,
+            dst_leaves,
+            src_leaf_indexes,
+            src_balances,
+            dst_amounts,
+            secret,
+            recipient,
+            src_proofs: [
+                LeafExistance::new(src_neighbor_nodes_0, inner_params.clone()),
+                LeafExistance::new(src_neighbor_nodes_1, inner_params),
+            ],
+            auditor_pubkeys,
+            commit,
+            src_escrow,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ed_on_bn254::{Fq as Fr, EdwardsParameters};
+    use ark_std::{rc::Rc, test_rng, UniformRand, rand::Rng};
+    use ark_relations::r1cs::{ConstraintSystem, ConstraintSystemRef, ConstraintSynthesizer};
+    use arkworks_utils::utils::common::{Curve, setup_params_x5_3, setup_params_x5_5};
+
+    use crate::circuits::poseidon::PoseidonHasherGadget;
+    use crate::vanilla::VanillaProof;
+    use crate::vanilla::hasher::{poseidon::PoseidonHasher, FieldHasher};
+    use crate::vanilla::transfer::{TransferConstParams, TransferOriginInputs, TransferVanillaProof, TransferPublicInputs};
+    use crate::vanilla::withdraw::gen_owner_key;
+    use super::TransferCircuit;
+
+    const HEIGHT: u8 = 24;
+
+    fn test_transfer_inner<R: Rng + ?Sized>(
+        rng: &mut R,
+        src_balances: [u64; 2],
+        dst_amounts: [u64; 2],
+        withdraw_amount: u64,
+        dst_leaf_index: u64,
+        recipient: Option<Fr>,
+        tamper: impl FnOnce(&mut TransferPublicInputs<EdwardsParameters>),
+    ) -> ConstraintSystemRef<Fr> {
+        let nullifier_params = setup_params_x5_3(Curve::Bn254);
+        let leaf_params = setup_params_x5_5::<Fr>(Curve::Bn254);
+        let inner_params = setup_params_x5_3::<Fr>(Curve::Bn254);
+        let asset_id = Fr::from(1u64);
+        let secret = Fr::rand(rng);
+        let owner = gen_owner_key::<_, PoseidonHasher<_>>(&nullifier_params, secret).unwrap();
+
+        // src leaves at index 0 and 1
+        let src_leaves = [0u64, 1]
+            .iter()
+            .zip(src_balances)
+            .map(|(index, balance)| {
+                PoseidonHasher::hash(&leaf_params, &[Fr::from(*index), asset_id, Fr::from(balance), owner]).unwrap()
+            })
+            .collect::<Vec<_>>();
+        let mut blank_nodes = vec![PoseidonHasher::empty_hash()];
+        for layer in 0..(HEIGHT as usize - 1) {
+            let node = PoseidonHasher::hash_two(&inner_params, blank_nodes[layer], blank_nodes[layer]).unwrap();
+            blank_nodes.push(node);
+        }
+        let mut src_neighbor_nodes_0 = blank_nodes.clone();
+        src_neighbor_nodes_0[0] = src_leaves[1];
+        let mut src_neighbor_nodes_1 = blank_nodes.clone();
+        src_neighbor_nodes_1[0] = src_leaves[0];
+
+        let params = TransferConstParams::<EdwardsParameters, _> {
+            nullifier_params: Rc::new(nullifier_params),
+            leaf_params: Rc::new(leaf_params),
+            inner_params: Rc::new(inner_params),
+            height: HEIGHT as usize,
+            commit: None,
+        };
+        let orig_in = TransferOriginInputs::<EdwardsParameters> {
+            src_balances,
+            src_leaf_indexes: [0, 1],
+            dst_amounts,
+            withdraw_amount,
+            asset_id,
+            dst_leaf_index,
+            receiver: Fr::rand(rng),
+            secret,
+            recipient: recipient.unwrap_or(owner),
+            src_neighbor_nodes: [src_neighbor_nodes_0, src_neighbor_nodes_1],
+            commit: None,
+            src_escrow: None,
+        };
+        let (mut pub_in, priv_in) = TransferVanillaProof::<_, PoseidonHasher<_>>::generate_vanilla_proof(
+            &params,
+            &orig_in,
+        ).unwrap();
+        tamper(&mut pub_in);
+
+        let transfer = TransferCircuit::<EdwardsParameters, _, PoseidonHasherGadget<_>>::new(
+            params.nullifier_params,
+            params.leaf_params,
+            params.inner_params,
+            pub_in.withdraw_amount,
+            pub_in.asset_id,
+            pub_in.receiver,
+            pub_in.src_root,
+            pub_in.nullifier_points,
+            pub_in.dst_leaf_index,
+            pub_in.dst_leaves,
+            priv_in.src_leaf_indexes,
+            priv_in.src_balances,
+            priv_in.dst_amounts,
+            priv_in.secret,
+            priv_in.recipient,
+            priv_in.src_neighbor_nodes,
+            pub_in.auditor_pubkeys,
+            None,
+            None,
+        );
+        let cs = ConstraintSystem::<_>::new_ref();
+        transfer.generate_constraints(cs.clone()).unwrap();
+
+        cs
+    }
+
+    #[test]
+    fn test_transfer() {
+        let rng = &mut test_rng();
+        let src_balances = [u64::rand(rng) >> 1, u64::rand(rng) >> 1];
+        let total = src_balances[0] + src_balances[1];
+
+        // merge two notes into one
+        let cs = test_transfer_inner(rng, src_balances, [total, 0], 0, 2, None, |_| {});
+        assert!(cs.is_satisfied().unwrap());
+        println!("constraints: {}", cs.num_constraints());
+
+        // split notes with a public withdrawal, dst leaves across subtrees
+        let withdraw_amount = total / 3;
+        let dst_amounts = [total / 3, total - total / 3 - withdraw_amount];
+        let cs = test_transfer_inner(rng, src_balances, dst_amounts, withdraw_amount, 7, None, |_| {});
+        assert!(cs.is_satisfied().unwrap());
+
+        // withdraw amount is changed, so amounts are not conserved
+        let cs = test_transfer_inner(rng, src_balances, dst_amounts, withdraw_amount, 3, None, |pub_in| {
+            pub_in.withdraw_amount += 1;
+        });
+        assert!(!cs.is_satisfied().unwrap());
+
+        // nullifiers are swapped
+        let cs = test_transfer_inner(rng, src_balances, dst_amounts, withdraw_amount, 3, None, |pub_in| {
+            pub_in.nullifier_points.swap(0, 1);
+        });
+        assert!(!cs.is_satisfied().unwrap());
+
+        // notes of another asset are claimed
+        let cs = test_transfer_inner(rng, src_balances, dst_amounts, withdraw_amount, 3, None, |pub_in| {
+            pub_in.asset_id = Fr::from(2u64);
+        });
+        assert!(!cs.is_satisfied().unwrap());
+    }
+    #[test]
+    fn test_transfer_to_recipient() {
+        let rng = &mut test_rng();
+        let nullifier_params = setup_params_x5_3(Curve::Bn254);
+        let leaf_params = setup_params_x5_5::<Fr>(Curve::Bn254);
+        let src_balances = [u64::rand(rng) >> 1, u64::rand(rng) >> 1];
+        let dst_amounts = [src_balances[0], src_balances[1]];
+
+        // the first dst leaf is bound to the owner key of the recipient
+        let recipient = gen_owner_key::<_, PoseidonHasher<_>>(&nullifier_params, Fr::rand(rng)).unwrap();
+        let cs = test_transfer_inner(rng, src_balances, dst_amounts, 0, 2, Some(recipient), |pub_in| {
+            let leaf = PoseidonHasher::hash(&leaf_params, &[Fr::from(2u64), pub_in.asset_id, Fr::from(dst_amounts[0]), recipient]).unwrap();
+            assert_eq!(pub_in.dst_leaves[0], leaf);
+        });
+        assert!(cs.is_satisfied().unwrap());
+    }
+}
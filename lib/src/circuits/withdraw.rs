@@ -1,17 +1,52 @@
-use ark_ec::ProjectiveCurve;
-use ark_ec::twisted_edwards_extended::GroupProjective;
 use ark_ec::{twisted_edwards_extended::GroupAffine, TEModelParameters};
 use ark_std::{cmp::Ordering, rc::Rc};
-use ark_ff::{PrimeField, FpParameters};
-use ark_r1cs_std::{groups::curves::twisted_edwards::AffineVar, ToBitsGadget};
-use ark_r1cs_std::{fields::fp::FpVar, eq::EqGadget, alloc::AllocVar, groups::CurveVar};
+use ark_ff::PrimeField;
+use ark_r1cs_std::groups::curves::twisted_edwards::AffineVar;
+use ark_r1cs_std::{fields::{fp::FpVar, FieldVar}, eq::EqGadget, alloc::AllocVar, groups::CurveVar};
 use ark_relations::r1cs::{ConstraintSystemRef, ConstraintSynthesizer, Result};
 
 use crate::vanilla::hasher::FieldHasher;
-use super::{FieldHasherGadget, Commit};
-use super::merkle::{AddNewLeaf, LeafExistance};
+use super::{FieldHasherGadget, Commit, Escrow, alloc_auditor_pubkeys, namespace};
+use super::commit::{generator_bases, to_scalar_bits};
+use super::merkle::{LeafExistance, NonMembership, Membership};
 use super::uint64::Uint64;
 
+/// Nullifier key point: nullifier_key * G.
+pub(super) fn gen_nullifier_key_point_gadget<P>(
+    nullifier_key: &FpVar<P::BaseField>,
+) -> Result<AffineVar<P, FpVar<P::BaseField>>>
+where
+    P: TEModelParameters,
+    P::BaseField: PrimeField,
+{
+    let nullifier_key = to_scalar_bits::<P>(nullifier_key)?;
+
+    let mut point = AffineVar::zero();
+    point.precomputed_base_scalar_mul_le(nullifier_key.iter().zip(generator_bases::<P>().iter()))?;
+
+    Ok(point)
+}
+
+pub(super) fn gen_nullifier_point_gadget<P, FH, FHG>(
+    nullifier_params: &FHG::ParametersVar,
+    leaf_index: FpVar<P::BaseField>,
+    owner: FpVar<P::BaseField>,
+    nullifier_key_point: &AffineVar<P, FpVar<P::BaseField>>,
+) -> Result<AffineVar<P, FpVar<P::BaseField>>>
+where
+    P: TEModelParameters,
+    FH: FieldHasher<P::BaseField>,
+    FHG: FieldHasherGadget<P::BaseField, FH>,
+    P::BaseField: PrimeField,
+{
+    // hash nullifier scalar: hash(leaf_index | owner)
+    let nullifier = FHG::hash_gadget(nullifier_params, &[leaf_index, owner])?;
+    // nullifier_point = nullifier * nullifier_key * G
+    let nullifier = to_scalar_bits::<P>(&nullifier)?;
+
+    nullifier_key_point.scalar_mul_le(nullifier.iter())
+}
+
 pub struct WithdrawCircuit<P, FH, FHG>
 where
     P: TEModelParameters,
@@ -23,16 +58,25 @@ where
     leaf_params: Rc<FH::Parameters>,
     src_leaf_index: u64,
     dst_leaf_index: u64,
+    min_anonymity_gap: u64,
     balance: u64,
     withdraw_amount: u64,
+    asset_id: P::BaseField,
     receiver: P::BaseField,
+    relayer: P::BaseField,
+    fee: u64,
     secret: P::BaseField,
-    prev_root: P::BaseField,
+    src_root: P::BaseField,
     dst_leaf: P::BaseField,
     nullifier_point: GroupAffine<P>,
     src_proof: LeafExistance<P::BaseField, FH, FHG>,
-    dst_proof: AddNewLeaf<P::BaseField, FH, FHG>,
+    auditor_pubkeys: Vec<GroupAffine<P>>,
     commit: Option<Commit<P, FH, FHG>>,
+    src_escrow: Option<Escrow<P, FH, FHG>>,
+    exclusion_root: Option<P::BaseField>,
+    exclusion_proof: Option<NonMembership<P::BaseField, FH, FHG>>,
+    allowlist_root: Option<P::BaseField>,
+    allowlist_proof: Option<Membership<P::BaseField, FH, FHG>>,
 }
 
 impl<P, FH, FHG> ConstraintSynthesizer<P::BaseField> for WithdrawCircuit<P, FH, FHG>
@@ -50,10 +94,20 @@ where
         // alloc input
         // withdraw amount bit size of 64 can verify in contract, so no need constrain in circuit
         let withdraw_amount = FpVar::new_input(cs.clone(), || Ok(P::BaseField::from(self.withdraw_amount)))?;
+        // asset id is checked against the withdrawn mint in contract
+        let asset_id = FpVar::new_input(cs.clone(), || Ok(self.asset_id))?;
         let _receiver_input = FpVar::new_input(cs.clone(), || Ok(self.receiver))?;
+        // relayer and fee are bound to the proof, so nobody else can take over the fee
+        let _relayer_input = FpVar::new_input(cs.clone(), || Ok(self.relayer))?;
+        // fee bit size of 64 can verify in contract, so no need constrain in circuit
+        let fee = FpVar::new_input(cs.clone(), || Ok(P::BaseField::from(self.fee)))?;
         let dst_leaf_index = FpVar::new_input(cs.clone(), || Ok(P::BaseField::from(self.dst_leaf_index)))?;
+        // gap bit size of 64 can verify in contract, so no need constrain in circuit
+        let min_anonymity_gap = FpVar::new_input(cs.clone(), || Ok(P::BaseField::from(self.min_anonymity_gap)))?;
+        // the dst leaf is inserted into the tree by the contract at the leaf index reserved for the withdrawal
         let dst_leaf_input = FpVar::new_input(cs.clone(), || Ok(self.dst_leaf))?;
-        let prev_root = FpVar::new_input(cs.clone(), || Ok(self.prev_root))?;
+        // src root can be any recent root of the tree
+        let src_root = FpVar::new_input(cs.clone(), || Ok(self.src_root))?;
         let nullifier_point = AffineVar::<_, FpVar<P::BaseField>>::new_input(cs.clone(), || Ok(self.nullifier_point))?;
 
         // alloc witness
@@ -61,72 +115,109 @@ where
         let balance = Uint64::new_witness(cs.clone(), || Ok(self.balance))?;
         let secret = FpVar::new_witness(cs.clone(), || Ok(self.secret))?;
 
+        // owner key: hash(secret), only the holder of secret can spend notes of the owner
+        let owner = namespace(&cs, "owner", || FHG::hash_gadget(&nullifier_params, ark_std::slice::from_ref(&secret)))?;
+        // nullifier key: hash(secret | 1), nobody else can compute nullifiers of the owner
+        let nullifier_key_point = namespace(&cs, "nullifier_key", || {
+            let nullifier_key = FHG::hash_gadget(&nullifier_params, &[secret, FpVar::one()])?;
+            gen_nullifier_key_point_gadget::<P>(&nullifier_key)
+        })?;
+
         // restrain withdraw amount is less and equal than balance
         let balance = balance.fp_var().clone();
-        balance.enforce_cmp_unchecked(
-            &withdraw_amount,
-            Ordering::Greater,
-            true,
-        )?;
+        namespace(&cs, "amounts", || {
+            balance.enforce_cmp_unchecked(
+                &withdraw_amount,
+                Ordering::Greater,
+                true,
+            )?;
+            // restrain fee is less and equal than withdraw amount
+            withdraw_amount.enforce_cmp_unchecked(
+                &fee,
+                Ordering::Greater,
+                true,
+            )
+        })?;
         let rest_amount = &balance - withdraw_amount;
 
-        // compute nullifier and mapping nullfier to curve point
-        {
-            let scalar_bits = <<P::ScalarField as PrimeField>::Params as FpParameters>::CAPACITY as usize;
+        // restrain dst leaf index - src leaf index >= min anonymity gap, src leaf index is bound to path bits
+        // and dst leaf index is checked against the tree height in contract
+        namespace(&cs, "anonymity_gap", || {
+            dst_leaf_index.enforce_cmp_unchecked(
+                &(&src_leaf_index + &min_anonymity_gap),
+                Ordering::Greater,
+                true,
+            )
+        })?;
 
-            // hash nullifier: hash(leaf_index | secret)
-            let nullifier = FHG::hash_gadget(
+        // compute nullifier and mapping nullfier to curve point
+        namespace(&cs, "nullifier", || {
+            let point = gen_nullifier_point_gadget::<P, FH, FHG>(
                 &nullifier_params,
-                &[src_leaf_index.clone(), secret.clone()],
+                src_leaf_index.clone(),
+                owner.clone(),
+                &nullifier_key_point,
             )?;
-            // nullifier_point = nullifier * G
-            let mut nullifier = nullifier.to_bits_le()?;
-            nullifier.truncate(scalar_bits);
-
-            let mut base = GroupProjective::prime_subgroup_generator();
-            let mut bases = Vec::with_capacity(scalar_bits);
-            for _ in 0..scalar_bits {
-                bases.push(base);
-                base.double_in_place();
-            }
-
-            let mut point = AffineVar::zero();
-            point.precomputed_base_scalar_mul_le(nullifier.iter().zip(bases.iter()))?;
             // constrain point = nullifier_point
-            point.enforce_equal(&nullifier_point)?;
-        }
+            point.enforce_equal(&nullifier_point)
+        })?;
+
+        // a committed src note is bound to the escrow of the nullifier key
+        let src_owner = match self.src_escrow {
+            Some(escrow) => namespace(&cs, "src_escrow", || {
+                escrow.synthesize(cs.clone(), &nullifier_key_point, owner.clone())
+            })?,
+            None => owner.clone(),
+        };
 
         // prove src leaf existance
-        {
-            // hash leaf: hash(leaf_index | balance | secret)
+        namespace(&cs, "src_leaf", || {
+            // hash leaf: hash(leaf_index | asset_id | balance | note owner)
             let src_leaf = FHG::hash_gadget(
                 &leaf_params,
-                &[src_leaf_index.clone(), balance, secret.clone()],
+                &[src_leaf_index.clone(), asset_id.clone(), balance, src_owner],
             )?;
             // gen existance proof
             self.src_proof.synthesize(
                 cs.clone(),
-                src_leaf_index,
+                src_leaf_index.clone(),
                 src_leaf,
-                prev_root.clone(),
-            )?;
-        }
+                src_root,
+            )
+        })?;
 
-        // insert assets into a new leaf 
-        {
-            // hash new back deposit data leaf: hash(leaf_index | rest_amount | secret)
+        // commit commitment, the rest note is bound to the escrow it is committed with
+        let dst_owner = match self.commit {
+            Some(commit) => namespace(&cs, "commit", || {
+                let auditor_pubkeys = alloc_auditor_pubkeys(cs.clone(), &self.auditor_pubkeys)?;
+                commit.synthesize(cs.clone(), &auditor_pubkeys, dst_leaf_index.clone(), owner.clone())
+            })?,
+            None => owner.clone(),
+        };
+
+        namespace(&cs, "dst_leaf", || {
+            // hash new back deposit data leaf: hash(leaf_index | asset_id | rest_amount | note owner)
             let dst_leaf = FHG::hash_gadget(
                 &leaf_params,
-                &[dst_leaf_index.clone(), rest_amount, secret.clone()],
+                &[dst_leaf_index, asset_id, rest_amount, dst_owner],
             )?;
-            dst_leaf_input.enforce_equal(&dst_leaf)?;
-            // gen add new leaf proof
-            self.dst_proof.synthesize(cs.clone(), dst_leaf_index.clone(), dst_leaf, prev_root)?;
+            dst_leaf_input.enforce_equal(&dst_leaf)
+        })?;
+
+        // src leaf index is not in the exclusion set published by the curator
+        if let Some((exclusion_root, exclusion_proof)) = self.exclusion_root.zip(self.exclusion_proof) {
+            namespace(&cs, "exclusion", || {
+                let exclusion_root = FpVar::new_input(cs.clone(), || Ok(exclusion_root))?;
+                exclusion_proof.synthesize(cs.clone(), src_leaf_index.clone(), exclusion_root)
+            })?;
         }
 
-        // commit commitment
-        if let Some(commit) = self.commit {
-            commit.synthesize(cs, dst_leaf_index, secret)?;
+        // owner key is in an allowlist, such as a set of depositors the vault associates with
+        if let Some((allowlist_root, allowlist_proof)) = self.allowlist_root.zip(self.allowlist_proof) {
+            namespace(&cs, "allowlist", || {
+                let allowlist_root = FpVar::new_input(cs.clone(), || Ok(allowlist_root))?;
+                allowlist_proof.synthesize(cs.clone(), owner, allowlist_root)
+            })?;
         }
 
         Ok(())
@@ -146,57 +237,81 @@ where
         leaf_params: Rc<FH::Parameters>,
         inner_params: Rc<FH::Parameters>,
         withdraw_amount: u64,
+        asset_id: P::BaseField,
         receiver: P::BaseField,
+        relayer: P::BaseField,
+        fee: u64,
         dst_leaf_index: u64,
+        min_anonymity_gap: u64,
         dst_leaf: P::BaseField,
-        prev_root: P::BaseField,
+        src_root: P::BaseField,
         nullifier_point: GroupAffine<P>,
-        update_nodes: Vec<P::BaseField>,
         src_leaf_index: u64,
         balance: u64,
         secret: P::BaseField,
         src_neighbor_nodes: Vec<(bool, P::BaseField)>,
-        dst_neighbor_nodes: Vec<(bool, P::BaseField)>,
+        auditor_pubkeys: Vec<GroupAffine<P>>,
         commit: Option<Commit<P, FH, FHG>>,
+        src_escrow: Option<Escrow<P, FH, FHG>>,
+        exclusion_root: Option<P::BaseField>,
+        exclusion_neighbor_nodes: Option<Vec<(bool, P::BaseField)>>,
+        allowlist_root: Option<P::BaseField>,
+        allowlist_neighbor_nodes: Option<Vec<(bool, P::BaseField)>>,
     ) -> Self {
         Self {
             nullifier_params,
             leaf_params,
             src_leaf_index,
             dst_leaf_index,
+            min_anonymity_gap,
             balance,
             withdraw_amount,
+            asset_id,
             receiver,
+            relayer,
+            fee,
             secret,
-            prev_root,
+            src_root,
             dst_leaf,
             nullifier_point,
             src_proof: LeafExistance::new(
                 src_neighbor_nodes,
                 inner_params.clone(),
             ),
-            dst_proof: AddNewLeaf::new(
-                dst_neighbor_nodes,
-                update_nodes,
-                inner_params,
-            ),
+            auditor_pubkeys,
             commit,
+            src_escrow,
+            exclusion_root,
+            exclusion_proof: exclusion_neighbor_nodes.map(|neighbor_nodes| NonMembership::new(neighbor_nodes, inner_params.clone())),
+            allowlist_root,
+            allowlist_proof: allowlist_neighbor_nodes.map(|neighbor_nodes| Membership::new(neighbor_nodes, inner_params)),
         }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use ark_ed_on_bn254::{Fq as Fr, EdwardsParameters};
+    use ark_bn254::Bn254;
+    use ark_ec::{twisted_edwards_extended::GroupProjective, ProjectiveCurve, AffineCurve};
+    use ark_ed_on_bn254::{Fq as Fr, Fr as Fq, EdwardsParameters};
+    use ark_ff::PrimeField;
+    use ark_groth16::Groth16;
     use ark_std::{rc::Rc, test_rng, UniformRand, rand::Rng};
     use ark_relations::r1cs::{ConstraintSystem, ConstraintSystemRef, ConstraintSynthesizer};
-	use arkworks_utils::utils::common::{Curve, setup_params_x5_3, setup_params_x5_4};
+	use arkworks_utils::utils::common::{Curve, setup_params_x5_3, setup_params_x5_5};
     use bitvec::{prelude::BitVec, field::BitField};
+    use num_traits::Zero;
 
     use crate::circuits::poseidon::PoseidonHasherGadget;
     use crate::vanilla::VanillaProof;
+    use crate::vanilla::merkle::gen_merkle_path;
     use crate::vanilla::hasher::{poseidon::PoseidonHasher, FieldHasher};
-    use crate::vanilla::withdraw::{WithdrawConstParams, WithdrawOriginInputs, WithdrawVanillaProof};
+    use crate::vanilla::withdraw::{WithdrawConstParams, WithdrawOriginInputs, WithdrawVanillaProof, gen_owner_key, gen_nullifier_key, gen_nullifier_point};
+    use crate::vanilla::commit::{CommitConstParams, CommitOriginInputs, EscrowOriginInputs, gen_escrow, gen_note_owner};
+    use crate::circuits::profile;
+    use crate::error::MazeLibError;
+    use crate::proof::{ProofScheme, scheme::WithdrawProof};
+    use crate::vanilla::index_set::{IndexSetTree, KeySetTree};
     use super::WithdrawCircuit;
 
     const HEIGHT: u8 = 24;
@@ -212,25 +327,28 @@ mod tests {
         (indexes, neighbor_nodes)
     }
 
-    fn test_withdraw_inner<R: Rng + ?Sized>(rng: &mut R, balance: u64, withdraw_amount: u64) -> ConstraintSystemRef<Fr> {
+    fn test_withdraw_inner<R: Rng + ?Sized>(
+        rng: &mut R,
+        balance: u64,
+        withdraw_amount: u64,
+        fee: u64,
+        min_anonymity_gap: u64,
+        other_asset: bool,
+    ) -> ConstraintSystemRef<Fr> {
         let nullifier_params = setup_params_x5_3(Curve::Bn254);
-        let leaf_params = setup_params_x5_4::<Fr>(Curve::Bn254);
+        let leaf_params = setup_params_x5_5::<Fr>(Curve::Bn254);
         let inner_params = setup_params_x5_3(Curve::Bn254);
         // withdraw data
         let secret = Fr::rand(rng);
+        let asset_id = Fr::rand(rng);
         let receiver = Fr::rand(rng);
+        let relayer = Fr::rand(rng);
 
         let (mut src_indexes, mut src_neighbor_nodes) = get_random_merkle_neighbors(rng);
         src_indexes[0] = false;
         src_neighbor_nodes[0] = PoseidonHasher::empty_hash();
         let src_leaf_index = BitVec::<u8>::from_iter(src_indexes).load_le::<u64>();
-        let src_leaf = PoseidonHasher::hash(
-            &leaf_params,
-            &[Fr::from(src_leaf_index), Fr::from(balance), secret],
-        ).unwrap();
 
-        let mut dst_neighbor_nodes = src_neighbor_nodes.clone();
-        dst_neighbor_nodes[0] = src_leaf;
         let dst_leaf_index = src_leaf_index + 1;
 
         let params = WithdrawConstParams::<EdwardsParameters, _> {
@@ -239,40 +357,64 @@ mod tests {
             inner_params: Rc::new(inner_params),
             height: HEIGHT as usize,
             commit: None,
+            exclusion: false,
+            allowlist: false,
         };
         let orig_in = WithdrawOriginInputs::<EdwardsParameters> {
             balance,
-            withdraw_amount,
+            withdraw_amount: withdraw_amount.min(balance),
+            asset_id,
             src_leaf_index,
             dst_leaf_index,
+            min_anonymity_gap: 0,
             receiver,
+            relayer,
+            fee: fee.min(withdraw_amount.min(balance)),
             secret,
             src_neighbor_nodes,
-            dst_neighbor_nodes,
             commit: None,
+            src_escrow: None,
+            exclusion_neighbor_nodes: None,
+            allowlist_neighbor_nodes: None,
         };
         // generate vanilla proof
-        let (pub_in, priv_in) = WithdrawVanillaProof::<_, PoseidonHasher<_>>::generate_vanilla_proof(
+        let (mut pub_in, priv_in) = WithdrawVanillaProof::<_, PoseidonHasher<_>>::generate_vanilla_proof(
             &params,
             &orig_in,
         ).unwrap();
+        // fee, amount and gap are claimed out of the proof to test the circuit constraints
+        pub_in.fee = fee;
+        pub_in.withdraw_amount = withdraw_amount;
+        pub_in.min_anonymity_gap = min_anonymity_gap;
+        // asset is claimed out of the proof to test the asset binding
+        if other_asset {
+            pub_in.asset_id = Fr::rand(rng);
+        }
 
         let withdrawal = WithdrawCircuit::<EdwardsParameters, _, PoseidonHasherGadget<_>>::new(
             params.nullifier_params,
             params.leaf_params,
             params.inner_params,
             pub_in.withdraw_amount,
+            pub_in.asset_id,
             pub_in.receiver,
+            pub_in.relayer,
+            pub_in.fee,
             pub_in.dst_leaf_index,
+            pub_in.min_anonymity_gap,
             pub_in.dst_leaf,
-            pub_in.prev_root,
+            pub_in.src_root,
             pub_in.nullifier_point,
-            pub_in.update_nodes.clone(),
             priv_in.src_leaf_index,
             priv_in.balance,
             priv_in.secret,
             priv_in.src_neighbor_nodes,
-            priv_in.dst_neighbor_nodes,
+            pub_in.auditor_pubkeys,
+            None,
+            None,
+            None,
+            None,
+            None,
             None,
         );
         // generate snark proof
@@ -288,16 +430,362 @@ mod tests {
         let deposit_amount = u64::rand(rng);
 
         let withdraw_amount = deposit_amount;
-        let cs = test_withdraw_inner(rng, deposit_amount, withdraw_amount);
+        let cs = test_withdraw_inner(rng, deposit_amount, withdraw_amount, 0, 0, false);
         assert!(cs.is_satisfied().unwrap());
         println!("constraints: {}", cs.num_constraints());
 
         let withdraw_amount = deposit_amount - deposit_amount / 2;
-        let cs = test_withdraw_inner(rng, deposit_amount, withdraw_amount);
+        let cs = test_withdraw_inner(rng, deposit_amount, withdraw_amount, withdraw_amount / 3, 0, false);
+        assert!(cs.is_satisfied().unwrap());
+
+        let cs = test_withdraw_inner(rng, deposit_amount, withdraw_amount, withdraw_amount, 0, false);
         assert!(cs.is_satisfied().unwrap());
 
+        let cs = test_withdraw_inner(rng, deposit_amount, withdraw_amount, withdraw_amount + 1, 0, false);
+        assert!(!cs.is_satisfied().unwrap());
+
         let withdraw_amount = deposit_amount + 1;
-        let cs = test_withdraw_inner(rng, deposit_amount, withdraw_amount);
+        let cs = test_withdraw_inner(rng, deposit_amount, withdraw_amount, 0, 0, false);
         assert!(!cs.is_satisfied().unwrap());
+
+        let withdraw_amount = deposit_amount;
+        let cs = test_withdraw_inner(rng, deposit_amount, withdraw_amount, 0, 0, true);
+        assert!(!cs.is_satisfied().unwrap());
+
+        // dst leaf is right after src leaf
+        let cs = test_withdraw_inner(rng, deposit_amount, withdraw_amount, 0, 1, false);
+        assert!(cs.is_satisfied().unwrap());
+
+        let cs = test_withdraw_inner(rng, deposit_amount, withdraw_amount, 0, 2, false);
+        assert!(!cs.is_satisfied().unwrap());
+    }
+
+    #[test]
+    fn test_withdraw_from_history_root() {
+        let rng = &mut test_rng();
+        let nullifier_params = setup_params_x5_3(Curve::Bn254);
+        let leaf_params = setup_params_x5_5::<Fr>(Curve::Bn254);
+        let inner_params = setup_params_x5_3::<Fr>(Curve::Bn254);
+        let secret = Fr::rand(rng);
+        let owner = gen_owner_key::<_, PoseidonHasher<_>>(&nullifier_params, secret).unwrap();
+        let balance = u64::rand(rng);
+        let asset_id = Fr::rand(rng);
+
+        let mut blank_nodes = vec![PoseidonHasher::empty_hash()];
+        for layer in 0..(HEIGHT as usize - 1) {
+            let node = PoseidonHasher::hash_two(&inner_params, blank_nodes[layer], blank_nodes[layer]).unwrap();
+            blank_nodes.push(node);
+        }
+
+        // src leaf at index 0, then another user inserts a leaf at index 1
+        let src_leaf = PoseidonHasher::hash(
+            &leaf_params,
+            &[Fr::from(0u64), asset_id, Fr::from(balance), owner],
+        ).unwrap();
+        let other_leaf = Fr::rand(rng);
+        let src_neighbor_nodes = blank_nodes.clone();
+
+        let params = WithdrawConstParams::<EdwardsParameters, _> {
+            nullifier_params: Rc::new(nullifier_params),
+            leaf_params: Rc::new(leaf_params),
+            inner_params: Rc::new(inner_params),
+            height: HEIGHT as usize,
+            commit: None,
+            exclusion: false,
+            allowlist: false,
+        };
+        let orig_in = WithdrawOriginInputs::<EdwardsParameters> {
+            balance,
+            withdraw_amount: balance,
+            asset_id,
+            src_leaf_index: 0,
+            dst_leaf_index: 2,
+            min_anonymity_gap: 2,
+            receiver: Fr::rand(rng),
+            relayer: Fr::rand(rng),
+            fee: 0,
+            secret,
+            src_neighbor_nodes,
+            commit: None,
+            src_escrow: None,
+            exclusion_neighbor_nodes: None,
+            allowlist_neighbor_nodes: None,
+        };
+        let (pub_in, priv_in) = WithdrawVanillaProof::<_, PoseidonHasher<_>>::generate_vanilla_proof(
+            &params,
+            &orig_in,
+        ).unwrap();
+
+        // src root is the stale root before the other leaf was inserted
+        let neighbors = blank_nodes.iter().map(|node| (false, *node)).collect::<Vec<_>>();
+        let stale_root = *gen_merkle_path::<_, PoseidonHasher<Fr>>(&params.inner_params, &neighbors, src_leaf)
+            .unwrap()
+            .last()
+            .unwrap();
+        assert_eq!(pub_in.src_root, stale_root);
+        let mut neighbors = neighbors;
+        neighbors[0] = (false, other_leaf);
+        let root = *gen_merkle_path::<_, PoseidonHasher<Fr>>(&params.inner_params, &neighbors, src_leaf)
+            .unwrap()
+            .last()
+            .unwrap();
+        assert_ne!(pub_in.src_root, root);
+
+        let withdrawal = WithdrawCircuit::<EdwardsParameters, _, PoseidonHasherGadget<_>>::new(
+            params.nullifier_params,
+            params.leaf_params,
+            params.inner_params,
+            pub_in.withdraw_amount,
+            pub_in.asset_id,
+            pub_in.receiver,
+            pub_in.relayer,
+            pub_in.fee,
+            pub_in.dst_leaf_index,
+            pub_in.min_anonymity_gap,
+            pub_in.dst_leaf,
+            pub_in.src_root,
+            pub_in.nullifier_point,
+            priv_in.src_leaf_index,
+            priv_in.balance,
+            priv_in.secret,
+            priv_in.src_neighbor_nodes,
+            pub_in.auditor_pubkeys,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+        let cs = ConstraintSystem::<_>::new_ref();
+        withdrawal.generate_constraints(cs.clone()).unwrap();
+
+        assert!(cs.is_satisfied().unwrap());
+    }
+
+    #[test]
+    fn test_withdraw_index_sets() {
+        let rng = &mut test_rng();
+        let inner_params = Rc::new(setup_params_x5_3::<Fr>(Curve::Bn254));
+        let params = WithdrawConstParams::<EdwardsParameters, PoseidonHasher<Fr>> {
+            nullifier_params: Rc::new(setup_params_x5_3(Curve::Bn254)),
+            leaf_params: Rc::new(setup_params_x5_5(Curve::Bn254)),
+            inner_params: inner_params.clone(),
+            height: HEIGHT as usize,
+            commit: None,
+            exclusion: true,
+            allowlist: true,
+        };
+        let secret = Fr::rand(rng);
+        let owner = gen_owner_key::<_, PoseidonHasher<_>>(&params.nullifier_params, secret).unwrap();
+        let asset_id = Fr::rand(rng);
+        let balance = u64::rand(rng);
+
+        // src leaf at index 0, withdraw into index 1
+        let mut blank_nodes = vec![PoseidonHasher::empty_hash()];
+        for layer in 0..(HEIGHT as usize - 1) {
+            blank_nodes.push(PoseidonHasher::hash_two(&inner_params, blank_nodes[layer], blank_nodes[layer]).unwrap());
+        }
+
+        let mut blocklist = IndexSetTree::<_, PoseidonHasher<Fr>>::new(inner_params.clone(), HEIGHT as usize).unwrap();
+        blocklist.insert(1).unwrap();
+        blocklist.insert(9).unwrap();
+        let mut allowlist = KeySetTree::<_, PoseidonHasher<Fr>>::new(inner_params, HEIGHT as usize).unwrap();
+        allowlist.insert(Fr::rand(rng)).unwrap();
+        allowlist.insert(owner).unwrap();
+        let orig_in = WithdrawOriginInputs::<EdwardsParameters> {
+            balance,
+            withdraw_amount: balance,
+            asset_id,
+            src_leaf_index: 0,
+            dst_leaf_index: 1,
+            min_anonymity_gap: 0,
+            receiver: Fr::rand(rng),
+            relayer: Fr::rand(rng),
+            fee: 0,
+            secret,
+            src_neighbor_nodes: blank_nodes,
+            commit: None,
+            src_escrow: None,
+            exclusion_neighbor_nodes: Some(blocklist.gen_non_membership_neighbor_nodes(0).unwrap()),
+            allowlist_neighbor_nodes: Some(allowlist.gen_membership_neighbor_nodes(&owner).unwrap()),
+        };
+        let is_satisfied = |pub_in, priv_in| {
+            let withdrawal = WithdrawProof::<_, _, PoseidonHasherGadget<_>, Groth16<Bn254>>::generate_circuit(&params, &pub_in, &priv_in).unwrap();
+            let cs = ConstraintSystem::<_>::new_ref();
+            withdrawal.generate_constraints(cs.clone()).unwrap();
+            cs.is_satisfied().unwrap()
+        };
+        let prove = || WithdrawVanillaProof::<_, PoseidonHasher<_>>::generate_vanilla_proof(&params, &orig_in).unwrap();
+
+        let (pub_in, priv_in) = prove();
+        assert_eq!(pub_in.exclusion_root, Some(blocklist.root()));
+        assert_eq!(pub_in.allowlist_root, Some(allowlist.root()));
+        assert!(is_satisfied(pub_in, priv_in));
+
+        // the curator blocks the src leaf, its slot is no longer empty under the new root
+        blocklist.insert(0).unwrap();
+        assert!(blocklist.gen_non_membership_neighbor_nodes(0).is_err());
+        let (mut pub_in, priv_in) = prove();
+        pub_in.exclusion_root = Some(blocklist.root());
+        assert!(!is_satisfied(pub_in, priv_in));
+
+        // an allowlist without the owner key
+        let mut other = KeySetTree::<_, PoseidonHasher<Fr>>::new(params.inner_params.clone(), HEIGHT as usize).unwrap();
+        other.insert(Fr::rand(rng)).unwrap();
+        other.insert(Fr::rand(rng)).unwrap();
+        assert!(other.gen_membership_neighbor_nodes(&owner).is_err());
+        let (mut pub_in, priv_in) = prove();
+        pub_in.allowlist_root = Some(other.root());
+        assert!(!is_satisfied(pub_in, priv_in));
+    }
+
+    #[test]
+    fn test_withdraw_committed_note() {
+        let rng = &mut test_rng();
+        let generator = GroupProjective::<EdwardsParameters>::prime_subgroup_generator();
+        let privkey = Fq::rand(rng);
+        let pubkeys = vec![generator.mul(privkey.into_repr()).into_affine()];
+        let nullifier_params = Rc::new(setup_params_x5_3::<Fr>(Curve::Bn254));
+        let inner_params = Rc::new(setup_params_x5_3::<Fr>(Curve::Bn254));
+        let params = WithdrawConstParams::<EdwardsParameters, PoseidonHasher<Fr>> {
+            nullifier_params: nullifier_params.clone(),
+            leaf_params: Rc::new(setup_params_x5_5(Curve::Bn254)),
+            inner_params: inner_params.clone(),
+            height: HEIGHT as usize,
+            commit: Some(CommitConstParams {
+                nullifier_params,
+                pubkeys: pubkeys.clone(),
+            }),
+            exclusion: false,
+            allowlist: false,
+        };
+        let secret = Fr::rand(rng);
+        let owner = gen_owner_key::<_, PoseidonHasher<_>>(&params.nullifier_params, secret).unwrap();
+        let nullifier_key = gen_nullifier_key::<_, PoseidonHasher<_>>(&params.nullifier_params, secret).unwrap();
+        let asset_id = Fr::rand(rng);
+        let balance = u64::rand(rng);
+
+        // src note at index 0 is committed with the escrow of the owner
+        let escrow_nonces = vec![Fq::rand(rng)];
+        let escrow = gen_escrow(&pubkeys, nullifier_key, &escrow_nonces).unwrap();
+        let note_owner = gen_note_owner::<_, PoseidonHasher<_>>(&params.nullifier_params, owner, &pubkeys, &escrow).unwrap();
+        let mut blank_nodes = vec![PoseidonHasher::empty_hash()];
+        for layer in 0..(HEIGHT as usize - 1) {
+            blank_nodes.push(PoseidonHasher::hash_two(&inner_params, blank_nodes[layer], blank_nodes[layer]).unwrap());
+        }
+        let src_leaf = PoseidonHasher::hash(
+            &params.leaf_params,
+            &[Fr::from(0u64), asset_id, Fr::from(balance), note_owner],
+        ).unwrap();
+        let src_path = blank_nodes.iter().map(|node| (false, *node)).collect::<Vec<_>>();
+        let src_root = *gen_merkle_path::<_, PoseidonHasher<_>>(&inner_params, &src_path, src_leaf).unwrap().last().unwrap();
+
+        let (receiver, relayer, nonce) = (Fr::rand(rng), Fr::rand(rng), Fq::rand(rng));
+        let orig_in = |escrow_nonces: Vec<Fq>| WithdrawOriginInputs::<EdwardsParameters> {
+            balance,
+            withdraw_amount: balance >> 1,
+            asset_id,
+            src_leaf_index: 0,
+            dst_leaf_index: 1,
+            min_anonymity_gap: 0,
+            receiver,
+            relayer,
+            fee: 0,
+            secret,
+            src_neighbor_nodes: blank_nodes.clone(),
+            commit: Some(CommitOriginInputs {
+                nonces: vec![nonce],
+                escrow: escrow.clone(),
+            }),
+            src_escrow: Some(EscrowOriginInputs {
+                pubkeys: pubkeys.clone(),
+                nonces: escrow_nonces,
+            }),
+            exclusion_neighbor_nodes: None,
+            allowlist_neighbor_nodes: None,
+        };
+        let is_satisfied = |pub_in, priv_in| {
+            let withdrawal = WithdrawProof::<_, _, PoseidonHasherGadget<_>, Groth16<Bn254>>::generate_circuit(&params, &pub_in, &priv_in).unwrap();
+            let cs = ConstraintSystem::<_>::new_ref();
+            withdrawal.generate_constraints(cs.clone()).unwrap();
+            cs.is_satisfied().unwrap()
+        };
+
+        let (pub_in, priv_in) = WithdrawVanillaProof::<_, PoseidonHasher<_>>::generate_vanilla_proof(&params, &orig_in(escrow_nonces)).unwrap();
+        assert_eq!(pub_in.src_root, src_root);
+        // the auditor decrypts the nullifier point of the rest note, which the owner publishes when spending it
+        let (c0, c1) = pub_in.commit.as_ref().unwrap().commitments[0];
+        let nullifier_point = gen_nullifier_point::<EdwardsParameters, PoseidonHasher<_>>(&params.nullifier_params, 1, owner, nullifier_key).unwrap();
+        assert_eq!(c1.into_projective() - c0.mul(privkey.into_repr()), nullifier_point.into_projective());
+        assert!(is_satisfied(pub_in, priv_in));
+
+        // an escrow of other nonces does not open the src note
+        let (mut pub_in, priv_in) = WithdrawVanillaProof::<_, PoseidonHasher<_>>::generate_vanilla_proof(&params, &orig_in(vec![Fq::rand(rng)])).unwrap();
+        assert_ne!(pub_in.src_root, src_root);
+        pub_in.src_root = src_root;
+        assert!(!is_satisfied(pub_in, priv_in));
+    }
+
+    #[test]
+    fn test_withdraw_invalid_inputs() {
+        let params = WithdrawConstParams::<EdwardsParameters, PoseidonHasher<Fr>> {
+            nullifier_params: Rc::new(setup_params_x5_3(Curve::Bn254)),
+            leaf_params: Rc::new(setup_params_x5_5(Curve::Bn254)),
+            inner_params: Rc::new(setup_params_x5_3(Curve::Bn254)),
+            height: HEIGHT as usize,
+            commit: None,
+            exclusion: false,
+            allowlist: false,
+        };
+        let orig_in = |balance, withdraw_amount, dst_leaf_index, height: u8| WithdrawOriginInputs::<EdwardsParameters> {
+            balance,
+            withdraw_amount,
+            asset_id: Fr::zero(),
+            src_leaf_index: 0,
+            dst_leaf_index,
+            min_anonymity_gap: 2,
+            receiver: Fr::zero(),
+            relayer: Fr::zero(),
+            fee: 0,
+            secret: Fr::zero(),
+            src_neighbor_nodes: vec![PoseidonHasher::empty_hash(); height as usize],
+            commit: None,
+            src_escrow: None,
+            exclusion_neighbor_nodes: None,
+            allowlist_neighbor_nodes: None,
+        };
+        let prove = |orig_in| WithdrawVanillaProof::<_, PoseidonHasher<_>>::generate_vanilla_proof(&params, &orig_in).err().unwrap();
+
+        assert!(matches!(prove(orig_in(10, 11, 2, HEIGHT)), MazeLibError::InsufficientBalance { balance: 10, amount: 11 }));
+        assert!(matches!(prove(orig_in(10, 10, 0, HEIGHT)), MazeLibError::InvalidIndex(_)));
+        assert!(matches!(prove(orig_in(10, 10, 1 << HEIGHT, HEIGHT)), MazeLibError::InvalidIndex(_)));
+        // dst leaf is within the anonymity gap of src leaf
+        assert!(matches!(prove(orig_in(10, 10, 1, HEIGHT)), MazeLibError::InvalidIndex(_)));
+        assert!(matches!(
+            prove(orig_in(10, 10, 2, HEIGHT - 1)),
+            MazeLibError::PathLengthMismatch { expected, actual, .. } if expected == HEIGHT as usize && actual + 1 == expected,
+        ));
+        assert!(prove(orig_in(10, 0, 2, HEIGHT)).is_user_error());
+    }
+
+    #[test]
+    fn test_withdraw_constraint_profile() {
+        let rng = &mut test_rng();
+        let balance = u64::rand(rng);
+
+        let mut cs = None;
+        let counts = profile(|| {
+            cs = Some(test_withdraw_inner(rng, balance, balance, 0, 0, false));
+            Ok(())
+        }).unwrap();
+        let cs = cs.unwrap();
+
+        let names = counts.iter().map(|count| count.namespace.as_str()).collect::<Vec<_>>();
+        assert_eq!(names, ["owner", "nullifier_key", "amounts", "anonymity_gap", "nullifier", "src_leaf", "dst_leaf"]);
+        assert!(counts.iter().all(|count| count.constraints > 0));
+        // all inputs are allocated before the namespaces
+        assert!(counts.iter().all(|count| count.inputs == 0));
+        assert!(counts.iter().map(|count| count.constraints).sum::<usize>() <= cs.num_constraints());
     }
 }
@@ -0,0 +1,33 @@
+use ark_relations::r1cs::SynthesisError;
+use thiserror::Error;
+
+/// Errors of building and proving the maze circuits.
+#[derive(Debug, Error)]
+pub enum MazeLibError {
+    #[error("leaf index is invalid: {0}")]
+    InvalidIndex(String),
+    #[error("balance {balance} is less than {amount}")]
+    InsufficientBalance { balance: u64, amount: u64 },
+    #[error("{name} length is {actual}, expected {expected}")]
+    PathLengthMismatch { name: &'static str, expected: usize, actual: usize },
+    #[error("inputs are invalid: {0}")]
+    InvalidInputs(String),
+    #[error("hash error: {0}")]
+    Hash(String),
+    #[error("synthesis error: {0}")]
+    Synthesis(#[from] SynthesisError),
+    #[error("snark error: {0}")]
+    Snark(String),
+}
+
+impl MazeLibError {
+    /// Errors caused by the inputs of a user, the others are bugs or broken parameters.
+    pub fn is_user_error(&self) -> bool {
+        matches!(
+            self,
+            Self::InvalidIndex(_) | Self::InsufficientBalance { .. } | Self::PathLengthMismatch { .. } | Self::InvalidInputs(_),
+        )
+    }
+}
+
+pub type Result<T> = ark_std::result::Result<T, MazeLibError>;
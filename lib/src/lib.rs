@@ -2,3 +2,4 @@ pub mod circuits;
 pub mod vanilla;
 pub mod proof;
 pub mod params;
+pub mod error;
@@ -0,0 +1,87 @@
+use ark_ff::{BigInteger, PrimeField};
+
+/// Grain LFSR in self-shrinking mode, used by the Poseidon reference implementation
+/// to derive round constants from the permutation parameters.
+pub(crate) struct GrainLFSR {
+    state: [bool; 80],
+    head: usize,
+}
+
+impl GrainLFSR {
+    pub fn new(
+        sbox: u64,
+        field_size: u64,
+        width: u64,
+        full_rounds: u64,
+        partial_rounds: u64,
+    ) -> Self {
+        let mut state = [false; 80];
+        // field: 1 for prime field
+        let mut bits = append_bits(&mut state, 0, 1, 2);
+        bits = append_bits(&mut state, bits, sbox, 4);
+        bits = append_bits(&mut state, bits, field_size, 12);
+        bits = append_bits(&mut state, bits, width, 12);
+        bits = append_bits(&mut state, bits, full_rounds, 10);
+        bits = append_bits(&mut state, bits, partial_rounds, 10);
+        state[bits..].iter_mut().for_each(|b| *b = true);
+
+        let mut lfsr = Self { state, head: 0 };
+        // discard the first 160 bits
+        (0..160).for_each(|_| { lfsr.update(); });
+
+        lfsr
+    }
+
+    fn update(&mut self) -> bool {
+        let bit = [62, 51, 38, 23, 13, 0]
+            .iter()
+            .fold(false, |acc, i| acc ^ self.state[(self.head + i) % 80]);
+        self.state[self.head] = bit;
+        self.head = (self.head + 1) % 80;
+
+        bit
+    }
+
+    fn next_bit(&mut self) -> bool {
+        loop {
+            let first = self.update();
+            let second = self.update();
+            if first {
+                return second;
+            }
+        }
+    }
+
+    /// Sample a field element by rejection, bits are taken in big-endian order.
+    pub fn next_field_element<F: PrimeField>(&mut self) -> F {
+        let size = F::size_in_bits();
+        loop {
+            let bits = (0..size).map(|_| self.next_bit()).collect::<Vec<_>>();
+            if let Some(elem) = F::from_repr(F::BigInt::from_bits_be(&bits)) {
+                return elem;
+            }
+        }
+    }
+
+    /// Sample a field element by reducing the bits modulo the field order.
+    pub fn next_field_element_mod_order<F: PrimeField>(&mut self) -> F {
+        let size = F::size_in_bits();
+        let mut bits = vec![false; (8 - size % 8) % 8];
+        bits.extend((0..size).map(|_| self.next_bit()));
+        let bytes = bits
+            .chunks(8)
+            .map(|byte| byte.iter().fold(0u8, |acc, b| (acc << 1) | *b as u8))
+            .collect::<Vec<_>>();
+
+        F::from_be_bytes_mod_order(&bytes)
+    }
+
+    pub fn next_field_elements<F: PrimeField>(&mut self, num: usize) -> Vec<F> {
+        (0..num).map(|_| self.next_field_element()).collect()
+    }
+}
+
+fn append_bits(state: &mut [bool; 80], offset: usize, value: u64, len: usize) -> usize {
+    (0..len).for_each(|i| state[offset + i] = (value >> (len - 1 - i)) & 1 == 1);
+    offset + len
+}
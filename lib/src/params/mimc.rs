@@ -0,0 +1,20 @@
+use ark_ff::PrimeField;
+use arkworks_utils::{mimc::MiMCParameters, utils::get_rounds_mimc_220};
+
+// round constants of circomlib MiMCSponge
+const ROUNDS: usize = 220;
+// only the first output is used, it is taken right after absorbing without extra squeezing
+const NUM_OUTPUTS: usize = 0;
+
+pub fn get_mimc_bn254_for_leaf<F: PrimeField>() -> MiMCParameters<F> {
+    MiMCParameters::new(F::zero(), ROUNDS, 5, NUM_OUTPUTS, get_rounds_mimc_220())
+}
+
+pub fn get_mimc_bn254_for_merkle<F: PrimeField>() -> MiMCParameters<F> {
+    MiMCParameters::new(F::zero(), ROUNDS, 3, NUM_OUTPUTS, get_rounds_mimc_220())
+}
+
+pub fn get_mimc_bn254_for_nullifier<F: PrimeField>() -> MiMCParameters<F> {
+    // keyed with 1, so that nullifiers and merkle nodes are different permutations
+    MiMCParameters::new(F::one(), ROUNDS, 3, NUM_OUTPUTS, get_rounds_mimc_220())
+}
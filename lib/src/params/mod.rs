@@ -1 +1,6 @@
-pub mod poseidon;
\ No newline at end of file
+pub mod poseidon;
+pub mod poseidon2;
+pub mod rescue;
+pub mod mimc;
+
+mod grain;
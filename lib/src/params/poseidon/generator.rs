@@ -0,0 +1,245 @@
+use ark_ff::PrimeField;
+use arkworks_utils::poseidon::{sbox::PoseidonSbox, PoseidonParameters};
+
+use crate::params::grain::GrainLFSR;
+
+fn log2_binomial(n: f64, k: f64) -> f64 {
+    let k = k.min(n - k);
+    (0..k.ceil() as usize).fold(0f64, |acc, i| acc + (n - i as f64).log2() - (i as f64 + 1.0).log2())
+}
+
+// security inequalities of the Poseidon reference script for x^alpha
+fn is_round_numbers_secure(
+    field_size: f64,
+    width: usize,
+    full_rounds: usize,
+    partial_rounds: usize,
+    alpha: u64,
+    security_level: usize,
+) -> bool {
+    let t = width as f64;
+    let r_f = full_rounds as f64;
+    let r_p = partial_rounds as f64;
+    let m = security_level as f64;
+    let log2_alpha = (alpha as f64).log2();
+
+    // statistical
+    let r_f_1 = if m <= (field_size - (alpha - 1) as f64 / 2.0).floor() * (t + 1.0) { 6.0 } else { 10.0 };
+    // interpolation
+    let r_f_2 = 1.0 + (m.min(field_size) / log2_alpha).ceil() + (t.log2() / log2_alpha).ceil() - r_p;
+    // groebner basis
+    let r_f_3 = m.min(field_size) / log2_alpha - r_p;
+    let r_f_4 = t - 1.0 + (m / (t + 1.0)).min(field_size / 2.0) / log2_alpha - r_p;
+    let r_f_5 = (t - 2.0 + m / (2.0 * log2_alpha) - r_p) / (t - 1.0);
+    let r_f_max = [r_f_1, r_f_2, r_f_3, r_f_4, r_f_5]
+        .iter()
+        .fold(f64::MIN, |acc, r| acc.max(r.ceil()));
+
+    // groebner basis attack of https://eprint.iacr.org/2023/537
+    let r_temp = (t / 3.0).floor();
+    let over = (r_f - 1.0) * t + r_p + r_temp + r_temp * (r_f / 2.0) + r_p + alpha as f64;
+    let under = r_temp * (r_f / 2.0) + r_p + alpha as f64;
+    let cost_gb4 = (2.0 * log2_binomial(over, under)).ceil();
+
+    r_f >= r_f_max && cost_gb4 >= m
+}
+
+/// Find the full and partial rounds with the fewest S-boxes that satisfy the security level,
+/// then add the security margin of the Poseidon paper: 2 more full rounds and 7.5% more partial rounds.
+/// Like the reference instances, the partial rounds are rounded up to a multiple of the width.
+pub fn gen_poseidon_round_numbers(
+    field_size: usize,
+    width: usize,
+    alpha: u64,
+    security_level: usize,
+) -> (usize, usize) {
+    (1..500usize)
+        .filter_map(|partial_rounds| {
+            (4..100usize)
+                .step_by(2)
+                .find(|full_rounds| {
+                    is_round_numbers_secure(
+                        field_size as f64,
+                        width,
+                        *full_rounds,
+                        partial_rounds,
+                        alpha,
+                        security_level,
+                    )
+                })
+                .map(|full_rounds| {
+                    (full_rounds + 2, (partial_rounds as f64 * 1.075).ceil() as usize)
+                })
+        })
+        .min_by_key(|(full_rounds, partial_rounds)| (full_rounds * width + partial_rounds, *full_rounds))
+        .map(|(full_rounds, partial_rounds)| (full_rounds, partial_rounds.div_ceil(width) * width))
+        .expect("no secure round numbers")
+}
+
+fn mat_mul<F: PrimeField>(a: &[Vec<F>], b: &[Vec<F>]) -> Vec<Vec<F>> {
+    a.iter()
+        .map(|row| {
+            (0..b[0].len())
+                .map(|j| row.iter().zip(b).fold(F::zero(), |acc, (x, col)| acc + *x * col[j]))
+                .collect()
+        })
+        .collect()
+}
+
+fn transpose<F: PrimeField>(m: &[Vec<F>]) -> Vec<Vec<F>> {
+    (0..m[0].len()).map(|j| m.iter().map(|row| row[j]).collect()).collect()
+}
+
+fn rank<F: PrimeField>(mut rows: Vec<Vec<F>>) -> usize {
+    let mut rank = 0;
+    for col in 0..rows.first().map_or(0, Vec::len) {
+        if let Some(pivot) = (rank..rows.len()).find(|i| !rows[*i][col].is_zero()) {
+            rows.swap(rank, pivot);
+            let inv = rows[rank][col].inverse().unwrap();
+            for i in rank + 1..rows.len() {
+                let c = rows[i][col] * inv;
+                for j in col..rows[i].len() {
+                    let x = rows[rank][j];
+                    rows[i][j] -= c * x;
+                }
+            }
+            rank += 1;
+        }
+    }
+
+    rank
+}
+
+// the smallest subspace invariant under m that contains the first unit vector is the whole space
+fn is_first_unit_cyclic<F: PrimeField>(m: &[Vec<F>]) -> bool {
+    let mut v = vec![F::zero(); m.len()];
+    v[0] = F::one();
+    let krylov = (0..m.len())
+        .map(|_| {
+            let next = m.iter().map(|row| row.iter().zip(&v).fold(F::zero(), |acc, (x, y)| acc + *x * y)).collect();
+            ark_std::mem::replace(&mut v, next)
+        })
+        .collect();
+
+    rank(krylov) == m.len()
+}
+
+/// Checks of the reference script against invariant subspace trails over up to `4 * width` rounds, with the
+/// single S-box of partial rounds on the first cell. For every power of the MDS matrix, no invariant subspace
+/// keeps the S-box inactive, i.e. lies in the inputs with a zero first cell, and the trail of an active S-box
+/// is not caught in a proper invariant subspace. The reference resamples the matrix until both hold.
+pub fn is_mds_secure<F: PrimeField>(mds: &[Vec<F>]) -> bool {
+    let mut power = mds.to_vec();
+    (0..4 * mds.len()).all(|_| {
+        // the largest invariant subspace with a zero first cell is trivial iff the first unit is cyclic for the transpose
+        let secure = is_first_unit_cyclic(&power) && is_first_unit_cyclic(&transpose(&power));
+        power = mat_mul(&power, mds);
+        secure
+    })
+}
+
+/// Generate Poseidon parameters for the field `F` like the reference script `generate_parameters_grain.sage`.
+/// Round constants come from the Grain LFSR, and the MDS matrix is a Cauchy matrix sampled after them,
+/// resampled until it is secure against invariant subspace trails.
+pub fn gen_poseidon_parameters<F: PrimeField>(
+    width: u8,
+    alpha: u64,
+    security_level: usize,
+) -> PoseidonParameters<F> {
+    let field_size = F::size_in_bits();
+    let (full_rounds, partial_rounds) = gen_poseidon_round_numbers(
+        field_size,
+        width as usize,
+        alpha,
+        security_level,
+    );
+
+    let mut grain = GrainLFSR::new(
+        0,
+        field_size as u64,
+        width as u64,
+        full_rounds as u64,
+        partial_rounds as u64,
+    );
+    let round_keys = grain.next_field_elements((full_rounds + partial_rounds) * width as usize);
+    let mds = loop {
+        let elems = (0..2 * width)
+            .map(|_| grain.next_field_element_mod_order::<F>())
+            .collect::<Vec<_>>();
+        let distinct = elems
+            .iter()
+            .enumerate()
+            .all(|(i, x)| elems[i + 1..].iter().all(|y| x != y));
+        if !distinct {
+            continue;
+        }
+
+        let (xs, ys) = elems.split_at(width as usize);
+        let mds = xs
+            .iter()
+            .map(|x| ys.iter().map(|y| (*x + y).inverse()).collect::<Option<Vec<_>>>())
+            .collect::<Option<Vec<_>>>();
+        if let Some(mds) = mds.filter(|mds| is_mds_secure(mds)) {
+            break mds;
+        }
+    };
+
+    PoseidonParameters::<F>::new(
+        round_keys,
+        mds,
+        full_rounds as u8,
+        partial_rounds as u8,
+        width,
+        PoseidonSbox(alpha as i8),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ed_on_bn254::Fq as Fr;
+    use ark_ff::PrimeField;
+    use arkworks_utils::utils::{parse_vec, common::{Curve, setup_params_x5_3, setup_params_x5_5}};
+
+    use crate::params::grain::GrainLFSR;
+    use super::{gen_poseidon_round_numbers, gen_poseidon_parameters, is_mds_secure};
+
+    #[test]
+    fn test_grain_round_constants() {
+        // the first round constant of circomlib poseidon with width 3, 8 full rounds and 57 partial rounds
+        let expected = parse_vec::<Fr>(vec!["0x0ee9a592ba9a9518d05986d656f40c2114c4993c11bb29938d21d47304cd8e6e"]);
+        let mut grain = GrainLFSR::new(0, Fr::size_in_bits() as u64, 3, 8, 57);
+        assert_eq!(grain.next_field_elements::<Fr>(1), expected);
+    }
+
+    #[test]
+    fn test_poseidon_round_numbers() {
+        // partial rounds of the BN254 reference instances for widths 2 to 17, as used by circomlib
+        let expected = [56, 57, 56, 60, 60, 63, 64, 63, 60, 66, 60, 65, 70, 60, 64, 68];
+        let field_size = Fr::size_in_bits();
+        expected.iter().enumerate().for_each(|(i, partial_rounds)| {
+            assert_eq!(gen_poseidon_round_numbers(field_size, i + 2, 5, 128), (8, *partial_rounds));
+        });
+    }
+
+    #[test]
+    fn test_poseidon_parameters() {
+        // the reference parameters of arkworks for widths 3 and 5
+        for expected in [setup_params_x5_3::<Fr>(Curve::Bn254), setup_params_x5_5::<Fr>(Curve::Bn254)] {
+            let params = gen_poseidon_parameters::<Fr>(expected.width, 5, 128);
+            assert_eq!(params.full_rounds, expected.full_rounds);
+            assert_eq!(params.partial_rounds, expected.partial_rounds);
+            assert_eq!(params.round_keys, expected.round_keys);
+            assert_eq!(params.mds_matrix, expected.mds_matrix);
+        }
+    }
+
+    #[test]
+    fn test_insecure_mds() {
+        // the identity leaves every subspace invariant, the lower triangular matrix leaves the second unit invariant
+        let one = Fr::from(1u64);
+        let zero = Fr::from(0u64);
+        assert!(!is_mds_secure(&[vec![one, zero], vec![zero, one]]));
+        assert!(!is_mds_secure(&[vec![one, zero], vec![Fr::from(2u64), Fr::from(3u64)]]));
+        assert!(is_mds_secure(&setup_params_x5_3::<Fr>(Curve::Bn254).mds_matrix));
+    }
+}
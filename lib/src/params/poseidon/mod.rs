@@ -1,7 +1,9 @@
 mod leaf;
 mod merkle;
 mod nullifier;
+mod generator;
 
 pub use leaf::*;
 pub use merkle::*;
 pub use nullifier::*;
+pub use generator::*;
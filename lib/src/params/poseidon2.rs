@@ -0,0 +1,133 @@
+use ark_ff::PrimeField;
+
+use crate::vanilla::hasher::poseidon2::Poseidon2Parameters;
+use super::grain::GrainLFSR;
+use super::poseidon::is_mds_secure;
+
+const FULL_ROUNDS: usize = 8;
+const PARTIAL_ROUNDS: usize = 56;
+const ALPHA: u64 = 5;
+
+// the internal matrix `1 + diag(d)` is invertible iff every d is not zero and `1 + sum(1 / d) != 0`
+fn is_internal_matrix_secure<F: PrimeField>(diag_m_1: &[F]) -> bool {
+    let invertible = diag_m_1
+        .iter()
+        .try_fold(F::one(), |acc, d| d.inverse().map(|inv| acc + inv))
+        .is_some_and(|det| !det.is_zero());
+    let matrix = (0..diag_m_1.len())
+        .map(|i| (0..diag_m_1.len()).map(|j| if i == j { F::one() + diag_m_1[i] } else { F::one() }).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    invertible && is_mds_secure(&matrix)
+}
+
+/// Derive Poseidon2 parameters like the reference script `poseidon2_rust_params.sage`, round constants come
+/// from the Grain LFSR in the order they are used: the first half of full rounds, the partial rounds,
+/// then the rest full rounds. `domain` is the capacity element of the sponge that separates instances.
+///
+/// Widths 2 and 3 use the internal matrices of the reference. For wider instances the reference samples
+/// the internal diagonal with an unseeded RNG, so these are custom instances: the diagonal is drawn from
+/// the Grain LFSR after the round constants, resampled until the internal matrix is invertible and secure
+/// against invariant subspace trails.
+pub fn gen_poseidon2_parameters<F: PrimeField>(
+    width: usize,
+    full_rounds: usize,
+    partial_rounds: usize,
+    alpha: u64,
+    domain: F,
+) -> Poseidon2Parameters<F> {
+    assert!(width >= 2, "unsupported width {:?}", width);
+    if width > 3 {
+        assert_eq!(width % 4, 0, "unsupported width {:?}", width);
+    }
+    assert_eq!(full_rounds % 2, 0, "full rounds should be even");
+
+    let mut grain = GrainLFSR::new(
+        0,
+        F::size_in_bits() as u64,
+        width as u64,
+        full_rounds as u64,
+        partial_rounds as u64,
+    );
+    let mut external_rc = (0..full_rounds / 2)
+        .map(|_| grain.next_field_elements(width))
+        .collect::<Vec<_>>();
+    let internal_rc = grain.next_field_elements(partial_rounds);
+    external_rc.extend((0..full_rounds / 2).map(|_| grain.next_field_elements(width)));
+    let internal_diag_m_1 = match width {
+        2 => vec![F::one(), F::from(2u64)],
+        3 => vec![F::one(), F::one(), F::from(2u64)],
+        _ => loop {
+            let diag_m_1 = grain.next_field_elements(width);
+            if is_internal_matrix_secure(&diag_m_1) {
+                break diag_m_1;
+            }
+        },
+    };
+
+    Poseidon2Parameters {
+        width,
+        full_rounds,
+        partial_rounds,
+        alpha,
+        external_rc,
+        internal_rc,
+        internal_diag_m_1,
+        domain,
+    }
+}
+
+/// A custom width 8 instance, see `gen_poseidon2_parameters`.
+pub fn get_poseidon2_bn254_for_leaf<F: PrimeField>() -> Poseidon2Parameters<F> {
+    // 4 inputs and the capacity element do not fit in width 4, the next supported width is 8
+    gen_poseidon2_parameters(8, FULL_ROUNDS, PARTIAL_ROUNDS, ALPHA, F::zero())
+}
+
+/// The reference width 3 instance.
+pub fn get_poseidon2_bn254_for_merkle<F: PrimeField>() -> Poseidon2Parameters<F> {
+    gen_poseidon2_parameters(3, FULL_ROUNDS, PARTIAL_ROUNDS, ALPHA, F::zero())
+}
+
+pub fn get_poseidon2_bn254_for_nullifier<F: PrimeField>() -> Poseidon2Parameters<F> {
+    // the merkle permutation with another domain tag, so that nullifiers and merkle nodes never collide
+    gen_poseidon2_parameters(3, FULL_ROUNDS, PARTIAL_ROUNDS, ALPHA, F::one())
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ed_on_bn254::Fq as Fr;
+    use arkworks_utils::utils::parse_vec;
+
+    use crate::vanilla::hasher::{FieldHasher, poseidon2::Poseidon2Hasher};
+    use super::*;
+
+    #[test]
+    fn test_poseidon2_kat() {
+        // the test vector of the reference implementation for BN254 with width 3
+        let expected = parse_vec::<Fr>(vec![
+            "0x0bb61d24daca55eebcb1929a82650f328134334da98ea4f847f760054f4a3033",
+            "0x303b6f7c86d043bfcbcc80214f26a30277a15d3f74ca654992defe7ff8d03570",
+            "0x1ed25194542b12eef8617361c3ba7c52e660b145994427cc86296242cf766ec8",
+        ]);
+        let params = get_poseidon2_bn254_for_merkle::<Fr>();
+        let state = (0..3u64).map(Fr::from).collect();
+        assert_eq!(Poseidon2Hasher::permute(&params, state).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_poseidon2_internal_matrix() {
+        let params = get_poseidon2_bn254_for_leaf::<Fr>();
+        assert!(is_internal_matrix_secure(&params.internal_diag_m_1));
+        // 1 + diag(-2, -2) is singular, and 1 + diag(1, 1, 1, 1) has the invariant subspaces of a circulant matrix
+        assert!(!is_internal_matrix_secure(&[-Fr::from(2u64), -Fr::from(2u64)]));
+        assert!(!is_internal_matrix_secure(&[Fr::from(1u64); 4]));
+    }
+
+    #[test]
+    fn test_poseidon2_domain() {
+        let inputs = [Fr::from(1u64), Fr::from(2u64)];
+        let merkle = Poseidon2Hasher::hash(&get_poseidon2_bn254_for_merkle(), &inputs).unwrap();
+        let nullifier = Poseidon2Hasher::hash(&get_poseidon2_bn254_for_nullifier(), &inputs).unwrap();
+        assert_ne!(merkle, nullifier);
+    }
+}
@@ -0,0 +1,178 @@
+use ark_ff::{FpParameters, PrimeField};
+use num_bigint::BigUint;
+use sha3::{Shake256, digest::{Update, ExtendableOutput, XofReader}};
+
+use crate::vanilla::hasher::rescue::RescueParameters;
+
+const CAPACITY: usize = 1;
+const SECURITY_LEVEL: usize = 128;
+const ALPHA: u64 = 5;
+
+/// Number of rounds against the Groebner basis attack as in the Rescue-Prime specification,
+/// with a minimum of 5 rounds and a 50% security margin.
+pub fn gen_rescue_rounds(width: usize, capacity: usize, alpha: u64, security_level: usize) -> usize {
+    let rate = width - capacity;
+    let log2_binomial = |n: usize, k: usize| {
+        (0..k).fold(0f64, |acc, i| acc + ((n - i) as f64).log2() - ((i + 1) as f64).log2())
+    };
+    let l1 = (1..25)
+        .find(|rounds| {
+            let dcon = (0.5 * (alpha - 1) as f64 * width as f64 * (rounds - 1) as f64 + 2.0).floor() as usize;
+            let v = width * (rounds - 1) + rate;
+            2.0 * log2_binomial(v + dcon, v) > security_level as f64
+        })
+        .expect("security level is too high");
+
+    (1.5 * l1.max(5) as f64).ceil() as usize
+}
+
+/// Round constants of the Rescue-Prime specification, read as little-endian integers modulo p
+/// from the SHAKE256 output of the seed `Rescue-XLIX(p,width,capacity,security_level)`.
+fn gen_rescue_round_constants<F: PrimeField>(
+    width: usize,
+    capacity: usize,
+    security_level: usize,
+    rounds: usize,
+) -> Vec<Vec<F>> {
+    let modulus: BigUint = F::Params::MODULUS.into();
+    let seed = format!("Rescue-XLIX({},{},{},{})", modulus, width, capacity, security_level);
+    let bytes_per_int = F::size_in_bits().div_ceil(8) + 1;
+
+    let mut shake = Shake256::default();
+    shake.update(seed.as_bytes());
+    let mut reader = shake.finalize_xof();
+
+    (0..2 * rounds)
+        .map(|_| {
+            (0..width)
+                .map(|_| {
+                    let mut chunk = vec![0u8; bytes_per_int];
+                    reader.read(&mut chunk);
+                    F::from_le_bytes_mod_order(&chunk)
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// MDS matrix of the Rescue-Prime specification, the transpose of the right half of the reduced echelon form
+/// of the `width x 2 * width` Vandermonde matrix `g^(i * j)`, where `g` is the smallest primitive element.
+fn gen_rescue_mds<F: PrimeField>(width: usize, generator: F) -> Vec<Vec<F>> {
+    let mut rows = (0..width)
+        .map(|i| (0..2 * width).map(|j| generator.pow([(i * j) as u64])).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+
+    // the left half is a Vandermonde matrix with distinct powers, so every pivot is on the diagonal
+    for col in 0..width {
+        let pivot = (col..width).find(|i| !rows[*i][col].is_zero()).expect("vandermonde matrix is invertible");
+        rows.swap(col, pivot);
+        let inv = rows[col][col].inverse().unwrap();
+        rows[col].iter_mut().for_each(|x| *x *= inv);
+        let pivot_row = rows[col].clone();
+        rows.iter_mut()
+            .enumerate()
+            .filter(|(i, _)| *i != col)
+            .for_each(|(_, row)| {
+                let c = row[col];
+                row.iter_mut().zip(&pivot_row).for_each(|(x, p)| *x -= c * p);
+            });
+    }
+
+    (0..width).map(|i| rows.iter().map(|row| row[width + i]).collect()).collect()
+}
+
+/// Derive Rescue-Prime parameters as in the reference implementation of the specification,
+/// `domain` is the capacity element of the sponge that separates instances of the same permutation.
+pub fn gen_rescue_parameters<F: PrimeField>(
+    width: usize,
+    rounds: usize,
+    alpha: u64,
+    domain: F,
+) -> RescueParameters<F> {
+    let modulus_minus_one: BigUint = F::Params::MODULUS.into() - 1u64;
+    assert_ne!(&modulus_minus_one % alpha, BigUint::from(0u64), "alpha should be coprime to p - 1");
+    let alpha_inv = (1..alpha)
+        .map(|k| modulus_minus_one.clone() * k + 1u64)
+        .find(|e| e % alpha == BigUint::from(0u64))
+        .map(|e| (e / alpha).to_u64_digits())
+        .expect("alpha should be coprime to p - 1");
+
+    // the multiplicative generator of arkworks, 5 for BN254, is the smallest primitive element
+    let mds = gen_rescue_mds(width, F::multiplicative_generator());
+    let round_constants = gen_rescue_round_constants(width, CAPACITY, SECURITY_LEVEL, rounds);
+
+    RescueParameters {
+        width,
+        rounds,
+        alpha,
+        alpha_inv,
+        mds,
+        round_constants,
+        domain,
+    }
+}
+
+pub fn get_rescue_bn254_for_leaf<F: PrimeField>() -> RescueParameters<F> {
+    let rounds = gen_rescue_rounds(5, CAPACITY, ALPHA, SECURITY_LEVEL);
+    gen_rescue_parameters(5, rounds, ALPHA, F::zero())
+}
+
+pub fn get_rescue_bn254_for_merkle<F: PrimeField>() -> RescueParameters<F> {
+    let rounds = gen_rescue_rounds(3, CAPACITY, ALPHA, SECURITY_LEVEL);
+    gen_rescue_parameters(3, rounds, ALPHA, F::zero())
+}
+
+pub fn get_rescue_bn254_for_nullifier<F: PrimeField>() -> RescueParameters<F> {
+    // the merkle permutation with another domain tag, so that nullifiers and merkle nodes never collide
+    let rounds = gen_rescue_rounds(3, CAPACITY, ALPHA, SECURITY_LEVEL);
+    gen_rescue_parameters(3, rounds, ALPHA, F::one())
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_ed_on_bn254::Fq as Fr;
+    use ark_ff::FftField;
+    use arkworks_utils::utils::parse_vec;
+
+    use crate::vanilla::hasher::{FieldHasher, rescue::RescueHasher};
+    use super::*;
+
+    #[test]
+    fn test_rescue_rounds() {
+        assert_eq!(gen_rescue_rounds(3, CAPACITY, ALPHA, SECURITY_LEVEL), 14);
+        assert_eq!(gen_rescue_rounds(5, CAPACITY, ALPHA, SECURITY_LEVEL), 9);
+    }
+
+    #[test]
+    fn test_rescue_kat() {
+        // outputs of the reference implementation of the specification on the input [0, 1, .., width - 1]
+        assert_eq!(Fr::multiplicative_generator(), Fr::from(5u64));
+        let expected = [
+            parse_vec::<Fr>(vec![
+                "0x0dc30ccd5d64e5bea071e99087ef86d433eb156aa0500a823298f9bb05328bd2",
+                "0x189893368d5815608c56e44cc67f7e821e093bb6254a0553f9ff69f4d99debc8",
+                "0x1acafc768221448ebc51fa2cd1e3c9b2044a0c04f3509d833b0a82c7e3462610",
+            ]),
+            parse_vec::<Fr>(vec![
+                "0x2cd8fc0509157f1faf9f730aeb40f6eb745872e281675e39a54b12f60b504dae",
+                "0x2888c57a46a166001bd4d59c6ee0996a952fd7321fd1f79dacb31b4729977743",
+                "0x1fd755076447d23085a92725eb0e9009678be249c091feb5a534fef9a6424402",
+                "0x2ee747db42419d1dff124db129c597a15857404c519a595ade6ab8c83fe3fa8f",
+                "0x0c3600f7a922d59314a27b26130c152b346c1f4372e9deef67acd6be36b5cd3d",
+            ]),
+        ];
+        let instances = [get_rescue_bn254_for_merkle::<Fr>(), get_rescue_bn254_for_leaf::<Fr>()];
+        instances.iter().zip(expected).for_each(|(params, expected)| {
+            let state = (0..params.width as u64).map(Fr::from).collect();
+            assert_eq!(RescueHasher::permute(params, state).unwrap(), expected);
+        });
+    }
+
+    #[test]
+    fn test_rescue_domain() {
+        let inputs = [Fr::from(1u64), Fr::from(2u64)];
+        let merkle = RescueHasher::hash(&get_rescue_bn254_for_merkle(), &inputs).unwrap();
+        let nullifier = RescueHasher::hash(&get_rescue_bn254_for_nullifier(), &inputs).unwrap();
+        assert_ne!(merkle, nullifier);
+    }
+}
@@ -0,0 +1,154 @@
+use ark_ec::{msm::VariableBaseMSM, AffineCurve, PairingEngine, ProjectiveCurve};
+use ark_ff::{Field, PrimeField, Zero};
+use ark_groth16::{prepare_verifying_key, verify_proof, PreparedVerifyingKey, Proof, VerifyingKey};
+use ark_std::rand::{Rng, RngCore, CryptoRng};
+
+use crate::error::{MazeLibError, Result};
+
+/// Check proofs against one verifying key with a random linear combination and a single
+/// final exponentiation, returns the indexes of the bad proofs.
+pub fn batch_verify<E, R>(
+    rng: &mut R,
+    vk: &VerifyingKey<E>,
+    inputs: &[Vec<E::Fr>],
+    proofs: &[Proof<E>],
+) -> Result<Vec<usize>>
+where
+    E: PairingEngine,
+    R: RngCore + CryptoRng,
+{
+    if inputs.len() != proofs.len() {
+        return Err(MazeLibError::InvalidInputs(format!("{} public inputs for {} proofs", inputs.len(), proofs.len())));
+    }
+    if let Some(input) = inputs.iter().find(|input| input.len() + 1 != vk.gamma_abc_g1.len()) {
+        return Err(MazeLibError::InvalidInputs(format!("{} public inputs for {} in verifying key", input.len(), vk.gamma_abc_g1.len() - 1)));
+    }
+
+    let pvk = prepare_verifying_key(vk);
+    let mut bad = Vec::new();
+    locate_bad_proofs(rng, &pvk, inputs, proofs, 0, &mut bad)?;
+
+    Ok(bad)
+}
+
+// bisect a failed batch until the bad proofs are found
+fn locate_bad_proofs<E, R>(
+    rng: &mut R,
+    pvk: &PreparedVerifyingKey<E>,
+    inputs: &[Vec<E::Fr>],
+    proofs: &[Proof<E>],
+    offset: usize,
+    bad: &mut Vec<usize>,
+) -> Result<()>
+where
+    E: PairingEngine,
+    R: RngCore + CryptoRng,
+{
+    match proofs.len() {
+        0 => {}
+        1 => {
+            if !verify_proof(pvk, &proofs[0], &inputs[0])? {
+                bad.push(offset);
+            }
+        }
+        len => {
+            if !verify_batch(rng, pvk, inputs, proofs)? {
+                let mid = len / 2;
+                locate_bad_proofs(rng, pvk, &inputs[..mid], &proofs[..mid], offset, bad)?;
+                locate_bad_proofs(rng, pvk, &inputs[mid..], &proofs[mid..], offset + mid, bad)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// prod e(r_j * A_j, B_j) * e(sum r_j * L_j, -gamma) * e(sum r_j * C_j, -delta) == e(alpha, beta)^(sum r_j)
+fn verify_batch<E, R>(
+    rng: &mut R,
+    pvk: &PreparedVerifyingKey<E>,
+    inputs: &[Vec<E::Fr>],
+    proofs: &[Proof<E>],
+) -> Result<bool>
+where
+    E: PairingEngine,
+    R: RngCore + CryptoRng,
+{
+    // 128 bits of randomness are enough to catch a bad proof
+    let randoms = proofs.iter().map(|_| E::Fr::from(rng.gen::<u128>())).collect::<Vec<_>>();
+    let randoms_sum = randoms.iter().fold(E::Fr::zero(), |sum, r| sum + r);
+
+    let mut input_scalars = vec![E::Fr::zero(); pvk.vk.gamma_abc_g1.len()];
+    input_scalars[0] = randoms_sum;
+    for (input, r) in inputs.iter().zip(randoms.iter()) {
+        for (scalar, x) in input_scalars[1..].iter_mut().zip(input.iter()) {
+            *scalar += *x * r;
+        }
+    }
+    let input_scalars = input_scalars.iter().map(|s| s.into_repr()).collect::<Vec<_>>();
+    let prepared_inputs = VariableBaseMSM::multi_scalar_mul(&pvk.vk.gamma_abc_g1, &input_scalars);
+
+    let c_bases = proofs.iter().map(|proof| proof.c).collect::<Vec<_>>();
+    let c_scalars = randoms.iter().map(|r| r.into_repr()).collect::<Vec<_>>();
+    let prepared_c = VariableBaseMSM::multi_scalar_mul(&c_bases, &c_scalars);
+
+    let mut pairs = proofs
+        .iter()
+        .zip(randoms.iter())
+        .map(|(proof, r)| (proof.a.mul(r.into_repr()).into_affine().into(), proof.b.into()))
+        .collect::<Vec<_>>();
+    pairs.push((prepared_inputs.into_affine().into(), pvk.gamma_g2_neg_pc.clone()));
+    pairs.push((prepared_c.into_affine().into(), pvk.delta_g2_neg_pc.clone()));
+
+    let qap = E::miller_loop(pairs.iter());
+    let test = E::final_exponentiation(&qap).ok_or_else(|| MazeLibError::Snark("unexpected identity in batch verification".into()))?;
+
+    Ok(test == pvk.alpha_g1_beta_g2.pow(randoms_sum.into_repr()))
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_bn254::{Bn254, Fr};
+    use ark_groth16::{create_random_proof, generate_random_parameters, Proof};
+    use ark_r1cs_std::{alloc::AllocVar, eq::EqGadget, fields::fp::FpVar};
+    use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+    use ark_std::{test_rng, UniformRand};
+
+    use super::batch_verify;
+
+    #[derive(Clone)]
+    struct SquareCircuit {
+        x: Fr,
+        y: Fr,
+    }
+
+    impl ConstraintSynthesizer<Fr> for SquareCircuit {
+        fn generate_constraints(self, cs: ConstraintSystemRef<Fr>) -> Result<(), SynthesisError> {
+            let y = FpVar::new_input(cs.clone(), || Ok(self.y))?;
+            let x = FpVar::new_witness(cs, || Ok(self.x))?;
+            (&x * &x).enforce_equal(&y)
+        }
+    }
+
+    #[test]
+    fn test_batch_verify() {
+        let rng = &mut test_rng();
+        let blank = SquareCircuit { x: Fr::default(), y: Fr::default() };
+        let pk = generate_random_parameters::<Bn254, _, _>(blank, rng).unwrap();
+
+        let mut inputs = Vec::new();
+        let mut proofs: Vec<Proof<Bn254>> = Vec::new();
+        for _ in 0..7 {
+            let x = Fr::rand(rng);
+            let y = x * x;
+            proofs.push(create_random_proof(SquareCircuit { x, y }, &pk, rng).unwrap());
+            inputs.push(vec![y]);
+        }
+        assert!(batch_verify(rng, &pk.vk, &inputs, &proofs).unwrap().is_empty());
+
+        inputs[2][0] += Fr::from(1u64);
+        proofs.swap(4, 5);
+        assert_eq!(batch_verify(rng, &pk.vk, &inputs, &proofs).unwrap(), [2, 4, 5]);
+        assert!(batch_verify(rng, &pk.vk, &inputs[..2], &proofs[..3]).is_err());
+    }
+}
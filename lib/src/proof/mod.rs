@@ -1,13 +1,36 @@
 pub mod scheme;
+mod batch;
+
+pub use batch::batch_verify;
 
-use anyhow::{anyhow, Result};
 use ark_crypto_primitives::snark::SNARK;
+use ark_ec::PairingEngine;
 use ark_ff::PrimeField;
-use ark_relations::r1cs::ConstraintSynthesizer;
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystem, ConstraintMatrices, OptimizationGoal, SynthesisError, SynthesisMode};
+use ark_groth16::{Proof, VerifyingKey};
 use ark_std::rand::{RngCore, CryptoRng};
 
+use crate::circuits::{profile, ConstraintCount};
+use crate::error::{MazeLibError, Result};
 use crate::vanilla::VanillaProof;
 
+/// Size of a circuit and where it goes, counted the same way as the parameters setup.
+#[derive(Debug, Clone)]
+pub struct ConstraintReport {
+    pub constraints: usize,
+    pub witnesses: usize,
+    /// public inputs, without the constant one
+    pub inputs: usize,
+    pub namespaces: Vec<ConstraintCount>,
+}
+
+/// R1CS matrices with a satisfying assignment, wire 0 is the constant one,
+/// followed by the public inputs and then the witnesses.
+pub struct R1CSExport<F: PrimeField> {
+    pub matrices: ConstraintMatrices<F>,
+    pub assignment: Vec<F>,
+}
+
 pub trait ProofScheme<F, C, S, V>
 where
     F: PrimeField,
@@ -21,12 +44,55 @@ where
         params: &V::ConstParams,
         pub_in: &V::PublicInputs,
         priv_in: &V::PrivateInputs,
-    ) -> C;
+    ) -> Result<C>;
 
     fn blank_circuit(params: &V::ConstParams) -> Result<C> {
         let (pub_in, priv_in) = V::blank_proof(params)?;
 
-        Ok(Self::generate_circuit(params, &pub_in, &priv_in))
+        Self::generate_circuit(params, &pub_in, &priv_in)
+    }
+
+    fn constraint_report(params: &V::ConstParams) -> Result<ConstraintReport> {
+        let circuit = Self::blank_circuit(params)?;
+        let cs = ConstraintSystem::new_ref();
+        cs.set_optimization_goal(OptimizationGoal::Constraints);
+        cs.set_mode(SynthesisMode::Setup);
+
+        let namespaces = profile(|| circuit.generate_constraints(cs.clone()))?;
+
+        Ok(ConstraintReport {
+            constraints: cs.num_constraints(),
+            witnesses: cs.num_witness_variables(),
+            inputs: cs.num_instance_variables() - 1,
+            namespaces,
+        })
+    }
+
+    fn export_r1cs(
+        params: &V::ConstParams,
+        pub_in: &V::PublicInputs,
+        priv_in: &V::PrivateInputs,
+    ) -> Result<R1CSExport<F>> {
+        let circuit = Self::generate_circuit(params, pub_in, priv_in)?;
+        let cs = ConstraintSystem::new_ref();
+        cs.set_optimization_goal(OptimizationGoal::Constraints);
+        cs.set_mode(SynthesisMode::Prove { construct_matrices: true });
+
+        circuit.generate_constraints(cs.clone())?;
+        cs.finalize();
+        if !cs.is_satisfied()? {
+            return Err(MazeLibError::InvalidInputs("constraints are not satisfied by the inputs".into()));
+        }
+
+        let matrices = cs.to_matrices().ok_or(MazeLibError::Synthesis(SynthesisError::MissingCS))?;
+        let cs = cs.borrow().ok_or(MazeLibError::Synthesis(SynthesisError::MissingCS))?;
+        let assignment = cs.instance_assignment
+            .iter()
+            .chain(cs.witness_assignment.iter())
+            .copied()
+            .collect();
+
+        Ok(R1CSExport { matrices, assignment })
     }
 
     fn parameters_setup<R: RngCore + CryptoRng>(
@@ -35,7 +101,7 @@ where
     ) -> Result<(S::ProvingKey, S::VerifyingKey)> {
         let circuit = Self::blank_circuit(params)?;
         let pvk = S::circuit_specific_setup(circuit, rng)
-            .map_err(|e| anyhow!("parameters set up error: {}", e))?;
+            .map_err(|e| MazeLibError::Snark(format!("parameters set up: {}", e)))?;
 
         Ok(pvk)
     }
@@ -47,9 +113,9 @@ where
         priv_in: &V::PrivateInputs,
         pk: &S::ProvingKey,
     ) -> Result<S::Proof> {
-        let circuit = Self::generate_circuit(params, pub_in, priv_in);
+        let circuit = Self::generate_circuit(params, pub_in, priv_in)?;
         let proof = S::prove(pk, circuit, rng)
-            .map_err(|e| anyhow!("generate snark proof error: {}", e))?;
+            .map_err(|e| MazeLibError::Snark(format!("generate snark proof: {}", e)))?;
 
         Ok(proof)
     }
@@ -61,8 +127,24 @@ where
     ) -> Result<bool> {
         let inputs = Self::generate_public_inputs(pub_in);
         let result = S::verify(vk, &inputs, proof)
-            .map_err(|e| anyhow!("verify snark proof error: {}", e))?;
+            .map_err(|e| MazeLibError::Snark(format!("verify snark proof: {}", e)))?;
 
         Ok(result)
     }
-}
\ No newline at end of file
+
+    /// Verify groth16 proofs in one batch, returns the indexes of the bad proofs.
+    fn batch_verify_snark_proofs<E, R>(
+        rng: &mut R,
+        pub_ins: &[V::PublicInputs],
+        proofs: &[Proof<E>],
+        vk: &VerifyingKey<E>,
+    ) -> Result<Vec<usize>>
+    where
+        E: PairingEngine<Fr = F>,
+        R: RngCore + CryptoRng,
+    {
+        let inputs = pub_ins.iter().map(Self::generate_public_inputs).collect::<Vec<_>>();
+
+        batch_verify(rng, vk, &inputs, proofs)
+    }
+}
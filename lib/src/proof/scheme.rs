@@ -3,8 +3,9 @@ use ark_ec::TEModelParameters;
 use ark_ff::PrimeField;
 use ark_std::marker::PhantomData;
 
-use crate::vanilla::{hasher::FieldHasher, withdraw::*, deposit::*};
-use crate::circuits::{DepositCircuit, Commit, WithdrawCircuit, FieldHasherGadget};
+use crate::error::Result;
+use crate::vanilla::{hasher::FieldHasher, withdraw::*, deposit::*, transfer::*, batch_deposit::*};
+use crate::circuits::{DepositCircuit, Commit, Escrow, WithdrawCircuit, TransferCircuit, BatchDepositCircuit, FieldHasherGadget};
 use super::ProofScheme;
 
 pub struct DepositProof<P, FH, FHG, S>
@@ -37,16 +38,21 @@ where
     fn generate_public_inputs(pub_in: &DepositPublicInputs<P>) -> Vec<P::BaseField> {
         let mut inputs = Vec::new();
         inputs.push(P::BaseField::from(pub_in.deposit_amount));
+        inputs.push(pub_in.asset_id);
         inputs.push(P::BaseField::from(pub_in.leaf_index));
         inputs.push(pub_in.leaf);
-        inputs.push(pub_in.prev_root);
-        inputs.extend_from_slice(&pub_in.update_nodes);
 
+        for pubkey in pub_in.auditor_pubkeys.iter() {
+            inputs.push(pubkey.x);
+            inputs.push(pubkey.y);
+        }
         if let Some(commit) = &pub_in.commit {
-            inputs.push(commit.commitment.0.x);
-            inputs.push(commit.commitment.0.y);
-            inputs.push(commit.commitment.1.x);
-            inputs.push(commit.commitment.1.y);
+            for commitment in commit.commitments.iter() {
+                inputs.push(commitment.0.x);
+                inputs.push(commitment.0.y);
+                inputs.push(commitment.1.x);
+                inputs.push(commitment.1.y);
+            }
         }
 
         inputs
@@ -56,7 +62,7 @@ where
         params: &DepositConstParams<P, FH>,
         pub_in: &DepositPublicInputs<P>,
         priv_in: &DepositPrivateInputs<P>,
-    ) -> DepositCircuit<P, FH, FHG> {
+    ) -> Result<DepositCircuit<P, FH, FHG>> {
         let commit = params.commit
             .as_ref()
             .zip(pub_in.commit.as_ref())
@@ -64,23 +70,109 @@ where
             .map(|((params, pub_in), priv_in)| {
                 Commit::new(
                     params.nullifier_params.clone(),
-                    params.pubkey,
                     priv_in.nonce_bits.clone(),
-                    pub_in.commitment.clone(),
+                    priv_in.escrow.clone(),
+                    pub_in.commitments.clone(),
                 )
             });
 
-        DepositCircuit::<P, FH, FHG>::new(
+        Ok(DepositCircuit::<P, FH, FHG>::new(
             params.leaf_params.clone(),
-            params.inner_params.clone(),
             pub_in.deposit_amount,
+            pub_in.asset_id,
             pub_in.leaf_index,
             pub_in.leaf,
-            pub_in.prev_root,
-            pub_in.update_nodes.clone(),
-            priv_in.secret,
-            priv_in.neighbor_nodes.clone(),
+            priv_in.owner,
+            pub_in.auditor_pubkeys.clone(),
             commit,
+        ))
+    }
+}
+
+pub struct BatchDepositProof<P, FH, FHG, S>
+where
+    P: TEModelParameters,
+    FH: FieldHasher<P::BaseField>,
+    FHG: FieldHasherGadget<P::BaseField, FH>,
+    S: SNARK<P::BaseField>,
+    P::BaseField: PrimeField,
+{
+    _p: PhantomData<P>,
+    _fh: PhantomData<FH>,
+    _fhg: PhantomData<FHG>,
+    _s: PhantomData<S>,
+}
+
+impl<P, FH, FHG, S> ProofScheme<
+    P::BaseField,
+    BatchDepositCircuit<P, FH, FHG>,
+    S,
+    BatchDepositVanillaProof<P, FH>,
+> for BatchDepositProof<P, FH, FHG, S>
+where
+    P: TEModelParameters,
+    FH: FieldHasher<P::BaseField>,
+    FHG: FieldHasherGadget<P::BaseField, FH>,
+    S: SNARK<P::BaseField>,
+    P::BaseField: PrimeField,
+{
+    fn generate_public_inputs(pub_in: &BatchDepositPublicInputs<P>) -> Vec<P::BaseField> {
+        let mut inputs = Vec::new();
+        inputs.extend(pub_in.deposit_amounts.iter().map(|amount| P::BaseField::from(*amount)));
+        inputs.push(pub_in.asset_id);
+        inputs.push(P::BaseField::from(pub_in.leaf_index));
+        inputs.extend_from_slice(&pub_in.leaves);
+        inputs.extend_from_slice(&pub_in.subtree_nodes);
+
+        for pubkey in pub_in.auditor_pubkeys.iter() {
+            inputs.push(pubkey.x);
+            inputs.push(pubkey.y);
+        }
+        if let Some(commits) = &pub_in.commit {
+            commits.iter().for_each(|commit| {
+                for commitment in commit.commitments.iter() {
+                    inputs.push(commitment.0.x);
+                    inputs.push(commitment.0.y);
+                    inputs.push(commitment.1.x);
+                    inputs.push(commitment.1.y);
+                }
+            });
+        }
+
+        inputs
+    }
+
+    fn generate_circuit(
+        params: &BatchDepositConstParams<P, FH>,
+        pub_in: &BatchDepositPublicInputs<P>,
+        priv_in: &BatchDepositPrivateInputs<P>,
+    ) -> Result<BatchDepositCircuit<P, FH, FHG>> {
+        let commits = params.commit
+            .as_ref()
+            .zip(pub_in.commit.as_ref())
+            .zip(priv_in.commit.as_ref())
+            .map(|((params, pub_in), priv_in)| {
+                pub_in.iter().zip(priv_in.iter()).map(|(pub_in, priv_in)| {
+                    Commit::new(
+                        params.nullifier_params.clone(),
+                        priv_in.nonce_bits.clone(),
+                        priv_in.escrow.clone(),
+                        pub_in.commitments.clone(),
+                    )
+                }).collect()
+            });
+
+        BatchDepositCircuit::<P, FH, FHG>::new(
+            params.leaf_params.clone(),
+            params.inner_params.clone(),
+            pub_in.deposit_amounts.clone(),
+            pub_in.asset_id,
+            pub_in.leaf_index,
+            pub_in.leaves.clone(),
+            pub_in.subtree_nodes.clone(),
+            priv_in.owners.clone(),
+            pub_in.auditor_pubkeys.clone(),
+            commits,
         )
     }
 }
@@ -113,21 +205,38 @@ where
     P::BaseField: PrimeField,
 {
     fn generate_public_inputs(pub_in: &WithdrawPublicInputs<P>) -> Vec<P::BaseField> {
-        let mut inputs = Vec::new();
-        inputs.push(P::BaseField::from(pub_in.withdraw_amount));
-        inputs.push(pub_in.receiver);
-        inputs.push(P::BaseField::from(pub_in.dst_leaf_index));
-        inputs.push(pub_in.dst_leaf);
-        inputs.push(pub_in.prev_root);
-        inputs.push(pub_in.nullifier_point.x);
-        inputs.push(pub_in.nullifier_point.y);
-        inputs.extend_from_slice(&pub_in.update_nodes);
+        let mut inputs = vec![
+            P::BaseField::from(pub_in.withdraw_amount),
+            pub_in.asset_id,
+            pub_in.receiver,
+            pub_in.relayer,
+            P::BaseField::from(pub_in.fee),
+            P::BaseField::from(pub_in.dst_leaf_index),
+            P::BaseField::from(pub_in.min_anonymity_gap),
+            pub_in.dst_leaf,
+            pub_in.src_root,
+            pub_in.nullifier_point.x,
+            pub_in.nullifier_point.y,
+        ];
 
+        for pubkey in pub_in.auditor_pubkeys.iter() {
+            inputs.push(pubkey.x);
+            inputs.push(pubkey.y);
+        }
         if let Some(commit) = &pub_in.commit {
-            inputs.push(commit.commitment.0.x);
-            inputs.push(commit.commitment.0.y);
-            inputs.push(commit.commitment.1.x);
-            inputs.push(commit.commitment.1.y);
+            for commitment in commit.commitments.iter() {
+                inputs.push(commitment.0.x);
+                inputs.push(commitment.0.y);
+                inputs.push(commitment.1.x);
+                inputs.push(commitment.1.y);
+            }
+        }
+
+        if let Some(exclusion_root) = pub_in.exclusion_root {
+            inputs.push(exclusion_root);
+        }
+        if let Some(allowlist_root) = pub_in.allowlist_root {
+            inputs.push(allowlist_root);
         }
 
         inputs
@@ -137,7 +246,7 @@ where
         params: &WithdrawConstParams<P, FH>,
         pub_in: &WithdrawPublicInputs<P>,
         priv_in: &WithdrawPrivateInputs<P>,
-    ) -> WithdrawCircuit<P, FH, FHG> {
+    ) -> Result<WithdrawCircuit<P, FH, FHG>> {
         let commit = params.commit
             .as_ref()
             .zip(pub_in.commit.as_ref())
@@ -145,29 +254,158 @@ where
             .map(|((params, pub_in), priv_in)| {
                 Commit::new(
                     params.nullifier_params.clone(),
-                    params.pubkey,
                     priv_in.nonce_bits.clone(),
-                    pub_in.commitment.clone(),
+                    priv_in.escrow.clone(),
+                    pub_in.commitments.clone(),
                 )
             });
+        let src_escrow = priv_in.src_escrow.as_ref().map(|escrow| {
+            Escrow::new(
+                params.nullifier_params.clone(),
+                escrow.pubkeys.clone(),
+                escrow.nonce_bits.clone(),
+                escrow.escrow.clone(),
+            )
+        });
 
-        WithdrawCircuit::<P, FH, FHG>::new(
+        Ok(WithdrawCircuit::<P, FH, FHG>::new(
             params.nullifier_params.clone(),
             params.leaf_params.clone(),
             params.inner_params.clone(),
             pub_in.withdraw_amount,
+            pub_in.asset_id,
             pub_in.receiver,
+            pub_in.relayer,
+            pub_in.fee,
             pub_in.dst_leaf_index,
+            pub_in.min_anonymity_gap,
             pub_in.dst_leaf,
-            pub_in.prev_root,
+            pub_in.src_root,
             pub_in.nullifier_point,
-            pub_in.update_nodes.clone(),
             priv_in.src_leaf_index,
             priv_in.balance,
             priv_in.secret,
             priv_in.src_neighbor_nodes.clone(),
-            priv_in.dst_neighbor_nodes.clone(),
+            pub_in.auditor_pubkeys.clone(),
             commit,
-        )
+            src_escrow,
+            pub_in.exclusion_root,
+            priv_in.exclusion_neighbor_nodes.clone(),
+            pub_in.allowlist_root,
+            priv_in.allowlist_neighbor_nodes.clone(),
+        ))
+    }
+}
+
+pub struct TransferProof<P, FH, FHG, S>
+where
+    P: TEModelParameters,
+    FH: FieldHasher<P::BaseField>,
+    FHG: FieldHasherGadget<P::BaseField, FH>,
+    S: SNARK<P::BaseField>,
+    P::BaseField: PrimeField,
+{
+    _p: PhantomData<P>,
+    _fh: PhantomData<FH>,
+    _fhg: PhantomData<FHG>,
+    _s: PhantomData<S>,
+}
+
+impl<P, FH, FHG, S> ProofScheme<
+    P::BaseField,
+    TransferCircuit<P, FH, FHG>,
+    S,
+    TransferVanillaProof<P, FH>,
+> for TransferProof<P, FH, FHG, S>
+where
+    P: TEModelParameters,
+    FH: FieldHasher<P::BaseField>,
+    FHG: FieldHasherGadget<P::BaseField, FH>,
+    S: SNARK<P::BaseField>,
+    P::BaseField: PrimeField,
+{
+    fn generate_public_inputs(pub_in: &TransferPublicInputs<P>) -> Vec<P::BaseField> {
+        let mut inputs = vec![
+            P::BaseField::from(pub_in.withdraw_amount),
+            pub_in.asset_id,
+            pub_in.receiver,
+            pub_in.src_root,
+        ];
+        for nullifier_point in pub_in.nullifier_points.iter() {
+            inputs.push(nullifier_point.x);
+            inputs.push(nullifier_point.y);
+        }
+        inputs.push(P::BaseField::from(pub_in.dst_leaf_index));
+        inputs.extend_from_slice(&pub_in.dst_leaves);
+
+        for pubkey in pub_in.auditor_pubkeys.iter() {
+            inputs.push(pubkey.x);
+            inputs.push(pubkey.y);
+        }
+        if let Some(commit) = &pub_in.commit {
+            for commit in commit.iter() {
+                for commitment in commit.commitments.iter() {
+                    inputs.push(commitment.0.x);
+                    inputs.push(commitment.0.y);
+                    inputs.push(commitment.1.x);
+                    inputs.push(commitment.1.y);
+                }
+            }
+        }
+
+        inputs
+    }
+
+    fn generate_circuit(
+        params: &TransferConstParams<P, FH>,
+        pub_in: &TransferPublicInputs<P>,
+        priv_in: &TransferPrivateInputs<P>,
+    ) -> Result<TransferCircuit<P, FH, FHG>> {
+        let commit = params.commit
+            .as_ref()
+            .zip(pub_in.commit.as_ref())
+            .zip(priv_in.commit.as_ref())
+            .map(|((params, pub_in), priv_in)| {
+                [0, 1].map(|i| {
+                    Commit::new(
+                        params.nullifier_params.clone(),
+                        priv_in[i].nonce_bits.clone(),
+                        priv_in[i].escrow.clone(),
+                        pub_in[i].commitments.clone(),
+                    )
+                })
+            });
+        let src_escrow = priv_in.src_escrow.as_ref().map(|escrow| {
+            escrow.each_ref().map(|escrow| {
+                Escrow::new(
+                    params.nullifier_params.clone(),
+                    escrow.pubkeys.clone(),
+                    escrow.nonce_bits.clone(),
+                    escrow.escrow.clone(),
+                )
+            })
+        });
+
+        Ok(TransferCircuit::<P, FH, FHG>::new(
+            params.nullifier_params.clone(),
+            params.leaf_params.clone(),
+            params.inner_params.clone(),
+            pub_in.withdraw_amount,
+            pub_in.asset_id,
+            pub_in.receiver,
+            pub_in.src_root,
+            pub_in.nullifier_points,
+            pub_in.dst_leaf_index,
+            pub_in.dst_leaves,
+            priv_in.src_leaf_indexes,
+            priv_in.src_balances,
+            priv_in.dst_amounts,
+            priv_in.secret,
+            priv_in.recipient,
+            priv_in.src_neighbor_nodes.clone(),
+            pub_in.auditor_pubkeys.clone(),
+            commit,
+            src_escrow,
+        ))
     }
 }
@@ -0,0 +1,185 @@
+use ark_std::{marker::PhantomData, rc::Rc};
+use ark_ec::{TEModelParameters, twisted_edwards_extended::GroupAffine};
+use ark_ff::PrimeField;
+use num_traits::Zero;
+
+use crate::error::{MazeLibError, Result};
+use super::{hasher::FieldHasher, VanillaProof, check_length, check_leaf_index, merkle::gen_subtree};
+use super::commit::{self, CommitConstParams, CommitOriginInputs, CommitPrivateInputs, CommitPublicInputs, gen_note_owner};
+
+#[derive(Default)]
+pub struct BatchDepositVanillaProof<P, FH>
+where
+    P: TEModelParameters,
+    FH: FieldHasher<P::BaseField>,
+    P::BaseField: PrimeField,
+{
+    _p: PhantomData<P>,
+    _fh: PhantomData<FH>,
+}
+
+#[derive(Debug)]
+pub struct BatchDepositConstParams<P, FH>
+where
+    P: TEModelParameters,
+    FH: FieldHasher<P::BaseField>,
+    P::BaseField: PrimeField,
+{
+    pub leaf_params: Rc<FH::Parameters>,
+    pub inner_params: Rc<FH::Parameters>,
+    pub height: usize,
+    /// depth of the subtree, `1 << depth` leaves are deposited in a batch
+    pub depth: usize,
+    pub commit: Option<CommitConstParams<P, FH>>,
+}
+
+#[derive(Debug)]
+pub struct BatchDepositOriginInputs<P: TEModelParameters> {
+    /// index of the first leaf, must be aligned to the subtree
+    pub leaf_index: u64,
+    pub deposit_amounts: Vec<u64>,
+    /// asset of all notes in the batch
+    pub asset_id: P::BaseField,
+    pub owners: Vec<P::BaseField>,
+    pub commit: Option<Vec<CommitOriginInputs<P>>>,
+}
+
+#[derive(Debug)]
+pub struct BatchDepositPublicInputs<P: TEModelParameters> {
+    pub deposit_amounts: Vec<u64>,
+    pub asset_id: P::BaseField,
+    pub leaf_index: u64,
+    pub leaves: Vec<P::BaseField>,
+    /// nodes of the subtree from layer 1 up to the subtree root, stored by the contract with the leaves
+    pub subtree_nodes: Vec<P::BaseField>,
+    /// auditor keys the commitments are encrypted to, empty without commitments
+    pub auditor_pubkeys: Vec<GroupAffine<P>>,
+    pub commit: Option<Vec<CommitPublicInputs<P>>>,
+}
+
+#[derive(Debug)]
+pub struct BatchDepositPrivateInputs<P: TEModelParameters> {
+    pub owners: Vec<P::BaseField>,
+    pub commit: Option<Vec<CommitPrivateInputs<P>>>,
+}
+
+impl<P, FH> VanillaProof<P::BaseField> for BatchDepositVanillaProof<P, FH>
+where
+    P: TEModelParameters,
+    FH: FieldHasher<P::BaseField>,
+    P::BaseField: PrimeField,
+{
+    type ConstParams = BatchDepositConstParams<P, FH>;
+    type OriginInputs = BatchDepositOriginInputs<P>;
+    type PublicInputs = BatchDepositPublicInputs<P>;
+    type PrivateInputs = BatchDepositPrivateInputs<P>;
+
+    fn blank_proof(params: &Self::ConstParams) -> Result<(Self::PublicInputs, Self::PrivateInputs)> {
+        let size = 1 << params.depth;
+        let origin_inputs = BatchDepositOriginInputs {
+            leaf_index: 0,
+            deposit_amounts: vec![1; size],
+            asset_id: P::BaseField::zero(),
+            owners: vec![P::BaseField::zero(); size],
+            commit: params.commit.as_ref().map(|commit| {
+                (0..size).map(|_| CommitOriginInputs {
+                    nonces: vec![P::ScalarField::zero(); commit.pubkeys.len()],
+                    escrow: vec![(GroupAffine::zero(), GroupAffine::zero()); commit.pubkeys.len()],
+                }).collect()
+            }),
+        };
+
+        Self::generate_vanilla_proof(params, &origin_inputs)
+    }
+
+    fn generate_vanilla_proof(
+        params: &BatchDepositConstParams<P, FH>,
+        orig_in: &BatchDepositOriginInputs<P>,
+    ) -> Result<(Self::PublicInputs, Self::PrivateInputs)> {
+        if params.depth == 0 || params.depth > params.height {
+            return Err(MazeLibError::InvalidInputs(format!("subtree depth {} is invalid for height {}", params.depth, params.height)));
+        }
+        let size = 1 << params.depth;
+        check_length("deposit amounts", orig_in.deposit_amounts.len(), size)?;
+        check_length("owners", orig_in.owners.len(), size)?;
+        check_leaf_index(orig_in.leaf_index, params.height)?;
+        if !orig_in.leaf_index.is_multiple_of(size as u64) {
+            return Err(MazeLibError::InvalidIndex(format!("{} is not aligned to a subtree of {} leaves", orig_in.leaf_index, size)));
+        }
+        if orig_in.deposit_amounts.contains(&0) {
+            return Err(MazeLibError::InvalidInputs("deposit amount must be greater than 0".into()));
+        }
+
+        let commit = params.commit
+            .as_ref()
+            .zip(orig_in.commit.as_ref())
+            .map(|(params, jj_orig_in)| {
+                check_length("commit inputs", jj_orig_in.len(), size)?;
+                jj_orig_in
+                    .iter()
+                    .zip(orig_in.owners.iter())
+                    .enumerate()
+                    .map(|(i, (jj_orig_in, owner))| {
+                        commit::generate_vanilla_proof(params, jj_orig_in, orig_in.leaf_index + i as u64, *owner)
+                    })
+                    .collect::<Result<Vec<_>>>()
+            })
+            .transpose()?;
+        let (jj_pub_in, jj_priv_in) =
+            if let Some(commit) = commit {
+                let (pub_in, priv_in) = commit.into_iter().unzip();
+                (Some(pub_in), Some(priv_in))
+            } else {
+                (None, None)
+            };
+
+        // every note is bound to the escrow it is committed with
+        let note_owners = match params.commit.as_ref().zip(orig_in.commit.as_ref()) {
+            Some((commit, jj_orig_in)) => orig_in.owners
+                .iter()
+                .zip(jj_orig_in.iter())
+                .map(|(owner, jj_orig_in)| {
+                    gen_note_owner::<P, FH>(&commit.nullifier_params, *owner, &commit.pubkeys, &jj_orig_in.escrow)
+                })
+                .collect::<Result<Vec<_>>>()?,
+            None => orig_in.owners.clone(),
+        };
+        let leaves = orig_in.deposit_amounts
+            .iter()
+            .zip(note_owners.iter())
+            .enumerate()
+            .map(|(i, (amount, owner))| {
+                FH::hash(&params.leaf_params, &[
+                    P::BaseField::from(orig_in.leaf_index + i as u64),
+                    orig_in.asset_id,
+                    P::BaseField::from(*amount),
+                    *owner,
+                ])
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let subtree_nodes = gen_subtree::<_, FH>(&params.inner_params, &leaves)?;
+
+        // auditor keys are public inputs, so the vault can rotate them without a new setup
+        let auditor_pubkeys = params.commit
+            .as_ref()
+            .filter(|_| jj_pub_in.is_some())
+            .map(|commit| commit.pubkeys.clone())
+            .unwrap_or_default();
+
+        let pub_in = BatchDepositPublicInputs {
+            deposit_amounts: orig_in.deposit_amounts.clone(),
+            asset_id: orig_in.asset_id,
+            leaf_index: orig_in.leaf_index,
+            leaves,
+            subtree_nodes,
+            auditor_pubkeys,
+            commit: jj_pub_in,
+        };
+        let priv_in = BatchDepositPrivateInputs {
+            owners: orig_in.owners.clone(),
+            commit: jj_priv_in,
+        };
+
+        Ok((pub_in, priv_in))
+    }
+}
@@ -1,9 +1,99 @@
 use ark_std::rc::Rc;
-use anyhow::{anyhow, Result};
 use ark_ff::{PrimeField, BigInteger, FpParameters};
 use ark_ec::{TEModelParameters, twisted_edwards_extended::{GroupProjective, GroupAffine}, ProjectiveCurve, AffineCurve};
 
-use super::hasher::FieldHasher;
+use crate::error::Result;
+use super::{hasher::FieldHasher, check_length};
+
+/// Elgamal ciphertext to one auditor key.
+pub type Ciphertext<P> = (GroupAffine<P>, GroupAffine<P>);
+
+type ScalarBigInt<P> = <<P as ark_ec::ModelParameters>::ScalarField as PrimeField>::BigInt;
+
+// truncate little endian bits to the capacity of the scalar field
+pub(crate) fn to_scalar<P: TEModelParameters>(mut bits: Vec<bool>) -> (ScalarBigInt<P>, Vec<bool>) {
+    let scalar_bits = <<P::ScalarField as PrimeField>::Params as FpParameters>::CAPACITY as usize;
+    bits.truncate(scalar_bits);
+
+    (<ScalarBigInt<P> as BigInteger>::from_bits_le(&bits), bits)
+}
+
+/// Nullifier key point: nullifier_key * G. Nullifier points of notes are multiples of it.
+pub fn gen_nullifier_key_point<P>(nullifier_key: P::BaseField) -> GroupAffine<P>
+where
+    P: TEModelParameters,
+    P::BaseField: PrimeField,
+{
+    let (nullifier_key, _) = to_scalar::<P>(nullifier_key.into_repr().to_bits_le());
+
+    GroupProjective::<P>::prime_subgroup_generator().mul(nullifier_key).into_affine()
+}
+
+/// Scalar of the nullifier point of a note: hash(leaf_index | owner). Anyone who knows the owner
+/// key can compute it, but not the nullifier point, which also takes the nullifier key.
+pub(crate) fn gen_nullifier_scalar<P, FH>(
+    nullifier_params: &FH::Parameters,
+    leaf_index: u64,
+    owner: P::BaseField,
+) -> Result<(ScalarBigInt<P>, Vec<bool>)>
+where
+    P: TEModelParameters,
+    FH: FieldHasher<P::BaseField>,
+    P::BaseField: PrimeField,
+{
+    let scalar = FH::hash(nullifier_params, &[P::BaseField::from(leaf_index), owner])?;
+
+    Ok(to_scalar::<P>(scalar.into_repr().to_bits_le()))
+}
+
+/// Escrow of the nullifier key point to each auditor key: (nonce * G, nullifier_key * G + nonce * P).
+/// It is published with the shielded address, so that a payer commits the nullifier of a note for
+/// the owner without learning it, and only the owner, who knows the nonces, can spend the note.
+pub fn gen_escrow<P>(
+    pubkeys: &[GroupAffine<P>],
+    nullifier_key: P::BaseField,
+    nonces: &[P::ScalarField],
+) -> Result<Vec<Ciphertext<P>>>
+where
+    P: TEModelParameters,
+    P::BaseField: PrimeField,
+{
+    check_length("escrow nonces", nonces.len(), pubkeys.len())?;
+    let g = GroupProjective::<P>::prime_subgroup_generator();
+    let nullifier_key_point = gen_nullifier_key_point::<P>(nullifier_key).into_projective();
+
+    let escrow = pubkeys.iter().zip(nonces.iter()).map(|(pubkey, nonce)| {
+        let (nonce, _) = to_scalar::<P>(nonce.into_repr().to_bits_le());
+        let escrow_0 = g.mul(nonce);
+        let escrow_1 = nullifier_key_point + pubkey.into_projective().mul(nonce);
+
+        (escrow_0.into_affine(), escrow_1.into_affine())
+    }).collect();
+
+    Ok(escrow)
+}
+
+/// Owner bound into the leaf of a committed note: the owner key chained with the auditor keys and
+/// the escrow, by hash(acc | coordinate) for every coordinate. Without auditors it is the owner key.
+pub fn gen_note_owner<P, FH>(
+    nullifier_params: &FH::Parameters,
+    owner: P::BaseField,
+    pubkeys: &[GroupAffine<P>],
+    escrow: &[Ciphertext<P>],
+) -> Result<P::BaseField>
+where
+    P: TEModelParameters,
+    FH: FieldHasher<P::BaseField>,
+    P::BaseField: PrimeField,
+{
+    check_length("escrow", escrow.len(), pubkeys.len())?;
+
+    pubkeys.iter().zip(escrow.iter()).try_fold(owner, |acc, (pubkey, (escrow_0, escrow_1))| {
+        [pubkey.x, pubkey.y, escrow_0.x, escrow_0.y, escrow_1.x, escrow_1.y]
+            .into_iter()
+            .try_fold(acc, |acc, coordinate| FH::hash(nullifier_params, &[acc, coordinate]))
+    })
+}
 
 #[derive(Debug)]
 pub struct CommitConstParams<P, FH>
@@ -13,67 +103,107 @@ where
     P::BaseField: PrimeField,
 {
     pub nullifier_params: Rc<FH::Parameters>,
-    pub pubkey: GroupAffine<P>,
+    /// viewing keys of independent auditors, the nullifier is encrypted to each of them
+    pub pubkeys: Vec<GroupAffine<P>>,
 }
 
 #[derive(Debug)]
 pub struct CommitOriginInputs<P: TEModelParameters> {
-    pub nonce: P::ScalarField,
+    /// one nonce for each auditor key
+    pub nonces: Vec<P::ScalarField>,
+    /// escrow of the note owner to the auditor keys, taken from the shielded address
+    pub escrow: Vec<Ciphertext<P>>,
 }
 
 #[derive(Debug)]
 pub struct CommitPublicInputs<P: TEModelParameters> {
-    pub commitment: (GroupAffine<P>, GroupAffine<P>),
+    /// one ciphertext for each auditor key
+    pub commitments: Vec<Ciphertext<P>>,
+}
+
+#[derive(Debug)]
+pub struct CommitPrivateInputs<P: TEModelParameters> {
+    pub nonce_bits: Vec<Vec<bool>>,
+    pub escrow: Vec<Ciphertext<P>>,
+}
+
+/// Inputs to open the escrow a spent note was committed with.
+#[derive(Debug)]
+pub struct EscrowOriginInputs<P: TEModelParameters> {
+    /// auditor keys when the note was committed, which can differ from the current ones
+    pub pubkeys: Vec<GroupAffine<P>>,
+    /// one nonce for each auditor key
+    pub nonces: Vec<P::ScalarField>,
 }
 
 #[derive(Debug)]
-pub struct CommitPrivateInputs {
-    pub nonce_bits: Vec<bool>,
+pub struct EscrowPrivateInputs<P: TEModelParameters> {
+    pub pubkeys: Vec<GroupAffine<P>>,
+    pub nonce_bits: Vec<Vec<bool>>,
+    pub escrow: Vec<Ciphertext<P>>,
 }
 
+/// Commit the nullifier point of the note at `leaf_index` of `owner` to each auditor key.
+/// The escrow is scaled by the nullifier scalar and then rerandomized:
+/// (s * E0 + nonce * G, s * E1 + nonce * P) decrypts to s * nullifier_key * G.
 pub fn generate_vanilla_proof<P, FH>(
     params: &CommitConstParams<P, FH>,
     orig_in: &CommitOriginInputs<P>,
     leaf_index: u64,
-    secret: P::BaseField,
-) -> Result<(CommitPublicInputs<P>, CommitPrivateInputs)>
+    owner: P::BaseField,
+) -> Result<(CommitPublicInputs<P>, CommitPrivateInputs<P>)>
 where
     P: TEModelParameters,
     FH: FieldHasher<P::BaseField>,
     P::BaseField: PrimeField,
 {
-    let scalar_bits = <<P::ScalarField as PrimeField>::Params as FpParameters>::CAPACITY as usize;
+    check_length("nonces", orig_in.nonces.len(), params.pubkeys.len())?;
+    check_length("escrow", orig_in.escrow.len(), params.pubkeys.len())?;
 
-    // convert nonce to nonce bits
-    let nonce: <P::ScalarField as PrimeField>::BigInt = orig_in.nonce.into();
-    let mut nonce_bits = nonce.to_bits_le();
-    nonce_bits.truncate(scalar_bits);
-    let nonce: <P::ScalarField as PrimeField>::BigInt = <<P::ScalarField as PrimeField>::BigInt as BigInteger>::from_bits_le(&nonce_bits);
-
-    let nullifier = FH::hash(
-        &params.nullifier_params,
-        &[P::BaseField::from(leaf_index), secret],
-    ).map_err(|e| anyhow!("hash error: {}", e))?;
-
-    // truncate nullifier to scalar field
-    let nullifier: <P::BaseField as PrimeField>::BigInt = nullifier.into();
-    let mut nullifier_bits = nullifier.to_bits_le();
-    nullifier_bits.truncate(scalar_bits);
-    let nullifier: <P::ScalarField as PrimeField>::BigInt = <<P::ScalarField as PrimeField>::BigInt as BigInteger>::from_bits_le(&nullifier_bits);
-
-    // encrypt nullifier by `Elgamal` algorithm.
-    // compute commitment_0 = nonce * G
+    let (nullifier, _) = gen_nullifier_scalar::<P, FH>(&params.nullifier_params, leaf_index, owner)?;
     let g = GroupProjective::prime_subgroup_generator();
-    let commitment_0 = g.mul(nonce);
-    
-    // compute commitment_1 = nullifier * G + nonce * P
-    let pubkey = params.pubkey.into_projective();
-    let commitment_1 = g.mul(nullifier) + pubkey.mul(nonce);
 
-    let pub_in = CommitPublicInputs {
-        commitment: (commitment_0.into_affine(), commitment_1.into_affine()),
-    };
-    let priv_in = CommitPrivateInputs { nonce_bits };
+    let (commitments, nonce_bits) = params.pubkeys
+        .iter()
+        .zip(orig_in.nonces.iter())
+        .zip(orig_in.escrow.iter())
+        .map(|((pubkey, nonce), (escrow_0, escrow_1))| {
+            // convert nonce to nonce bits
+            let (nonce, nonce_bits) = to_scalar::<P>(nonce.into_repr().to_bits_le());
+
+            // compute commitment_0 = nullifier * E0 + nonce * G
+            let commitment_0 = escrow_0.mul(nullifier) + g.mul(nonce);
+            // compute commitment_1 = nullifier * E1 + nonce * P
+            let commitment_1 = escrow_1.mul(nullifier) + pubkey.into_projective().mul(nonce);
+
+            ((commitment_0.into_affine(), commitment_1.into_affine()), nonce_bits)
+        })
+        .unzip();
+
+    let pub_in = CommitPublicInputs { commitments };
+    let priv_in = CommitPrivateInputs { nonce_bits, escrow: orig_in.escrow.clone() };
 
     Ok((pub_in, priv_in))
-}
\ No newline at end of file
+}
+
+/// Open the escrow of a spent note, the escrow is recomputed from the nullifier key of the spender.
+pub fn generate_escrow_proof<P>(
+    orig_in: &EscrowOriginInputs<P>,
+    nullifier_key: P::BaseField,
+) -> Result<EscrowPrivateInputs<P>>
+where
+    P: TEModelParameters,
+    P::BaseField: PrimeField,
+{
+    let escrow = gen_escrow(&orig_in.pubkeys, nullifier_key, &orig_in.nonces)?;
+    let nonce_bits = orig_in.nonces
+        .iter()
+        .map(|nonce| to_scalar::<P>(nonce.into_repr().to_bits_le()).1)
+        .collect();
+
+    Ok(EscrowPrivateInputs {
+        pubkeys: orig_in.pubkeys.clone(),
+        nonce_bits,
+        escrow,
+    })
+}
@@ -1,11 +1,11 @@
 use ark_std::{marker::PhantomData, rc::Rc};
-use anyhow::{anyhow, Result};
-use ark_ec::TEModelParameters;
+use ark_ec::{TEModelParameters, twisted_edwards_extended::GroupAffine};
 use ark_ff::PrimeField;
 use num_traits::Zero;
 
-use super::{hasher::FieldHasher, VanillaProof, merkle::gen_merkle_path};
-use super::commit::{self, CommitConstParams, CommitOriginInputs, CommitPrivateInputs, CommitPublicInputs};
+use crate::error::{MazeLibError, Result};
+use super::{hasher::FieldHasher, VanillaProof, check_leaf_index};
+use super::commit::{self, CommitConstParams, CommitOriginInputs, CommitPrivateInputs, CommitPublicInputs, gen_note_owner};
 
 #[derive(Default)]
 pub struct DepositVanillaProof<P, FH>
@@ -26,7 +26,6 @@ where
     P::BaseField: PrimeField,
 {
     pub leaf_params: Rc<FH::Parameters>,
-    pub inner_params: Rc<FH::Parameters>,
     pub height: usize,
     pub commit: Option<CommitConstParams<P, FH>>,
 }
@@ -35,26 +34,27 @@ where
 pub struct DepositOriginInputs<P: TEModelParameters> {
     pub leaf_index: u64,
     pub deposit_amount: u64,
-    pub secret: P::BaseField,
-    pub neighbor_nodes: Vec<P::BaseField>,
+    /// asset of the note, derived from the token mint
+    pub asset_id: P::BaseField,
+    pub owner: P::BaseField,
     pub commit: Option<CommitOriginInputs<P>>,
 }
 
 #[derive(Debug)]
 pub struct DepositPublicInputs<P: TEModelParameters> {
     pub deposit_amount: u64,
+    pub asset_id: P::BaseField,
     pub leaf_index: u64,
     pub leaf: P::BaseField,
-    pub prev_root: P::BaseField,
-    pub update_nodes: Vec<P::BaseField>,
+    /// auditor keys the commitments are encrypted to, empty without commitments
+    pub auditor_pubkeys: Vec<GroupAffine<P>>,
     pub commit: Option<CommitPublicInputs<P>>,
 }
 
 #[derive(Debug)]
 pub struct DepositPrivateInputs<P: TEModelParameters> {
-    pub secret: P::BaseField,
-    pub neighbor_nodes: Vec<(bool, P::BaseField)>,
-    pub commit: Option<CommitPrivateInputs>,
+    pub owner: P::BaseField,
+    pub commit: Option<CommitPrivateInputs<P>>,
 }
 
 impl<P, FH> VanillaProof<P::BaseField> for DepositVanillaProof<P, FH>
@@ -72,11 +72,12 @@ where
         let origin_inputs = DepositOriginInputs {
             leaf_index: 0,
             deposit_amount: 1,
-            secret: P::BaseField::zero(),
-            neighbor_nodes: vec![FH::empty_hash(); params.height],
-            commit: params.commit.as_ref().and(Some(CommitOriginInputs {
-                nonce: P::ScalarField::zero(),
-            })),
+            asset_id: P::BaseField::zero(),
+            owner: P::BaseField::zero(),
+            commit: params.commit.as_ref().map(|commit| CommitOriginInputs {
+                nonces: vec![P::ScalarField::zero(); commit.pubkeys.len()],
+                escrow: vec![(GroupAffine::zero(), GroupAffine::zero()); commit.pubkeys.len()],
+            }),
         };
 
         Self::generate_vanilla_proof(params, &origin_inputs)
@@ -86,45 +87,16 @@ where
         params: &DepositConstParams<P, FH>,
         orig_in: &DepositOriginInputs<P>,
     ) -> Result<(Self::PublicInputs, Self::PrivateInputs)> {
-        assert_eq!(orig_in.neighbor_nodes.len(), params.height);
-        assert!(orig_in.leaf_index < (1 << params.height));
-        assert!(orig_in.deposit_amount > 0, "deposit amount must be greater than 0");
-
-        let neighbor_nodes = orig_in.neighbor_nodes
-            .iter()
-            .enumerate()
-            .map(|(layer, node)| {
-                let is_left = ((orig_in.leaf_index >> layer) & 1) == 1;
-                Ok((is_left, node.clone()))
-            })
-            .collect::<Result<Vec<_>>>()?;
-
-        let leaf = FH::hash(&params.leaf_params, &[
-            P::BaseField::from(orig_in.leaf_index),
-            P::BaseField::from(orig_in.deposit_amount),
-            orig_in.secret,
-        ]).map_err(|e| anyhow!("hash error: {}", e))?;
-
-        let prev_root = gen_merkle_path::<_, FH>(
-            &params.inner_params,
-            &neighbor_nodes,
-            FH::empty_hash(),
-        )
-        .map_err(|e| anyhow!("gen merkle path error: {:?}", e))?
-        .last()
-        .unwrap()
-        .clone();
-        let update_nodes = gen_merkle_path::<_, FH>(
-            &params.inner_params,
-            &neighbor_nodes,
-            leaf,
-        ).map_err(|e| anyhow!("gen merkle path error: {:?}", e))?;
+        check_leaf_index(orig_in.leaf_index, params.height)?;
+        if orig_in.deposit_amount == 0 {
+            return Err(MazeLibError::InvalidInputs("deposit amount must be greater than 0".into()));
+        }
 
         let commit = params.commit
             .as_ref()
             .zip(orig_in.commit.as_ref())
             .map(|(params, jj_orig_in)| {
-                commit::generate_vanilla_proof(params, jj_orig_in, orig_in.leaf_index, orig_in.secret)
+                commit::generate_vanilla_proof(params, jj_orig_in, orig_in.leaf_index, orig_in.owner)
             })
             .transpose()?;
         let (jj_pub_in, jj_priv_in) =
@@ -134,17 +106,35 @@ where
                 (None, None)
             };
 
+        // the note is bound to the escrow it is committed with
+        let note_owner = match params.commit.as_ref().zip(orig_in.commit.as_ref()) {
+            Some((commit, jj_orig_in)) => gen_note_owner::<P, FH>(&commit.nullifier_params, orig_in.owner, &commit.pubkeys, &jj_orig_in.escrow)?,
+            None => orig_in.owner,
+        };
+        let leaf = FH::hash(&params.leaf_params, &[
+            P::BaseField::from(orig_in.leaf_index),
+            orig_in.asset_id,
+            P::BaseField::from(orig_in.deposit_amount),
+            note_owner,
+        ])?;
+
+        // auditor keys are public inputs, so the vault can rotate them without a new setup
+        let auditor_pubkeys = params.commit
+            .as_ref()
+            .filter(|_| jj_pub_in.is_some())
+            .map(|commit| commit.pubkeys.clone())
+            .unwrap_or_default();
+
         let pub_in = DepositPublicInputs {
             deposit_amount: orig_in.deposit_amount,
+            asset_id: orig_in.asset_id,
             leaf_index: orig_in.leaf_index,
             leaf,
-            prev_root,
-            update_nodes,
+            auditor_pubkeys,
             commit: jj_pub_in,
         };
         let priv_in = DepositPrivateInputs {
-            secret: orig_in.secret,
-            neighbor_nodes,
+            owner: orig_in.owner,
             commit: jj_priv_in,
         };
 
@@ -1,8 +1,8 @@
 use ark_std::marker::PhantomData;
 use ark_ff::PrimeField;
-use ark_crypto_primitives::Error;
 use arkworks_utils::mimc::{MiMCParameters, MiMCError};
 
+use crate::error::{MazeLibError, Result};
 use super::FieldHasher;
 
 #[derive(Clone)]
@@ -15,29 +15,29 @@ impl<F: PrimeField> FieldHasher<F> for MIMCHasher<F> {
         F::zero()
     }
 
-    fn hash(params: &Self::Parameters, inputs: &[F]) -> Result<F, Error> {
-        assert!(
-            inputs.len() < params.num_inputs,
-            "incorrect input length {:?} for width {:?}",
-            inputs.len(),
-            params.num_inputs,
-        );
+    fn hash(params: &Self::Parameters, inputs: &[F]) -> Result<F> {
+        if inputs.len() >= params.num_inputs {
+            return Err(MazeLibError::Hash(format!("incorrect input length {} for width {}", inputs.len(), params.num_inputs)));
+        }
 
         let mut buffer = inputs.to_vec();
         buffer.resize(params.num_inputs, F::zero());
 
-		let result = Self::mimc(params, buffer)?
+		let result = Self::mimc(params, buffer)
+            .map_err(|e| MazeLibError::Hash(e.to_string()))?
             .get(0)
             .cloned()
-            .ok_or(MiMCError::InvalidInputs)?;
+            .ok_or_else(|| MazeLibError::Hash(MiMCError::InvalidInputs.to_string()))?;
 
         Ok(result)
     }
 }
 
 impl<F: PrimeField> MIMCHasher<F> {
-    fn mimc(params: &MiMCParameters<F>, state: Vec<F>) -> Result<Vec<F>, MiMCError> {
-		assert!(state.len() == params.num_inputs);
+    fn mimc(params: &MiMCParameters<F>, state: Vec<F>) -> ark_std::result::Result<Vec<F>, MiMCError> {
+		if state.len() != params.num_inputs {
+			return Err(MiMCError::InvalidInputs);
+		}
 		let mut l_out: F = F::zero();
 		let mut r_out: F = F::zero();
 		for (i, s) in state.iter().enumerate() {
@@ -67,7 +67,7 @@ impl<F: PrimeField> MIMCHasher<F> {
 		Ok(outs)
 	}
 
-	fn feistel(params: &MiMCParameters<F>, left: F, right: F) -> Result<[F; 2], MiMCError> {
+	fn feistel(params: &MiMCParameters<F>, left: F, right: F) -> ark_std::result::Result<[F; 2], MiMCError> {
 		let mut x_l = left;
 		let mut x_r = right;
 		let mut c: F;
@@ -1,17 +1,20 @@
 pub mod mimc;
 pub mod poseidon;
+pub mod poseidon2;
+pub mod rescue;
 
-use ark_crypto_primitives::Error;
 use ark_ff::PrimeField;
 
+use crate::error::Result;
+
 pub trait FieldHasher<F: PrimeField> {
 	type Parameters: Clone + Default;
 
     fn empty_hash() -> F;
 
-	fn hash(params: &Self::Parameters, inputs: &[F]) -> Result<F, Error>;
+	fn hash(params: &Self::Parameters, inputs: &[F]) -> Result<F>;
 	
-	fn hash_two(params: &Self::Parameters, left: F, right: F) -> Result<F, Error> {
+	fn hash_two(params: &Self::Parameters, left: F, right: F) -> Result<F> {
 		Self::hash(params, &[left, right])
 	}
 }
\ No newline at end of file
@@ -1,9 +1,9 @@
 use ark_std::marker::PhantomData;
 use ark_ff::PrimeField;
-use ark_crypto_primitives::Error;
 use arkworks_utils::poseidon::{PoseidonParameters, PoseidonError};
 use arkworks_gadgets::poseidon::CRH as PoseidonCRH;
 
+use crate::error::{MazeLibError, Result};
 use super::FieldHasher;
 
 #[derive(Clone)]
@@ -16,23 +16,21 @@ impl<F: PrimeField> FieldHasher<F> for PoseidonHasher<F> {
         F::zero()
     }
 
-    fn hash(params: &Self::Parameters, inputs: &[F]) -> Result<F, Error> {
-        assert!(
-            inputs.len() < params.width.into(),
-            "incorrect input length {:?} for width {:?}",
-            inputs.len(),
-            params.width,
-        );
+    fn hash(params: &Self::Parameters, inputs: &[F]) -> Result<F> {
+        if inputs.len() >= params.width.into() {
+            return Err(MazeLibError::Hash(format!("incorrect input length {} for width {}", inputs.len(), params.width)));
+        }
 
         let mut buffer = Vec::with_capacity(params.width as usize);
         buffer.push(F::zero());
         buffer.extend_from_slice(inputs);
         buffer.resize(params.width as usize, F::zero());
 
-		let result = PoseidonCRH::permute(params, buffer)?
+		let result = PoseidonCRH::permute(params, buffer)
+            .map_err(|e| MazeLibError::Hash(e.to_string()))?
             .get(0)
             .cloned()
-            .ok_or(PoseidonError::InvalidInputs)?;
+            .ok_or_else(|| MazeLibError::Hash(PoseidonError::InvalidInputs.to_string()))?;
 
         Ok(result)
     }
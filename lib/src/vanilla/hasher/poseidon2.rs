@@ -0,0 +1,122 @@
+use ark_std::marker::PhantomData;
+use ark_ff::PrimeField;
+
+use crate::error::{MazeLibError, Result};
+use super::FieldHasher;
+
+#[derive(Debug, Default, Clone)]
+pub struct Poseidon2Parameters<F: PrimeField> {
+    /// The size of the permutation, in field elements.
+    pub width: usize,
+    /// Number of full rounds, half of them before the partial rounds.
+    pub full_rounds: usize,
+    /// Number of partial rounds.
+    pub partial_rounds: usize,
+    /// The exponent of the S-box.
+    pub alpha: u64,
+    /// Round constants of the full rounds, `width` constants per round.
+    pub external_rc: Vec<Vec<F>>,
+    /// Round constants of the partial rounds, added to the first element only.
+    pub internal_rc: Vec<F>,
+    /// Diagonal of the internal matrix minus one, the matrix is `1 + diag(internal_diag_m_1)`.
+    pub internal_diag_m_1: Vec<F>,
+    /// The capacity element of the sponge, a domain tag that separates instances sharing a permutation.
+    pub domain: F,
+}
+
+#[derive(Clone)]
+pub struct Poseidon2Hasher<F>(PhantomData<F>);
+
+impl<F: PrimeField> FieldHasher<F> for Poseidon2Hasher<F> {
+    type Parameters = Poseidon2Parameters<F>;
+
+    fn empty_hash() -> F {
+        F::zero()
+    }
+
+    fn hash(params: &Self::Parameters, inputs: &[F]) -> Result<F> {
+        if inputs.len() >= params.width {
+            return Err(MazeLibError::Hash(format!("incorrect input length {} for width {}", inputs.len(), params.width)));
+        }
+
+        let mut buffer = Vec::with_capacity(params.width);
+        buffer.push(params.domain);
+        buffer.extend_from_slice(inputs);
+        buffer.resize(params.width, F::zero());
+
+        let result = Self::permute(params, buffer)?[0];
+
+        Ok(result)
+    }
+}
+
+impl<F: PrimeField> Poseidon2Hasher<F> {
+    pub fn permute(params: &Poseidon2Parameters<F>, mut state: Vec<F>) -> Result<Vec<F>> {
+        if state.len() != params.width {
+            return Err(MazeLibError::Hash(format!("incorrect state length {} for width {}", state.len(), params.width)));
+        }
+        if params.width > 4 && !params.width.is_multiple_of(4) {
+            return Err(MazeLibError::Hash(format!("unsupported width {}", params.width)));
+        }
+        if params.external_rc.len() != params.full_rounds || params.internal_diag_m_1.len() != params.width {
+            return Err(MazeLibError::Hash("parameters do not match the width and rounds".into()));
+        }
+        let half_rounds = params.full_rounds / 2;
+
+        Self::matmul_external(&mut state);
+        for rc in params.external_rc[..half_rounds].iter() {
+            state.iter_mut().zip(rc).for_each(|(s, c)| *s = (*s + c).pow([params.alpha]));
+            Self::matmul_external(&mut state);
+        }
+        for rc in params.internal_rc.iter() {
+            state[0] = (state[0] + rc).pow([params.alpha]);
+            Self::matmul_internal(&mut state, &params.internal_diag_m_1);
+        }
+        for rc in params.external_rc[half_rounds..].iter() {
+            state.iter_mut().zip(rc).for_each(|(s, c)| *s = (*s + c).pow([params.alpha]));
+            Self::matmul_external(&mut state);
+        }
+
+        Ok(state)
+    }
+
+    fn matmul_m4(state: &mut [F]) {
+        // circ-like matrix [[5, 7, 1, 3], [4, 6, 1, 1], [1, 3, 5, 7], [1, 1, 4, 6]]
+        let t0 = state[0] + state[1];
+        let t1 = state[2] + state[3];
+        let t2 = state[1].double() + t1;
+        let t3 = state[3].double() + t0;
+        let t4 = t1.double().double() + t3;
+        let t5 = t0.double().double() + t2;
+        let t6 = t3 + t5;
+        let t7 = t2 + t4;
+        state[0] = t6;
+        state[1] = t5;
+        state[2] = t7;
+        state[3] = t4;
+    }
+
+    fn matmul_external(state: &mut [F]) {
+        match state.len() {
+            2 | 3 => {
+                // circ(2, 1) and circ(2, 1, 1)
+                let sum = state.iter().fold(F::zero(), |acc, s| acc + s);
+                state.iter_mut().for_each(|s| *s += sum);
+            }
+            4 => Self::matmul_m4(state),
+            // other widths are multiples of 4, checked by permute
+            _ => {
+                state.chunks_mut(4).for_each(Self::matmul_m4);
+                let sums = (0..4)
+                    .map(|i| state.iter().skip(i).step_by(4).fold(F::zero(), |acc, s| acc + s))
+                    .collect::<Vec<_>>();
+                state.iter_mut().enumerate().for_each(|(i, s)| *s += sums[i % 4]);
+            }
+        }
+    }
+
+    fn matmul_internal(state: &mut [F], diag_m_1: &[F]) {
+        let sum = state.iter().fold(F::zero(), |acc, s| acc + s);
+        state.iter_mut().zip(diag_m_1).for_each(|(s, d)| *s = *s * d + sum);
+    }
+}
@@ -0,0 +1,80 @@
+use ark_std::marker::PhantomData;
+use ark_ff::PrimeField;
+
+use crate::error::{MazeLibError, Result};
+use super::FieldHasher;
+
+#[derive(Debug, Default, Clone)]
+pub struct RescueParameters<F: PrimeField> {
+    /// The size of the permutation, in field elements.
+    pub width: usize,
+    /// Number of rounds, each round applies the S-box and its inverse.
+    pub rounds: usize,
+    /// The exponent of the S-box.
+    pub alpha: u64,
+    /// The exponent of the inverse S-box, `alpha * alpha_inv = 1 mod (p - 1)`.
+    pub alpha_inv: Vec<u64>,
+    /// The MDS matrix to apply in the mix layer.
+    pub mds: Vec<Vec<F>>,
+    /// Round constants, `2 * rounds` steps of `width` constants.
+    pub round_constants: Vec<Vec<F>>,
+    /// The capacity element of the sponge, a domain tag that separates instances sharing a permutation.
+    pub domain: F,
+}
+
+#[derive(Clone)]
+pub struct RescueHasher<F>(PhantomData<F>);
+
+impl<F: PrimeField> FieldHasher<F> for RescueHasher<F> {
+    type Parameters = RescueParameters<F>;
+
+    fn empty_hash() -> F {
+        F::zero()
+    }
+
+    fn hash(params: &Self::Parameters, inputs: &[F]) -> Result<F> {
+        if inputs.len() >= params.width {
+            return Err(MazeLibError::Hash(format!("incorrect input length {} for width {}", inputs.len(), params.width)));
+        }
+
+        let mut buffer = Vec::with_capacity(params.width);
+        buffer.push(params.domain);
+        buffer.extend_from_slice(inputs);
+        buffer.resize(params.width, F::zero());
+
+        let result = Self::permute(params, buffer)?[0];
+
+        Ok(result)
+    }
+}
+
+impl<F: PrimeField> RescueHasher<F> {
+    pub fn permute(params: &RescueParameters<F>, mut state: Vec<F>) -> Result<Vec<F>> {
+        if state.len() != params.width {
+            return Err(MazeLibError::Hash(format!("incorrect state length {} for width {}", state.len(), params.width)));
+        }
+        // every round has a step of the S-box and a step of its inverse
+        if params.mds.len() != params.width || !params.round_constants.len().is_multiple_of(2) {
+            return Err(MazeLibError::Hash("parameters do not match the width and rounds".into()));
+        }
+
+        for rc in params.round_constants.chunks(2) {
+            state.iter_mut().for_each(|s| *s = s.pow([params.alpha]));
+            state = Self::mix(&params.mds, &state, &rc[0]);
+
+            state.iter_mut().for_each(|s| *s = s.pow(&params.alpha_inv));
+            state = Self::mix(&params.mds, &state, &rc[1]);
+        }
+
+        Ok(state)
+    }
+
+    fn mix(mds: &[Vec<F>], state: &[F], rc: &[F]) -> Vec<F> {
+        mds.iter()
+            .zip(rc)
+            .map(|(row, c)| {
+                row.iter().zip(state).fold(*c, |acc, (m, s)| acc + *m * s)
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,144 @@
+use ark_ff::PrimeField;
+use ark_std::{collections::BTreeMap, rc::Rc};
+
+use crate::error::{MazeLibError, Result};
+use super::{hasher::FieldHasher, check_leaf_index};
+
+/// Leaf of a member index, any value other than the empty hash marks a member.
+pub fn member_leaf<F: PrimeField>() -> F {
+    F::one()
+}
+
+/// Sparse merkle tree of leaf indexes, such as the exclusion set of a curator. It has the height
+/// of the vault tree, so a leaf index is also the key of its slot.
+pub struct IndexSetTree<F: PrimeField, FH: FieldHasher<F>> {
+    inner_params: Rc<FH::Parameters>,
+    height: usize,
+    nodes: BTreeMap<(usize, u64), F>,
+    // blank[layer] is the root of an empty subtree at layer, up to the root of an empty tree
+    blank: Vec<F>,
+}
+
+impl<F: PrimeField, FH: FieldHasher<F>> IndexSetTree<F, FH> {
+    pub fn new(inner_params: Rc<FH::Parameters>, height: usize) -> Result<Self> {
+        let mut blank = vec![FH::empty_hash()];
+        for layer in 0..height {
+            blank.push(FH::hash_two(&inner_params, blank[layer], blank[layer])?);
+        }
+
+        Ok(Self {
+            inner_params,
+            height,
+            nodes: BTreeMap::new(),
+            blank,
+        })
+    }
+
+    fn node(&self, layer: usize, index: u64) -> F {
+        self.nodes.get(&(layer, index)).copied().unwrap_or(self.blank[layer])
+    }
+
+    pub fn root(&self) -> F {
+        self.node(self.height, 0)
+    }
+
+    pub fn contains(&self, index: u64) -> bool {
+        self.nodes.contains_key(&(0, index))
+    }
+
+    pub fn insert(&mut self, index: u64) -> Result<()> {
+        self.insert_leaf(index, member_leaf())
+    }
+
+    fn insert_leaf(&mut self, index: u64, leaf: F) -> Result<()> {
+        check_leaf_index(index, self.height)?;
+
+        let mut node = leaf;
+        for layer in 0..self.height {
+            let index = index >> layer;
+            self.nodes.insert((layer, index), node);
+            node = if index & 1 == 1 {
+                FH::hash_two(&self.inner_params, self.node(layer, index - 1), node)?
+            } else {
+                FH::hash_two(&self.inner_params, node, self.node(layer, index + 1))?
+            };
+        }
+        self.nodes.insert((self.height, 0), node);
+
+        Ok(())
+    }
+
+    fn neighbor_nodes(&self, index: u64) -> Vec<F> {
+        (0..self.height)
+            .map(|layer| self.node(layer, (index >> layer) ^ 1))
+            .collect()
+    }
+
+    /// Neighbor nodes from the slot of `index` to the root, which prove `index` is a member.
+    pub fn gen_membership_neighbor_nodes(&self, index: u64) -> Result<Vec<F>> {
+        check_leaf_index(index, self.height)?;
+        if !self.contains(index) {
+            return Err(MazeLibError::InvalidIndex(format!("{} is not in the index set", index)));
+        }
+
+        Ok(self.neighbor_nodes(index))
+    }
+
+    /// Neighbor nodes from the slot of `index` to the root, which prove the slot is empty.
+    pub fn gen_non_membership_neighbor_nodes(&self, index: u64) -> Result<Vec<F>> {
+        check_leaf_index(index, self.height)?;
+        if self.contains(index) {
+            return Err(MazeLibError::InvalidIndex(format!("{} is in the index set", index)));
+        }
+
+        Ok(self.neighbor_nodes(index))
+    }
+}
+
+/// Merkle tree of keys, such as an allowlist of owner keys. Keys take consecutive slots, and a member proves
+/// its key is the leaf of some slot without revealing which one. A key stays a member whatever notes it owns,
+/// so the change of a withdrawal and the outputs of a transfer to the same owner remain withdrawable.
+pub struct KeySetTree<F: PrimeField, FH: FieldHasher<F>> {
+    tree: IndexSetTree<F, FH>,
+    keys: Vec<F>,
+}
+
+impl<F: PrimeField, FH: FieldHasher<F>> KeySetTree<F, FH> {
+    pub fn new(inner_params: Rc<FH::Parameters>, height: usize) -> Result<Self> {
+        Ok(Self {
+            tree: IndexSetTree::new(inner_params, height)?,
+            keys: Vec::new(),
+        })
+    }
+
+    pub fn root(&self) -> F {
+        self.tree.root()
+    }
+
+    pub fn contains(&self, key: &F) -> bool {
+        self.keys.contains(key)
+    }
+
+    /// Append the key to the next empty slot, and return the slot.
+    pub fn insert(&mut self, key: F) -> Result<u64> {
+        // empty slots hold the empty hash, which would make it a member of every tree
+        if key == FH::empty_hash() || self.contains(&key) {
+            return Err(MazeLibError::InvalidInputs("key is empty or already in the key set".into()));
+        }
+        let slot = self.keys.len() as u64;
+        self.tree.insert_leaf(slot, key)?;
+        self.keys.push(key);
+
+        Ok(slot)
+    }
+
+    /// Slot of the key and its neighbor nodes to the root, which prove the key is a member.
+    pub fn gen_membership_neighbor_nodes(&self, key: &F) -> Result<(u64, Vec<F>)> {
+        let slot = self.keys
+            .iter()
+            .position(|k| k == key)
+            .ok_or_else(|| MazeLibError::InvalidInputs("key is not in the key set".into()))? as u64;
+
+        Ok((slot, self.tree.neighbor_nodes(slot)))
+    }
+}
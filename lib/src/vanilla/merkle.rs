@@ -1,13 +1,13 @@
-use ark_crypto_primitives::Error;
 use ark_ff::PrimeField;
 
+use crate::error::{MazeLibError, Result};
 use super::hasher::FieldHasher;
 
 pub fn gen_merkle_path<F: PrimeField, FH: FieldHasher<F>>(
     inner_params: &FH::Parameters,
     neighbors: &[(bool, F)],
     leaf_hash: F,
-) -> Result<Vec<F>, Error> {
+) -> Result<Vec<F>> {
     let mut previous = leaf_hash;
     neighbors
         .into_iter()
@@ -22,3 +22,25 @@ pub fn gen_merkle_path<F: PrimeField, FH: FieldHasher<F>>(
         })
         .collect()
 }
+
+/// Hash `leaves` into a subtree, returns nodes from layer 1 up to the subtree root.
+pub fn gen_subtree<F: PrimeField, FH: FieldHasher<F>>(
+    inner_params: &FH::Parameters,
+    leaves: &[F],
+) -> Result<Vec<F>> {
+    if !leaves.len().is_power_of_two() {
+        return Err(MazeLibError::InvalidInputs(format!("leaves length {} is not a power of 2", leaves.len())));
+    }
+
+    let mut nodes = Vec::with_capacity(leaves.len() - 1);
+    let mut layer = leaves.to_vec();
+    while layer.len() > 1 {
+        layer = layer
+            .chunks(2)
+            .map(|pair| FH::hash_two(inner_params, pair[0], pair[1]))
+            .collect::<Result<Vec<_>>>()?;
+        nodes.extend_from_slice(&layer);
+    }
+
+    Ok(nodes)
+}
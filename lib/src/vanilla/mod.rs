@@ -2,11 +2,15 @@ pub mod merkle;
 pub mod hasher;
 pub mod withdraw;
 pub mod deposit;
+pub mod batch_deposit;
 pub mod commit;
+pub mod transfer;
+pub mod index_set;
 
-use anyhow::Result;
 use ark_ff::PrimeField;
 
+use crate::error::{MazeLibError, Result};
+
 pub trait VanillaProof<F: PrimeField> {
     type ConstParams;
     type OriginInputs;
@@ -20,3 +24,19 @@ pub trait VanillaProof<F: PrimeField> {
         orig_in: &Self::OriginInputs,
     ) -> Result<(Self::PublicInputs, Self::PrivateInputs)>;
 }
+
+pub(crate) fn check_length(name: &'static str, actual: usize, expected: usize) -> Result<()> {
+    if actual != expected {
+        return Err(MazeLibError::PathLengthMismatch { name, expected, actual });
+    }
+
+    Ok(())
+}
+
+pub(crate) fn check_leaf_index(index: u64, height: usize) -> Result<()> {
+    if height < 64 && index >= 1 << height {
+        return Err(MazeLibError::InvalidIndex(format!("{} is out of a tree of height {}", index, height)));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,299 @@
+use ark_ec::{twisted_edwards_extended::GroupAffine, TEModelParameters};
+use ark_std::{marker::PhantomData, rc::Rc};
+use ark_ff::PrimeField;
+use num_traits::Zero;
+
+use crate::error::{MazeLibError, Result};
+use super::{hasher::FieldHasher, VanillaProof, check_length, check_leaf_index, withdraw::{gen_nullifier_point, gen_nullifier_key, gen_owner_key}};
+use super::merkle::gen_merkle_path;
+use super::commit::{self, CommitConstParams, CommitOriginInputs, CommitPrivateInputs, CommitPublicInputs};
+use super::commit::{EscrowOriginInputs, EscrowPrivateInputs, gen_escrow, gen_note_owner};
+
+#[derive(Default)]
+pub struct TransferVanillaProof<P, FH>
+where
+    P: TEModelParameters,
+    FH: FieldHasher<P::BaseField>,
+    P::BaseField: PrimeField,
+{
+    _p: PhantomData<P>,
+    _fh: PhantomData<FH>,
+}
+
+#[derive(Debug)]
+pub struct TransferConstParams<P, FH>
+where
+    P: TEModelParameters,
+    FH: FieldHasher<P::BaseField>,
+    P::BaseField: PrimeField,
+{
+    pub nullifier_params: Rc<FH::Parameters>,
+    pub leaf_params: Rc<FH::Parameters>,
+    pub inner_params: Rc<FH::Parameters>,
+    pub height: usize,
+    pub commit: Option<CommitConstParams<P, FH>>,
+}
+
+#[derive(Debug)]
+pub struct TransferOriginInputs<P: TEModelParameters> {
+    pub src_balances: [u64; 2],
+    pub src_leaf_indexes: [u64; 2],
+    pub dst_amounts: [u64; 2],
+    pub withdraw_amount: u64,
+    /// asset of all src and dst notes, transfers never mix assets
+    pub asset_id: P::BaseField,
+    /// dst leaves are inserted by the contract at `dst_leaf_index` and `dst_leaf_index + 1`
+    pub dst_leaf_index: u64,
+    pub receiver: P::BaseField,
+    pub secret: P::BaseField,
+    /// owner key of the first dst leaf, which is the shielded address of the payee
+    pub recipient: P::BaseField,
+    /// neighbor nodes of both src leaves, taken from the same tree
+    pub src_neighbor_nodes: [Vec<P::BaseField>; 2],
+    /// the first one carries the escrow of the recipient, the second one the escrow of the owner
+    pub commit: Option<[CommitOriginInputs<P>; 2]>,
+    /// escrows both src notes were committed with, required with commitments
+    pub src_escrow: Option<[EscrowOriginInputs<P>; 2]>,
+}
+
+#[derive(Debug)]
+pub struct TransferPublicInputs<P: TEModelParameters> {
+    pub withdraw_amount: u64,
+    pub asset_id: P::BaseField,
+    pub receiver: P::BaseField,
+    pub src_root: P::BaseField,
+    pub nullifier_points: [GroupAffine<P>; 2],
+    pub dst_leaf_index: u64,
+    pub dst_leaves: [P::BaseField; 2],
+    /// auditor keys the commitments are encrypted to, empty without commitments
+    pub auditor_pubkeys: Vec<GroupAffine<P>>,
+    pub commit: Option<[CommitPublicInputs<P>; 2]>,
+}
+
+#[derive(Debug)]
+pub struct TransferPrivateInputs<P: TEModelParameters> {
+    pub src_balances: [u64; 2],
+    pub src_leaf_indexes: [u64; 2],
+    pub dst_amounts: [u64; 2],
+    pub secret: P::BaseField,
+    pub recipient: P::BaseField,
+    pub src_neighbor_nodes: [Vec<(bool, P::BaseField)>; 2],
+    pub commit: Option<[CommitPrivateInputs<P>; 2]>,
+    pub src_escrow: Option<[EscrowPrivateInputs<P>; 2]>,
+}
+
+fn to_neighbor_nodes<F: PrimeField>(leaf_index: u64, nodes: &[F]) -> Vec<(bool, F)> {
+    nodes
+        .iter()
+        .enumerate()
+        .map(|(layer, node)| {
+            let is_left = ((leaf_index >> layer) & 1) == 1;
+            (is_left, *node)
+        })
+        .collect()
+}
+
+impl<P, FH> VanillaProof<P::BaseField> for TransferVanillaProof<P, FH>
+where
+    P: TEModelParameters,
+    FH: FieldHasher<P::BaseField>,
+    P::BaseField: PrimeField,
+{
+    type ConstParams = TransferConstParams<P, FH>;
+    type OriginInputs = TransferOriginInputs<P>;
+    type PublicInputs = TransferPublicInputs<P>;
+    type PrivateInputs = TransferPrivateInputs<P>;
+
+    fn blank_proof(params: &Self::ConstParams) -> Result<(Self::PublicInputs, Self::PrivateInputs)> {
+        let src_leaf_indexes = [0, 1];
+        let balance = 1;
+        let asset_id = P::BaseField::zero();
+        let secret = P::BaseField::zero();
+        let owner = gen_owner_key::<_, FH>(&params.nullifier_params, secret)?;
+        let nullifier_key = gen_nullifier_key::<_, FH>(&params.nullifier_params, secret)?;
+        let pubkeys = params.commit.as_ref().map(|commit| commit.pubkeys.clone()).unwrap_or_default();
+        let nonces = vec![P::ScalarField::zero(); pubkeys.len()];
+        let escrow = gen_escrow(&pubkeys, nullifier_key, &nonces)?;
+        let note_owner = gen_note_owner::<P, FH>(&params.nullifier_params, owner, &pubkeys, &escrow)?;
+        let leaves = src_leaf_indexes
+            .iter()
+            .map(|index| {
+                FH::hash(
+                    &params.leaf_params,
+                    &[P::BaseField::from(*index), asset_id, P::BaseField::from(balance), note_owner],
+                )
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut src_neighbor_nodes_0 = vec![FH::empty_hash(); params.height];
+        src_neighbor_nodes_0[0] = leaves[1];
+        let mut src_neighbor_nodes_1 = vec![FH::empty_hash(); params.height];
+        src_neighbor_nodes_1[0] = leaves[0];
+
+        let origin_inputs = TransferOriginInputs {
+            src_balances: [balance; 2],
+            src_leaf_indexes,
+            dst_amounts: [balance; 2],
+            withdraw_amount: 0,
+            asset_id,
+            dst_leaf_index: 2,
+            receiver: P::BaseField::zero(),
+            secret,
+            recipient: owner,
+            src_neighbor_nodes: [src_neighbor_nodes_0, src_neighbor_nodes_1],
+            commit: params.commit.as_ref().map(|_| [0, 1].map(|_| CommitOriginInputs {
+                nonces: nonces.clone(),
+                escrow: escrow.clone(),
+            })),
+            src_escrow: params.commit.as_ref().map(|_| [0, 1].map(|_| EscrowOriginInputs {
+                pubkeys: pubkeys.clone(),
+                nonces: nonces.clone(),
+            })),
+        };
+
+        Self::generate_vanilla_proof(params, &origin_inputs)
+    }
+
+    fn generate_vanilla_proof(
+        params: &TransferConstParams<P, FH>,
+        orig_in: &TransferOriginInputs<P>,
+    ) -> Result<(Self::PublicInputs, Self::PrivateInputs)> {
+        check_length("src neighbor nodes", orig_in.src_neighbor_nodes[0].len(), params.height)?;
+        check_length("src neighbor nodes", orig_in.src_neighbor_nodes[1].len(), params.height)?;
+        check_leaf_index(orig_in.dst_leaf_index + 1, params.height)?;
+        if orig_in.src_leaf_indexes[0] == orig_in.src_leaf_indexes[1] {
+            return Err(MazeLibError::InvalidIndex("src leaves must be different".into()));
+        }
+        if let Some(index) = orig_in.src_leaf_indexes.iter().find(|index| **index >= orig_in.dst_leaf_index) {
+            return Err(MazeLibError::InvalidIndex(format!("src leaf {} is not before dst leaf {}", index, orig_in.dst_leaf_index)));
+        }
+
+        let src_amount = orig_in.src_balances[0] as u128 + orig_in.src_balances[1] as u128;
+        let dst_amount = orig_in.dst_amounts[0] as u128 + orig_in.dst_amounts[1] as u128 + orig_in.withdraw_amount as u128;
+        if src_amount < dst_amount {
+            return Err(MazeLibError::InsufficientBalance {
+                balance: src_amount.try_into().unwrap_or(u64::MAX),
+                amount: dst_amount.try_into().unwrap_or(u64::MAX),
+            });
+        }
+        if src_amount != dst_amount {
+            return Err(MazeLibError::InvalidInputs("src balances must equal to dst amounts plus withdraw amount".into()));
+        }
+
+        let owner = gen_owner_key::<_, FH>(&params.nullifier_params, orig_in.secret)?;
+        let nullifier_key = gen_nullifier_key::<_, FH>(&params.nullifier_params, orig_in.secret)?;
+
+        // committed src notes are bound to the escrows they were committed with
+        let src_escrow = params.commit
+            .as_ref()
+            .map(|commit| {
+                let src_escrow = orig_in.src_escrow
+                    .as_ref()
+                    .ok_or_else(|| MazeLibError::InvalidInputs("src escrow is missing".into()))?;
+                let [escrow_0, escrow_1] = src_escrow.each_ref().map(|src_escrow| {
+                    check_length("src escrow keys", src_escrow.pubkeys.len(), commit.pubkeys.len())?;
+                    commit::generate_escrow_proof(src_escrow, nullifier_key)
+                });
+                Ok::<_, MazeLibError>([escrow_0?, escrow_1?])
+            })
+            .transpose()?;
+
+        let mut nullifier_points = Vec::with_capacity(2);
+        let mut src_roots = Vec::with_capacity(2);
+        let mut src_neighbor_nodes = Vec::with_capacity(2);
+        for i in 0..2 {
+            let src_leaf_index = orig_in.src_leaf_indexes[i];
+            let neighbor_nodes = to_neighbor_nodes(src_leaf_index, &orig_in.src_neighbor_nodes[i]);
+
+            nullifier_points.push(gen_nullifier_point::<P, FH>(
+                &params.nullifier_params,
+                src_leaf_index,
+                owner,
+                nullifier_key,
+            )?);
+
+            let src_owner = match &src_escrow {
+                Some(escrow) => gen_note_owner::<P, FH>(&params.nullifier_params, owner, &escrow[i].pubkeys, &escrow[i].escrow)?,
+                None => owner,
+            };
+            let src_leaf = FH::hash(
+                &params.leaf_params,
+                &[P::BaseField::from(src_leaf_index), orig_in.asset_id, P::BaseField::from(orig_in.src_balances[i]), src_owner],
+            )?;
+            let src_root = gen_merkle_path::<_, FH>(&params.inner_params, &neighbor_nodes, src_leaf)?
+                .last()
+                .copied()
+                .unwrap_or(src_leaf);
+
+            src_roots.push(src_root);
+            src_neighbor_nodes.push(neighbor_nodes);
+        }
+        // both src leaves are proved against one root
+        if src_roots[0] != src_roots[1] {
+            return Err(MazeLibError::InvalidInputs("src leaves are not in the same tree".into()));
+        }
+
+        let dst_owners = [orig_in.recipient, owner];
+        // dst notes are bound to the escrows they are committed with
+        let dst_note_owners = match params.commit.as_ref().zip(orig_in.commit.as_ref()) {
+            Some((commit, jj_orig_in)) => [
+                gen_note_owner::<P, FH>(&commit.nullifier_params, dst_owners[0], &commit.pubkeys, &jj_orig_in[0].escrow)?,
+                gen_note_owner::<P, FH>(&commit.nullifier_params, dst_owners[1], &commit.pubkeys, &jj_orig_in[1].escrow)?,
+            ],
+            None => dst_owners,
+        };
+        let dst_leaves = dst_note_owners
+            .iter()
+            .zip(orig_in.dst_amounts)
+            .enumerate()
+            .map(|(i, (dst_owner, dst_amount))| {
+                let dst_leaf_index = orig_in.dst_leaf_index + i as u64;
+                FH::hash(
+                    &params.leaf_params,
+                    &[P::BaseField::from(dst_leaf_index), orig_in.asset_id, P::BaseField::from(dst_amount), *dst_owner],
+                )
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let (jj_pub_in, jj_priv_in) = if let Some((params, jj_orig_in)) = params.commit.as_ref().zip(orig_in.commit.as_ref()) {
+            let (pub_in_0, priv_in_0) = commit::generate_vanilla_proof(params, &jj_orig_in[0], orig_in.dst_leaf_index, dst_owners[0])?;
+            let (pub_in_1, priv_in_1) = commit::generate_vanilla_proof(params, &jj_orig_in[1], orig_in.dst_leaf_index + 1, dst_owners[1])?;
+            (Some([pub_in_0, pub_in_1]), Some([priv_in_0, priv_in_1]))
+        } else {
+            (None, None)
+        };
+
+        let [src_neighbor_nodes_0, src_neighbor_nodes_1]: [_; 2] = src_neighbor_nodes.try_into().unwrap();
+
+        // auditor keys are public inputs, so the vault can rotate them without a new setup
+        let auditor_pubkeys = params.commit
+            .as_ref()
+            .filter(|_| jj_pub_in.is_some())
+            .map(|commit| commit.pubkeys.clone())
+            .unwrap_or_default();
+
+        let pub_in = TransferPublicInputs {
+            withdraw_amount: orig_in.withdraw_amount,
+            asset_id: orig_in.asset_id,
+            receiver: orig_in.receiver,
+            src_root: src_roots[0],
+            nullifier_points: [nullifier_points[0], nullifier_points[1]],
+            dst_leaf_index: orig_in.dst_leaf_index,
+            dst_leaves: [dst_leaves[0], dst_leaves[1]],
+            auditor_pubkeys,
+            commit: jj_pub_in,
+        };
+        let priv_in = TransferPrivateInputs {
+            src_balances: orig_in.src_balances,
+            src_leaf_indexes: orig_in.src_leaf_indexes,
+            dst_amounts: orig_in.dst_amounts,
+            secret: orig_in.secret,
+            recipient: orig_in.recipient,
+            src_neighbor_nodes: [src_neighbor_nodes_0, src_neighbor_nodes_1],
+            commit: jj_priv_in,
+            src_escrow,
+        };
+
+        Ok((pub_in, priv_in))
+    }
+}
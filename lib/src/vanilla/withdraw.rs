@@ -1,11 +1,87 @@
-use ark_ec::{twisted_edwards_extended::{GroupProjective, GroupAffine}, TEModelParameters, ProjectiveCurve};
+use ark_ec::{twisted_edwards_extended::GroupAffine, TEModelParameters, AffineCurve};
 use ark_std::{marker::PhantomData, rc::Rc};
-use anyhow::{anyhow, Result};
-use ark_ff::{PrimeField, BigInteger, FpParameters};
+use ark_ff::PrimeField;
 use num_traits::Zero;
 
-use super::{hasher::FieldHasher, VanillaProof, merkle::gen_merkle_path};
+use crate::error::{MazeLibError, Result};
+use super::{hasher::FieldHasher, VanillaProof, check_length, check_leaf_index, merkle::gen_merkle_path};
 use super::commit::{self, CommitConstParams, CommitOriginInputs, CommitPrivateInputs, CommitPublicInputs};
+use super::commit::{EscrowOriginInputs, EscrowPrivateInputs, gen_escrow, gen_note_owner, gen_nullifier_key_point, gen_nullifier_scalar};
+
+/// Owner key of notes: hash(secret). Notes are bound to the owner key, which can be
+/// shared as a shielded address, while only the holder of `secret` can spend them.
+pub fn gen_owner_key<F, FH>(nullifier_params: &FH::Parameters, secret: F) -> Result<F>
+where
+    F: PrimeField,
+    FH: FieldHasher<F>,
+{
+    FH::hash(nullifier_params, &[secret])
+}
+
+/// Nullifier key of notes: hash(secret | 1). It is never derived from the owner key, so that
+/// knowing a shielded address does not reveal when its notes are spent.
+pub fn gen_nullifier_key<F, FH>(nullifier_params: &FH::Parameters, secret: F) -> Result<F>
+where
+    F: PrimeField,
+    FH: FieldHasher<F>,
+{
+    FH::hash(nullifier_params, &[secret, F::one()])
+}
+
+/// Nullifier point of the note at `leaf_index`: hash(leaf_index | owner) * nullifier_key * G.
+pub fn gen_nullifier_point<P, FH>(
+    nullifier_params: &FH::Parameters,
+    leaf_index: u64,
+    owner: P::BaseField,
+    nullifier_key: P::BaseField,
+) -> Result<GroupAffine<P>>
+where
+    P: TEModelParameters,
+    FH: FieldHasher<P::BaseField>,
+    P::BaseField: PrimeField,
+{
+    let (nullifier, _) = gen_nullifier_scalar::<P, FH>(nullifier_params, leaf_index, owner)?;
+    let nullifier_point = gen_nullifier_key_point::<P>(nullifier_key).mul(nullifier).into();
+
+    Ok(nullifier_point)
+}
+
+// root of an index set tree and the neighbor nodes marked by side
+type IndexSetProof<F> = (Option<F>, Option<Vec<(bool, F)>>);
+
+// computed from `leaf` at the slot of `index`, none when the circuit does not carry the index set
+fn gen_index_set_proof<P, FH>(
+    params: &WithdrawConstParams<P, FH>,
+    name: &'static str,
+    enabled: bool,
+    neighbor_nodes: Option<&Vec<P::BaseField>>,
+    index: u64,
+    leaf: P::BaseField,
+) -> Result<IndexSetProof<P::BaseField>>
+where
+    P: TEModelParameters,
+    FH: FieldHasher<P::BaseField>,
+    P::BaseField: PrimeField,
+{
+    if !enabled {
+        return Ok((None, None));
+    }
+
+    let neighbor_nodes = neighbor_nodes
+        .ok_or_else(|| MazeLibError::InvalidInputs(format!("{} are missing", name)))?;
+    check_length(name, neighbor_nodes.len(), params.height)?;
+    let neighbor_nodes = neighbor_nodes
+        .iter()
+        .enumerate()
+        .map(|(layer, node)| (((index >> layer) & 1) == 1, *node))
+        .collect::<Vec<_>>();
+    let root = gen_merkle_path::<_, FH>(&params.inner_params, &neighbor_nodes, leaf)?
+        .last()
+        .copied()
+        .unwrap_or(leaf);
+
+    Ok((Some(root), Some(neighbor_nodes)))
+}
 
 #[derive(Default)]
 pub struct WithdrawVanillaProof<P, FH>
@@ -30,31 +106,55 @@ where
     pub inner_params: Rc<FH::Parameters>,
     pub height: usize,
     pub commit: Option<CommitConstParams<P, FH>>,
+    /// prove the src leaf index is not in the exclusion set of the curator
+    pub exclusion: bool,
+    /// prove the owner key is in an allowlist accepted by the vault
+    pub allowlist: bool,
 }
 
 #[derive(Debug)]
 pub struct WithdrawOriginInputs<P: TEModelParameters> {
     pub balance: u64,
     pub withdraw_amount: u64,
+    /// asset of both the spent and the rest note
+    pub asset_id: P::BaseField,
     pub src_leaf_index: u64,
     pub dst_leaf_index: u64,
+    /// vault policy, dst leaf index must be at least this many leaves after src leaf index
+    pub min_anonymity_gap: u64,
     pub receiver: P::BaseField,
+    /// relayer who submits the withdrawal and takes the fee
+    pub relayer: P::BaseField,
+    /// paid to the relayer out of the withdraw amount
+    pub fee: u64,
     pub secret: P::BaseField,
     pub src_neighbor_nodes: Vec<P::BaseField>,
-    pub dst_neighbor_nodes: Vec<P::BaseField>,
     pub commit: Option<CommitOriginInputs<P>>,
+    /// escrow the src note was committed with, required with commitments
+    pub src_escrow: Option<EscrowOriginInputs<P>>,
+    /// neighbor nodes of the src leaf index in the exclusion tree
+    pub exclusion_neighbor_nodes: Option<Vec<P::BaseField>>,
+    /// slot of the owner key in the allowlist tree and its neighbor nodes
+    pub allowlist_neighbor_nodes: Option<(u64, Vec<P::BaseField>)>,
 }
 
 #[derive(Debug)]
 pub struct WithdrawPublicInputs<P: TEModelParameters> {
     pub withdraw_amount: u64,
+    pub asset_id: P::BaseField,
     pub receiver: P::BaseField,
+    pub relayer: P::BaseField,
+    pub fee: u64,
     pub dst_leaf_index: u64,
+    pub min_anonymity_gap: u64,
     pub dst_leaf: P::BaseField,
-    pub prev_root: P::BaseField,
+    pub src_root: P::BaseField,
     pub nullifier_point: GroupAffine<P>,
-    pub update_nodes: Vec<P::BaseField>,
+    /// auditor keys the commitments are encrypted to, empty without commitments
+    pub auditor_pubkeys: Vec<GroupAffine<P>>,
     pub commit: Option<CommitPublicInputs<P>>,
+    pub exclusion_root: Option<P::BaseField>,
+    pub allowlist_root: Option<P::BaseField>,
 }
 
 #[derive(Debug)]
@@ -62,10 +162,12 @@ pub struct WithdrawPrivateInputs<P: TEModelParameters> {
     pub balance: u64,
     pub secret: P::BaseField,
     pub src_neighbor_nodes: Vec<(bool, P::BaseField)>,
-    pub dst_neighbor_nodes: Vec<(bool, P::BaseField)>,
     pub src_leaf_index: u64,
     pub src_leaf: P::BaseField,
-    pub commit: Option<CommitPrivateInputs>,
+    pub commit: Option<CommitPrivateInputs<P>>,
+    pub src_escrow: Option<EscrowPrivateInputs<P>>,
+    pub exclusion_neighbor_nodes: Option<Vec<(bool, P::BaseField)>>,
+    pub allowlist_neighbor_nodes: Option<Vec<(bool, P::BaseField)>>,
 }
 
 impl<P, FH> VanillaProof<P::BaseField> for WithdrawVanillaProof<P, FH>
@@ -82,29 +184,38 @@ where
     fn blank_proof(params: &Self::ConstParams) -> Result<(Self::PublicInputs, Self::PrivateInputs)> {
         let src_leaf_index = 0;
         let balance = 1;
+        let asset_id = P::BaseField::zero();
         let receiver = P::BaseField::zero();
+        let relayer = P::BaseField::zero();
         let secret = P::BaseField::zero();
-        let leaf = FH::hash(
-            &params.leaf_params,
-            &[P::BaseField::from(src_leaf_index), P::BaseField::from(balance), secret],
-        ).map_err(|e| anyhow!("hash error: {}", e))?;
-
+        let nullifier_key = gen_nullifier_key::<_, FH>(&params.nullifier_params, secret)?;
+        let pubkeys = params.commit.as_ref().map(|commit| commit.pubkeys.clone()).unwrap_or_default();
+        let nonces = vec![P::ScalarField::zero(); pubkeys.len()];
+        let escrow = gen_escrow(&pubkeys, nullifier_key, &nonces)?;
         let src_neighbor_nodes = vec![FH::empty_hash(); params.height];
-        let mut dst_neighbor_nodes = vec![FH::empty_hash(); params.height];
-        dst_neighbor_nodes[0] = leaf;
 
         let origin_inputs = WithdrawOriginInputs {
             balance,
             withdraw_amount: balance,
+            asset_id,
             src_leaf_index,
             dst_leaf_index: src_leaf_index + 1,
+            min_anonymity_gap: 0,
             receiver,
+            relayer,
+            fee: 0,
             secret,
             src_neighbor_nodes,
-            dst_neighbor_nodes,
-            commit: params.commit.as_ref().and(Some(CommitOriginInputs {
-                nonce: P::ScalarField::zero(),
-            })),
+            commit: params.commit.as_ref().map(|_| CommitOriginInputs {
+                nonces: nonces.clone(),
+                escrow,
+            }),
+            src_escrow: params.commit.as_ref().map(|_| EscrowOriginInputs {
+                pubkeys,
+                nonces,
+            }),
+            exclusion_neighbor_nodes: params.exclusion.then(|| vec![FH::empty_hash(); params.height]),
+            allowlist_neighbor_nodes: params.allowlist.then(|| (0, vec![FH::empty_hash(); params.height])),
         };
 
         Self::generate_vanilla_proof(params, &origin_inputs)
@@ -114,11 +225,28 @@ where
         params: &WithdrawConstParams<P, FH>,
         orig_in: &WithdrawOriginInputs<P>,
     ) -> Result<(Self::PublicInputs, Self::PrivateInputs)> {
-        assert_eq!(orig_in.src_neighbor_nodes.len(), params.height);
-        assert_eq!(orig_in.dst_neighbor_nodes.len(), params.height);
-        assert!(orig_in.dst_leaf_index < (1 << params.height));
-        assert!(orig_in.src_leaf_index < orig_in.dst_leaf_index);
-        assert!(orig_in.withdraw_amount > 0);
+        check_length("src neighbor nodes", orig_in.src_neighbor_nodes.len(), params.height)?;
+        check_leaf_index(orig_in.dst_leaf_index, params.height)?;
+        if orig_in.src_leaf_index >= orig_in.dst_leaf_index {
+            return Err(MazeLibError::InvalidIndex(format!(
+                "src leaf {} is not before dst leaf {}", orig_in.src_leaf_index, orig_in.dst_leaf_index,
+            )));
+        }
+        if orig_in.dst_leaf_index - orig_in.src_leaf_index < orig_in.min_anonymity_gap {
+            return Err(MazeLibError::InvalidIndex(format!(
+                "src leaf {} is less than {} leaves before dst leaf {}",
+                orig_in.src_leaf_index, orig_in.min_anonymity_gap, orig_in.dst_leaf_index,
+            )));
+        }
+        if orig_in.withdraw_amount == 0 {
+            return Err(MazeLibError::InvalidInputs("withdraw amount must be greater than 0".into()));
+        }
+        if orig_in.withdraw_amount > orig_in.balance {
+            return Err(MazeLibError::InsufficientBalance { balance: orig_in.balance, amount: orig_in.withdraw_amount });
+        }
+        if orig_in.fee > orig_in.withdraw_amount {
+            return Err(MazeLibError::InvalidInputs(format!("fee {} is more than withdraw amount {}", orig_in.fee, orig_in.withdraw_amount)));
+        }
 
         let src_neighbor_nodes = orig_in.src_neighbor_nodes
             .iter()
@@ -129,48 +257,46 @@ where
             })
             .collect::<Result<Vec<_>>>()?;
 
-        let dst_neighbor_nodes = orig_in.dst_neighbor_nodes
-            .iter()
-            .enumerate()
-            .map(|(layer, node)| {
-                let is_left = ((orig_in.dst_leaf_index >> layer) & 1) == 1;
-                Ok((is_left, node.clone()))
-            })
-            .collect::<Result<Vec<_>>>()?;
-
-        let nullifier = FH::hash(
+        let owner = gen_owner_key::<_, FH>(&params.nullifier_params, orig_in.secret)?;
+        let nullifier_key = gen_nullifier_key::<_, FH>(&params.nullifier_params, orig_in.secret)?;
+        let nullifier_point = gen_nullifier_point::<P, FH>(
             &params.nullifier_params,
-            &[P::BaseField::from(orig_in.src_leaf_index), orig_in.secret],
-        ).unwrap();
-        let nullifier: <P::BaseField as PrimeField>::BigInt = nullifier.into();
-        let mut nullifier_bits = nullifier.to_bits_le();
-        nullifier_bits.truncate(<<P::ScalarField as PrimeField>::Params as FpParameters>::CAPACITY as usize);
-        let nullifier: <P::ScalarField as PrimeField>::BigInt = <<P::ScalarField as PrimeField>::BigInt as BigInteger>::from_bits_le(&nullifier_bits);
-        // nullifier_point = nullifier * G
-        let nullifier_point = GroupProjective::prime_subgroup_generator().mul(nullifier).into();
+            orig_in.src_leaf_index,
+            owner,
+            nullifier_key,
+        )?;
+
+        // a committed src note is bound to the escrow it was committed with
+        let src_escrow = params.commit
+            .as_ref()
+            .map(|commit| {
+                let src_escrow = orig_in.src_escrow
+                    .as_ref()
+                    .ok_or_else(|| MazeLibError::InvalidInputs("src escrow is missing".into()))?;
+                check_length("src escrow keys", src_escrow.pubkeys.len(), commit.pubkeys.len())?;
+                commit::generate_escrow_proof(src_escrow, nullifier_key)
+            })
+            .transpose()?;
+        let src_owner = match &src_escrow {
+            Some(escrow) => gen_note_owner::<P, FH>(&params.nullifier_params, owner, &escrow.pubkeys, &escrow.escrow)?,
+            None => owner,
+        };
 
         let src_leaf = FH::hash(
             &params.leaf_params,
-            &[P::BaseField::from(orig_in.src_leaf_index), P::BaseField::from(orig_in.balance), orig_in.secret],
-        ).unwrap();
-        let prev_root = gen_merkle_path::<_, FH>(&params.inner_params, &src_neighbor_nodes, src_leaf)
-            .map_err(|e| anyhow!("gen merkle path error: {:?}", e))?
+            &[P::BaseField::from(orig_in.src_leaf_index), orig_in.asset_id, P::BaseField::from(orig_in.balance), src_owner],
+        )?;
+        // src leaf can be proved against any recent root of the tree
+        let src_root = gen_merkle_path::<_, FH>(&params.inner_params, &src_neighbor_nodes, src_leaf)?
             .last()
-            .unwrap()
-            .clone();
-        let rest_amount = orig_in.balance.saturating_sub(orig_in.withdraw_amount);
-        let dst_leaf = FH::hash(
-            &params.leaf_params,
-            &[P::BaseField::from(orig_in.dst_leaf_index), P::BaseField::from(rest_amount), orig_in.secret],
-        ).unwrap();
-        let update_nodes = gen_merkle_path::<_, FH>(&params.inner_params, &dst_neighbor_nodes, dst_leaf)
-            .map_err(|e| anyhow!("gen merkle path error: {:?}", e))?;
+            .copied()
+            .unwrap_or(src_leaf);
 
         let commit = params.commit
             .as_ref()
             .zip(orig_in.commit.as_ref())
             .map(|(params, jj_orig_in)| {
-                commit::generate_vanilla_proof(params, jj_orig_in, orig_in.dst_leaf_index, orig_in.secret)
+                commit::generate_vanilla_proof(params, jj_orig_in, orig_in.dst_leaf_index, owner)
             })
             .transpose()?;
         let (jj_pub_in, jj_priv_in) = if let Some((pub_in, priv_in)) = commit {
@@ -179,24 +305,73 @@ where
             (None, None)
         };
 
+        // the rest note goes back to the owner, bound to the escrow it is committed with
+        let dst_owner = match params.commit.as_ref().zip(orig_in.commit.as_ref()) {
+            Some((commit, jj_orig_in)) => gen_note_owner::<P, FH>(&commit.nullifier_params, owner, &commit.pubkeys, &jj_orig_in.escrow)?,
+            None => owner,
+        };
+        let rest_amount = orig_in.balance - orig_in.withdraw_amount;
+        let dst_leaf = FH::hash(
+            &params.leaf_params,
+            &[P::BaseField::from(orig_in.dst_leaf_index), orig_in.asset_id, P::BaseField::from(rest_amount), dst_owner],
+        )?;
+
+        // src leaf index is the key of its slot in the exclusion tree
+        let (exclusion_root, exclusion_neighbor_nodes) = gen_index_set_proof(
+            params,
+            "exclusion neighbor nodes",
+            params.exclusion,
+            orig_in.exclusion_neighbor_nodes.as_ref(),
+            orig_in.src_leaf_index,
+            FH::empty_hash(),
+        )?;
+        // owner key is the leaf at its slot in the allowlist tree, so rest and transfer notes stay allowed
+        let (allowlist_slot, allowlist_neighbor_nodes) = orig_in.allowlist_neighbor_nodes
+            .as_ref()
+            .map(|(slot, neighbor_nodes)| (*slot, Some(neighbor_nodes)))
+            .unwrap_or((0, None));
+        let (allowlist_root, allowlist_neighbor_nodes) = gen_index_set_proof(
+            params,
+            "allowlist neighbor nodes",
+            params.allowlist,
+            allowlist_neighbor_nodes,
+            allowlist_slot,
+            owner,
+        )?;
+
+        // auditor keys are public inputs, so the vault can rotate them without a new setup
+        let auditor_pubkeys = params.commit
+            .as_ref()
+            .filter(|_| jj_pub_in.is_some())
+            .map(|commit| commit.pubkeys.clone())
+            .unwrap_or_default();
+
         let pub_in = WithdrawPublicInputs {
             withdraw_amount: orig_in.withdraw_amount,
+            asset_id: orig_in.asset_id,
             receiver: orig_in.receiver,
+            relayer: orig_in.relayer,
+            fee: orig_in.fee,
             dst_leaf_index: orig_in.dst_leaf_index,
+            min_anonymity_gap: orig_in.min_anonymity_gap,
             dst_leaf,
-            prev_root,
+            src_root,
             nullifier_point,
-            update_nodes,
+            auditor_pubkeys,
             commit: jj_pub_in,
+            exclusion_root,
+            allowlist_root,
         };
         let priv_in = WithdrawPrivateInputs {
             balance: orig_in.balance,
             secret: orig_in.secret,
             src_neighbor_nodes,
-            dst_neighbor_nodes,
             src_leaf_index: orig_in.src_leaf_index,
             src_leaf,
             commit: jj_priv_in,
+            src_escrow,
+            exclusion_neighbor_nodes,
+            allowlist_neighbor_nodes,
         };
 
         Ok((pub_in, priv_in))
@@ -0,0 +1,94 @@
+use borsh::{BorshSerialize, BorshDeserialize};
+use serde::{Serialize, Deserialize};
+use solana_program::{msg, pubkey::Pubkey, program_pack::IsInitialized, entrypoint::ProgramResult};
+
+use crate::bn::BigInteger256 as BigInteger;
+use crate::{params::MAX_ALLOWLIST_ROOTS, Packer, error::MazeError};
+use super::is_fr_valid;
+
+pub fn get_allowlist_registry_pda<'a>(
+    vault: &'a Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, (&'a [u8], &'static [u8], [u8; 1])) {
+    let vault_ref = vault.as_ref();
+
+    let (key, seed) = Pubkey::find_program_address(
+        &[vault_ref, b"allowlist"],
+        program_id,
+    );
+
+    (key, (vault_ref, b"allowlist", [seed]))
+}
+
+/// Roots of the allowlists a vault accepts, each one a sparse merkle tree of the owner keys
+/// of an association set of depositors. Withdrawals prove their owner key is a member.
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize, Serialize, Deserialize)]
+pub struct AllowlistRegistry {
+    is_initialized: bool,
+    pub vault: Pubkey,
+    /// empty slots are default roots
+    pub roots: [BigInteger; MAX_ALLOWLIST_ROOTS],
+}
+
+impl AllowlistRegistry {
+    pub fn new(vault: Pubkey) -> Self {
+        Self {
+            is_initialized: true,
+            vault,
+            roots: [BigInteger::default(); MAX_ALLOWLIST_ROOTS],
+        }
+    }
+
+    pub fn check_root(&self, root: &BigInteger) -> ProgramResult {
+        if root != &BigInteger::default() && self.roots.contains(root) {
+            Ok(())
+        } else {
+            msg!("Allowlist root is not accepted by the vault");
+            Err(MazeError::InvalidVanillaData.into())
+        }
+    }
+
+    pub fn add_root(&mut self, root: BigInteger) -> ProgramResult {
+        if !is_fr_valid(&root) || root == BigInteger::default() {
+            msg!("Allowlist root is invalid");
+            return Err(MazeError::InvalidVanillaData.into());
+        }
+        if self.roots.contains(&root) {
+            msg!("Allowlist root is already accepted by the vault");
+            return Err(MazeError::InvalidVanillaData.into());
+        }
+        let slot = self.roots
+            .iter_mut()
+            .find(|r| *r == &BigInteger::default())
+            .ok_or_else(|| {
+                msg!("Vault accepts too many allowlist roots");
+                MazeError::InvalidVanillaData
+            })?;
+        *slot = root;
+
+        Ok(())
+    }
+
+    pub fn remove_root(&mut self, root: &BigInteger) -> ProgramResult {
+        let slot = self.roots
+            .iter_mut()
+            .find(|r| *r == root && root != &BigInteger::default())
+            .ok_or_else(|| {
+                msg!("Allowlist root is not accepted by the vault");
+                MazeError::InvalidVanillaData
+            })?;
+        *slot = BigInteger::default();
+
+        Ok(())
+    }
+}
+
+impl IsInitialized for AllowlistRegistry {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+impl Packer for AllowlistRegistry {
+    const LEN: usize = 1 + 32 + 32 * MAX_ALLOWLIST_ROOTS;
+}
@@ -0,0 +1,95 @@
+use borsh::{BorshSerialize, BorshDeserialize};
+use solana_program::{msg, pubkey::Pubkey, program_error::ProgramError};
+
+use crate::params::{verify::ProofType, HEIGHT, BATCH_DEPOSIT_SIZE, AUDITORS};
+use crate::{error::MazeError, bn::BigInteger256 as BigInteger};
+
+use super::{pubkey_to_fr_repr, is_fr_valid, EdwardsAffine};
+use super::commitment::{is_commitment_valid, extend_auditor_key_inputs, extend_commitment_inputs, InnerCommitment};
+use super::{VanillaData, credential::Credential};
+
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct BatchDepositVanillaData {
+    pub deposit_amounts: Box<Vec<u64>>,
+    /// asset id of all leaves is derived from the mint
+    pub token_mint: Pubkey,
+    /// index of the first leaf, aligned to the subtree of the batch
+    pub leaf_index: u64,
+    pub leaves: Box<Vec<BigInteger>>,
+    /// nodes of the subtree from layer 1 up to the subtree root, stored with the leaves
+    pub subtree_nodes: Box<Vec<BigInteger>>,
+    pub commitments: Box<Vec<InnerCommitment>>,
+}
+
+impl BatchDepositVanillaData {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        deposit_amounts: Box<Vec<u64>>,
+        token_mint: Pubkey,
+        leaf_index: u64,
+        leaves: Box<Vec<BigInteger>>,
+        subtree_nodes: Box<Vec<BigInteger>>,
+        commitments: Box<Vec<InnerCommitment>>,
+    ) -> Result<Self, ProgramError> {
+        if deposit_amounts.len() != BATCH_DEPOSIT_SIZE {
+            msg!("deposit amounts are invalid");
+            return Err(MazeError::InvalidVanillaData.into());
+        }
+        if leaf_index % BATCH_DEPOSIT_SIZE as u64 != 0 || leaf_index + BATCH_DEPOSIT_SIZE as u64 > 1 << HEIGHT {
+            msg!("leaf index is invalid");
+            return Err(MazeError::InvalidVanillaData.into());
+        }
+        if leaves.len() != BATCH_DEPOSIT_SIZE || !leaves.iter().all(is_fr_valid) {
+            msg!("leaves are invalid");
+            return Err(MazeError::InvalidVanillaData.into());
+        }
+        if subtree_nodes.len() != BATCH_DEPOSIT_SIZE - 1 || !subtree_nodes.iter().all(is_fr_valid) {
+            msg!("subtree nodes are invalid");
+            return Err(MazeError::InvalidVanillaData.into());
+        }
+        if commitments.len() != BATCH_DEPOSIT_SIZE || !commitments.iter().all(is_commitment_valid) {
+            msg!("commitments are invalid");
+            return Err(MazeError::InvalidVanillaData.into());
+        }
+
+        Ok(Self {
+            deposit_amounts,
+            token_mint,
+            leaf_index,
+            leaves,
+            subtree_nodes,
+            commitments,
+        })
+    }
+
+    #[inline]
+    pub fn total_amount(&self) -> Option<u64> {
+        self.deposit_amounts.iter().try_fold(0u64, |sum, amount| sum.checked_add(*amount))
+    }
+}
+
+impl VanillaData for BatchDepositVanillaData {
+    const PROOF_TYPE: ProofType = ProofType::BatchDeposit;
+    const INPUTS_LEN: usize = BATCH_DEPOSIT_SIZE + 1 + 1 + BATCH_DEPOSIT_SIZE
+        + (BATCH_DEPOSIT_SIZE - 1) + 2 * AUDITORS + 4 * AUDITORS * BATCH_DEPOSIT_SIZE;
+    const SIZE: usize = 4 + 8 * BATCH_DEPOSIT_SIZE + 32 + 8 + 4 + 32 * BATCH_DEPOSIT_SIZE
+        + 4 + 32 * (BATCH_DEPOSIT_SIZE - 1) + 4 + (4 + AUDITORS * 4 * 32) * BATCH_DEPOSIT_SIZE;
+
+    fn to_public_inputs(self, auditor_keys: &[EdwardsAffine]) -> Box<Vec<BigInteger>> {
+        let mut inputs = Box::new(Vec::with_capacity(Self::INPUTS_LEN));
+
+        inputs.extend(self.deposit_amounts.iter().map(|amount| BigInteger::from(*amount)));
+        inputs.push(pubkey_to_fr_repr(&self.token_mint));
+        inputs.push(BigInteger::from(self.leaf_index));
+        inputs.extend_from_slice(&self.leaves);
+        inputs.extend_from_slice(&self.subtree_nodes);
+        extend_auditor_key_inputs(&mut inputs, auditor_keys);
+        self.commitments.iter().for_each(|commitment| extend_commitment_inputs(&mut inputs, commitment));
+
+        assert_eq!(inputs.len(), Self::INPUTS_LEN);
+
+        inputs
+    }
+}
+
+pub type BatchDepositCredential = Credential<BatchDepositVanillaData>;
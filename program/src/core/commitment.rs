@@ -2,11 +2,15 @@ use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::program_pack::IsInitialized;
 use solana_program::pubkey::Pubkey;
 
-use crate::Packer;
+use crate::{Packer, params::AUDITORS};
 use crate::bn::{BigInteger256, BigInteger};
 use super::{is_edwards_affine_valid, EdwardsAffine};
 
-pub type InnerCommitment = (EdwardsAffine, EdwardsAffine);
+/// Elgamal ciphertext of the nullifier point to one auditor key.
+pub type Ciphertext = (EdwardsAffine, EdwardsAffine);
+
+/// One ciphertext for each auditor key, in the order of the keys.
+pub type InnerCommitment = Vec<Ciphertext>;
 
 #[derive(Debug, Clone, BorshDeserialize, BorshSerialize)]
 pub struct Commitment {
@@ -30,12 +34,31 @@ impl IsInitialized for Commitment {
 }
 
 impl Packer for Commitment {
-    const LEN: usize = 1 + 32 * 2 * 2;
+    const LEN: usize = 1 + 4 + 32 * 2 * 2 * AUDITORS;
 }
 
 #[inline]
 pub fn is_commitment_valid(inner: &InnerCommitment) -> bool {
-    is_edwards_affine_valid(&inner.0) && is_edwards_affine_valid(&inner.1)
+    inner.len() == AUDITORS && inner.iter().all(|c| {
+        is_edwards_affine_valid(&c.0) && is_edwards_affine_valid(&c.1)
+    })
+}
+
+pub fn extend_commitment_inputs(inputs: &mut Vec<BigInteger256>, inner: &InnerCommitment) {
+    inner.iter().for_each(|c| {
+        inputs.push(c.0.x);
+        inputs.push(c.0.y);
+        inputs.push(c.1.x);
+        inputs.push(c.1.y);
+    });
+}
+
+/// Auditor keys of the vault are public inputs in front of the commitments.
+pub fn extend_auditor_key_inputs(inputs: &mut Vec<BigInteger256>, auditor_keys: &[EdwardsAffine]) {
+    auditor_keys.iter().for_each(|key| {
+        inputs.push(key.x);
+        inputs.push(key.y);
+    });
 }
 
 pub fn get_commitment_pda(
@@ -2,11 +2,13 @@ use borsh::{BorshSerialize, BorshDeserialize};
 use solana_program::program_pack::IsInitialized;
 use solana_program::pubkey::Pubkey;
 
-use crate::Packer;
-use super::VanillaData;
+use crate::{Packer, params::AUDITORS};
+use super::{VanillaData, EdwardsAffine};
 
 const DEPOSIT_TAG: &[u8] = &[0];
 const WITHDRAW_TAG: &[u8] = &[1];
+const TRANSFER_TAG: &[u8] = &[2];
+const BATCH_DEPOSIT_TAG: &[u8] = &[3];
 
 pub fn get_deposit_credential_pda<'a>(
     vault: &'a Pubkey,
@@ -24,6 +26,22 @@ pub fn get_deposit_credential_pda<'a>(
     (key, (vault_ref, DEPOSIT_TAG, depositor_ref, [seed]))
 }
 
+pub fn get_batch_deposit_credential_pda<'a>(
+    vault: &'a Pubkey,
+    depositor: &'a Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, (&'a [u8], &'static [u8], &'a [u8], [u8; 1])) {
+    let vault_ref = vault.as_ref();
+    let depositor_ref = depositor.as_ref();
+
+    let (key, seed) = Pubkey::find_program_address(
+        &[vault_ref, &BATCH_DEPOSIT_TAG, depositor_ref],
+        program_id,
+    );
+
+    (key, (vault_ref, BATCH_DEPOSIT_TAG, depositor_ref, [seed]))
+}
+
 pub fn get_withdraw_credential_pda<'a>(
     vault: &'a Pubkey,
     delegator: &'a Pubkey,
@@ -42,11 +60,29 @@ pub fn get_withdraw_credential_pda<'a>(
     (key, (vault_ref, WITHDRAW_TAG, delegator_ref, receiver_ref, [seed]))
 }
 
+pub fn get_transfer_credential_pda<'a>(
+    vault: &'a Pubkey,
+    owner: &'a Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, (&'a [u8], &'static [u8], &'a [u8], [u8; 1])) {
+    let vault_ref = vault.as_ref();
+    let owner_ref = owner.as_ref();
+
+    let (key, seed) = Pubkey::find_program_address(
+        &[vault_ref, &TRANSFER_TAG, owner_ref],
+        program_id,
+    );
+
+    (key, (vault_ref, TRANSFER_TAG, owner_ref, [seed]))
+}
+
 #[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
 pub struct Credential<V: VanillaData> {
     pub is_initialized: bool,
     pub vault: Pubkey,
     pub owner: Pubkey,
+    /// auditor keys of the vault when the credential is created, rotation does not break it in flight
+    pub auditor_keys: [EdwardsAffine; AUDITORS],
     pub vanilla_data: V,
 }
 
@@ -54,12 +90,14 @@ impl<V: VanillaData> Credential<V> {
     pub fn new(
         vault: Pubkey,
         owner: Pubkey,
+        auditor_keys: [EdwardsAffine; AUDITORS],
         vanilla_data: V,
     ) -> Self {
         Self {
             is_initialized: true,
             vault,
             owner,
+            auditor_keys,
             vanilla_data,
         }
     }
@@ -72,5 +110,5 @@ impl<V: VanillaData> IsInitialized for Credential<V> {
 }
 
 impl<V: VanillaData> Packer for Credential<V> {
-    const LEN: usize = 1 + 32 + 32 + V::SIZE;
+    const LEN: usize = 1 + 32 + 32 + 64 * AUDITORS + V::SIZE;
 }
@@ -0,0 +1,81 @@
+use borsh::{BorshSerialize, BorshDeserialize};
+use serde::{Serialize, Deserialize};
+use solana_program::{msg, pubkey::Pubkey, program_pack::IsInitialized, entrypoint::ProgramResult};
+
+use crate::bn::BigInteger256 as BigInteger;
+use crate::{params::root::DEFAULT_ROOT_HASH, Packer, error::MazeError};
+use super::is_fr_valid;
+
+pub fn get_exclusion_set_pda<'a>(
+    vault: &'a Pubkey,
+    program_id: &Pubkey,
+) -> (Pubkey, (&'a [u8], &'static [u8], [u8; 1])) {
+    let vault_ref = vault.as_ref();
+
+    let (key, seed) = Pubkey::find_program_address(
+        &[vault_ref, b"exclusion"],
+        program_id,
+    );
+
+    (key, (vault_ref, b"exclusion", [seed]))
+}
+
+/// Root of the sparse merkle tree of blocked leaf indexes of a vault, published by its curator.
+/// Withdrawals prove their src leaf index is an empty slot of the tree.
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize, Serialize, Deserialize)]
+pub struct ExclusionSet {
+    is_initialized: bool,
+    pub vault: Pubkey,
+    pub curator: Pubkey,
+    pub root: BigInteger,
+}
+
+impl ExclusionSet {
+    pub fn new(vault: Pubkey, curator: Pubkey) -> Self {
+        Self {
+            is_initialized: true,
+            vault,
+            curator,
+            // the exclusion tree has the height and hasher of the vault tree, so it starts from the same empty root
+            root: DEFAULT_ROOT_HASH,
+        }
+    }
+
+    pub fn check_curator(&self, curator: &Pubkey) -> ProgramResult {
+        if &self.curator != curator {
+            msg!("Curator of exclusion set is invalid");
+            return Err(MazeError::UnmatchedAccounts.into());
+        }
+
+        Ok(())
+    }
+
+    pub fn update(&mut self, root: BigInteger) -> ProgramResult {
+        if !is_fr_valid(&root) {
+            msg!("Exclusion root is invalid");
+            return Err(MazeError::InvalidVanillaData.into());
+        }
+        self.root = root;
+
+        Ok(())
+    }
+
+    pub fn check_root(&self, root: &BigInteger) -> ProgramResult {
+        if &self.root != root {
+            msg!("Exclusion root of vanilla data does not match with the curator");
+            return Err(MazeError::InvalidVanillaData.into());
+        }
+
+        Ok(())
+    }
+}
+
+impl IsInitialized for ExclusionSet {
+    fn is_initialized(&self) -> bool {
+        self.is_initialized
+    }
+}
+
+impl Packer for ExclusionSet {
+    const LEN: usize = 1 + 32 + 32 + 32;
+}
@@ -1,15 +1,20 @@
 pub mod vault;
 pub mod withdraw;
+pub mod transfer;
 pub mod node;
 pub mod commitment;
 pub mod deposit;
+pub mod batch_deposit;
 pub mod nullifier;
 pub mod credential;
 pub mod utxo;
+pub mod exclusion;
+pub mod allowlist;
 
 use std::fmt::Debug;
 use arrayref::array_refs;
 use borsh::{BorshSerialize, BorshDeserialize};
+use serde::{Serialize, Deserialize};
 use solana_program::{hash::{hash, Hash}, pubkey::Pubkey, program_error::ProgramError};
 
 use crate::bn::{BigInteger256 as BigInteger, FpParameters};
@@ -17,7 +22,7 @@ use crate::params::bn::FrParameters;
 use crate::params::{verify::ProofType, verify::PreparedVerifyingKey};
 use crate::verifier::{Proof, Verifier, program::Program, prepare_inputs::PrepareInputs};
 
-#[derive(Debug, Clone, BorshDeserialize, BorshSerialize)]
+#[derive(Debug, Clone, Copy, Default, BorshDeserialize, BorshSerialize, Serialize, Deserialize)]
 pub struct EdwardsAffine {
     pub x: BigInteger,
     pub y: BigInteger,
@@ -52,22 +57,54 @@ pub trait VanillaData: Debug + Clone + BorshSerialize + BorshDeserialize {
     const INPUTS_LEN: usize;
     const SIZE: usize;
 
-    fn to_public_inputs(self) -> Box<Vec<BigInteger>>;
+    fn to_public_inputs(self, auditor_keys: &[EdwardsAffine]) -> Box<Vec<BigInteger>>;
+
+    /// Circuit the data is proven by, data with optional inputs picks the circuit carrying them.
+    fn proof_type(&self) -> ProofType {
+        Self::PROOF_TYPE
+    }
 
     fn hash(&self) -> Result<Hash, ProgramError> {
         let data = self.try_to_vec()?;
         Ok(hash(&data))
     }
 
-    fn to_verifier(self, proof: Box<Proof>) -> Result<Verifier, ProgramError> {
+    fn to_verifier(self, proof: Box<Proof>, auditor_keys: &[EdwardsAffine]) -> Result<Verifier, ProgramError> {
         let credential_hash = self.hash()?;
-        let public_inputs = self.to_public_inputs();
+        let proof_type = self.proof_type();
+        let public_inputs = self.to_public_inputs(auditor_keys);
         let program = Program::PrepareInputs(PrepareInputs::new(
-            Self::PVK,
+            proof_type.pvk(),
             public_inputs,
             proof,
         ));
 
-        Ok(Verifier::new(Self::PROOF_TYPE, credential_hash, program))
+        Ok(Verifier::new(proof_type, credential_hash, program))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::params::verify::ProofType;
+    use super::VanillaData;
+    use super::{deposit::DepositVanillaData, batch_deposit::BatchDepositVanillaData};
+    use super::{withdraw::WithdrawVanillaData, transfer::TransferVanillaData};
+
+    fn check_pvk(proof_type: ProofType, inputs_len: usize) {
+        // gamma_abc_g1 of the setup has one base point for the constant term and one for each public input,
+        // the first one is split out as g_ic_init
+        assert_eq!(proof_type.pvk().gamma_abc_g1.len(), inputs_len, "{:?}", proof_type);
+    }
+
+    #[test]
+    fn test_pvk_inputs_len() {
+        check_pvk(DepositVanillaData::PROOF_TYPE, DepositVanillaData::INPUTS_LEN);
+        check_pvk(BatchDepositVanillaData::PROOF_TYPE, BatchDepositVanillaData::INPUTS_LEN);
+        check_pvk(WithdrawVanillaData::PROOF_TYPE, WithdrawVanillaData::INPUTS_LEN);
+        check_pvk(TransferVanillaData::PROOF_TYPE, TransferVanillaData::INPUTS_LEN);
+        // the exclusion root and allowlist root follow the commitment
+        check_pvk(ProofType::WithdrawExclusion, WithdrawVanillaData::INPUTS_LEN + 1);
+        check_pvk(ProofType::WithdrawAllowlist, WithdrawVanillaData::INPUTS_LEN + 1);
+        check_pvk(ProofType::WithdrawExclusionAllowlist, WithdrawVanillaData::INPUTS_LEN + 2);
     }
 }
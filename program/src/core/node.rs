@@ -2,8 +2,10 @@ use borsh::{BorshSerialize, BorshDeserialize};
 use solana_program::program_pack::IsInitialized;
 use solana_program::pubkey::Pubkey;
 
-use crate::{Packer, params::HEIGHT, bn::BigInteger256 as BigInteger};
-use super::is_fr_valid;
+use num_traits::Zero;
+
+use crate::{Packer, params::{HEIGHT, BATCH_DEPOSIT_DEPTH, BATCH_DEPOSIT_SIZE, bn::Fr}, bn::{BigInteger256 as BigInteger, Field}};
+use crate::params::merkle::{WIDTH, FULL_ROUNDS, PARTIAL_ROUNDS, ALPHA, ROUND_KEYS, MDS_MATRIX, DEFAULT_NODE_HASHES};
 
 /////////////////// Binary Merkle Tree //////////////////////////
 ///                         O                 ---------- root
@@ -20,17 +22,60 @@ use super::is_fr_valid;
 /////////////////////////////////////////////////////////////////
 
 #[inline]
-pub fn is_updating_nodes_valid(nodes: &[BigInteger]) -> bool {
-    if nodes.len() != HEIGHT {
-        false
-    } else {
-        nodes.iter().all(|x| is_fr_valid(x))
-    }
+pub fn gen_merkle_path_from_leaf_index(index: u64) -> Vec<(u8, u64)> {
+    (0..HEIGHT).into_iter().map(|layer| (layer as u8, index >> layer)).collect()
 }
 
+/// Nodes written by a batch deposit at `index`: all nodes of the subtree layer by layer,
+/// the path above the subtree root is hashed up by `UpdateMerkleNode` like any other leaf.
 #[inline]
-pub fn gen_merkle_path_from_leaf_index(index: u64) -> Vec<(u8, u64)> {
-    (0..HEIGHT).into_iter().map(|layer| (layer as u8, index >> layer)).collect()
+pub fn gen_batch_subtree_from_leaf_index(index: u64) -> Vec<(u8, u64)> {
+    (0..=BATCH_DEPOSIT_DEPTH).into_iter().flat_map(|layer| {
+        (0..(BATCH_DEPOSIT_SIZE >> layer) as u64).map(move |i| (layer as u8, (index >> layer) + i))
+    }).collect()
+}
+
+/// Hash of an empty node at `layer`.
+#[inline]
+pub fn default_node_hash(layer: u8) -> BigInteger {
+    DEFAULT_NODE_HASHES[layer as usize]
+}
+
+#[inline]
+fn sbox(x: &mut Fr) {
+    let base = *x;
+    for i in (0..(63 - ALPHA.leading_zeros())).rev() {
+        x.square_in_place();
+        if (ALPHA >> i) & 1 == 1 {
+            *x *= base;
+        }
+    }
+}
+
+/// Poseidon permutation of the merkle hasher of the circuits, so that the contract hashes up
+/// the nodes of a leaf written by a proof without trusting any node from the user.
+pub fn hash_two(left: &BigInteger, right: &BigInteger) -> Option<BigInteger> {
+    let mut state = [Fr::zero(); WIDTH];
+    state[1] = Fr::from_repr(*left)?;
+    state[2] = Fr::from_repr(*right)?;
+
+    let half_rounds = FULL_ROUNDS / 2;
+    for (r, round_keys) in ROUND_KEYS.chunks(WIDTH).take(FULL_ROUNDS + PARTIAL_ROUNDS).enumerate() {
+        state.iter_mut().zip(round_keys).for_each(|(x, k)| *x += k);
+
+        if r < half_rounds || r >= half_rounds + PARTIAL_ROUNDS {
+            state.iter_mut().for_each(sbox);
+        } else {
+            sbox(&mut state[0]);
+        }
+
+        let prev = state;
+        state.iter_mut().zip(MDS_MATRIX).for_each(|(x, row)| {
+            *x = row.iter().zip(prev.iter()).fold(Fr::zero(), |acc, (m, y)| acc + *m * y);
+        });
+    }
+
+    Some(state[0].into_repr())
 }
 
 pub fn get_merkle_node_pda<'a>(
@@ -75,3 +120,21 @@ impl IsInitialized for MerkleNode {
 impl Packer for MerkleNode {
     const LEN: usize = 1 + 32;
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::params::{HEIGHT, root::DEFAULT_ROOT_HASH};
+    use super::{hash_two, default_node_hash};
+
+    #[test]
+    fn test_default_node_hashes() {
+        let mut hash = default_node_hash(0);
+        for layer in 1..HEIGHT {
+            hash = hash_two(&hash, &hash).unwrap();
+            assert_eq!(hash, default_node_hash(layer as u8));
+        }
+
+        let root = hash_two(&hash, &hash).unwrap();
+        assert_eq!(root, DEFAULT_ROOT_HASH);
+    }
+}
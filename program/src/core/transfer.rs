@@ -0,0 +1,101 @@
+use borsh::{BorshSerialize, BorshDeserialize};
+use solana_program::{msg, pubkey::Pubkey, program_error::ProgramError};
+
+use crate::params::{verify::ProofType, HEIGHT, AUDITORS};
+use crate::{error::MazeError, bn::BigInteger256 as BigInteger};
+use super::{pubkey_to_fr_repr, is_fr_valid, is_edwards_affine_valid};
+use super::commitment::{is_commitment_valid, extend_auditor_key_inputs, extend_commitment_inputs, InnerCommitment};
+use super::{EdwardsAffine, VanillaData, credential::Credential};
+
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct TransferVanillaData {
+    pub receiver: Pubkey,
+    pub withdraw_amount: u64,
+    /// asset id of all leaves is derived from the mint
+    pub token_mint: Pubkey,
+    pub nullifier_points: [EdwardsAffine; 2],
+    pub src_root: BigInteger,
+    /// leaves are added at `leaf_index` and `leaf_index + 1`
+    pub leaf_index: u64,
+    pub leaves: [BigInteger; 2],
+    pub commitments: Box<Vec<InnerCommitment>>,
+}
+
+impl TransferVanillaData {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        receiver: Pubkey,
+        withdraw_amount: u64,
+        token_mint: Pubkey,
+        nullifier_points: [EdwardsAffine; 2],
+        src_root: BigInteger,
+        leaf_index: u64,
+        leaves: [BigInteger; 2],
+        commitments: Box<Vec<InnerCommitment>>,
+    ) -> Result<Self, ProgramError> {
+        if !nullifier_points.iter().all(is_edwards_affine_valid) {
+            msg!("nullifier point is invalid");
+            return Err(MazeError::InvalidVanillaData.into());
+        }
+        if nullifier_points[0].x == nullifier_points[1].x && nullifier_points[0].y == nullifier_points[1].y {
+            msg!("nullifier points are duplicated");
+            return Err(MazeError::InvalidVanillaData.into());
+        }
+        if !is_fr_valid(&src_root) {
+            msg!("src root is invalid");
+            return Err(MazeError::InvalidVanillaData.into());
+        }
+        if leaf_index + 1 >= 1 << HEIGHT {
+            msg!("dst leaf index is too large");
+            return Err(MazeError::InvalidVanillaData.into());
+        }
+        if !leaves.iter().all(is_fr_valid) {
+            msg!("leaf is invalid");
+            return Err(MazeError::InvalidVanillaData.into());
+        }
+        if commitments.len() != 2 || !commitments.iter().all(is_commitment_valid) {
+            msg!("commitment is invalid");
+            return Err(MazeError::InvalidVanillaData.into());
+        }
+
+        Ok(Self {
+            receiver,
+            withdraw_amount,
+            token_mint,
+            nullifier_points,
+            src_root,
+            leaf_index,
+            leaves,
+            commitments,
+        })
+    }
+}
+
+impl VanillaData for TransferVanillaData {
+    const PROOF_TYPE: ProofType = ProofType::Transfer;
+    const INPUTS_LEN: usize = 1 + 1 + 1 + 1 + 2 * 2 + 1 + 2 + 2 * AUDITORS + 2 * 4 * AUDITORS;
+    const SIZE: usize = 32 + 8 + 32 + 2 * 32 * 2 + 32 + 8 + 2 * 32 + 4 + 2 * (4 + AUDITORS * 4 * 32);
+
+    fn to_public_inputs(self, auditor_keys: &[EdwardsAffine]) -> Box<Vec<BigInteger>> {
+        let mut inputs = Box::new(Vec::with_capacity(Self::INPUTS_LEN));
+
+        inputs.push(BigInteger::from(self.withdraw_amount));
+        inputs.push(pubkey_to_fr_repr(&self.token_mint));
+        inputs.push(pubkey_to_fr_repr(&self.receiver));
+        inputs.push(self.src_root);
+        self.nullifier_points.iter().for_each(|point| {
+            inputs.push(point.x);
+            inputs.push(point.y);
+        });
+        inputs.push(BigInteger::from(self.leaf_index));
+        inputs.extend(self.leaves);
+        extend_auditor_key_inputs(&mut inputs, auditor_keys);
+        self.commitments.iter().for_each(|commitment| extend_commitment_inputs(&mut inputs, commitment));
+
+        assert_eq!(inputs.len(), Self::INPUTS_LEN);
+
+        inputs
+    }
+}
+
+pub type TransferCredential = Credential<TransferVanillaData>;
@@ -1,8 +1,9 @@
 use borsh::{BorshSerialize, BorshDeserialize};
 use serde::{Serialize, Deserialize};
-use solana_program::{program_pack::IsInitialized, pubkey::Pubkey};
+use solana_program::{program_pack::IsInitialized, pubkey::Pubkey, entrypoint::ProgramResult, msg};
 
-use crate::Packer;
+use crate::{Packer, error::MazeError, params::MAX_NOTE_CIPHER_SIZE};
+use super::EdwardsAffine;
 
 pub fn get_utxo_pda<'a>(
     utxo: &'a [u8],
@@ -16,10 +17,36 @@ pub fn get_utxo_pda<'a>(
     (key, (utxo, [seed]))
 }
 
+/// Ciphers are authenticated note ciphertexts, which are opaque to the program.
 #[derive(Debug, Clone, BorshSerialize, BorshDeserialize, Serialize, Deserialize)]
 pub enum Amount {
     Origin(u64),
-    Cipher(u128),
+    Cipher(Vec<u8>),
+    /// note encrypted to the viewing key of a shielded address,
+    /// `ephemeral_pubkey` is used by the recipient to derive the shared key.
+    Shielded {
+        ephemeral_pubkey: EdwardsAffine,
+        cipher: Vec<u8>,
+    },
+}
+
+impl Amount {
+    fn size(&self) -> usize {
+        match self {
+            Amount::Origin(_) => 1 + 8,
+            Amount::Cipher(cipher) => 1 + 4 + cipher.len(),
+            Amount::Shielded { cipher, .. } => 1 + 64 + 4 + cipher.len(),
+        }
+    }
+}
+
+pub fn check_note_cipher(cipher: &[u8]) -> ProgramResult {
+    if cipher.is_empty() || cipher.len() > MAX_NOTE_CIPHER_SIZE {
+        msg!("Note cipher length is invalid");
+        return Err(MazeError::InvalidNoteCipher.into());
+    }
+
+    Ok(())
 }
 
 #[derive(Debug, Clone, BorshSerialize, BorshDeserialize, Serialize, Deserialize)]
@@ -37,6 +64,11 @@ impl UTXO {
             amount,
         }
     }
+
+    /// Size of the account, UTXO accounts are allocated to fit their note.
+    pub fn size(&self) -> usize {
+        1 + 8 + self.amount.size()
+    }
 }
 
 impl IsInitialized for UTXO {
@@ -46,5 +78,6 @@ impl IsInitialized for UTXO {
 }
 
 impl Packer for UTXO {
-    const LEN: usize = 1 + 8 + 1 + 16;
+    /// the largest layout, a shielded note with the longest cipher
+    const LEN: usize = 1 + 8 + 1 + 64 + 4 + MAX_NOTE_CIPHER_SIZE;
 }
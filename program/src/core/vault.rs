@@ -1,23 +1,39 @@
 use borsh::{BorshSerialize, BorshDeserialize};
 use serde::{Serialize, Deserialize};
 use solana_program::{msg, pubkey::Pubkey, program_pack::IsInitialized, entrypoint::ProgramResult};
+use spl_associated_token_account::get_associated_token_address;
 
 use crate::bn::BigInteger256 as BigInteger;
-use crate::{params::root::DEFAULT_ROOT_HASH, Packer, error::MazeError};
+use crate::{params::{root::DEFAULT_ROOT_HASH, HEIGHT, ROOT_HISTORY_SIZE, BATCH_DEPOSIT_SIZE, MAX_VAULT_ASSETS, AUDITORS}, Packer, error::MazeError};
+use super::{EdwardsAffine, is_edwards_affine_valid};
 
 #[derive(Debug, Clone, BorshSerialize, BorshDeserialize, Serialize, Deserialize)]
 pub struct Vault {
     is_initialized: bool,
     pub enable: bool,
     pub admin: Pubkey,
-    pub token_account: Pubkey,
+    /// a multi-asset vault custodies several mints in one tree, notes are bound to their mint by asset id
+    pub multi_asset: bool,
+    /// custodied mints, empty slots are default pubkeys, the first one is the mint the vault is created with
+    pub token_mints: [Pubkey; MAX_VAULT_ASSETS],
     pub authority: Pubkey,
     pub seed: [u8; 1],
     pub root: BigInteger,
     pub index: u64,
+    /// number of roots pushed into root history since the vault is created
+    pub root_index: u64,
+    /// ring buffer of recent roots, the `i`th pushed root is stored at `i % ROOT_HISTORY_SIZE`
+    pub root_history: [BigInteger; ROOT_HISTORY_SIZE],
     pub min_deposit: u64,
     pub min_withdraw: u64,
-    pub delegate_fee: u64,
+    /// a withdrawal must insert its dst leaf at least this many leaves after its src leaf, 0 disables the policy
+    pub min_anonymity_gap: u64,
+    /// auditor keys pushed into public inputs of every proof, rotatable without a new setup
+    pub auditor_keys: [EdwardsAffine; AUDITORS],
+    /// a withdrawal must prove its src leaf index is not in the exclusion set of the curator
+    pub exclusion: bool,
+    /// a withdrawal must prove its owner key is in one of the allowlists accepted by the vault
+    pub allowlist: bool,
 }
 
 #[inline]
@@ -52,27 +68,41 @@ pub fn get_vault_authority_pda<'a>(
 }
 
 impl Vault {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         admin: Pubkey,
-        token_account: Pubkey,
+        multi_asset: bool,
+        token_mint: Pubkey,
         authority: Pubkey,
         seed: [u8; 1],
         min_deposit: u64,
         min_withdraw: u64,
-        delegate_fee: u64,
+        min_anonymity_gap: u64,
+        auditor_keys: [EdwardsAffine; AUDITORS],
     ) -> Self {
+        let mut root_history = [BigInteger::default(); ROOT_HISTORY_SIZE];
+        root_history[0] = DEFAULT_ROOT_HASH;
+        let mut token_mints = [Pubkey::default(); MAX_VAULT_ASSETS];
+        token_mints[0] = token_mint;
+
         Self {
             is_initialized: true,
             enable: true,
             admin,
-            token_account,
+            multi_asset,
+            token_mints,
             authority,
             seed,
             root: DEFAULT_ROOT_HASH,
             index: 0,
+            root_index: 0,
+            root_history,
             min_deposit,
             min_withdraw,
-            delegate_fee,
+            min_anonymity_gap,
+            auditor_keys,
+            exclusion: false,
+            allowlist: false,
         }
     }
 
@@ -84,18 +114,72 @@ impl Vault {
         }
     }
 
-    pub fn check_consistency(&self, index: u64, root: &BigInteger) -> ProgramResult {
-        if self.index != index {
-            msg!("Lastest index of vanilla data does not match with vault");
-            return Err(MazeError::InvalidVanillaData.into()); 
+    pub fn check_token_mint(&self, token_mint: &Pubkey) -> ProgramResult {
+        if token_mint != &Pubkey::default() && self.token_mints.contains(token_mint) {
+            Ok(())
+        } else {
+            msg!("Token mint is not custodied by the vault");
+            Err(MazeError::UnmatchedAccounts.into())
         }
-        if &self.root != root {
-            msg!("Root hash of vanilla data does not match with vault");
-            return Err(MazeError::InvalidVanillaData.into()); 
+    }
+
+    /// Token accounts of the vault are associated token accounts of the vault authority.
+    pub fn check_token_account(&self, token_mint: &Pubkey, token_account: &Pubkey) -> ProgramResult {
+        self.check_token_mint(token_mint)?;
+        if &get_associated_token_address(&self.authority, token_mint) != token_account {
+            msg!("Vault token account pubkey is invalid");
+            return Err(MazeError::UnmatchedAccounts.into());
+        }
+        Ok(())
+    }
+
+    /// Leaf index of the next batch deposit, leaves before the aligned subtree are left empty.
+    #[inline]
+    pub fn batch_leaf_index(&self) -> u64 {
+        let size = BATCH_DEPOSIT_SIZE as u64;
+        (self.index + size - 1) / size * size
+    }
+
+    /// Reserve `count` leaves from `leaf_index` for a credential, their nodes are written once the proof is verified.
+    fn reserve(&mut self, leaf_index: u64, count: u64) -> ProgramResult {
+        let index = leaf_index.checked_add(count).ok_or(MazeError::Overflow)?;
+        if index > 1 << HEIGHT {
+            msg!("Merkle tree of vault is full");
+            return Err(MazeError::InvalidVanillaData.into());
         }
+        self.index = index;
         Ok(())
     }
 
+    pub fn reserve_leaves(&mut self, leaf_index: u64, count: u64) -> ProgramResult {
+        if self.index != leaf_index {
+            msg!("Leaf index of vanilla data does not match with vault");
+            return Err(MazeError::InvalidVanillaData.into());
+        }
+        self.reserve(leaf_index, count)
+    }
+
+    pub fn reserve_batch_leaves(&mut self, leaf_index: u64) -> ProgramResult {
+        if self.batch_leaf_index() != leaf_index {
+            msg!("Batch leaf index of vanilla data does not match with vault");
+            return Err(MazeError::InvalidVanillaData.into());
+        }
+        self.reserve(leaf_index, BATCH_DEPOSIT_SIZE as u64)
+    }
+
+    pub fn check_known_root(&self, root: &BigInteger) -> ProgramResult {
+        let history = (self.root_index + 1).min(ROOT_HISTORY_SIZE as u64);
+        let known = (0..history)
+            .map(|i| (self.root_index - i) as usize % ROOT_HISTORY_SIZE)
+            .any(|i| &self.root_history[i] == root);
+        if known {
+            Ok(())
+        } else {
+            msg!("Source root of vanilla data is not in vault root history");
+            Err(MazeError::InvalidVanillaData.into())
+        }
+    }
+
     pub fn check_deposit(&self, deposit_amount: u64) -> ProgramResult {
         if deposit_amount < self.min_deposit {
             msg!("Deposit amount is less than minimum deposit");
@@ -108,14 +192,44 @@ impl Vault {
     pub fn check_withdraw(&self, withdraw_amount: u64) -> ProgramResult {
         if withdraw_amount < self.min_withdraw {
             msg!("Withdraw amount is less than minimum withdraw");
+            Err(MazeError::InvalidVanillaData.into())
+        } else {
+            Ok(())
+        }
+    }
+
+    pub fn check_anonymity_gap(&self, min_anonymity_gap: u64) -> ProgramResult {
+        if min_anonymity_gap != self.min_anonymity_gap {
+            msg!("Anonymity gap of vanilla data does not match with vault");
+            Err(MazeError::InvalidVanillaData.into())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Withdrawals of the vault are restricted by a policy the transfer circuit does not prove.
+    pub fn has_withdraw_policy(&self) -> bool {
+        self.min_anonymity_gap > 0 || self.exclusion || self.allowlist
+    }
+
+    pub fn check_transfer(&self, withdraw_amount: u64) -> ProgramResult {
+        // the transfer circuit does not prove its inputs are outside the exclusion set, so a blocked note
+        // could move to a fresh leaf and leave the set, every note goes through the withdraw circuit instead
+        if self.exclusion {
+            msg!("Transfer is disabled in a vault with an exclusion set");
             return Err(MazeError::InvalidVanillaData.into());
         }
-        if withdraw_amount < self.delegate_fee {
-            msg!("Withdraw amount is less than delegate fee");
+        // the public withdrawal of a transfer would bypass the policy, it goes through the withdraw circuit instead
+        if withdraw_amount > 0 && self.has_withdraw_policy() {
+            msg!("Transfer can not withdraw from a vault with a withdraw policy");
             return Err(MazeError::InvalidVanillaData.into());
         }
-
-        Ok(())
+        if withdraw_amount > 0 && withdraw_amount < self.min_withdraw {
+            msg!("Withdraw amount of transfer is less than minimum withdraw");
+            Err(MazeError::InvalidVanillaData.into())
+        } else {
+            Ok(())
+        }
     }
 
     #[inline]
@@ -123,14 +237,64 @@ impl Vault {
         [vault.as_ref(), &self.seed]
     }
 
-    pub fn update(&mut self, new_root: BigInteger) {
+    fn push_root(&mut self, new_root: BigInteger) {
         self.root = new_root;
-        self.index += 1;
+        self.root_index += 1;
+        self.root_history[self.root_index as usize % ROOT_HISTORY_SIZE] = new_root;
+    }
+
+    /// Set the root hashed up from the merkle nodes, an unchanged root evicts nothing from the history.
+    pub fn update_root(&mut self, new_root: BigInteger) {
+        if self.root != new_root {
+            self.push_root(new_root);
+        }
     }
 
     pub fn control(&mut self, enable: bool) {
         self.enable = enable;
     }
+
+    pub fn add_token_mint(&mut self, token_mint: Pubkey) -> ProgramResult {
+        if !self.multi_asset {
+            msg!("Vault is not a multi-asset vault");
+            return Err(MazeError::InvalidVanillaData.into());
+        }
+        if self.token_mints.contains(&token_mint) {
+            msg!("Token mint is already custodied by the vault");
+            return Err(MazeError::InvalidVanillaData.into());
+        }
+        let slot = self.token_mints
+            .iter_mut()
+            .find(|mint| *mint == &Pubkey::default())
+            .ok_or_else(|| {
+                msg!("Vault custodies too many mints");
+                MazeError::InvalidVanillaData
+            })?;
+        *slot = token_mint;
+
+        Ok(())
+    }
+
+    pub fn set_min_anonymity_gap(&mut self, min_anonymity_gap: u64) -> ProgramResult {
+        // no leaf could be withdrawn under a gap as large as the tree
+        if min_anonymity_gap >= 1 << HEIGHT {
+            msg!("Anonymity gap is too large");
+            return Err(MazeError::InvalidVanillaData.into());
+        }
+        self.min_anonymity_gap = min_anonymity_gap;
+
+        Ok(())
+    }
+
+    pub fn rotate_auditor_keys(&mut self, auditor_keys: [EdwardsAffine; AUDITORS]) -> ProgramResult {
+        if !auditor_keys.iter().all(is_edwards_affine_valid) {
+            msg!("Auditor key is invalid");
+            return Err(MazeError::InvalidVanillaData.into());
+        }
+        self.auditor_keys = auditor_keys;
+
+        Ok(())
+    }
 }
 
 impl IsInitialized for Vault {
@@ -140,5 +304,204 @@ impl IsInitialized for Vault {
 }
 
 impl Packer for Vault {
-    const LEN: usize = 1 + 1 + 32 + 32 + 32 + 1 + 32 + 8 + 8 + 8 + 8;
+    const LEN: usize = 1 + 1 + 32 + 1 + 32 * MAX_VAULT_ASSETS + 32 + 1 + 32 + 8 + 8 + 32 * ROOT_HISTORY_SIZE + 8 + 8 + 8 + 64 * AUDITORS + 1 + 1;
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::pubkey::Pubkey;
+    use spl_associated_token_account::get_associated_token_address;
+
+    use crate::bn::BigInteger256 as BigInteger;
+    use crate::core::EdwardsAffine;
+    use crate::params::{root::DEFAULT_ROOT_HASH, HEIGHT, ROOT_HISTORY_SIZE, BATCH_DEPOSIT_SIZE, MAX_VAULT_ASSETS, AUDITORS};
+    use super::Vault;
+
+    #[test]
+    fn test_root_history() {
+        let mut vault = Vault::new(
+            Pubkey::default(),
+            false,
+            Pubkey::new_unique(),
+            Pubkey::default(),
+            [0],
+            0,
+            0,
+            0,
+            [EdwardsAffine::default(); AUDITORS],
+        );
+        assert!(vault.check_known_root(&DEFAULT_ROOT_HASH).is_ok());
+        assert!(vault.check_known_root(&BigInteger::default()).is_err());
+
+        // an unchanged root is not pushed again
+        vault.update_root(DEFAULT_ROOT_HASH);
+        assert_eq!(vault.root_index, 0);
+
+        let roots = (1..=(ROOT_HISTORY_SIZE as u64 + 1))
+            .map(BigInteger::from)
+            .collect::<Vec<_>>();
+        roots.iter().for_each(|root| vault.update_root(*root));
+
+        // the oldest roots have been overwritten
+        assert!(vault.check_known_root(&DEFAULT_ROOT_HASH).is_err());
+        assert!(vault.check_known_root(&roots[0]).is_err());
+        roots[1..].iter().for_each(|root| assert!(vault.check_known_root(root).is_ok()));
+    }
+
+    #[test]
+    fn test_reserve_leaves() {
+        let mut vault = Vault::new(
+            Pubkey::default(),
+            false,
+            Pubkey::new_unique(),
+            Pubkey::default(),
+            [0],
+            0,
+            0,
+            0,
+            [EdwardsAffine::default(); AUDITORS],
+        );
+        assert_eq!(vault.batch_leaf_index(), 0);
+
+        assert!(vault.reserve_leaves(1, 1).is_err());
+        assert!(vault.reserve_leaves(0, 1).is_ok());
+        assert_eq!(vault.index, 1);
+        // a reservation does not touch the root
+        assert_eq!(vault.root, DEFAULT_ROOT_HASH);
+        assert_eq!(vault.root_index, 0);
+
+        let leaf_index = vault.batch_leaf_index();
+        assert_eq!(leaf_index, BATCH_DEPOSIT_SIZE as u64);
+        assert!(vault.reserve_batch_leaves(1).is_err());
+        assert!(vault.reserve_batch_leaves(leaf_index).is_ok());
+        assert_eq!(vault.index, 2 * BATCH_DEPOSIT_SIZE as u64);
+        assert_eq!(vault.batch_leaf_index(), vault.index);
+
+        assert!(vault.reserve_leaves(vault.index, 2).is_ok());
+        assert_eq!(vault.index, 2 * BATCH_DEPOSIT_SIZE as u64 + 2);
+
+        // the tree is full
+        vault.index = (1 << HEIGHT) - 1;
+        assert!(vault.reserve_leaves(vault.index, 2).is_err());
+        assert!(vault.reserve_leaves(vault.index, 1).is_ok());
+        assert!(vault.reserve_leaves(vault.index, 1).is_err());
+        assert!(vault.reserve_batch_leaves(vault.batch_leaf_index()).is_err());
+    }
+
+    #[test]
+    fn test_multi_asset() {
+        let usdc = Pubkey::new_unique();
+        let usdt = Pubkey::new_unique();
+
+        let mut vault = Vault::new(
+            Pubkey::default(),
+            false,
+            usdc,
+            Pubkey::new_unique(),
+            [0],
+            0,
+            0,
+            0,
+            [EdwardsAffine::default(); AUDITORS],
+        );
+        assert!(vault.check_token_mint(&usdc).is_ok());
+        assert!(vault.add_token_mint(usdt).is_err());
+
+        vault.multi_asset = true;
+        assert!(vault.check_token_mint(&usdt).is_err());
+        assert!(vault.add_token_mint(usdt).is_ok());
+        assert!(vault.add_token_mint(usdt).is_err());
+        assert!(vault.check_token_mint(&usdt).is_ok());
+        assert!(vault.check_token_mint(&Pubkey::default()).is_err());
+
+        let token_account = get_associated_token_address(&vault.authority, &usdt);
+        assert!(vault.check_token_account(&usdt, &token_account).is_ok());
+        assert!(vault.check_token_account(&usdc, &token_account).is_err());
+
+        (2..MAX_VAULT_ASSETS).for_each(|_| vault.add_token_mint(Pubkey::new_unique()).unwrap());
+        assert!(vault.add_token_mint(Pubkey::new_unique()).is_err());
+    }
+
+    #[test]
+    fn test_rotate_auditor_keys() {
+        let mut vault = Vault::new(
+            Pubkey::default(),
+            false,
+            Pubkey::new_unique(),
+            Pubkey::default(),
+            [0],
+            0,
+            0,
+            0,
+            [EdwardsAffine::default(); AUDITORS],
+        );
+
+        let auditor_keys = [EdwardsAffine { x: BigInteger::from(1), y: BigInteger::from(2) }; AUDITORS];
+        assert!(vault.rotate_auditor_keys(auditor_keys).is_ok());
+        assert_eq!(vault.auditor_keys[0].y, BigInteger::from(2));
+
+        // coordinates out of field are rejected and the old keys are kept
+        let invalid_keys = [EdwardsAffine { x: BigInteger::from(1), y: BigInteger::new([u64::MAX; 4]) }; AUDITORS];
+        assert!(vault.rotate_auditor_keys(invalid_keys).is_err());
+        assert_eq!(vault.auditor_keys[0].y, BigInteger::from(2));
+    }
+
+    #[test]
+    fn test_anonymity_gap() {
+        let mut vault = Vault::new(
+            Pubkey::default(),
+            false,
+            Pubkey::new_unique(),
+            Pubkey::default(),
+            [0],
+            0,
+            0,
+            0,
+            [EdwardsAffine::default(); AUDITORS],
+        );
+        assert!(vault.check_anonymity_gap(0).is_ok());
+
+        assert!(vault.set_min_anonymity_gap(16).is_ok());
+        assert!(vault.check_anonymity_gap(16).is_ok());
+        assert!(vault.check_anonymity_gap(0).is_err());
+
+        assert!(vault.set_min_anonymity_gap(1 << HEIGHT).is_err());
+        assert_eq!(vault.min_anonymity_gap, 16);
+    }
+
+    #[test]
+    fn test_transfer_under_withdraw_policy() {
+        let mut vault = Vault::new(
+            Pubkey::default(),
+            false,
+            Pubkey::new_unique(),
+            Pubkey::default(),
+            [0],
+            0,
+            10,
+            0,
+            [EdwardsAffine::default(); AUDITORS],
+        );
+        assert!(!vault.has_withdraw_policy());
+        assert!(vault.check_transfer(0).is_ok());
+        assert!(vault.check_transfer(9).is_err());
+        assert!(vault.check_transfer(10).is_ok());
+
+        // a transfer can still join and split notes, but not withdraw around the gap
+        vault.set_min_anonymity_gap(16).unwrap();
+        assert!(vault.has_withdraw_policy());
+        assert!(vault.check_transfer(0).is_ok());
+        assert!(vault.check_transfer(10).is_err());
+
+        vault.set_min_anonymity_gap(0).unwrap();
+        vault.exclusion = true;
+        assert!(vault.has_withdraw_policy());
+        assert!(vault.check_transfer(0).is_err());
+        assert!(vault.check_transfer(10).is_err());
+
+        vault.exclusion = false;
+        vault.allowlist = true;
+        assert!(vault.has_withdraw_policy());
+        assert!(vault.check_transfer(10).is_err());
+    }
 }
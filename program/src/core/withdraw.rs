@@ -1,37 +1,55 @@
 use borsh::{BorshSerialize, BorshDeserialize};
 use solana_program::{msg, pubkey::Pubkey, program_error::ProgramError};
 
-use crate::params::{verify::ProofType, HEIGHT};
+use crate::params::{verify::ProofType, HEIGHT, AUDITORS};
 use crate::{error::MazeError, bn::BigInteger256 as BigInteger};
 use super::{pubkey_to_fr_repr, is_fr_valid, is_edwards_affine_valid};
-use super::node::is_updating_nodes_valid;
-use super::commitment::{is_commitment_valid, InnerCommitment};
+use super::commitment::{is_commitment_valid, extend_auditor_key_inputs, extend_commitment_inputs, InnerCommitment};
 use super::{EdwardsAffine, VanillaData, credential::Credential};
 
 #[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
 pub struct WithdrawVanillaData {
     pub receiver: Pubkey,
+    /// relayer who finalizes the withdrawal and takes the fee
+    pub relayer: Pubkey,
     pub withdraw_amount: u64,
+    /// asset id of both leaves is derived from the mint
+    pub token_mint: Pubkey,
+    pub fee: u64,
     pub nullifier_point: EdwardsAffine,
     pub leaf_index: u64,
+    /// gap of the vault when the credential is created, the src leaf is at least this many leaves before
+    pub min_anonymity_gap: u64,
     pub leaf: BigInteger,
-    pub prev_root: BigInteger,
-    pub updating_nodes: Box<Vec<BigInteger>>,
+    pub src_root: BigInteger,
     pub commitment: InnerCommitment,
+    /// root of the exclusion set published by the curator, for vaults with an exclusion set
+    pub exclusion_root: Option<BigInteger>,
+    /// root of an allowlist accepted by the vault, for vaults with an allowlist registry
+    pub allowlist_root: Option<BigInteger>,
 }
 
 impl WithdrawVanillaData {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         receiver: Pubkey,
+        relayer: Pubkey,
         withdraw_amount: u64,
+        token_mint: Pubkey,
+        fee: u64,
         nullifier_point: EdwardsAffine,
         leaf_index: u64,
+        min_anonymity_gap: u64,
         leaf: BigInteger,
-        prev_root: BigInteger,
-        updating_nodes: Box<Vec<BigInteger>>,
+        src_root: BigInteger,
         commitment: InnerCommitment,
+        exclusion_root: Option<BigInteger>,
+        allowlist_root: Option<BigInteger>,
     ) -> Result<Self, ProgramError> {
+        if fee > withdraw_amount {
+            msg!("fee is greater than withdraw amount");
+            return Err(MazeError::InvalidVanillaData.into());
+        }
         if !is_edwards_affine_valid(&nullifier_point) {
             msg!("nullifier point is invalid");
             return Err(MazeError::InvalidVanillaData.into());
@@ -44,54 +62,79 @@ impl WithdrawVanillaData {
             msg!("leaf is invalid");
             return Err(MazeError::InvalidVanillaData.into());
         }
-        if !is_fr_valid(&prev_root) {
-            msg!("prev root is invalid");
-            return Err(MazeError::InvalidVanillaData.into());
-        }
-        if !is_updating_nodes_valid(&updating_nodes) {
-            msg!("updating nodes are invalid");
+        if !is_fr_valid(&src_root) {
+            msg!("src root is invalid");
             return Err(MazeError::InvalidVanillaData.into());
         }
         if !is_commitment_valid(&commitment) {
             msg!("commitment is invalid");
             return Err(MazeError::InvalidVanillaData.into());
         }
+        if !exclusion_root.as_ref().map_or(true, is_fr_valid) {
+            msg!("exclusion root is invalid");
+            return Err(MazeError::InvalidVanillaData.into());
+        }
+        if !allowlist_root.as_ref().map_or(true, is_fr_valid) {
+            msg!("allowlist root is invalid");
+            return Err(MazeError::InvalidVanillaData.into());
+        }
         
         Ok(Self {
             receiver,
+            relayer,
             withdraw_amount,
+            token_mint,
+            fee,
             nullifier_point,
             leaf_index,
+            min_anonymity_gap,
             leaf,
-            prev_root,
-            updating_nodes,
+            src_root,
             commitment,
+            exclusion_root,
+            allowlist_root,
         })
     }
 }
 
 impl VanillaData for WithdrawVanillaData {
     const PROOF_TYPE: ProofType = ProofType::Withdraw;
-    const INPUTS_LEN: usize = 1 + 1 + 1 + 1 + 1 + 1 + 1 + HEIGHT + 4;
-    const SIZE: usize = 32 + 8 + 32 * 2 + 8 + 32 + 32 + 4 + 32 * HEIGHT + 4 * 32;
+    const INPUTS_LEN: usize = 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 1 + 2 * AUDITORS + 4 * AUDITORS;
+    const SIZE: usize = 32 + 32 + 8 + 32 + 8 + 32 * 2 + 8 + 8 + 32 + 32 + 4 + AUDITORS * 4 * 32 + 1 + 32 + 1 + 32;
+
+    fn proof_type(&self) -> ProofType {
+        match (self.exclusion_root.is_some(), self.allowlist_root.is_some()) {
+            (false, false) => ProofType::Withdraw,
+            (true, false) => ProofType::WithdrawExclusion,
+            (false, true) => ProofType::WithdrawAllowlist,
+            (true, true) => ProofType::WithdrawExclusionAllowlist,
+        }
+    }
 
-    fn to_public_inputs(self) -> Box<Vec<BigInteger>> {
-        let mut inputs = Box::new(Vec::with_capacity(Self::INPUTS_LEN));
+    fn to_public_inputs(self, auditor_keys: &[EdwardsAffine]) -> Box<Vec<BigInteger>> {
+        // the roots of index sets follow the commitment, one for each set the circuit carries
+        let inputs_len = Self::INPUTS_LEN
+            + self.exclusion_root.is_some() as usize
+            + self.allowlist_root.is_some() as usize;
+        let mut inputs = Box::new(Vec::with_capacity(inputs_len));
 
         inputs.push(BigInteger::from(self.withdraw_amount));
+        inputs.push(pubkey_to_fr_repr(&self.token_mint));
         inputs.push(pubkey_to_fr_repr(&self.receiver));
+        inputs.push(pubkey_to_fr_repr(&self.relayer));
+        inputs.push(BigInteger::from(self.fee));
         inputs.push(BigInteger::from(self.leaf_index));
+        inputs.push(BigInteger::from(self.min_anonymity_gap));
         inputs.push(self.leaf);
-        inputs.push(self.prev_root);
+        inputs.push(self.src_root);
         inputs.push(self.nullifier_point.x);
         inputs.push(self.nullifier_point.y);
-        inputs.extend(*self.updating_nodes);
-        inputs.push(self.commitment.0.x);
-        inputs.push(self.commitment.0.y);
-        inputs.push(self.commitment.1.x);
-        inputs.push(self.commitment.1.y);
+        extend_auditor_key_inputs(&mut inputs, auditor_keys);
+        extend_commitment_inputs(&mut inputs, &self.commitment);
+        inputs.extend(self.exclusion_root);
+        inputs.extend(self.allowlist_root);
 
-        assert_eq!(inputs.len(), Self::INPUTS_LEN);
+        assert_eq!(inputs.len(), inputs_len);
 
         inputs
     }
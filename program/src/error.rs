@@ -37,6 +37,8 @@ pub enum MazeError {
     NotInitialized,
     #[error("Math operation overflow")]
     Overflow,
+    #[error("Note cipher is invalid")]
+    InvalidNoteCipher,
 }
 
 impl From<MazeError> for ProgramError {
@@ -8,13 +8,16 @@ use crate::{
     verifier::{Proof, get_verifier_pda},
     core::{
         nullifier::get_nullifier_pda,
-        credential::{get_deposit_credential_pda, get_withdraw_credential_pda},
+        credential::{get_deposit_credential_pda, get_withdraw_credential_pda, get_transfer_credential_pda, get_batch_deposit_credential_pda},
         commitment::{get_commitment_pda, InnerCommitment},
         vault::{get_vault_pda, get_vault_authority_pda},
-        node::{get_merkle_node_pda, gen_merkle_path_from_leaf_index},
+        node::{get_merkle_node_pda, gen_batch_subtree_from_leaf_index},
         utxo::get_utxo_pda,
+        exclusion::get_exclusion_set_pda,
+        allowlist::get_allowlist_registry_pda,
         EdwardsAffine,
     },
+    params::{AUDITORS, HEIGHT},
     error::MazeError,
 };
 
@@ -22,8 +25,10 @@ use crate::{
 pub enum MazeInstruction {
     CreateDepositCredential {
         deposit_amount: u64,
+        token_mint: Pubkey,
+        /// leaf reserved by the credential, the next leaf of the vault
+        leaf_index: u64,
         leaf: BigInteger,
-        updating_nodes: Box<Vec<BigInteger>>,
         commitment: InnerCommitment,
     },
     CreateDepositVerifier {
@@ -35,11 +40,19 @@ pub enum MazeInstruction {
     },
     CreateWithdrawCredential {
         withdraw_amount: u64,
+        token_mint: Pubkey,
+        /// paid to the delegator, who is bound to the proof as relayer
+        fee: u64,
         receiver: Pubkey,
         nullifier_point: EdwardsAffine,
+        leaf_index: u64,
         leaf: BigInteger,
-        updating_nodes: Box<Vec<BigInteger>>,
+        src_root: BigInteger,
         commitment: InnerCommitment,
+        /// root of the exclusion set, required by a vault with one
+        exclusion_root: Option<BigInteger>,
+        /// root of an accepted allowlist, required by a vault with an allowlist registry
+        allowlist_root: Option<BigInteger>,
     },
     CreateWithdrawVerifier {
         proof: Box<Proof>,
@@ -47,15 +60,70 @@ pub enum MazeInstruction {
     VerifyWithdrawProof,
     FinalizeWithdraw {
         utxo: [u8; 32],
-        balance_cipher: u128,
+        balance_cipher: Vec<u8>,
+    },
+    CreateTransferCredential {
+        withdraw_amount: u64,
+        token_mint: Pubkey,
+        receiver: Pubkey,
+        nullifier_points: [EdwardsAffine; 2],
+        src_root: BigInteger,
+        leaf_index: u64,
+        leaves: [BigInteger; 2],
+        commitments: Box<Vec<InnerCommitment>>,
+    },
+    CreateTransferVerifier {
+        proof: Box<Proof>,
+    },
+    VerifyTransferProof,
+    FinalizeTransfer {
+        utxos: [[u8; 32]; 2],
+        balance_ciphers: Box<Vec<Vec<u8>>>,
+        /// set if the first output is paid to another shielded address
+        ephemeral_pubkey: Option<EdwardsAffine>,
+    },
+    CreateBatchDepositCredential {
+        deposit_amounts: Box<Vec<u64>>,
+        token_mint: Pubkey,
+        /// first leaf of the reserved subtree, aligned to the batch size
+        leaf_index: u64,
+        leaves: Box<Vec<BigInteger>>,
+        subtree_nodes: Box<Vec<BigInteger>>,
+        commitments: Box<Vec<InnerCommitment>>,
+    },
+    CreateBatchDepositVerifier {
+        proof: Box<Proof>,
+    },
+    VerifyBatchDepositProof,
+    FinalizeBatchDeposit {
+        utxos: Box<Vec<[u8; 32]>>,
     },
     // 128 ~
     CreateVault {
         min_deposit: u64,
         min_withdraw: u64,
-        delegate_fee: u64,
+        /// minimum leaves between the src and dst leaf of a withdrawal
+        min_anonymity_gap: u64,
+        /// custody several mints in one tree
+        multi_asset: bool,
+        auditor_keys: [EdwardsAffine; AUDITORS],
     },
     ControlVault(bool),
+    AddVaultAsset,
+    RotateAuditorKeys([EdwardsAffine; AUDITORS]),
+    SetMinAnonymityGap(u64),
+    CreateExclusionSet {
+        curator: Pubkey,
+    },
+    UpdateExclusionRoot(BigInteger),
+    CreateAllowlistRegistry,
+    AddAllowlistRoot(BigInteger),
+    RemoveAllowlistRoot(BigInteger),
+    /// hash the children of a node, the node at layer `HEIGHT` is the root of the vault
+    UpdateMerkleNode {
+        layer: u8,
+        index: u64,
+    },
 }
 
 pub fn create_vault(
@@ -63,7 +131,9 @@ pub fn create_vault(
     admin: Pubkey,
     min_deposit: u64,
     min_withdraw: u64,
-    delegate_fee: u64,
+    min_anonymity_gap: u64,
+    multi_asset: bool,
+    auditor_keys: [EdwardsAffine; AUDITORS],
 ) -> Result<Instruction, MazeError> {
     let (vault, _) = get_vault_pda(&admin, &token_mint, &ID);
     let (vault_signer, _) = get_vault_authority_pda(&vault, &ID);
@@ -76,7 +146,9 @@ pub fn create_vault(
     let data = MazeInstruction::CreateVault {
         min_deposit,
         min_withdraw,
-        delegate_fee,
+        min_anonymity_gap,
+        multi_asset,
+        auditor_keys,
     }.try_to_vec().map_err(|_| MazeError::InstructionUnpackError)?;
 
     Ok(Instruction {
@@ -109,20 +181,154 @@ pub fn control_vault(vault: Pubkey, admin: Pubkey, enable: bool) -> Result<Instr
     })
 }
 
+pub fn add_vault_asset(vault: Pubkey, token_mint: Pubkey, admin: Pubkey) -> Result<Instruction, MazeError> {
+    let (vault_signer, _) = get_vault_authority_pda(&vault, &ID);
+    let vault_token_account = get_associated_token_address(&vault_signer, &token_mint);
+
+    let data = MazeInstruction::AddVaultAsset.try_to_vec().map_err(|_| MazeError::InstructionUnpackError)?;
+
+    Ok(Instruction {
+        program_id: ID,
+        accounts: vec![
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+            AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+            AccountMeta::new_readonly(sysvar::rent::ID, false),
+            AccountMeta::new_readonly(token_mint, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new_readonly(vault_signer, false),
+            AccountMeta::new(vault_token_account, false),
+            AccountMeta::new_readonly(admin, true),
+        ],
+        data,
+    })
+}
+
+pub fn rotate_auditor_keys(vault: Pubkey, admin: Pubkey, auditor_keys: [EdwardsAffine; AUDITORS]) -> Result<Instruction, MazeError> {
+    let data = MazeInstruction::RotateAuditorKeys(auditor_keys).try_to_vec().map_err(|_| MazeError::InstructionUnpackError)?;
+
+    Ok(Instruction {
+        program_id: ID,
+        accounts: vec![
+            AccountMeta::new(vault, false),
+            AccountMeta::new_readonly(admin, true),
+        ],
+        data,
+    })
+}
+
+pub fn set_min_anonymity_gap(vault: Pubkey, admin: Pubkey, min_anonymity_gap: u64) -> Result<Instruction, MazeError> {
+    let data = MazeInstruction::SetMinAnonymityGap(min_anonymity_gap).try_to_vec().map_err(|_| MazeError::InstructionUnpackError)?;
+
+    Ok(Instruction {
+        program_id: ID,
+        accounts: vec![
+            AccountMeta::new(vault, false),
+            AccountMeta::new_readonly(admin, true),
+        ],
+        data,
+    })
+}
+
+pub fn create_exclusion_set(vault: Pubkey, admin: Pubkey, curator: Pubkey) -> Result<Instruction, MazeError> {
+    let (exclusion_set, _) = get_exclusion_set_pda(&vault, &ID);
+
+    let data = MazeInstruction::CreateExclusionSet { curator }.try_to_vec().map_err(|_| MazeError::InstructionUnpackError)?;
+
+    Ok(Instruction {
+        program_id: ID,
+        accounts: vec![
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(sysvar::rent::ID, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new(exclusion_set, false),
+            AccountMeta::new(admin, true),
+        ],
+        data,
+    })
+}
+
+pub fn update_exclusion_root(vault: Pubkey, curator: Pubkey, root: BigInteger) -> Result<Instruction, MazeError> {
+    let (exclusion_set, _) = get_exclusion_set_pda(&vault, &ID);
+
+    let data = MazeInstruction::UpdateExclusionRoot(root).try_to_vec().map_err(|_| MazeError::InstructionUnpackError)?;
+
+    Ok(Instruction {
+        program_id: ID,
+        accounts: vec![
+            AccountMeta::new(exclusion_set, false),
+            AccountMeta::new_readonly(curator, true),
+        ],
+        data,
+    })
+}
+
+pub fn create_allowlist_registry(vault: Pubkey, admin: Pubkey) -> Result<Instruction, MazeError> {
+    let (allowlist_registry, _) = get_allowlist_registry_pda(&vault, &ID);
+
+    let data = MazeInstruction::CreateAllowlistRegistry.try_to_vec().map_err(|_| MazeError::InstructionUnpackError)?;
+
+    Ok(Instruction {
+        program_id: ID,
+        accounts: vec![
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(sysvar::rent::ID, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new(allowlist_registry, false),
+            AccountMeta::new(admin, true),
+        ],
+        data,
+    })
+}
+
+pub fn add_allowlist_root(vault: Pubkey, admin: Pubkey, root: BigInteger) -> Result<Instruction, MazeError> {
+    let (allowlist_registry, _) = get_allowlist_registry_pda(&vault, &ID);
+
+    let data = MazeInstruction::AddAllowlistRoot(root).try_to_vec().map_err(|_| MazeError::InstructionUnpackError)?;
+
+    Ok(Instruction {
+        program_id: ID,
+        accounts: vec![
+            AccountMeta::new_readonly(vault, false),
+            AccountMeta::new(allowlist_registry, false),
+            AccountMeta::new_readonly(admin, true),
+        ],
+        data,
+    })
+}
+
+pub fn remove_allowlist_root(vault: Pubkey, admin: Pubkey, root: BigInteger) -> Result<Instruction, MazeError> {
+    let (allowlist_registry, _) = get_allowlist_registry_pda(&vault, &ID);
+
+    let data = MazeInstruction::RemoveAllowlistRoot(root).try_to_vec().map_err(|_| MazeError::InstructionUnpackError)?;
+
+    Ok(Instruction {
+        program_id: ID,
+        accounts: vec![
+            AccountMeta::new_readonly(vault, false),
+            AccountMeta::new(allowlist_registry, false),
+            AccountMeta::new_readonly(admin, true),
+        ],
+        data,
+    })
+}
+
 pub fn create_deposit_credential(
     vault: Pubkey,
     depositor: Pubkey,
     deposit_amount: u64,
+    token_mint: Pubkey,
+    leaf_index: u64,
     leaf: BigInteger,
-    updating_nodes: Box<Vec<BigInteger>>,
     commitment: InnerCommitment,
 ) -> Result<Instruction, MazeError> {
     let (credential, _) = get_deposit_credential_pda(&vault, &depositor, &ID);
 
     let data = MazeInstruction::CreateDepositCredential {
         deposit_amount,
+        token_mint,
+        leaf_index,
         leaf,
-        updating_nodes,
         commitment,
     }.try_to_vec().map_err(|_| MazeError::InstructionUnpackError)?;
 
@@ -131,7 +337,7 @@ pub fn create_deposit_credential(
         accounts: vec![
             AccountMeta::new_readonly(system_program::ID, false),
             AccountMeta::new_readonly(sysvar::rent::ID, false),
-            AccountMeta::new_readonly(vault, false),
+            AccountMeta::new(vault, false),
             AccountMeta::new(credential, false),
             AccountMeta::new(depositor, true),
         ],
@@ -205,8 +411,144 @@ pub fn finalize_deposit(
     let user_token_account = get_associated_token_address(&depositor, &token_mint);
     let (utxo_key, _) = get_utxo_pda(&utxo, &ID);
 
-    let merkle_path = gen_merkle_path_from_leaf_index(leaf_index);
-    let nodes_accounts = merkle_path.into_iter().map(|(layer, index)| {
+    let (node, _) = get_merkle_node_pda(&vault, 0, leaf_index, &ID);
+
+    let accounts = vec![
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(spl_token::ID, false),
+        AccountMeta::new_readonly(sysvar::rent::ID, false),
+        AccountMeta::new_readonly(vault, false),
+        AccountMeta::new(credential, false),
+        AccountMeta::new(verifier, false),
+        AccountMeta::new(commitment, false),
+        AccountMeta::new(user_token_account, false),
+        AccountMeta::new(vault_token_account, false),
+        AccountMeta::new(utxo_key, false),
+        AccountMeta::new(depositor, true),
+        AccountMeta::new(node, false),
+    ];
+
+    let data = MazeInstruction::FinalizeDeposit {
+        utxo,
+    }.try_to_vec().map_err(|_| MazeError::InstructionUnpackError)?;
+
+    Ok(Instruction {
+        program_id: ID,
+        accounts,
+        data,
+    })
+}
+
+pub fn create_batch_deposit_credential(
+    vault: Pubkey,
+    depositor: Pubkey,
+    deposit_amounts: Box<Vec<u64>>,
+    token_mint: Pubkey,
+    leaf_index: u64,
+    leaves: Box<Vec<BigInteger>>,
+    subtree_nodes: Box<Vec<BigInteger>>,
+    commitments: Box<Vec<InnerCommitment>>,
+) -> Result<Instruction, MazeError> {
+    let (credential, _) = get_batch_deposit_credential_pda(&vault, &depositor, &ID);
+
+    let data = MazeInstruction::CreateBatchDepositCredential {
+        deposit_amounts,
+        token_mint,
+        leaf_index,
+        leaves,
+        subtree_nodes,
+        commitments,
+    }.try_to_vec().map_err(|_| MazeError::InstructionUnpackError)?;
+
+    Ok(Instruction {
+        program_id: ID,
+        accounts: vec![
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(sysvar::rent::ID, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new(credential, false),
+            AccountMeta::new(depositor, true),
+        ],
+        data,
+    })
+}
+
+pub fn create_batch_deposit_verifier(
+    vault: Pubkey,
+    depositor: Pubkey,
+    proof: Box<Proof>,
+) -> Result<Instruction, MazeError> {
+    let (credential, _) = get_batch_deposit_credential_pda(&vault, &depositor, &ID);
+    let (verifier, _) = get_verifier_pda(&credential, &ID);
+
+    let data = MazeInstruction::CreateBatchDepositVerifier {
+        proof,
+    }.try_to_vec().map_err(|_| MazeError::InstructionUnpackError)?;
+
+    Ok(Instruction {
+        program_id: ID,
+        accounts: vec![
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(sysvar::rent::ID, false),
+            AccountMeta::new_readonly(vault, false),
+            AccountMeta::new(credential, false),
+            AccountMeta::new(verifier, false),
+            AccountMeta::new(depositor, true),
+        ],
+        data,
+    })
+}
+
+pub fn verify_batch_deposit_proof(
+    vault: Pubkey,
+    depositor: Pubkey,
+    padding: Vec<u8>,
+) -> Result<Instruction, MazeError> {
+    let (credential, _) = get_batch_deposit_credential_pda(&vault, &depositor, &ID);
+    let (verifier, _) = get_verifier_pda(&credential, &ID);
+
+    let mut data = MazeInstruction::VerifyBatchDepositProof
+        .try_to_vec()
+        .map_err(|_| MazeError::InstructionUnpackError)?;
+    data.extend(padding);
+
+    Ok(Instruction {
+        program_id: ID,
+        accounts: vec![
+            AccountMeta::new_readonly(vault, false),
+            AccountMeta::new_readonly(credential, false),
+            AccountMeta::new(verifier, false),
+        ],
+        data,
+    })
+}
+
+/// Too many accounts are referenced to fit in a legacy transaction,
+/// send it in a versioned transaction with an address lookup table.
+pub fn finalize_batch_deposit(
+    vault: Pubkey,
+    token_mint: Pubkey,
+    depositor: Pubkey,
+    leaf_index: u64,
+    leaves: &[BigInteger],
+    utxos: Box<Vec<[u8; 32]>>,
+) -> Result<Instruction, MazeError> {
+    let (vault_signer, _) = get_vault_authority_pda(&vault, &ID);
+    let (credential, _) = get_batch_deposit_credential_pda(&vault, &depositor, &ID);
+    let (verifier, _) = get_verifier_pda(&credential, &ID);
+    let vault_token_account = get_associated_token_address(&vault_signer, &token_mint);
+    let user_token_account = get_associated_token_address(&depositor, &token_mint);
+
+    let commitments_accounts = leaves.iter().map(|leaf| {
+        let (commitment, _) = get_commitment_pda(leaf, &ID);
+        AccountMeta::new(commitment, false)
+    });
+    let utxos_accounts = utxos.iter().map(|utxo| {
+        let (utxo_key, _) = get_utxo_pda(utxo, &ID);
+        AccountMeta::new(utxo_key, false)
+    });
+    let subtree_path = gen_batch_subtree_from_leaf_index(leaf_index);
+    let nodes_accounts = subtree_path.into_iter().map(|(layer, index)| {
         let (node, _) = get_merkle_node_pda(
             &vault,
             layer,
@@ -214,25 +556,25 @@ pub fn finalize_deposit(
             &ID,
         );
         AccountMeta::new(node, false)
-    }).collect::<Vec<_>>();
+    });
 
     let mut accounts = vec![
         AccountMeta::new_readonly(system_program::ID, false),
         AccountMeta::new_readonly(spl_token::ID, false),
         AccountMeta::new_readonly(sysvar::rent::ID, false),
-        AccountMeta::new(vault, false),
+        AccountMeta::new_readonly(vault, false),
         AccountMeta::new(credential, false),
         AccountMeta::new(verifier, false),
-        AccountMeta::new(commitment, false),
         AccountMeta::new(user_token_account, false),
         AccountMeta::new(vault_token_account, false),
-        AccountMeta::new(utxo_key, false),
         AccountMeta::new(depositor, true),
     ];
+    accounts.extend(commitments_accounts);
+    accounts.extend(utxos_accounts);
     accounts.extend(nodes_accounts);
 
-    let data = MazeInstruction::FinalizeDeposit {
-        utxo,
+    let data = MazeInstruction::FinalizeBatchDeposit {
+        utxos,
     }.try_to_vec().map_err(|_| MazeError::InstructionUnpackError)?;
 
     Ok(Instruction {
@@ -242,25 +584,38 @@ pub fn finalize_deposit(
     })
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn create_withdraw_credential(
     vault: Pubkey,
     receiver: Pubkey,
     delegator: Pubkey,
     withdraw_amount: u64,
+    token_mint: Pubkey,
+    fee: u64,
     nullifier_point: EdwardsAffine,
+    leaf_index: u64,
     leaf: BigInteger,
-    updating_nodes: Box<Vec<BigInteger>>,
+    src_root: BigInteger,
     commitment: InnerCommitment,
+    exclusion_root: Option<BigInteger>,
+    allowlist_root: Option<BigInteger>,
 ) -> Result<Instruction, MazeError> {
     let (credential, _) = get_withdraw_credential_pda(&vault, &delegator, &receiver, &ID);
+    let (exclusion_set, _) = get_exclusion_set_pda(&vault, &ID);
+    let (allowlist_registry, _) = get_allowlist_registry_pda(&vault, &ID);
 
     let data = MazeInstruction::CreateWithdrawCredential {
         withdraw_amount,
+        token_mint,
+        fee,
         receiver,
         nullifier_point,
+        leaf_index,
         leaf,
-        updating_nodes,
+        src_root,
         commitment,
+        exclusion_root,
+        allowlist_root,
     }.try_to_vec().map_err(|_| MazeError::InstructionUnpackError)?;
 
     Ok(Instruction {
@@ -268,9 +623,11 @@ pub fn create_withdraw_credential(
         accounts: vec![
             AccountMeta::new_readonly(system_program::ID, false),
             AccountMeta::new_readonly(sysvar::rent::ID, false),
-            AccountMeta::new_readonly(vault, false),
+            AccountMeta::new(vault, false),
             AccountMeta::new(credential, false),
             AccountMeta::new(delegator, true),
+            AccountMeta::new_readonly(exclusion_set, false),
+            AccountMeta::new_readonly(allowlist_registry, false),
         ],
         data,
     })
@@ -284,6 +641,8 @@ pub fn create_withdraw_verifier(
 ) -> Result<Instruction, MazeError> {
     let (credential, _) = get_withdraw_credential_pda(&vault, &delegator, &receiver, &ID);
     let (verifier, _) = get_verifier_pda(&credential, &ID);
+    let (exclusion_set, _) = get_exclusion_set_pda(&vault, &ID);
+    let (allowlist_registry, _) = get_allowlist_registry_pda(&vault, &ID);
 
     let data = MazeInstruction::CreateWithdrawVerifier { proof }
         .try_to_vec()
@@ -298,6 +657,8 @@ pub fn create_withdraw_verifier(
             AccountMeta::new_readonly(credential, false),
             AccountMeta::new(verifier, false),
             AccountMeta::new(delegator, true),
+            AccountMeta::new_readonly(exclusion_set, false),
+            AccountMeta::new_readonly(allowlist_registry, false),
         ],
         data,
     })
@@ -306,6 +667,7 @@ pub fn create_withdraw_verifier(
 pub fn verify_withdraw_proof(vault: Pubkey, delegator: &Pubkey, owner: Pubkey, padding: Vec<u8>) -> Result<Instruction, MazeError> {
     let (credential, _) = get_withdraw_credential_pda(&vault, delegator, &owner, &ID);
     let (verifier, _) = get_verifier_pda(&credential, &ID);
+    let (exclusion_set, _) = get_exclusion_set_pda(&vault, &ID);
 
     let mut data = MazeInstruction::VerifyWithdrawProof.try_to_vec().map_err(|_| MazeError::InstructionUnpackError)?;
     data.extend(padding);
@@ -316,6 +678,7 @@ pub fn verify_withdraw_proof(vault: Pubkey, delegator: &Pubkey, owner: Pubkey, p
             AccountMeta::new_readonly(vault, false),
             AccountMeta::new_readonly(credential, false),
             AccountMeta::new(verifier, false),
+            AccountMeta::new_readonly(exclusion_set, false),
         ],
         data,
     })
@@ -331,7 +694,7 @@ pub fn finalize_withdraw(
     leaf: BigInteger,
     nullifier_point: EdwardsAffine,
     utxo: [u8; 32],
-    balance_cipher: u128,
+    balance_cipher: Vec<u8>,
 ) -> Result<Instruction, MazeError> {
     let (vault_signer, _) = get_vault_authority_pda(&vault, &ID);
     let (credential, _) = get_withdraw_credential_pda(&vault, &delegator, &receiver, &ID);
@@ -342,25 +705,18 @@ pub fn finalize_withdraw(
     let user_token_account = get_associated_token_address(&receiver, &token_mint);
     let delegator_token_account = get_associated_token_address(&delegator, &token_mint);
     let (utxo_key, _) = get_utxo_pda(&utxo, &ID);
+    let (exclusion_set, _) = get_exclusion_set_pda(&vault, &ID);
+    let (allowlist_registry, _) = get_allowlist_registry_pda(&vault, &ID);
 
-    let merkle_path = gen_merkle_path_from_leaf_index(leaf_index);
-    let nodes_accounts = merkle_path.into_iter().map(|(layer, index)| {
-        let (node, _) = get_merkle_node_pda(
-            &vault,
-            layer,
-            index,
-            &ID,
-        );
-        AccountMeta::new(node, false)
-    }).collect::<Vec<_>>();
+    let (node, _) = get_merkle_node_pda(&vault, 0, leaf_index, &ID);
 
-    let mut accounts = vec![
+    let accounts = vec![
         AccountMeta::new_readonly(system_program::ID, false),
         AccountMeta::new_readonly(spl_token::ID, false),
         AccountMeta::new_readonly(spl_associated_token_account::ID, false),
         AccountMeta::new_readonly(sysvar::rent::ID, false),
         AccountMeta::new_readonly(token_mint, false),
-        AccountMeta::new(vault, false),
+        AccountMeta::new_readonly(vault, false),
         AccountMeta::new(credential, false),
         AccountMeta::new(verifier, false),
         AccountMeta::new(nullifier, false),
@@ -372,8 +728,10 @@ pub fn finalize_withdraw(
         AccountMeta::new(receiver, false),
         AccountMeta::new(utxo_key, false),
         AccountMeta::new(delegator, true),
+        AccountMeta::new_readonly(exclusion_set, false),
+        AccountMeta::new_readonly(allowlist_registry, false),
+        AccountMeta::new(node, false),
     ];
-    accounts.extend(nodes_accounts);
 
     let data = MazeInstruction::FinalizeWithdraw {
         utxo,
@@ -387,6 +745,191 @@ pub fn finalize_withdraw(
     })
 }
 
+#[allow(clippy::too_many_arguments)]
+pub fn create_transfer_credential(
+    vault: Pubkey,
+    receiver: Pubkey,
+    owner: Pubkey,
+    withdraw_amount: u64,
+    token_mint: Pubkey,
+    nullifier_points: [EdwardsAffine; 2],
+    src_root: BigInteger,
+    leaf_index: u64,
+    leaves: [BigInteger; 2],
+    commitments: Box<Vec<InnerCommitment>>,
+) -> Result<Instruction, MazeError> {
+    let (credential, _) = get_transfer_credential_pda(&vault, &owner, &ID);
+
+    let data = MazeInstruction::CreateTransferCredential {
+        withdraw_amount,
+        token_mint,
+        receiver,
+        nullifier_points,
+        src_root,
+        leaf_index,
+        leaves,
+        commitments,
+    }.try_to_vec().map_err(|_| MazeError::InstructionUnpackError)?;
+
+    Ok(Instruction {
+        program_id: ID,
+        accounts: vec![
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(sysvar::rent::ID, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new(credential, false),
+            AccountMeta::new(owner, true),
+        ],
+        data,
+    })
+}
+
+pub fn create_transfer_verifier(
+    vault: Pubkey,
+    owner: Pubkey,
+    proof: Box<Proof>,
+) -> Result<Instruction, MazeError> {
+    let (credential, _) = get_transfer_credential_pda(&vault, &owner, &ID);
+    let (verifier, _) = get_verifier_pda(&credential, &ID);
+
+    let data = MazeInstruction::CreateTransferVerifier { proof }
+        .try_to_vec()
+        .map_err(|_| MazeError::InstructionUnpackError)?;
+
+    Ok(Instruction {
+        program_id: ID,
+        accounts: vec![
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(sysvar::rent::ID, false),
+            AccountMeta::new_readonly(vault, false),
+            AccountMeta::new_readonly(credential, false),
+            AccountMeta::new(verifier, false),
+            AccountMeta::new(owner, true),
+        ],
+        data,
+    })
+}
+
+pub fn verify_transfer_proof(vault: Pubkey, owner: Pubkey, padding: Vec<u8>) -> Result<Instruction, MazeError> {
+    let (credential, _) = get_transfer_credential_pda(&vault, &owner, &ID);
+    let (verifier, _) = get_verifier_pda(&credential, &ID);
+
+    let mut data = MazeInstruction::VerifyTransferProof.try_to_vec().map_err(|_| MazeError::InstructionUnpackError)?;
+    data.extend(padding);
+
+    Ok(Instruction {
+        program_id: ID,
+        accounts: vec![
+            AccountMeta::new_readonly(vault, false),
+            AccountMeta::new_readonly(credential, false),
+            AccountMeta::new(verifier, false),
+        ],
+        data,
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn finalize_transfer(
+    vault: Pubkey,
+    token_mint: Pubkey,
+    receiver: Pubkey,
+    owner: Pubkey,
+    leaf_index: u64,
+    leaves: [BigInteger; 2],
+    nullifier_points: [EdwardsAffine; 2],
+    utxos: [[u8; 32]; 2],
+    balance_ciphers: [Vec<u8>; 2],
+    ephemeral_pubkey: Option<EdwardsAffine>,
+) -> Result<Instruction, MazeError> {
+    let (vault_signer, _) = get_vault_authority_pda(&vault, &ID);
+    let (credential, _) = get_transfer_credential_pda(&vault, &owner, &ID);
+    let (verifier, _) = get_verifier_pda(&credential, &ID);
+    let vault_token_account = get_associated_token_address(&vault_signer, &token_mint);
+    let receiver_token_account = get_associated_token_address(&receiver, &token_mint);
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(spl_token::ID, false),
+        AccountMeta::new_readonly(spl_associated_token_account::ID, false),
+        AccountMeta::new_readonly(sysvar::rent::ID, false),
+        AccountMeta::new_readonly(token_mint, false),
+        AccountMeta::new_readonly(vault, false),
+        AccountMeta::new(credential, false),
+        AccountMeta::new(verifier, false),
+    ];
+    accounts.extend(nullifier_points.iter().map(|point| {
+        let (nullifier, _) = get_nullifier_pda(point, &ID);
+        AccountMeta::new(nullifier, false)
+    }));
+    accounts.extend(leaves.iter().map(|leaf| {
+        let (commitment, _) = get_commitment_pda(leaf, &ID);
+        AccountMeta::new(commitment, false)
+    }));
+    accounts.extend([
+        AccountMeta::new(vault_token_account, false),
+        AccountMeta::new(receiver_token_account, false),
+        AccountMeta::new_readonly(vault_signer, false),
+        AccountMeta::new(receiver, false),
+    ]);
+    accounts.extend(utxos.iter().map(|utxo| {
+        let (utxo_key, _) = get_utxo_pda(utxo, &ID);
+        AccountMeta::new(utxo_key, false)
+    }));
+    accounts.push(AccountMeta::new(owner, true));
+    accounts.extend([leaf_index, leaf_index + 1].iter().map(|index| {
+        let (node, _) = get_merkle_node_pda(&vault, 0, *index, &ID);
+        AccountMeta::new(node, false)
+    }));
+
+    let data = MazeInstruction::FinalizeTransfer {
+        utxos,
+        balance_ciphers: Box::new(balance_ciphers.to_vec()),
+        ephemeral_pubkey,
+    }.try_to_vec().map_err(|_| MazeError::InstructionUnpackError)?;
+
+    Ok(Instruction {
+        program_id: ID,
+        accounts,
+        data,
+    })
+}
+
+/// Hashing a node exceeds the default compute budget, request more units in the same transaction.
+pub fn update_merkle_node(
+    vault: Pubkey,
+    payer: Pubkey,
+    layer: u8,
+    index: u64,
+) -> Result<Instruction, MazeError> {
+    let (left, _) = get_merkle_node_pda(&vault, layer - 1, index << 1, &ID);
+    let (right, _) = get_merkle_node_pda(&vault, layer - 1, (index << 1) + 1, &ID);
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(sysvar::rent::ID, false),
+        AccountMeta::new(vault, false),
+        AccountMeta::new_readonly(left, false),
+        AccountMeta::new_readonly(right, false),
+    ];
+    // the node at the top layer is the root stored in the vault
+    if layer as usize != HEIGHT {
+        let (node, _) = get_merkle_node_pda(&vault, layer, index, &ID);
+        accounts.push(AccountMeta::new(node, false));
+        accounts.push(AccountMeta::new(payer, true));
+    }
+
+    let data = MazeInstruction::UpdateMerkleNode {
+        layer,
+        index,
+    }.try_to_vec().map_err(|_| MazeError::InstructionUnpackError)?;
+
+    Ok(Instruction {
+        program_id: ID,
+        accounts,
+        data,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use solana_program::{pubkey::Pubkey, instruction::Instruction, message::v0::Message, system_program};
@@ -516,20 +1059,6 @@ mod tests {
         let signer = Keypair::from_base58_string(USER_KEYPAIR);
         let deposit_amount = 100_000_000;
         let leaf = BigInteger::new([10238628474373320456, 14022387074461718602, 2475330134695199970, 1313074002618417116]);
-        let updating_nodes = vec![
-            BigInteger::new([13964979764574450530, 6814902463828884090, 3490476130675498614, 939454355663535017]),
-            BigInteger::new([12524172229863401954, 4796040823255991742, 4069760990520073033, 868000759613921587]),
-            BigInteger::new([1596125167915367140, 6156920030815160090, 10382505956980516360, 2927580863364086847]),
-            BigInteger::new([1765955334735741199, 1291223536655544861, 16639810887409821899, 880497050557539739]),
-            BigInteger::new([12643707220000567509, 11436727266683695517, 4732916465160176110, 1964838653473584553]),
-            BigInteger::new([15885659284373961508, 5471913312835855211, 2653419779047489694, 1900210723210987730]),
-            BigInteger::new([10596518041243485014, 11924227743320673010, 287941000929346776, 245476564885753827]),
-            BigInteger::new([9798514336536856130, 3632004711427197341, 11339261941566790797, 3119499272359887178]),
-            BigInteger::new([10570135904687397455, 18190639330091595662, 17985847417806757659, 2492070591683873349]),
-            BigInteger::new([13472515854679819921, 6007832876773512197, 945556273399982984, 126431182602079263]),
-            BigInteger::new([8536281558937608028, 12462637082463092570, 4313476410340840383, 2403661115683713579]),
-            BigInteger::new([7973243436672073066, 16221803217086044779, 1982661521783632814, 1972000485319446447]),
-        ];
         let commitment = (
             EdwardsAffine {
                 x: BigInteger::new([4426581770956920, 3780038317459993260, 5978800350633987884, 311273432824146036]),
@@ -563,14 +1092,14 @@ mod tests {
         );
         let proof = Proof { a, b, c };
 
-        // let instruction = create_vault(TOKEN_MINT, signer.pubkey(), 10000000, 10000000, 2000000).unwrap();
+        // let instruction = create_vault(TOKEN_MINT, signer.pubkey(), 10000000, 10000000).unwrap();
 
         // let instruction = create_deposit_credential(
         //     VAULT,
         //     signer.pubkey(),
         //     deposit_amount,
+        //     0,
         //     leaf,
-        //     Box::new(updating_nodes),
         //     commitment,
         // ).unwrap();
 
@@ -615,7 +1144,7 @@ mod tests {
             leaf,
             commitment.0,
             [1u8; 32],
-            100_000_000,
+            vec![1u8; 64],
         ).unwrap();
 
         let blockhash = client.get_latest_blockhash().unwrap();
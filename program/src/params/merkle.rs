@@ -0,0 +1,167 @@
+use crate::{params::bn::Fr, bn::BigInteger256 as BigInteger};
+
+// generated by `soda-maze-gen-params gen-merkle-hasher` with height 12
+pub const WIDTH: usize = 3;
+pub const FULL_ROUNDS: usize = 8;
+pub const PARTIAL_ROUNDS: usize = 33;
+pub const ALPHA: u64 = 17;
+
+pub const ROUND_KEYS: &[Fr] = &[
+    Fr::new(BigInteger::new([3071082444983934733, 537070958703240090, 14614196838951728462, 2890766902725539464])),
+    Fr::new(BigInteger::new([15574130690518863535, 8004003901533705910, 4476396729836952406, 2691036887561687732])),
+    Fr::new(BigInteger::new([10848817212390143827, 8609569482147588435, 14117725275926327702, 3412179355754128305])),
+    Fr::new(BigInteger::new([17702908178812655197, 18355583863780059239, 14402841913906050966, 2015448929538191993])),
+    Fr::new(BigInteger::new([3727527292619672646, 5751744014674560273, 1066669092954058858, 2394251230983802727])),
+    Fr::new(BigInteger::new([2120653216974530672, 12235332370404437365, 10001652319794161593, 1797714487039265704])),
+    Fr::new(BigInteger::new([12551630365944361111, 16320105572601806329, 14101099226960243942, 409128156452626350])),
+    Fr::new(BigInteger::new([7250389259098035164, 6696966635736811114, 111618092303370125, 2422897204529908370])),
+    Fr::new(BigInteger::new([15095805090765059874, 16282894063515966969, 3194688654924429765, 3011405621556107856])),
+    Fr::new(BigInteger::new([13910685734905079766, 12036144824669700525, 11049979338155728428, 2548689412781627964])),
+    Fr::new(BigInteger::new([17473594486824332334, 17017564610638256939, 17966839946250111694, 1281738744766719036])),
+    Fr::new(BigInteger::new([11327925559804819835, 14171255143135263010, 929468518074285183, 2553008473701975056])),
+    Fr::new(BigInteger::new([8030770247200225869, 1859323180173269803, 11063869315329921915, 3313195654706568088])),
+    Fr::new(BigInteger::new([11522776244236831096, 13629362558011038916, 14559736668510708409, 3325999512366837383])),
+    Fr::new(BigInteger::new([11659092287359853113, 4743998067082218207, 14259168122175530072, 896501834379912633])),
+    Fr::new(BigInteger::new([14798395328735856992, 122532072312497860, 4730752004049759301, 163186797334170331])),
+    Fr::new(BigInteger::new([13286647157107140900, 2170758505088396472, 4901747887539747865, 1522623978786576906])),
+    Fr::new(BigInteger::new([8644016855439083740, 6780675809784864695, 4861900103180441565, 2229629289468162731])),
+    Fr::new(BigInteger::new([15973399468894020579, 15724319517067173069, 6507948406103051626, 3157365306199705385])),
+    Fr::new(BigInteger::new([1345967956289492837, 3661538328612251693, 12495422511153556658, 1973073796021968590])),
+    Fr::new(BigInteger::new([4932555719930969782, 4109218581378516579, 14357478869087832180, 1951722045452325074])),
+    Fr::new(BigInteger::new([6158916854900795143, 14837771014420657281, 7206068881395142816, 2777509616043952399])),
+    Fr::new(BigInteger::new([16786456626929169831, 1348442237907170330, 18348804248225119777, 602679396532959797])),
+    Fr::new(BigInteger::new([14220802742967618418, 14173765986596205621, 8095393829175475445, 2697590536137013260])),
+    Fr::new(BigInteger::new([10826845585706045416, 11904242395077993660, 16434676378260357498, 1408866786009842483])),
+    Fr::new(BigInteger::new([14315846592301277869, 17321332548500120747, 9659088441285714712, 2357384303064451496])),
+    Fr::new(BigInteger::new([7706847498995976025, 4339479656135235227, 3566417152948201980, 3244858428701598961])),
+    Fr::new(BigInteger::new([3595004681673000845, 6476672829862644456, 13008585136518216971, 603143198572744096])),
+    Fr::new(BigInteger::new([6724520615836271106, 7947151758069077387, 13301941888386001436, 1454458172170804674])),
+    Fr::new(BigInteger::new([6544902506226043106, 2424057974701091382, 9805757104833541973, 2932198584110701143])),
+    Fr::new(BigInteger::new([2068121027439549234, 7078607749356430814, 2183727816483549835, 3162097649955664392])),
+    Fr::new(BigInteger::new([7063637477048082669, 4482045741106895932, 10224651110160309996, 821376230671457494])),
+    Fr::new(BigInteger::new([7760975025228861121, 5133351956624387755, 15792188590851446531, 1478955714240440656])),
+    Fr::new(BigInteger::new([16197568445262145536, 13858338449186057093, 11735310743105863859, 1257280293418305723])),
+    Fr::new(BigInteger::new([5641547832966717907, 14557009519432437554, 9898416732529599859, 1927177421697153438])),
+    Fr::new(BigInteger::new([17081170272024878594, 8188080015428016495, 8629679824774426386, 1720335842461879083])),
+    Fr::new(BigInteger::new([4684540905576431563, 10074074484194705650, 13460975634442830533, 477022920224636680])),
+    Fr::new(BigInteger::new([3180844686421394396, 12085254264607365957, 5937981226507287255, 715877201151187403])),
+    Fr::new(BigInteger::new([9532585696346810278, 6779662718387816174, 17780547633212119254, 1207575237002694434])),
+    Fr::new(BigInteger::new([2047281969358049155, 7580330807420309542, 15007024189728168558, 1625684371761980772])),
+    Fr::new(BigInteger::new([8694891781076050761, 15616943188936495840, 12567682976090272373, 90663297014287312])),
+    Fr::new(BigInteger::new([8740046453759993914, 13399721940650313589, 6036690923346936092, 2025788126017586331])),
+    Fr::new(BigInteger::new([18336933172554413080, 10462245777112239248, 488314549160535520, 3228198750934106043])),
+    Fr::new(BigInteger::new([14066174979547573489, 15378927255590434145, 13312074547701012767, 2119934000204400319])),
+    Fr::new(BigInteger::new([11663254426079774258, 12705672101568905732, 2141030646967079738, 964106417273018817])),
+    Fr::new(BigInteger::new([14600823867131808915, 164027710015427908, 1131949508444268862, 474700570416993122])),
+    Fr::new(BigInteger::new([5454965499196696197, 2318135849610157477, 4834529650430546526, 1600493944758421484])),
+    Fr::new(BigInteger::new([393279766966628984, 8703549507610303546, 3455296951469844633, 2848428136669459872])),
+    Fr::new(BigInteger::new([8650155510186382269, 11553972588108294083, 4524073869100882181, 1327966918763175349])),
+    Fr::new(BigInteger::new([1149642361397519627, 17606171509907624764, 18136245918452996167, 3059364207421775022])),
+    Fr::new(BigInteger::new([16971146211200214268, 10263104530613607017, 13861386861281864527, 409678197056635562])),
+    Fr::new(BigInteger::new([14518990969553585194, 15340678810531056469, 12149070931080187559, 994877897129304361])),
+    Fr::new(BigInteger::new([6460275172041546325, 9024136905163828064, 10900268931359622082, 3018945313455108759])),
+    Fr::new(BigInteger::new([17092730794929426052, 6690108079744197758, 9631504495588911003, 2463881570868302289])),
+    Fr::new(BigInteger::new([2030394421917111035, 12005361729891683348, 9845922101596253975, 2623198512124430244])),
+    Fr::new(BigInteger::new([13909483065550033519, 2747317081988872847, 8486810803114909181, 2206538532796220522])),
+    Fr::new(BigInteger::new([13838602413168530334, 13105090190955994702, 16904028459270804751, 2363791931972309652])),
+    Fr::new(BigInteger::new([15755565468239464742, 1670859861685940239, 3054511330643233364, 3432615457792395559])),
+    Fr::new(BigInteger::new([8597972626825718189, 14757250130543988447, 4969130592474186581, 2111599097798416723])),
+    Fr::new(BigInteger::new([4326464580691273727, 6708324672066935211, 15756372617279203132, 2773839662729048480])),
+    Fr::new(BigInteger::new([7950634186143126768, 11223573534131861068, 1577191028892459108, 1601627029490409002])),
+    Fr::new(BigInteger::new([17365828745518673804, 710817978071884299, 15971727157954450955, 3276035692495446575])),
+    Fr::new(BigInteger::new([16905057427043770614, 3663681261141838340, 372393010416594563, 2233466729893452961])),
+    Fr::new(BigInteger::new([1664168538835536724, 15574640616450092261, 2247043750282675169, 3407523442365620819])),
+    Fr::new(BigInteger::new([13155371857690728641, 9190718339571516193, 5959572852752559163, 3137450013767084729])),
+    Fr::new(BigInteger::new([16926720472115278290, 17014023313040700561, 5809424284964832861, 1966120690352225236])),
+    Fr::new(BigInteger::new([363993343758538420, 15012649229304101172, 15005147389424665358, 936287785683821208])),
+    Fr::new(BigInteger::new([8546474597654612689, 18427197256042348229, 6289486570851614862, 2082676665875447072])),
+    Fr::new(BigInteger::new([12306301924743973880, 12476981303208127939, 11447068642783980678, 1596400745817562750])),
+    Fr::new(BigInteger::new([14308251684523260883, 6721530883669472323, 10248354213551035788, 2926184185170060737])),
+    Fr::new(BigInteger::new([9097740634073634921, 13025826555355281654, 4138096505840256490, 3178745991061866441])),
+    Fr::new(BigInteger::new([11188166645845618138, 15616999882060599536, 11621289979449551824, 1479927541618601733])),
+    Fr::new(BigInteger::new([17167596934994207511, 10649448176205810523, 13326567336444735603, 898296817909285782])),
+    Fr::new(BigInteger::new([15245044136769331675, 10283443910921332586, 8312543839148281766, 468137636290102308])),
+    Fr::new(BigInteger::new([15653910894895807145, 14071722478828440724, 18289586808173472030, 2426016682160502582])),
+    Fr::new(BigInteger::new([14964848201112569056, 13967802387023233924, 8886757108682566495, 3441313966764559297])),
+    Fr::new(BigInteger::new([16127809617657907624, 12780842445651709505, 3367988406319797969, 1808864324440248660])),
+    Fr::new(BigInteger::new([10988647789265339637, 2323289902941312792, 15806099935402099119, 582227250028879614])),
+    Fr::new(BigInteger::new([18029426177551075403, 15004963352847507148, 4283119772128976579, 1560660748828979676])),
+    Fr::new(BigInteger::new([14377837641686210495, 13370037434339490937, 15826387291503991902, 992621027530954105])),
+    Fr::new(BigInteger::new([9863406882378460467, 14037174801705695978, 18077447833545665059, 918291459817434627])),
+    Fr::new(BigInteger::new([4536763040519058776, 4977436247288194914, 10296386937900363096, 624270956030524297])),
+    Fr::new(BigInteger::new([17847412718686562706, 14800637247664816154, 9708904776396511503, 1893650896581213539])),
+    Fr::new(BigInteger::new([3641383025275966847, 15964244547254666045, 2726444136124595520, 1611443734416120114])),
+    Fr::new(BigInteger::new([2487491062676650065, 14484907354145998150, 2101769888085059592, 1396833727249778623])),
+    Fr::new(BigInteger::new([15774612608778001028, 1331590677224942817, 15909891385436432987, 1444996702094405785])),
+    Fr::new(BigInteger::new([10104497174581824087, 13289935370216931303, 8510571847891833251, 1453069120625459062])),
+    Fr::new(BigInteger::new([9002772183381161652, 8656796352766336214, 5371353125335591335, 2437427982159784509])),
+    Fr::new(BigInteger::new([16514960546905736140, 1684914590497690206, 14090365351840716012, 2852668512401413237])),
+    Fr::new(BigInteger::new([17186212682581658411, 16546137091694026667, 16672039668786283454, 1677898024734426339])),
+    Fr::new(BigInteger::new([341124643965553069, 10555873386691593590, 7956863797491227137, 493293544218993470])),
+    Fr::new(BigInteger::new([14666595446655276661, 17941668219490602936, 10571923380430329311, 3037988182317902407])),
+    Fr::new(BigInteger::new([1598643829121893509, 2102422488351254716, 6399054154959524780, 645338277503674145])),
+    Fr::new(BigInteger::new([283409033028486977, 15744734834610963888, 210809210902330476, 2630156924046514255])),
+    Fr::new(BigInteger::new([15972318145135534513, 5435432362144671969, 5424646484597769636, 1225912924841340923])),
+    Fr::new(BigInteger::new([6286289183379542488, 16843496486649944610, 5817680445593536266, 2336974519718284518])),
+    Fr::new(BigInteger::new([932455642075755040, 3641715201585195219, 3780524895248302131, 1779225412841002304])),
+    Fr::new(BigInteger::new([3625006169684631891, 8684696786230962324, 13316442954983888891, 1886566976990333426])),
+    Fr::new(BigInteger::new([11463736413725097552, 16780193815640206701, 8943101912791099818, 1115074093867208715])),
+    Fr::new(BigInteger::new([14179510747652700866, 8359028067777879866, 10117435010379350486, 3221960029835985671])),
+    Fr::new(BigInteger::new([7013809867409744542, 16060771681051697828, 2421472498739243592, 1211339489551680739])),
+    Fr::new(BigInteger::new([6174582939921962362, 2579163929210648915, 3360273912602087543, 1226073282861953556])),
+    Fr::new(BigInteger::new([18179492619557932489, 8841023102046795539, 10826382930478012663, 3045378250868381661])),
+    Fr::new(BigInteger::new([7527348316079765565, 2334645224889929837, 15155731841849090038, 3049462118678171897])),
+    Fr::new(BigInteger::new([9703302392765002206, 8824071094129297196, 11253977837490920598, 2181651855040084716])),
+    Fr::new(BigInteger::new([6979427345648456628, 9243246964448818922, 439061809525849352, 847141089972474354])),
+    Fr::new(BigInteger::new([2023835219783437804, 8437175082273630309, 18113350981379126149, 1406894593741852154])),
+    Fr::new(BigInteger::new([2702565676068247845, 17230357225765876924, 9272128989556696265, 2673170950842007387])),
+    Fr::new(BigInteger::new([2075221424094800690, 17270246156094133092, 9876654741166282594, 2052172326733804233])),
+    Fr::new(BigInteger::new([4413768302196633284, 6627276699718117149, 4048526575466534311, 1089932231471843027])),
+    Fr::new(BigInteger::new([3620412591805636149, 4637197773368572787, 1061012917955976177, 1281128365550147558])),
+    Fr::new(BigInteger::new([207200833509670437, 16196429315504329124, 4824415846780346938, 2232512519835523659])),
+    Fr::new(BigInteger::new([7643165442214264334, 3523252636680449995, 663818618139659614, 3067446858028606657])),
+    Fr::new(BigInteger::new([9668547171012471579, 4919755367428550828, 4638035152901025129, 1632359721497634763])),
+    Fr::new(BigInteger::new([9030475167938278192, 16905996340243013152, 5165063336170866096, 1501453619375560818])),
+    Fr::new(BigInteger::new([15444675210889743344, 17983334482304120490, 3471462463009993154, 1986361140812413633])),
+    Fr::new(BigInteger::new([15106473859945238163, 8420027241700749048, 6862352616944931453, 2664296527853790633])),
+    Fr::new(BigInteger::new([14480597453085483307, 6834807831422217600, 17117630996646129665, 3298819650254440573])),
+    Fr::new(BigInteger::new([7546972598174682140, 15443740713623509561, 12250649896245533178, 2749335199169612335])),
+    Fr::new(BigInteger::new([13594601347192056703, 17375218470002715331, 12287262673969105709, 369263277151390565])),
+    Fr::new(BigInteger::new([9735025708296947636, 2002280214181043543, 8660090307844962831, 2105616039056492986])),
+    Fr::new(BigInteger::new([11338837357134728654, 5353372103232295095, 598895547513159581, 1234415658498647389])),
+    Fr::new(BigInteger::new([8079056009799470304, 13864182843764163779, 7929192757895790011, 2594365713955798781])),
+];
+
+pub const MDS_MATRIX: &[[Fr; WIDTH]] = &[
+    [
+        Fr::new(BigInteger::new([3646162796728384100, 6146676345822133883, 14073254011710720331, 3278838502800088855])),
+        Fr::new(BigInteger::new([7310021299771840102, 17916195838905994216, 5368808490731049385, 82500693633047191])),
+        Fr::new(BigInteger::new([3555984715968406056, 1055652105071650118, 15664763820897693141, 728042011252546441])),
+    ],
+    [
+        Fr::new(BigInteger::new([14558611718715760051, 13141519064658451245, 13998155368757032277, 1190325396805548881])),
+        Fr::new(BigInteger::new([6966488266193601989, 17773168922275648792, 17314011382857617331, 747305941182167628])),
+        Fr::new(BigInteger::new([11419794658884990523, 5332043832746053174, 14693811951958482181, 2842381854519159630])),
+    ],
+    [
+        Fr::new(BigInteger::new([16869328664174211186, 14885174605438506051, 1412609967409729011, 2854298553923648955])),
+        Fr::new(BigInteger::new([4695537688960456741, 11419805192224425683, 13183000868734201553, 3048301568893057411])),
+        Fr::new(BigInteger::new([5583577429468306557, 3868034880467136058, 15847069379792524549, 50280928077494956])),
+    ],
+];
+
+/// hashes of empty nodes from the leaves up to the layer below the root
+pub const DEFAULT_NODE_HASHES: &[BigInteger] = &[
+    BigInteger::new([0, 0, 0, 0]),
+    BigInteger::new([4098389181189007193, 6033357606331541017, 2924962632647858051, 2002457357787638259]),
+    BigInteger::new([1958344761141809874, 4383116016779029464, 4126739728009296708, 2056675836852212919]),
+    BigInteger::new([15809647379975545815, 2038363012389572273, 17666162383505859689, 7863755923239522]),
+    BigInteger::new([3213349777060254387, 10099815269893495540, 14961331054896432626, 668468404232674026]),
+    BigInteger::new([17619248550632435119, 1210085825830696634, 12496731760392854301, 2563858422937012691]),
+    BigInteger::new([14142002917966538117, 897063572087146458, 12811980802238888678, 1752539258614329102]),
+    BigInteger::new([1782200951255987685, 15541832790949781695, 11740665814296219941, 1130734482437556954]),
+    BigInteger::new([5603292307488545391, 9502573327678029389, 17472581903754551923, 3484623345192578706]),
+    BigInteger::new([8107204126964072261, 6241837163458027446, 12066702220578791236, 574289560539867220]),
+    BigInteger::new([16117325039383252453, 5201656278715637160, 692005675377062857, 3166258324951227282]),
+    BigInteger::new([12589723082500285351, 12441951445247487899, 7936229932115288967, 1343339888760578314]),
+];
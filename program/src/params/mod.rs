@@ -1,5 +1,27 @@
 pub mod bn;
+pub mod merkle;
 pub mod root;
 pub mod verify;
 
-pub const HEIGHT: usize = 12;
\ No newline at end of file
+pub const HEIGHT: usize = 12;
+
+/// depth of the subtree filled by a batch deposit
+pub const BATCH_DEPOSIT_DEPTH: usize = 3;
+
+/// number of leaves deposited in a batch
+pub const BATCH_DEPOSIT_SIZE: usize = 1 << BATCH_DEPOSIT_DEPTH;
+
+/// number of recent roots a withdraw can prove its source leaf against
+pub const ROOT_HISTORY_SIZE: usize = 30;
+
+/// maximum number of mints a multi-asset vault custodies
+pub const MAX_VAULT_ASSETS: usize = 8;
+
+/// number of independent auditor keys every nullifier is committed to
+pub const AUDITORS: usize = 1;
+
+/// maximum number of allowlist roots a vault accepts at once
+pub const MAX_ALLOWLIST_ROOTS: usize = 8;
+
+/// maximum length of a note ciphertext stored in a UTXO account
+pub const MAX_NOTE_CIPHER_SIZE: usize = 256;
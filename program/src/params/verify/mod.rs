@@ -1,5 +1,10 @@
 pub mod pvk_deposit;
 pub mod pvk_withdraw;
+pub mod pvk_transfer;
+pub mod pvk_batch_deposit;
+pub mod pvk_withdraw_exclusion;
+pub mod pvk_withdraw_allowlist;
+pub mod pvk_withdraw_exclusion_allowlist;
 
 use borsh::{BorshSerialize, BorshDeserialize};
 
@@ -17,10 +22,18 @@ pub struct PreparedVerifyingKey<'a> {
     pub delta_g2_neg_pc: &'a G2Prepared254<'a>,
 }
 
-#[derive(Clone, BorshSerialize, BorshDeserialize)]
+#[derive(Clone, Debug, BorshSerialize, BorshDeserialize)]
 pub enum ProofType {
     Deposit,
     Withdraw,
+    Transfer,
+    BatchDeposit,
+    /// withdraw proving its src leaf index is not in the exclusion set
+    WithdrawExclusion,
+    /// withdraw proving its owner key is in an accepted allowlist
+    WithdrawAllowlist,
+    /// withdraw proving both of the above
+    WithdrawExclusionAllowlist,
 }
 
 impl ProofType {
@@ -40,7 +53,42 @@ impl ProofType {
                 alpha_g1_beta_g2: pvk_withdraw::ALPHA_G1_BETA_G2,
                 gamma_g2_neg_pc: pvk_withdraw::GAMMA_G2_NEG_PC,
                 delta_g2_neg_pc: pvk_withdraw::DELTA_G2_NEG_PC,
-            }
+            },
+            ProofType::Transfer => &PreparedVerifyingKey {
+                g_ic_init: pvk_transfer::G_IC_INIT,
+                gamma_abc_g1: pvk_transfer::GAMMA_ABC_G1,
+                alpha_g1_beta_g2: pvk_transfer::ALPHA_G1_BETA_G2,
+                gamma_g2_neg_pc: pvk_transfer::GAMMA_G2_NEG_PC,
+                delta_g2_neg_pc: pvk_transfer::DELTA_G2_NEG_PC,
+            },
+            ProofType::BatchDeposit => &PreparedVerifyingKey {
+                g_ic_init: pvk_batch_deposit::G_IC_INIT,
+                gamma_abc_g1: pvk_batch_deposit::GAMMA_ABC_G1,
+                alpha_g1_beta_g2: pvk_batch_deposit::ALPHA_G1_BETA_G2,
+                gamma_g2_neg_pc: pvk_batch_deposit::GAMMA_G2_NEG_PC,
+                delta_g2_neg_pc: pvk_batch_deposit::DELTA_G2_NEG_PC,
+            },
+            ProofType::WithdrawExclusion => &PreparedVerifyingKey {
+                g_ic_init: pvk_withdraw_exclusion::G_IC_INIT,
+                gamma_abc_g1: pvk_withdraw_exclusion::GAMMA_ABC_G1,
+                alpha_g1_beta_g2: pvk_withdraw_exclusion::ALPHA_G1_BETA_G2,
+                gamma_g2_neg_pc: pvk_withdraw_exclusion::GAMMA_G2_NEG_PC,
+                delta_g2_neg_pc: pvk_withdraw_exclusion::DELTA_G2_NEG_PC,
+            },
+            ProofType::WithdrawAllowlist => &PreparedVerifyingKey {
+                g_ic_init: pvk_withdraw_allowlist::G_IC_INIT,
+                gamma_abc_g1: pvk_withdraw_allowlist::GAMMA_ABC_G1,
+                alpha_g1_beta_g2: pvk_withdraw_allowlist::ALPHA_G1_BETA_G2,
+                gamma_g2_neg_pc: pvk_withdraw_allowlist::GAMMA_G2_NEG_PC,
+                delta_g2_neg_pc: pvk_withdraw_allowlist::DELTA_G2_NEG_PC,
+            },
+            ProofType::WithdrawExclusionAllowlist => &PreparedVerifyingKey {
+                g_ic_init: pvk_withdraw_exclusion_allowlist::G_IC_INIT,
+                gamma_abc_g1: pvk_withdraw_exclusion_allowlist::GAMMA_ABC_G1,
+                alpha_g1_beta_g2: pvk_withdraw_exclusion_allowlist::ALPHA_G1_BETA_G2,
+                gamma_g2_neg_pc: pvk_withdraw_exclusion_allowlist::GAMMA_G2_NEG_PC,
+                delta_g2_neg_pc: pvk_withdraw_exclusion_allowlist::DELTA_G2_NEG_PC,
+            },
         }
     }
 }
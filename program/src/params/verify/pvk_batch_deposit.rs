@@ -0,0 +1,2896 @@
+use crate::{params::bn::{G1Projective254, G1Affine254, G2Prepared254, Fq, Fq2, Fqk254, Fq6}, bn::BigInteger256 as BigInteger};
+
+pub const G_IC_INIT: &G1Projective254 = &G1Projective254::new_const(
+    Fq::new(BigInteger::new([17743905130317277065, 5414884484252493954, 2708851181113293811, 53918405610082179])),
+    Fq::new(BigInteger::new([5152192918093899421, 10606371103918753761, 18386611953566362572, 444730317896889086])),
+    Fq::new(BigInteger::new([15230403791020821917, 754611498739239741, 7381016538464732716, 1011752739694698287])),
+);
+
+pub const GAMMA_ABC_G1: &[G1Affine254] = &[
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([16778319591000414437, 5105952047387663839, 7052049900369475571, 310366362503788861])),
+        Fq::new(BigInteger::new([5702516352185773368, 15286796456916691788, 16284287323668105020, 1278379294607925364])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([13029278179862971243, 7190599138396509920, 10718080984826757837, 310162414876311565])),
+        Fq::new(BigInteger::new([16557557197100331627, 6252328917729694378, 14876102994232811256, 1079379161229463306])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([12717762148417694109, 12202902392480902570, 10160701497379404134, 1783692081924641251])),
+        Fq::new(BigInteger::new([17461460358572363599, 13890533535530353462, 9278169475923501566, 738753600289172242])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([6036729195954067542, 7373084537817969843, 15945031746406435036, 1146891750069786091])),
+        Fq::new(BigInteger::new([3214159223513959999, 6519321172670326340, 15814962996281567510, 2423111707047996894])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([7511016817346760352, 5806950081537550616, 11100293041163162164, 244191646662711125])),
+        Fq::new(BigInteger::new([7933982438325509221, 8356902954647815746, 16763258128555387241, 3288515579857386671])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([625889304706801739, 642203543261631917, 9586515889855677611, 3189237924753035865])),
+        Fq::new(BigInteger::new([5644793879378366282, 455992140908166217, 3530757518219070342, 3198757053164673735])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([9675032465126225366, 9876860817024727041, 10158689096352709400, 3069178212206151582])),
+        Fq::new(BigInteger::new([15022366965321770698, 5455131792808034909, 12366931392086356038, 1395297526929188330])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([18387088206734790913, 4360299121907742120, 1816060282284660266, 3114749312096003330])),
+        Fq::new(BigInteger::new([16040664439657146470, 18094403044842283689, 4825819405347235900, 446925972122980218])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([10013237230115487201, 3391098297404092680, 17605692824772571891, 613529192107980166])),
+        Fq::new(BigInteger::new([12446384000153647593, 13171227390162091261, 17395702629560501366, 2833386790605268504])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([2025816347727716258, 1468948090766952517, 10258357441967784312, 2633486091604223161])),
+        Fq::new(BigInteger::new([13595666797602721864, 14188989018788672034, 2895551559830439971, 2266187727927184316])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([10959940765579528663, 5339326840147832824, 2124601211637577097, 1318746041702205222])),
+        Fq::new(BigInteger::new([18136808324386499213, 5886776205864824537, 16298679117538652305, 489173793889161784])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([15699702742581176193, 12771578031405321301, 1219672701759284923, 2426682291172185304])),
+        Fq::new(BigInteger::new([12725193927949823543, 11487858794513262941, 1691576639128275347, 1647349913043125119])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([10470571688315309337, 6371738648802881503, 13659049410804950087, 436597866581419416])),
+        Fq::new(BigInteger::new([16768228206589330286, 2135370334440102346, 16915593279596590543, 1299294570022391876])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([13411320149385168547, 4292525803720155403, 4125500259079923637, 1379158321508992538])),
+        Fq::new(BigInteger::new([16833728761804634914, 7620385508698938438, 10156217934687919111, 3275364307023244855])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([16877258265539457842, 16631385620735708934, 12557772697415078950, 2193658850914222717])),
+        Fq::new(BigInteger::new([18391812511507481745, 3697083291891641682, 14494833371680821271, 3309271935563234406])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([9054583944753386709, 16031017187824207618, 15444427146011660179, 1469089886167147980])),
+        Fq::new(BigInteger::new([1257174671353968051, 8482591773884560694, 65252369078153686, 2115059919481267494])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([5033844580830966637, 327590289780362085, 12779762809406309302, 566256694775845192])),
+        Fq::new(BigInteger::new([15383195119649996886, 6215035785719833990, 14498575617180255601, 3223537202716979632])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([9885359950072348882, 13661632582148780522, 10747459995666458365, 1250115547209350285])),
+        Fq::new(BigInteger::new([6795048223343328119, 5592443795109469096, 5667087828530110201, 1157974950984091139])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([1549615969489350351, 4254587145684263897, 17949385114082893762, 1182224693645239149])),
+        Fq::new(BigInteger::new([7419806865212005513, 13861932157001334265, 9220609870624859574, 3468635339024092714])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([6474107484960611483, 15617397877173215933, 8830839267517497340, 1460345143379341691])),
+        Fq::new(BigInteger::new([3813173371105005468, 8931281030255158432, 12890838587766796690, 137833292998840163])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([5697070113460738647, 9820487121139947253, 14662988027568396137, 1153609292562575451])),
+        Fq::new(BigInteger::new([10692296817827880526, 16951034116126283137, 231655247367519158, 150505870505725114])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([10451746830777782589, 15667361739470667802, 11897051433566678696, 2766656182410784499])),
+        Fq::new(BigInteger::new([16072302417140259337, 11191680871662755355, 16805867326072716102, 2445356494288376243])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([309468640931635242, 17327423131207637736, 13102663036497371704, 3415919896025437874])),
+        Fq::new(BigInteger::new([4792369043742192750, 96677246136780243, 16724568430180915393, 2662997793529318871])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([1587030931880663121, 10062720950212147674, 7837283533567832690, 2628635146023139207])),
+        Fq::new(BigInteger::new([9112740927887688608, 2107325014518982735, 9564610427559336995, 223655270642261863])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([8563725115800922475, 14472573105934903518, 15967193436209281981, 2405284627850906931])),
+        Fq::new(BigInteger::new([15405856261380068016, 18366681175725449245, 11585782538302103876, 368872180063205017])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([5360757385086155023, 58537000284272387, 1027029847014222343, 3041567475448602532])),
+        Fq::new(BigInteger::new([12084701297834326472, 2863499133296424555, 8865565822050610858, 2826573452330167277])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([15025291935432431675, 12222197443295837067, 8122541501658630693, 2046701083075184558])),
+        Fq::new(BigInteger::new([18266653486549212920, 2373828265255041564, 7871060925717246741, 1181228455191905111])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([697032814575829737, 8770852835112174238, 9273340553432769800, 2500082635704679357])),
+        Fq::new(BigInteger::new([5287974824732689570, 1900869033366410203, 6385563549818778215, 2704695127044261460])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([14822170499632003808, 10285541682490779785, 1066711658283953673, 920806264701796193])),
+        Fq::new(BigInteger::new([8703298905469610623, 12029416535415377794, 9533463603029654814, 3137715880951243272])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([377509959906112681, 4172824830554163882, 5094316034959483192, 700416235078696828])),
+        Fq::new(BigInteger::new([7951981980904567732, 17407255542496713886, 1626753398627391102, 186599348863411584])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([4655102323246992100, 10916060891368411429, 16935917605786882294, 654506772850923073])),
+        Fq::new(BigInteger::new([10086493669267647736, 17937343385285489335, 12656671077309923760, 2158908964485394527])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([9136774240626910112, 8574170911219545309, 6541838813592677627, 2069488240652518212])),
+        Fq::new(BigInteger::new([14745155400507480548, 934407550028975375, 9578787185315259023, 368238796995012764])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([34997291396611781, 2456631732501979652, 5085804136098219657, 48057980233745282])),
+        Fq::new(BigInteger::new([6184197512217054411, 5993139415576955944, 15893784044606246543, 354628331933794516])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([464448117340811960, 7339782683869821616, 14800802878538339802, 1409055993092403416])),
+        Fq::new(BigInteger::new([6368485524047279026, 5658129411095642823, 9986408231460165783, 3338185213860983690])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([10434785739443133425, 11444795357994954287, 8783275676485189111, 40825218604708164])),
+        Fq::new(BigInteger::new([2428472845415642704, 11188489875799884628, 312047514359106340, 1729885275621116555])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([13540512943223253347, 5414398281357155847, 13882461543800029767, 2240737593807522921])),
+        Fq::new(BigInteger::new([7957705048536946210, 6792399880650939472, 15786465917828168881, 1990390647993466427])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([16232824115047962669, 3813338995358453578, 17264659042247716633, 2942007772470701385])),
+        Fq::new(BigInteger::new([2818182282833839668, 7207597877026590352, 17014416747224446870, 598313714955444739])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([2295693095162887854, 17540776826466254363, 4521410630600187766, 1342268959811119034])),
+        Fq::new(BigInteger::new([2267818482513889426, 14840273346286657462, 8451365957222133087, 706112543254981567])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([10139653831908097495, 12624466549653344687, 182967755094845062, 1902931637568345754])),
+        Fq::new(BigInteger::new([1984647824621823114, 2199320851714709266, 16349769706067525063, 206674215202287437])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([1279711626862029677, 17207055993940837696, 15092686302228482451, 3393181685280292460])),
+        Fq::new(BigInteger::new([4558620218309839911, 1547898736104510930, 14670689143753694832, 1124691075819415386])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([14207179043344857513, 5825022599551434674, 16717777567975987788, 1026924575659175932])),
+        Fq::new(BigInteger::new([5342406390850368501, 3528895688438728269, 17860905132330090715, 2744817381419375407])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([15643365479139983257, 257275577231999576, 13619056954699299033, 272083885577509400])),
+        Fq::new(BigInteger::new([17088004061335075719, 14766243476973344824, 9958905627474177493, 1833215343100399086])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([13358208524308022234, 5421644895455836777, 10954390247878322434, 26479800462139329])),
+        Fq::new(BigInteger::new([14595377228121265127, 7994766137530960570, 13669441181849019386, 3473417745248546359])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([426777396461446341, 658077848682282869, 5684706704422187905, 1594665035077914432])),
+        Fq::new(BigInteger::new([1513516593508763527, 16113113304999269080, 16007864439454796040, 83470950720535342])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([2178984501511436898, 18041606463597106493, 2776103968745751197, 105613539977951287])),
+        Fq::new(BigInteger::new([10781694010753737896, 4118820176060415533, 14250695406771131528, 1990459799595388153])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([4855233069057062737, 12984318317998982250, 4298393916115878294, 1331271506987274076])),
+        Fq::new(BigInteger::new([13414917527761075193, 5460818058982790614, 1781408678517524250, 3255918998346849228])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([3803570508211888682, 10272745487737174120, 2395836718276950318, 1066483609755193253])),
+        Fq::new(BigInteger::new([5348129539298770102, 5905104821264690987, 9195506791573116447, 1653149224178143151])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([830232777425528883, 406327258016504661, 9746967033253889025, 3435578699071338607])),
+        Fq::new(BigInteger::new([8927716235844472478, 8334211254113242104, 11729178759976024205, 1268362721271583559])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([17761975752718005261, 7338107539933523656, 11359669844780822317, 1547629374509455981])),
+        Fq::new(BigInteger::new([2033149265907259374, 14321069173160023816, 16066525141518690039, 660713971612332575])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([2185875476354323505, 10971141473204579527, 3340349354987898999, 686527525393199684])),
+        Fq::new(BigInteger::new([18035054761942381110, 6160464896696569191, 1257810646988574437, 3357356297856472658])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([245737019148873536, 15296631976684970249, 3809778758585057105, 1518550803511646206])),
+        Fq::new(BigInteger::new([7669804219426651721, 14334278367594643204, 6355977606235768182, 1951176290305358267])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([17708884875111229173, 9298761732455115000, 10870603325717459323, 1875245178072310905])),
+        Fq::new(BigInteger::new([320156437613530190, 11974893126229634756, 2072546373128332807, 1907951001785303921])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([2911042089988624585, 9948530912702696395, 8055044596299610991, 125505944907466242])),
+        Fq::new(BigInteger::new([5514621996561311514, 16451610142535939812, 14391079428789900419, 2909765591325789253])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([2114384041954730070, 18302009066185244629, 6822734262698006742, 866612481011459512])),
+        Fq::new(BigInteger::new([15179179604204674074, 1137133484294806411, 1969278612671360587, 129587846171436452])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([6313975710523720307, 11377531148063102927, 15602050597831711998, 1946224593878014515])),
+        Fq::new(BigInteger::new([8153688483081211383, 7101503469616167350, 5338104716811884142, 578216089266439042])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([17122967109942535496, 16851959030107273529, 5497758385310426955, 2185821574248353120])),
+        Fq::new(BigInteger::new([15052187691633613802, 17340920105197500459, 8968212169683826948, 904518471793602773])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([4547032381137728142, 13622470013231860236, 3812025576886219093, 2120422601486201126])),
+        Fq::new(BigInteger::new([9625469718795304568, 9628611852477740995, 16552298184677854843, 125512935474466293])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([17948576612505634892, 16838532561739676101, 2332352209968275932, 2319516199832865510])),
+        Fq::new(BigInteger::new([13804067371752066337, 17270462360015639866, 13403844717144482433, 2967719239415395636])),
+        false,
+    ),
+    G1Affine254::new_const(
+        Fq::new(BigInteger::new([9609513570701510450, 6153973030391196675, 18339450092587948500, 3009928995739403339])),
+        Fq::new(BigInteger::new([6905350688269808896, 2747397188234601598, 5191190524327108082, 249254170987461739])),
+        false,
+    ),
+];
+
+pub const ALPHA_G1_BETA_G2: &Fqk254 = &Fqk254::new_const(
+    Fq6::new_const(
+        Fq2::new_const(
+            Fq::new(BigInteger::new([8035349878198547088, 16495477028811560057, 13258357263093636776, 3062612996566768222])),
+            Fq::new(BigInteger::new([12758260575419121125, 1365286129732351427, 5403867346039667406, 2823544379132793160])),
+        ),
+        Fq2::new_const(
+            Fq::new(BigInteger::new([9248307290322242908, 15862596056335625878, 2557311625314099898, 1242209264612509145])),
+            Fq::new(BigInteger::new([6427700214587973930, 12870701350984218193, 8197910484492776192, 1309817778755941419])),
+        ),
+        Fq2::new_const(
+            Fq::new(BigInteger::new([11670891256269772308, 8410269116322331106, 5842819351701827906, 1952853329790611951])),
+            Fq::new(BigInteger::new([13534441350507716964, 17304553075550827414, 12867154692696413329, 93317604314704605])),
+        ),
+    ),
+    Fq6::new_const(
+        Fq2::new_const(
+            Fq::new(BigInteger::new([2989916535322296897, 12446529532568849677, 14356852947919099303, 1632133669617409385])),
+            Fq::new(BigInteger::new([9632240190118302070, 1129574792157062118, 18070063413630105129, 393366383312335248])),
+        ),
+        Fq2::new_const(
+            Fq::new(BigInteger::new([6591261367597657465, 10961242848928014279, 11066084535489999962, 1716607512116415472])),
+            Fq::new(BigInteger::new([8851953487020489270, 15145151113586491800, 1992775490766744878, 2427219268386615893])),
+        ),
+        Fq2::new_const(
+            Fq::new(BigInteger::new([16850747671233282496, 13120606804985631924, 16750415166840217597, 1732321478824476273])),
+            Fq::new(BigInteger::new([14568953402172805665, 18295913123041594614, 9005137692908737632, 854141915765359487])),
+        ),
+    ),
+);
+
+pub const GAMMA_G2_NEG_PC: &G2Prepared254 = &G2Prepared254 {
+    ell_coeffs: &[
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([13561823019654849787, 2343080162139748275, 12130203231125779507, 1182672306843915142])),
+                Fq::new(BigInteger::new([12280263523435487586, 2262148577765565660, 1945067216530537586, 3104281969095793937])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([15153157375979271419, 1354576498625907599, 10675597608744517287, 2690958214120499577])),
+                Fq::new(BigInteger::new([4089209470497461562, 2105296120094050881, 3406542843737892152, 3096880753008949352])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([16651403534927482600, 8497976867627883551, 9483536321049421554, 3428368959652397107])),
+                Fq::new(BigInteger::new([16883123864576898037, 17297024779053182708, 1005382378001135406, 1469061584034455526])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([858978386848508791, 8937264803559671159, 5367146857651637103, 1535315837701984519])),
+                Fq::new(BigInteger::new([2141360901743885720, 8193419204140192019, 12799505762692374177, 3095559988657717664])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([16205511185779559310, 233533350757492271, 17332271626406966516, 2085330811162810355])),
+                Fq::new(BigInteger::new([3773283844953205274, 16601303640659360047, 11167866900965013990, 1982502904286706155])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([3702538243535889432, 2617472740417279771, 15127969872163109767, 3067468444821479786])),
+                Fq::new(BigInteger::new([1679980083165939752, 5573081731977487723, 7943265680755104563, 3352851303018891841])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([8922948144405200398, 13751427786450559602, 9646440971490649240, 2704331115901624008])),
+                Fq::new(BigInteger::new([10490671968436241819, 6628442198050307817, 16233408510759319604, 2754037415879654911])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([9070450130084228140, 15739625262404602245, 2291785769080863509, 1186939966118664307])),
+                Fq::new(BigInteger::new([8502419491061801251, 8601909760788904643, 466144466967236385, 2745151706086014523])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([3270151763492059053, 7160073314885138969, 1775190364601539788, 2792688065001759086])),
+                Fq::new(BigInteger::new([6844590148684222559, 8766834818395747308, 15499278997793761367, 2183995852641096545])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([14715041743786098360, 15608549643396186532, 15464028700370606843, 313959288237014903])),
+                Fq::new(BigInteger::new([929211612194361451, 8036377266809505834, 13658360878830856422, 1369848415243723829])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([13948490665506024529, 3205861411018992265, 12429174313389542627, 2935517910498789710])),
+                Fq::new(BigInteger::new([16242386250926265427, 4901232149017783601, 17317878269346120662, 371983589924800873])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([16677737589770644871, 10799586170515711498, 5291487033287206517, 498901111503524450])),
+                Fq::new(BigInteger::new([15215988502367151447, 16663273898717976809, 1854767911411908346, 3101089234710158720])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([13512694168023210907, 17906427789522624350, 8484894788042296898, 3297850288889318733])),
+                Fq::new(BigInteger::new([8824988961971061193, 13556459721360498741, 2969725009439056446, 1943090643486812411])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([12391186134992239333, 10348330791150418083, 15091990130866489302, 1557579859908815192])),
+                Fq::new(BigInteger::new([3613570370437983433, 1437154195446517473, 13072582976511371133, 199401088460613677])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([15267054547449535728, 6785975143416605939, 5466729582344480078, 2708649382282320783])),
+                Fq::new(BigInteger::new([14025288865332549810, 172023552372792484, 5940264618735544594, 480223403268998074])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([3284356424283682632, 280688798866845890, 2376009756800095767, 3439512050665490449])),
+                Fq::new(BigInteger::new([13238208016493713813, 10109193841163973674, 13114232238746250100, 3251313490281375137])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([9344619276148321553, 16341683069663962899, 369187329723083874, 1368469839146697548])),
+                Fq::new(BigInteger::new([10317234549245594117, 14188058534743140077, 1951783894382885468, 350433609136249681])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([6687259505933131986, 3991685772338856733, 10593448610083843545, 517414520930017096])),
+                Fq::new(BigInteger::new([7981336061327066544, 5895180147985289569, 516011511681791251, 3400154530195872659])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([10960160407136144170, 5578632635245936291, 6837139123303347249, 1405239017799456214])),
+                Fq::new(BigInteger::new([17194912223912990623, 14103391246863459949, 18183117675306913618, 1616329904872407899])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([12357231857457462695, 8232519612264802931, 15035191760008382570, 2265803953407972143])),
+                Fq::new(BigInteger::new([1210471083354779992, 12570794407269137187, 6819868582789135955, 1971659492646663224])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([17639237646050953421, 4877548423761563618, 13535982165668428783, 31952194530587298])),
+                Fq::new(BigInteger::new([11735490351995686034, 17112921975703935050, 13351999216537938482, 979764292891491756])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([4524766898043513393, 16300917835553398734, 14595974489069526923, 385827293736387565])),
+                Fq::new(BigInteger::new([10287403576114189427, 1854788749122257609, 8629295937090040203, 1638545562387917380])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([11307156192116711214, 15000651998633934817, 9577440403185925696, 1042224161257047567])),
+                Fq::new(BigInteger::new([17290005453857198713, 16835339198166258334, 6693187309765013120, 1442026968385260127])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([69575189530217175, 5228698507019278269, 202706045901484874, 1777449526944397567])),
+                Fq::new(BigInteger::new([2957133711162487626, 13324743406873129483, 696148469170238070, 1416050319063769083])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([6470317541212471999, 13468597593365002568, 15236312529939862781, 1195660881230549570])),
+                Fq::new(BigInteger::new([4706152345362125196, 8279642165326783411, 14613728469678666212, 2465819270114667440])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([4905956322412552653, 12943036866993476121, 12824273662950755585, 2202516010118843369])),
+                Fq::new(BigInteger::new([6185837958866940712, 249741370555067992, 12122161163137308965, 1710838237443951149])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([4737988162754853704, 17582948730589420317, 10800409062679308305, 1807706417493110003])),
+                Fq::new(BigInteger::new([4358755222359832320, 9832387021300674927, 15504074763794634576, 1413095475612516927])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([3526558025080693059, 15066439199827250119, 4560115442872055891, 1680221240236132938])),
+                Fq::new(BigInteger::new([4559700479492305231, 13193184171580038217, 17360777965555907490, 542058901373076172])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([9178431650815440432, 15151206792058040706, 7223073031094194032, 988967641318277180])),
+                Fq::new(BigInteger::new([12455387436477192607, 9079967901463644557, 12629569598770373252, 1103792082790523791])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([16360896213607909730, 9844286081359555894, 7228926957206319203, 3000985099968026914])),
+                Fq::new(BigInteger::new([1784274709725521661, 4530565098480166040, 1971598069151449630, 3247480130734932390])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([18249945219921844679, 8223831813501706875, 1619172260030948989, 3271459623669312070])),
+                Fq::new(BigInteger::new([5290749470251305140, 7112380934077087989, 16352563440881238600, 1134538501926748610])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([7146737410604176092, 18038639199122195596, 10682862908816997091, 3217915683729405963])),
+                Fq::new(BigInteger::new([11615858020074167868, 2672371955903075963, 5308566878208951164, 1857904394650782091])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([17028967253596877941, 18327007928466530938, 13314488130418146439, 3358552499534016587])),
+                Fq::new(BigInteger::new([1369615015943452412, 14985372317939474767, 3778983664518364422, 1850455047980178316])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([5960580440911046159, 17820675413174106391, 12955968663964250346, 3356109599242380790])),
+                Fq::new(BigInteger::new([11207302515202934362, 14197691164868753275, 541449558908198776, 355986852335651020])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([9642721058408004507, 11276112427300594011, 14014286936366547022, 2248935797508071147])),
+                Fq::new(BigInteger::new([14054564009937444575, 10439679060910308748, 17864452476930628577, 3112087759471020293])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([16043289265723138977, 7033967116076643717, 9134216998248200846, 67258635524917880])),
+                Fq::new(BigInteger::new([15538408531711659917, 15794654293241146785, 1006494577835118224, 321697420767244765])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([2671560735658582260, 1860699927045899662, 14059381132644950317, 3008261440202613046])),
+                Fq::new(BigInteger::new([14571235366162089657, 16044035083596849841, 15944088477198415366, 167288961158935164])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([4444732590407464444, 6453897496190609003, 9039292526651990351, 3091747631905609601])),
+                Fq::new(BigInteger::new([7122707083871624973, 1425398244518735800, 15021145355665476155, 3135576278935713696])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([144747779028217827, 833955928901891550, 16886624315992670018, 2181947301607856580])),
+                Fq::new(BigInteger::new([409972955873621500, 14389740309750441047, 674707523839941356, 1399265762856122702])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([17449893649473722193, 10962329502248482450, 14380998827131698221, 2865493893723686020])),
+                Fq::new(BigInteger::new([16793724845325220304, 18414492024426117641, 10565494163973275298, 2208827417878146987])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([4543035470139530726, 3930402043241538474, 13466736462048587527, 956938879454077261])),
+                Fq::new(BigInteger::new([7243468638755152415, 6620001297254938420, 9340731768368931964, 471918077801637318])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([5919172967342356678, 16485641810891013298, 2932742232602374913, 109621662273539666])),
+                Fq::new(BigInteger::new([3943405938516336939, 5433234705959432394, 11132870830410227218, 3291336808124410806])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([11748698918912165906, 1774447923109183113, 10532958950093937931, 1533143926552133496])),
+                Fq::new(BigInteger::new([558324220425965173, 3984226775129939774, 6034114759179487993, 1214183972017476212])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([15698632214432233707, 163983704818594120, 2056805436091470169, 2935509886180173170])),
+                Fq::new(BigInteger::new([8332037362304337074, 17975089865985446994, 8709569906988619585, 76670938864477863])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([2965717813543299807, 10165294512744012554, 8510674965094988232, 3348170564392244088])),
+                Fq::new(BigInteger::new([6456398662206465901, 14541904975864166715, 10104643007572861543, 2276344871341457189])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([16855205237499243148, 8088250031760695797, 13047225264176956272, 750940216627955461])),
+                Fq::new(BigInteger::new([14916577790586811754, 13719381517091934571, 9680578144779763340, 2391778743787859402])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([2530210345721114751, 17927047565189578546, 15151987003448173895, 1673029110460983092])),
+                Fq::new(BigInteger::new([15501563000390317411, 15483519054281013317, 12607069658838591031, 1116316522273191971])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([7086300500735363084, 12763007981002204347, 16877332116567363372, 2071073705384791195])),
+                Fq::new(BigInteger::new([12864837436192013394, 8981253587834190420, 15235931984913686631, 1807544944236921135])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([6510600617491709023, 3793928457734963145, 14779892834218546383, 2718313657881810089])),
+                Fq::new(BigInteger::new([14145456111926786873, 5286583292010977189, 8567998578103615866, 3247785614029686018])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([18393504910902718094, 16351632890285271908, 12971499929794547778, 2755528570819207263])),
+                Fq::new(BigInteger::new([4006647364480866897, 14944653198589199491, 833533675790701161, 2245072618442584674])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([4641729396615923725, 16703920341722786534, 78377814197577476, 2297355245764589920])),
+                Fq::new(BigInteger::new([15558683651077298166, 17068129228117306604, 6349178513868647020, 1358980516857841217])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([6031923826695205441, 2436535684095554819, 7855881311228747221, 738485404636777777])),
+                Fq::new(BigInteger::new([6393104500893744178, 3637259561307848540, 6914012157751505043, 991412223668197691])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([6138763942133174663, 3592489774467471421, 1568266310399496676, 2480579429133253237])),
+                Fq::new(BigInteger::new([6472636058001379485, 11533749784420800984, 6011518001485942201, 3232845011318159419])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([9260333020320105250, 2166192250344177578, 6288886563607376019, 720345624121122101])),
+                Fq::new(BigInteger::new([9072793405344986439, 5025188916299053309, 5012500711806121270, 2303271646001037426])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([15924933256681079476, 3953670300915019923, 4199812134028063304, 3328015692113606389])),
+                Fq::new(BigInteger::new([547270579354471963, 169708407209110734, 14554632179128012262, 1791801521156529799])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([8068802814471822200, 8683507439303910781, 10208584499065964637, 2422110147031045331])),
+                Fq::new(BigInteger::new([7862099216416099237, 10646467696894990968, 7690327413058711429, 1420777560597976693])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([11968163845827556663, 3272080007720308044, 12990832779901559012, 1525926115902628686])),
+                Fq::new(BigInteger::new([9150358214614073406, 16694891493713874405, 10917435403078696868, 2926759339424008852])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([13104119716055227045, 5368282278282495539, 2122570592818790055, 1576213023852924867])),
+                Fq::new(BigInteger::new([15179177027750870843, 7067152203832394138, 11175426857617353637, 2077788411808264324])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([10500388174812834894, 12779008628544458717, 1266664920141910107, 6385549020987654])),
+                Fq::new(BigInteger::new([14525630499498114949, 3875676654624097736, 10268574992567239862, 2133497837529385075])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([3846534757964247364, 10466785139013181911, 2529666279431776926, 1762991065140806857])),
+                Fq::new(BigInteger::new([14253844149287152017, 5709492505888733383, 8813875184290979238, 299889838933638338])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([8536750730564943559, 9923714870105764241, 10193435349498317442, 3000792745942877803])),
+                Fq::new(BigInteger::new([2179250255723402345, 13748238053837448230, 15615856158934889192, 1709174405785789361])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([11124659677179091037, 15602477242191620395, 4033018618286055612, 64778497818116764])),
+                Fq::new(BigInteger::new([6716879325029600212, 12937315594461061147, 11450684438893165177, 2948040424875443724])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([7980371434197220257, 7653258786583203740, 8406702887897961620, 3130541119088974043])),
+                Fq::new(BigInteger::new([7988488881627770752, 1578329944623715710, 1525505044269620778, 2842260982642366265])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([11143436348200257591, 10731503970782773999, 17207976983163976354, 109546270034074816])),
+                Fq::new(BigInteger::new([9863448803492372275, 9030734747936436079, 12932467517907931789, 46931903658293141])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([3453551810866133255, 6832505058424334757, 3924055450049913205, 655602840595175621])),
+                Fq::new(BigInteger::new([8985435758087888017, 11834420817713558419, 13737141698423069240, 2727992481061996248])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([15560987770718896682, 3362958368172137474, 14684743259356302078, 439585889997694391])),
+                Fq::new(BigInteger::new([256845063852517855, 7078497536999555461, 14941391509990975357, 1478621193779374135])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([12724078712792600162, 18345259558154901684, 9811791483983735095, 2621741844677629269])),
+                Fq::new(BigInteger::new([7176094187654476874, 13342878616400494510, 10752492725712686496, 1374770969840949354])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([15044015385891226412, 3090517369215879755, 17222635697747715068, 1817587527400309687])),
+                Fq::new(BigInteger::new([8898262628790287288, 333912812562899159, 6964988296670940845, 2496972808479104368])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([18096953791146534334, 7828856885514786811, 18289771457807841868, 2938238201042353590])),
+                Fq::new(BigInteger::new([4824051458475604009, 7183767577387925975, 15437803240669131025, 1608133214101168446])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([8664257846285571271, 12715114071414047515, 15770844764745865531, 206450545271110010])),
+                Fq::new(BigInteger::new([7733398659413187277, 530970980101801969, 8265807849253725438, 1638256545303929736])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([14185952149304859401, 8145446167988247463, 15342070130259535520, 634399437934073474])),
+                Fq::new(BigInteger::new([3813208078887765425, 1044879494274912897, 16841428980294383053, 254278731765738375])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([12372115939041485789, 5825527295345239052, 176107062636513027, 2170574185808542644])),
+                Fq::new(BigInteger::new([14161637671624367672, 4842713318055646761, 6083081553227204315, 3074097905339110245])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([18237450938305874684, 8297105057720524605, 6454545689243691522, 554517379995674788])),
+                Fq::new(BigInteger::new([11275433632794851502, 3122977093622821668, 6486636955925318205, 2262917372507496474])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([17571224083334147512, 10643148786726828529, 7234313208791921341, 723253355774912688])),
+                Fq::new(BigInteger::new([15622196004698970177, 2450318284786365188, 9008144579021462709, 2306648417565586640])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([13086824666988169620, 4422700110672095812, 5322804240182188865, 1353082333361307378])),
+                Fq::new(BigInteger::new([16255732660423533138, 9417103800983568023, 16339142549089840971, 1309771154761108580])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([5343460911838475575, 3803565859323228137, 10767008182122695047, 1462092333401715833])),
+                Fq::new(BigInteger::new([17605168686820999678, 8882969468093323015, 8819129672712230092, 399359067319873502])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([4148447730892713142, 1445433395864946103, 13676331551989697112, 1430531669518555547])),
+                Fq::new(BigInteger::new([11353462825317234272, 11124475175235415076, 6061746676710608296, 710647381934428746])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([16814428355402741614, 7050227969776414842, 12143643482159716783, 3245625824416467464])),
+                Fq::new(BigInteger::new([18164324666602943634, 4892881321642823226, 13669238449237730856, 1528347346594366745])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([16820285995482823149, 16096928115397517935, 10304682337567798119, 735677930962334698])),
+                Fq::new(BigInteger::new([16629698513244575478, 6789404198591344877, 7031129124329984017, 28132391814144639])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([1368945879352332674, 851563594407813658, 5081522401029773479, 2533446887853853661])),
+                Fq::new(BigInteger::new([17225970073475580020, 7298635776701978335, 3083299673910142581, 1014077273334740493])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([14741194042262469003, 10783259063645399841, 1018398011113234253, 2866275946439146897])),
+                Fq::new(BigInteger::new([1025437437547656601, 3367381428517426090, 2840060882157311451, 2242649225521831476])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([12279151554651260759, 5998243188499446835, 4954207167926654180, 959682207052064723])),
+                Fq::new(BigInteger::new([1894767127830079265, 7546045182316797890, 18379742019056173815, 1245020024094071028])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([1363671878959814469, 9914129165938377847, 11637101417840395703, 3398622286788038367])),
+                Fq::new(BigInteger::new([7688571432347005468, 4024600974345448743, 3984131679840975741, 3375459787478173352])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([11548446592454231556, 6200817324347102839, 9745182772004622753, 2710767106079454564])),
+                Fq::new(BigInteger::new([2602627079319375983, 9107493678160205692, 16272057384397344065, 1931756710886091666])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([17404969497324990747, 15451254952993132962, 1239711978804938323, 2117408670801643455])),
+                Fq::new(BigInteger::new([6088926605334424698, 9937544205369686045, 13803287002150930027, 2905164268851171895])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([689028100017523785, 9600397852124657239, 6451746290786477086, 902098007128726191])),
+                Fq::new(BigInteger::new([3910039834463705986, 6987586244367204529, 4796980404329413802, 684261742388895072])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([6318897513596432794, 3077615940901328032, 5590634978668438930, 1278354177713235045])),
+                Fq::new(BigInteger::new([16121022760845568950, 7717653541412627202, 5257540238035724720, 2807791198078200065])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([3900460347943062604, 2344471565793219611, 16687158389332287095, 879211061910250546])),
+                Fq::new(BigInteger::new([14553541852551497391, 3952224639250376923, 16252098916851545893, 2165395642844567644])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([7091712061156773204, 17182196712692809290, 16402724957190103371, 1115350827337741379])),
+                Fq::new(BigInteger::new([12218638155658363151, 13207715444409409243, 17526584007643004310, 3416549194984011906])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([2927958576896249056, 5738480780197298168, 13088795800008863969, 1008807766018053421])),
+                Fq::new(BigInteger::new([8907823755946594165, 4719781861277166837, 4596455230599386972, 2838831190620768697])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([9508152612620671441, 2523447733748609625, 10151365500237441030, 1069192211308445732])),
+                Fq::new(BigInteger::new([866113604643353875, 16904645888314608678, 16731015105918355057, 2140461184286766851])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([14503737105482749836, 4550460412614647205, 16153378806185391905, 794794944207175764])),
+                Fq::new(BigInteger::new([9836459047299437353, 10301040271575100999, 12641994478263311317, 1515484825835684180])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([16860098171715356623, 14115578936485903774, 17291144658677267287, 2242396817011653546])),
+                Fq::new(BigInteger::new([12183330282075521326, 13792081702876763187, 46649795565407809, 1674004435646638619])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([8193260629315944952, 10320381457817965539, 6683266681094850724, 471419880987178402])),
+                Fq::new(BigInteger::new([12520091042002636972, 10956275950833333324, 16016299450136282379, 998707863737763273])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([9548079481874796398, 11604741830714488938, 13237860503452540015, 804052065076974365])),
+                Fq::new(BigInteger::new([1816769502987434153, 16414636324443256072, 6183865706539072473, 2720727999158079599])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([3793718718864808588, 8007764677671337625, 2374949237458486244, 3029776983765316153])),
+                Fq::new(BigInteger::new([1061807587419321098, 3973466673068264143, 18078486756141051043, 1367004171336759263])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([8723181147736874196, 7543156635484397282, 11988449163206830972, 3132317168465434950])),
+                Fq::new(BigInteger::new([7977365135866460638, 16178002206133842348, 16486305232833669023, 2166927572534721615])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([6592594922188806947, 16562273412052769709, 2731311525796961232, 492976258208691395])),
+                Fq::new(BigInteger::new([15661569026420342824, 15805627977296303628, 3156362864092154876, 3110876408249447633])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([13118242130756994210, 4347058880178924669, 12334017590639566048, 1311900375959223617])),
+                Fq::new(BigInteger::new([3689567005005630855, 2733704538607440146, 2626501266237776090, 1612761522244127367])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([12516423141052829522, 16501198842012267646, 14140022414451998876, 22298468781010527])),
+                Fq::new(BigInteger::new([11330881884586061726, 5428389903263131960, 9407902706688544699, 3113334051632245665])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([14345871705327084629, 5739839452640449199, 8094625545577356778, 3377247976275970030])),
+                Fq::new(BigInteger::new([10771640438271476458, 2003116266228404213, 7369576514075013108, 1398263857142936957])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([9876346137528098003, 17571775575807216374, 7798695376011638984, 1627464436746461322])),
+                Fq::new(BigInteger::new([6166983598162520422, 8624389540179915182, 13490362330273872569, 3282579627932891749])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([8812970243865697658, 12643678897080261804, 13164708306532848037, 2625773697904136713])),
+                Fq::new(BigInteger::new([375967539247071935, 10008580418320847223, 7866534436212208436, 2964194274226673374])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([7157526161645714381, 11714071524116391343, 566476305232649556, 3272572212242019516])),
+                Fq::new(BigInteger::new([13812944454279108557, 13137143185605082247, 4393208830612547970, 1333873544855273673])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([11242701122482655153, 4872537698409558593, 7090443510554893963, 1442885151743925593])),
+                Fq::new(BigInteger::new([12557867240817011915, 9166091729510466941, 14283632847877311445, 2404979422923189755])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([18133198879309477697, 5553837476322796725, 18278586821083316182, 438853803759067845])),
+                Fq::new(BigInteger::new([1066312719707076875, 15708172627075336203, 14452372962913772125, 3113801987216218674])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([9464921311952132694, 225260877242999899, 16544626584287837459, 2771984352531626869])),
+                Fq::new(BigInteger::new([6303748153850514850, 12892708390268865603, 3552322784815845830, 2005551460355127342])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([14695842110882350664, 12747313223119583003, 4358618773169054787, 1478281423648234670])),
+                Fq::new(BigInteger::new([12039432944093557345, 191132015226251914, 10842294224282026654, 2741933464538958677])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([4596571429804835222, 7452051091904563237, 6539103815800496362, 2574807634301163149])),
+                Fq::new(BigInteger::new([2045224923615193101, 4145556547164068028, 1155359405618448652, 1758751180144615096])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([10350408096569766559, 5090840671149855872, 8917677630169090403, 2886665426098888271])),
+                Fq::new(BigInteger::new([8480784422116067513, 13090611955346224056, 17647567022931912373, 2758355963585153279])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([14353979383664197360, 863209224358892421, 11467131509773241438, 2824170529089052888])),
+                Fq::new(BigInteger::new([10613114475808735218, 4303879672299656854, 18169713422798148430, 3342470536838255814])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([6108208250530553012, 8019905273051501264, 18082007093438380000, 403816594587422619])),
+                Fq::new(BigInteger::new([16683396804526221078, 7660167567346395329, 7948091802184551592, 1314449713220159229])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([14997493716942744032, 17296501563805431838, 69529584610898151, 2523243070675158044])),
+                Fq::new(BigInteger::new([6061329675510906128, 2052171293666358344, 9808898699570261026, 303244792693381760])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([17444592676820846183, 1148428576761255174, 6820567804672165986, 1104276218447470036])),
+                Fq::new(BigInteger::new([10095196907485892850, 1530252679784368524, 13300377585355160287, 3365925461947658610])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([2081591636008913796, 3965048018978327469, 7304724198135353135, 1037788612388782204])),
+                Fq::new(BigInteger::new([12584989840364766750, 434272675064504887, 4293428836642994087, 1979647937276893764])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([6716274911359127493, 10458847153375706994, 9601190970718989601, 38780871529861352])),
+                Fq::new(BigInteger::new([12607632280383005872, 7876499884249134206, 18351757274332316, 1426790100387199657])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([4141363522554601773, 16248567222552065521, 3381782990029824595, 3002383512615594226])),
+                Fq::new(BigInteger::new([11227985780511448261, 11599628254931712310, 10537048567976724239, 2372800874900546145])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([7524437830209776082, 10976165549506703985, 14859506254891085341, 1999177171830421721])),
+                Fq::new(BigInteger::new([16072708524257284583, 15844104373399506146, 5954989517949824408, 1516171061028041351])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([8103638924803353755, 17950717359402832032, 3967747523472402143, 730241509142415344])),
+                Fq::new(BigInteger::new([14607017604381923016, 3627406188390103481, 1317387059817336130, 3762246758148860])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([12868966153902050342, 11105803054461601653, 14674677521652595335, 1249015834323690717])),
+                Fq::new(BigInteger::new([3761781616782249820, 1519370798822472258, 4735670945011718825, 2739818585471695717])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([14579638538015783358, 2079827702820117263, 3609877315187309577, 1774473012581211418])),
+                Fq::new(BigInteger::new([17308693209211248348, 16750420647805352947, 16903960008718521794, 732643430239568746])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([3607368699101675393, 3768117990446736457, 15833390793340146755, 2395707070230781665])),
+                Fq::new(BigInteger::new([2408619880392194511, 6155461607501342911, 17994049699857930657, 417528462093622895])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([16619470704625795472, 8572906260291258072, 910347985853521757, 2283510807875294297])),
+                Fq::new(BigInteger::new([4872552397253558582, 17861419347038449180, 4654041445638473072, 1038064558381620656])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([2973187346996225395, 15880201341057673, 2733766674007150569, 805967944070040190])),
+                Fq::new(BigInteger::new([4396103197074631776, 12878289389744685075, 8327566817250493665, 2169116843616923591])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([10182604077527385935, 2047680351076821224, 3328785167661328781, 1339702191221215177])),
+                Fq::new(BigInteger::new([12184564044098964853, 14416099572823926887, 18217082399846507238, 1067867460124773315])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([5525334997072909903, 8167401403236370147, 14962009434280381704, 2410798069081212075])),
+                Fq::new(BigInteger::new([753099350312245151, 5342815699587872849, 11544418963050038741, 1937301517625692814])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([17301099073708199041, 12331272222893480654, 6809698210151286802, 3039942401970976719])),
+                Fq::new(BigInteger::new([5055730359710415881, 7119654852104579304, 3202059414936002360, 1916417893177851904])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([8674544556413295451, 7344551641704846048, 2999352658271277926, 1592233985264040315])),
+                Fq::new(BigInteger::new([2302769345204311581, 7283042597463931975, 15020436714592222177, 1836593107107164600])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([13925894543848963153, 4615000475063400047, 2619283859940955220, 1797542302290357169])),
+                Fq::new(BigInteger::new([5220676369595445542, 10443799775963604013, 8013528322925874971, 2793491049667229868])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([10153067566404569330, 18130485764100592218, 10998390948078745432, 451586643732390188])),
+                Fq::new(BigInteger::new([2077613238178457940, 10090552062174198581, 244165948539664231, 2527562921136221715])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([13685509224896920451, 13012496302589316952, 11748109632386607265, 2185124570153652670])),
+                Fq::new(BigInteger::new([17454036283859328919, 3043543642781042627, 8165188141191133555, 425528495989022204])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([12191173376765638233, 5195181247222699139, 598473791348013057, 2310342967904314808])),
+                Fq::new(BigInteger::new([17285818182268780831, 2580701376574985828, 8883932666360592271, 932147795553740669])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([468552179054484302, 16119328102680921537, 14117417563402135903, 363069692151263846])),
+                Fq::new(BigInteger::new([16748657464932852406, 17785513846159640968, 9214181479903091137, 3316307792010688937])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([6537406720139409882, 17653852093311334783, 15014117944069903891, 570957654612572965])),
+                Fq::new(BigInteger::new([16926668731534440157, 8317539471711869644, 18239714750070757130, 1681850885290573929])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([14122708966255225834, 18221272500436080179, 15122294454778366766, 2183511436474513713])),
+                Fq::new(BigInteger::new([12964223281217890581, 448173803515533022, 5998622858462877346, 1337548488304095237])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([12997036867541185573, 12726945946729526562, 17972120044446302129, 1752539236701994369])),
+                Fq::new(BigInteger::new([16724682291289569209, 9015067604804069065, 8480903122472766716, 3167502714377073650])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([9884645689941179365, 9540108542992140609, 6236109961106184769, 460008567264344058])),
+                Fq::new(BigInteger::new([16325920471863240214, 10357715017799903731, 17676620437695669772, 1892788773964015661])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([4854723428902798005, 9104617642770771763, 3181592235193349937, 2811758974754117337])),
+                Fq::new(BigInteger::new([6877588237937722973, 2393055273943245596, 8750968593993095736, 3183061151886729160])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([8379697745965295652, 12321228053297358230, 15554739400010229496, 564630124827073490])),
+                Fq::new(BigInteger::new([13492038540533389996, 6077322692052770395, 1328445105945267742, 570042718861731330])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([10078467324481424423, 2728957923336976006, 5056586543863394671, 1621172945005739349])),
+                Fq::new(BigInteger::new([14454425088341252659, 5050540748547665973, 17538926365931137279, 2954270443707471008])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([16055405210098419827, 14721831558385369338, 10256526867823058471, 2309261670158640460])),
+                Fq::new(BigInteger::new([17245014913821585685, 17397910722883001423, 6372605746829300931, 182747075749715456])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([3085717056742449434, 7078881868314454513, 3686301128467950982, 2266819471994072502])),
+                Fq::new(BigInteger::new([13972514893925583849, 7668580042847108951, 13926669502042793593, 3268233009433205654])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([6727249676621113998, 3050001469395735139, 1633847378830133142, 1356133897538247442])),
+                Fq::new(BigInteger::new([15533632936651283538, 8961406254141819307, 17672635878062472614, 2664490865782676028])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([9385150229817506388, 14774165582831825743, 16160294558019573485, 2677345627856469900])),
+                Fq::new(BigInteger::new([8581201997931795945, 15926892571163227430, 15328449283615661555, 1531163126363715185])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([2517802101806960712, 761528101127881527, 17281386923470896764, 2710151797441243256])),
+                Fq::new(BigInteger::new([620805109021079103, 14189327810102447438, 4098716979267321990, 2870550233328561716])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([13612798698030413789, 2939630333072701762, 14311982164713722101, 558028682318871995])),
+                Fq::new(BigInteger::new([9050919505262794854, 17982196097726870816, 13848188998224130866, 2118593165266831103])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([17602296775999240706, 10508120007738745988, 2296365122391671849, 1387590529350703354])),
+                Fq::new(BigInteger::new([9070066253029267179, 6242700849982108385, 1613457936149591628, 1117661633589850707])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([6809105045530332979, 14804415597306427174, 1376053716389341978, 3083473666869108669])),
+                Fq::new(BigInteger::new([5527395831079804323, 13751381687888723980, 3389815024811847111, 2765161618760251297])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([14347210446992902938, 17633409484409016654, 16205705410705178869, 1794051181723490033])),
+                Fq::new(BigInteger::new([7518616299550140948, 9366115567841260292, 14669188413293319301, 2016431952099530448])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([1210427037554502305, 5099654351440275511, 6780015979444548206, 3295020814035679745])),
+                Fq::new(BigInteger::new([10891809996888504966, 1423699883513345179, 13517564647804799063, 712715497841148490])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([12591910192469163352, 244851945580543565, 14845194204672300608, 1788648737139641748])),
+                Fq::new(BigInteger::new([4578202542200299096, 16687037060294366116, 10558086180064411566, 925307391369419797])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([14805956361149491815, 17393219696441706234, 5557066789633234432, 834604950223183463])),
+                Fq::new(BigInteger::new([9443243435154188681, 12263190721315518905, 18431389043340505967, 1062611828453130258])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([10466097868705270418, 9781947555952706342, 6412592648407299389, 1182639211778970361])),
+                Fq::new(BigInteger::new([11901972051188255278, 164762760390338063, 11093767382369920223, 417569052783996458])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([5388431185311201335, 5433617212347851902, 3579362420125919212, 1107076294720113860])),
+                Fq::new(BigInteger::new([5546197972123892544, 11002275653072732264, 10175641587331944671, 65732939559661087])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([7341573490219244352, 331012737371061781, 6787309604520013903, 634443994916190313])),
+                Fq::new(BigInteger::new([6255251807301932601, 5162454962315870383, 6280207148090194317, 2366222764533303345])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([10761491470701319792, 5926642387527169573, 7723215570733102207, 3421676825500894019])),
+                Fq::new(BigInteger::new([6819333908234170946, 2408929183887323187, 8045735067792071525, 1055108186061667968])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([2804883827655038568, 8027486267659939942, 6637529347613770487, 2005284674505703850])),
+                Fq::new(BigInteger::new([3428951877433355560, 6049807916309910953, 10107028304800489418, 1050620134794246565])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([9840470643879775484, 4829146044183594192, 13661105307617932964, 2349267276733719554])),
+                Fq::new(BigInteger::new([16194640638971838928, 4693759886978601071, 16455151822290689726, 3008306122269246210])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([6207718068639342987, 1309556733826085154, 18414514319384344193, 3436193672149672459])),
+                Fq::new(BigInteger::new([15348118769679388791, 1737009630222730485, 680432736980909225, 2107323372275121818])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([17329210339506128480, 1587352142060584753, 11303018380267669858, 2650755732823713818])),
+                Fq::new(BigInteger::new([6471645956949187480, 2160366507269251924, 16509197290829470494, 3395739293562938454])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([4429642067259206932, 2864725098433168496, 8651982407563693474, 3486105935470173314])),
+                Fq::new(BigInteger::new([10291499041414923626, 10839299130404082924, 4619173524325197831, 888956976226276551])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([8335811491251909405, 17832921817721024182, 2489621755036944525, 1657945184506976304])),
+                Fq::new(BigInteger::new([7036232443129451839, 904265190940001082, 16742171446346980912, 1200830882988074156])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([16574971476334976179, 13725952420730559782, 15974753855047828631, 148335938473839334])),
+                Fq::new(BigInteger::new([5002514048971663363, 8687359882791095721, 15849227755884289816, 2733953756305988880])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([9191707530756503541, 17355051148686750190, 5991299511393582484, 1205030311240636158])),
+                Fq::new(BigInteger::new([15879141703191005880, 17517118412751980266, 9670957456851363350, 2644913238967146863])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([17773804803907468456, 17884731842095488819, 1263700832874835506, 1186484809409436598])),
+                Fq::new(BigInteger::new([4496802745102993567, 10977510805392705692, 8211839423189375641, 626257195210832297])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([9421275699602094181, 8384147010383113417, 15650660854612406983, 81566997118267102])),
+                Fq::new(BigInteger::new([6894897230244348405, 13052803587064883402, 6045409105432921343, 1780585338505366485])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([2712280129495555132, 1123725169129007640, 13669931215802911497, 2194133962836731715])),
+                Fq::new(BigInteger::new([3642001492228109985, 13816873864328186155, 8781391191429513838, 1560995828445073525])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([11352893118708164676, 17450800075108735862, 16967003634498400340, 1393539267808215613])),
+                Fq::new(BigInteger::new([4780811149969360006, 6547449974653273965, 13981273498757243218, 2131091877593919534])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([1071357807057155270, 8234764586334185571, 17722957018628245177, 404034161028808650])),
+                Fq::new(BigInteger::new([18215239177913523105, 11919037941171185748, 13509759260496677231, 1036506962270641558])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([984264157884156157, 12234160500123568771, 1303764018368761831, 21388853442806958])),
+                Fq::new(BigInteger::new([1285823890726840594, 11937077779886969164, 3708908615717385131, 378919159249746430])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([13553386920690152128, 12636035969360489784, 12933906424373569000, 3080184601395624990])),
+                Fq::new(BigInteger::new([3931179210608312226, 12901186972633641668, 12381548150351976666, 2583940909409968312])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([10024060319963571474, 17135772934454249279, 9234692095502813946, 2899799477855289863])),
+                Fq::new(BigInteger::new([2109325680789775953, 13735115146016322127, 14209659429804215985, 1621762483893935442])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([13495334048400125213, 9008696179238448295, 12938944225877300735, 2368074902179949473])),
+                Fq::new(BigInteger::new([12727384658307801416, 17701618016532923695, 1103872527305610255, 181417424123847645])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([10582245881442548410, 15201388973833599475, 17160753744468270622, 2158993586735874938])),
+                Fq::new(BigInteger::new([592587084009489693, 3071595427914852124, 15053098481424786389, 160970753486636630])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([15634102455467719002, 13921581893595366212, 17918282241953578036, 2741846549332348675])),
+                Fq::new(BigInteger::new([7785775982890279470, 1490693645401005197, 6753068597271812655, 489560464196607504])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([6400314454915356086, 15482917223557543174, 9944442850277284527, 1959298455529654373])),
+                Fq::new(BigInteger::new([2749784181884173102, 3852326269280074172, 15691758517033185349, 3328630200437777031])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([17047654078304033927, 17900002650722807312, 4096721741328016992, 1235892865513510480])),
+                Fq::new(BigInteger::new([7056427168128656056, 10771904816248200688, 2175750108418525152, 1236370909856237961])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([10581120143441097359, 4077059222330576432, 15497112522464977776, 3212120719295067790])),
+                Fq::new(BigInteger::new([11362289495652500411, 8012420710095612674, 2491968942282358266, 3291351140067278609])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([18266975991443222196, 1994230390197958912, 16308103199885495066, 1971120817544922373])),
+                Fq::new(BigInteger::new([16098980279755601549, 15927798020727517335, 15678667728559281112, 2929349104331315052])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([8245492365652664442, 15611628388030477978, 15749434835599454851, 2991544548088062804])),
+                Fq::new(BigInteger::new([6381271612889548040, 10156285997723460494, 14700871437032092815, 2652665176445975615])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([6116792117247423971, 13197154604644336961, 17521298649430806040, 272083797042113810])),
+                Fq::new(BigInteger::new([10731261530445310554, 5787587450205840411, 1727282084966072559, 871484875105240570])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([9798694437055136682, 9106681269303453457, 3103695664704273828, 1970101462196155602])),
+                Fq::new(BigInteger::new([4146345982561710226, 3230323075690806105, 468156658525682192, 3323571870958686186])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([16769293987942056739, 5096611654819154453, 9032747151458946524, 1862682904678388617])),
+                Fq::new(BigInteger::new([3239118827761542193, 1646278605127071538, 12229459916133929029, 1625888626798889143])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([722238916089290704, 14506037404939997154, 9993560337811803275, 1650845610492038007])),
+                Fq::new(BigInteger::new([17335757663214304068, 3258089010909092737, 12860524266352681399, 195738562988347455])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([7933884588525869445, 12166262816646265212, 9935958202967411382, 3099874574003028766])),
+                Fq::new(BigInteger::new([17186980852703643018, 16510748861016410974, 10146019643355625184, 1225834704766537430])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([9869736407989751969, 15618097515300745479, 1570171963048480368, 1000207313870705727])),
+                Fq::new(BigInteger::new([6976188952661542097, 15021669713748474745, 18155862676979723222, 1262850867395398820])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([15581282287356062640, 13276397447007008607, 16815407193774056076, 1688776386933397944])),
+                Fq::new(BigInteger::new([596640435501645065, 8166117114752968579, 10812659035470031280, 263725131885995718])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([3015323783894560867, 985294518295457664, 3474625628659806696, 2618005718111702930])),
+                Fq::new(BigInteger::new([4758087270118064143, 13541272387411751592, 17715816211412774700, 289292717154625851])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([3490207954950515230, 14042274081570025533, 9260205917388453601, 3314223001311378319])),
+                Fq::new(BigInteger::new([6399818821870355107, 13125392374604441887, 15811221192633804615, 57821251913599898])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([4239264126786347452, 6104266883894980711, 723333153756499398, 2960217995385398228])),
+                Fq::new(BigInteger::new([17971456312412982642, 9663471063458214493, 2499460139986165335, 2256889687566420166])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([13159001935954194952, 544271585817922139, 7244430658826030699, 1539918229084547393])),
+                Fq::new(BigInteger::new([16143969327917473582, 3345845622789806802, 17423948113145947619, 2403772551491057669])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([7424065032241701011, 6495620331466663750, 15974790360081877185, 1604196060718167306])),
+                Fq::new(BigInteger::new([18043624842273367148, 1506136492357904708, 1198514324896983867, 3333679949861421588])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([10073568520609552105, 18274449824815899636, 3501466313554169348, 3113404184125045427])),
+                Fq::new(BigInteger::new([6109704587054065890, 4965971427053772363, 3123448441611523304, 2732839025069063063])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([1141258918259839342, 10020255671657330641, 1401977482008764086, 1969291514512925299])),
+                Fq::new(BigInteger::new([3167189055643246014, 17928210256519768181, 16129257915331540598, 2520265668735649157])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([12423063123837997818, 4940086409583350959, 9562294743730347991, 718000884660828399])),
+                Fq::new(BigInteger::new([3489672731064047046, 11137062578461491446, 2061729554874856248, 1773235928330523281])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([1092874119948934749, 6966920405679340101, 12078851311011804084, 2675891588897496745])),
+                Fq::new(BigInteger::new([11237393261598491751, 12986506959096635673, 9034488829005071179, 1276083256787032639])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([1052073620691632731, 8575333620974088755, 11084014562338392621, 3154593487369053987])),
+                Fq::new(BigInteger::new([16976587978919803993, 16044570260050395018, 9007152552984870867, 1795893309053220057])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([3140651704983117589, 8027541119563518566, 12859115720563688506, 1558774257238601979])),
+                Fq::new(BigInteger::new([13966455601468245998, 8205585343369180488, 15229216623381500503, 50468581937870363])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([13692874096308608538, 10564435183606105188, 17067732631043372865, 643126976971923826])),
+                Fq::new(BigInteger::new([16149618185365335181, 16599058809928104247, 13595293216540986048, 2901262453463818960])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([18363425424493501376, 15077547200185222724, 14079895658742805671, 1545338990452354467])),
+                Fq::new(BigInteger::new([3830415238336386018, 4806037231428270980, 13160548026751245938, 1434676691417366434])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([18152065247766761954, 2962689848769510577, 9177764506576533152, 49954332334501914])),
+                Fq::new(BigInteger::new([14679974594390323415, 9641172715477216781, 6918427200574388833, 928358829373903230])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([5839469628609789380, 3486937069065007785, 3214564664698894213, 2051139575585370498])),
+                Fq::new(BigInteger::new([2049799856785930320, 15680927021346302234, 857946956907764989, 1750448257658120377])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([15576213174575898730, 17064923091573531726, 15230590516489394218, 2616635870016061856])),
+                Fq::new(BigInteger::new([4842387492683177816, 6050352402176266601, 18379923414235415256, 2103330408780611192])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([10930743118816828822, 4836254962731845342, 13386715913880829890, 3449196626354112812])),
+                Fq::new(BigInteger::new([4115060329388657153, 2691349213772033315, 8468441734432720070, 393024560929807538])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([17491073605300066999, 12374181787111602397, 3310614390848256054, 2566756286648357583])),
+                Fq::new(BigInteger::new([14488769294837397825, 11162575785434881858, 2354822922644698732, 2685645982368466297])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([16585445372579437544, 16496165642158784098, 18284688408846150395, 1366923993847707843])),
+                Fq::new(BigInteger::new([10034023539093079833, 3300284356281274465, 9634645323473715128, 822314217940182451])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([5657919258648959496, 8330187292415859252, 14894645249492857760, 346389145328233180])),
+                Fq::new(BigInteger::new([15529457529246879615, 5203513329817159950, 2154772461150181976, 1860325840552338947])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([17442051985158487231, 13191666045624086500, 15989000777274644039, 55938840074173085])),
+                Fq::new(BigInteger::new([6648551415028216934, 15808832679867023428, 8722783616214244735, 2616658201109705013])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([11413681450795363078, 9785505681245014371, 7589488964769061761, 1156153287514574608])),
+                Fq::new(BigInteger::new([5969074422209664465, 8081272480641921873, 8333012270257933379, 367191806645515428])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([18093287411265672136, 14054245562599139093, 13468690202277756933, 2684554296450725759])),
+                Fq::new(BigInteger::new([4416486606172104721, 156707973112158349, 12839711410282112211, 2226013071415508322])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([12682489269962854905, 16824224915525443308, 7387117328999969873, 1782947511364345224])),
+                Fq::new(BigInteger::new([12674023883555053928, 1877155176385138230, 8129001858756855508, 174929245818636123])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([6149303126732021826, 16871848808494456615, 18154852451912700150, 368231907608031927])),
+                Fq::new(BigInteger::new([9096201698324407486, 10647414267901836674, 5070263005623222743, 394269394853386866])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([3921471584801035911, 5336417050830492914, 4041225236970251175, 230596659863933236])),
+                Fq::new(BigInteger::new([10064667128112788863, 13619661021420035611, 17492908552561417994, 1588570824912994027])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([16295727165507424234, 5442883708734855849, 9010382058913893991, 2636546353399730953])),
+                Fq::new(BigInteger::new([8153854044742194299, 17702483250707579790, 15257096923174263380, 482943164372811938])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([13695265491412548812, 2014739447989618551, 15442266230684103505, 2312467688795804082])),
+                Fq::new(BigInteger::new([12198769724167504159, 4695665956677054087, 16257383090424901498, 73137579632745644])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([12604830806099919812, 10341659208638703968, 4715092694613117954, 2252212058979389689])),
+                Fq::new(BigInteger::new([4063839944820304769, 15606873841546118835, 11019661219342858171, 2412281499678895485])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([12359356861293099989, 7541015466639871406, 202522410901918899, 2053607866280231830])),
+                Fq::new(BigInteger::new([2646422762882930171, 1703283668590915208, 7231481797953857582, 1418069008007405615])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([9359951728652651652, 14311923755832007955, 8095244228567246267, 297648245081398567])),
+                Fq::new(BigInteger::new([5516263468987976005, 2619328180994503975, 14612514497389101479, 1156123453738249187])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([6392701072502177839, 16839976837721034111, 15434021158493199113, 2900506049010682213])),
+                Fq::new(BigInteger::new([8181137095504079422, 456145147625142811, 6773333392915502927, 223294458548052268])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([7668325773171527475, 11012888271552093469, 2190111795016000044, 2691248365941595921])),
+                Fq::new(BigInteger::new([7233276232624386698, 4806289138818436103, 18227073243270833238, 954961197068429040])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([5094392262259944328, 7538604398326339055, 11964802838651885235, 2988491052043943550])),
+                Fq::new(BigInteger::new([7677780103699655740, 12512388351282840101, 7520425191445583702, 574274982166178976])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([3292190221894984813, 9673687116443712799, 16182568699702063787, 1036629547291037050])),
+                Fq::new(BigInteger::new([6508189165774950755, 17304081078982674826, 1744385817396092561, 519120310734502041])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([1274993171084853464, 7967006124716820138, 10350847141168421168, 630651952581944564])),
+                Fq::new(BigInteger::new([2173795943083822399, 9796971699898411391, 12980083698606452397, 2301926938223365956])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([7660748632341726483, 13765948281095166687, 1076421962536329048, 3460082009463404434])),
+                Fq::new(BigInteger::new([17017505331450601539, 8535539727270518469, 13989533118619043488, 877513886888464603])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([16666481777049982706, 16583560073503566205, 11259108418742685048, 1211229747268271725])),
+                Fq::new(BigInteger::new([3700643420050873114, 11532215811732949542, 6529801237068298596, 1628946573736864247])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([1941119241956067146, 9794278293805066317, 12234531242204015718, 889738302251577742])),
+                Fq::new(BigInteger::new([17655523590644634776, 18319029652694614964, 647409683181791873, 2809368160348500177])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([5806746188336998018, 789647083593443066, 1279225873083735973, 1857140424819040645])),
+                Fq::new(BigInteger::new([8599094410912028593, 11879892151548651811, 1975292755700893784, 1128990875913085272])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([12733849864550642143, 17862909503307199729, 14870877281554489306, 2232907343437304434])),
+                Fq::new(BigInteger::new([13215449913387452482, 16734020651497143762, 2510352195829308509, 784665111547340887])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([6611994683751008164, 7640655260473058785, 10855722231166561246, 2569367226792671779])),
+                Fq::new(BigInteger::new([15445105545947903081, 561012323665207414, 8903985768756512210, 620858988042531841])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([11270433424507052921, 442030780252808190, 17344155976380380246, 387106294959485775])),
+                Fq::new(BigInteger::new([8809366779374087350, 2522401842063370842, 6723269951748485582, 3383476372147403359])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([17604561634616946814, 57872299746264540, 4649812565810666180, 2469600129948475444])),
+                Fq::new(BigInteger::new([4628969718430962779, 873162700294314455, 18260394196555508874, 2208743682327827887])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([426588562992612101, 1617007727872932012, 1795163317886883223, 2937454389649158949])),
+                Fq::new(BigInteger::new([13958217953706789853, 8248175911854862172, 4176543980908226469, 3142108813900168222])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([8676770541664819304, 3086791404952445954, 10960094184540135450, 112739093243890357])),
+                Fq::new(BigInteger::new([8916967501221001338, 10698546921720795233, 5561699706114413460, 1514064790037774689])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([3532627116620950087, 18341978503303319558, 4593400907458913871, 1767302481986814191])),
+                Fq::new(BigInteger::new([8847416875603752403, 16473008003260727374, 8983663351113356738, 2868737993513274101])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([314985360182273473, 1675909805411402683, 10840932593638610765, 300640236211401637])),
+                Fq::new(BigInteger::new([3063948398448384005, 11896849732154932812, 5967180814595909766, 2235026382970004802])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([13748820212488735974, 10272024124032019566, 1960576766929038577, 3392996676389933268])),
+                Fq::new(BigInteger::new([9499053459826052780, 12050316248250582859, 6506922594944845205, 884430117007611047])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([8838082437195997376, 13313084447062283592, 7856073941125333077, 2392537239804881275])),
+                Fq::new(BigInteger::new([10710059788406096962, 16080163392441625095, 13974083697334749575, 804863177962472417])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([14811152125277519530, 10576489054696522985, 7503793982100852939, 2079757465715282986])),
+                Fq::new(BigInteger::new([4495643438333234280, 1584484331328092942, 613580444461488127, 3103512548069817952])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([10299789968609550173, 15571742597469610859, 13744929272861931047, 3479454020874653251])),
+                Fq::new(BigInteger::new([12386417264509199428, 15146987237948406455, 1856180443100173315, 1447465021045482569])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([2579333544870694226, 3927266729464904916, 13451930743777046293, 727839243239767242])),
+                Fq::new(BigInteger::new([15129236344395728848, 122950328511231567, 7163471293853996004, 1350000706817506466])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([15508694773120801888, 15429911739215386548, 8593150427857352102, 2210764060391930500])),
+                Fq::new(BigInteger::new([2974456371645422606, 2290179130212032811, 7070758803936237200, 3239258296245867985])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([2390608053052934075, 11119164041542914724, 12636574419094619678, 2362862994032832719])),
+                Fq::new(BigInteger::new([15158118896133991672, 16296644142241315124, 15522383403345413375, 1941468632348232381])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([1897396394097251338, 9025969316025293975, 11962384854596512251, 2622032714378645089])),
+                Fq::new(BigInteger::new([12194246914587275576, 7141165637008832815, 6455908967632180897, 1733234519669190796])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([3455368417161770174, 13892514176528770358, 820214103812945114, 2403163012519191708])),
+                Fq::new(BigInteger::new([5055332886474279154, 15278045983700164154, 10369696756074161830, 1009958054762550477])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([7380749886736613066, 7074515867297113709, 10334984355135090748, 1676763635577078667])),
+                Fq::new(BigInteger::new([15637088376823307863, 2296960185157011413, 1472266892652301536, 1662137141571040900])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([17932474879702769204, 5588285309512284850, 3031437171908523932, 3349758087574945473])),
+                Fq::new(BigInteger::new([14030034927976884134, 919515194673366864, 3680668199929768439, 993811029797966417])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([7440596901286161036, 9035068080079051271, 13130495495278426414, 1957967812359325700])),
+                Fq::new(BigInteger::new([11551817849558504965, 16044611170217734412, 13160720480566008637, 1755864251504968925])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([654316266674308912, 11097836519599427988, 7752201591914882070, 771659491611144231])),
+                Fq::new(BigInteger::new([2764189343336458039, 11437527084533155913, 1613068877936092030, 487792067950781907])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([1434482683025568127, 17221526144261638531, 5929014545720597259, 1645541391928806455])),
+                Fq::new(BigInteger::new([3432785970861926088, 4167562409977918660, 18366916326911784062, 3356009141283993283])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([1501933410412791721, 6345181834788522482, 13728612316911790150, 1671734280484611552])),
+                Fq::new(BigInteger::new([13705566485741188681, 16872405553754183213, 5475112509313528221, 228388007130710126])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([13247237826784337249, 9415174244682001877, 13427242099533300075, 1862105693389690865])),
+                Fq::new(BigInteger::new([11010572031913357656, 6109885308503471794, 2241417388545681417, 381324297769557773])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([12125114179081698953, 6574900025068776111, 8564549205765013386, 741845642509381639])),
+                Fq::new(BigInteger::new([102038965350315035, 3307182705321110768, 8065149847979489380, 1721384795871698148])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([14588267516555140427, 6917376221926316400, 16732988650094517792, 2095855430705682852])),
+                Fq::new(BigInteger::new([12553310531368712591, 553452522009244939, 2932003992137752846, 545758979004441248])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([17570316721623778256, 6488519816204123884, 11064745373228654611, 2889008498761137011])),
+                Fq::new(BigInteger::new([209971179363994385, 10536038731908167783, 13783517883793519274, 3352191360008908817])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([1457829619413321076, 8482615718345079591, 13622161563039047635, 2529631718003906165])),
+                Fq::new(BigInteger::new([15249173835149652601, 5425199488017799711, 15817422849777642897, 2648559217214154781])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([13486484851919800949, 374147384794764296, 12082941979776813648, 677906312503042801])),
+                Fq::new(BigInteger::new([9175225391641832434, 6725818145823063295, 4743967193110814632, 1891743665866317452])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([11715648294912962560, 12555075985828027659, 9453571241407413416, 3459327663670364352])),
+                Fq::new(BigInteger::new([13733683113655189793, 9355805448947679046, 4612689965456070634, 1216887893751390906])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([4854495646788386445, 18347366436805889905, 17155506168231511031, 101261086203296594])),
+                Fq::new(BigInteger::new([6789006663044657591, 955656255569878383, 4428549293901320877, 1775955749488336057])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([15652837588151245832, 14381666282253102766, 2173307092053597354, 3388782484908258427])),
+                Fq::new(BigInteger::new([18181692999253728199, 8784927019031142049, 4118515483064860401, 1072521752223183147])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([3057228697087765224, 10042584715089142381, 11764298159600439685, 2449961333390497470])),
+                Fq::new(BigInteger::new([1092977019252747389, 14764722390384216032, 13659559770859939706, 2004386545739207658])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([2972167712318684889, 4865616292797514883, 3157399494430765111, 2095651419831378444])),
+                Fq::new(BigInteger::new([15043013678282645661, 17463522683472977914, 13545875678393259429, 1558469275158505481])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([14086379548647203472, 6215324132038767429, 12092861972710280414, 2691144505241864809])),
+                Fq::new(BigInteger::new([14107913999999900487, 8486200710372577839, 14953880201166914271, 2938733575627759315])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([5915517644204721575, 16819352197380383005, 14974543202147025397, 1772000684247443883])),
+                Fq::new(BigInteger::new([8140051767970127979, 4810804564892599001, 4320226048199047, 238946107738046346])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([11910363055554051328, 12776368546499473052, 10158882995978709931, 977363801770510803])),
+                Fq::new(BigInteger::new([15280166846874469158, 8391117663263712161, 7177882353504141190, 607408410844823274])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([6026960366633317521, 2307602483828038897, 1618414043176960221, 1987364976345196001])),
+                Fq::new(BigInteger::new([11738102249825844030, 9734767274678903178, 6074755857253370096, 2244662886575828761])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([6465561999772094004, 1991073044729224822, 18336420922094783567, 154494035459860127])),
+                Fq::new(BigInteger::new([8695159750637307731, 13241888794593698695, 10544258862229794533, 2387063108904378971])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([2504670031509133917, 691683907014325548, 3264716196174656524, 885029405370594106])),
+                Fq::new(BigInteger::new([17111505485710303181, 7736865561266679049, 8448725402990798718, 2127287518331231569])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([2952460502677961411, 12397554548830671409, 1038567427788641464, 3124449038642363820])),
+                Fq::new(BigInteger::new([3623710985134424862, 9422881810637794892, 18388036584980920497, 2307599082016315967])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([16856835167135556546, 747358480681043261, 18368889691631737510, 2702573842607961593])),
+                Fq::new(BigInteger::new([220840250728562066, 3739423577768432066, 6965636209795820962, 1274473989926767561])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([7793912613181744044, 16237367757895418944, 12175452610313221483, 374415163828391969])),
+                Fq::new(BigInteger::new([5131891538845296023, 7322480928085634400, 3346796089954091344, 2479761180111257051])),
+            ),
+       ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([511890770297423673, 30286792583790190, 16438581147300862507, 2206385609647494606])),
+                Fq::new(BigInteger::new([8606143112190932259, 13747400949520676050, 17792444089872379779, 3000731928626707131])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([412103270665968269, 10593633596390326517, 16653910048110095484, 1780919997478424303])),
+                Fq::new(BigInteger::new([10082053938147358023, 10303216608087145191, 13832077337142282000, 1873443118975171416])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([13019837702663802634, 9596134519385364633, 13508261406542027778, 2636482431277811936])),
+                Fq::new(BigInteger::new([453525816247757680, 5569088094233013193, 7075245904394004932, 2053880935031337516])),
+            ),
+       ),
+    ],
+    infinity: false,
+};
+
+pub const DELTA_G2_NEG_PC: &G2Prepared254 = &G2Prepared254 {
+    ell_coeffs: &[
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([7465403023205272469, 13909528479505583793, 16359928329550713359, 736542877909539431])),
+                Fq::new(BigInteger::new([14348799136310682425, 17092593753038228891, 6252883487549491715, 3458998400691590134])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([5805296390256089659, 14298654350944787268, 13467106669105974440, 1816890946958692249])),
+                Fq::new(BigInteger::new([16323222340165166717, 14559749602190429446, 6304507077643881031, 3164485244373099756])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([8744210127329726600, 5191351205753752228, 10538658506868395536, 1773773852195046851])),
+                Fq::new(BigInteger::new([9989864185723082284, 14971663752423922282, 6246293295182653296, 1323655868231226610])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([2381971731732939810, 4824329585732994354, 395646506928656566, 932187847974115490])),
+                Fq::new(BigInteger::new([10045825009976921474, 5417944157615319580, 18190808788799454732, 1815364552213084593])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([986807581767740100, 2916915582915496251, 12916294185621462991, 3298134071927959517])),
+                Fq::new(BigInteger::new([10422682959134342613, 17655402570524454354, 5189180551180672468, 3053258389281237865])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([14540242866222802676, 2832253072647102442, 2538096168251060878, 1170502506694603909])),
+                Fq::new(BigInteger::new([9583142002341803954, 17203030964549802671, 399012438318222639, 761315679165066311])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([14587383783820768937, 17583468808771536508, 5976517869926403361, 3005785102667639910])),
+                Fq::new(BigInteger::new([6521590344905733560, 16601788509023368239, 10345932312464709390, 1928319589377354169])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([5251157466671211439, 3795393559949237029, 9194601860152401013, 527549634281483503])),
+                Fq::new(BigInteger::new([13942002422563967253, 10124378999250545942, 4429742824164695700, 1596610134806539966])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([1915032138594181368, 10551204756625384329, 11532709005235121338, 3174137139920727017])),
+                Fq::new(BigInteger::new([8876260403828320029, 4086395144820504466, 4821270860369293653, 3246159249872903380])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([14393767398982741070, 3810348385550652523, 8748136106152843285, 1572821930530301280])),
+                Fq::new(BigInteger::new([8600366842442319975, 5420143257076582547, 14611978382610906126, 2674039178904984484])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([9229657184309772362, 15686993659241556739, 1135037280910692426, 2813768977060116068])),
+                Fq::new(BigInteger::new([3389036975521245449, 9479401062237219798, 9357127138065890017, 735835054622225449])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([4620711783344869835, 16554455490662577914, 18100801541362097785, 2153748896749375405])),
+                Fq::new(BigInteger::new([517170851299782000, 16518565026071674392, 8761737747441046373, 930714557990575157])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([559142875336577297, 2601359611282031095, 17084663678195037681, 2467031668021873829])),
+                Fq::new(BigInteger::new([18227128546160851201, 8515877066426486554, 18120988628290890408, 481281574208495869])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([9493165135122163641, 9038231872599525496, 1297007279318590060, 3038655938204398324])),
+                Fq::new(BigInteger::new([14782507751070932902, 17248988776056313088, 7373487080545447928, 3335026330011133935])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([6697411335293313483, 9807411664206302053, 1301536547009380759, 2659067106698187330])),
+                Fq::new(BigInteger::new([1101175830232953628, 15300931987906291743, 2361389139028629875, 214747778920878753])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([6915982197819532014, 701742430312633368, 8181961989492043109, 2178010322292838124])),
+                Fq::new(BigInteger::new([3438320880002564041, 12921941529037035982, 323639572693139033, 158030134491330922])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([5368797334772015488, 4546237528694819006, 9012193717083867663, 779284777952677993])),
+                Fq::new(BigInteger::new([10035681684408557082, 13203572537694730559, 3437897989935888753, 1798822869974120270])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([12787691444663242206, 14705675309209221546, 4494433531898640045, 3250895726578697279])),
+                Fq::new(BigInteger::new([10151642784066967865, 17446345886446608757, 14848802556860741523, 1179620465143809715])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([5077821589876766803, 6992861166724159579, 8045913799172133631, 3033214902029678867])),
+                Fq::new(BigInteger::new([18353506165298354822, 11124320260526695919, 15290565095305978035, 2764557372272989421])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([6359155685099253609, 7657349540954778102, 2011750382904485251, 3002862714702461779])),
+                Fq::new(BigInteger::new([9814717326571379893, 14286386727618252709, 8731200977037399108, 2122597474313081297])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([11333138058524257668, 6077503174362749494, 13075005325662043369, 826461106619441513])),
+                Fq::new(BigInteger::new([814583690943389774, 17560058486681077544, 2685072410126450974, 224071824374360613])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([12126478301524055678, 6578144228583172363, 14624034389664052404, 1121539495935823216])),
+                Fq::new(BigInteger::new([14705089242847274262, 13731036179922810873, 8833844790692865845, 913510295270125636])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([14888108122312554842, 8711571164250674980, 12048847713619184078, 2223619848830944958])),
+                Fq::new(BigInteger::new([14182580952708542766, 12563693092029518214, 15420843640456755446, 18199570573499933])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([5110344208765097052, 8003816909135092790, 4458777284688366303, 401405727441527187])),
+                Fq::new(BigInteger::new([17716872824369630840, 6417147298428392746, 11070983675675822239, 628761400910371834])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([9665155530017481570, 11175538534572899030, 18173208562247175459, 3316753459877892048])),
+                Fq::new(BigInteger::new([3477880573223233795, 453865727474497337, 1971085099738824232, 2167597765843894072])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([12079347705322902828, 9103405071560854067, 9730116104247276032, 91448331100425971])),
+                Fq::new(BigInteger::new([5961802524844398955, 12738604985920141752, 13443092536286292923, 2358232376962294524])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([13328620965857454949, 1097451931343912551, 8052078406407400338, 3475238347239142245])),
+                Fq::new(BigInteger::new([6133341174737869433, 2256247881775494860, 17234427411700269080, 2585713551927375323])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([3191908373494696202, 16879282369316471508, 739709972773640392, 2414416614747648310])),
+                Fq::new(BigInteger::new([11875591610956375957, 9288305223797129291, 17536023082064563743, 528817106374493354])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([11274879117644967287, 3254741042415413042, 2682294378813112960, 1802889798323467938])),
+                Fq::new(BigInteger::new([12265855812992415357, 14088443275340154557, 16618172241270921307, 3458781650193902713])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([128964161796045985, 18099701519593772075, 13003602329676108567, 2839052642107389565])),
+                Fq::new(BigInteger::new([17447058655747957385, 17636779479348864398, 2276620048315947206, 750095225639722824])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([5132871591836181207, 3269044871380524065, 13093999576297672996, 3008273645450612574])),
+                Fq::new(BigInteger::new([13795663138339629894, 13413191051707174270, 7907808316180001768, 3054424625156939256])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([4450471092435593769, 764871392349624415, 16455076411424796413, 2615547771661934941])),
+                Fq::new(BigInteger::new([18187144091167114380, 16177067603605203869, 11974040410903486070, 1397508107552582430])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([12881147443832850353, 4422735012766279090, 15737187527087529153, 1643520214662869005])),
+                Fq::new(BigInteger::new([7877998583607036472, 6778463451682375108, 3883747256617690711, 2955262815982604970])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([6181237893512592155, 11059899962378680373, 2986421518022541610, 1265555921587226279])),
+                Fq::new(BigInteger::new([16433679800662119880, 15972042158266333203, 2008676211607813033, 667810506239774873])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([16885117386482354184, 13569472137676123355, 6292361577748798923, 3188106158367241978])),
+                Fq::new(BigInteger::new([3199400749836044039, 10720524516245827718, 2401057122241108342, 970558430815519384])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([9890832664195408300, 1164518781082949870, 7894832952178645549, 3394447906144911293])),
+                Fq::new(BigInteger::new([783920774586502530, 12106885815567643406, 11406715459952998013, 1818404944303701028])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([10298242574299442481, 6430688491722923765, 16204347734113528167, 2072062468706261704])),
+                Fq::new(BigInteger::new([7406538784694202704, 15940160640375795742, 13614335746678474384, 3417871913021135367])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([7694902613207670595, 3176586932919471842, 10109675987033732996, 229794520340775677])),
+                Fq::new(BigInteger::new([11748370726135352403, 2501602488532153843, 8337389780943285716, 1702913393151311330])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([14032433323674464168, 92923605350454566, 9448461858001283059, 361757529900846219])),
+                Fq::new(BigInteger::new([17014268886153131557, 2846767463770860643, 314892792714056917, 3016216322309768916])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([8327001118217919581, 17304316604601950938, 2032161838231047233, 109583433582833120])),
+                Fq::new(BigInteger::new([17464412799824261257, 11204815386604757162, 4760230105865214587, 2561703737171455642])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([17162454351036118659, 7119597965182222675, 11825104069008636226, 939894676289903357])),
+                Fq::new(BigInteger::new([7877966409105236777, 9154224566032751510, 8849012417765966290, 2350511482299937498])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([5607243506577678311, 8379969857087309214, 1250039348953869625, 1288448394538626728])),
+                Fq::new(BigInteger::new([12717436279047444490, 6980954052911767105, 6466940135019101723, 2485571152774809220])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([14982866992636828351, 12670314516462942893, 15024985549538433377, 1498628071832461640])),
+                Fq::new(BigInteger::new([14735061210474947771, 4023093023679641276, 17631937837722737973, 3368416500925754926])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([4081238824565666194, 3989614552117312364, 11286433265037280775, 1474216756025914123])),
+                Fq::new(BigInteger::new([17827622432297850659, 16742664895463887493, 12619505726984346419, 748171743558836163])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([14175021036779920144, 2921417023606844771, 6519885835058209749, 819189301938962553])),
+                Fq::new(BigInteger::new([13574749996199402572, 5935805047237435950, 17126638079724648313, 557584257421266524])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([15840275989288252467, 6407474783554325559, 14143833380875446765, 1399459694988116623])),
+                Fq::new(BigInteger::new([17702045766593827156, 2540588671076906105, 12715318362748917716, 1128040588803847592])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([15623919018618957026, 7971087015010413379, 14746298036002375799, 1125947203239896162])),
+                Fq::new(BigInteger::new([2722295853447777537, 9733714531718594711, 2257916321693467098, 2233384525852728735])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([16020781098040327700, 16644870341668120014, 148202124501215101, 304536710273622604])),
+                Fq::new(BigInteger::new([2664020757165829734, 8643371317282818104, 8203070889784037788, 3126882526872099877])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([16024564940273431881, 15080228744420274799, 5373800922259381105, 1339806732461663760])),
+                Fq::new(BigInteger::new([7378772566210253513, 12936839695518926848, 9171496457882979564, 1333438994293065165])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([11223413455057113146, 17743938804446823985, 7485125889537826632, 2423715365846876220])),
+                Fq::new(BigInteger::new([9336456450246914278, 10004142161762390818, 10979682016082939923, 1110595221773228998])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([9686406932527665931, 6861253133014259239, 2210275853428223964, 2873692081897263621])),
+                Fq::new(BigInteger::new([3501002177385335470, 7646928098849921088, 7107580374028462989, 2378913891202140194])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([14274448588492153835, 954260495150896250, 8975836328444118406, 3171562468894640639])),
+                Fq::new(BigInteger::new([11689762137958958829, 8959719957401393760, 5554524895453403677, 1988348245101845834])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([17405833369749486270, 12292387580077361236, 3060540291260482708, 2087488326923196997])),
+                Fq::new(BigInteger::new([15108046972742425146, 17065714787168224897, 11696439277137030188, 1428716435962999322])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([488583077091388577, 11253237152616447614, 10180555619922604956, 2569263992323011891])),
+                Fq::new(BigInteger::new([4394399138113721296, 7861937262079295697, 10541068830135290492, 2870513277226189373])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([3572425638076844348, 10764656924618626069, 16439038681707439250, 610748596122108175])),
+                Fq::new(BigInteger::new([11090028454205407568, 18274029573794266784, 6211689729631605122, 1221245998612757356])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([18354092326321453955, 1523818437228802337, 6541211788382344186, 1321153100749339699])),
+                Fq::new(BigInteger::new([468327242828789123, 15743186101359223889, 15533838542690090917, 851147901823380878])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([6552794325234325954, 2649823110352850351, 3120272321913035273, 2577556024437868309])),
+                Fq::new(BigInteger::new([11856106359824772853, 13821268215054867107, 13841299939599402693, 1969783089800628299])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([4890240353011681070, 13542190769313807080, 3738686194326214736, 1083311580557433289])),
+                Fq::new(BigInteger::new([685758364766884187, 6654096586452201959, 12367435229074222043, 1922956976948156480])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([2367495000584160002, 7354280023712457152, 7147983012565280172, 1609714476488001079])),
+                Fq::new(BigInteger::new([5372539531551991515, 13170876724127803618, 7178296386122776202, 2163020820865761037])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([16797214870742990813, 13466834998267476395, 17551424081609273577, 2774322482273442922])),
+                Fq::new(BigInteger::new([2255981525115770504, 10689343425898912172, 1048871974150057852, 848518807040717704])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([1881001950325278452, 14027291972730414063, 430912602396680184, 1926809717596214069])),
+                Fq::new(BigInteger::new([17936332738206672920, 17903766828632067357, 8041333665881813477, 2884109813984320951])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([583013416546691777, 16331446457218641660, 9328105671605028905, 2339231651033090176])),
+                Fq::new(BigInteger::new([16451294081512922155, 14341021519473674660, 13418840526896454962, 924151640000272340])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([28334468542189791, 14210875672560930047, 8523360835352449184, 301379068041868142])),
+                Fq::new(BigInteger::new([15643474635679040826, 3171960808267358489, 909048796209709865, 1405122554308440253])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([2301242503823855857, 12509678232041299226, 15555500093839644275, 2890761984578167320])),
+                Fq::new(BigInteger::new([12335109055255344477, 9449249421953982856, 4766901404824450333, 961070977951730120])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([12189367780958881282, 13868515360535481620, 12869276216782405590, 2133381312916080132])),
+                Fq::new(BigInteger::new([12983984035506003327, 8872042773982060286, 13086389401231439651, 2611229073630387970])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([5737340650551979410, 11677490819534503433, 15162111710724188609, 2217083431163922583])),
+                Fq::new(BigInteger::new([8578127640159882529, 12993463185869404585, 14566588406307318289, 615274705556045163])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([8655116835477685097, 6922928572040637233, 3499422801124119535, 50799410877137145])),
+                Fq::new(BigInteger::new([12136892649795763971, 17426460410809581610, 4988616304027559321, 2772228711911005272])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([1712215283794324540, 17792717211235935107, 10884357128431333002, 149927915026253659])),
+                Fq::new(BigInteger::new([4628649548325239498, 14276525436665018415, 8317587432254953605, 1104759759916751625])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([10039857603113998597, 4456774724419980197, 14000333128081613838, 888172471363418787])),
+                Fq::new(BigInteger::new([2102470925387215259, 6963927206451387258, 14600594234909552895, 1982656657741142103])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([8707175715993436289, 16168937994479961646, 9250085841285329221, 2402826301870243161])),
+                Fq::new(BigInteger::new([5141500710792421117, 5446139804323027896, 15294729123160551129, 3386363072237230188])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([14550437119746099837, 9167984859462985368, 16816562032441432563, 1057025515798550962])),
+                Fq::new(BigInteger::new([14319734081540279875, 6188947327225723774, 10947384693122477577, 1090069676544964807])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([6748273661382137044, 11404626363847331198, 1349738579203728751, 1447873308181219037])),
+                Fq::new(BigInteger::new([3529852182062360901, 3160193257903911005, 10196366570066469533, 1848960700617940671])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([1697495186313108949, 17617702428369409420, 13903357293890765085, 570175822867004345])),
+                Fq::new(BigInteger::new([8382334472793852455, 8612760833279832715, 7912439582814695342, 2177549053999274050])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([17412141503125041747, 10799980480925890721, 13558114963763912247, 194527193473072826])),
+                Fq::new(BigInteger::new([1975091347989361872, 6940167368250489556, 5244923894681170691, 564296292256371325])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([14719068792728338912, 2642334267751305365, 12434432759860982544, 2645241985584970340])),
+                Fq::new(BigInteger::new([15040480098397853558, 1640401229228838891, 8005985271974158253, 577843956501430317])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([16771491229559672992, 15641690556265669125, 16689197832106242537, 3453004929051855482])),
+                Fq::new(BigInteger::new([7957549596588864491, 9184201980629152915, 16189907253526412344, 3099180228442835222])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([5085748336587945410, 11538926475482009973, 10281381078950056507, 19399637515990178])),
+                Fq::new(BigInteger::new([1619226636405815704, 8075308844327497964, 3981193656822714065, 3200777728724048871])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([14341110082212370359, 12276915339929919421, 3797901804749797198, 3480836957503714119])),
+                Fq::new(BigInteger::new([18180517817250597739, 14172381937644001111, 13508111220017765599, 2672297842282008191])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([3502951942047662171, 13133009664570286022, 2817748585483221919, 2261207473138639997])),
+                Fq::new(BigInteger::new([8823599854745040277, 10463986439686770127, 1706246851662337084, 949347374874696994])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([2670716442742243144, 11100832743243417765, 10404736981546430787, 1049436059380557527])),
+                Fq::new(BigInteger::new([8723956713728798840, 10693750153164789885, 11715244501481133025, 1139449130906521042])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([364539694035004401, 10393714377994968122, 8261684889322856639, 2285564223630814446])),
+                Fq::new(BigInteger::new([14129089575612231801, 18330944924475902197, 2607983032650825591, 368975693018665342])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([14616156733005241553, 1460788771012978547, 11636712568460947269, 802581810902658492])),
+                Fq::new(BigInteger::new([7926521660200020037, 17126277298170039809, 7698336420123563232, 2993560011314502909])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([7974965710138459287, 13323873964567958150, 7052716833882660810, 670565382067617853])),
+                Fq::new(BigInteger::new([10174812129010119003, 7761571081266884837, 4166909755403621194, 562356750013186753])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([6762487034398990641, 6328126706435645381, 159386729316108217, 3208598759976493069])),
+                Fq::new(BigInteger::new([18415133391226886161, 7206088086988014421, 10977042113302390050, 498726038635765804])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([14188157397669577364, 8899215240351637848, 14274793394729286064, 1762787585192840377])),
+                Fq::new(BigInteger::new([7133143287921253880, 15229894362467136983, 462020782686406179, 998596560462087457])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([7331678090675661760, 4074984221031039466, 9614111614491625530, 2262670040007996057])),
+                Fq::new(BigInteger::new([13783146913182325483, 7179316941854758466, 16657901154399895319, 950591774478919167])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([7879781991484201971, 14005984353755752002, 2394397792779360865, 2023742759367064837])),
+                Fq::new(BigInteger::new([8198004013467198975, 4248641668080390387, 188354685385210325, 752004641786902403])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([9857750747435977208, 1596474677766303419, 4753492222594213411, 1141974066615201660])),
+                Fq::new(BigInteger::new([751261925284301437, 16590123316266788762, 15729471525681413089, 2186229939387822052])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([11338788426568238652, 16977921763670856422, 5090253495263930411, 592506746923567500])),
+                Fq::new(BigInteger::new([12568827952301326017, 7380439681847069903, 11564499000860354593, 3119440916239744637])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([10676041309207878309, 3669942307759108313, 6396174457305985174, 47807119096574500])),
+                Fq::new(BigInteger::new([9208232909968384676, 11174025918761939812, 989239138990698055, 2807124788434374493])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([12982735910328203010, 15227357969743960981, 15285441265402481610, 2181766018054910244])),
+                Fq::new(BigInteger::new([2307037031342411742, 179627565254493698, 1635255369183525934, 3377045571621487970])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([3985720104839644657, 2376672064053957898, 627655293028247677, 2666626068724617705])),
+                Fq::new(BigInteger::new([13804326207415045944, 2547467276534863627, 9921550687299882537, 2682804767093693094])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([1426911524271513203, 5824972970902155027, 1160267341119169230, 775533448793179920])),
+                Fq::new(BigInteger::new([14730651404953469959, 3000243212234593093, 5137429152538084608, 2453637109131505991])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([6149620642100653216, 4298254769502364765, 12410100272339456990, 828548102665277738])),
+                Fq::new(BigInteger::new([14840901033149347749, 10183913332534644811, 14719226362246894756, 2897597184954499997])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([4558047459572331389, 4410087070208251447, 16054424411842087794, 2807046229919745838])),
+                Fq::new(BigInteger::new([17848967056657785194, 8016231380654392890, 14482126572591527052, 2956600261033457979])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([14198927669567930929, 15291807215954766443, 10936179087552875350, 1148345568205379506])),
+                Fq::new(BigInteger::new([7136508559294467349, 5061212254118197442, 10085361697754480017, 2123218657992668144])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([13794097658380244164, 9201745175945560689, 986410790431141489, 2281604388425452423])),
+                Fq::new(BigInteger::new([12865677028644488144, 4019209117718575469, 9037326801963460066, 686471936553425013])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([4791741997188768213, 6699389170666094371, 6945886859548765109, 3327620276199389983])),
+                Fq::new(BigInteger::new([13448926006779320489, 7603935982039004183, 1573738816556173229, 1664832342812510072])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([5070356240554912785, 12735703918790735888, 6810056977498100416, 561557927241743014])),
+                Fq::new(BigInteger::new([1142015161697483965, 879958915809892283, 11190849946505721919, 1129554095028134144])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([15316066769855472182, 7202324005418106987, 12667698633404789881, 2593525013844229638])),
+                Fq::new(BigInteger::new([895399636631759710, 12581920099862249489, 11271585805033580954, 2816943269928211779])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([9808319194354456841, 14795524304541134153, 8391876773378862547, 2554152819584289239])),
+                Fq::new(BigInteger::new([12924472501856182019, 16671618846508596701, 7119436578148462894, 878283759605037793])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([12170626251764673355, 2420310821589567129, 14539421949976390101, 1197585469487164099])),
+                Fq::new(BigInteger::new([8937580838411413506, 17468860019069073649, 10800685804395554624, 535692251968891020])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([12168262730510397015, 3388186366163734928, 14448194778884384468, 875916773875797481])),
+                Fq::new(BigInteger::new([12360750717217228349, 3845929167291749844, 9328904045580698438, 1913224854652245056])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([6639787443250207768, 5612826062485172392, 5508605830066639801, 3384905178401811007])),
+                Fq::new(BigInteger::new([3428031587946801197, 11871108360491028199, 7552602531908832383, 1272112332276055877])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([3997755106630147488, 671821585827735258, 16660635679593178968, 2975202486870290660])),
+                Fq::new(BigInteger::new([5362207970907189648, 13885556134368016286, 11424282521078851239, 653914697326359217])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([17301068182149052989, 9034493436377260010, 6060967591844743074, 2982970926455402950])),
+                Fq::new(BigInteger::new([3096534403921077462, 17550288464171652679, 614248056319968922, 454763131400897481])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([1980044181096909332, 10515520667128512211, 11029554645726121705, 2059709063001281400])),
+                Fq::new(BigInteger::new([5034055028293935490, 16277265686123324214, 9570737136522349051, 1228982010747871158])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([1148015906625065533, 14935762559014303327, 15949896892242973072, 2867361586938679482])),
+                Fq::new(BigInteger::new([1918592872020506570, 11695919321970898492, 6640556270569699267, 2483399796487443872])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([2581921555445488555, 6500617709741746742, 6616271351900754499, 1253255783804722228])),
+                Fq::new(BigInteger::new([2738823595782675911, 3203013988597144412, 5807925294374216435, 2078113785545885679])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([10286939490402685166, 7250632652214814419, 5743867084755689092, 270265084120256158])),
+                Fq::new(BigInteger::new([9392489577343787033, 701276858569119730, 18216251805428388642, 2541173586768318503])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([6168718551906975238, 9770172265668712456, 5679812429617062549, 3020589353624286918])),
+                Fq::new(BigInteger::new([12298381544801932757, 16802534210946053927, 8151537484222300298, 855719121981615682])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([13494935421887642656, 18209009432241921025, 260791493322699944, 886007165318507140])),
+                Fq::new(BigInteger::new([8392467902069947463, 4618247837522773145, 4021545582382462431, 3118559159519517880])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([4006536864620571896, 11604863746429178679, 13664341596893801345, 1986945130965603046])),
+                Fq::new(BigInteger::new([4964088601604068520, 6610632193081224275, 1179775041977771607, 197492762319030823])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([11288377670525770063, 14069816366090693171, 899023744146748988, 3172455872958823801])),
+                Fq::new(BigInteger::new([9991211056255906549, 1435385971808103714, 12267856062084336290, 430973212622977680])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([12059297285303405530, 15970745889279084138, 3943911905162247977, 2868340302393676081])),
+                Fq::new(BigInteger::new([351753236969540042, 4271158924015698257, 2084689761811441817, 3463562280357850703])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([14952003317333984517, 10635448707385748341, 16253617188030548547, 3478176359744927927])),
+                Fq::new(BigInteger::new([330525082626718754, 14304343880864575329, 18189806503385269581, 3172502448617242413])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([13977520067079920877, 356935743603575465, 5934409022517077374, 1468466484346526927])),
+                Fq::new(BigInteger::new([15761499760293948000, 1344597785792189873, 884766636487040842, 1280356724297298230])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([15614877115381493730, 4623247219895862574, 11548096416909973247, 946145945332983579])),
+                Fq::new(BigInteger::new([2033415691566488418, 16201828480717182234, 8084403067935322469, 451603369826355928])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([12569759061060298066, 9508666045662284374, 7986884576325283966, 1521073646615718400])),
+                Fq::new(BigInteger::new([9856001893781463796, 15756739534495996855, 9372001032636439955, 688039546380030663])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([14904542299452905021, 8212964782110109603, 17139263522308377542, 1482097992743486074])),
+                Fq::new(BigInteger::new([13815592733216012398, 3679657010022460336, 8211093415805882489, 2251127964033672908])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([7840376946335423861, 18324843262871770301, 15035691978539718588, 1068948336227996716])),
+                Fq::new(BigInteger::new([16165431245485331621, 11766598491438584010, 3395491497724793203, 1178546426593688897])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([10064622175616702362, 1375147503300410373, 14260519379701695514, 596870098891523152])),
+                Fq::new(BigInteger::new([13400201144864845839, 15644021143734873136, 15468491302281913494, 2707780737709628091])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([3488901950172339566, 3305912009347549915, 8209323839581133389, 2596134315078415458])),
+                Fq::new(BigInteger::new([1029737920086059150, 4403896959069745415, 10920644812910945258, 1570782264453980038])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([8948078184971729469, 3633966455899094455, 12740124348635776294, 2072671399868876131])),
+                Fq::new(BigInteger::new([16649039891011054376, 8730313138258420926, 9592449095171580386, 1885408506169606914])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([1237661031144856, 16880321150944371232, 4678365094092475730, 1150581390847453999])),
+                Fq::new(BigInteger::new([14187895778322212342, 8021007496450709144, 2581503771869819652, 509141656703006297])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([10729256692498617103, 8008773372772309205, 16684684519267078568, 811333716320930136])),
+                Fq::new(BigInteger::new([16182242143925272609, 192202732713487274, 7348892640857342905, 1095170775081354042])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([574373504670645614, 533351659892396325, 10124433500624856623, 2822571517864061444])),
+                Fq::new(BigInteger::new([13927731029727973278, 3794675341338760919, 4854478896248608282, 117602530654162577])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([8585164765249448635, 1203402631107069486, 977219116574857958, 595742188770447705])),
+                Fq::new(BigInteger::new([10269028233730949965, 16824930369335965310, 10028332242406347358, 4475036332651474])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([7488728850159822632, 13129324659625795791, 14281282666643253987, 2300338393717756779])),
+                Fq::new(BigInteger::new([2755352651046662794, 11413055159945045085, 4140222320414775869, 3257726614650270792])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([14461626375024795424, 16125851086464961731, 11879967254206943948, 2968226497992580272])),
+                Fq::new(BigInteger::new([10654764721248012490, 11213656567532681789, 6481666582305865841, 703499475511583815])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([13800994725668278242, 10058439775359742252, 13909925168839512822, 2901592775486275564])),
+                Fq::new(BigInteger::new([1316652062084882989, 2874796829800935415, 15318523332680356378, 947978150038494640])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([3073168425910353627, 16634445742573584160, 239552941475823031, 1049400617420750202])),
+                Fq::new(BigInteger::new([7459913494139623022, 14887101171259289911, 3362687499625749926, 2002703377690960925])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([10638882742772822546, 7540160476598225376, 10180706668964420569, 3210775030980058942])),
+                Fq::new(BigInteger::new([17958123768881961010, 4481355785823468219, 4507041269093534502, 416067924685823781])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([2835833911578368203, 15352583404129482759, 186604322354261489, 2576589019167865394])),
+                Fq::new(BigInteger::new([5234294655448726626, 1075949561603773920, 14262274185657345034, 3054357023815671013])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([11267603310069748634, 8821362895625749497, 3455938847887071782, 3161032056843056543])),
+                Fq::new(BigInteger::new([5543713733846054761, 9825098343531091844, 14912574280601212743, 362565790608781108])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([11602907425669426827, 1575130017251912115, 7342378338420281039, 1641061966734462203])),
+                Fq::new(BigInteger::new([14327348426890311243, 3988158738142514589, 7546577900981014396, 1855991035749018846])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([7893217421532044000, 9824654448079824895, 3491657636889969332, 2194595096846960863])),
+                Fq::new(BigInteger::new([300617638693839169, 17967652743748433086, 9800636439587320048, 3307219326029935404])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([7403026766084335003, 11141696260577974276, 3121148962401876653, 355075485029167187])),
+                Fq::new(BigInteger::new([10044381242290170322, 9959219499564743650, 14467925338572116862, 3214080528034360703])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([16132503987171086888, 3837790513702802954, 6941859713039230601, 1981918622436057736])),
+                Fq::new(BigInteger::new([9048333353230740803, 2927681080335946413, 13299560062396100804, 2338374301230034358])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([4738935728792809413, 18040844402667564803, 1891181512869099940, 588851500650874359])),
+                Fq::new(BigInteger::new([16589604836463233700, 7546240808681151263, 8218946094824142160, 653728778770838974])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([7313679203304180384, 16756434091031450295, 7230451669091437169, 2104426762988813050])),
+                Fq::new(BigInteger::new([12944573798154973522, 4636744477726208604, 1832106954266952195, 2399798486872707925])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([14862178202606375033, 16573222788497193858, 11885256820722159481, 3398390832588523372])),
+                Fq::new(BigInteger::new([15106315835120399356, 10114232335940957133, 12630204840731385240, 3145443169658596761])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([7522582329970376774, 10397081908421691542, 12043571876349155082, 971448433803461657])),
+                Fq::new(BigInteger::new([2804488572692575390, 16820651413196439761, 12921273066228963510, 2386881679896937848])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([2748276457254005883, 12876413925557247211, 11566457584203176797, 2298627730363886077])),
+                Fq::new(BigInteger::new([899296161652581174, 9795308266793160369, 2290720914314601624, 1425515568858922147])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([688898589068563501, 17434427310614926261, 12298510131099276260, 2546709505998712003])),
+                Fq::new(BigInteger::new([1614377782754277461, 9130248472329113037, 15925921547113846531, 523137644592806721])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([5810650166038412842, 4725148940982856718, 13763253509993468334, 2127685020992193225])),
+                Fq::new(BigInteger::new([3767185594573261721, 7162186614782389713, 6804871202002013186, 1862850180241362238])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([17673741899645350155, 11011753836409402437, 9896429285668948688, 1691854453538114054])),
+                Fq::new(BigInteger::new([4722826722022910901, 99923817798332932, 301123161014038314, 2511200801384960200])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([135827056096217802, 3689788984884628305, 2439770075028470999, 2471258701108121698])),
+                Fq::new(BigInteger::new([16092260644969428508, 9939551862452099313, 9889733989144181333, 428771949226384621])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([17043325230311044854, 12984352398015713037, 8572179977481660273, 2749530024502250197])),
+                Fq::new(BigInteger::new([453738434576420246, 926126525671454623, 10556088135588805170, 1646964831080472399])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([15059721722644641291, 4215432510795204248, 1189586448788372775, 2192502786362131787])),
+                Fq::new(BigInteger::new([6837947423156680855, 14210239757360169444, 10410294030517553689, 2624168575916187310])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([10215507153820817564, 9530466503714379917, 4598166809088037080, 3397135451718858874])),
+                Fq::new(BigInteger::new([1697235471504591328, 12743232661199062348, 13229192157301354179, 3094724113480877607])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([750339342053736752, 11107412075326277991, 13695402915109780969, 187228311507342811])),
+                Fq::new(BigInteger::new([1266155738493246702, 5459113217776592830, 4240352521014520390, 178615856580786216])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([4575830128330877024, 800312733889602593, 16301624998990688926, 3468188042858728269])),
+                Fq::new(BigInteger::new([17056144696381527199, 14074294467063797581, 3705574141648115637, 842048284220356952])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([18197526030217570202, 1898554872293859106, 11506631854057416805, 2241396091773047622])),
+                Fq::new(BigInteger::new([6367513230217685469, 16003576710416790376, 3419251285683591921, 143228380714255834])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([14900479437695703840, 16816231238787689044, 12037378909959788533, 1416896734843504874])),
+                Fq::new(BigInteger::new([10582702951929723506, 15348003731132501904, 17832543413152342271, 1013886668895913855])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([8883114529688253351, 16681659598798414722, 15504478166254978989, 2158294551805984539])),
+                Fq::new(BigInteger::new([15474421610550198109, 11332053670165687010, 12866649497763931728, 2066420731853487966])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([14952423774089053852, 17256969650342913152, 7368718727839301389, 2568658610282757092])),
+                Fq::new(BigInteger::new([7975565815871487559, 13688258299498625023, 1000845371823688623, 2978217498659835540])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([13513611338118867682, 8213207671605996520, 17461056279121151325, 3174295837153625706])),
+                Fq::new(BigInteger::new([3061805721021393873, 16964254031889604182, 10322802940441490449, 716958295588970478])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([2802094407588336512, 10633054629034312303, 8733337080590055234, 1111578939685174009])),
+                Fq::new(BigInteger::new([16877855264436102066, 7098161600497965870, 2443966843427614035, 1703427904328050877])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([14815048005088592956, 17444495519991913112, 13283385057782836410, 2853824301379437852])),
+                Fq::new(BigInteger::new([12682108313696764426, 13043933149848868551, 3136380293647618965, 206113512500537924])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([13519922303402730778, 15088533604150873939, 227607781639321291, 1929948069462018175])),
+                Fq::new(BigInteger::new([15871842489556680619, 10201460959519534831, 11180602963411529293, 452563055072203997])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([14709622643251885703, 6860265556309836225, 13716755643962398298, 2826156275573668347])),
+                Fq::new(BigInteger::new([3955418673689871851, 12202250972956672813, 14799024473361139511, 414182988744562817])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([210641430249971198, 10584679418036130408, 15639326928990186335, 1733247088042648274])),
+                Fq::new(BigInteger::new([7971640731679852890, 14746206246216763982, 11909122965584886728, 2302011564680811928])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([15576887552896198606, 992721992957525705, 8364218115445877253, 1254093809261881315])),
+                Fq::new(BigInteger::new([11708874525997290921, 13744683932861352385, 1908417632325408454, 1685338636005418667])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([5823342506043968407, 51539339210304756, 12993264777818743601, 2655140730739939595])),
+                Fq::new(BigInteger::new([17552321103464205399, 14113678606553807122, 10651183331174705071, 2785554495424411380])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([16099742473547623048, 3631636625385806169, 16224232896626828627, 2048500000282148141])),
+                Fq::new(BigInteger::new([16998878228674542193, 711651820129056729, 5886205979664498293, 1406135376699346553])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([12719657966547037499, 15442732563005054290, 4084456221206877433, 3387796685014122705])),
+                Fq::new(BigInteger::new([13048975678679591897, 9433774346164166957, 10258163564850799672, 1020301067422440324])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([12271647513138661587, 10217253364135730250, 9500572653491914658, 586442050507638672])),
+                Fq::new(BigInteger::new([13756138915836122791, 4671376427984940558, 17247285696912763316, 773305044987211777])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([9247971540016583037, 7183322239790791926, 8320584716174978383, 960959023554192331])),
+                Fq::new(BigInteger::new([517630037268228576, 16133815782722228630, 516781927028570079, 1132661895631406827])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([9404072631577312724, 15017476465682054634, 8736334359885848413, 2916196220018488315])),
+                Fq::new(BigInteger::new([4235960093418811481, 12432156168677120211, 9592288078032847616, 485213682499966359])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([14903587923562007612, 15510146532392832602, 69010575340673303, 2793406903882066364])),
+                Fq::new(BigInteger::new([5968033165672328744, 10775245320745297106, 10713470323024809417, 2561698060219278359])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([12931125127240273830, 12216308053124879809, 369582289000972958, 30153107451993211])),
+                Fq::new(BigInteger::new([1626536226935153686, 3599177325027697784, 7370738730101713065, 2867294262383194734])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([8284215244941368176, 9790998079121145373, 7145281730385331310, 1502283141360284601])),
+                Fq::new(BigInteger::new([16216592302069271409, 6105867224954130648, 17407417843054838113, 3356567049963466622])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([15668359608328640168, 15062725525251234528, 408912291207527401, 1288993057488608544])),
+                Fq::new(BigInteger::new([13935658424239381354, 8563786274427774627, 9251713299869563061, 1464625670103660445])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([4593101851149855737, 10884623626667024443, 7140400334667849640, 2486876595440194706])),
+                Fq::new(BigInteger::new([2372646472953017062, 14630698696004932933, 12482773744282713204, 2076063074707229411])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([16416648816766819471, 13799817707862500118, 11837196640769555076, 658050394678389440])),
+                Fq::new(BigInteger::new([12477675635650100058, 17959390977838495504, 6359377861190592543, 15355393163650605])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([10902216661912858122, 2925284908538848637, 8186067751932009635, 2539065742603406735])),
+                Fq::new(BigInteger::new([2340865950887393687, 4748703442515655696, 15892903406333611302, 2094120160996264618])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([17427609131887752661, 18312072534516054266, 12368748162991362113, 2167177557339082745])),
+                Fq::new(BigInteger::new([7632016662478520045, 8813639274801352859, 17152817430669966383, 1708691249364088759])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([5392412307076526260, 11258353358658626830, 828359708943237913, 819590074178188478])),
+                Fq::new(BigInteger::new([10062366231009540075, 10485803720065526565, 8015504856488916284, 630521424292394658])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([9753109657680752092, 3631046797228281390, 12755846965432853735, 1671643378912409797])),
+                Fq::new(BigInteger::new([1479939344433848301, 13297787640567392854, 7030225714530786212, 2564615723136920383])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([11684782742384641247, 14512835242113749994, 12697501308454627581, 441580303896277251])),
+                Fq::new(BigInteger::new([17831628218912282074, 10363400355380185351, 16226558953655731365, 271093008460781446])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([12200342820887901384, 16771001348233814604, 17544801314556427930, 3406475033196405011])),
+                Fq::new(BigInteger::new([16420754684086715728, 15562635516891766686, 8283419609034949371, 1259686936234893730])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([7295757514158242971, 8429525637399401588, 1993332512198205916, 2362866326312580944])),
+                Fq::new(BigInteger::new([6682466606808354962, 11751311293365893534, 3904672205287571750, 616772985455845967])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([6460980040656494006, 7959898807695085899, 7791703774040405340, 409504318816496793])),
+                Fq::new(BigInteger::new([12801669683454689318, 12901895327552068786, 13790343390815880575, 1410229569253547364])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([4842667947953926249, 2998653899816876120, 6673599732786505678, 2489590238564304345])),
+                Fq::new(BigInteger::new([5010362625880364294, 14593900414167489671, 2483227838820250593, 822523434147838220])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([3375575185602429809, 13590133731959957532, 4945013223303922184, 130404077211711185])),
+                Fq::new(BigInteger::new([10451892084058978486, 7782483440161747635, 10313901604261031269, 1602976696565298986])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([11052289279978006659, 16734905263880428748, 2850066155482128623, 476868100241906275])),
+                Fq::new(BigInteger::new([13956050510087247491, 14842475409009112943, 2373793604712703456, 803276274985775082])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([9851960348683250461, 17525770138086457412, 5480276683683173358, 2099090024316363535])),
+                Fq::new(BigInteger::new([9167283428189215135, 2309213246185047115, 296636826850578038, 1739619449194060052])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([11589522741762815736, 1495568921728614975, 6031329861074015469, 1870468881909990929])),
+                Fq::new(BigInteger::new([16992195173368555632, 1788790745248757725, 15451482313053488437, 1487025603261139381])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([16936715802817265585, 18237791105417304954, 10792994316286698510, 2115474057740312882])),
+                Fq::new(BigInteger::new([9993150016215682394, 734377780049500684, 9155898320752311934, 2607244693348402529])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([13918398567698751924, 8936238300743415665, 9950075558294733866, 1819169251953878157])),
+                Fq::new(BigInteger::new([18384709408798106272, 17061832008365595159, 9994755626730801860, 3474899739016956498])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([14914475087151189098, 3760027877602300265, 3356465659741536744, 58864448164753980])),
+                Fq::new(BigInteger::new([10984981109853297537, 10108377996695493590, 6844063892357226164, 1216035281375724394])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([8843021046813156819, 15355991458509878067, 6703591095063324324, 2842909647787772205])),
+                Fq::new(BigInteger::new([6818464140096288965, 2722089244374258971, 15437470843282195647, 808197577302852522])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([14696308398005391651, 7982411865780244230, 18312300439287956928, 33145103936349075])),
+                Fq::new(BigInteger::new([5154254337530902, 2261518197085241304, 8165862238095212199, 1852409491069254496])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([12362705888659758181, 10936486292931753825, 894174184658396565, 1862683630195875595])),
+                Fq::new(BigInteger::new([1619556499434992157, 5488754950280056461, 14186532720921448198, 192623376239928815])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([9593063921631892607, 2881215015492434302, 15687321387654538438, 283107667034851748])),
+                Fq::new(BigInteger::new([17425591538202529634, 7583956211879363343, 14604408060035277155, 1862658781715183091])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([4428682855977762765, 7889788841762789209, 15991003597202906267, 269152902824065423])),
+                Fq::new(BigInteger::new([10365772012235609464, 13129231438142339544, 13123188345279285779, 3445959349617892092])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([13566296572338208691, 4029264159459248861, 8442395812741355456, 2071536101582020882])),
+                Fq::new(BigInteger::new([9896013706170631443, 4960215942141227678, 2495538251292802436, 1827766419439691781])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([11091984955126341644, 2992753474831475809, 4075215901032851056, 2692900423295926203])),
+                Fq::new(BigInteger::new([15577836281318798451, 14489357753142678388, 7583413458511396134, 2170715549673811932])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([16219870356628061775, 18332090381107902358, 15269722543599771992, 1141345915647001773])),
+                Fq::new(BigInteger::new([7096055280999198734, 15156924754673573879, 11986899511303574953, 2108072223291177433])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([4014787692303502303, 11583619047657942230, 9163248505916904362, 2733967822754381481])),
+                Fq::new(BigInteger::new([17663890789807038789, 8771824181766345203, 7636100200575926527, 2616684851739347158])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([14493246473465774999, 205250328413858431, 15788589549751512397, 2231867682687330565])),
+                Fq::new(BigInteger::new([9577796555656502102, 14179571492187762320, 9975006062832099040, 3140577655289750340])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([16803196150201996183, 14543640284827735270, 17469639320102382192, 2586560851797662178])),
+                Fq::new(BigInteger::new([16195151667353910379, 11665455790252211352, 2528743901084807666, 2296633484343820100])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([18304853082401369442, 4377364528842518897, 3577143334867761466, 3472345809393621586])),
+                Fq::new(BigInteger::new([8214721850794340418, 13240855718659618454, 16035611769304658699, 3437152914258472966])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([5155273281328187267, 6945155459234624311, 2680456069201472398, 1570189656924702011])),
+                Fq::new(BigInteger::new([9358057046637148698, 12113713458242483575, 9032695617412790431, 3224429230784929519])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([6000425160487084845, 17450925996692207936, 4202386416018952928, 897395423040502118])),
+                Fq::new(BigInteger::new([12654384635081266143, 2121719114169248999, 5161936294551489639, 3295459971132569712])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([13545656871521228038, 9539433563331164983, 10264783554522196331, 3421386012953308808])),
+                Fq::new(BigInteger::new([10368804127158869919, 8082352783232462852, 15032031750533959361, 2868592901211557475])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([8833305674910074063, 9839849179650769750, 10086116754146948002, 1414592492900416060])),
+                Fq::new(BigInteger::new([12702296322570946639, 7366151852835168471, 10226802137260432466, 952895046789980022])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([5055438193832929848, 4223662954145742761, 2693407208065600960, 1274887409944618769])),
+                Fq::new(BigInteger::new([1604529750905535742, 5123804814754917082, 1499051515935776560, 3128012300282193941])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([9798425220290962770, 7461722092621792227, 11710239693155289748, 188680252867539028])),
+                Fq::new(BigInteger::new([16388248527676479243, 14496881579844737843, 18191521904603742129, 1740829724421343843])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([537618616051728826, 12357659223967401560, 2627121170333356219, 3121340495428235683])),
+                Fq::new(BigInteger::new([11877104121878683821, 17283578526156156756, 6522189204359917105, 2610223882953769795])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([8627453996860564882, 13582219392967704003, 8252983287262666002, 3457910564840655257])),
+                Fq::new(BigInteger::new([11647550741787519444, 170552176229093882, 11252674244593136553, 3238786723768434083])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([11132961702402449767, 5840447149110330986, 8906938314835952425, 3211222686727037618])),
+                Fq::new(BigInteger::new([866643041789533661, 2584720802350605500, 9063273403937310358, 3187313450128447752])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([871838750070249869, 11500993902816840266, 17561996048217806460, 2282899349124864023])),
+                Fq::new(BigInteger::new([1506444484357840581, 10280236247500188681, 7231110975006063374, 3243022465773712259])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([13758713777898291136, 7686678209091066197, 14169996771118345650, 861402937663729011])),
+                Fq::new(BigInteger::new([9537197033272397845, 70947596299416366, 2495840903760689818, 2298613173414336373])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([13320945681773186432, 17762662509470342957, 5253200464038854421, 3034405273617227876])),
+                Fq::new(BigInteger::new([2787240227925244549, 7484128960839403172, 11366046685408635627, 3100924949261654658])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([13024977894935707628, 18275720700786541398, 7444068100004533367, 3336853988304768697])),
+                Fq::new(BigInteger::new([1295521828358866834, 5455772204519336830, 9363987149102081787, 2930424393511256677])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([2241387771087621827, 13168289671805152595, 10181084274238228412, 620537930542429651])),
+                Fq::new(BigInteger::new([385437214785822370, 12041876039632737427, 4022791044450135727, 598186687382450467])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([4471739717968976779, 15527599702079567122, 15558508199946224122, 2569215270476360946])),
+                Fq::new(BigInteger::new([2081686030660438194, 6009091868120084845, 3427718076778740165, 1528866604097043684])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([4690324331179128147, 7382542314001802960, 16660775663578576657, 2110265406619727808])),
+                Fq::new(BigInteger::new([7620747093998590778, 5713238022088325342, 15370657061841641109, 1975441764516953466])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([3605407909109761280, 12235316447869809841, 17020182234801237990, 231100824615613729])),
+                Fq::new(BigInteger::new([14706648444971289099, 12144290485389301946, 2736632560943879930, 1484379640502237342])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([15627181285635498941, 18434902718621258263, 10579256811183189459, 1215518259644498319])),
+                Fq::new(BigInteger::new([11198160621269259772, 59211852979761974, 15969548944731749217, 888848725823444737])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([5566329963354270850, 8714114758563737192, 15673378872779887559, 2329649883169874077])),
+                Fq::new(BigInteger::new([51917820385397416, 6396575392012605983, 1540411635190148120, 3395518179364900215])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([12496772018731573012, 3461229929306582, 5215874312570749232, 1135253186988511840])),
+                Fq::new(BigInteger::new([890158663555548350, 14505756183736993837, 12250395876470787537, 2178098658588115085])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([4230312718108167011, 12755094534989182168, 6975770365681394720, 747549498367762301])),
+                Fq::new(BigInteger::new([6311718662084648820, 8578308880258812449, 11722121144388138724, 970506639170622075])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([15554472939998898742, 7883048157545391419, 6844217583901498233, 3310919607443597381])),
+                Fq::new(BigInteger::new([17470541411833937683, 12940973191801872080, 10818379084136158563, 669558086621766362])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([17137059489658125347, 5605899836779680109, 7780539876843453221, 2145680560092885198])),
+                Fq::new(BigInteger::new([5497697061799091738, 4239079391969109104, 7373126181234018765, 1562609996119308949])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([10687733339182081952, 15806360823717309590, 13601497567545795112, 3431903531431074849])),
+                Fq::new(BigInteger::new([11410654052842609626, 11055004090475199523, 7642917573190340557, 335711990923728527])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([4358655114957984312, 11151616419128877048, 14489515166008821631, 3047298109844177676])),
+                Fq::new(BigInteger::new([211775739217393780, 9143252241098259242, 16179889925948096640, 2117998943368394343])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([10982415433425578039, 266228073183095965, 832029946835863114, 980547154855217565])),
+                Fq::new(BigInteger::new([18334083073579736672, 7913740451407932395, 16930479253562231944, 1028639969290379083])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([3358118999040536847, 11359643761760382144, 18263608077084916884, 1289705338101548470])),
+                Fq::new(BigInteger::new([2173460153622215909, 9023044598114189306, 12903088690028776205, 2223899409322730032])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([10436606263410540573, 2164391227658059074, 7426586937023787867, 642578852807439602])),
+                Fq::new(BigInteger::new([12882544167856771860, 779497142868727348, 341290086468647688, 1232790521716561582])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([16075139025600360057, 7606709196111089397, 1392183226801060080, 2145464658348177486])),
+                Fq::new(BigInteger::new([18137285554718271040, 15597288447721547523, 1099800816454678719, 1027605611805972870])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([8461055839143476345, 8918491231777652041, 17724596558085246792, 2335853205483846282])),
+                Fq::new(BigInteger::new([14745911599075577264, 2990789289997328566, 9158081063832842506, 99717826739225974])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([8395115129502695634, 1765507221479279513, 14115607728581897817, 2849356670595619654])),
+                Fq::new(BigInteger::new([16289959163079316048, 12826532515001173222, 324712040133290483, 78970391527651926])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([17663800698482431916, 9984503236726704607, 5079722771392398756, 1894033570492858223])),
+                Fq::new(BigInteger::new([15132656539597604706, 18075244100197712139, 12743252325489213753, 746870024184626894])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([11550518583969865386, 16201711693816617013, 10893355412080219274, 2116980752449761640])),
+                Fq::new(BigInteger::new([1365119921761721668, 2455228866735903373, 10633037727077577541, 2420727138642434438])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([17695690509589026119, 16834139655993410954, 17203554564893710030, 73053868422965081])),
+                Fq::new(BigInteger::new([4936435086546383358, 3125693007948278807, 17854182784509468911, 2015289967270690971])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([15629211252819894299, 12845065669794316323, 4621712584921809600, 3450103630117463880])),
+                Fq::new(BigInteger::new([8738278191563407102, 11303642141298515488, 10278508012968647337, 2731440913457277906])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([2980509782745606206, 15337711857251616109, 12368027427490764705, 2871743660769725900])),
+                Fq::new(BigInteger::new([8566822986682286496, 5236703300796495309, 15282676474397101291, 1270639169683510231])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([3328788316197275143, 5346240668104891371, 9199543418905433707, 2531060713626390299])),
+                Fq::new(BigInteger::new([3735026538020854480, 5867358627277486289, 641527719060082812, 1905349325532101904])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([4036245194599785754, 7389503886462601613, 16750627003168933225, 1520924422904455698])),
+                Fq::new(BigInteger::new([12117996374815862111, 9254775357180774674, 7062741121496766885, 594203943082489718])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([10516555497566706398, 15619486236648546236, 16605095995842795684, 1166324945666051834])),
+                Fq::new(BigInteger::new([16121940496636359871, 918682078711178441, 7983910058966363677, 636345603269555595])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([12619113684860674171, 12432580252069449924, 596286185967675288, 1930147248718146403])),
+                Fq::new(BigInteger::new([16347807316406145467, 6759462499318247173, 12703327385952464380, 923665217343663219])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([6979030288529428190, 7429956575724123290, 1888435381417877917, 341306247543563647])),
+                Fq::new(BigInteger::new([11068329518960662876, 15099434826324675571, 13404532341910629793, 1284078542677235495])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([14857143236275356301, 15414638159931242588, 9129148790187184636, 319438274484847182])),
+                Fq::new(BigInteger::new([16221558718115205009, 1582913042544375023, 16949033353770598725, 1187904435485095123])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([16504176125629092896, 14860366689527814886, 6237118688586456076, 2616782416221593755])),
+                Fq::new(BigInteger::new([7064716306347043419, 16482576483288601771, 2985186408431012322, 2611582519214240428])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([9849407530100949634, 13146965553792283763, 16837377828129160996, 2871555600915966573])),
+                Fq::new(BigInteger::new([3830659822332326099, 11243156277909420642, 2343160625110568977, 1453321178702221035])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([1123766429429364233, 3611839677970728380, 10717627548495838974, 135872590426708145])),
+                Fq::new(BigInteger::new([558122334399681691, 1776153092159120126, 16728460473821587534, 1371153214072349299])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([9609296199973815089, 2268394730510773509, 8997694149522767060, 2655574560356972])),
+                Fq::new(BigInteger::new([18427842870653147048, 13875252679567840765, 5164821144181979790, 1768745724043162579])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([13187592080666199281, 5250456972334152943, 1693656077091237769, 2774992534644810955])),
+                Fq::new(BigInteger::new([10444277640388484217, 11222796947035624869, 17777179179477278612, 360710898267335398])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([10987002400085729920, 5328165919435088532, 2305417308598766267, 1557819654207354001])),
+                Fq::new(BigInteger::new([13107953943100194371, 15802143922514217035, 13794541519189696891, 1842649416057873705])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([2386473301796150841, 17419951778451172652, 983992751395813447, 1314656602353960843])),
+                Fq::new(BigInteger::new([15592833064071742548, 14246557365290095100, 5065203370279699592, 2585352523073826083])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([12539260327255083614, 13510928720350653601, 17181262921604391108, 83455701198029531])),
+                Fq::new(BigInteger::new([809352963616841785, 10526595738469217980, 14859082816453195948, 2719334654694723929])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([10968805082101338462, 981651782717016774, 5049722155143280155, 395057070933172264])),
+                Fq::new(BigInteger::new([11842882396403448082, 5438900806808454224, 2805094357375958318, 2688016518330828225])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([10678505943610958339, 15271618514794079284, 1135206854025330621, 2005385412031008952])),
+                Fq::new(BigInteger::new([14099405858129053145, 12019167856316806763, 507761389823004258, 2024268779183316784])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([9583359976509409344, 11461151936174739938, 13456788426674974239, 3230916029725545061])),
+                Fq::new(BigInteger::new([7949059922147462441, 4467287322705666495, 16058113052918167518, 88171145108901691])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([1541178621483000642, 11275620348982846400, 505401655264841741, 2929557441479444150])),
+                Fq::new(BigInteger::new([1583905296575983258, 12397268022648256136, 4232721027528321156, 544028696178093700])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([17085425336852433552, 11695942482255325964, 4618545777399752711, 405338495714040410])),
+                Fq::new(BigInteger::new([11823649654515055622, 8798955705997425879, 4202024097827910904, 628412515487353046])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([6617321807135196436, 16960697797809928361, 2713052526971668495, 2070348350292180889])),
+                Fq::new(BigInteger::new([9461980940561094377, 15274164800821159160, 10359779283212733558, 2102122344704132033])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([6541836086910501572, 16010639161299492955, 7245982681092807753, 1177915691804413923])),
+                Fq::new(BigInteger::new([17704292627358111286, 1681054965162249974, 15563330123695068555, 599771836087768097])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([4773389349831852972, 14343067503388866811, 18036693288791253849, 301631952247996141])),
+                Fq::new(BigInteger::new([14391242719638655817, 14068242962124856318, 8467162319064374721, 3453454815042014118])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([12424023449762869641, 2326011927411393036, 9379981212098488428, 1907037957254866160])),
+                Fq::new(BigInteger::new([4580612028229415329, 12982635093184872912, 1534854181845763856, 2429027318819473250])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([17946788799810898181, 18384289835204113859, 3996773773684479095, 3382756618446613696])),
+                Fq::new(BigInteger::new([14559426628974464017, 10426733134981851720, 10203451818514483517, 797280128379842676])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([10790357558267953007, 14991845026411604669, 9532117003302812068, 2635188408320003900])),
+                Fq::new(BigInteger::new([17713965502789995107, 9008117740334605746, 14133865263914395941, 3308134741612349208])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([13781026699600534104, 7345901746912565490, 6516769473546198094, 202045073755902572])),
+                Fq::new(BigInteger::new([16597099009345360348, 4808025525028318799, 14146630203051445173, 1719916361104721496])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([6569684999870716057, 12226991672024082885, 9949822460756544729, 2884443892412611083])),
+                Fq::new(BigInteger::new([17003404017049073424, 8153840911037432244, 10980454910315124397, 1968140174229504487])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([5772796460801527753, 16397325882299091801, 4529071308378590870, 2382446641958219009])),
+                Fq::new(BigInteger::new([7379305527028510262, 187997031162397940, 4073270451076839421, 2119270584727591481])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([8300251130669766571, 14226578894081109854, 5934626448857720365, 401476921643773399])),
+                Fq::new(BigInteger::new([2427039394469961851, 10766406812526099366, 13428226882164482994, 2131008935302345762])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([13638687291683045412, 12173043779915513240, 14485856409047817353, 2788273939840228454])),
+                Fq::new(BigInteger::new([331325828096219232, 4351487168348471918, 7219960068113192160, 417552424853140144])),
+            ),
+        ),
+        (
+            Fq2::new_const(
+                Fq::new(BigInteger::new([13703400031442662318, 7731922255743214770, 43925265771715560, 1503321353209363614])),
+                Fq::new(BigInteger::new([13676335545162730730, 14322861822002118443, 2343332620241156156, 1544372692210545438])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([949319784844733284, 17000143093460280407, 8821921600919858284, 783664617558175381])),
+                Fq::new(BigInteger::new([1570570450848139493, 1041166013097283878, 4398928443305274349, 2034169338847316091])),
+            ),
+            Fq2::new_const(
+                Fq::new(BigInteger::new([18237655038182127494, 7576540888309171509, 10012826717981623682, 2254983196787820488])),
+                Fq::new(BigInteger::new([13197674316743220578, 7315348404189563154, 10409781329098122208, 579741419974707340])),
+            ),
+        ),
+    ],
+    infinity: false,
+};
@@ -1,110 +1,60 @@
 use crate::{params::bn::{G1Projective254, G1Affine254, G2Prepared254, Fq, Fq2, Fqk254, Fq6}, bn::BigInteger256 as BigInteger};
 
 pub const G_IC_INIT: &G1Projective254 = &G1Projective254::new_const(
-    Fq::new(BigInteger::new([17854084382631191545, 11241147742880698961, 15038307028306097613, 1993567917222341098])),
-    Fq::new(BigInteger::new([5221600325994482856, 18412335658090063851, 3570771962706853153, 2441569008359973714])),
+    Fq::new(BigInteger::new([7033577145882926473, 4085051857102556104, 8623271800052566990, 1897402990893243252])),
+    Fq::new(BigInteger::new([4537320927284812074, 9374901934741788615, 4593027274035518319, 1482708791462680962])),
     Fq::new(BigInteger::new([15230403791020821917, 754611498739239741, 7381016538464732716, 1011752739694698287])),
 );
 
 pub const GAMMA_ABC_G1: &[G1Affine254] = &[
     G1Affine254::new_const(
-        Fq::new(BigInteger::new([16535706859230341300, 6066093718182583279, 16009304815578961404, 3160212177427169737])),
-        Fq::new(BigInteger::new([15238000234719498000, 16612687702544370296, 8471993504453294390, 317281399644239179])),
+        Fq::new(BigInteger::new([4363528768271332679, 5711291708384455790, 15390167402399872101, 3140492670821986489])),
+        Fq::new(BigInteger::new([15309731343281792871, 6558236205080486160, 5166503184643020625, 2669317280000883193])),
         false,
     ),
     G1Affine254::new_const(
-        Fq::new(BigInteger::new([16108136286835030331, 3866357464465731570, 5000393965920562218, 85996609641579846])),
-        Fq::new(BigInteger::new([3478471097418678476, 2385599950293808200, 14604621404701093720, 1708094378535513175])),
+        Fq::new(BigInteger::new([9047544991395781160, 15902878952328724411, 13077475239942479554, 2916205380881338865])),
+        Fq::new(BigInteger::new([3269654000702496075, 2003079851162222475, 1435014547629902346, 2753295466402226877])),
         false,
     ),
     G1Affine254::new_const(
-        Fq::new(BigInteger::new([12105777768733743386, 8461817103552340295, 1059323363635415371, 1947058738123728752])),
-        Fq::new(BigInteger::new([10315497946662574372, 11097082446518669311, 8013202994543835809, 2687065296228720166])),
+        Fq::new(BigInteger::new([732359399318919841, 6470441281174011742, 18206989594941606512, 484736967606560078])),
+        Fq::new(BigInteger::new([17825316296882102377, 15032546790046623849, 11421540042720352866, 3045914109058852866])),
         false,
     ),
     G1Affine254::new_const(
-        Fq::new(BigInteger::new([17972266501005339195, 4913358077495269848, 16446109838814864406, 183783821798013334])),
-        Fq::new(BigInteger::new([7868050336934194583, 3570614935217207198, 11525213982140923371, 3408830046335346361])),
+        Fq::new(BigInteger::new([2937898691466803264, 8438721046384256073, 5479424045994480029, 1335135541894963800])),
+        Fq::new(BigInteger::new([8462118239901401025, 6678570098686236470, 393562324498380469, 2599042603299911321])),
         false,
     ),
     G1Affine254::new_const(
-        Fq::new(BigInteger::new([827841682089880490, 11455129360695626988, 3017758988312887936, 1551117088205374156])),
-        Fq::new(BigInteger::new([11254632468363593075, 549845025632022769, 16505333545665288065, 2738987125356470530])),
+        Fq::new(BigInteger::new([9601168373730607488, 885450381193352685, 9032229163853870519, 1645015398347296046])),
+        Fq::new(BigInteger::new([11810427547445106379, 12781277934934021433, 16324912940279782500, 2986700662695215992])),
         false,
     ),
     G1Affine254::new_const(
-        Fq::new(BigInteger::new([12159033686788910829, 15984029703615547205, 1292868932947724854, 471376765277483666])),
-        Fq::new(BigInteger::new([2772093104092839279, 14006951145162312553, 11528845072814491395, 471599675643911278])),
+        Fq::new(BigInteger::new([6698289545905938162, 5123307753436294924, 2170688470015041215, 1678875395203218205])),
+        Fq::new(BigInteger::new([18441367453144238774, 9968692999844076480, 17505337459018980481, 929657397877822359])),
         false,
     ),
     G1Affine254::new_const(
-        Fq::new(BigInteger::new([15092717688394571586, 2355323265917719887, 12390987348850935418, 3273349953456378102])),
-        Fq::new(BigInteger::new([17367432811511168049, 1022877657304867808, 9677038596233327095, 3060836127601564580])),
+        Fq::new(BigInteger::new([9416206799149692471, 13948377705311579264, 12632088396343706972, 1159375151648202290])),
+        Fq::new(BigInteger::new([3915812307840640862, 6079327992462346692, 11900261762419245203, 1727550333886156411])),
         false,
     ),
     G1Affine254::new_const(
-        Fq::new(BigInteger::new([4854161747505877959, 15236282091550385866, 2532192668073838423, 2840190793016935345])),
-        Fq::new(BigInteger::new([10017756301665857347, 13632596270657238620, 14167546200500847667, 1967473193792018785])),
+        Fq::new(BigInteger::new([18081663651169914737, 297263991965625819, 3795853781204639806, 1801474837580218166])),
+        Fq::new(BigInteger::new([4602605769627872392, 9789916084023998935, 18239056737202686620, 1850008052136462943])),
         false,
     ),
     G1Affine254::new_const(
-        Fq::new(BigInteger::new([13533053349840353609, 10979664767162550189, 18316945724860871062, 1670079954812612344])),
-        Fq::new(BigInteger::new([8951047711167107131, 18250518772766684432, 8676501750524476049, 2255649694431631370])),
+        Fq::new(BigInteger::new([11804449598560091701, 13931122381981788773, 3795602054062252214, 2368898617283303485])),
+        Fq::new(BigInteger::new([2080585029740339152, 14400434583635706587, 10356446401270484755, 2595808609601843876])),
         false,
     ),
     G1Affine254::new_const(
-        Fq::new(BigInteger::new([3753223028227252392, 12485430666270764435, 17357573869699980303, 2578015156484697584])),
-        Fq::new(BigInteger::new([18416756914131653175, 14295316835625338984, 15896239525966185903, 2370805688495453713])),
-        false,
-    ),
-    G1Affine254::new_const(
-        Fq::new(BigInteger::new([12779117361444744487, 15994541538486569592, 9133860050926268792, 405088243071564922])),
-        Fq::new(BigInteger::new([8452432801622368034, 3833869128054480965, 8336873323157938453, 1817130235799929740])),
-        false,
-    ),
-    G1Affine254::new_const(
-        Fq::new(BigInteger::new([4033042681825315476, 16348067823981052272, 9866885925713868757, 2466939909540227555])),
-        Fq::new(BigInteger::new([13563547202663449887, 12508103828049730062, 12686719749556113090, 1152616016680385487])),
-        false,
-    ),
-    G1Affine254::new_const(
-        Fq::new(BigInteger::new([12983123261342403701, 12201633829908785597, 3506847637550433616, 1450432202016797520])),
-        Fq::new(BigInteger::new([8367576436583690761, 5811526956764070061, 15174472103398033962, 1956676187868848985])),
-        false,
-    ),
-    G1Affine254::new_const(
-        Fq::new(BigInteger::new([1691442108973184702, 18281523349925806617, 3572220976353010117, 3393519152907423256])),
-        Fq::new(BigInteger::new([7338671613059920288, 7849499413123303296, 14087502822189886493, 880593522460239397])),
-        false,
-    ),
-    G1Affine254::new_const(
-        Fq::new(BigInteger::new([3882719905294209437, 13990884374581958599, 14195790263469105036, 1819953219904811796])),
-        Fq::new(BigInteger::new([2034720869304715166, 14661974480315949794, 6946260673606100722, 1735922549057799905])),
-        false,
-    ),
-    G1Affine254::new_const(
-        Fq::new(BigInteger::new([12514105895296613627, 18204957919237005418, 4826243044029280137, 92053510999731974])),
-        Fq::new(BigInteger::new([14190240755650386848, 7659536668086924920, 14146675455298212224, 817659817801532903])),
-        false,
-    ),
-    G1Affine254::new_const(
-        Fq::new(BigInteger::new([5284012329764582522, 3606567958097727043, 1304523698078044216, 1257010757699111711])),
-        Fq::new(BigInteger::new([9409732454448511258, 1185459992534941816, 12026107558700993893, 247515606678836379])),
-        false,
-    ),
-    G1Affine254::new_const(
-        Fq::new(BigInteger::new([6149160922239665148, 15903455651997781603, 12006701264029610111, 2083445743256143001])),
-        Fq::new(BigInteger::new([3513496232539587631, 4218911788039979760, 4388115667920976942, 849512675420327619])),
-        false,
-    ),
-    G1Affine254::new_const(
-        Fq::new(BigInteger::new([16886456883233598125, 15963534290244008310, 3465411917928933360, 1392005050712979476])),
-        Fq::new(BigInteger::new([15953182995663084522, 3226404125077218186, 16963212620772136597, 546400370482631794])),
-        false,
-    ),
-    G1Affine254::new_const(
-        Fq::new(BigInteger::new([12816857476654317577, 13860160686614715492, 5868034414583442001, 1600266649014636489])),
-        Fq::new(BigInteger::new([4095364296260608546, 6054269393292379562, 7127059820526354796, 1977305975865018847])),
+        Fq::new(BigInteger::new([4882592079269449622, 16628197027332626936, 9617676050513276733, 2250986028130202821])),
+        Fq::new(BigInteger::new([1579035427541194074, 7155716556200977168, 3961182086486774861, 138199711326788078])),
         false,
     ),
 ];
@@ -112,30 +62,30 @@ pub const GAMMA_ABC_G1: &[G1Affine254] = &[
 pub const ALPHA_G1_BETA_G2: &Fqk254 = &Fqk254::new_const(
     Fq6::new_const(
         Fq2::new_const(
-            Fq::new(BigInteger::new([12234169641588244851, 2026320257624713926, 8709058609185572771, 3486274550793019785])),
-            Fq::new(BigInteger::new([13193602386681633928, 13765104695341264731, 6435897020781695236, 2931098940794402418])),
+            Fq::new(BigInteger::new([8035349878198547088, 16495477028811560057, 13258357263093636776, 3062612996566768222])),
+            Fq::new(BigInteger::new([12758260575419121125, 1365286129732351427, 5403867346039667406, 2823544379132793160])),
         ),
         Fq2::new_const(
-            Fq::new(BigInteger::new([762761813668539417, 14575532879611334809, 10992413884720268551, 2354222816349746419])),
-            Fq::new(BigInteger::new([186327975408613335, 15794186852331568349, 333877818651086219, 657516603224016252])),
+            Fq::new(BigInteger::new([9248307290322242908, 15862596056335625878, 2557311625314099898, 1242209264612509145])),
+            Fq::new(BigInteger::new([6427700214587973930, 12870701350984218193, 8197910484492776192, 1309817778755941419])),
         ),
         Fq2::new_const(
-            Fq::new(BigInteger::new([5615890445168411092, 15946057113157695424, 7457758829913754896, 2305362637863349023])),
-            Fq::new(BigInteger::new([5219126374290756112, 17338708325200500252, 5209899792854455676, 200752386384226736])),
+            Fq::new(BigInteger::new([11670891256269772308, 8410269116322331106, 5842819351701827906, 1952853329790611951])),
+            Fq::new(BigInteger::new([13534441350507716964, 17304553075550827414, 12867154692696413329, 93317604314704605])),
         ),
     ),
     Fq6::new_const(
         Fq2::new_const(
-            Fq::new(BigInteger::new([772795935148350328, 5798957973573465707, 12386600156146908427, 3366063520414917262])),
-            Fq::new(BigInteger::new([2717393712672263306, 15297861747127331575, 13521416001206395542, 2183073318729117638])),
+            Fq::new(BigInteger::new([2989916535322296897, 12446529532568849677, 14356852947919099303, 1632133669617409385])),
+            Fq::new(BigInteger::new([9632240190118302070, 1129574792157062118, 18070063413630105129, 393366383312335248])),
         ),
         Fq2::new_const(
-            Fq::new(BigInteger::new([17620212289547241599, 6712803054401801823, 11200899458402675088, 2011998531470789804])),
-            Fq::new(BigInteger::new([16118175999730584823, 11888750782946674363, 7669494655519381656, 572742447411181621])),
+            Fq::new(BigInteger::new([6591261367597657465, 10961242848928014279, 11066084535489999962, 1716607512116415472])),
+            Fq::new(BigInteger::new([8851953487020489270, 15145151113586491800, 1992775490766744878, 2427219268386615893])),
         ),
         Fq2::new_const(
-            Fq::new(BigInteger::new([7426374750337705486, 1040285348015521359, 18356554215836299014, 37944601632798149])),
-            Fq::new(BigInteger::new([15913625429909189713, 11758618395865642804, 651099776437363814, 2458977723008246079])),
+            Fq::new(BigInteger::new([16850747671233282496, 13120606804985631924, 16750415166840217597, 1732321478824476273])),
+            Fq::new(BigInteger::new([14568953402172805665, 18295913123041594614, 9005137692908737632, 854141915765359487])),
         ),
     ),
 );
@@ -144,1276 +94,1276 @@ pub const GAMMA_G2_NEG_PC: &G2Prepared254 = &G2Prepared254 {
     ell_coeffs: &[
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([9895118869653472794, 15399926093610853964, 7765079125921529439, 1187522227351647357])),
-                Fq::new(BigInteger::new([7199473718059756968, 3484864300626677925, 2517322756645898003, 2732858340557047444])),
+                Fq::new(BigInteger::new([13561823019654849787, 2343080162139748275, 12130203231125779507, 1182672306843915142])),
+                Fq::new(BigInteger::new([12280263523435487586, 2262148577765565660, 1945067216530537586, 3104281969095793937])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([5449764827422024983, 15980837033962800862, 10607723345290398453, 2325728346611270554])),
-                Fq::new(BigInteger::new([12434708200063767607, 10607322477668044317, 3725564662254567175, 3361393465836820951])),
+                Fq::new(BigInteger::new([15153157375979271419, 1354576498625907599, 10675597608744517287, 2690958214120499577])),
+                Fq::new(BigInteger::new([4089209470497461562, 2105296120094050881, 3406542843737892152, 3096880753008949352])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([7599818440825922932, 15525152330736550017, 6228187810850344477, 2741513842309152446])),
-                Fq::new(BigInteger::new([9761903496311973189, 7915648265843095591, 11212353395632098013, 3328195503428969735])),
+                Fq::new(BigInteger::new([16651403534927482600, 8497976867627883551, 9483536321049421554, 3428368959652397107])),
+                Fq::new(BigInteger::new([16883123864576898037, 17297024779053182708, 1005382378001135406, 1469061584034455526])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([14301820748068231288, 12520381793367058585, 5986465415310484058, 668544613366349044])),
-                Fq::new(BigInteger::new([2434952430993804839, 10777842063991401833, 15163107100081441367, 624517414166698332])),
+                Fq::new(BigInteger::new([858978386848508791, 8937264803559671159, 5367146857651637103, 1535315837701984519])),
+                Fq::new(BigInteger::new([2141360901743885720, 8193419204140192019, 12799505762692374177, 3095559988657717664])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([13883577332414927382, 1149331154917287003, 3577328423886856451, 2278061746966917151])),
-                Fq::new(BigInteger::new([15943931350214182096, 7828026924522155561, 15741556537150721507, 1349956219256175155])),
+                Fq::new(BigInteger::new([16205511185779559310, 233533350757492271, 17332271626406966516, 2085330811162810355])),
+                Fq::new(BigInteger::new([3773283844953205274, 16601303640659360047, 11167866900965013990, 1982502904286706155])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([12003607002226436585, 11480604986348912952, 12995070610237432379, 1582531529973970325])),
-                Fq::new(BigInteger::new([13318329089350900356, 9290038200471726747, 3666607376745595497, 3484516902511205689])),
+                Fq::new(BigInteger::new([3702538243535889432, 2617472740417279771, 15127969872163109767, 3067468444821479786])),
+                Fq::new(BigInteger::new([1679980083165939752, 5573081731977487723, 7943265680755104563, 3352851303018891841])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([1379456116385766639, 6108812457455390118, 6131101919120943632, 2316613371462065957])),
-                Fq::new(BigInteger::new([1402930676498392496, 6546752893316344575, 13696766605531874785, 177513843359682539])),
+                Fq::new(BigInteger::new([8922948144405200398, 13751427786450559602, 9646440971490649240, 2704331115901624008])),
+                Fq::new(BigInteger::new([10490671968436241819, 6628442198050307817, 16233408510759319604, 2754037415879654911])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([12555808590571980495, 8653424543195244197, 12264042473039738872, 1902326594372516985])),
-                Fq::new(BigInteger::new([12226332373832253873, 9628095560234544087, 13100072819887939225, 3037602198761387702])),
+                Fq::new(BigInteger::new([9070450130084228140, 15739625262404602245, 2291785769080863509, 1186939966118664307])),
+                Fq::new(BigInteger::new([8502419491061801251, 8601909760788904643, 466144466967236385, 2745151706086014523])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([54114498525547976, 9983704716299854705, 3347639353885414743, 1554534556306815567])),
-                Fq::new(BigInteger::new([13023338353135802210, 16374686647257594103, 11260369274249698064, 84070039660490556])),
+                Fq::new(BigInteger::new([3270151763492059053, 7160073314885138969, 1775190364601539788, 2792688065001759086])),
+                Fq::new(BigInteger::new([6844590148684222559, 8766834818395747308, 15499278997793761367, 2183995852641096545])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([2610744814933312965, 18010934610463929455, 2227799768080252583, 2734959784099168466])),
-                Fq::new(BigInteger::new([1068150567411307004, 10727767578837570917, 10007518328285401125, 3186137327833261116])),
+                Fq::new(BigInteger::new([14715041743786098360, 15608549643396186532, 15464028700370606843, 313959288237014903])),
+                Fq::new(BigInteger::new([929211612194361451, 8036377266809505834, 13658360878830856422, 1369848415243723829])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([17992973775070674347, 3949950987807158290, 6162829680103404948, 1311744081660146073])),
-                Fq::new(BigInteger::new([16031260251996531050, 3227759996257632812, 13807992450376258385, 273463007748711300])),
+                Fq::new(BigInteger::new([13948490665506024529, 3205861411018992265, 12429174313389542627, 2935517910498789710])),
+                Fq::new(BigInteger::new([16242386250926265427, 4901232149017783601, 17317878269346120662, 371983589924800873])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([393119702026637134, 10284748094393971481, 700551816461774309, 2016509801471846467])),
-                Fq::new(BigInteger::new([17113761580672774987, 5863610377999117933, 13280608672736128361, 613456097924327523])),
+                Fq::new(BigInteger::new([16677737589770644871, 10799586170515711498, 5291487033287206517, 498901111503524450])),
+                Fq::new(BigInteger::new([15215988502367151447, 16663273898717976809, 1854767911411908346, 3101089234710158720])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([10882584552863279631, 17052692897802122591, 18340380544396506948, 184030548357981384])),
-                Fq::new(BigInteger::new([1591306716710612948, 12609934077253241084, 15076151176001880395, 1399235305089737570])),
+                Fq::new(BigInteger::new([13512694168023210907, 17906427789522624350, 8484894788042296898, 3297850288889318733])),
+                Fq::new(BigInteger::new([8824988961971061193, 13556459721360498741, 2969725009439056446, 1943090643486812411])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([12784160138044733056, 3977210590335722317, 10995254663010136115, 528241709629740003])),
-                Fq::new(BigInteger::new([9150733603638932198, 9666530451202844579, 878664207457676661, 1609976831346658542])),
+                Fq::new(BigInteger::new([12391186134992239333, 10348330791150418083, 15091990130866489302, 1557579859908815192])),
+                Fq::new(BigInteger::new([3613570370437983433, 1437154195446517473, 13072582976511371133, 199401088460613677])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([86795861490818113, 3706827898457233465, 14377720827243812267, 679303343134085545])),
-                Fq::new(BigInteger::new([10048526844048486850, 11203219614381219174, 8743781798522079700, 1576382860913100340])),
+                Fq::new(BigInteger::new([15267054547449535728, 6785975143416605939, 5466729582344480078, 2708649382282320783])),
+                Fq::new(BigInteger::new([14025288865332549810, 172023552372792484, 5940264618735544594, 480223403268998074])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([18436517468666205991, 12044090058684500528, 17899386918532037566, 3258580760936775538])),
-                Fq::new(BigInteger::new([12775101199379000393, 11061199675761915781, 9252435619576303568, 2246719307463697614])),
+                Fq::new(BigInteger::new([3284356424283682632, 280688798866845890, 2376009756800095767, 3439512050665490449])),
+                Fq::new(BigInteger::new([13238208016493713813, 10109193841163973674, 13114232238746250100, 3251313490281375137])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([5752987463309635352, 6628120990824074382, 8053558828741493241, 359710846671701696])),
-                Fq::new(BigInteger::new([18064022930052740309, 10938694771391462716, 8010764113883408994, 1862114441127158550])),
+                Fq::new(BigInteger::new([9344619276148321553, 16341683069663962899, 369187329723083874, 1368469839146697548])),
+                Fq::new(BigInteger::new([10317234549245594117, 14188058534743140077, 1951783894382885468, 350433609136249681])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([5198446907837466911, 11097006478565519821, 14513456064477498629, 2109638762842717037])),
-                Fq::new(BigInteger::new([16940068770664397104, 16245888995901924179, 9287623969503227658, 913977315999571486])),
+                Fq::new(BigInteger::new([6687259505933131986, 3991685772338856733, 10593448610083843545, 517414520930017096])),
+                Fq::new(BigInteger::new([7981336061327066544, 5895180147985289569, 516011511681791251, 3400154530195872659])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([2447745077347321365, 15706004251815088202, 4679188203995434993, 2190212278464715176])),
-                Fq::new(BigInteger::new([15487983520958782697, 6854968422458667190, 5912255154586050319, 1467308247042548941])),
+                Fq::new(BigInteger::new([10960160407136144170, 5578632635245936291, 6837139123303347249, 1405239017799456214])),
+                Fq::new(BigInteger::new([17194912223912990623, 14103391246863459949, 18183117675306913618, 1616329904872407899])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([6168231417043785925, 6540638081463349403, 7971071022725286687, 1317536186213141244])),
-                Fq::new(BigInteger::new([8352665720171314257, 17942315253712791025, 5720811720730118822, 2228617375529190385])),
+                Fq::new(BigInteger::new([12357231857457462695, 8232519612264802931, 15035191760008382570, 2265803953407972143])),
+                Fq::new(BigInteger::new([1210471083354779992, 12570794407269137187, 6819868582789135955, 1971659492646663224])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([11358906120430877178, 9199874398481736836, 2901367405725938702, 462267117523173518])),
-                Fq::new(BigInteger::new([8318737158034154401, 4385692289110767328, 4705486995049212936, 964682923133723067])),
+                Fq::new(BigInteger::new([17639237646050953421, 4877548423761563618, 13535982165668428783, 31952194530587298])),
+                Fq::new(BigInteger::new([11735490351995686034, 17112921975703935050, 13351999216537938482, 979764292891491756])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([1828472543515504284, 8873510757256628371, 14117916681365031179, 844098192953524844])),
-                Fq::new(BigInteger::new([826551836674222364, 13457435024592849642, 2499174056983910316, 2718559252309686282])),
+                Fq::new(BigInteger::new([4524766898043513393, 16300917835553398734, 14595974489069526923, 385827293736387565])),
+                Fq::new(BigInteger::new([10287403576114189427, 1854788749122257609, 8629295937090040203, 1638545562387917380])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([15330096027734258415, 9355200126337911998, 2629490105673310914, 94415389863529275])),
-                Fq::new(BigInteger::new([8495959032541396772, 13297510770446364448, 2121218440278792304, 1457437641300768066])),
+                Fq::new(BigInteger::new([11307156192116711214, 15000651998633934817, 9577440403185925696, 1042224161257047567])),
+                Fq::new(BigInteger::new([17290005453857198713, 16835339198166258334, 6693187309765013120, 1442026968385260127])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([15312925338139584231, 15166312717827454184, 2289073638519525681, 199651372213283865])),
-                Fq::new(BigInteger::new([15800595816801549559, 15307977999731427863, 13194290832797178794, 472390234567722036])),
+                Fq::new(BigInteger::new([69575189530217175, 5228698507019278269, 202706045901484874, 1777449526944397567])),
+                Fq::new(BigInteger::new([2957133711162487626, 13324743406873129483, 696148469170238070, 1416050319063769083])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([13414974503894739038, 5884833504144721487, 11576944579110804239, 1973519291994421145])),
-                Fq::new(BigInteger::new([18118122216608140738, 2381839584878530383, 3348274011399136647, 3316792088438921111])),
+                Fq::new(BigInteger::new([6470317541212471999, 13468597593365002568, 15236312529939862781, 1195660881230549570])),
+                Fq::new(BigInteger::new([4706152345362125196, 8279642165326783411, 14613728469678666212, 2465819270114667440])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([9235347696426990541, 11293242418696698880, 9099691056815413595, 3116633431256587438])),
-                Fq::new(BigInteger::new([16927952604676468581, 10539519536128204369, 5151296022397790306, 2533713825544561673])),
+                Fq::new(BigInteger::new([4905956322412552653, 12943036866993476121, 12824273662950755585, 2202516010118843369])),
+                Fq::new(BigInteger::new([6185837958866940712, 249741370555067992, 12122161163137308965, 1710838237443951149])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([13556714432272535536, 5879283091798528104, 17030832728531832709, 1291170497411840242])),
-                Fq::new(BigInteger::new([18029870435749963135, 1917590295890735768, 2249214794059721554, 2974508157082518963])),
+                Fq::new(BigInteger::new([4737988162754853704, 17582948730589420317, 10800409062679308305, 1807706417493110003])),
+                Fq::new(BigInteger::new([4358755222359832320, 9832387021300674927, 15504074763794634576, 1413095475612516927])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([6494768323437204358, 11671567209459928732, 4995247706279460809, 2450516388401717960])),
-                Fq::new(BigInteger::new([18406847080688744863, 6688165684889465663, 13120362403230630853, 193303989930274635])),
+                Fq::new(BigInteger::new([3526558025080693059, 15066439199827250119, 4560115442872055891, 1680221240236132938])),
+                Fq::new(BigInteger::new([4559700479492305231, 13193184171580038217, 17360777965555907490, 542058901373076172])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([18007632342018845099, 4208603692856207459, 7358306527395850381, 730672740420408660])),
-                Fq::new(BigInteger::new([16049300191605185365, 8143310011137856257, 6982983195526046529, 979965044575148269])),
+                Fq::new(BigInteger::new([9178431650815440432, 15151206792058040706, 7223073031094194032, 988967641318277180])),
+                Fq::new(BigInteger::new([12455387436477192607, 9079967901463644557, 12629569598770373252, 1103792082790523791])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([558819837518313606, 1094439556540456607, 136842677279298530, 2587037752404833414])),
-                Fq::new(BigInteger::new([13238036017142070528, 6820243573930764252, 834998215224602155, 2059641648436904805])),
+                Fq::new(BigInteger::new([16360896213607909730, 9844286081359555894, 7228926957206319203, 3000985099968026914])),
+                Fq::new(BigInteger::new([1784274709725521661, 4530565098480166040, 1971598069151449630, 3247480130734932390])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([2588562682120734053, 4175312046980796538, 3367002697103322710, 2482988774909120802])),
-                Fq::new(BigInteger::new([11851637200048376306, 13190809951348164905, 15951580860905755277, 1511502751577946564])),
+                Fq::new(BigInteger::new([18249945219921844679, 8223831813501706875, 1619172260030948989, 3271459623669312070])),
+                Fq::new(BigInteger::new([5290749470251305140, 7112380934077087989, 16352563440881238600, 1134538501926748610])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([15103718988586331604, 15058758342180683528, 14825172659676433480, 2660205292965165280])),
-                Fq::new(BigInteger::new([11903980995801081332, 1245703797623446599, 9269861143773314779, 2232566569517313278])),
+                Fq::new(BigInteger::new([7146737410604176092, 18038639199122195596, 10682862908816997091, 3217915683729405963])),
+                Fq::new(BigInteger::new([11615858020074167868, 2672371955903075963, 5308566878208951164, 1857904394650782091])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([9468794642736305098, 15037654983549327962, 16642304241550533630, 2799977537118034452])),
-                Fq::new(BigInteger::new([13563039810005145549, 9723853222733618869, 5900738385685780591, 3319657111572744552])),
+                Fq::new(BigInteger::new([17028967253596877941, 18327007928466530938, 13314488130418146439, 3358552499534016587])),
+                Fq::new(BigInteger::new([1369615015943452412, 14985372317939474767, 3778983664518364422, 1850455047980178316])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([698541366501789897, 5860091124664290999, 2637055237996404825, 1117089897533470101])),
-                Fq::new(BigInteger::new([3822013957829763534, 17975055077792977202, 14964125853212837556, 840579321070393487])),
+                Fq::new(BigInteger::new([5960580440911046159, 17820675413174106391, 12955968663964250346, 3356109599242380790])),
+                Fq::new(BigInteger::new([11207302515202934362, 14197691164868753275, 541449558908198776, 355986852335651020])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([6689593820653704146, 4874075478083593644, 1841498599452869724, 2476999946761696827])),
-                Fq::new(BigInteger::new([5314151219571149277, 2353268507610022069, 12908360387285493412, 3024230277694595325])),
+                Fq::new(BigInteger::new([9642721058408004507, 11276112427300594011, 14014286936366547022, 2248935797508071147])),
+                Fq::new(BigInteger::new([14054564009937444575, 10439679060910308748, 17864452476930628577, 3112087759471020293])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([368546286180176696, 16002245120288909759, 18195820883307419415, 588214394756730033])),
-                Fq::new(BigInteger::new([8242640909842153580, 11711164968800008163, 4227093934298064503, 190037340455999547])),
+                Fq::new(BigInteger::new([16043289265723138977, 7033967116076643717, 9134216998248200846, 67258635524917880])),
+                Fq::new(BigInteger::new([15538408531711659917, 15794654293241146785, 1006494577835118224, 321697420767244765])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([13349296320429608397, 10909908023644575080, 14008567770862308103, 2265445321213983388])),
-                Fq::new(BigInteger::new([7353971281537363126, 1822210830984971667, 7329924618959394449, 529955292882614347])),
+                Fq::new(BigInteger::new([2671560735658582260, 1860699927045899662, 14059381132644950317, 3008261440202613046])),
+                Fq::new(BigInteger::new([14571235366162089657, 16044035083596849841, 15944088477198415366, 167288961158935164])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([10739466398493827164, 9591879431254515706, 9706086701626712102, 1934959700350320079])),
-                Fq::new(BigInteger::new([2764867262016635306, 15109543025942977059, 11285240278756379041, 534745856759471861])),
+                Fq::new(BigInteger::new([4444732590407464444, 6453897496190609003, 9039292526651990351, 3091747631905609601])),
+                Fq::new(BigInteger::new([7122707083871624973, 1425398244518735800, 15021145355665476155, 3135576278935713696])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([14265798283897499637, 16878380932126912918, 10686457374654324331, 1660420223852916925])),
-                Fq::new(BigInteger::new([6697967717937274684, 7456506116952888026, 14526552100681687372, 587652433750197260])),
+                Fq::new(BigInteger::new([144747779028217827, 833955928901891550, 16886624315992670018, 2181947301607856580])),
+                Fq::new(BigInteger::new([409972955873621500, 14389740309750441047, 674707523839941356, 1399265762856122702])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([12227085353396777512, 13147687889781882987, 2437692648822277973, 1510322600131904629])),
-                Fq::new(BigInteger::new([6087272124243482986, 12426469677496628626, 7668863373911044287, 2346243656967801694])),
+                Fq::new(BigInteger::new([17449893649473722193, 10962329502248482450, 14380998827131698221, 2865493893723686020])),
+                Fq::new(BigInteger::new([16793724845325220304, 18414492024426117641, 10565494163973275298, 2208827417878146987])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([6913715546463920, 4994738471997510291, 13861164269406066509, 2533455387094358252])),
-                Fq::new(BigInteger::new([6462447564432774221, 1706176516544151386, 12979163210738830082, 2079525768785352505])),
+                Fq::new(BigInteger::new([4543035470139530726, 3930402043241538474, 13466736462048587527, 956938879454077261])),
+                Fq::new(BigInteger::new([7243468638755152415, 6620001297254938420, 9340731768368931964, 471918077801637318])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([1379473608350011486, 2173874077936623885, 15936057019792120665, 3139973869722227105])),
-                Fq::new(BigInteger::new([15276778792308998479, 3935008145408165428, 16763373998259772966, 817446408702629937])),
+                Fq::new(BigInteger::new([5919172967342356678, 16485641810891013298, 2932742232602374913, 109621662273539666])),
+                Fq::new(BigInteger::new([3943405938516336939, 5433234705959432394, 11132870830410227218, 3291336808124410806])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([8150853348463726506, 4747018675843552181, 12242238026202721337, 1993371239930976542])),
-                Fq::new(BigInteger::new([9864992082626031440, 4300388801207984091, 902192902916167675, 3286442913661192892])),
+                Fq::new(BigInteger::new([11748698918912165906, 1774447923109183113, 10532958950093937931, 1533143926552133496])),
+                Fq::new(BigInteger::new([558324220425965173, 3984226775129939774, 6034114759179487993, 1214183972017476212])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([12616021686098508482, 6889496292806548902, 2121362861267915507, 2272071706383021116])),
-                Fq::new(BigInteger::new([9023346764724074512, 12092771535757288159, 11753517383332566437, 1581357177182994301])),
+                Fq::new(BigInteger::new([15698632214432233707, 163983704818594120, 2056805436091470169, 2935509886180173170])),
+                Fq::new(BigInteger::new([8332037362304337074, 17975089865985446994, 8709569906988619585, 76670938864477863])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([13384753311292895156, 6745520988493746482, 343045229561640704, 3281452077765697])),
-                Fq::new(BigInteger::new([730038164317732365, 1316642513063693956, 14538324699010708296, 642961076708953258])),
+                Fq::new(BigInteger::new([2965717813543299807, 10165294512744012554, 8510674965094988232, 3348170564392244088])),
+                Fq::new(BigInteger::new([6456398662206465901, 14541904975864166715, 10104643007572861543, 2276344871341457189])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([8543545278142794872, 15371163184534493425, 6341071346808433456, 592519975927590398])),
-                Fq::new(BigInteger::new([11627238048554177745, 9103786022206909626, 15425726432695390084, 670046222484421181])),
+                Fq::new(BigInteger::new([16855205237499243148, 8088250031760695797, 13047225264176956272, 750940216627955461])),
+                Fq::new(BigInteger::new([14916577790586811754, 13719381517091934571, 9680578144779763340, 2391778743787859402])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([17195884536689507664, 4017056408351229483, 15876694598362399488, 173349316743033228])),
-                Fq::new(BigInteger::new([7418327755049684788, 15306813598321226784, 1254850529698263803, 3197899636207340693])),
+                Fq::new(BigInteger::new([2530210345721114751, 17927047565189578546, 15151987003448173895, 1673029110460983092])),
+                Fq::new(BigInteger::new([15501563000390317411, 15483519054281013317, 12607069658838591031, 1116316522273191971])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([4173962261460541221, 3594836855940201995, 2577722410461402676, 593361519234255987])),
-                Fq::new(BigInteger::new([11933307525852852174, 2179305688904287260, 15815405142503771285, 2932952018274229104])),
+                Fq::new(BigInteger::new([7086300500735363084, 12763007981002204347, 16877332116567363372, 2071073705384791195])),
+                Fq::new(BigInteger::new([12864837436192013394, 8981253587834190420, 15235931984913686631, 1807544944236921135])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([10176210707507676164, 7971595637235362972, 14288245030618877100, 3455777239997406887])),
-                Fq::new(BigInteger::new([6330079539130587213, 18298721999445480205, 17433173883250527913, 560979332659074913])),
+                Fq::new(BigInteger::new([6510600617491709023, 3793928457734963145, 14779892834218546383, 2718313657881810089])),
+                Fq::new(BigInteger::new([14145456111926786873, 5286583292010977189, 8567998578103615866, 3247785614029686018])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([17545194522637107688, 17451186676470497953, 15750707050911929087, 1234996148449880095])),
-                Fq::new(BigInteger::new([13082237552068532154, 16323210123356707125, 14523259407996942899, 2050283349153900765])),
+                Fq::new(BigInteger::new([18393504910902718094, 16351632890285271908, 12971499929794547778, 2755528570819207263])),
+                Fq::new(BigInteger::new([4006647364480866897, 14944653198589199491, 833533675790701161, 2245072618442584674])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([14021040358661199816, 6104015730120724766, 4967476039011465010, 2290855752427128483])),
-                Fq::new(BigInteger::new([15261200250189659079, 10725169105006342319, 8485429536436531046, 2462156727159134687])),
+                Fq::new(BigInteger::new([4641729396615923725, 16703920341722786534, 78377814197577476, 2297355245764589920])),
+                Fq::new(BigInteger::new([15558683651077298166, 17068129228117306604, 6349178513868647020, 1358980516857841217])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([14630677658282705399, 4569869916404871815, 12453234966320986221, 1812133077140682938])),
-                Fq::new(BigInteger::new([458118796243137689, 12347151585528258683, 6502208200312862816, 3311640252260010831])),
+                Fq::new(BigInteger::new([6031923826695205441, 2436535684095554819, 7855881311228747221, 738485404636777777])),
+                Fq::new(BigInteger::new([6393104500893744178, 3637259561307848540, 6914012157751505043, 991412223668197691])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([2273420521938137397, 18323059590009215901, 406701886549353798, 3200422783505674980])),
-                Fq::new(BigInteger::new([7924659538372859663, 15568644020176510842, 13942184070753666964, 3067474121200168267])),
+                Fq::new(BigInteger::new([6138763942133174663, 3592489774467471421, 1568266310399496676, 2480579429133253237])),
+                Fq::new(BigInteger::new([6472636058001379485, 11533749784420800984, 6011518001485942201, 3232845011318159419])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([9290947957144053512, 12657997651503629329, 8333508838914505501, 241789481022978869])),
-                Fq::new(BigInteger::new([18318946250486007009, 11807082930957694422, 17489386578804750470, 3424832188896943643])),
+                Fq::new(BigInteger::new([9260333020320105250, 2166192250344177578, 6288886563607376019, 720345624121122101])),
+                Fq::new(BigInteger::new([9072793405344986439, 5025188916299053309, 5012500711806121270, 2303271646001037426])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([10528108769869442946, 15966371248744300602, 15331709808724754783, 1445265521693781558])),
-                Fq::new(BigInteger::new([730518547126458398, 15534676512642685377, 16100315027875359484, 490578947739725456])),
+                Fq::new(BigInteger::new([15924933256681079476, 3953670300915019923, 4199812134028063304, 3328015692113606389])),
+                Fq::new(BigInteger::new([547270579354471963, 169708407209110734, 14554632179128012262, 1791801521156529799])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([11123388512832040780, 2244582140677337963, 5688531115584293622, 79317328315263550])),
-                Fq::new(BigInteger::new([9171415112995249367, 2888019697238498643, 17655060626734773979, 2058105839725954261])),
+                Fq::new(BigInteger::new([8068802814471822200, 8683507439303910781, 10208584499065964637, 2422110147031045331])),
+                Fq::new(BigInteger::new([7862099216416099237, 10646467696894990968, 7690327413058711429, 1420777560597976693])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([1477647010749348684, 8005466540801556056, 4057820853980275627, 1033172316954756985])),
-                Fq::new(BigInteger::new([7588103756507707978, 2123163395115940755, 12869640732954132542, 2978309394400558121])),
+                Fq::new(BigInteger::new([11968163845827556663, 3272080007720308044, 12990832779901559012, 1525926115902628686])),
+                Fq::new(BigInteger::new([9150358214614073406, 16694891493713874405, 10917435403078696868, 2926759339424008852])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([5413807119173501102, 3018810479329280332, 16257146032622405330, 3092590149720816383])),
-                Fq::new(BigInteger::new([9354812362346257835, 8680278458529511421, 12347426476834529813, 2185773168781905508])),
+                Fq::new(BigInteger::new([13104119716055227045, 5368282278282495539, 2122570592818790055, 1576213023852924867])),
+                Fq::new(BigInteger::new([15179177027750870843, 7067152203832394138, 11175426857617353637, 2077788411808264324])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([5343353848086135952, 17622137370313801432, 16530855520505903251, 2438732086469020088])),
-                Fq::new(BigInteger::new([16093514447421201821, 14591770404899625421, 8595081378861644694, 1816641780165981754])),
+                Fq::new(BigInteger::new([10500388174812834894, 12779008628544458717, 1266664920141910107, 6385549020987654])),
+                Fq::new(BigInteger::new([14525630499498114949, 3875676654624097736, 10268574992567239862, 2133497837529385075])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([4077307849787550304, 3116189304912854790, 17992534851027202699, 1282531287121656217])),
-                Fq::new(BigInteger::new([17318193297503729512, 12801836989994406501, 18252888727618202198, 1785756378238242437])),
+                Fq::new(BigInteger::new([3846534757964247364, 10466785139013181911, 2529666279431776926, 1762991065140806857])),
+                Fq::new(BigInteger::new([14253844149287152017, 5709492505888733383, 8813875184290979238, 299889838933638338])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([1952655822411869354, 3396344827324259115, 1444479199453630105, 972249434318772882])),
-                Fq::new(BigInteger::new([2276032775478512411, 5379884102180991159, 13742336338983161730, 2303713794738933184])),
+                Fq::new(BigInteger::new([8536750730564943559, 9923714870105764241, 10193435349498317442, 3000792745942877803])),
+                Fq::new(BigInteger::new([2179250255723402345, 13748238053837448230, 15615856158934889192, 1709174405785789361])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([17012092141319763831, 4709532144795775792, 18020465299096829385, 723266226410899359])),
-                Fq::new(BigInteger::new([18440638074859488244, 3715784234540895021, 8899513662121019863, 1376924611965019918])),
+                Fq::new(BigInteger::new([11124659677179091037, 15602477242191620395, 4033018618286055612, 64778497818116764])),
+                Fq::new(BigInteger::new([6716879325029600212, 12937315594461061147, 11450684438893165177, 2948040424875443724])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([16583659811503827804, 13248202688682926804, 16726170430171465469, 2328311993950521569])),
-                Fq::new(BigInteger::new([17462571126012595661, 11178534382205065451, 11431987715164056126, 2683727575598091118])),
+                Fq::new(BigInteger::new([7980371434197220257, 7653258786583203740, 8406702887897961620, 3130541119088974043])),
+                Fq::new(BigInteger::new([7988488881627770752, 1578329944623715710, 1525505044269620778, 2842260982642366265])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([4663576508173932991, 11903898917244392092, 612831418289367053, 612825463445227134])),
-                Fq::new(BigInteger::new([5052848409559593804, 15989127716521760963, 7023252280230870698, 866508792585589217])),
+                Fq::new(BigInteger::new([11143436348200257591, 10731503970782773999, 17207976983163976354, 109546270034074816])),
+                Fq::new(BigInteger::new([9863448803492372275, 9030734747936436079, 12932467517907931789, 46931903658293141])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([13836644911043093179, 3704323793470434198, 4827426928551108300, 3381164620395166470])),
-                Fq::new(BigInteger::new([4837335532927918997, 5403454368822983374, 13104841082765759770, 2076970788340931733])),
+                Fq::new(BigInteger::new([3453551810866133255, 6832505058424334757, 3924055450049913205, 655602840595175621])),
+                Fq::new(BigInteger::new([8985435758087888017, 11834420817713558419, 13737141698423069240, 2727992481061996248])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([17197055176504195492, 624265246247931938, 296589887233815831, 1482875428916781941])),
-                Fq::new(BigInteger::new([3670461637828731471, 740547138091432311, 16169204967105426236, 2099062423658746612])),
+                Fq::new(BigInteger::new([15560987770718896682, 3362958368172137474, 14684743259356302078, 439585889997694391])),
+                Fq::new(BigInteger::new([256845063852517855, 7078497536999555461, 14941391509990975357, 1478621193779374135])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([444992109275929195, 6687434028666223692, 8699765478414004897, 2788359427594093573])),
-                Fq::new(BigInteger::new([1375057092887392291, 12369950433467303425, 8638086046224001226, 834791787002178196])),
+                Fq::new(BigInteger::new([12724078712792600162, 18345259558154901684, 9811791483983735095, 2621741844677629269])),
+                Fq::new(BigInteger::new([7176094187654476874, 13342878616400494510, 10752492725712686496, 1374770969840949354])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([17418213518531665955, 2406714693009174410, 9529252696615037899, 2044850482638687286])),
-                Fq::new(BigInteger::new([14789917584740848479, 13215167883025048508, 9993743730933297404, 2871595290130347954])),
+                Fq::new(BigInteger::new([15044015385891226412, 3090517369215879755, 17222635697747715068, 1817587527400309687])),
+                Fq::new(BigInteger::new([8898262628790287288, 333912812562899159, 6964988296670940845, 2496972808479104368])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([10983753192828069228, 15981259048461091173, 11131655334238194556, 529777956164712586])),
-                Fq::new(BigInteger::new([6631395852205742752, 14421723122922510538, 15536053785824257997, 1789403512880763387])),
+                Fq::new(BigInteger::new([18096953791146534334, 7828856885514786811, 18289771457807841868, 2938238201042353590])),
+                Fq::new(BigInteger::new([4824051458475604009, 7183767577387925975, 15437803240669131025, 1608133214101168446])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([9455656651195016067, 10390705755449683042, 4591434707483218597, 811884955584022667])),
-                Fq::new(BigInteger::new([1908229105320308850, 1562084811346856377, 18227197647190414235, 2971454298002763196])),
+                Fq::new(BigInteger::new([8664257846285571271, 12715114071414047515, 15770844764745865531, 206450545271110010])),
+                Fq::new(BigInteger::new([7733398659413187277, 530970980101801969, 8265807849253725438, 1638256545303929736])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([8210482224403759245, 8430383590164494367, 7957223158265057086, 2461758065373514440])),
-                Fq::new(BigInteger::new([5234298541297388717, 13240373081216120408, 17618757074850918846, 1911405487754486910])),
+                Fq::new(BigInteger::new([14185952149304859401, 8145446167988247463, 15342070130259535520, 634399437934073474])),
+                Fq::new(BigInteger::new([3813208078887765425, 1044879494274912897, 16841428980294383053, 254278731765738375])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([17589150692497726883, 4471997048708076558, 9613717914312515623, 2115228666403618801])),
-                Fq::new(BigInteger::new([12603285370399319777, 4211104466453521538, 14013508611992711682, 358087580251647253])),
+                Fq::new(BigInteger::new([12372115939041485789, 5825527295345239052, 176107062636513027, 2170574185808542644])),
+                Fq::new(BigInteger::new([14161637671624367672, 4842713318055646761, 6083081553227204315, 3074097905339110245])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([10302705985952067856, 7219803123438312407, 10292769146420896139, 2529370399143607532])),
-                Fq::new(BigInteger::new([11574988196247987080, 4857182632106057330, 14160775359319300531, 1527577778226771064])),
+                Fq::new(BigInteger::new([18237450938305874684, 8297105057720524605, 6454545689243691522, 554517379995674788])),
+                Fq::new(BigInteger::new([11275433632794851502, 3122977093622821668, 6486636955925318205, 2262917372507496474])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([10643573688424146250, 1688544077594272983, 6345664100947512709, 977118241620420491])),
-                Fq::new(BigInteger::new([9953441999628839652, 15498829494348390788, 16653016273549284969, 2517061915799515909])),
+                Fq::new(BigInteger::new([17571224083334147512, 10643148786726828529, 7234313208791921341, 723253355774912688])),
+                Fq::new(BigInteger::new([15622196004698970177, 2450318284786365188, 9008144579021462709, 2306648417565586640])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([12017807572116901247, 3785969158626822056, 11557426382587244209, 3230221925868355535])),
-                Fq::new(BigInteger::new([12978262740018211839, 12021335721681432559, 8506443144276604018, 1537867586578470234])),
+                Fq::new(BigInteger::new([13086824666988169620, 4422700110672095812, 5322804240182188865, 1353082333361307378])),
+                Fq::new(BigInteger::new([16255732660423533138, 9417103800983568023, 16339142549089840971, 1309771154761108580])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([16441766136076145046, 1014293950472018691, 18403052754139919629, 3240717806083078218])),
-                Fq::new(BigInteger::new([5680910207510309523, 6916586725483975146, 17226213526635322961, 1432437003120001655])),
+                Fq::new(BigInteger::new([5343460911838475575, 3803565859323228137, 10767008182122695047, 1462092333401715833])),
+                Fq::new(BigInteger::new([17605168686820999678, 8882969468093323015, 8819129672712230092, 399359067319873502])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([2818777087952322355, 7361863889195093199, 13273324154579038621, 1123152888328287173])),
-                Fq::new(BigInteger::new([12449626269652762688, 5808314867980802574, 12886218578935582085, 2586437468497897095])),
+                Fq::new(BigInteger::new([4148447730892713142, 1445433395864946103, 13676331551989697112, 1430531669518555547])),
+                Fq::new(BigInteger::new([11353462825317234272, 11124475175235415076, 6061746676710608296, 710647381934428746])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([6008240351908362251, 1976959171816664128, 17908170130665936461, 3143608802203790996])),
-                Fq::new(BigInteger::new([6941065084242886886, 10708127724984626330, 1425796700116338612, 1656300190700949102])),
+                Fq::new(BigInteger::new([16814428355402741614, 7050227969776414842, 12143643482159716783, 3245625824416467464])),
+                Fq::new(BigInteger::new([18164324666602943634, 4892881321642823226, 13669238449237730856, 1528347346594366745])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([7871281113311912131, 3743143528609391639, 12840453101104726348, 2126412146171113351])),
-                Fq::new(BigInteger::new([1286253646647064747, 1827266052379900316, 13937929144748441635, 3215339656426954181])),
+                Fq::new(BigInteger::new([16820285995482823149, 16096928115397517935, 10304682337567798119, 735677930962334698])),
+                Fq::new(BigInteger::new([16629698513244575478, 6789404198591344877, 7031129124329984017, 28132391814144639])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([6638428297556039583, 6385366477371200232, 1511856444052404330, 1633280392662876932])),
-                Fq::new(BigInteger::new([13266939300665094173, 230101452014241128, 4274749462323673239, 2659607029374525654])),
+                Fq::new(BigInteger::new([1368945879352332674, 851563594407813658, 5081522401029773479, 2533446887853853661])),
+                Fq::new(BigInteger::new([17225970073475580020, 7298635776701978335, 3083299673910142581, 1014077273334740493])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([1673164053129837092, 1617794426168777731, 14657362248409287408, 90288367755896283])),
-                Fq::new(BigInteger::new([10625090264306912027, 18405881777250974449, 17841993536525176250, 239214946910569547])),
+                Fq::new(BigInteger::new([14741194042262469003, 10783259063645399841, 1018398011113234253, 2866275946439146897])),
+                Fq::new(BigInteger::new([1025437437547656601, 3367381428517426090, 2840060882157311451, 2242649225521831476])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([17970275108278074488, 4594600902371253389, 6266321542414621881, 1081323328933349144])),
-                Fq::new(BigInteger::new([857587214319718815, 18420943203004429548, 10192506944433037383, 476695850031285367])),
+                Fq::new(BigInteger::new([12279151554651260759, 5998243188499446835, 4954207167926654180, 959682207052064723])),
+                Fq::new(BigInteger::new([1894767127830079265, 7546045182316797890, 18379742019056173815, 1245020024094071028])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([13487694987409524989, 2283507815296900491, 5864388378876570834, 362373410375880699])),
-                Fq::new(BigInteger::new([2487443831236340638, 3235560801112510356, 6915229072447160933, 1043580287032208751])),
+                Fq::new(BigInteger::new([1363671878959814469, 9914129165938377847, 11637101417840395703, 3398622286788038367])),
+                Fq::new(BigInteger::new([7688571432347005468, 4024600974345448743, 3984131679840975741, 3375459787478173352])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([17348194226628401968, 5104254554601164474, 3994889731122805746, 2096992360925208972])),
-                Fq::new(BigInteger::new([2722537823228902031, 16064275040903997204, 9598586766933734245, 3087230599049718638])),
+                Fq::new(BigInteger::new([11548446592454231556, 6200817324347102839, 9745182772004622753, 2710767106079454564])),
+                Fq::new(BigInteger::new([2602627079319375983, 9107493678160205692, 16272057384397344065, 1931756710886091666])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([7138561298686224182, 10924193786868832473, 1001720856223937261, 483132930690021810])),
-                Fq::new(BigInteger::new([5304168416155142947, 6689907542888476891, 68754365544246949, 848196261988850750])),
+                Fq::new(BigInteger::new([17404969497324990747, 15451254952993132962, 1239711978804938323, 2117408670801643455])),
+                Fq::new(BigInteger::new([6088926605334424698, 9937544205369686045, 13803287002150930027, 2905164268851171895])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([1515415091275211956, 7132911366437108713, 883265940067051126, 3209794912714310792])),
-                Fq::new(BigInteger::new([10553617341365646476, 9417474207964470335, 6919260522438124957, 1890553065685163228])),
+                Fq::new(BigInteger::new([689028100017523785, 9600397852124657239, 6451746290786477086, 902098007128726191])),
+                Fq::new(BigInteger::new([3910039834463705986, 6987586244367204529, 4796980404329413802, 684261742388895072])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([15826775752711279476, 13876849535847936954, 18084495275470158071, 796803922477748936])),
-                Fq::new(BigInteger::new([17423035879656061734, 4259529469822666932, 6054724923964287325, 661839753352758427])),
+                Fq::new(BigInteger::new([6318897513596432794, 3077615940901328032, 5590634978668438930, 1278354177713235045])),
+                Fq::new(BigInteger::new([16121022760845568950, 7717653541412627202, 5257540238035724720, 2807791198078200065])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([540177922235570257, 8922097681517706504, 12300919482238445027, 428619908286010982])),
-                Fq::new(BigInteger::new([13146066845284847990, 8509853924163304818, 13686061897796355739, 1867689351768775702])),
+                Fq::new(BigInteger::new([3900460347943062604, 2344471565793219611, 16687158389332287095, 879211061910250546])),
+                Fq::new(BigInteger::new([14553541852551497391, 3952224639250376923, 16252098916851545893, 2165395642844567644])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([17312568775894798755, 14239581074028111030, 16238486510620411513, 3227783208075123357])),
-                Fq::new(BigInteger::new([2371781770147274831, 8214047879657498175, 9147557852626189893, 1925083627753867883])),
+                Fq::new(BigInteger::new([7091712061156773204, 17182196712692809290, 16402724957190103371, 1115350827337741379])),
+                Fq::new(BigInteger::new([12218638155658363151, 13207715444409409243, 17526584007643004310, 3416549194984011906])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([9508899618893391359, 14048547835350946383, 16719423055404120338, 31944522701268638])),
-                Fq::new(BigInteger::new([11391900596997246185, 3661220933283164349, 10449624188356933238, 1924491818891531959])),
+                Fq::new(BigInteger::new([2927958576896249056, 5738480780197298168, 13088795800008863969, 1008807766018053421])),
+                Fq::new(BigInteger::new([8907823755946594165, 4719781861277166837, 4596455230599386972, 2838831190620768697])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([9188866236017178377, 300706698371046365, 8514981940050543437, 181566387270237983])),
-                Fq::new(BigInteger::new([6168099813027280607, 17592182873066069743, 3365943418165439018, 3076856133386827283])),
+                Fq::new(BigInteger::new([9508152612620671441, 2523447733748609625, 10151365500237441030, 1069192211308445732])),
+                Fq::new(BigInteger::new([866113604643353875, 16904645888314608678, 16731015105918355057, 2140461184286766851])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([3193025881636654965, 4654083739021345678, 16936670964050632843, 2510357909748055280])),
-                Fq::new(BigInteger::new([13618713070267659115, 8742447443414204888, 17329126672444432882, 2944908407835103840])),
+                Fq::new(BigInteger::new([14503737105482749836, 4550460412614647205, 16153378806185391905, 794794944207175764])),
+                Fq::new(BigInteger::new([9836459047299437353, 10301040271575100999, 12641994478263311317, 1515484825835684180])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([4679763860897590898, 3268632798911078979, 1567795559413640615, 84612827945118195])),
-                Fq::new(BigInteger::new([14458771239889622742, 868363296322142525, 17620697679626257961, 1221508511235871826])),
+                Fq::new(BigInteger::new([16860098171715356623, 14115578936485903774, 17291144658677267287, 2242396817011653546])),
+                Fq::new(BigInteger::new([12183330282075521326, 13792081702876763187, 46649795565407809, 1674004435646638619])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([10659046116082163882, 8949070290870234493, 7354576867888073751, 874681836048264571])),
-                Fq::new(BigInteger::new([6718891049304756726, 1895827326877993617, 17124132012986743514, 55809546831481158])),
+                Fq::new(BigInteger::new([8193260629315944952, 10320381457817965539, 6683266681094850724, 471419880987178402])),
+                Fq::new(BigInteger::new([12520091042002636972, 10956275950833333324, 16016299450136282379, 998707863737763273])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([11198402694922892018, 4519563862682151998, 5717794048160507506, 1503048378823556987])),
-                Fq::new(BigInteger::new([13299387358393743537, 5614711668465773126, 14567833235926039250, 695150865390825865])),
+                Fq::new(BigInteger::new([9548079481874796398, 11604741830714488938, 13237860503452540015, 804052065076974365])),
+                Fq::new(BigInteger::new([1816769502987434153, 16414636324443256072, 6183865706539072473, 2720727999158079599])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([10278951053574658701, 13391365290357944962, 9892705017322268130, 3195000611049757472])),
-                Fq::new(BigInteger::new([14576681647174207658, 11208701898526296331, 17536247011502414846, 1052163794928745629])),
+                Fq::new(BigInteger::new([3793718718864808588, 8007764677671337625, 2374949237458486244, 3029776983765316153])),
+                Fq::new(BigInteger::new([1061807587419321098, 3973466673068264143, 18078486756141051043, 1367004171336759263])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([16874984856673025528, 14188028091726241208, 3460531842781109325, 151745437304731732])),
-                Fq::new(BigInteger::new([13740661268002409010, 16795644640000817731, 5092159500720763991, 1748824743202562302])),
+                Fq::new(BigInteger::new([8723181147736874196, 7543156635484397282, 11988449163206830972, 3132317168465434950])),
+                Fq::new(BigInteger::new([7977365135866460638, 16178002206133842348, 16486305232833669023, 2166927572534721615])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([6855435249249962862, 3081632026333288611, 2595592888643379884, 716537978453391968])),
-                Fq::new(BigInteger::new([9471441656271832395, 12634629969465855204, 5340452252931912420, 1350513784705578028])),
+                Fq::new(BigInteger::new([6592594922188806947, 16562273412052769709, 2731311525796961232, 492976258208691395])),
+                Fq::new(BigInteger::new([15661569026420342824, 15805627977296303628, 3156362864092154876, 3110876408249447633])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([700014527837402204, 10565520303555001150, 9348781071768849759, 3399807196829677330])),
-                Fq::new(BigInteger::new([1945175074553867791, 17615342284197269254, 17360899019443359549, 1470011823707156925])),
+                Fq::new(BigInteger::new([13118242130756994210, 4347058880178924669, 12334017590639566048, 1311900375959223617])),
+                Fq::new(BigInteger::new([3689567005005630855, 2733704538607440146, 2626501266237776090, 1612761522244127367])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([1578586459541579625, 8691982975965763514, 7233633566340269111, 1640299845674550720])),
-                Fq::new(BigInteger::new([16241875637210291132, 16311454934112813763, 5044206063591041555, 2916570841960288915])),
+                Fq::new(BigInteger::new([12516423141052829522, 16501198842012267646, 14140022414451998876, 22298468781010527])),
+                Fq::new(BigInteger::new([11330881884586061726, 5428389903263131960, 9407902706688544699, 3113334051632245665])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([2133049586391496008, 9760231030973184552, 13235125858235602751, 784115211509040900])),
-                Fq::new(BigInteger::new([16814682713773571589, 14369277376730220524, 4960941065915026261, 1412141981700321057])),
+                Fq::new(BigInteger::new([14345871705327084629, 5739839452640449199, 8094625545577356778, 3377247976275970030])),
+                Fq::new(BigInteger::new([10771640438271476458, 2003116266228404213, 7369576514075013108, 1398263857142936957])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([2478894887189273283, 14454318542999932756, 8326315765615087625, 813344343006072035])),
-                Fq::new(BigInteger::new([15635754628664743500, 16717914662833012626, 10010673388364519295, 1859362969194728663])),
+                Fq::new(BigInteger::new([9876346137528098003, 17571775575807216374, 7798695376011638984, 1627464436746461322])),
+                Fq::new(BigInteger::new([6166983598162520422, 8624389540179915182, 13490362330273872569, 3282579627932891749])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([7592773784958116835, 6433467001979583056, 12503344437627087469, 2263801775801564365])),
-                Fq::new(BigInteger::new([4607732295813295073, 5302822469075576322, 743145813843137584, 3390628329605467279])),
+                Fq::new(BigInteger::new([8812970243865697658, 12643678897080261804, 13164708306532848037, 2625773697904136713])),
+                Fq::new(BigInteger::new([375967539247071935, 10008580418320847223, 7866534436212208436, 2964194274226673374])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([2922400434853037293, 10094296003322132331, 6765958820837009044, 1329365998220598492])),
-                Fq::new(BigInteger::new([7258078538597875540, 17097673500379575553, 323829497221473147, 2379660443438448450])),
+                Fq::new(BigInteger::new([7157526161645714381, 11714071524116391343, 566476305232649556, 3272572212242019516])),
+                Fq::new(BigInteger::new([13812944454279108557, 13137143185605082247, 4393208830612547970, 1333873544855273673])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([14997228298913163313, 14730827927679912846, 10381189493531431646, 298630480333804030])),
-                Fq::new(BigInteger::new([17168796302108049063, 4989234679569878074, 8115671663159160565, 833165405570036445])),
+                Fq::new(BigInteger::new([11242701122482655153, 4872537698409558593, 7090443510554893963, 1442885151743925593])),
+                Fq::new(BigInteger::new([12557867240817011915, 9166091729510466941, 14283632847877311445, 2404979422923189755])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([597040844375353196, 3589207539927188731, 11501824640062885408, 2675627424561502228])),
-                Fq::new(BigInteger::new([7944036511142406516, 3639637396898616197, 10222493356866632438, 879761583326995886])),
+                Fq::new(BigInteger::new([18133198879309477697, 5553837476322796725, 18278586821083316182, 438853803759067845])),
+                Fq::new(BigInteger::new([1066312719707076875, 15708172627075336203, 14452372962913772125, 3113801987216218674])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([9302079250723980720, 14193582636060691347, 11153900572449185642, 1654920388361559114])),
-                Fq::new(BigInteger::new([5689034622747696934, 9591405189016339959, 9630748448370690283, 2445541453181370976])),
+                Fq::new(BigInteger::new([9464921311952132694, 225260877242999899, 16544626584287837459, 2771984352531626869])),
+                Fq::new(BigInteger::new([6303748153850514850, 12892708390268865603, 3552322784815845830, 2005551460355127342])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([5350627983240681512, 12108074248088109735, 5950141330116511174, 2504880957968662316])),
-                Fq::new(BigInteger::new([3730681748090785357, 3121326243680028319, 9635426471995129596, 3171882687963707515])),
+                Fq::new(BigInteger::new([14695842110882350664, 12747313223119583003, 4358618773169054787, 1478281423648234670])),
+                Fq::new(BigInteger::new([12039432944093557345, 191132015226251914, 10842294224282026654, 2741933464538958677])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([3813797812536666710, 13564732758223018202, 11551153087194255934, 2836875294317651720])),
-                Fq::new(BigInteger::new([14051604730105855389, 1579228495056483599, 14791666203110362032, 575567505179777807])),
+                Fq::new(BigInteger::new([4596571429804835222, 7452051091904563237, 6539103815800496362, 2574807634301163149])),
+                Fq::new(BigInteger::new([2045224923615193101, 4145556547164068028, 1155359405618448652, 1758751180144615096])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([7712704736996884984, 9305418887436233713, 12740813839404677660, 2246217062451977256])),
-                Fq::new(BigInteger::new([4147595045923555577, 5094802152272670240, 12041525999420345529, 1361026433010735039])),
+                Fq::new(BigInteger::new([10350408096569766559, 5090840671149855872, 8917677630169090403, 2886665426098888271])),
+                Fq::new(BigInteger::new([8480784422116067513, 13090611955346224056, 17647567022931912373, 2758355963585153279])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([5038038872599522251, 5483633016816436770, 4743386016416008136, 147029388167774839])),
-                Fq::new(BigInteger::new([2738510115965378406, 12731073989531147232, 3390689804736971075, 2615987031502107855])),
+                Fq::new(BigInteger::new([14353979383664197360, 863209224358892421, 11467131509773241438, 2824170529089052888])),
+                Fq::new(BigInteger::new([10613114475808735218, 4303879672299656854, 18169713422798148430, 3342470536838255814])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([717940258753262530, 2616964516481624748, 4083593752444801003, 974334228770291754])),
-                Fq::new(BigInteger::new([17760573811626455121, 2206337630208786753, 9413838333296499802, 1602015467120835389])),
+                Fq::new(BigInteger::new([6108208250530553012, 8019905273051501264, 18082007093438380000, 403816594587422619])),
+                Fq::new(BigInteger::new([16683396804526221078, 7660167567346395329, 7948091802184551592, 1314449713220159229])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([3458613573650190849, 14477027206589509544, 10353617718242126410, 345991897322239834])),
-                Fq::new(BigInteger::new([14772786640008987993, 16153547774403787611, 17810265442334437539, 3135247578067542692])),
+                Fq::new(BigInteger::new([14997493716942744032, 17296501563805431838, 69529584610898151, 2523243070675158044])),
+                Fq::new(BigInteger::new([6061329675510906128, 2052171293666358344, 9808898699570261026, 303244792693381760])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([10692932497293499374, 4194431021504483192, 9318490179160702197, 1280578154444600023])),
-                Fq::new(BigInteger::new([4804290716406604204, 13018368543986379021, 12038883430353831817, 2585115122400645896])),
+                Fq::new(BigInteger::new([17444592676820846183, 1148428576761255174, 6820567804672165986, 1104276218447470036])),
+                Fq::new(BigInteger::new([10095196907485892850, 1530252679784368524, 13300377585355160287, 3365925461947658610])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([15869157003655316995, 12011669495817282883, 1100870338713841459, 35421190659825502])),
-                Fq::new(BigInteger::new([9326097479498550811, 9803547348959097472, 18180888014864996659, 389229901731216377])),
+                Fq::new(BigInteger::new([2081591636008913796, 3965048018978327469, 7304724198135353135, 1037788612388782204])),
+                Fq::new(BigInteger::new([12584989840364766750, 434272675064504887, 4293428836642994087, 1979647937276893764])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([13323387754707057610, 2539943923234982524, 10302333459498818063, 1722564392188655097])),
-                Fq::new(BigInteger::new([865981319051891755, 12752633123416192905, 3040023718521426453, 2701581090337090995])),
+                Fq::new(BigInteger::new([6716274911359127493, 10458847153375706994, 9601190970718989601, 38780871529861352])),
+                Fq::new(BigInteger::new([12607632280383005872, 7876499884249134206, 18351757274332316, 1426790100387199657])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([3970112145811566150, 1719554058474123199, 7659785066277394311, 1243158387251999848])),
-                Fq::new(BigInteger::new([14764946561589426044, 7297156229818593941, 948507703795022022, 2534869604964070247])),
+                Fq::new(BigInteger::new([4141363522554601773, 16248567222552065521, 3381782990029824595, 3002383512615594226])),
+                Fq::new(BigInteger::new([11227985780511448261, 11599628254931712310, 10537048567976724239, 2372800874900546145])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([6687726671244712844, 13237466931884672644, 4988708466818753766, 1460925995081952663])),
-                Fq::new(BigInteger::new([3697401251297431035, 2768443153506638523, 4127817269004364922, 2346257428730983514])),
+                Fq::new(BigInteger::new([7524437830209776082, 10976165549506703985, 14859506254891085341, 1999177171830421721])),
+                Fq::new(BigInteger::new([16072708524257284583, 15844104373399506146, 5954989517949824408, 1516171061028041351])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([14480428629158578412, 12549449429676369704, 10197899111409853230, 448704205162266776])),
-                Fq::new(BigInteger::new([11743410594434935859, 8577137686225312619, 4890896353599725362, 2584438338161901325])),
+                Fq::new(BigInteger::new([8103638924803353755, 17950717359402832032, 3967747523472402143, 730241509142415344])),
+                Fq::new(BigInteger::new([14607017604381923016, 3627406188390103481, 1317387059817336130, 3762246758148860])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([2835126973906766069, 14863036875392730930, 8084967088883176683, 464091633993841106])),
-                Fq::new(BigInteger::new([15567667874543387178, 11690172073687484101, 15672746535333055200, 1740967051045784543])),
+                Fq::new(BigInteger::new([12868966153902050342, 11105803054461601653, 14674677521652595335, 1249015834323690717])),
+                Fq::new(BigInteger::new([3761781616782249820, 1519370798822472258, 4735670945011718825, 2739818585471695717])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([17292650372572731719, 4390410843183201817, 12690856550525752152, 1237055304472850986])),
-                Fq::new(BigInteger::new([797024984199942294, 7085036252541802589, 6263298763620220877, 799524820780849786])),
+                Fq::new(BigInteger::new([14579638538015783358, 2079827702820117263, 3609877315187309577, 1774473012581211418])),
+                Fq::new(BigInteger::new([17308693209211248348, 16750420647805352947, 16903960008718521794, 732643430239568746])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([2275295155783703909, 12629001222331148655, 6897742240750749339, 1042962736130918307])),
-                Fq::new(BigInteger::new([795852355401384764, 7169515456869904423, 3307611684907413016, 2008831486999017175])),
+                Fq::new(BigInteger::new([3607368699101675393, 3768117990446736457, 15833390793340146755, 2395707070230781665])),
+                Fq::new(BigInteger::new([2408619880392194511, 6155461607501342911, 17994049699857930657, 417528462093622895])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([2846451324543758047, 15016886987955763476, 6960121868706126404, 2151104494901776297])),
-                Fq::new(BigInteger::new([10262265499959014659, 8601159534603190927, 15257272776191302979, 3223804836737624499])),
+                Fq::new(BigInteger::new([16619470704625795472, 8572906260291258072, 910347985853521757, 2283510807875294297])),
+                Fq::new(BigInteger::new([4872552397253558582, 17861419347038449180, 4654041445638473072, 1038064558381620656])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([15682437501766683043, 15070444999718077189, 12749744035658666306, 1626603618082252560])),
-                Fq::new(BigInteger::new([11538416206235843112, 6965002610647879004, 16713405868443656078, 2735503463054037794])),
+                Fq::new(BigInteger::new([2973187346996225395, 15880201341057673, 2733766674007150569, 805967944070040190])),
+                Fq::new(BigInteger::new([4396103197074631776, 12878289389744685075, 8327566817250493665, 2169116843616923591])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([15315858816458106577, 17801008650768669000, 4082929069965973105, 2081681035864954995])),
-                Fq::new(BigInteger::new([18213672609054416320, 10113836625766626726, 7307420054385984114, 760378959310393486])),
+                Fq::new(BigInteger::new([10182604077527385935, 2047680351076821224, 3328785167661328781, 1339702191221215177])),
+                Fq::new(BigInteger::new([12184564044098964853, 14416099572823926887, 18217082399846507238, 1067867460124773315])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([15234313394428037158, 16297872413159771199, 16045418528746992013, 571217931164381671])),
-                Fq::new(BigInteger::new([9930113696989080634, 1508396323420195787, 3631885977536092487, 3056658290916598652])),
+                Fq::new(BigInteger::new([5525334997072909903, 8167401403236370147, 14962009434280381704, 2410798069081212075])),
+                Fq::new(BigInteger::new([753099350312245151, 5342815699587872849, 11544418963050038741, 1937301517625692814])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([13018160076939441037, 907103463685477650, 17855927787491726927, 147575427267771162])),
-                Fq::new(BigInteger::new([15532235247587375580, 2146431160999496934, 11122868463559070541, 523970961651372048])),
+                Fq::new(BigInteger::new([17301099073708199041, 12331272222893480654, 6809698210151286802, 3039942401970976719])),
+                Fq::new(BigInteger::new([5055730359710415881, 7119654852104579304, 3202059414936002360, 1916417893177851904])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([11181654567475169006, 14027585580669870583, 1478420990561489722, 3343263839864650151])),
-                Fq::new(BigInteger::new([16392281289850179073, 9810612878231292707, 949379616488815310, 1472298104548381255])),
+                Fq::new(BigInteger::new([8674544556413295451, 7344551641704846048, 2999352658271277926, 1592233985264040315])),
+                Fq::new(BigInteger::new([2302769345204311581, 7283042597463931975, 15020436714592222177, 1836593107107164600])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([15469091698037334684, 7901858342767334124, 300361856634757056, 2321588299803116848])),
-                Fq::new(BigInteger::new([414337304086612899, 2185871906046761055, 5580377750320409668, 364237878896993564])),
+                Fq::new(BigInteger::new([13925894543848963153, 4615000475063400047, 2619283859940955220, 1797542302290357169])),
+                Fq::new(BigInteger::new([5220676369595445542, 10443799775963604013, 8013528322925874971, 2793491049667229868])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([14039124479063436229, 5446043819216634644, 7812623794109163601, 1595514634113710104])),
-                Fq::new(BigInteger::new([4406521949023689237, 17979596945477852877, 324565291148988597, 1088803590447059169])),
+                Fq::new(BigInteger::new([10153067566404569330, 18130485764100592218, 10998390948078745432, 451586643732390188])),
+                Fq::new(BigInteger::new([2077613238178457940, 10090552062174198581, 244165948539664231, 2527562921136221715])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([10093188107488632410, 9747517417793449553, 10214279478513544574, 3106273785461780597])),
-                Fq::new(BigInteger::new([503013628808577983, 17392085160619499593, 12512131396377444932, 263704260183022192])),
+                Fq::new(BigInteger::new([13685509224896920451, 13012496302589316952, 11748109632386607265, 2185124570153652670])),
+                Fq::new(BigInteger::new([17454036283859328919, 3043543642781042627, 8165188141191133555, 425528495989022204])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([4536810399819706006, 5771678645403944097, 50813325066698069, 1551615091344763499])),
-                Fq::new(BigInteger::new([9982126520612338761, 7650425061605853567, 4242177856587714329, 2910878014798514708])),
+                Fq::new(BigInteger::new([12191173376765638233, 5195181247222699139, 598473791348013057, 2310342967904314808])),
+                Fq::new(BigInteger::new([17285818182268780831, 2580701376574985828, 8883932666360592271, 932147795553740669])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([17222959870371653928, 1958116438368272874, 4350865801909725596, 2721799607002088170])),
-                Fq::new(BigInteger::new([12517503945422523964, 16376645726966610589, 16548784195689670925, 1792105255903053509])),
+                Fq::new(BigInteger::new([468552179054484302, 16119328102680921537, 14117417563402135903, 363069692151263846])),
+                Fq::new(BigInteger::new([16748657464932852406, 17785513846159640968, 9214181479903091137, 3316307792010688937])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([4070687981860886270, 16521079697250096238, 2291020814893921571, 3304570226954543847])),
-                Fq::new(BigInteger::new([13054391653866929303, 49532275362929911, 14566551854193764044, 1527285227481096566])),
+                Fq::new(BigInteger::new([6537406720139409882, 17653852093311334783, 15014117944069903891, 570957654612572965])),
+                Fq::new(BigInteger::new([16926668731534440157, 8317539471711869644, 18239714750070757130, 1681850885290573929])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([70070755758116380, 11254388456341298544, 5887811290300479781, 1035011649124769250])),
-                Fq::new(BigInteger::new([6112530926798823422, 3445364603206366811, 14899799139676482203, 1059945601035517572])),
+                Fq::new(BigInteger::new([14122708966255225834, 18221272500436080179, 15122294454778366766, 2183511436474513713])),
+                Fq::new(BigInteger::new([12964223281217890581, 448173803515533022, 5998622858462877346, 1337548488304095237])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([14118015134448359664, 14123512110026470353, 13589379153548912184, 2903322864740955656])),
-                Fq::new(BigInteger::new([7756445704123275307, 7262975949367788874, 4185119329851625814, 2108545135450672221])),
+                Fq::new(BigInteger::new([12997036867541185573, 12726945946729526562, 17972120044446302129, 1752539236701994369])),
+                Fq::new(BigInteger::new([16724682291289569209, 9015067604804069065, 8480903122472766716, 3167502714377073650])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([1304054998086048345, 11086303851537152714, 8130701022669053510, 1748396170222133227])),
-                Fq::new(BigInteger::new([4417472602880456124, 11577382111553781530, 6284285611035132067, 1445435137264829739])),
+                Fq::new(BigInteger::new([9884645689941179365, 9540108542992140609, 6236109961106184769, 460008567264344058])),
+                Fq::new(BigInteger::new([16325920471863240214, 10357715017799903731, 17676620437695669772, 1892788773964015661])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([13693591219870475288, 10477469747753428144, 6989559578529027432, 2359369547537472188])),
-                Fq::new(BigInteger::new([187212608547301128, 9800439353382625426, 2329754755331439371, 1399192575499653748])),
+                Fq::new(BigInteger::new([4854723428902798005, 9104617642770771763, 3181592235193349937, 2811758974754117337])),
+                Fq::new(BigInteger::new([6877588237937722973, 2393055273943245596, 8750968593993095736, 3183061151886729160])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([14814483017666812690, 18330716537822287120, 11332841148533843720, 1845089408784578924])),
-                Fq::new(BigInteger::new([7600865508868080206, 10612051430006539364, 14465613140420024236, 1189407209700937350])),
+                Fq::new(BigInteger::new([8379697745965295652, 12321228053297358230, 15554739400010229496, 564630124827073490])),
+                Fq::new(BigInteger::new([13492038540533389996, 6077322692052770395, 1328445105945267742, 570042718861731330])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([9270070823030716682, 2903137675422310439, 9765715683153702337, 3340176654685009744])),
-                Fq::new(BigInteger::new([1511172769867060798, 12100020068269439621, 15070290610790537289, 1692705672143093557])),
+                Fq::new(BigInteger::new([10078467324481424423, 2728957923336976006, 5056586543863394671, 1621172945005739349])),
+                Fq::new(BigInteger::new([14454425088341252659, 5050540748547665973, 17538926365931137279, 2954270443707471008])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([13942403146984972438, 8949186798443456105, 17827142164744919494, 526055412051631188])),
-                Fq::new(BigInteger::new([4557404560587020467, 12898038914035112360, 14505729262447444803, 341644052385153181])),
+                Fq::new(BigInteger::new([16055405210098419827, 14721831558385369338, 10256526867823058471, 2309261670158640460])),
+                Fq::new(BigInteger::new([17245014913821585685, 17397910722883001423, 6372605746829300931, 182747075749715456])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([13309673769634724518, 6511374078816749786, 14144287977903618485, 1168505923429472034])),
-                Fq::new(BigInteger::new([3028599228646781723, 16383774521796914584, 11778436462309517953, 3416699282634767466])),
+                Fq::new(BigInteger::new([3085717056742449434, 7078881868314454513, 3686301128467950982, 2266819471994072502])),
+                Fq::new(BigInteger::new([13972514893925583849, 7668580042847108951, 13926669502042793593, 3268233009433205654])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([556826901473635921, 13503187242159076960, 17070450665062548408, 262925688225978680])),
-                Fq::new(BigInteger::new([13631382165967710195, 12201516165863937871, 13546554997032390068, 2287003217799607268])),
+                Fq::new(BigInteger::new([6727249676621113998, 3050001469395735139, 1633847378830133142, 1356133897538247442])),
+                Fq::new(BigInteger::new([15533632936651283538, 8961406254141819307, 17672635878062472614, 2664490865782676028])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([1668437356076209801, 7579823834209353869, 5712648622658894085, 278689360324501310])),
-                Fq::new(BigInteger::new([14643449874055688085, 9793259295894904339, 12168653705690099585, 2186693526676341728])),
+                Fq::new(BigInteger::new([9385150229817506388, 14774165582831825743, 16160294558019573485, 2677345627856469900])),
+                Fq::new(BigInteger::new([8581201997931795945, 15926892571163227430, 15328449283615661555, 1531163126363715185])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([1096797312555478719, 6010036050058384955, 1476550338575474407, 1957848999649789415])),
-                Fq::new(BigInteger::new([18355750897791405069, 18426123090424476897, 3618707434052601808, 976699227915717447])),
+                Fq::new(BigInteger::new([2517802101806960712, 761528101127881527, 17281386923470896764, 2710151797441243256])),
+                Fq::new(BigInteger::new([620805109021079103, 14189327810102447438, 4098716979267321990, 2870550233328561716])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([9466298894242125195, 6026691891090515034, 10887528279744482525, 2955644627124605721])),
-                Fq::new(BigInteger::new([6944529398026491127, 13793079573003292357, 815653769580043229, 389203237139394689])),
+                Fq::new(BigInteger::new([13612798698030413789, 2939630333072701762, 14311982164713722101, 558028682318871995])),
+                Fq::new(BigInteger::new([9050919505262794854, 17982196097726870816, 13848188998224130866, 2118593165266831103])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([2199939281408719427, 10637232545975493770, 3879230819664989668, 3224481174169988176])),
-                Fq::new(BigInteger::new([14756373637779054156, 18099446093834806691, 9944026800669480335, 3375061790263344185])),
+                Fq::new(BigInteger::new([17602296775999240706, 10508120007738745988, 2296365122391671849, 1387590529350703354])),
+                Fq::new(BigInteger::new([9070066253029267179, 6242700849982108385, 1613457936149591628, 1117661633589850707])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([9277504079475845436, 8134191502561459174, 11846838166626299699, 595197438109859467])),
-                Fq::new(BigInteger::new([4750289170920109264, 12340019055520829214, 3502884545101854697, 528474612918916576])),
+                Fq::new(BigInteger::new([6809105045530332979, 14804415597306427174, 1376053716389341978, 3083473666869108669])),
+                Fq::new(BigInteger::new([5527395831079804323, 13751381687888723980, 3389815024811847111, 2765161618760251297])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([4940161168968999988, 17874459756377112078, 3920812217702690567, 1437954527249783723])),
-                Fq::new(BigInteger::new([4838272036140019734, 11491972975787292430, 8848956172421705577, 2501107423140287701])),
+                Fq::new(BigInteger::new([14347210446992902938, 17633409484409016654, 16205705410705178869, 1794051181723490033])),
+                Fq::new(BigInteger::new([7518616299550140948, 9366115567841260292, 14669188413293319301, 2016431952099530448])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([6023687541162872085, 7342997969213751986, 252355860918197150, 2233533065754431645])),
-                Fq::new(BigInteger::new([891044695476336345, 9631900882827567894, 8312061436954062386, 309758202534661804])),
+                Fq::new(BigInteger::new([1210427037554502305, 5099654351440275511, 6780015979444548206, 3295020814035679745])),
+                Fq::new(BigInteger::new([10891809996888504966, 1423699883513345179, 13517564647804799063, 712715497841148490])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([2889506901389781817, 7618364887045359399, 18113597555388843832, 1092785569070594221])),
-                Fq::new(BigInteger::new([14998334700543325775, 4384677289053403355, 11719404875097226833, 2565011240552215413])),
+                Fq::new(BigInteger::new([12591910192469163352, 244851945580543565, 14845194204672300608, 1788648737139641748])),
+                Fq::new(BigInteger::new([4578202542200299096, 16687037060294366116, 10558086180064411566, 925307391369419797])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([2512279479454954756, 16778929733074553096, 10301133242469474379, 1074724556765188853])),
-                Fq::new(BigInteger::new([6224225546008622222, 8235942919927601882, 10123553345064991824, 198633921168162008])),
+                Fq::new(BigInteger::new([14805956361149491815, 17393219696441706234, 5557066789633234432, 834604950223183463])),
+                Fq::new(BigInteger::new([9443243435154188681, 12263190721315518905, 18431389043340505967, 1062611828453130258])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([17606817518616062502, 11507288437583698166, 8716225252117583965, 1903629483547316674])),
-                Fq::new(BigInteger::new([1653416573878399887, 5284124573917545717, 17851590976867307989, 2821546855967030146])),
+                Fq::new(BigInteger::new([10466097868705270418, 9781947555952706342, 6412592648407299389, 1182639211778970361])),
+                Fq::new(BigInteger::new([11901972051188255278, 164762760390338063, 11093767382369920223, 417569052783996458])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([3776961205391590468, 15554132554472050154, 12131886961309493265, 1353613509844949710])),
-                Fq::new(BigInteger::new([13507103598344646467, 2494630542022452645, 1001571854852790514, 3353191041331728895])),
+                Fq::new(BigInteger::new([5388431185311201335, 5433617212347851902, 3579362420125919212, 1107076294720113860])),
+                Fq::new(BigInteger::new([5546197972123892544, 11002275653072732264, 10175641587331944671, 65732939559661087])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([14925068784953538029, 11229911785287922009, 17479470648060446647, 1861418793953885538])),
-                Fq::new(BigInteger::new([4933030963243450393, 531715283682173004, 7670499050046766764, 2950524633414138438])),
+                Fq::new(BigInteger::new([7341573490219244352, 331012737371061781, 6787309604520013903, 634443994916190313])),
+                Fq::new(BigInteger::new([6255251807301932601, 5162454962315870383, 6280207148090194317, 2366222764533303345])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([8299669688527227600, 12712733920561988012, 1622606108207419272, 253248745101368962])),
-                Fq::new(BigInteger::new([2591436161956978269, 7029929434486551935, 3156469831669871720, 2102251743433162427])),
+                Fq::new(BigInteger::new([10761491470701319792, 5926642387527169573, 7723215570733102207, 3421676825500894019])),
+                Fq::new(BigInteger::new([6819333908234170946, 2408929183887323187, 8045735067792071525, 1055108186061667968])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([16738456475514550555, 17969474795623474761, 17125453289060743465, 1128339700893425992])),
-                Fq::new(BigInteger::new([2294487439634117009, 17120109616021855374, 12377351322340682314, 741195174580097520])),
+                Fq::new(BigInteger::new([2804883827655038568, 8027486267659939942, 6637529347613770487, 2005284674505703850])),
+                Fq::new(BigInteger::new([3428951877433355560, 6049807916309910953, 10107028304800489418, 1050620134794246565])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([12420500198813703491, 9403295937464118602, 5791151444593511018, 2623633497588394605])),
-                Fq::new(BigInteger::new([14009710168442701073, 14352805971228022894, 8815494610853243459, 1908736964262630546])),
+                Fq::new(BigInteger::new([9840470643879775484, 4829146044183594192, 13661105307617932964, 2349267276733719554])),
+                Fq::new(BigInteger::new([16194640638971838928, 4693759886978601071, 16455151822290689726, 3008306122269246210])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([4953080375939852889, 10352805971967068581, 8812052394941193572, 2821221690992190609])),
-                Fq::new(BigInteger::new([8916467312816735890, 10569082750222945030, 3849350137237194435, 2219182410456280924])),
+                Fq::new(BigInteger::new([6207718068639342987, 1309556733826085154, 18414514319384344193, 3436193672149672459])),
+                Fq::new(BigInteger::new([15348118769679388791, 1737009630222730485, 680432736980909225, 2107323372275121818])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([5154164411663975728, 1941433891748471428, 10754949332450853603, 2063933738630249040])),
-                Fq::new(BigInteger::new([4677360940880618861, 11869835992544276409, 8558189355701538193, 1539318201347362398])),
+                Fq::new(BigInteger::new([17329210339506128480, 1587352142060584753, 11303018380267669858, 2650755732823713818])),
+                Fq::new(BigInteger::new([6471645956949187480, 2160366507269251924, 16509197290829470494, 3395739293562938454])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([4995640132538010198, 3093488778327228339, 13300324731922956865, 1486552691178051036])),
-                Fq::new(BigInteger::new([2123295448290375147, 5349521426472661687, 2499062233979904176, 3406205733038196509])),
+                Fq::new(BigInteger::new([4429642067259206932, 2864725098433168496, 8651982407563693474, 3486105935470173314])),
+                Fq::new(BigInteger::new([10291499041414923626, 10839299130404082924, 4619173524325197831, 888956976226276551])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([10324338076057546301, 12435518989781377527, 1150149937121560151, 1230520656167026093])),
-                Fq::new(BigInteger::new([14379499895762665007, 3717118880869056758, 1190833481211052536, 1981723839866497765])),
+                Fq::new(BigInteger::new([8335811491251909405, 17832921817721024182, 2489621755036944525, 1657945184506976304])),
+                Fq::new(BigInteger::new([7036232443129451839, 904265190940001082, 16742171446346980912, 1200830882988074156])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([7515604540473525618, 8021876937771820881, 6392459560253406676, 483865541976052839])),
-                Fq::new(BigInteger::new([15673435284592780246, 4833073399372997790, 2336555254618767178, 3410319818911530944])),
+                Fq::new(BigInteger::new([16574971476334976179, 13725952420730559782, 15974753855047828631, 148335938473839334])),
+                Fq::new(BigInteger::new([5002514048971663363, 8687359882791095721, 15849227755884289816, 2733953756305988880])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([16307862271444703937, 14368102691272808107, 500099231777505882, 3246072812276259204])),
-                Fq::new(BigInteger::new([4123563320813832776, 15839865316496150981, 1888798301974751946, 156701541453910549])),
+                Fq::new(BigInteger::new([9191707530756503541, 17355051148686750190, 5991299511393582484, 1205030311240636158])),
+                Fq::new(BigInteger::new([15879141703191005880, 17517118412751980266, 9670957456851363350, 2644913238967146863])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([3575439444408880049, 666208461148903722, 3085649511032175175, 3002287193549436516])),
-                Fq::new(BigInteger::new([17440278838707772096, 10544456621431161567, 15004080206677882587, 1041006328228566378])),
+                Fq::new(BigInteger::new([17773804803907468456, 17884731842095488819, 1263700832874835506, 1186484809409436598])),
+                Fq::new(BigInteger::new([4496802745102993567, 10977510805392705692, 8211839423189375641, 626257195210832297])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([16668694965482274726, 14153092004030537532, 1257711895313723150, 2811857345611991142])),
-                Fq::new(BigInteger::new([14323962485508555171, 15398929257587734974, 10550232106716602802, 479502804784468069])),
+                Fq::new(BigInteger::new([9421275699602094181, 8384147010383113417, 15650660854612406983, 81566997118267102])),
+                Fq::new(BigInteger::new([6894897230244348405, 13052803587064883402, 6045409105432921343, 1780585338505366485])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([353380579531669733, 8436205228231805537, 14202847336307074564, 1558286017436436192])),
-                Fq::new(BigInteger::new([7741575329144744850, 2698373293756423314, 11433513785329217510, 343667304278882232])),
+                Fq::new(BigInteger::new([2712280129495555132, 1123725169129007640, 13669931215802911497, 2194133962836731715])),
+                Fq::new(BigInteger::new([3642001492228109985, 13816873864328186155, 8781391191429513838, 1560995828445073525])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([16103281680615263880, 13972357344304774336, 6934235204824820328, 1483121548514249037])),
-                Fq::new(BigInteger::new([6210426949431889507, 2105176643238851587, 13489758546130660682, 688975145441016045])),
+                Fq::new(BigInteger::new([11352893118708164676, 17450800075108735862, 16967003634498400340, 1393539267808215613])),
+                Fq::new(BigInteger::new([4780811149969360006, 6547449974653273965, 13981273498757243218, 2131091877593919534])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([183297309641012852, 6088168484384186932, 3149854563879540487, 2105250446581297107])),
-                Fq::new(BigInteger::new([16418600081431918668, 3352726000465667406, 3662244707786999067, 2899102295783370236])),
+                Fq::new(BigInteger::new([1071357807057155270, 8234764586334185571, 17722957018628245177, 404034161028808650])),
+                Fq::new(BigInteger::new([18215239177913523105, 11919037941171185748, 13509759260496677231, 1036506962270641558])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([14171247548680089260, 7655153265317991961, 12072443212128446969, 3414051134265255826])),
-                Fq::new(BigInteger::new([7240392901629087008, 2612525619996676128, 15774566724821263744, 3313668310586687787])),
+                Fq::new(BigInteger::new([984264157884156157, 12234160500123568771, 1303764018368761831, 21388853442806958])),
+                Fq::new(BigInteger::new([1285823890726840594, 11937077779886969164, 3708908615717385131, 378919159249746430])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([18139032106159386808, 10054523000550460740, 5854085859956443506, 1316287754260306961])),
-                Fq::new(BigInteger::new([7768832110228056693, 3635321048197889597, 10610458067574546325, 2178802044358333975])),
+                Fq::new(BigInteger::new([13553386920690152128, 12636035969360489784, 12933906424373569000, 3080184601395624990])),
+                Fq::new(BigInteger::new([3931179210608312226, 12901186972633641668, 12381548150351976666, 2583940909409968312])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([11141705014939891381, 10558933885939853073, 10538718544584024060, 1402328068984123098])),
-                Fq::new(BigInteger::new([17577423824821860413, 15619374771999592624, 8748415848563417028, 366052827297752099])),
+                Fq::new(BigInteger::new([10024060319963571474, 17135772934454249279, 9234692095502813946, 2899799477855289863])),
+                Fq::new(BigInteger::new([2109325680789775953, 13735115146016322127, 14209659429804215985, 1621762483893935442])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([12346344087950515099, 6727870721292093794, 11123659879642187447, 2925953942837015076])),
-                Fq::new(BigInteger::new([7092582001016896494, 15334430269149272976, 18436817043027979730, 3017554067788255083])),
+                Fq::new(BigInteger::new([13495334048400125213, 9008696179238448295, 12938944225877300735, 2368074902179949473])),
+                Fq::new(BigInteger::new([12727384658307801416, 17701618016532923695, 1103872527305610255, 181417424123847645])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([8492628177791872157, 1446230126081399397, 8332843808544738738, 1370126075763555589])),
-                Fq::new(BigInteger::new([17917527515310493800, 12834166821257915318, 10608745201204240267, 1592625884069205172])),
+                Fq::new(BigInteger::new([10582245881442548410, 15201388973833599475, 17160753744468270622, 2158993586735874938])),
+                Fq::new(BigInteger::new([592587084009489693, 3071595427914852124, 15053098481424786389, 160970753486636630])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([732226468503306298, 9099512530472517133, 4168569801124061798, 1810098181772955432])),
-                Fq::new(BigInteger::new([9014828103331570892, 7137814003457746383, 12904195719437834829, 2249304098601881545])),
+                Fq::new(BigInteger::new([15634102455467719002, 13921581893595366212, 17918282241953578036, 2741846549332348675])),
+                Fq::new(BigInteger::new([7785775982890279470, 1490693645401005197, 6753068597271812655, 489560464196607504])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([8458875696449882578, 16514148460398859321, 7188700233956687639, 340127869260662643])),
-                Fq::new(BigInteger::new([15814707704258512206, 2714695412896549376, 5000175998011325616, 196079902382824247])),
+                Fq::new(BigInteger::new([6400314454915356086, 15482917223557543174, 9944442850277284527, 1959298455529654373])),
+                Fq::new(BigInteger::new([2749784181884173102, 3852326269280074172, 15691758517033185349, 3328630200437777031])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([4007751776444663737, 6915275817424140352, 10863967437897306511, 2126171496796878012])),
-                Fq::new(BigInteger::new([4890154464579570566, 6219085138662411322, 13931964497981015476, 785897575264880466])),
+                Fq::new(BigInteger::new([17047654078304033927, 17900002650722807312, 4096721741328016992, 1235892865513510480])),
+                Fq::new(BigInteger::new([7056427168128656056, 10771904816248200688, 2175750108418525152, 1236370909856237961])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([13457013108544338322, 1186884999985444084, 17645667182386694590, 2513437829947716694])),
-                Fq::new(BigInteger::new([15879457411083972563, 8820604232712770540, 13934009973448848617, 1393805394965630812])),
+                Fq::new(BigInteger::new([10581120143441097359, 4077059222330576432, 15497112522464977776, 3212120719295067790])),
+                Fq::new(BigInteger::new([11362289495652500411, 8012420710095612674, 2491968942282358266, 3291351140067278609])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([399803241586888871, 8840556779697774450, 15642710282231405295, 3099159064774135389])),
-                Fq::new(BigInteger::new([4769513886007350176, 1617344296314549752, 903392306476267392, 3287750747419116919])),
+                Fq::new(BigInteger::new([18266975991443222196, 1994230390197958912, 16308103199885495066, 1971120817544922373])),
+                Fq::new(BigInteger::new([16098980279755601549, 15927798020727517335, 15678667728559281112, 2929349104331315052])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([8691051379382853179, 7320971144180690248, 9035979406795466790, 1406114161132760433])),
-                Fq::new(BigInteger::new([16871655599066104266, 15199163153612127147, 17815022445255435231, 361092838756892647])),
+                Fq::new(BigInteger::new([8245492365652664442, 15611628388030477978, 15749434835599454851, 2991544548088062804])),
+                Fq::new(BigInteger::new([6381271612889548040, 10156285997723460494, 14700871437032092815, 2652665176445975615])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([15188997929212751021, 10786485391684147200, 14419733405169498449, 2769434264339586286])),
-                Fq::new(BigInteger::new([15088623376635079390, 1478151873453598683, 8593875594860483671, 123754622861468807])),
+                Fq::new(BigInteger::new([6116792117247423971, 13197154604644336961, 17521298649430806040, 272083797042113810])),
+                Fq::new(BigInteger::new([10731261530445310554, 5787587450205840411, 1727282084966072559, 871484875105240570])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([6239935670184945027, 8635240458854472490, 16498166651412072870, 3178218781531686424])),
-                Fq::new(BigInteger::new([702965387356923970, 699168518380104647, 2975306923765151784, 2903060441072874710])),
+                Fq::new(BigInteger::new([9798694437055136682, 9106681269303453457, 3103695664704273828, 1970101462196155602])),
+                Fq::new(BigInteger::new([4146345982561710226, 3230323075690806105, 468156658525682192, 3323571870958686186])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([5062453714703764089, 810319451385663105, 8986739698488747627, 1663025038973807518])),
-                Fq::new(BigInteger::new([12175534445326410139, 10587372156694361189, 6981038888659542195, 1416903125976442276])),
+                Fq::new(BigInteger::new([16769293987942056739, 5096611654819154453, 9032747151458946524, 1862682904678388617])),
+                Fq::new(BigInteger::new([3239118827761542193, 1646278605127071538, 12229459916133929029, 1625888626798889143])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([1525985414565450462, 1724918831763394866, 8033991164614073758, 3082928173977813899])),
-                Fq::new(BigInteger::new([3713877704494274521, 6740987771099489010, 4553527112402366367, 1166255033728345029])),
+                Fq::new(BigInteger::new([722238916089290704, 14506037404939997154, 9993560337811803275, 1650845610492038007])),
+                Fq::new(BigInteger::new([17335757663214304068, 3258089010909092737, 12860524266352681399, 195738562988347455])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([16153161842161199622, 3627526175940245055, 2043764448880080608, 1329972570767671798])),
-                Fq::new(BigInteger::new([885282049970186615, 6378264905106693431, 1716536621086259679, 2101156022646594178])),
+                Fq::new(BigInteger::new([7933884588525869445, 12166262816646265212, 9935958202967411382, 3099874574003028766])),
+                Fq::new(BigInteger::new([17186980852703643018, 16510748861016410974, 10146019643355625184, 1225834704766537430])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([13540115555367115397, 10417582310353877431, 12246090709352116865, 609159232347815964])),
-                Fq::new(BigInteger::new([16279057790194513960, 15018949886050589459, 16561149295141702151, 576562285655659240])),
+                Fq::new(BigInteger::new([9869736407989751969, 15618097515300745479, 1570171963048480368, 1000207313870705727])),
+                Fq::new(BigInteger::new([6976188952661542097, 15021669713748474745, 18155862676979723222, 1262850867395398820])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([6918827981322203969, 17474318256448938565, 13838647497269814199, 2264518230081096220])),
-                Fq::new(BigInteger::new([16199868673913673104, 16810159395703785049, 15966889225898424513, 597315660242851777])),
+                Fq::new(BigInteger::new([15581282287356062640, 13276397447007008607, 16815407193774056076, 1688776386933397944])),
+                Fq::new(BigInteger::new([596640435501645065, 8166117114752968579, 10812659035470031280, 263725131885995718])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([16674859769070495081, 13282715045079529395, 16195611981589293810, 2595696177761930767])),
-                Fq::new(BigInteger::new([16714281379495597189, 15868303134877971603, 17816559815592274674, 1768436260804254844])),
+                Fq::new(BigInteger::new([3015323783894560867, 985294518295457664, 3474625628659806696, 2618005718111702930])),
+                Fq::new(BigInteger::new([4758087270118064143, 13541272387411751592, 17715816211412774700, 289292717154625851])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([2158652908248626969, 13304481550824887482, 6469779542741013587, 2311562005661475677])),
-                Fq::new(BigInteger::new([17218606834566030214, 13141228224020529673, 15624256656467125286, 85806322634704110])),
+                Fq::new(BigInteger::new([3490207954950515230, 14042274081570025533, 9260205917388453601, 3314223001311378319])),
+                Fq::new(BigInteger::new([6399818821870355107, 13125392374604441887, 15811221192633804615, 57821251913599898])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([12819112022702450135, 18017527757778036448, 6791183352496118791, 2928518751189438574])),
-                Fq::new(BigInteger::new([6550320606664932127, 6259525430539300917, 46119403155124255, 515046847616855441])),
+                Fq::new(BigInteger::new([4239264126786347452, 6104266883894980711, 723333153756499398, 2960217995385398228])),
+                Fq::new(BigInteger::new([17971456312412982642, 9663471063458214493, 2499460139986165335, 2256889687566420166])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([4221086887292690070, 14718478322628179675, 18035330007092901702, 149259160593927387])),
-                Fq::new(BigInteger::new([14792153021223829354, 4220842122524474598, 1110579028556864668, 1650974261814740711])),
+                Fq::new(BigInteger::new([13159001935954194952, 544271585817922139, 7244430658826030699, 1539918229084547393])),
+                Fq::new(BigInteger::new([16143969327917473582, 3345845622789806802, 17423948113145947619, 2403772551491057669])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([5146727325664994914, 8127331886384565629, 4934166077104390747, 3478039157880371287])),
-                Fq::new(BigInteger::new([4401736078132899517, 1707660696780705258, 6733631928443512101, 3378297617083383564])),
+                Fq::new(BigInteger::new([7424065032241701011, 6495620331466663750, 15974790360081877185, 1604196060718167306])),
+                Fq::new(BigInteger::new([18043624842273367148, 1506136492357904708, 1198514324896983867, 3333679949861421588])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([18276134977295897951, 6731210793630278790, 3176187181504131474, 92855403134651689])),
-                Fq::new(BigInteger::new([12880443621122276999, 18039590164352242466, 12481648422615713671, 830676451278457788])),
+                Fq::new(BigInteger::new([10073568520609552105, 18274449824815899636, 3501466313554169348, 3113404184125045427])),
+                Fq::new(BigInteger::new([6109704587054065890, 4965971427053772363, 3123448441611523304, 2732839025069063063])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([2451744114255273247, 6667271325578930413, 13005428501557336182, 254175181176770842])),
-                Fq::new(BigInteger::new([12160536427649947034, 16260562798997101737, 17576690381195974599, 386392189581833291])),
+                Fq::new(BigInteger::new([1141258918259839342, 10020255671657330641, 1401977482008764086, 1969291514512925299])),
+                Fq::new(BigInteger::new([3167189055643246014, 17928210256519768181, 16129257915331540598, 2520265668735649157])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([12716155088364170165, 17987173216492187158, 12831806661804883985, 1085752913637411098])),
-                Fq::new(BigInteger::new([12535145121636047006, 18354105361188860804, 3860935309468734222, 2752341377703395250])),
+                Fq::new(BigInteger::new([12423063123837997818, 4940086409583350959, 9562294743730347991, 718000884660828399])),
+                Fq::new(BigInteger::new([3489672731064047046, 11137062578461491446, 2061729554874856248, 1773235928330523281])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([7286712774450788975, 18379306613324143366, 16016415364531370888, 319552106366922293])),
-                Fq::new(BigInteger::new([13604668386132515264, 5590011882799894216, 1379063979291472113, 398733282983459332])),
+                Fq::new(BigInteger::new([1092874119948934749, 6966920405679340101, 12078851311011804084, 2675891588897496745])),
+                Fq::new(BigInteger::new([11237393261598491751, 12986506959096635673, 9034488829005071179, 1276083256787032639])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([2403416644936225062, 18335844110612078341, 15183667318981790845, 1352209738352298969])),
-                Fq::new(BigInteger::new([10883907320357538056, 17214015766012472236, 7205501533896685412, 3082591881753612213])),
+                Fq::new(BigInteger::new([1052073620691632731, 8575333620974088755, 11084014562338392621, 3154593487369053987])),
+                Fq::new(BigInteger::new([16976587978919803993, 16044570260050395018, 9007152552984870867, 1795893309053220057])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([7704036371606923934, 15670711485257671608, 7136819985040934884, 1523910516409445907])),
-                Fq::new(BigInteger::new([6150164612203911803, 1718976036339420606, 17565231944257656760, 1345462008265741039])),
+                Fq::new(BigInteger::new([3140651704983117589, 8027541119563518566, 12859115720563688506, 1558774257238601979])),
+                Fq::new(BigInteger::new([13966455601468245998, 8205585343369180488, 15229216623381500503, 50468581937870363])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([9356814882204415906, 11374805671362818428, 6993202783307207637, 3015052366766391676])),
-                Fq::new(BigInteger::new([3122713945504048797, 1768107588204060220, 6657485124984362723, 1654578831872518882])),
+                Fq::new(BigInteger::new([13692874096308608538, 10564435183606105188, 17067732631043372865, 643126976971923826])),
+                Fq::new(BigInteger::new([16149618185365335181, 16599058809928104247, 13595293216540986048, 2901262453463818960])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([3085527733133297047, 647771181535746568, 1490571030487553749, 2794509059522882139])),
-                Fq::new(BigInteger::new([18400304045685065096, 2147325659190267593, 15868402912847936625, 1455667772903362947])),
+                Fq::new(BigInteger::new([18363425424493501376, 15077547200185222724, 14079895658742805671, 1545338990452354467])),
+                Fq::new(BigInteger::new([3830415238336386018, 4806037231428270980, 13160548026751245938, 1434676691417366434])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([3297432593485510095, 2255141070719351667, 15667166466854539951, 3444238186009948025])),
-                Fq::new(BigInteger::new([11720205603029554435, 14949313914395632267, 1750525267099362481, 2267329018880427852])),
+                Fq::new(BigInteger::new([18152065247766761954, 2962689848769510577, 9177764506576533152, 49954332334501914])),
+                Fq::new(BigInteger::new([14679974594390323415, 9641172715477216781, 6918427200574388833, 928358829373903230])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([6034931660649179605, 17181029614374834513, 3583859401225029409, 894656264180466569])),
-                Fq::new(BigInteger::new([14561152475628515374, 1269051268557187794, 10379796351100945064, 1114360610922523108])),
+                Fq::new(BigInteger::new([5839469628609789380, 3486937069065007785, 3214564664698894213, 2051139575585370498])),
+                Fq::new(BigInteger::new([2049799856785930320, 15680927021346302234, 857946956907764989, 1750448257658120377])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([18148127612217524737, 2214154829413969172, 9795500236520633714, 3395234689633105323])),
-                Fq::new(BigInteger::new([6832839395857447003, 5940965098203228349, 9570869900929737563, 637189735674664903])),
+                Fq::new(BigInteger::new([15576213174575898730, 17064923091573531726, 15230590516489394218, 2616635870016061856])),
+                Fq::new(BigInteger::new([4842387492683177816, 6050352402176266601, 18379923414235415256, 2103330408780611192])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([2060365394006863767, 101907956604528976, 8964951131910105835, 2280302750621507217])),
-                Fq::new(BigInteger::new([13892984117607581059, 955911031802563551, 2795327141522685340, 2210152485253273279])),
+                Fq::new(BigInteger::new([10930743118816828822, 4836254962731845342, 13386715913880829890, 3449196626354112812])),
+                Fq::new(BigInteger::new([4115060329388657153, 2691349213772033315, 8468441734432720070, 393024560929807538])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([12504221430860560183, 14522487392276832648, 9797779480722992834, 663471480794462758])),
-                Fq::new(BigInteger::new([17719499967283978220, 16388814396549843158, 13705592419963844342, 1335514278462680663])),
+                Fq::new(BigInteger::new([17491073605300066999, 12374181787111602397, 3310614390848256054, 2566756286648357583])),
+                Fq::new(BigInteger::new([14488769294837397825, 11162575785434881858, 2354822922644698732, 2685645982368466297])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([1846673817403162576, 14435719299504875572, 9594445764390590656, 2547587887950624048])),
-                Fq::new(BigInteger::new([18418182629621790178, 8032148252686645918, 7141678385723211905, 2789354380771165894])),
+                Fq::new(BigInteger::new([16585445372579437544, 16496165642158784098, 18284688408846150395, 1366923993847707843])),
+                Fq::new(BigInteger::new([10034023539093079833, 3300284356281274465, 9634645323473715128, 822314217940182451])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([4193902442013075893, 9631851793364412947, 10898119601796112535, 2248275090496246650])),
-                Fq::new(BigInteger::new([4784095392333413085, 4693899030644137427, 10474717400984971586, 190378835742044849])),
+                Fq::new(BigInteger::new([5657919258648959496, 8330187292415859252, 14894645249492857760, 346389145328233180])),
+                Fq::new(BigInteger::new([15529457529246879615, 5203513329817159950, 2154772461150181976, 1860325840552338947])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([10351028050167259511, 5643324161990018827, 7332261591738442495, 1087660529128282386])),
-                Fq::new(BigInteger::new([7491807908899404658, 5560933234533214647, 12469539564084859119, 2348187207522354121])),
+                Fq::new(BigInteger::new([17442051985158487231, 13191666045624086500, 15989000777274644039, 55938840074173085])),
+                Fq::new(BigInteger::new([6648551415028216934, 15808832679867023428, 8722783616214244735, 2616658201109705013])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([8606568705811268070, 3842994138588630082, 14108323530495917302, 526405409400431646])),
-                Fq::new(BigInteger::new([4093745348889399, 8466836665311021055, 10125291511170722135, 1917963933616729320])),
+                Fq::new(BigInteger::new([11413681450795363078, 9785505681245014371, 7589488964769061761, 1156153287514574608])),
+                Fq::new(BigInteger::new([5969074422209664465, 8081272480641921873, 8333012270257933379, 367191806645515428])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([15108712431024248242, 7702642790150760770, 8715142776289156250, 793620215126720449])),
-                Fq::new(BigInteger::new([10497236754327538780, 3673479643273657452, 3946671783624242148, 35953188665862134])),
+                Fq::new(BigInteger::new([18093287411265672136, 14054245562599139093, 13468690202277756933, 2684554296450725759])),
+                Fq::new(BigInteger::new([4416486606172104721, 156707973112158349, 12839711410282112211, 2226013071415508322])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([13919517200041937175, 18256582785569854590, 16557011370162068979, 1385059877219353489])),
-                Fq::new(BigInteger::new([155638863391849113, 13133485105599417241, 18041565919627822885, 240855583092429509])),
+                Fq::new(BigInteger::new([12682489269962854905, 16824224915525443308, 7387117328999969873, 1782947511364345224])),
+                Fq::new(BigInteger::new([12674023883555053928, 1877155176385138230, 8129001858756855508, 174929245818636123])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([5665198897040371555, 353976653942481873, 6192834708123156523, 3003264611922221836])),
-                Fq::new(BigInteger::new([8253684686802447116, 14434610474099519326, 14683755011997947707, 677062387306365467])),
+                Fq::new(BigInteger::new([6149303126732021826, 16871848808494456615, 18154852451912700150, 368231907608031927])),
+                Fq::new(BigInteger::new([9096201698324407486, 10647414267901836674, 5070263005623222743, 394269394853386866])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([10906620378489170961, 17836740786800700644, 3867795570734983338, 2497695613267457794])),
-                Fq::new(BigInteger::new([10508422857205585169, 2625670412686372367, 5339054488668096814, 1591740237290858035])),
+                Fq::new(BigInteger::new([3921471584801035911, 5336417050830492914, 4041225236970251175, 230596659863933236])),
+                Fq::new(BigInteger::new([10064667128112788863, 13619661021420035611, 17492908552561417994, 1588570824912994027])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([12148486219202696868, 10362867299257714414, 2124970080635149599, 1318886980505116529])),
-                Fq::new(BigInteger::new([5886601702929095379, 11441404071962825063, 15046247484339718330, 401541084577598893])),
+                Fq::new(BigInteger::new([16295727165507424234, 5442883708734855849, 9010382058913893991, 2636546353399730953])),
+                Fq::new(BigInteger::new([8153854044742194299, 17702483250707579790, 15257096923174263380, 482943164372811938])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([10028343386572173751, 13604184799169527981, 10272440317790573019, 367632127171757847])),
-                Fq::new(BigInteger::new([5582745668989153005, 9719258631052739723, 1248013881194399688, 1518360116085143671])),
+                Fq::new(BigInteger::new([13695265491412548812, 2014739447989618551, 15442266230684103505, 2312467688795804082])),
+                Fq::new(BigInteger::new([12198769724167504159, 4695665956677054087, 16257383090424901498, 73137579632745644])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([12707739041949539667, 125668656135951959, 17236871001302344039, 2123138754841390133])),
-                Fq::new(BigInteger::new([6406804164900838658, 10373317432899708059, 7146550830210949323, 2061771370313927859])),
+                Fq::new(BigInteger::new([12604830806099919812, 10341659208638703968, 4715092694613117954, 2252212058979389689])),
+                Fq::new(BigInteger::new([4063839944820304769, 15606873841546118835, 11019661219342858171, 2412281499678895485])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([233391175578016103, 16419367914534741989, 18140416245822120139, 1496448631012445061])),
-                Fq::new(BigInteger::new([9986082920849642231, 16962423406239583174, 7304171480951975946, 2998171312743440937])),
+                Fq::new(BigInteger::new([12359356861293099989, 7541015466639871406, 202522410901918899, 2053607866280231830])),
+                Fq::new(BigInteger::new([2646422762882930171, 1703283668590915208, 7231481797953857582, 1418069008007405615])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([8791035375741242605, 3349855000711071833, 398342961408079001, 1672799514028867178])),
-                Fq::new(BigInteger::new([13840724301991691039, 15834865494123432271, 12072620140128014313, 405138640358801104])),
+                Fq::new(BigInteger::new([9359951728652651652, 14311923755832007955, 8095244228567246267, 297648245081398567])),
+                Fq::new(BigInteger::new([5516263468987976005, 2619328180994503975, 14612514497389101479, 1156123453738249187])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([1752872256295074797, 178722888030562205, 15537147227038085028, 1433016614984907876])),
-                Fq::new(BigInteger::new([6038569184943543256, 9895033663177661496, 658256402706278795, 1488385790315087605])),
+                Fq::new(BigInteger::new([6392701072502177839, 16839976837721034111, 15434021158493199113, 2900506049010682213])),
+                Fq::new(BigInteger::new([8181137095504079422, 456145147625142811, 6773333392915502927, 223294458548052268])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([14930539439208238517, 9322212693557739945, 13320184779765512121, 3100155553556764530])),
-                Fq::new(BigInteger::new([6636558617468127012, 15232517246973830494, 11124236087945604511, 2383681136066334294])),
+                Fq::new(BigInteger::new([7668325773171527475, 11012888271552093469, 2190111795016000044, 2691248365941595921])),
+                Fq::new(BigInteger::new([7233276232624386698, 4806289138818436103, 18227073243270833238, 954961197068429040])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([3162643289635360063, 457410226848681319, 88707093430379023, 2604441898644414224])),
-                Fq::new(BigInteger::new([3292245467652252636, 12473281570162077266, 16029769178943603407, 2640700725615175984])),
+                Fq::new(BigInteger::new([5094392262259944328, 7538604398326339055, 11964802838651885235, 2988491052043943550])),
+                Fq::new(BigInteger::new([7677780103699655740, 12512388351282840101, 7520425191445583702, 574274982166178976])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([11974752255696013553, 17869838219140918810, 9395946587301162511, 499012931873863534])),
-                Fq::new(BigInteger::new([5796732316208806478, 13375313486392051843, 6319807986605530467, 1669410586020318545])),
+                Fq::new(BigInteger::new([3292190221894984813, 9673687116443712799, 16182568699702063787, 1036629547291037050])),
+                Fq::new(BigInteger::new([6508189165774950755, 17304081078982674826, 1744385817396092561, 519120310734502041])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([14315724512657315607, 8699662324716020305, 3368420718784526878, 2274958778325705701])),
-                Fq::new(BigInteger::new([15358960853647075871, 9076523125355845835, 13401970053236893901, 1610911966138560716])),
+                Fq::new(BigInteger::new([1274993171084853464, 7967006124716820138, 10350847141168421168, 630651952581944564])),
+                Fq::new(BigInteger::new([2173795943083822399, 9796971699898411391, 12980083698606452397, 2301926938223365956])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([15324576171564312331, 3363442985873253358, 2862519130121585136, 2217787572229916862])),
-                Fq::new(BigInteger::new([17220534333271505134, 8759936956643723992, 8369489456250507700, 3035440641080248734])),
+                Fq::new(BigInteger::new([7660748632341726483, 13765948281095166687, 1076421962536329048, 3460082009463404434])),
+                Fq::new(BigInteger::new([17017505331450601539, 8535539727270518469, 13989533118619043488, 877513886888464603])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([16643674106567474142, 13868427252366590063, 15695064192205546142, 890365973207600441])),
-                Fq::new(BigInteger::new([7302698968013437379, 11485382396247419671, 5635591330595587890, 3022891189966818742])),
+                Fq::new(BigInteger::new([16666481777049982706, 16583560073503566205, 11259108418742685048, 1211229747268271725])),
+                Fq::new(BigInteger::new([3700643420050873114, 11532215811732949542, 6529801237068298596, 1628946573736864247])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([7882462022755576152, 39434801438967879, 14470076071281719418, 3447065879711592586])),
-                Fq::new(BigInteger::new([3363419170421980070, 12956146112682326874, 15734285975233070016, 319039192019060276])),
+                Fq::new(BigInteger::new([1941119241956067146, 9794278293805066317, 12234531242204015718, 889738302251577742])),
+                Fq::new(BigInteger::new([17655523590644634776, 18319029652694614964, 647409683181791873, 2809368160348500177])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([12658083809234096707, 16073303027382677698, 16182829787568911934, 212277463362965735])),
-                Fq::new(BigInteger::new([11325974193360259878, 2012681959292706586, 508399255992732328, 865864408482705208])),
+                Fq::new(BigInteger::new([5806746188336998018, 789647083593443066, 1279225873083735973, 1857140424819040645])),
+                Fq::new(BigInteger::new([8599094410912028593, 11879892151548651811, 1975292755700893784, 1128990875913085272])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([10119132452668990312, 14400693125152813379, 14011051130229306513, 3186900610122486564])),
-                Fq::new(BigInteger::new([14644350835782748120, 2269254356081692386, 5078447318388354249, 937798788404590551])),
+                Fq::new(BigInteger::new([12733849864550642143, 17862909503307199729, 14870877281554489306, 2232907343437304434])),
+                Fq::new(BigInteger::new([13215449913387452482, 16734020651497143762, 2510352195829308509, 784665111547340887])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([2326106296740905354, 5132603745871813584, 1277658688179892901, 2962914932246895748])),
-                Fq::new(BigInteger::new([766529493680712945, 10171028759379349423, 13254314509239515356, 181019913338558230])),
+                Fq::new(BigInteger::new([6611994683751008164, 7640655260473058785, 10855722231166561246, 2569367226792671779])),
+                Fq::new(BigInteger::new([15445105545947903081, 561012323665207414, 8903985768756512210, 620858988042531841])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([8563119904984944883, 14539398881574009087, 2886061864630258655, 1531336917621305834])),
-                Fq::new(BigInteger::new([4452233039382756710, 12912124231556389654, 444903697643921122, 1326966544444054836])),
+                Fq::new(BigInteger::new([11270433424507052921, 442030780252808190, 17344155976380380246, 387106294959485775])),
+                Fq::new(BigInteger::new([8809366779374087350, 2522401842063370842, 6723269951748485582, 3383476372147403359])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([11299635841199881009, 9916379211625077272, 4183805127181216718, 2508357820154351884])),
-                Fq::new(BigInteger::new([14442847060655231691, 9278637192694065066, 7655718072620663135, 1837777459412415504])),
+                Fq::new(BigInteger::new([17604561634616946814, 57872299746264540, 4649812565810666180, 2469600129948475444])),
+                Fq::new(BigInteger::new([4628969718430962779, 873162700294314455, 18260394196555508874, 2208743682327827887])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([8336976774408021522, 17960675047597140181, 14868019614204432202, 3095484311659417054])),
-                Fq::new(BigInteger::new([5360692040995940637, 15668869635416895297, 6096396631705084694, 872671935280711234])),
+                Fq::new(BigInteger::new([426588562992612101, 1617007727872932012, 1795163317886883223, 2937454389649158949])),
+                Fq::new(BigInteger::new([13958217953706789853, 8248175911854862172, 4176543980908226469, 3142108813900168222])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([7877660230419313736, 18160903300746751853, 17028413675293895071, 1385188645880081265])),
-                Fq::new(BigInteger::new([568788430069049075, 8543211624392896945, 16485087043985048745, 186709191941094137])),
+                Fq::new(BigInteger::new([8676770541664819304, 3086791404952445954, 10960094184540135450, 112739093243890357])),
+                Fq::new(BigInteger::new([8916967501221001338, 10698546921720795233, 5561699706114413460, 1514064790037774689])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([7078960516451177990, 17007536565014571345, 14651874735052815630, 1848983083836141648])),
-                Fq::new(BigInteger::new([15171645015894546348, 16276338709667905699, 17602700947699413607, 1917508558443907873])),
+                Fq::new(BigInteger::new([3532627116620950087, 18341978503303319558, 4593400907458913871, 1767302481986814191])),
+                Fq::new(BigInteger::new([8847416875603752403, 16473008003260727374, 8983663351113356738, 2868737993513274101])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([12511673438416086113, 11580902869806457872, 10759645229390908950, 2618160326016056277])),
-                Fq::new(BigInteger::new([3967574215784564913, 12064810456506651493, 13563697650692141428, 1413656679071807994])),
+                Fq::new(BigInteger::new([314985360182273473, 1675909805411402683, 10840932593638610765, 300640236211401637])),
+                Fq::new(BigInteger::new([3063948398448384005, 11896849732154932812, 5967180814595909766, 2235026382970004802])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([9999452514436482155, 18243426147229636760, 12497360415640520776, 705573223369636640])),
-                Fq::new(BigInteger::new([10582879495081268210, 17527617266666219529, 5948599161477182344, 479726550928398762])),
+                Fq::new(BigInteger::new([13748820212488735974, 10272024124032019566, 1960576766929038577, 3392996676389933268])),
+                Fq::new(BigInteger::new([9499053459826052780, 12050316248250582859, 6506922594944845205, 884430117007611047])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([5430901113140186664, 10966101291368132063, 16350290064914318972, 1459369272146145955])),
-                Fq::new(BigInteger::new([2488361581094509789, 999402241945348613, 2843361755213421152, 1178845835833057043])),
+                Fq::new(BigInteger::new([8838082437195997376, 13313084447062283592, 7856073941125333077, 2392537239804881275])),
+                Fq::new(BigInteger::new([10710059788406096962, 16080163392441625095, 13974083697334749575, 804863177962472417])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([11147513625770077607, 15299695648781719145, 15386272287625348749, 2619426591038669925])),
-                Fq::new(BigInteger::new([12393371774824907555, 6138592120437582937, 9442705450251183215, 3030515821579060693])),
+                Fq::new(BigInteger::new([14811152125277519530, 10576489054696522985, 7503793982100852939, 2079757465715282986])),
+                Fq::new(BigInteger::new([4495643438333234280, 1584484331328092942, 613580444461488127, 3103512548069817952])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([11053940606530813797, 3950175713492124764, 5094408045924027915, 62095824958184462])),
-                Fq::new(BigInteger::new([16577512216009419513, 1464617249225458442, 3005168936913497051, 1820891085928274723])),
+                Fq::new(BigInteger::new([10299789968609550173, 15571742597469610859, 13744929272861931047, 3479454020874653251])),
+                Fq::new(BigInteger::new([12386417264509199428, 15146987237948406455, 1856180443100173315, 1447465021045482569])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([5754218010635235959, 7983297060756083829, 11624591380707442558, 2233906743231809426])),
-                Fq::new(BigInteger::new([7793262811130390740, 8173854982432914226, 7556273790151645180, 3255857480676631309])),
+                Fq::new(BigInteger::new([2579333544870694226, 3927266729464904916, 13451930743777046293, 727839243239767242])),
+                Fq::new(BigInteger::new([15129236344395728848, 122950328511231567, 7163471293853996004, 1350000706817506466])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([1567413274797411794, 15871282788847816388, 14803727126131543467, 980656388930507145])),
-                Fq::new(BigInteger::new([7606608937655548530, 2011445966177155338, 13712810687353002266, 2911984833667148508])),
+                Fq::new(BigInteger::new([15508694773120801888, 15429911739215386548, 8593150427857352102, 2210764060391930500])),
+                Fq::new(BigInteger::new([2974456371645422606, 2290179130212032811, 7070758803936237200, 3239258296245867985])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([1190389811000789574, 14554437248172481952, 9694882556079237455, 562425419771544962])),
-                Fq::new(BigInteger::new([13723873722999036409, 3406982918878687698, 16333553111435882327, 2111273503374564048])),
+                Fq::new(BigInteger::new([2390608053052934075, 11119164041542914724, 12636574419094619678, 2362862994032832719])),
+                Fq::new(BigInteger::new([15158118896133991672, 16296644142241315124, 15522383403345413375, 1941468632348232381])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([6304904153359618020, 4017169018443968474, 13557192609445298684, 3111978869012731024])),
-                Fq::new(BigInteger::new([14371092190819936756, 289559336256863794, 1868855725698450653, 753878332896899390])),
+                Fq::new(BigInteger::new([1897396394097251338, 9025969316025293975, 11962384854596512251, 2622032714378645089])),
+                Fq::new(BigInteger::new([12194246914587275576, 7141165637008832815, 6455908967632180897, 1733234519669190796])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([15363007688222020423, 17085456572456608503, 18028904468347944022, 1586077936182093901])),
-                Fq::new(BigInteger::new([6270685060643511422, 14518255809652432738, 5559602389400892006, 2480122393570720974])),
+                Fq::new(BigInteger::new([3455368417161770174, 13892514176528770358, 820214103812945114, 2403163012519191708])),
+                Fq::new(BigInteger::new([5055332886474279154, 15278045983700164154, 10369696756074161830, 1009958054762550477])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([6781704566502280121, 8253512236803506681, 7595384223537750115, 707854529660289489])),
-                Fq::new(BigInteger::new([9569175186741463283, 13603599610016562977, 8308067309272119771, 2118367461008236270])),
+                Fq::new(BigInteger::new([7380749886736613066, 7074515867297113709, 10334984355135090748, 1676763635577078667])),
+                Fq::new(BigInteger::new([15637088376823307863, 2296960185157011413, 1472266892652301536, 1662137141571040900])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([1341106055552285187, 2584015035340183399, 13078993332177332186, 1857294014249098267])),
-                Fq::new(BigInteger::new([8867823608463555410, 5183366666551330098, 14321689001647303762, 1062313032871305885])),
+                Fq::new(BigInteger::new([17932474879702769204, 5588285309512284850, 3031437171908523932, 3349758087574945473])),
+                Fq::new(BigInteger::new([14030034927976884134, 919515194673366864, 3680668199929768439, 993811029797966417])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([4518195638935595722, 16817053785026500736, 14862293956422141104, 1621481584696209442])),
-                Fq::new(BigInteger::new([5795047709411841636, 8775632009360628847, 18201778195834610326, 2146713357142682559])),
+                Fq::new(BigInteger::new([7440596901286161036, 9035068080079051271, 13130495495278426414, 1957967812359325700])),
+                Fq::new(BigInteger::new([11551817849558504965, 16044611170217734412, 13160720480566008637, 1755864251504968925])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([4395492477533081543, 11750784436318400861, 5480159630179360784, 1365051289361676981])),
-                Fq::new(BigInteger::new([7185866152368017036, 1629513356210555071, 8587423501381345833, 3446893053054738712])),
+                Fq::new(BigInteger::new([654316266674308912, 11097836519599427988, 7752201591914882070, 771659491611144231])),
+                Fq::new(BigInteger::new([2764189343336458039, 11437527084533155913, 1613068877936092030, 487792067950781907])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([15050449368074909668, 11670126355761366912, 12854112238021485218, 1099143846461718012])),
-                Fq::new(BigInteger::new([14258896823880310526, 10372074734119825303, 617720014347359422, 1902451938958025443])),
+                Fq::new(BigInteger::new([1434482683025568127, 17221526144261638531, 5929014545720597259, 1645541391928806455])),
+                Fq::new(BigInteger::new([3432785970861926088, 4167562409977918660, 18366916326911784062, 3356009141283993283])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([18128997154387622046, 477591899163497261, 10472240728418218385, 1861638622882789606])),
-                Fq::new(BigInteger::new([17657399934035335365, 11502823937253652255, 504838283631154329, 2126093341324562242])),
+                Fq::new(BigInteger::new([1501933410412791721, 6345181834788522482, 13728612316911790150, 1671734280484611552])),
+                Fq::new(BigInteger::new([13705566485741188681, 16872405553754183213, 5475112509313528221, 228388007130710126])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([12627704587098814429, 16452539980093432042, 6170335710859393324, 2912173888286761636])),
-                Fq::new(BigInteger::new([4089399722049389415, 5054863454460215832, 8046964755643100847, 942599368983085158])),
+                Fq::new(BigInteger::new([13247237826784337249, 9415174244682001877, 13427242099533300075, 1862105693389690865])),
+                Fq::new(BigInteger::new([11010572031913357656, 6109885308503471794, 2241417388545681417, 381324297769557773])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([7775845733618453598, 12517956735413264890, 13770943070541302652, 1746577238766624442])),
-                Fq::new(BigInteger::new([14709135554815506124, 5346901228431121651, 14061402302871353281, 1193606139948322951])),
+                Fq::new(BigInteger::new([12125114179081698953, 6574900025068776111, 8564549205765013386, 741845642509381639])),
+                Fq::new(BigInteger::new([102038965350315035, 3307182705321110768, 8065149847979489380, 1721384795871698148])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([8741859865769421736, 4833090803929518516, 10039025579615932894, 3153808503267606740])),
-                Fq::new(BigInteger::new([15210095057759800639, 6257119823361987594, 13715845163957100815, 3406979473992634382])),
+                Fq::new(BigInteger::new([14588267516555140427, 6917376221926316400, 16732988650094517792, 2095855430705682852])),
+                Fq::new(BigInteger::new([12553310531368712591, 553452522009244939, 2932003992137752846, 545758979004441248])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([6480793832219915575, 17251741543974589521, 11402230261499712801, 2175981738287900164])),
-                Fq::new(BigInteger::new([783095036385025186, 10668172035342436393, 7245387509421934532, 1631788098209633584])),
+                Fq::new(BigInteger::new([17570316721623778256, 6488519816204123884, 11064745373228654611, 2889008498761137011])),
+                Fq::new(BigInteger::new([209971179363994385, 10536038731908167783, 13783517883793519274, 3352191360008908817])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([15839904179732804303, 16006860020112141194, 12611366072894060126, 461797050146942071])),
-                Fq::new(BigInteger::new([14315661419008831559, 12528451272929286994, 13824649241442057226, 2652135527824376072])),
+                Fq::new(BigInteger::new([1457829619413321076, 8482615718345079591, 13622161563039047635, 2529631718003906165])),
+                Fq::new(BigInteger::new([15249173835149652601, 5425199488017799711, 15817422849777642897, 2648559217214154781])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([18057792722164167638, 8791543880210233436, 18041193695670486411, 532939827835388465])),
-                Fq::new(BigInteger::new([9954911957100874934, 2351765377954950597, 9534059029170541252, 2009505843969754626])),
+                Fq::new(BigInteger::new([13486484851919800949, 374147384794764296, 12082941979776813648, 677906312503042801])),
+                Fq::new(BigInteger::new([9175225391641832434, 6725818145823063295, 4743967193110814632, 1891743665866317452])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([15000787036991889031, 3217867954894832320, 17298964753346896130, 13410150864033591])),
-                Fq::new(BigInteger::new([5444880530592576225, 7186993215404741312, 638980111487660872, 1318457590616578139])),
+                Fq::new(BigInteger::new([11715648294912962560, 12555075985828027659, 9453571241407413416, 3459327663670364352])),
+                Fq::new(BigInteger::new([13733683113655189793, 9355805448947679046, 4612689965456070634, 1216887893751390906])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([11200603098939558078, 4305108811884815114, 16815770172611364496, 3283665613743026352])),
-                Fq::new(BigInteger::new([8999174689181355067, 12171631697997749548, 15967949122129554701, 1122980642880149237])),
+                Fq::new(BigInteger::new([4854495646788386445, 18347366436805889905, 17155506168231511031, 101261086203296594])),
+                Fq::new(BigInteger::new([6789006663044657591, 955656255569878383, 4428549293901320877, 1775955749488336057])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([11326264529608808756, 11637792733502021425, 6702935431330981965, 3045124744316768048])),
-                Fq::new(BigInteger::new([6263678937751407430, 12265137946350473281, 13818948122491432426, 112207364972500682])),
+                Fq::new(BigInteger::new([15652837588151245832, 14381666282253102766, 2173307092053597354, 3388782484908258427])),
+                Fq::new(BigInteger::new([18181692999253728199, 8784927019031142049, 4118515483064860401, 1072521752223183147])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([11205626941333337235, 17137092271273300916, 8387332879667264856, 3312554591884973813])),
-                Fq::new(BigInteger::new([16605298004239303906, 13981203959256632284, 4819391888821424443, 988865724539617851])),
+                Fq::new(BigInteger::new([3057228697087765224, 10042584715089142381, 11764298159600439685, 2449961333390497470])),
+                Fq::new(BigInteger::new([1092977019252747389, 14764722390384216032, 13659559770859939706, 2004386545739207658])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([11055922891661074671, 12448754620887941364, 16013133995677488912, 2063161047656974195])),
-                Fq::new(BigInteger::new([12083826552251388181, 1146356854649680923, 17973886374601100233, 3023236216131754188])),
+                Fq::new(BigInteger::new([2972167712318684889, 4865616292797514883, 3157399494430765111, 2095651419831378444])),
+                Fq::new(BigInteger::new([15043013678282645661, 17463522683472977914, 13545875678393259429, 1558469275158505481])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([727039494885563764, 5855996529865612194, 3898134228147054700, 800713318965716992])),
-                Fq::new(BigInteger::new([5732998875438815143, 11952531937261649856, 17606734481190622016, 823040649039120821])),
+                Fq::new(BigInteger::new([14086379548647203472, 6215324132038767429, 12092861972710280414, 2691144505241864809])),
+                Fq::new(BigInteger::new([14107913999999900487, 8486200710372577839, 14953880201166914271, 2938733575627759315])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([12524793378643401585, 3016182203734916957, 14739384076926528790, 970848053145087441])),
-                Fq::new(BigInteger::new([14656459779394918480, 17262336151979681858, 8574123727447167909, 1500067551413047658])),
+                Fq::new(BigInteger::new([5915517644204721575, 16819352197380383005, 14974543202147025397, 1772000684247443883])),
+                Fq::new(BigInteger::new([8140051767970127979, 4810804564892599001, 4320226048199047, 238946107738046346])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([10322031617851940842, 4273399548590435751, 6058932274587523172, 1774619888775120732])),
-                Fq::new(BigInteger::new([12546604348456844556, 16896853035565266211, 15936723050391301893, 174922204698434863])),
+                Fq::new(BigInteger::new([11910363055554051328, 12776368546499473052, 10158882995978709931, 977363801770510803])),
+                Fq::new(BigInteger::new([15280166846874469158, 8391117663263712161, 7177882353504141190, 607408410844823274])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([3568631136485935847, 6831241809780000807, 16537620814408998749, 1752805253869030197])),
-                Fq::new(BigInteger::new([10310121930106456360, 2356969656699894192, 7383039108351135342, 264686869510365988])),
+                Fq::new(BigInteger::new([6026960366633317521, 2307602483828038897, 1618414043176960221, 1987364976345196001])),
+                Fq::new(BigInteger::new([11738102249825844030, 9734767274678903178, 6074755857253370096, 2244662886575828761])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([18057203087104337468, 15632311642065903124, 15739216399647677215, 964433337144717463])),
-                Fq::new(BigInteger::new([15917368935681963492, 3390179931916237035, 3723040424817182374, 1169803592221461101])),
+                Fq::new(BigInteger::new([6465561999772094004, 1991073044729224822, 18336420922094783567, 154494035459860127])),
+                Fq::new(BigInteger::new([8695159750637307731, 13241888794593698695, 10544258862229794533, 2387063108904378971])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([12183786007742157473, 11501250972489460785, 9258613274508374199, 2906290692008203490])),
-                Fq::new(BigInteger::new([8278226075493673896, 17529772869902414721, 11375223208808472018, 804995970151609712])),
+                Fq::new(BigInteger::new([2504670031509133917, 691683907014325548, 3264716196174656524, 885029405370594106])),
+                Fq::new(BigInteger::new([17111505485710303181, 7736865561266679049, 8448725402990798718, 2127287518331231569])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([6938926375263393857, 5812947263570145605, 6649798121241566492, 291274746800687209])),
-                Fq::new(BigInteger::new([3981026792991538150, 16727292550849378639, 7703753616624547457, 3147020366230543844])),
+                Fq::new(BigInteger::new([2952460502677961411, 12397554548830671409, 1038567427788641464, 3124449038642363820])),
+                Fq::new(BigInteger::new([3623710985134424862, 9422881810637794892, 18388036584980920497, 2307599082016315967])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([2352677388090521603, 15621624163795615639, 7089929302987278445, 603952858895104885])),
-                Fq::new(BigInteger::new([849418820743760774, 1546195225966655352, 12059694247955330707, 1345616498448822428])),
+                Fq::new(BigInteger::new([16856835167135556546, 747358480681043261, 18368889691631737510, 2702573842607961593])),
+                Fq::new(BigInteger::new([220840250728562066, 3739423577768432066, 6965636209795820962, 1274473989926767561])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([2353998823967462554, 4714747304159609671, 13665490600459497358, 1823178254820144035])),
-                Fq::new(BigInteger::new([6234534973340715131, 11492086197264033143, 8104821356204099773, 2659680104330362431])),
+                Fq::new(BigInteger::new([7793912613181744044, 16237367757895418944, 12175452610313221483, 374415163828391969])),
+                Fq::new(BigInteger::new([5131891538845296023, 7322480928085634400, 3346796089954091344, 2479761180111257051])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([16956655726464531392, 15678865828711489588, 6913797657955959697, 3034390681795196129])),
-                Fq::new(BigInteger::new([14071376569355046919, 10963094778879303707, 510470840947053957, 697274879279193881])),
+                Fq::new(BigInteger::new([511890770297423673, 30286792583790190, 16438581147300862507, 2206385609647494606])),
+                Fq::new(BigInteger::new([8606143112190932259, 13747400949520676050, 17792444089872379779, 3000731928626707131])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([16770324266696168009, 4590842690246356862, 15342200857292262579, 3046426248258286813])),
-                Fq::new(BigInteger::new([18142697234649143275, 16444049338725665240, 11988526336474905026, 2906823006869889367])),
+                Fq::new(BigInteger::new([412103270665968269, 10593633596390326517, 16653910048110095484, 1780919997478424303])),
+                Fq::new(BigInteger::new([10082053938147358023, 10303216608087145191, 13832077337142282000, 1873443118975171416])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([10155433042770738964, 2898294733825214411, 3502509348326190968, 295850612451700365])),
-                Fq::new(BigInteger::new([13174895230261983772, 764340385655456746, 18063349510637851208, 3187163828383160066])),
+                Fq::new(BigInteger::new([13019837702663802634, 9596134519385364633, 13508261406542027778, 2636482431277811936])),
+                Fq::new(BigInteger::new([453525816247757680, 5569088094233013193, 7075245904394004932, 2053880935031337516])),
             ),
        ),
     ],
@@ -1424,1276 +1374,1276 @@ pub const DELTA_G2_NEG_PC: &G2Prepared254 = &G2Prepared254 {
     ell_coeffs: &[
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([14787523285172091455, 9805037859534401226, 4030030598444228938, 64978180510651595])),
-                Fq::new(BigInteger::new([13398466612011769725, 8216152596868643813, 8215572647258681659, 2877983667989290608])),
+                Fq::new(BigInteger::new([7465403023205272469, 13909528479505583793, 16359928329550713359, 736542877909539431])),
+                Fq::new(BigInteger::new([14348799136310682425, 17092593753038228891, 6252883487549491715, 3458998400691590134])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([5331593430561040425, 3477654930204735200, 6314034046303215962, 1693460078331500375])),
-                Fq::new(BigInteger::new([11009116375437204889, 9994382035535301052, 5955106339973674344, 1161607026059075422])),
+                Fq::new(BigInteger::new([5805296390256089659, 14298654350944787268, 13467106669105974440, 1816890946958692249])),
+                Fq::new(BigInteger::new([16323222340165166717, 14559749602190429446, 6304507077643881031, 3164485244373099756])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([7901607188529511286, 13869659036820286854, 8318372685852221599, 1605783490406040400])),
-                Fq::new(BigInteger::new([17608484236722471991, 16270847142074471282, 5974217285601309520, 1811341719076947683])),
+                Fq::new(BigInteger::new([8744210127329726600, 5191351205753752228, 10538658506868395536, 1773773852195046851])),
+                Fq::new(BigInteger::new([9989864185723082284, 14971663752423922282, 6246293295182653296, 1323655868231226610])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([12078883446520404094, 7501855103196351969, 12214813208302852180, 1877046654573132075])),
-                Fq::new(BigInteger::new([7453473748699943400, 3499702445179933536, 15891982028968015374, 1108372388989140388])),
+                Fq::new(BigInteger::new([2381971731732939810, 4824329585732994354, 395646506928656566, 932187847974115490])),
+                Fq::new(BigInteger::new([10045825009976921474, 5417944157615319580, 18190808788799454732, 1815364552213084593])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([6903597788671292718, 10850026687778171600, 13129538493235157762, 1320228484690331776])),
-                Fq::new(BigInteger::new([4172632770663511964, 6677795824380683402, 3249820849067152065, 2558726541937297602])),
+                Fq::new(BigInteger::new([986807581767740100, 2916915582915496251, 12916294185621462991, 3298134071927959517])),
+                Fq::new(BigInteger::new([10422682959134342613, 17655402570524454354, 5189180551180672468, 3053258389281237865])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([17603937485598295476, 11405607842970111763, 8333628472981002929, 766530990663806649])),
-                Fq::new(BigInteger::new([10901670653639453499, 8977483723972977491, 2876327406451621424, 2937677416695512429])),
+                Fq::new(BigInteger::new([14540242866222802676, 2832253072647102442, 2538096168251060878, 1170502506694603909])),
+                Fq::new(BigInteger::new([9583142002341803954, 17203030964549802671, 399012438318222639, 761315679165066311])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([6052589753899517817, 12785912685553510721, 10647807593422461664, 2423116152011153887])),
-                Fq::new(BigInteger::new([1937422529124470613, 10229215439325039783, 12797297437770157446, 2961440864888502968])),
+                Fq::new(BigInteger::new([14587383783820768937, 17583468808771536508, 5976517869926403361, 3005785102667639910])),
+                Fq::new(BigInteger::new([6521590344905733560, 16601788509023368239, 10345932312464709390, 1928319589377354169])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([8520436560878205093, 6289077845967947006, 15828046456269755583, 2131159649375149586])),
-                Fq::new(BigInteger::new([3374130612215207231, 2037496102267964387, 16000265982053581550, 3307107163727485454])),
+                Fq::new(BigInteger::new([5251157466671211439, 3795393559949237029, 9194601860152401013, 527549634281483503])),
+                Fq::new(BigInteger::new([13942002422563967253, 10124378999250545942, 4429742824164695700, 1596610134806539966])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([16462853262870402807, 14138491156692819162, 2234966012111777568, 1409458830189414769])),
-                Fq::new(BigInteger::new([13613672018111572706, 7197665932366008984, 1684148135812092559, 336628137730896329])),
+                Fq::new(BigInteger::new([1915032138594181368, 10551204756625384329, 11532709005235121338, 3174137139920727017])),
+                Fq::new(BigInteger::new([8876260403828320029, 4086395144820504466, 4821270860369293653, 3246159249872903380])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([18405347289557622442, 620575254407915595, 11528836719137329230, 534759488865068918])),
-                Fq::new(BigInteger::new([4226289850614705611, 17682565039257759718, 6254969982694006592, 998662302906131195])),
+                Fq::new(BigInteger::new([14393767398982741070, 3810348385550652523, 8748136106152843285, 1572821930530301280])),
+                Fq::new(BigInteger::new([8600366842442319975, 5420143257076582547, 14611978382610906126, 2674039178904984484])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([15072661367898088563, 17903385151412261143, 8565478642896570781, 76456181118827637])),
-                Fq::new(BigInteger::new([13375646779740447142, 11384893258575546513, 15254998899809644424, 2564997345725891889])),
+                Fq::new(BigInteger::new([9229657184309772362, 15686993659241556739, 1135037280910692426, 2813768977060116068])),
+                Fq::new(BigInteger::new([3389036975521245449, 9479401062237219798, 9357127138065890017, 735835054622225449])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([11692593108639713452, 9572795685843433332, 3269230888056638486, 3355805276087353934])),
-                Fq::new(BigInteger::new([4428605504668995594, 17997659592778818775, 3307560843068403474, 2458936838623365202])),
+                Fq::new(BigInteger::new([4620711783344869835, 16554455490662577914, 18100801541362097785, 2153748896749375405])),
+                Fq::new(BigInteger::new([517170851299782000, 16518565026071674392, 8761737747441046373, 930714557990575157])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([12442571781487434141, 14871495841569855009, 11991657821634872539, 2658891495616142915])),
-                Fq::new(BigInteger::new([2422249224066673874, 7395056655722269868, 5476762555177567046, 2356914555276451139])),
+                Fq::new(BigInteger::new([559142875336577297, 2601359611282031095, 17084663678195037681, 2467031668021873829])),
+                Fq::new(BigInteger::new([18227128546160851201, 8515877066426486554, 18120988628290890408, 481281574208495869])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([17747522612448402521, 1986744063012341762, 15820212008337555748, 1553433885419003882])),
-                Fq::new(BigInteger::new([4098446004568433487, 17923831640503820949, 15083506436658102356, 2254568772596648452])),
+                Fq::new(BigInteger::new([9493165135122163641, 9038231872599525496, 1297007279318590060, 3038655938204398324])),
+                Fq::new(BigInteger::new([14782507751070932902, 17248988776056313088, 7373487080545447928, 3335026330011133935])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([1549749885953757074, 17127950986191083781, 1349534641915669680, 2105724547233900554])),
-                Fq::new(BigInteger::new([11612521340677614939, 16968750596922631878, 4214452196463593613, 1408474586947187967])),
+                Fq::new(BigInteger::new([6697411335293313483, 9807411664206302053, 1301536547009380759, 2659067106698187330])),
+                Fq::new(BigInteger::new([1101175830232953628, 15300931987906291743, 2361389139028629875, 214747778920878753])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([18000804729047804814, 13838057176245489, 15243684352948142634, 3465096084868980501])),
-                Fq::new(BigInteger::new([8687303704672338116, 6633876966627224783, 9477375457932504678, 2017899696053904444])),
+                Fq::new(BigInteger::new([6915982197819532014, 701742430312633368, 8181961989492043109, 2178010322292838124])),
+                Fq::new(BigInteger::new([3438320880002564041, 12921941529037035982, 323639572693139033, 158030134491330922])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([6299604019763535874, 2491198932819611995, 6030492263087817363, 2983705214492985734])),
-                Fq::new(BigInteger::new([9328026941342667055, 11828063604750490489, 2721596816227937755, 342503794344238938])),
+                Fq::new(BigInteger::new([5368797334772015488, 4546237528694819006, 9012193717083867663, 779284777952677993])),
+                Fq::new(BigInteger::new([10035681684408557082, 13203572537694730559, 3437897989935888753, 1798822869974120270])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([3736042258484971194, 2781333220501508469, 8945177072523205651, 1085359225924702374])),
-                Fq::new(BigInteger::new([8847301188848138516, 9305126143354940923, 11057558529016927282, 647228990406837559])),
+                Fq::new(BigInteger::new([12787691444663242206, 14705675309209221546, 4494433531898640045, 3250895726578697279])),
+                Fq::new(BigInteger::new([10151642784066967865, 17446345886446608757, 14848802556860741523, 1179620465143809715])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([5467762135002965319, 14110147155493124459, 7842153837393048372, 2545869456884179343])),
-                Fq::new(BigInteger::new([9921843838776267232, 12641474210014925573, 5451323915506323707, 3127146080050140075])),
+                Fq::new(BigInteger::new([5077821589876766803, 6992861166724159579, 8045913799172133631, 3033214902029678867])),
+                Fq::new(BigInteger::new([18353506165298354822, 11124320260526695919, 15290565095305978035, 2764557372272989421])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([13881851597579904816, 18095229334329526820, 1278348477715628943, 2594135817328620105])),
-                Fq::new(BigInteger::new([491732668788360070, 16568828009624855940, 10250872580558273518, 1310595062218108354])),
+                Fq::new(BigInteger::new([6359155685099253609, 7657349540954778102, 2011750382904485251, 3002862714702461779])),
+                Fq::new(BigInteger::new([9814717326571379893, 14286386727618252709, 8731200977037399108, 2122597474313081297])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([15239015873866801937, 16121477261776970416, 2605133448226774297, 315318125909630305])),
-                Fq::new(BigInteger::new([12342697984136892385, 10111189331006193848, 12121549526565855058, 449671231048055465])),
+                Fq::new(BigInteger::new([11333138058524257668, 6077503174362749494, 13075005325662043369, 826461106619441513])),
+                Fq::new(BigInteger::new([814583690943389774, 17560058486681077544, 2685072410126450974, 224071824374360613])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([2090217060048847298, 12824923106171813860, 2812141078202770885, 2180096961136368017])),
-                Fq::new(BigInteger::new([1371786518256165687, 13983538566536765132, 1163305075305206579, 378585260149645518])),
+                Fq::new(BigInteger::new([12126478301524055678, 6578144228583172363, 14624034389664052404, 1121539495935823216])),
+                Fq::new(BigInteger::new([14705089242847274262, 13731036179922810873, 8833844790692865845, 913510295270125636])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([15102954843565402556, 10915765080328546779, 15631681149475949975, 495771623121150582])),
-                Fq::new(BigInteger::new([7320687088165491408, 10321619657647990285, 4206593653752366624, 3261940408568358568])),
+                Fq::new(BigInteger::new([14888108122312554842, 8711571164250674980, 12048847713619184078, 2223619848830944958])),
+                Fq::new(BigInteger::new([14182580952708542766, 12563693092029518214, 15420843640456755446, 18199570573499933])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([9012200032502072907, 9037896212668081003, 14910839650239964198, 3164182265147173956])),
-                Fq::new(BigInteger::new([8665398163949753771, 6757529926347718756, 8919030660428750958, 1895360287444416142])),
+                Fq::new(BigInteger::new([5110344208765097052, 8003816909135092790, 4458777284688366303, 401405727441527187])),
+                Fq::new(BigInteger::new([17716872824369630840, 6417147298428392746, 11070983675675822239, 628761400910371834])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([12244351299550463311, 13229736164688011808, 5802990766790409130, 179457481462751697])),
-                Fq::new(BigInteger::new([3594422090279666660, 18131837370853772935, 12297172097452294550, 3253741627063864033])),
+                Fq::new(BigInteger::new([9665155530017481570, 11175538534572899030, 18173208562247175459, 3316753459877892048])),
+                Fq::new(BigInteger::new([3477880573223233795, 453865727474497337, 1971085099738824232, 2167597765843894072])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([2067160994087136057, 8907125826150993560, 15171049026351790140, 3163696169819675506])),
-                Fq::new(BigInteger::new([12480334681800370645, 7194658205598696240, 2027278686726115678, 259425478670348337])),
+                Fq::new(BigInteger::new([12079347705322902828, 9103405071560854067, 9730116104247276032, 91448331100425971])),
+                Fq::new(BigInteger::new([5961802524844398955, 12738604985920141752, 13443092536286292923, 2358232376962294524])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([12641380981424986857, 4274426488772542534, 4598528666934169953, 2423897776398913234])),
-                Fq::new(BigInteger::new([17743342087719514422, 10566747942112008058, 5736736066257104892, 1868666506132437048])),
+                Fq::new(BigInteger::new([13328620965857454949, 1097451931343912551, 8052078406407400338, 3475238347239142245])),
+                Fq::new(BigInteger::new([6133341174737869433, 2256247881775494860, 17234427411700269080, 2585713551927375323])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([5192216407106253951, 3198660813761589661, 2571019066859153619, 2166004185135048054])),
-                Fq::new(BigInteger::new([1505001303376592100, 6211013077415935938, 18189554736702148294, 135013043311965518])),
+                Fq::new(BigInteger::new([3191908373494696202, 16879282369316471508, 739709972773640392, 2414416614747648310])),
+                Fq::new(BigInteger::new([11875591610956375957, 9288305223797129291, 17536023082064563743, 528817106374493354])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([1122037925414620770, 16336882554363271805, 1190292979515037823, 2485550579738603490])),
-                Fq::new(BigInteger::new([14683975234844416030, 3194111091444863833, 7517476874952452326, 367898910888624649])),
+                Fq::new(BigInteger::new([11274879117644967287, 3254741042415413042, 2682294378813112960, 1802889798323467938])),
+                Fq::new(BigInteger::new([12265855812992415357, 14088443275340154557, 16618172241270921307, 3458781650193902713])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([16416152816708417290, 17355533115350068636, 10619443367219146065, 2849016753813338444])),
-                Fq::new(BigInteger::new([8893747683796855834, 11198311487955842266, 6457493204155686971, 204566932245528961])),
+                Fq::new(BigInteger::new([128964161796045985, 18099701519593772075, 13003602329676108567, 2839052642107389565])),
+                Fq::new(BigInteger::new([17447058655747957385, 17636779479348864398, 2276620048315947206, 750095225639722824])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([10611771222610234069, 6505072348851946046, 8071187078061648899, 3135513724057931753])),
-                Fq::new(BigInteger::new([5738898003942779762, 7708670865423868015, 6493683970758172863, 2370330954875035852])),
+                Fq::new(BigInteger::new([5132871591836181207, 3269044871380524065, 13093999576297672996, 3008273645450612574])),
+                Fq::new(BigInteger::new([13795663138339629894, 13413191051707174270, 7907808316180001768, 3054424625156939256])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([14517526982883704021, 16570238021604941020, 15149827791176344480, 576694937934928796])),
-                Fq::new(BigInteger::new([15131414720110423580, 8598167957500907141, 1069390472448180793, 997506084624104556])),
+                Fq::new(BigInteger::new([4450471092435593769, 764871392349624415, 16455076411424796413, 2615547771661934941])),
+                Fq::new(BigInteger::new([18187144091167114380, 16177067603605203869, 11974040410903486070, 1397508107552582430])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([4537536006352267296, 5864718010705223257, 80131380793368681, 621233655450280337])),
-                Fq::new(BigInteger::new([16650408727421959922, 13911126641231433530, 17040891054388339204, 874920582235709271])),
+                Fq::new(BigInteger::new([12881147443832850353, 4422735012766279090, 15737187527087529153, 1643520214662869005])),
+                Fq::new(BigInteger::new([7877998583607036472, 6778463451682375108, 3883747256617690711, 2955262815982604970])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([15138557562728243880, 14337864852925827379, 14618649776519065384, 2769796732330954001])),
-                Fq::new(BigInteger::new([1913396208472179653, 10157412472141556321, 14996990928919491620, 1463208813831254286])),
+                Fq::new(BigInteger::new([6181237893512592155, 11059899962378680373, 2986421518022541610, 1265555921587226279])),
+                Fq::new(BigInteger::new([16433679800662119880, 15972042158266333203, 2008676211607813033, 667810506239774873])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([11607015493312139362, 4821612876908411074, 1836422652589540672, 1924343895361525608])),
-                Fq::new(BigInteger::new([1585642235923237347, 7746983912646184196, 9202725992743350669, 1886414450057555231])),
+                Fq::new(BigInteger::new([16885117386482354184, 13569472137676123355, 6292361577748798923, 3188106158367241978])),
+                Fq::new(BigInteger::new([3199400749836044039, 10720524516245827718, 2401057122241108342, 970558430815519384])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([6661530046293155070, 10282564705931632163, 5264699110845478022, 2051135333838469495])),
-                Fq::new(BigInteger::new([1466450237216634122, 14122427082825251877, 972384569860692274, 2323908938067228914])),
+                Fq::new(BigInteger::new([9890832664195408300, 1164518781082949870, 7894832952178645549, 3394447906144911293])),
+                Fq::new(BigInteger::new([783920774586502530, 12106885815567643406, 11406715459952998013, 1818404944303701028])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([2693297217812729418, 266332243056767933, 12963165788357421532, 3161192381751420406])),
-                Fq::new(BigInteger::new([12849597309743133682, 12617788264565229854, 12193724337964902483, 2879486319169968651])),
+                Fq::new(BigInteger::new([10298242574299442481, 6430688491722923765, 16204347734113528167, 2072062468706261704])),
+                Fq::new(BigInteger::new([7406538784694202704, 15940160640375795742, 13614335746678474384, 3417871913021135367])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([2342173111922703870, 789717503029311328, 16492642864533066449, 2253286397893586323])),
-                Fq::new(BigInteger::new([10860357748228931659, 14009316000341991918, 6307584392023744951, 2963590333907096017])),
+                Fq::new(BigInteger::new([7694902613207670595, 3176586932919471842, 10109675987033732996, 229794520340775677])),
+                Fq::new(BigInteger::new([11748370726135352403, 2501602488532153843, 8337389780943285716, 1702913393151311330])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([5339253027465074698, 12748925271883914654, 1408484520444284267, 785227247943160286])),
-                Fq::new(BigInteger::new([4566145531456523326, 16488127159393188088, 8836729823653640925, 844416612774757514])),
+                Fq::new(BigInteger::new([14032433323674464168, 92923605350454566, 9448461858001283059, 361757529900846219])),
+                Fq::new(BigInteger::new([17014268886153131557, 2846767463770860643, 314892792714056917, 3016216322309768916])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([1162571439824573266, 4943364221808443244, 17322479529704437849, 3342510361717108959])),
-                Fq::new(BigInteger::new([11981935326742119465, 15386118953639176203, 5306102845998761092, 513675989189184202])),
+                Fq::new(BigInteger::new([8327001118217919581, 17304316604601950938, 2032161838231047233, 109583433582833120])),
+                Fq::new(BigInteger::new([17464412799824261257, 11204815386604757162, 4760230105865214587, 2561703737171455642])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([12817861577390284161, 14712877975836544127, 7641754990168132410, 3283025615347103033])),
-                Fq::new(BigInteger::new([10759434888536347661, 8697902495261276988, 7151311309487226569, 2017837669935498407])),
+                Fq::new(BigInteger::new([17162454351036118659, 7119597965182222675, 11825104069008636226, 939894676289903357])),
+                Fq::new(BigInteger::new([7877966409105236777, 9154224566032751510, 8849012417765966290, 2350511482299937498])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([11958637520710252177, 12497239961267176528, 6097816953482545847, 2301872357945226010])),
-                Fq::new(BigInteger::new([11265876623026320069, 1456844278988809725, 14417731096629356016, 142221467111981816])),
+                Fq::new(BigInteger::new([5607243506577678311, 8379969857087309214, 1250039348953869625, 1288448394538626728])),
+                Fq::new(BigInteger::new([12717436279047444490, 6980954052911767105, 6466940135019101723, 2485571152774809220])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([10460355542044817313, 11940793448810253457, 5471256955309154353, 3077571286887860405])),
-                Fq::new(BigInteger::new([14653642911796867053, 16619137411180749186, 6769119218707964248, 1487533993043829170])),
+                Fq::new(BigInteger::new([14982866992636828351, 12670314516462942893, 15024985549538433377, 1498628071832461640])),
+                Fq::new(BigInteger::new([14735061210474947771, 4023093023679641276, 17631937837722737973, 3368416500925754926])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([15671793257176647468, 5105647095793396439, 14352063796175451201, 2455895363789298980])),
-                Fq::new(BigInteger::new([15123305784172419608, 4065347894785117240, 10715375771108625276, 131520749289282226])),
+                Fq::new(BigInteger::new([4081238824565666194, 3989614552117312364, 11286433265037280775, 1474216756025914123])),
+                Fq::new(BigInteger::new([17827622432297850659, 16742664895463887493, 12619505726984346419, 748171743558836163])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([15817732248923861251, 17608346214204240549, 13140183120901722423, 2878296635276045781])),
-                Fq::new(BigInteger::new([5972335726090022432, 4221015852886522892, 11180050600118385598, 1039951919354680844])),
+                Fq::new(BigInteger::new([14175021036779920144, 2921417023606844771, 6519885835058209749, 819189301938962553])),
+                Fq::new(BigInteger::new([13574749996199402572, 5935805047237435950, 17126638079724648313, 557584257421266524])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([9332964565926108364, 14985635370876402832, 3634761116883627507, 3166497831669550264])),
-                Fq::new(BigInteger::new([8268040452999170206, 5730780056632205688, 9892166995769983823, 1954810909921574424])),
+                Fq::new(BigInteger::new([15840275989288252467, 6407474783554325559, 14143833380875446765, 1399459694988116623])),
+                Fq::new(BigInteger::new([17702045766593827156, 2540588671076906105, 12715318362748917716, 1128040588803847592])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([248280622964860444, 2617801954491976234, 4129656810973411360, 775806008543868158])),
-                Fq::new(BigInteger::new([17363829911671424912, 5197862734146079599, 15995280415522890182, 2767814963527722773])),
+                Fq::new(BigInteger::new([15623919018618957026, 7971087015010413379, 14746298036002375799, 1125947203239896162])),
+                Fq::new(BigInteger::new([2722295853447777537, 9733714531718594711, 2257916321693467098, 2233384525852728735])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([1155342397137307574, 978873554612264984, 8532433184345313289, 1260504148885270340])),
-                Fq::new(BigInteger::new([6077077388233304787, 1900632091541182903, 5153229115016592120, 1644767131510751664])),
+                Fq::new(BigInteger::new([16020781098040327700, 16644870341668120014, 148202124501215101, 304536710273622604])),
+                Fq::new(BigInteger::new([2664020757165829734, 8643371317282818104, 8203070889784037788, 3126882526872099877])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([3123824806616281562, 10179275983040202795, 13415096542597192801, 2791459728051579671])),
-                Fq::new(BigInteger::new([8724496640416367108, 3042568752414578164, 14224555569238595680, 1229924486158952023])),
+                Fq::new(BigInteger::new([16024564940273431881, 15080228744420274799, 5373800922259381105, 1339806732461663760])),
+                Fq::new(BigInteger::new([7378772566210253513, 12936839695518926848, 9171496457882979564, 1333438994293065165])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([11035491761375940826, 16678320164902544113, 14090596078825982898, 1823238634400090009])),
-                Fq::new(BigInteger::new([923716254781499963, 9670145057751064109, 9205625873713506975, 189852389416879377])),
+                Fq::new(BigInteger::new([11223413455057113146, 17743938804446823985, 7485125889537826632, 2423715365846876220])),
+                Fq::new(BigInteger::new([9336456450246914278, 10004142161762390818, 10979682016082939923, 1110595221773228998])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([11296474284392065469, 382443601119412825, 4581050386153365498, 489766007304200866])),
-                Fq::new(BigInteger::new([15809741879962847938, 17251347121144130602, 2734776130845733431, 1077733171801683785])),
+                Fq::new(BigInteger::new([9686406932527665931, 6861253133014259239, 2210275853428223964, 2873692081897263621])),
+                Fq::new(BigInteger::new([3501002177385335470, 7646928098849921088, 7107580374028462989, 2378913891202140194])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([6183442001024137141, 4386176284169399521, 15291659168348332134, 2756200064142728797])),
-                Fq::new(BigInteger::new([8047032943456098353, 3844436319462887718, 2670196013854331189, 1744140064654524501])),
+                Fq::new(BigInteger::new([14274448588492153835, 954260495150896250, 8975836328444118406, 3171562468894640639])),
+                Fq::new(BigInteger::new([11689762137958958829, 8959719957401393760, 5554524895453403677, 1988348245101845834])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([10831526750291097367, 9988376401457985622, 3625048727667406818, 1704478650158951656])),
-                Fq::new(BigInteger::new([3600827407331551586, 8195823196490464117, 8557577401170892131, 2629283947336184190])),
+                Fq::new(BigInteger::new([17405833369749486270, 12292387580077361236, 3060540291260482708, 2087488326923196997])),
+                Fq::new(BigInteger::new([15108046972742425146, 17065714787168224897, 11696439277137030188, 1428716435962999322])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([127672764899268748, 15402216938351279072, 5319670107210978360, 2709911522293629412])),
-                Fq::new(BigInteger::new([16870622574479250914, 9274571462396514894, 12219503036661810123, 3129359054146296689])),
+                Fq::new(BigInteger::new([488583077091388577, 11253237152616447614, 10180555619922604956, 2569263992323011891])),
+                Fq::new(BigInteger::new([4394399138113721296, 7861937262079295697, 10541068830135290492, 2870513277226189373])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([18273469717518098276, 11616058780190996178, 8832439073399318911, 2642882580925695147])),
-                Fq::new(BigInteger::new([771125542055715852, 792149189139442898, 1114209781636398989, 3109912118027245534])),
+                Fq::new(BigInteger::new([3572425638076844348, 10764656924618626069, 16439038681707439250, 610748596122108175])),
+                Fq::new(BigInteger::new([11090028454205407568, 18274029573794266784, 6211689729631605122, 1221245998612757356])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([8306676629857538149, 14268011708190311868, 13999836502098127578, 1185038172506528435])),
-                Fq::new(BigInteger::new([18393538655037636448, 16408149086602151330, 526297842415736176, 1579904159097523038])),
+                Fq::new(BigInteger::new([18354092326321453955, 1523818437228802337, 6541211788382344186, 1321153100749339699])),
+                Fq::new(BigInteger::new([468327242828789123, 15743186101359223889, 15533838542690090917, 851147901823380878])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([7329699305722060820, 6875418738727314433, 14624784855507094642, 1999546516158711306])),
-                Fq::new(BigInteger::new([585284376477081723, 14998610496634423105, 5328881677121574415, 2154293049979485985])),
+                Fq::new(BigInteger::new([6552794325234325954, 2649823110352850351, 3120272321913035273, 2577556024437868309])),
+                Fq::new(BigInteger::new([11856106359824772853, 13821268215054867107, 13841299939599402693, 1969783089800628299])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([13875448438694377234, 12999811929059666343, 106835288809825833, 2819724500072127610])),
-                Fq::new(BigInteger::new([13745869089795907487, 15773873329575693002, 10593075733028055493, 2401032992272632093])),
+                Fq::new(BigInteger::new([4890240353011681070, 13542190769313807080, 3738686194326214736, 1083311580557433289])),
+                Fq::new(BigInteger::new([685758364766884187, 6654096586452201959, 12367435229074222043, 1922956976948156480])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([17414840141812157522, 11036107049148573096, 12753007077890915454, 3104236349383463218])),
-                Fq::new(BigInteger::new([2813708120221042069, 3554413296492272442, 2387671281971049942, 131568584995622052])),
+                Fq::new(BigInteger::new([2367495000584160002, 7354280023712457152, 7147983012565280172, 1609714476488001079])),
+                Fq::new(BigInteger::new([5372539531551991515, 13170876724127803618, 7178296386122776202, 2163020820865761037])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([2922922879510985613, 3744744495878860519, 9620469705557321244, 252324342084078927])),
-                Fq::new(BigInteger::new([2179059863044567215, 5914574843049176964, 17522933213541417240, 2671431475886430519])),
+                Fq::new(BigInteger::new([16797214870742990813, 13466834998267476395, 17551424081609273577, 2774322482273442922])),
+                Fq::new(BigInteger::new([2255981525115770504, 10689343425898912172, 1048871974150057852, 848518807040717704])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([1359884927592929772, 3441421014572063728, 2971793694182814056, 1956134034945415567])),
-                Fq::new(BigInteger::new([10027970054225243594, 1103107729624887284, 9191164785418103856, 3385389536094061898])),
+                Fq::new(BigInteger::new([1881001950325278452, 14027291972730414063, 430912602396680184, 1926809717596214069])),
+                Fq::new(BigInteger::new([17936332738206672920, 17903766828632067357, 8041333665881813477, 2884109813984320951])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([4654902763579965765, 7485166734743162633, 7434651439937619472, 1070948630622017875])),
-                Fq::new(BigInteger::new([4498907020917406237, 10213136547024339106, 5301772010490580090, 2161649132782103954])),
+                Fq::new(BigInteger::new([583013416546691777, 16331446457218641660, 9328105671605028905, 2339231651033090176])),
+                Fq::new(BigInteger::new([16451294081512922155, 14341021519473674660, 13418840526896454962, 924151640000272340])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([8953886205812066822, 15933404935812542099, 486511733951777197, 1547636657200821552])),
-                Fq::new(BigInteger::new([11482959666973505417, 10300785781220802763, 7591808456303782708, 909467830950178102])),
+                Fq::new(BigInteger::new([28334468542189791, 14210875672560930047, 8523360835352449184, 301379068041868142])),
+                Fq::new(BigInteger::new([15643474635679040826, 3171960808267358489, 909048796209709865, 1405122554308440253])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([352648726409381133, 9559707753852981128, 11370339951629918129, 2472700007383146182])),
-                Fq::new(BigInteger::new([16378271923003686100, 3198855800748037747, 10007628949306830500, 2112331716704168116])),
+                Fq::new(BigInteger::new([2301242503823855857, 12509678232041299226, 15555500093839644275, 2890761984578167320])),
+                Fq::new(BigInteger::new([12335109055255344477, 9449249421953982856, 4766901404824450333, 961070977951730120])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([6001575260037229862, 3098091733006212865, 12759628954583110286, 2167953024531849363])),
-                Fq::new(BigInteger::new([12299886474852322426, 8236390167157588956, 5824825076330250262, 1793527845428200431])),
+                Fq::new(BigInteger::new([12189367780958881282, 13868515360535481620, 12869276216782405590, 2133381312916080132])),
+                Fq::new(BigInteger::new([12983984035506003327, 8872042773982060286, 13086389401231439651, 2611229073630387970])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([3604765174027914333, 4087533018795301133, 12335451986811255059, 2583632311223646383])),
-                Fq::new(BigInteger::new([11081867214593277245, 17176399362317880429, 6623698107869837366, 1399547714597968816])),
+                Fq::new(BigInteger::new([5737340650551979410, 11677490819534503433, 15162111710724188609, 2217083431163922583])),
+                Fq::new(BigInteger::new([8578127640159882529, 12993463185869404585, 14566588406307318289, 615274705556045163])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([5839463027997781351, 10848288698087466455, 7307624743077848845, 563674218332079321])),
-                Fq::new(BigInteger::new([13273541754765353983, 5297285445807971913, 13814905425369771151, 741050018715238144])),
+                Fq::new(BigInteger::new([8655116835477685097, 6922928572040637233, 3499422801124119535, 50799410877137145])),
+                Fq::new(BigInteger::new([12136892649795763971, 17426460410809581610, 4988616304027559321, 2772228711911005272])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([13095682948179626851, 10417054517386695144, 9400075288905095053, 1007156370660105194])),
-                Fq::new(BigInteger::new([17786653732161718154, 17148720317299431722, 14160221345082622877, 2742453234661107652])),
+                Fq::new(BigInteger::new([1712215283794324540, 17792717211235935107, 10884357128431333002, 149927915026253659])),
+                Fq::new(BigInteger::new([4628649548325239498, 14276525436665018415, 8317587432254953605, 1104759759916751625])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([1808533338655748316, 14454696171740919266, 12957638462330815553, 2063488694759038082])),
-                Fq::new(BigInteger::new([5924640897941008932, 15748073845485722243, 14883381934062832955, 249036230167186549])),
+                Fq::new(BigInteger::new([10039857603113998597, 4456774724419980197, 14000333128081613838, 888172471363418787])),
+                Fq::new(BigInteger::new([2102470925387215259, 6963927206451387258, 14600594234909552895, 1982656657741142103])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([4943450946516700712, 14174515743357972204, 11870519159470839979, 3359093867147926073])),
-                Fq::new(BigInteger::new([17246079986106886495, 15127944975509117517, 15924766067143550258, 712139452709408914])),
+                Fq::new(BigInteger::new([8707175715993436289, 16168937994479961646, 9250085841285329221, 2402826301870243161])),
+                Fq::new(BigInteger::new([5141500710792421117, 5446139804323027896, 15294729123160551129, 3386363072237230188])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([4878104168402823480, 13454281687791797743, 8774355807692400941, 377984979239753992])),
-                Fq::new(BigInteger::new([15111541004867403313, 151364467825031441, 7201009539861322049, 2017863406335115408])),
+                Fq::new(BigInteger::new([14550437119746099837, 9167984859462985368, 16816562032441432563, 1057025515798550962])),
+                Fq::new(BigInteger::new([14319734081540279875, 6188947327225723774, 10947384693122477577, 1090069676544964807])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([1473311129349514521, 7800791004457142536, 2080821799438093037, 564463801814615559])),
-                Fq::new(BigInteger::new([13423219911925784757, 3762973777431661762, 11679355224516114305, 428285275239545677])),
+                Fq::new(BigInteger::new([6748273661382137044, 11404626363847331198, 1349738579203728751, 1447873308181219037])),
+                Fq::new(BigInteger::new([3529852182062360901, 3160193257903911005, 10196366570066469533, 1848960700617940671])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([11214564100784956124, 18201535771202263069, 13591992138166836788, 1012738911169513532])),
-                Fq::new(BigInteger::new([9379216639126253894, 13693027112787231734, 16273214939311216077, 1283170646145942940])),
+                Fq::new(BigInteger::new([1697495186313108949, 17617702428369409420, 13903357293890765085, 570175822867004345])),
+                Fq::new(BigInteger::new([8382334472793852455, 8612760833279832715, 7912439582814695342, 2177549053999274050])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([13498167910364240773, 4693174372847296825, 1182987010160053263, 1275293264040798579])),
-                Fq::new(BigInteger::new([7806799493616417383, 11919120040212212269, 6791798511123510671, 1538837645677645833])),
+                Fq::new(BigInteger::new([17412141503125041747, 10799980480925890721, 13558114963763912247, 194527193473072826])),
+                Fq::new(BigInteger::new([1975091347989361872, 6940167368250489556, 5244923894681170691, 564296292256371325])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([283031510328028855, 7508767015819600878, 16861133558243242413, 3198412480358106895])),
-                Fq::new(BigInteger::new([1841995783178184954, 3009638160125136797, 15302668392411240335, 1882513805323287343])),
+                Fq::new(BigInteger::new([14719068792728338912, 2642334267751305365, 12434432759860982544, 2645241985584970340])),
+                Fq::new(BigInteger::new([15040480098397853558, 1640401229228838891, 8005985271974158253, 577843956501430317])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([17654279384848582910, 7362923655967117443, 6431320931957982056, 1946232511432858104])),
-                Fq::new(BigInteger::new([17989769277902348727, 15031333385444273612, 16271676828425236245, 3464905102708405316])),
+                Fq::new(BigInteger::new([16771491229559672992, 15641690556265669125, 16689197832106242537, 3453004929051855482])),
+                Fq::new(BigInteger::new([7957549596588864491, 9184201980629152915, 16189907253526412344, 3099180228442835222])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([17510912103280377821, 14610260127792693230, 790332887505212444, 2684788864810272646])),
-                Fq::new(BigInteger::new([947005621798766981, 4117534382120031518, 3137451992627749528, 149553957312245052])),
+                Fq::new(BigInteger::new([5085748336587945410, 11538926475482009973, 10281381078950056507, 19399637515990178])),
+                Fq::new(BigInteger::new([1619226636405815704, 8075308844327497964, 3981193656822714065, 3200777728724048871])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([4400853069636065914, 9190166282822011764, 4287445366316681270, 3202091825721458418])),
-                Fq::new(BigInteger::new([13186310552397354518, 6573207506576060327, 1155664677645232559, 2477509632998254814])),
+                Fq::new(BigInteger::new([14341110082212370359, 12276915339929919421, 3797901804749797198, 3480836957503714119])),
+                Fq::new(BigInteger::new([18180517817250597739, 14172381937644001111, 13508111220017765599, 2672297842282008191])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([2935615347712942155, 13510662564554725097, 17211847757056353829, 477334093241892082])),
-                Fq::new(BigInteger::new([5024600035230705157, 15405653706435726060, 11491094893703240369, 2693669741720467018])),
+                Fq::new(BigInteger::new([3502951942047662171, 13133009664570286022, 2817748585483221919, 2261207473138639997])),
+                Fq::new(BigInteger::new([8823599854745040277, 10463986439686770127, 1706246851662337084, 949347374874696994])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([5522423443725880705, 9272720214659333978, 7039050805338025937, 2964573478342360700])),
-                Fq::new(BigInteger::new([7592152049310020660, 13933308457273372045, 317182605669302046, 1804461136211659891])),
+                Fq::new(BigInteger::new([2670716442742243144, 11100832743243417765, 10404736981546430787, 1049436059380557527])),
+                Fq::new(BigInteger::new([8723956713728798840, 10693750153164789885, 11715244501481133025, 1139449130906521042])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([7306232196695584460, 7553990099823763080, 13417137052522107927, 154732970715325384])),
-                Fq::new(BigInteger::new([9082016974455470278, 5589263504038097652, 10442090939536258961, 1061077802197568767])),
+                Fq::new(BigInteger::new([364539694035004401, 10393714377994968122, 8261684889322856639, 2285564223630814446])),
+                Fq::new(BigInteger::new([14129089575612231801, 18330944924475902197, 2607983032650825591, 368975693018665342])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([9102957326700530503, 4707033098416668684, 11425455304560614968, 378119646294276743])),
-                Fq::new(BigInteger::new([14725687289899577003, 13461045048556096339, 15161122622375517623, 3225088644254635912])),
+                Fq::new(BigInteger::new([14616156733005241553, 1460788771012978547, 11636712568460947269, 802581810902658492])),
+                Fq::new(BigInteger::new([7926521660200020037, 17126277298170039809, 7698336420123563232, 2993560011314502909])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([7814194103756538655, 3603932707316266671, 7525897284951150567, 1811283753030857856])),
-                Fq::new(BigInteger::new([8515104052925741530, 13398857275552548312, 2305100109853493585, 1685108353283608854])),
+                Fq::new(BigInteger::new([7974965710138459287, 13323873964567958150, 7052716833882660810, 670565382067617853])),
+                Fq::new(BigInteger::new([10174812129010119003, 7761571081266884837, 4166909755403621194, 562356750013186753])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([12128771798611398439, 11138471914106345524, 10180169409143119378, 678049472590668938])),
-                Fq::new(BigInteger::new([4731892234209358889, 18015124850546615364, 14495335445904864646, 901141393863637229])),
+                Fq::new(BigInteger::new([6762487034398990641, 6328126706435645381, 159386729316108217, 3208598759976493069])),
+                Fq::new(BigInteger::new([18415133391226886161, 7206088086988014421, 10977042113302390050, 498726038635765804])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([970544139940568245, 15014235324226209222, 13520504755748199563, 179328581401805089])),
-                Fq::new(BigInteger::new([12112226181212330796, 14321914103993607977, 13847129023332235177, 189363281313560996])),
+                Fq::new(BigInteger::new([14188157397669577364, 8899215240351637848, 14274793394729286064, 1762787585192840377])),
+                Fq::new(BigInteger::new([7133143287921253880, 15229894362467136983, 462020782686406179, 998596560462087457])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([1760129603641888919, 6893662544752198626, 11676853806516984061, 3215631624642356655])),
-                Fq::new(BigInteger::new([9911599449184188608, 10354187772558138379, 18321014268414040007, 1718708401764804344])),
+                Fq::new(BigInteger::new([7331678090675661760, 4074984221031039466, 9614111614491625530, 2262670040007996057])),
+                Fq::new(BigInteger::new([13783146913182325483, 7179316941854758466, 16657901154399895319, 950591774478919167])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([17915599857371846708, 14174021789754952535, 968417004198575787, 2929822813643617008])),
-                Fq::new(BigInteger::new([2586800615340213475, 1647824316880754761, 18324248679982288552, 628475640791542592])),
+                Fq::new(BigInteger::new([7879781991484201971, 14005984353755752002, 2394397792779360865, 2023742759367064837])),
+                Fq::new(BigInteger::new([8198004013467198975, 4248641668080390387, 188354685385210325, 752004641786902403])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([7444175638545767619, 5968761688176238146, 14299002563974781497, 374285861955697624])),
-                Fq::new(BigInteger::new([16247377192647194742, 18114612261673507741, 6459464063132480995, 743156972154234011])),
+                Fq::new(BigInteger::new([9857750747435977208, 1596474677766303419, 4753492222594213411, 1141974066615201660])),
+                Fq::new(BigInteger::new([751261925284301437, 16590123316266788762, 15729471525681413089, 2186229939387822052])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([7423768822614976405, 9226439896076791460, 4252626193292796647, 3280087000165864793])),
-                Fq::new(BigInteger::new([4323083872550085582, 7270783945880606975, 9927176456862696455, 1055752557390569894])),
+                Fq::new(BigInteger::new([11338788426568238652, 16977921763670856422, 5090253495263930411, 592506746923567500])),
+                Fq::new(BigInteger::new([12568827952301326017, 7380439681847069903, 11564499000860354593, 3119440916239744637])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([532637802883128412, 6105871372774183990, 12714368392250579814, 2061351989849930096])),
-                Fq::new(BigInteger::new([7824187456319809547, 10699491026221115356, 257004074479332892, 2919095186800773397])),
+                Fq::new(BigInteger::new([10676041309207878309, 3669942307759108313, 6396174457305985174, 47807119096574500])),
+                Fq::new(BigInteger::new([9208232909968384676, 11174025918761939812, 989239138990698055, 2807124788434374493])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([9070941436256987582, 11774387066746316487, 14426286028309010405, 1615819295661136781])),
-                Fq::new(BigInteger::new([7084406652678418152, 15313936584512081678, 2980893106815472181, 2983933375057864184])),
+                Fq::new(BigInteger::new([12982735910328203010, 15227357969743960981, 15285441265402481610, 2181766018054910244])),
+                Fq::new(BigInteger::new([2307037031342411742, 179627565254493698, 1635255369183525934, 3377045571621487970])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([17075847293471805801, 11304733736757821254, 4821461126703835665, 219482541579497217])),
-                Fq::new(BigInteger::new([5446142820656302924, 10088815438285666642, 12782042355339112633, 1359965981430654992])),
+                Fq::new(BigInteger::new([3985720104839644657, 2376672064053957898, 627655293028247677, 2666626068724617705])),
+                Fq::new(BigInteger::new([13804326207415045944, 2547467276534863627, 9921550687299882537, 2682804767093693094])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([6284791642598012552, 4429097378216616605, 6855462624010242353, 306986193747669712])),
-                Fq::new(BigInteger::new([6086579873370418240, 11051047072671984350, 6664467621005943260, 1695556708461116346])),
+                Fq::new(BigInteger::new([1426911524271513203, 5824972970902155027, 1160267341119169230, 775533448793179920])),
+                Fq::new(BigInteger::new([14730651404953469959, 3000243212234593093, 5137429152538084608, 2453637109131505991])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([17824636056459355788, 12545905035283970288, 488268409369486747, 701912672442983190])),
-                Fq::new(BigInteger::new([5324975931459715129, 9129539605284438028, 2600893297195555278, 1494745675987959235])),
+                Fq::new(BigInteger::new([6149620642100653216, 4298254769502364765, 12410100272339456990, 828548102665277738])),
+                Fq::new(BigInteger::new([14840901033149347749, 10183913332534644811, 14719226362246894756, 2897597184954499997])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([13625916611729770675, 10773363143230406876, 18443730442825560848, 2053907185636863714])),
-                Fq::new(BigInteger::new([9051445300782167802, 9836520507609653577, 12517217506864888052, 901444204944826136])),
+                Fq::new(BigInteger::new([4558047459572331389, 4410087070208251447, 16054424411842087794, 2807046229919745838])),
+                Fq::new(BigInteger::new([17848967056657785194, 8016231380654392890, 14482126572591527052, 2956600261033457979])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([9732461195955224710, 5273931745112505270, 15963660437641789819, 3440175507259792040])),
-                Fq::new(BigInteger::new([1324072425979288721, 10382311933635188433, 13610800295763569225, 2938559481062432130])),
+                Fq::new(BigInteger::new([14198927669567930929, 15291807215954766443, 10936179087552875350, 1148345568205379506])),
+                Fq::new(BigInteger::new([7136508559294467349, 5061212254118197442, 10085361697754480017, 2123218657992668144])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([848133181244238828, 12835529051602407862, 9242445799442865507, 2706726647003964836])),
-                Fq::new(BigInteger::new([458713465463407274, 15559824970076764148, 9633928392791367400, 1295358617836624949])),
+                Fq::new(BigInteger::new([13794097658380244164, 9201745175945560689, 986410790431141489, 2281604388425452423])),
+                Fq::new(BigInteger::new([12865677028644488144, 4019209117718575469, 9037326801963460066, 686471936553425013])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([1578938133846980877, 15789267899212403731, 8713706997742009333, 1745683884647557319])),
-                Fq::new(BigInteger::new([4509067133272185770, 1875545232841964435, 346889567132245056, 3150541178812214396])),
+                Fq::new(BigInteger::new([4791741997188768213, 6699389170666094371, 6945886859548765109, 3327620276199389983])),
+                Fq::new(BigInteger::new([13448926006779320489, 7603935982039004183, 1573738816556173229, 1664832342812510072])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([703451800819196188, 6344539974707003536, 4448133537034327410, 1517333137801726507])),
-                Fq::new(BigInteger::new([12216746292598882924, 7073653716534217244, 6526959349138136705, 3376195587163604453])),
+                Fq::new(BigInteger::new([5070356240554912785, 12735703918790735888, 6810056977498100416, 561557927241743014])),
+                Fq::new(BigInteger::new([1142015161697483965, 879958915809892283, 11190849946505721919, 1129554095028134144])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([11315213737886517292, 17686896460567981668, 13796602568009004982, 777541888821543414])),
-                Fq::new(BigInteger::new([10416743104222770882, 9413595745837707991, 3482623626867216149, 38732217955517264])),
+                Fq::new(BigInteger::new([15316066769855472182, 7202324005418106987, 12667698633404789881, 2593525013844229638])),
+                Fq::new(BigInteger::new([895399636631759710, 12581920099862249489, 11271585805033580954, 2816943269928211779])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([1435642726659945313, 5833202091583090481, 10731367481817763307, 1727146270485212493])),
-                Fq::new(BigInteger::new([12076784038105070195, 10942860748145160929, 3284321464603155844, 1260709698295797726])),
+                Fq::new(BigInteger::new([9808319194354456841, 14795524304541134153, 8391876773378862547, 2554152819584289239])),
+                Fq::new(BigInteger::new([12924472501856182019, 16671618846508596701, 7119436578148462894, 878283759605037793])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([16123275819280760070, 16614209365143692932, 14528316233271730467, 135100055080815916])),
-                Fq::new(BigInteger::new([7031125984695320886, 5025105648823186841, 16266284247215065378, 1149181420176083737])),
+                Fq::new(BigInteger::new([12170626251764673355, 2420310821589567129, 14539421949976390101, 1197585469487164099])),
+                Fq::new(BigInteger::new([8937580838411413506, 17468860019069073649, 10800685804395554624, 535692251968891020])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([7877247567951364223, 15764381694425374456, 672083238317966909, 2850652720628474866])),
-                Fq::new(BigInteger::new([8062854355519435563, 7108584040228220230, 16003676946689741887, 512222093322155474])),
+                Fq::new(BigInteger::new([12168262730510397015, 3388186366163734928, 14448194778884384468, 875916773875797481])),
+                Fq::new(BigInteger::new([12360750717217228349, 3845929167291749844, 9328904045580698438, 1913224854652245056])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([6151534755716070441, 13035155072047423768, 11519529434880290770, 670819020528492043])),
-                Fq::new(BigInteger::new([12514589543403270922, 12642662652242171328, 9904162407170398736, 3208002260199767099])),
+                Fq::new(BigInteger::new([6639787443250207768, 5612826062485172392, 5508605830066639801, 3384905178401811007])),
+                Fq::new(BigInteger::new([3428031587946801197, 11871108360491028199, 7552602531908832383, 1272112332276055877])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([6343817270034224483, 14158272186410454255, 16881334832077129370, 98912607856515953])),
-                Fq::new(BigInteger::new([11279660823817182882, 17192601049197139517, 10149122440703826783, 988065534438251909])),
+                Fq::new(BigInteger::new([3997755106630147488, 671821585827735258, 16660635679593178968, 2975202486870290660])),
+                Fq::new(BigInteger::new([5362207970907189648, 13885556134368016286, 11424282521078851239, 653914697326359217])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([16143996210658724680, 7430587439826753430, 14160053345826462788, 990395108416112771])),
-                Fq::new(BigInteger::new([17962088373910057580, 14949296647042089695, 9067972161090900572, 2454975906621490425])),
+                Fq::new(BigInteger::new([17301068182149052989, 9034493436377260010, 6060967591844743074, 2982970926455402950])),
+                Fq::new(BigInteger::new([3096534403921077462, 17550288464171652679, 614248056319968922, 454763131400897481])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([11934829638292229409, 5198565512928959553, 16998989461213130962, 605943154993046094])),
-                Fq::new(BigInteger::new([3654275966279334881, 14566794106992878842, 17810369937747345671, 871270333718294136])),
+                Fq::new(BigInteger::new([1980044181096909332, 10515520667128512211, 11029554645726121705, 2059709063001281400])),
+                Fq::new(BigInteger::new([5034055028293935490, 16277265686123324214, 9570737136522349051, 1228982010747871158])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([7087545770816985974, 11848154169881729625, 3277806277439691630, 3445787039802753702])),
-                Fq::new(BigInteger::new([12398113646002135484, 12075352036318657623, 9990444909780300613, 1260280108455819308])),
+                Fq::new(BigInteger::new([1148015906625065533, 14935762559014303327, 15949896892242973072, 2867361586938679482])),
+                Fq::new(BigInteger::new([1918592872020506570, 11695919321970898492, 6640556270569699267, 2483399796487443872])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([10621307300765896110, 6831158359329456921, 4057977159684134158, 1868247693189030002])),
-                Fq::new(BigInteger::new([13352209210019529743, 9610734776129181369, 15733833882624958031, 49667269746463610])),
+                Fq::new(BigInteger::new([2581921555445488555, 6500617709741746742, 6616271351900754499, 1253255783804722228])),
+                Fq::new(BigInteger::new([2738823595782675911, 3203013988597144412, 5807925294374216435, 2078113785545885679])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([1558786832829654029, 17072105908617835823, 11949195581118790462, 1849518110829894265])),
-                Fq::new(BigInteger::new([325558070284462206, 1932350467872610003, 9768605301118021392, 2547045388528948554])),
+                Fq::new(BigInteger::new([10286939490402685166, 7250632652214814419, 5743867084755689092, 270265084120256158])),
+                Fq::new(BigInteger::new([9392489577343787033, 701276858569119730, 18216251805428388642, 2541173586768318503])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([347577903609526848, 15505809893585651224, 17178524387659980488, 1198021336139980034])),
-                Fq::new(BigInteger::new([11626749685263140444, 5842356173330473400, 390866649975704987, 108404730979020032])),
+                Fq::new(BigInteger::new([6168718551906975238, 9770172265668712456, 5679812429617062549, 3020589353624286918])),
+                Fq::new(BigInteger::new([12298381544801932757, 16802534210946053927, 8151537484222300298, 855719121981615682])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([10397348076665157978, 15694105512390486472, 9208726220728833586, 878835556152781737])),
-                Fq::new(BigInteger::new([10987240831154995186, 4457316081910837900, 3092225014949497770, 1590195175859882152])),
+                Fq::new(BigInteger::new([13494935421887642656, 18209009432241921025, 260791493322699944, 886007165318507140])),
+                Fq::new(BigInteger::new([8392467902069947463, 4618247837522773145, 4021545582382462431, 3118559159519517880])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([14830872801751859256, 4616090739858294473, 8223375565503062742, 1351641386293843195])),
-                Fq::new(BigInteger::new([4076836168862896990, 11199170291644187431, 14259404409902841087, 2108976981138153177])),
+                Fq::new(BigInteger::new([4006536864620571896, 11604863746429178679, 13664341596893801345, 1986945130965603046])),
+                Fq::new(BigInteger::new([4964088601604068520, 6610632193081224275, 1179775041977771607, 197492762319030823])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([230501393196193992, 5736143380664170102, 8278347495936270341, 1533581000598910892])),
-                Fq::new(BigInteger::new([15576895687530303722, 11239326044981375614, 9935033522293012089, 2566463281622621495])),
+                Fq::new(BigInteger::new([11288377670525770063, 14069816366090693171, 899023744146748988, 3172455872958823801])),
+                Fq::new(BigInteger::new([9991211056255906549, 1435385971808103714, 12267856062084336290, 430973212622977680])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([5194828822189711484, 11842725740496957933, 1788695403475205738, 1325827188897434562])),
-                Fq::new(BigInteger::new([16963337269730772338, 6143394701421925947, 10742679411141463270, 2517629451138040999])),
+                Fq::new(BigInteger::new([12059297285303405530, 15970745889279084138, 3943911905162247977, 2868340302393676081])),
+                Fq::new(BigInteger::new([351753236969540042, 4271158924015698257, 2084689761811441817, 3463562280357850703])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([3946041934676496074, 5703987950887141349, 225879500592929308, 1683184840246676596])),
-                Fq::new(BigInteger::new([16998057833284757782, 7872064570714011531, 8850868689145326815, 1820327017250186926])),
+                Fq::new(BigInteger::new([14952003317333984517, 10635448707385748341, 16253617188030548547, 3478176359744927927])),
+                Fq::new(BigInteger::new([330525082626718754, 14304343880864575329, 18189806503385269581, 3172502448617242413])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([11586188729169326479, 12468218525072884071, 6304708357114360413, 2410908124778272108])),
-                Fq::new(BigInteger::new([14806926637121868641, 7664320268681589838, 10562064541541426405, 1488027053059444525])),
+                Fq::new(BigInteger::new([13977520067079920877, 356935743603575465, 5934409022517077374, 1468466484346526927])),
+                Fq::new(BigInteger::new([15761499760293948000, 1344597785792189873, 884766636487040842, 1280356724297298230])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([5825726427767400142, 13391892970577542548, 16451328869293458627, 2075254949402253301])),
-                Fq::new(BigInteger::new([11914490909320184684, 1021992557510541308, 5398504554820107725, 2597902156158936377])),
+                Fq::new(BigInteger::new([15614877115381493730, 4623247219895862574, 11548096416909973247, 946145945332983579])),
+                Fq::new(BigInteger::new([2033415691566488418, 16201828480717182234, 8084403067935322469, 451603369826355928])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([15024045545491785621, 16404652181098298844, 4445960289960409732, 2617109851863132178])),
-                Fq::new(BigInteger::new([13151921308292107092, 2017927808990828889, 5529616190849194339, 2893993475784372958])),
+                Fq::new(BigInteger::new([12569759061060298066, 9508666045662284374, 7986884576325283966, 1521073646615718400])),
+                Fq::new(BigInteger::new([9856001893781463796, 15756739534495996855, 9372001032636439955, 688039546380030663])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([13384740139954949515, 8597811377055792145, 12776048844126409868, 2305237541570194785])),
-                Fq::new(BigInteger::new([18221290688403594024, 17008752504319906871, 3100822188472193025, 1187317594331329064])),
+                Fq::new(BigInteger::new([14904542299452905021, 8212964782110109603, 17139263522308377542, 1482097992743486074])),
+                Fq::new(BigInteger::new([13815592733216012398, 3679657010022460336, 8211093415805882489, 2251127964033672908])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([7619286981327766371, 16453895817911943609, 4472265835174290718, 220200126381289293])),
-                Fq::new(BigInteger::new([8160984625952242647, 13875920570539664206, 7973785515092425430, 1175367798392495514])),
+                Fq::new(BigInteger::new([7840376946335423861, 18324843262871770301, 15035691978539718588, 1068948336227996716])),
+                Fq::new(BigInteger::new([16165431245485331621, 11766598491438584010, 3395491497724793203, 1178546426593688897])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([18186615675518024733, 472821183764923522, 10938009009284998846, 133855181661724127])),
-                Fq::new(BigInteger::new([6568005998352185738, 6061423578136779796, 5311729443412041276, 1443797838931211707])),
+                Fq::new(BigInteger::new([10064622175616702362, 1375147503300410373, 14260519379701695514, 596870098891523152])),
+                Fq::new(BigInteger::new([13400201144864845839, 15644021143734873136, 15468491302281913494, 2707780737709628091])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([893896563755218809, 13117757767131991810, 3508763465745401231, 2787532264196352990])),
-                Fq::new(BigInteger::new([1891403101070597415, 7393501870513494715, 1629913992701327379, 423275256145431671])),
+                Fq::new(BigInteger::new([3488901950172339566, 3305912009347549915, 8209323839581133389, 2596134315078415458])),
+                Fq::new(BigInteger::new([1029737920086059150, 4403896959069745415, 10920644812910945258, 1570782264453980038])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([17839447171669842765, 13801196608448823904, 11885166800098148527, 2745125857424268177])),
-                Fq::new(BigInteger::new([14640534826953124367, 8740142352869209151, 1702946000468951267, 1537618326674644877])),
+                Fq::new(BigInteger::new([8948078184971729469, 3633966455899094455, 12740124348635776294, 2072671399868876131])),
+                Fq::new(BigInteger::new([16649039891011054376, 8730313138258420926, 9592449095171580386, 1885408506169606914])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([7421628919581076952, 1924264635857468977, 12980987877958202691, 2264555072022945597])),
-                Fq::new(BigInteger::new([3057867440612676473, 3711309438608551877, 12123355713709888145, 1031613054859298714])),
+                Fq::new(BigInteger::new([1237661031144856, 16880321150944371232, 4678365094092475730, 1150581390847453999])),
+                Fq::new(BigInteger::new([14187895778322212342, 8021007496450709144, 2581503771869819652, 509141656703006297])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([3105269663498415246, 1415738897155285688, 12029987923633512637, 2977398488050055315])),
-                Fq::new(BigInteger::new([10117037747228221638, 9853214950586019295, 17823005804824710501, 3458505449728870431])),
+                Fq::new(BigInteger::new([10729256692498617103, 8008773372772309205, 16684684519267078568, 811333716320930136])),
+                Fq::new(BigInteger::new([16182242143925272609, 192202732713487274, 7348892640857342905, 1095170775081354042])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([18098730400299940793, 10042116848303187334, 5558197382018985897, 1997941197791045023])),
-                Fq::new(BigInteger::new([8189037237845730236, 3610607413376576904, 13894179374798571525, 3034751744408366732])),
+                Fq::new(BigInteger::new([574373504670645614, 533351659892396325, 10124433500624856623, 2822571517864061444])),
+                Fq::new(BigInteger::new([13927731029727973278, 3794675341338760919, 4854478896248608282, 117602530654162577])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([1047760835001833242, 7509963562798827747, 10114054541489371233, 2472551280049697504])),
-                Fq::new(BigInteger::new([7626954764212104183, 6284354680799478271, 1305375345059302488, 2139805890277138354])),
+                Fq::new(BigInteger::new([8585164765249448635, 1203402631107069486, 977219116574857958, 595742188770447705])),
+                Fq::new(BigInteger::new([10269028233730949965, 16824930369335965310, 10028332242406347358, 4475036332651474])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([14523749593967345665, 1403927071912021155, 11159021864931467125, 1233179732417275483])),
-                Fq::new(BigInteger::new([1034720384323507467, 15369508362955030912, 18436498794782809587, 848417476436045980])),
+                Fq::new(BigInteger::new([7488728850159822632, 13129324659625795791, 14281282666643253987, 2300338393717756779])),
+                Fq::new(BigInteger::new([2755352651046662794, 11413055159945045085, 4140222320414775869, 3257726614650270792])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([10623212045643338630, 1954609857321650775, 7101392895113096025, 279914900929163843])),
-                Fq::new(BigInteger::new([17997264437629998207, 14634867102535870292, 1451553485868521666, 1473184512692702659])),
+                Fq::new(BigInteger::new([14461626375024795424, 16125851086464961731, 11879967254206943948, 2968226497992580272])),
+                Fq::new(BigInteger::new([10654764721248012490, 11213656567532681789, 6481666582305865841, 703499475511583815])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([12476429309681985324, 5722033009479237266, 9984219495954126205, 1608432778041677284])),
-                Fq::new(BigInteger::new([1065982428000579599, 17077651633612242781, 14293595862874946462, 1938459974015645424])),
+                Fq::new(BigInteger::new([13800994725668278242, 10058439775359742252, 13909925168839512822, 2901592775486275564])),
+                Fq::new(BigInteger::new([1316652062084882989, 2874796829800935415, 15318523332680356378, 947978150038494640])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([16989950550275611220, 10633480277505534395, 17584944444905835780, 3354810760223326084])),
-                Fq::new(BigInteger::new([3091638725755464952, 15371936546475688865, 1011837260015572116, 2142986033429180550])),
+                Fq::new(BigInteger::new([3073168425910353627, 16634445742573584160, 239552941475823031, 1049400617420750202])),
+                Fq::new(BigInteger::new([7459913494139623022, 14887101171259289911, 3362687499625749926, 2002703377690960925])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([17185827308122851701, 14862052990246937369, 10083742817783165487, 3067158217651327442])),
-                Fq::new(BigInteger::new([10207263316136331220, 18352985377780734031, 14983610323156690647, 1502927433179785087])),
+                Fq::new(BigInteger::new([10638882742772822546, 7540160476598225376, 10180706668964420569, 3210775030980058942])),
+                Fq::new(BigInteger::new([17958123768881961010, 4481355785823468219, 4507041269093534502, 416067924685823781])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([10888036846156239253, 4505409095125018089, 17684637329216138017, 3053131701126752565])),
-                Fq::new(BigInteger::new([5970688936683898598, 3460741692323128416, 3645653824664807683, 1699868036663777373])),
+                Fq::new(BigInteger::new([2835833911578368203, 15352583404129482759, 186604322354261489, 2576589019167865394])),
+                Fq::new(BigInteger::new([5234294655448726626, 1075949561603773920, 14262274185657345034, 3054357023815671013])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([3540194045676810062, 7393380426737152862, 12003518080170982679, 3244408987051555922])),
-                Fq::new(BigInteger::new([18060888562176685003, 4069706860476941766, 2739879522317116419, 3285854469675627037])),
+                Fq::new(BigInteger::new([11267603310069748634, 8821362895625749497, 3455938847887071782, 3161032056843056543])),
+                Fq::new(BigInteger::new([5543713733846054761, 9825098343531091844, 14912574280601212743, 362565790608781108])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([10202982185816104658, 10834695587007596599, 12398077160589173960, 1199313677393301070])),
-                Fq::new(BigInteger::new([11776666790542795060, 6530008502094543988, 2911794360599280551, 3002310926190963101])),
+                Fq::new(BigInteger::new([11602907425669426827, 1575130017251912115, 7342378338420281039, 1641061966734462203])),
+                Fq::new(BigInteger::new([14327348426890311243, 3988158738142514589, 7546577900981014396, 1855991035749018846])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([5293649345915647268, 8179874287015979430, 3161521501494739985, 545253299491827912])),
-                Fq::new(BigInteger::new([1717985724616511205, 16741014150431918260, 5300640023778969132, 1838076115193624621])),
+                Fq::new(BigInteger::new([7893217421532044000, 9824654448079824895, 3491657636889969332, 2194595096846960863])),
+                Fq::new(BigInteger::new([300617638693839169, 17967652743748433086, 9800636439587320048, 3307219326029935404])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([182830563813346273, 3864299515277653629, 7857157843596792603, 1306795342507878450])),
-                Fq::new(BigInteger::new([8170404714110066125, 7804201581297761807, 243428607063192297, 278969455008662362])),
+                Fq::new(BigInteger::new([7403026766084335003, 11141696260577974276, 3121148962401876653, 355075485029167187])),
+                Fq::new(BigInteger::new([10044381242290170322, 9959219499564743650, 14467925338572116862, 3214080528034360703])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([10525678408723926527, 4457276324248481831, 10674304905283858959, 1819705165247704081])),
-                Fq::new(BigInteger::new([2063999085272544268, 7946675596461250095, 15073254516908098639, 3451352169639002646])),
+                Fq::new(BigInteger::new([16132503987171086888, 3837790513702802954, 6941859713039230601, 1981918622436057736])),
+                Fq::new(BigInteger::new([9048333353230740803, 2927681080335946413, 13299560062396100804, 2338374301230034358])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([18410015302543534737, 680916194997150006, 1955501977719974997, 2270360497278179067])),
-                Fq::new(BigInteger::new([17276332236056324547, 8020599327119486124, 7517312621918983342, 2233518975761371222])),
+                Fq::new(BigInteger::new([4738935728792809413, 18040844402667564803, 1891181512869099940, 588851500650874359])),
+                Fq::new(BigInteger::new([16589604836463233700, 7546240808681151263, 8218946094824142160, 653728778770838974])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([7577345358286291688, 16518816919015726882, 4702815730519568711, 2563837372399511511])),
-                Fq::new(BigInteger::new([11156069940442718372, 3525507534902334437, 10035284207228901380, 1523847709794953087])),
+                Fq::new(BigInteger::new([7313679203304180384, 16756434091031450295, 7230451669091437169, 2104426762988813050])),
+                Fq::new(BigInteger::new([12944573798154973522, 4636744477726208604, 1832106954266952195, 2399798486872707925])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([6707670475499613008, 2872289715264053409, 7306917850325145340, 1404076576367795834])),
-                Fq::new(BigInteger::new([12305564792212687068, 8571965344822641104, 16141032449379460163, 911640089477745747])),
+                Fq::new(BigInteger::new([14862178202606375033, 16573222788497193858, 11885256820722159481, 3398390832588523372])),
+                Fq::new(BigInteger::new([15106315835120399356, 10114232335940957133, 12630204840731385240, 3145443169658596761])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([743549459372188825, 11577453284591024660, 591701185407797391, 722276141626196207])),
-                Fq::new(BigInteger::new([16139799655722858409, 657961283423993955, 15989127146728202831, 2424443587732256169])),
+                Fq::new(BigInteger::new([7522582329970376774, 10397081908421691542, 12043571876349155082, 971448433803461657])),
+                Fq::new(BigInteger::new([2804488572692575390, 16820651413196439761, 12921273066228963510, 2386881679896937848])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([3253877699706554652, 3578139292754203948, 6440442572059690584, 988730645322116608])),
-                Fq::new(BigInteger::new([12893983322827831868, 9061185309749190020, 10357278738819642758, 2140557671363947745])),
+                Fq::new(BigInteger::new([2748276457254005883, 12876413925557247211, 11566457584203176797, 2298627730363886077])),
+                Fq::new(BigInteger::new([899296161652581174, 9795308266793160369, 2290720914314601624, 1425515568858922147])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([14737002151793562817, 15342717245472041377, 6954180070551483211, 1677609339380181417])),
-                Fq::new(BigInteger::new([7101872804212040305, 5530236170742096663, 4576425836912644753, 1724335382264215480])),
+                Fq::new(BigInteger::new([688898589068563501, 17434427310614926261, 12298510131099276260, 2546709505998712003])),
+                Fq::new(BigInteger::new([1614377782754277461, 9130248472329113037, 15925921547113846531, 523137644592806721])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([1954958583801318218, 16684197043783644247, 8017881233561956957, 1076095939606994714])),
-                Fq::new(BigInteger::new([4243962305094843929, 4140188373537170652, 14783435181930803931, 326606353943451582])),
+                Fq::new(BigInteger::new([5810650166038412842, 4725148940982856718, 13763253509993468334, 2127685020992193225])),
+                Fq::new(BigInteger::new([3767185594573261721, 7162186614782389713, 6804871202002013186, 1862850180241362238])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([6847986826689430935, 2098531655517787177, 1768881193228421960, 1778562695884889154])),
-                Fq::new(BigInteger::new([14562171343062620467, 3698917150189907566, 17993365042069910632, 3005680673192254621])),
+                Fq::new(BigInteger::new([17673741899645350155, 11011753836409402437, 9896429285668948688, 1691854453538114054])),
+                Fq::new(BigInteger::new([4722826722022910901, 99923817798332932, 301123161014038314, 2511200801384960200])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([13785806797712024216, 15295905232914292077, 1660683596797953310, 2445568023061556446])),
-                Fq::new(BigInteger::new([3866555192157953258, 697646492724375526, 14364269736221997446, 2121264154545582168])),
+                Fq::new(BigInteger::new([135827056096217802, 3689788984884628305, 2439770075028470999, 2471258701108121698])),
+                Fq::new(BigInteger::new([16092260644969428508, 9939551862452099313, 9889733989144181333, 428771949226384621])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([3365070701552891905, 5153759552645395828, 5140993491333695623, 2750940834294114323])),
-                Fq::new(BigInteger::new([4425701807850317133, 6591314293948025504, 3131619480330507914, 1348584375461017775])),
+                Fq::new(BigInteger::new([17043325230311044854, 12984352398015713037, 8572179977481660273, 2749530024502250197])),
+                Fq::new(BigInteger::new([453738434576420246, 926126525671454623, 10556088135588805170, 1646964831080472399])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([6302845035677938806, 427979419132245239, 3983562922595941730, 1572189018876016057])),
-                Fq::new(BigInteger::new([12052886104930373079, 15435520293819542468, 18247703330507354088, 2040143557538258896])),
+                Fq::new(BigInteger::new([15059721722644641291, 4215432510795204248, 1189586448788372775, 2192502786362131787])),
+                Fq::new(BigInteger::new([6837947423156680855, 14210239757360169444, 10410294030517553689, 2624168575916187310])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([8872743208926118785, 13798683778882382867, 13632205311900425067, 1630501921238952336])),
-                Fq::new(BigInteger::new([17713566694594655402, 15210950327310191689, 13047124431476652108, 1439142398236169513])),
+                Fq::new(BigInteger::new([10215507153820817564, 9530466503714379917, 4598166809088037080, 3397135451718858874])),
+                Fq::new(BigInteger::new([1697235471504591328, 12743232661199062348, 13229192157301354179, 3094724113480877607])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([3494852773179896710, 7199341668866101809, 14197178293267001614, 68729808178474492])),
-                Fq::new(BigInteger::new([4670807613905999559, 5349082980066400179, 5163805270547811548, 2956420875156100528])),
+                Fq::new(BigInteger::new([750339342053736752, 11107412075326277991, 13695402915109780969, 187228311507342811])),
+                Fq::new(BigInteger::new([1266155738493246702, 5459113217776592830, 4240352521014520390, 178615856580786216])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([16471878356720578311, 11628288752953309678, 5091780341048890515, 2782003813770252287])),
-                Fq::new(BigInteger::new([945690250901781840, 12767783887993243881, 12607729108405409803, 672097580987199842])),
+                Fq::new(BigInteger::new([4575830128330877024, 800312733889602593, 16301624998990688926, 3468188042858728269])),
+                Fq::new(BigInteger::new([17056144696381527199, 14074294467063797581, 3705574141648115637, 842048284220356952])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([10440811963967470576, 2993712317385358872, 15623703089437507640, 2896859640631067301])),
-                Fq::new(BigInteger::new([8639362261543310194, 12073418418955477160, 67891815079650985, 1686814260456423592])),
+                Fq::new(BigInteger::new([18197526030217570202, 1898554872293859106, 11506631854057416805, 2241396091773047622])),
+                Fq::new(BigInteger::new([6367513230217685469, 16003576710416790376, 3419251285683591921, 143228380714255834])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([2326604173825837437, 16355112449310692184, 7428951574235532082, 2595049026129094467])),
-                Fq::new(BigInteger::new([5929895309092001899, 10737709614711154420, 14712841213978916089, 3147154221254522617])),
+                Fq::new(BigInteger::new([14900479437695703840, 16816231238787689044, 12037378909959788533, 1416896734843504874])),
+                Fq::new(BigInteger::new([10582702951929723506, 15348003731132501904, 17832543413152342271, 1013886668895913855])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([18292860682437870795, 15402392596962272607, 11192997971742227158, 2755147264831022669])),
-                Fq::new(BigInteger::new([125722145561815640, 12030994021914753724, 1434088388565245210, 1189682671894204775])),
+                Fq::new(BigInteger::new([8883114529688253351, 16681659598798414722, 15504478166254978989, 2158294551805984539])),
+                Fq::new(BigInteger::new([15474421610550198109, 11332053670165687010, 12866649497763931728, 2066420731853487966])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([13458991421609459859, 5689338858174692356, 16954865725810826168, 2366697527433367659])),
-                Fq::new(BigInteger::new([18243096590169953381, 15967930829872229552, 3092290015606455187, 3318827862455414451])),
+                Fq::new(BigInteger::new([14952423774089053852, 17256969650342913152, 7368718727839301389, 2568658610282757092])),
+                Fq::new(BigInteger::new([7975565815871487559, 13688258299498625023, 1000845371823688623, 2978217498659835540])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([1449932281483631547, 13635228402859605560, 1253019273422431470, 865600248871003650])),
-                Fq::new(BigInteger::new([10895498020185497337, 1685092262616230627, 6715155604293506680, 166776979728197389])),
+                Fq::new(BigInteger::new([13513611338118867682, 8213207671605996520, 17461056279121151325, 3174295837153625706])),
+                Fq::new(BigInteger::new([3061805721021393873, 16964254031889604182, 10322802940441490449, 716958295588970478])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([8590785723639288809, 9762259709006918701, 9839489614560592810, 1512447969484224233])),
-                Fq::new(BigInteger::new([17275694277590130645, 8984559883559471504, 8272502008720639801, 8114772842639732])),
+                Fq::new(BigInteger::new([2802094407588336512, 10633054629034312303, 8733337080590055234, 1111578939685174009])),
+                Fq::new(BigInteger::new([16877855264436102066, 7098161600497965870, 2443966843427614035, 1703427904328050877])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([13898422353536278801, 9238368830692953488, 3429725737610421748, 2342015322732041036])),
-                Fq::new(BigInteger::new([9142709332732598048, 3340710050130611602, 8691938676678148750, 689652986379445725])),
+                Fq::new(BigInteger::new([14815048005088592956, 17444495519991913112, 13283385057782836410, 2853824301379437852])),
+                Fq::new(BigInteger::new([12682108313696764426, 13043933149848868551, 3136380293647618965, 206113512500537924])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([17607197026668130429, 4876022274595458496, 4429258873677168369, 276403476606273931])),
-                Fq::new(BigInteger::new([4551571514601374580, 4210334567206747159, 13524752136912328981, 1754301552287254375])),
+                Fq::new(BigInteger::new([13519922303402730778, 15088533604150873939, 227607781639321291, 1929948069462018175])),
+                Fq::new(BigInteger::new([15871842489556680619, 10201460959519534831, 11180602963411529293, 452563055072203997])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([12297683292420412660, 230101541933518862, 14582545124378237025, 1149324620562238634])),
-                Fq::new(BigInteger::new([12095876721299535882, 7589368145134812517, 16210973127382597718, 2208861836666992340])),
+                Fq::new(BigInteger::new([14709622643251885703, 6860265556309836225, 13716755643962398298, 2826156275573668347])),
+                Fq::new(BigInteger::new([3955418673689871851, 12202250972956672813, 14799024473361139511, 414182988744562817])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([15117509252706780280, 11827930308191712733, 11865232237340102947, 847507758613550897])),
-                Fq::new(BigInteger::new([3093495833038149377, 7301467996261811415, 13328118551612513439, 453049557336391714])),
+                Fq::new(BigInteger::new([210641430249971198, 10584679418036130408, 15639326928990186335, 1733247088042648274])),
+                Fq::new(BigInteger::new([7971640731679852890, 14746206246216763982, 11909122965584886728, 2302011564680811928])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([7607038880463765856, 3648846693878015421, 3824456795176051357, 3437735601859628989])),
-                Fq::new(BigInteger::new([4599230899342839109, 17128286588372975559, 17418528249805545818, 1724775219434374653])),
+                Fq::new(BigInteger::new([15576887552896198606, 992721992957525705, 8364218115445877253, 1254093809261881315])),
+                Fq::new(BigInteger::new([11708874525997290921, 13744683932861352385, 1908417632325408454, 1685338636005418667])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([12127179323175850488, 14201584961049747162, 17255592204138136247, 602372053846356235])),
-                Fq::new(BigInteger::new([9356331262083266948, 6587934423594966478, 16722421516719203003, 2059910888965206827])),
+                Fq::new(BigInteger::new([5823342506043968407, 51539339210304756, 12993264777818743601, 2655140730739939595])),
+                Fq::new(BigInteger::new([17552321103464205399, 14113678606553807122, 10651183331174705071, 2785554495424411380])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([16228247086757121578, 9767239633637037854, 12435700505080269395, 3200758034662155047])),
-                Fq::new(BigInteger::new([784171230129834501, 8829642283877651057, 3238205510479434672, 2914763060972581669])),
+                Fq::new(BigInteger::new([16099742473547623048, 3631636625385806169, 16224232896626828627, 2048500000282148141])),
+                Fq::new(BigInteger::new([16998878228674542193, 711651820129056729, 5886205979664498293, 1406135376699346553])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([16181971621648379914, 7360041480921490209, 6249935765335230810, 820930507947615818])),
-                Fq::new(BigInteger::new([13520661170194762228, 16163035141909906924, 163758057562793543, 2114425683121109023])),
+                Fq::new(BigInteger::new([12719657966547037499, 15442732563005054290, 4084456221206877433, 3387796685014122705])),
+                Fq::new(BigInteger::new([13048975678679591897, 9433774346164166957, 10258163564850799672, 1020301067422440324])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([17605503457517162359, 14926496587250439733, 15556464326003913503, 2902572892269274288])),
-                Fq::new(BigInteger::new([8049841561518695392, 15339492828250689111, 18120780313323847775, 1413954155709238901])),
+                Fq::new(BigInteger::new([12271647513138661587, 10217253364135730250, 9500572653491914658, 586442050507638672])),
+                Fq::new(BigInteger::new([13756138915836122791, 4671376427984940558, 17247285696912763316, 773305044987211777])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([4350844524112238084, 9452457445900917292, 2995579681823586562, 2015306418734935869])),
-                Fq::new(BigInteger::new([5190639923453841670, 16020086331637820514, 14011142995702996665, 2010124736495030839])),
+                Fq::new(BigInteger::new([9247971540016583037, 7183322239790791926, 8320584716174978383, 960959023554192331])),
+                Fq::new(BigInteger::new([517630037268228576, 16133815782722228630, 516781927028570079, 1132661895631406827])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([7302952758786989194, 5573356132553475476, 13924697934845730092, 3319819723983102677])),
-                Fq::new(BigInteger::new([6325776724821211548, 1881528691222260692, 554937952943189318, 545704241333193484])),
+                Fq::new(BigInteger::new([9404072631577312724, 15017476465682054634, 8736334359885848413, 2916196220018488315])),
+                Fq::new(BigInteger::new([4235960093418811481, 12432156168677120211, 9592288078032847616, 485213682499966359])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([14539231510293720674, 5944461225985730574, 11943174390664057154, 2195752481678895289])),
-                Fq::new(BigInteger::new([2507960733353951430, 3749742614266298675, 7064262369272237631, 2381986644206074446])),
+                Fq::new(BigInteger::new([14903587923562007612, 15510146532392832602, 69010575340673303, 2793406903882066364])),
+                Fq::new(BigInteger::new([5968033165672328744, 10775245320745297106, 10713470323024809417, 2561698060219278359])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([17703338073789587711, 1558622172464302128, 8385667423336042595, 836098346278712494])),
-                Fq::new(BigInteger::new([7951454093974464037, 5777247350142048771, 12328097538157947580, 1787569417323637622])),
+                Fq::new(BigInteger::new([12931125127240273830, 12216308053124879809, 369582289000972958, 30153107451993211])),
+                Fq::new(BigInteger::new([1626536226935153686, 3599177325027697784, 7370738730101713065, 2867294262383194734])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([3894033490403754127, 11372842050432503859, 12264420758801248891, 2692923895675798591])),
-                Fq::new(BigInteger::new([17063862390827248667, 12315826611226531224, 10300890176685031036, 2471352378666833083])),
+                Fq::new(BigInteger::new([8284215244941368176, 9790998079121145373, 7145281730385331310, 1502283141360284601])),
+                Fq::new(BigInteger::new([16216592302069271409, 6105867224954130648, 17407417843054838113, 3356567049963466622])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([6416766804416527576, 14320150289675802225, 17213855743329163728, 3439985803390630171])),
-                Fq::new(BigInteger::new([8292572351047016823, 2223977412193810425, 8019297563710792491, 2579446722201466604])),
+                Fq::new(BigInteger::new([15668359608328640168, 15062725525251234528, 408912291207527401, 1288993057488608544])),
+                Fq::new(BigInteger::new([13935658424239381354, 8563786274427774627, 9251713299869563061, 1464625670103660445])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([1121830672634006571, 5366012715608088393, 97571627592734434, 593773087224324324])),
-                Fq::new(BigInteger::new([16147945412510056165, 9612211931839059297, 13327312722420223288, 1735859890194508096])),
+                Fq::new(BigInteger::new([4593101851149855737, 10884623626667024443, 7140400334667849640, 2486876595440194706])),
+                Fq::new(BigInteger::new([2372646472953017062, 14630698696004932933, 12482773744282713204, 2076063074707229411])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([1645890380446540405, 14058078292995941466, 11478276944285801903, 392102880067806482])),
-                Fq::new(BigInteger::new([17565268136584091347, 10450098144739030993, 7628843154616651825, 957781006600336248])),
+                Fq::new(BigInteger::new([16416648816766819471, 13799817707862500118, 11837196640769555076, 658050394678389440])),
+                Fq::new(BigInteger::new([12477675635650100058, 17959390977838495504, 6359377861190592543, 15355393163650605])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([2004978198098589677, 12815709129400360957, 739497323100978555, 744553517864604531])),
-                Fq::new(BigInteger::new([10824876686322092792, 13197876055154687564, 13734425995059688206, 1846981145160312160])),
+                Fq::new(BigInteger::new([10902216661912858122, 2925284908538848637, 8186067751932009635, 2539065742603406735])),
+                Fq::new(BigInteger::new([2340865950887393687, 4748703442515655696, 15892903406333611302, 2094120160996264618])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([6016994069214943925, 1371501644970864910, 1770711025815171915, 902415572660313606])),
-                Fq::new(BigInteger::new([18180723223797968951, 13766803268727491913, 12727973894544616907, 363432644562085525])),
+                Fq::new(BigInteger::new([17427609131887752661, 18312072534516054266, 12368748162991362113, 2167177557339082745])),
+                Fq::new(BigInteger::new([7632016662478520045, 8813639274801352859, 17152817430669966383, 1708691249364088759])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([16234835231278095012, 14361959423073067665, 973232421014015945, 2164267917790657496])),
-                Fq::new(BigInteger::new([8310094612837236457, 13494520948072194571, 15980940716565817354, 615860261541057355])),
+                Fq::new(BigInteger::new([5392412307076526260, 11258353358658626830, 828359708943237913, 819590074178188478])),
+                Fq::new(BigInteger::new([10062366231009540075, 10485803720065526565, 8015504856488916284, 630521424292394658])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([15632843849390954190, 3469961208585233241, 3459084078997205505, 3120910930340194750])),
-                Fq::new(BigInteger::new([4874344148671836733, 5710426346975814548, 15018979613666803243, 2233258631856594975])),
+                Fq::new(BigInteger::new([9753109657680752092, 3631046797228281390, 12755846965432853735, 1671643378912409797])),
+                Fq::new(BigInteger::new([1479939344433848301, 13297787640567392854, 7030225714530786212, 2564615723136920383])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([9035278593158960812, 2201340928433314765, 15375803699834947517, 584025831828855941])),
-                Fq::new(BigInteger::new([14198092765417023791, 7634970317940975632, 9768581789359733402, 2465753408921555543])),
+                Fq::new(BigInteger::new([11684782742384641247, 14512835242113749994, 12697501308454627581, 441580303896277251])),
+                Fq::new(BigInteger::new([17831628218912282074, 10363400355380185351, 16226558953655731365, 271093008460781446])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([17134867429610408853, 4919951743907915088, 12526370098960883207, 239041751923296432])),
-                Fq::new(BigInteger::new([10233661814668166012, 11256706854393059758, 16192268309098433267, 3464129618210540713])),
+                Fq::new(BigInteger::new([12200342820887901384, 16771001348233814604, 17544801314556427930, 3406475033196405011])),
+                Fq::new(BigInteger::new([16420754684086715728, 15562635516891766686, 8283419609034949371, 1259686936234893730])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([10777346796772036153, 10829596886727908556, 565138797295374767, 551916732891197236])),
-                Fq::new(BigInteger::new([16466543140602227323, 8465225952894239891, 9461013583160938140, 3022046605631458109])),
+                Fq::new(BigInteger::new([7295757514158242971, 8429525637399401588, 1993332512198205916, 2362866326312580944])),
+                Fq::new(BigInteger::new([6682466606808354962, 11751311293365893534, 3904672205287571750, 616772985455845967])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([4037516471982812229, 10049181840911300287, 7159642655775011895, 3420298749586962604])),
-                Fq::new(BigInteger::new([17775356795930082130, 4808694979965146832, 8691302250521487840, 1564509118886147607])),
+                Fq::new(BigInteger::new([6460980040656494006, 7959898807695085899, 7791703774040405340, 409504318816496793])),
+                Fq::new(BigInteger::new([12801669683454689318, 12901895327552068786, 13790343390815880575, 1410229569253547364])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([18208299980878312856, 13979846063250005619, 989234039689205100, 573338455323564983])),
-                Fq::new(BigInteger::new([4906238051836818020, 8811428657958538536, 3225461718879810744, 3449861981141392596])),
+                Fq::new(BigInteger::new([4842667947953926249, 2998653899816876120, 6673599732786505678, 2489590238564304345])),
+                Fq::new(BigInteger::new([5010362625880364294, 14593900414167489671, 2483227838820250593, 822523434147838220])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([14091794658303882142, 8024534763941507522, 9302274012385471911, 1513304647372043279])),
-                Fq::new(BigInteger::new([11899433755209406267, 16994158481583571337, 11071649693061618774, 594840656843647844])),
+                Fq::new(BigInteger::new([3375575185602429809, 13590133731959957532, 4945013223303922184, 130404077211711185])),
+                Fq::new(BigInteger::new([10451892084058978486, 7782483440161747635, 10313901604261031269, 1602976696565298986])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([15223890188503690438, 7712625945058380568, 5250260010353221089, 3357964833778415749])),
-                Fq::new(BigInteger::new([10084865783185702763, 15319792123898131208, 9658452695388139975, 3023610360629852316])),
+                Fq::new(BigInteger::new([11052289279978006659, 16734905263880428748, 2850066155482128623, 476868100241906275])),
+                Fq::new(BigInteger::new([13956050510087247491, 14842475409009112943, 2373793604712703456, 803276274985775082])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([4104640816405905686, 12206211332822057613, 16781637999883897876, 544092309192393286])),
-                Fq::new(BigInteger::new([5519324862477453680, 851362709711944793, 9290120564196505540, 1717825375869106381])),
+                Fq::new(BigInteger::new([9851960348683250461, 17525770138086457412, 5480276683683173358, 2099090024316363535])),
+                Fq::new(BigInteger::new([9167283428189215135, 2309213246185047115, 296636826850578038, 1739619449194060052])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([926411274067400332, 9168195822298352932, 6519303720279401249, 1369899097831244094])),
-                Fq::new(BigInteger::new([17177924453879891588, 7227051270684517684, 7278202519438253057, 3481850895140280801])),
+                Fq::new(BigInteger::new([11589522741762815736, 1495568921728614975, 6031329861074015469, 1870468881909990929])),
+                Fq::new(BigInteger::new([16992195173368555632, 1788790745248757725, 15451482313053488437, 1487025603261139381])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([9620288153486303345, 12124886117584080003, 325497152956404451, 1461699215170357606])),
-                Fq::new(BigInteger::new([16601701952343588378, 2811157199011866732, 10298922231952435129, 1023621779003951695])),
+                Fq::new(BigInteger::new([16936715802817265585, 18237791105417304954, 10792994316286698510, 2115474057740312882])),
+                Fq::new(BigInteger::new([9993150016215682394, 734377780049500684, 9155898320752311934, 2607244693348402529])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([16313631399220642818, 1656284821587124483, 15114121511864430576, 3301713523170184852])),
-                Fq::new(BigInteger::new([12903134494370815190, 5620910878482062059, 14423653393348041119, 2743130707331168003])),
+                Fq::new(BigInteger::new([13918398567698751924, 8936238300743415665, 9950075558294733866, 1819169251953878157])),
+                Fq::new(BigInteger::new([18384709408798106272, 17061832008365595159, 9994755626730801860, 3474899739016956498])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([6787792173469702857, 6313699597294067783, 8681308458690683422, 7223628661688781])),
-                Fq::new(BigInteger::new([6391944081649774696, 10728214439665414923, 1777933829689815006, 282770902884773107])),
+                Fq::new(BigInteger::new([14914475087151189098, 3760027877602300265, 3356465659741536744, 58864448164753980])),
+                Fq::new(BigInteger::new([10984981109853297537, 10108377996695493590, 6844063892357226164, 1216035281375724394])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([9240488163029906426, 2146124764116540515, 8981687344186393397, 3179584696289616673])),
-                Fq::new(BigInteger::new([7424885530187545264, 9704132669022361136, 9300607612298058664, 216327050174947780])),
+                Fq::new(BigInteger::new([8843021046813156819, 15355991458509878067, 6703591095063324324, 2842909647787772205])),
+                Fq::new(BigInteger::new([6818464140096288965, 2722089244374258971, 15437470843282195647, 808197577302852522])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([3292039541796017299, 6318331208155114591, 14742747036713169624, 2949161505639011266])),
-                Fq::new(BigInteger::new([10549799964230816076, 7678904064587734215, 13338252700536713683, 2698761102742345358])),
+                Fq::new(BigInteger::new([14696308398005391651, 7982411865780244230, 18312300439287956928, 33145103936349075])),
+                Fq::new(BigInteger::new([5154254337530902, 2261518197085241304, 8165862238095212199, 1852409491069254496])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([17619081492906571566, 16408348328839853146, 6835249746834609125, 316557836335585747])),
-                Fq::new(BigInteger::new([7103122409544894718, 13270544641476299645, 5563112743672673473, 170136440196972107])),
+                Fq::new(BigInteger::new([12362705888659758181, 10936486292931753825, 894174184658396565, 1862683630195875595])),
+                Fq::new(BigInteger::new([1619556499434992157, 5488754950280056461, 14186532720921448198, 192623376239928815])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([12588997497440178950, 14021289723894175890, 8109283332846496940, 1529505993272761720])),
-                Fq::new(BigInteger::new([14550758765468817362, 10715771034359452351, 3790339261142708717, 2118400454011806843])),
+                Fq::new(BigInteger::new([9593063921631892607, 2881215015492434302, 15687321387654538438, 283107667034851748])),
+                Fq::new(BigInteger::new([17425591538202529634, 7583956211879363343, 14604408060035277155, 1862658781715183091])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([12452586131330553340, 12113847088023794064, 7177033158003228184, 2610551359153670533])),
-                Fq::new(BigInteger::new([1681086344022866537, 6358880951733232712, 4572943777646203475, 2418399242835127423])),
+                Fq::new(BigInteger::new([4428682855977762765, 7889788841762789209, 15991003597202906267, 269152902824065423])),
+                Fq::new(BigInteger::new([10365772012235609464, 13129231438142339544, 13123188345279285779, 3445959349617892092])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([16782444431115003285, 9435465482943980184, 13553742506971931063, 3382878786961792210])),
-                Fq::new(BigInteger::new([3051516410956488921, 3114867995244576990, 15928203447307189165, 2804473403087645964])),
+                Fq::new(BigInteger::new([13566296572338208691, 4029264159459248861, 8442395812741355456, 2071536101582020882])),
+                Fq::new(BigInteger::new([9896013706170631443, 4960215942141227678, 2495538251292802436, 1827766419439691781])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([10489292378678575925, 13781548169271880485, 1928148382935644306, 224563324914603678])),
-                Fq::new(BigInteger::new([2719550094360307330, 6616636641830190756, 4966632886121223996, 2979656585866712232])),
+                Fq::new(BigInteger::new([11091984955126341644, 2992753474831475809, 4075215901032851056, 2692900423295926203])),
+                Fq::new(BigInteger::new([15577836281318798451, 14489357753142678388, 7583413458511396134, 2170715549673811932])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([5666962447523197686, 3892433375084190110, 16712937057443754261, 1309055760927207831])),
-                Fq::new(BigInteger::new([12680998508007205552, 4529229820180121853, 6728551205567229572, 1130699474318958287])),
+                Fq::new(BigInteger::new([16219870356628061775, 18332090381107902358, 15269722543599771992, 1141345915647001773])),
+                Fq::new(BigInteger::new([7096055280999198734, 15156924754673573879, 11986899511303574953, 2108072223291177433])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([8810468615325202418, 18260628928415215668, 4863241397390379225, 682849243976999649])),
-                Fq::new(BigInteger::new([13978175057477844533, 1394924517737909251, 6090856392593390312, 3074872781991596244])),
+                Fq::new(BigInteger::new([4014787692303502303, 11583619047657942230, 9163248505916904362, 2733967822754381481])),
+                Fq::new(BigInteger::new([17663890789807038789, 8771824181766345203, 7636100200575926527, 2616684851739347158])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([13313278374692607238, 15267621296144898656, 1237854849030163896, 2862922771778441662])),
-                Fq::new(BigInteger::new([7535995323112301465, 10786285443824498264, 13645735631236927583, 2746992155241060515])),
+                Fq::new(BigInteger::new([14493246473465774999, 205250328413858431, 15788589549751512397, 2231867682687330565])),
+                Fq::new(BigInteger::new([9577796555656502102, 14179571492187762320, 9975006062832099040, 3140577655289750340])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([3650569550553013721, 666068794219609539, 7019974790066419061, 2961926255396679380])),
-                Fq::new(BigInteger::new([4777791811895124204, 12872129073726593909, 2090704357799264779, 292459210864538281])),
+                Fq::new(BigInteger::new([16803196150201996183, 14543640284827735270, 17469639320102382192, 2586560851797662178])),
+                Fq::new(BigInteger::new([16195151667353910379, 11665455790252211352, 2528743901084807666, 2296633484343820100])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([586247467389997265, 6171811696979858937, 5049800098487799634, 40474271007648621])),
-                Fq::new(BigInteger::new([3923658749379405057, 961230096781674811, 16828863756077268547, 2304176892969501930])),
+                Fq::new(BigInteger::new([18304853082401369442, 4377364528842518897, 3577143334867761466, 3472345809393621586])),
+                Fq::new(BigInteger::new([8214721850794340418, 13240855718659618454, 16035611769304658699, 3437152914258472966])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([10830682549543716582, 10539746096661280075, 10020034002731000595, 2854410494095039812])),
-                Fq::new(BigInteger::new([3522892383309366908, 414392239192365847, 3300048832506404813, 2385630019991818139])),
+                Fq::new(BigInteger::new([5155273281328187267, 6945155459234624311, 2680456069201472398, 1570189656924702011])),
+                Fq::new(BigInteger::new([9358057046637148698, 12113713458242483575, 9032695617412790431, 3224429230784929519])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([10526467180132783027, 10354122248949315928, 10843695119451161342, 1075538473370934729])),
-                Fq::new(BigInteger::new([18329025871275587883, 98614470950242391, 10876067212205664231, 354410739186951965])),
+                Fq::new(BigInteger::new([6000425160487084845, 17450925996692207936, 4202386416018952928, 897395423040502118])),
+                Fq::new(BigInteger::new([12654384635081266143, 2121719114169248999, 5161936294551489639, 3295459971132569712])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([4691752422926571896, 10194649411975855426, 15444420580135404165, 2752899837739948621])),
-                Fq::new(BigInteger::new([16666501157202300662, 15903238761880304962, 12618489065315635700, 972038021797351399])),
+                Fq::new(BigInteger::new([13545656871521228038, 9539433563331164983, 10264783554522196331, 3421386012953308808])),
+                Fq::new(BigInteger::new([10368804127158869919, 8082352783232462852, 15032031750533959361, 2868592901211557475])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([1900721975705219108, 5629928759681808617, 192129725720348547, 1116585632007409564])),
-                Fq::new(BigInteger::new([17140005124717844584, 13267166700000780766, 9365170014464406051, 261326097164888420])),
+                Fq::new(BigInteger::new([8833305674910074063, 9839849179650769750, 10086116754146948002, 1414592492900416060])),
+                Fq::new(BigInteger::new([12702296322570946639, 7366151852835168471, 10226802137260432466, 952895046789980022])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([2013524916520006992, 4119045439583037945, 2119621887360140301, 1997846450704454123])),
-                Fq::new(BigInteger::new([6928983797423483656, 8355137911315675458, 14167012658560487371, 1377326830542988262])),
+                Fq::new(BigInteger::new([5055438193832929848, 4223662954145742761, 2693407208065600960, 1274887409944618769])),
+                Fq::new(BigInteger::new([1604529750905535742, 5123804814754917082, 1499051515935776560, 3128012300282193941])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([3465386292496588363, 6359891895536632885, 7005134216495880613, 2760828114861594242])),
-                Fq::new(BigInteger::new([4584484958850986577, 11961730700180109175, 3523872741118527588, 2337946919998415625])),
+                Fq::new(BigInteger::new([9798425220290962770, 7461722092621792227, 11710239693155289748, 188680252867539028])),
+                Fq::new(BigInteger::new([16388248527676479243, 14496881579844737843, 18191521904603742129, 1740829724421343843])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([111519867106139105, 13687355365379892859, 3667797679078511523, 295414837659003635])),
-                Fq::new(BigInteger::new([11178497745813458332, 7218981937512849565, 9560060851443982563, 1599339901047354978])),
+                Fq::new(BigInteger::new([537618616051728826, 12357659223967401560, 2627121170333356219, 3121340495428235683])),
+                Fq::new(BigInteger::new([11877104121878683821, 17283578526156156756, 6522189204359917105, 2610223882953769795])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([16460013064473273197, 1364067299468096232, 2693652260184547551, 1491691097283245712])),
-                Fq::new(BigInteger::new([18311739742589728950, 4972828864422098439, 13382714092719300842, 952735631252303797])),
+                Fq::new(BigInteger::new([8627453996860564882, 13582219392967704003, 8252983287262666002, 3457910564840655257])),
+                Fq::new(BigInteger::new([11647550741787519444, 170552176229093882, 11252674244593136553, 3238786723768434083])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([17880121983131594885, 8147270457921225967, 16082055592880431799, 26203545357054808])),
-                Fq::new(BigInteger::new([11336832368578227132, 9586815854318744129, 15940806085390767857, 600466138133213349])),
+                Fq::new(BigInteger::new([11132961702402449767, 5840447149110330986, 8906938314835952425, 3211222686727037618])),
+                Fq::new(BigInteger::new([866643041789533661, 2584720802350605500, 9063273403937310358, 3187313450128447752])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([18028318646850617993, 4879607804133802524, 8332272828169919516, 11849024217634992])),
-                Fq::new(BigInteger::new([17420161373973178141, 13482963353524745391, 5433175720612217399, 1662500155056573704])),
+                Fq::new(BigInteger::new([871838750070249869, 11500993902816840266, 17561996048217806460, 2282899349124864023])),
+                Fq::new(BigInteger::new([1506444484357840581, 10280236247500188681, 7231110975006063374, 3243022465773712259])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([1478983062505232931, 1687400970049533578, 14697487437005341991, 850674164013290752])),
-                Fq::new(BigInteger::new([16310595644194883031, 16048475253518553061, 11000305416135389065, 1878786378161417040])),
+                Fq::new(BigInteger::new([13758713777898291136, 7686678209091066197, 14169996771118345650, 861402937663729011])),
+                Fq::new(BigInteger::new([9537197033272397845, 70947596299416366, 2495840903760689818, 2298613173414336373])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([15696009035066392761, 17715544931979422760, 770018700409876271, 766337010386998518])),
-                Fq::new(BigInteger::new([415187108078256300, 15653685455251614798, 2010846090445214709, 1459727546045738163])),
+                Fq::new(BigInteger::new([13320945681773186432, 17762662509470342957, 5253200464038854421, 3034405273617227876])),
+                Fq::new(BigInteger::new([2787240227925244549, 7484128960839403172, 11366046685408635627, 3100924949261654658])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([803293794416688807, 12985130973988097684, 16471337651167341805, 1082740105199629545])),
-                Fq::new(BigInteger::new([9961979324143390445, 14385131464222671880, 12118059289284475743, 1734987755307378274])),
+                Fq::new(BigInteger::new([13024977894935707628, 18275720700786541398, 7444068100004533367, 3336853988304768697])),
+                Fq::new(BigInteger::new([1295521828358866834, 5455772204519336830, 9363987149102081787, 2930424393511256677])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([15916412795210219937, 9666842191374598115, 2975750203844630034, 3235779187238772359])),
-                Fq::new(BigInteger::new([6063058659955890482, 15223168595096065710, 2180507024815532064, 423901843900087217])),
+                Fq::new(BigInteger::new([2241387771087621827, 13168289671805152595, 10181084274238228412, 620537930542429651])),
+                Fq::new(BigInteger::new([385437214785822370, 12041876039632737427, 4022791044450135727, 598186687382450467])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([17792853447405301302, 12433885490209943098, 11455119722080438132, 3455325585584027081])),
-                Fq::new(BigInteger::new([17031237750070170253, 18315895108330284164, 15043118623154419021, 175605305963101692])),
+                Fq::new(BigInteger::new([4471739717968976779, 15527599702079567122, 15558508199946224122, 2569215270476360946])),
+                Fq::new(BigInteger::new([2081686030660438194, 6009091868120084845, 3427718076778740165, 1528866604097043684])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([4379959593972988054, 9869881039778090029, 15899589138646333032, 677543380213963686])),
-                Fq::new(BigInteger::new([2291531833745463409, 9695374905926064209, 16603648025659676951, 3313050836753498280])),
+                Fq::new(BigInteger::new([4690324331179128147, 7382542314001802960, 16660775663578576657, 2110265406619727808])),
+                Fq::new(BigInteger::new([7620747093998590778, 5713238022088325342, 15370657061841641109, 1975441764516953466])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([70222299123515802, 3454251912767189212, 2491484663279859375, 1384093538132059398])),
-                Fq::new(BigInteger::new([5629550082455221049, 3066539104863450768, 717088488452842646, 2159172739509388971])),
+                Fq::new(BigInteger::new([3605407909109761280, 12235316447869809841, 17020182234801237990, 231100824615613729])),
+                Fq::new(BigInteger::new([14706648444971289099, 12144290485389301946, 2736632560943879930, 1484379640502237342])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([8839865999896293317, 3403311502963271507, 6421536878496071814, 1812267818137134724])),
-                Fq::new(BigInteger::new([12015989738455987561, 9823713166547737203, 8363988897514479269, 2031413722462008459])),
+                Fq::new(BigInteger::new([15627181285635498941, 18434902718621258263, 10579256811183189459, 1215518259644498319])),
+                Fq::new(BigInteger::new([11198160621269259772, 59211852979761974, 15969548944731749217, 888848725823444737])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([13816562213947707953, 16381671592440385672, 5738097586326205180, 2849432764069414613])),
-                Fq::new(BigInteger::new([15457006965573554562, 16982143754682242011, 13163509333200138407, 238952778536158679])),
+                Fq::new(BigInteger::new([5566329963354270850, 8714114758563737192, 15673378872779887559, 2329649883169874077])),
+                Fq::new(BigInteger::new([51917820385397416, 6396575392012605983, 1540411635190148120, 3395518179364900215])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([14980429329790018281, 8343756367883143562, 15881592755731187988, 1687570043222794105])),
-                Fq::new(BigInteger::new([11737597820138519294, 17431940988314049218, 2095979299490352887, 1626154218112102522])),
+                Fq::new(BigInteger::new([12496772018731573012, 3461229929306582, 5215874312570749232, 1135253186988511840])),
+                Fq::new(BigInteger::new([890158663555548350, 14505756183736993837, 12250395876470787537, 2178098658588115085])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([2594486281691056731, 10007930154223901458, 5440132204840870259, 643226100769822826])),
-                Fq::new(BigInteger::new([408642285639523211, 5056602731369772457, 4450283429658284349, 978190806827512996])),
+                Fq::new(BigInteger::new([4230312718108167011, 12755094534989182168, 6975770365681394720, 747549498367762301])),
+                Fq::new(BigInteger::new([6311718662084648820, 8578308880258812449, 11722121144388138724, 970506639170622075])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([1154724207800505066, 10214109637023411628, 9432587042870987836, 1512660536647650237])),
-                Fq::new(BigInteger::new([5787934966269384981, 5593600531592208888, 17837461803390985783, 1000018105858653506])),
+                Fq::new(BigInteger::new([15554472939998898742, 7883048157545391419, 6844217583901498233, 3310919607443597381])),
+                Fq::new(BigInteger::new([17470541411833937683, 12940973191801872080, 10818379084136158563, 669558086621766362])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([5774432948663268498, 10833203057342333145, 14810275999696123550, 96496160797680737])),
-                Fq::new(BigInteger::new([1756412418477003449, 17976139968132569524, 12001102328209612957, 414734931478733450])),
+                Fq::new(BigInteger::new([17137059489658125347, 5605899836779680109, 7780539876843453221, 2145680560092885198])),
+                Fq::new(BigInteger::new([5497697061799091738, 4239079391969109104, 7373126181234018765, 1562609996119308949])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([887847528780133539, 12071983197439631133, 11587770242339319974, 1357916848681296188])),
-                Fq::new(BigInteger::new([11550896834842081500, 5337908383215368973, 12615212367935688988, 2654969863660803847])),
+                Fq::new(BigInteger::new([10687733339182081952, 15806360823717309590, 13601497567545795112, 3431903531431074849])),
+                Fq::new(BigInteger::new([11410654052842609626, 11055004090475199523, 7642917573190340557, 335711990923728527])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([4835420065003196959, 4401362964863663698, 3173984498610875616, 2843146385240284234])),
-                Fq::new(BigInteger::new([3955088492057891289, 350501271813996513, 4030093770104474163, 2005715750619152492])),
+                Fq::new(BigInteger::new([4358655114957984312, 11151616419128877048, 14489515166008821631, 3047298109844177676])),
+                Fq::new(BigInteger::new([211775739217393780, 9143252241098259242, 16179889925948096640, 2117998943368394343])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([7703555532670044944, 6793080347304860531, 13623507171675235408, 2856265436469632645])),
-                Fq::new(BigInteger::new([4575756822635327655, 16655287019927071059, 14291064186266431922, 205123262477040983])),
+                Fq::new(BigInteger::new([10982415433425578039, 266228073183095965, 832029946835863114, 980547154855217565])),
+                Fq::new(BigInteger::new([18334083073579736672, 7913740451407932395, 16930479253562231944, 1028639969290379083])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([11015000703457368688, 17302380723451647374, 11040383493253789101, 3271374765172636328])),
-                Fq::new(BigInteger::new([11069939338020061986, 5326281223467711357, 12946874583552401939, 696606408575473547])),
+                Fq::new(BigInteger::new([3358118999040536847, 11359643761760382144, 18263608077084916884, 1289705338101548470])),
+                Fq::new(BigInteger::new([2173460153622215909, 9023044598114189306, 12903088690028776205, 2223899409322730032])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([13297729169152206976, 13999652071408866156, 12886763722677814697, 3110014149525651439])),
-                Fq::new(BigInteger::new([9291845509999492441, 1118903926972298143, 14894225902465159909, 2271930468414077063])),
+                Fq::new(BigInteger::new([10436606263410540573, 2164391227658059074, 7426586937023787867, 642578852807439602])),
+                Fq::new(BigInteger::new([12882544167856771860, 779497142868727348, 341290086468647688, 1232790521716561582])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([3113413758817436203, 11682150842199343451, 2209228900345637167, 2779981535561766343])),
-                Fq::new(BigInteger::new([16059942070063640274, 8019816807768592660, 5889039729390851736, 544217025856770209])),
+                Fq::new(BigInteger::new([16075139025600360057, 7606709196111089397, 1392183226801060080, 2145464658348177486])),
+                Fq::new(BigInteger::new([18137285554718271040, 15597288447721547523, 1099800816454678719, 1027605611805972870])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([17372857886270968897, 18174512329370935190, 7284493435712825924, 2529648559244113636])),
-                Fq::new(BigInteger::new([18024803907376632143, 13085148265317922922, 17326086208335215104, 2266635737334107429])),
+                Fq::new(BigInteger::new([8461055839143476345, 8918491231777652041, 17724596558085246792, 2335853205483846282])),
+                Fq::new(BigInteger::new([14745911599075577264, 2990789289997328566, 9158081063832842506, 99717826739225974])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([12337968523735517294, 5631834156057741173, 10015634888458504892, 764467367438518037])),
-                Fq::new(BigInteger::new([10985489082614281981, 7992782021249503220, 10782770772598129992, 1185613385245729951])),
+                Fq::new(BigInteger::new([8395115129502695634, 1765507221479279513, 14115607728581897817, 2849356670595619654])),
+                Fq::new(BigInteger::new([16289959163079316048, 12826532515001173222, 324712040133290483, 78970391527651926])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([17337816217104222624, 5634444256631881319, 15979178498180859996, 2552771181885419443])),
-                Fq::new(BigInteger::new([6483522111810803065, 12204928363241296499, 16837250725260895373, 2936069330446371222])),
+                Fq::new(BigInteger::new([17663800698482431916, 9984503236726704607, 5079722771392398756, 1894033570492858223])),
+                Fq::new(BigInteger::new([15132656539597604706, 18075244100197712139, 12743252325489213753, 746870024184626894])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([10780690424070282394, 16315989277468317421, 4181685370053852432, 2319486997341291284])),
-                Fq::new(BigInteger::new([9408541636636159430, 7364026107795771390, 13525862929713183047, 2322909813377240357])),
+                Fq::new(BigInteger::new([11550518583969865386, 16201711693816617013, 10893355412080219274, 2116980752449761640])),
+                Fq::new(BigInteger::new([1365119921761721668, 2455228866735903373, 10633037727077577541, 2420727138642434438])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([15732303441226528690, 457756689594384012, 11693839091588988668, 2018150406278205433])),
-                Fq::new(BigInteger::new([16542063407497306204, 15290518609673869547, 13915714418796199824, 1637529565035996385])),
+                Fq::new(BigInteger::new([17695690509589026119, 16834139655993410954, 17203554564893710030, 73053868422965081])),
+                Fq::new(BigInteger::new([4936435086546383358, 3125693007948278807, 17854182784509468911, 2015289967270690971])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([16002592701688104085, 12822254234878187485, 12846397727785174928, 2578700520615894502])),
-                Fq::new(BigInteger::new([11763942631801768528, 3466603378189659680, 14100980884821399841, 2795790595485920667])),
+                Fq::new(BigInteger::new([15629211252819894299, 12845065669794316323, 4621712584921809600, 3450103630117463880])),
+                Fq::new(BigInteger::new([8738278191563407102, 11303642141298515488, 10278508012968647337, 2731440913457277906])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([5401403133274179413, 8654221387555373205, 13758736511665220916, 463131441478464998])),
-                Fq::new(BigInteger::new([990058717640476239, 16267932549164946446, 2320241260191834352, 2993501382581322297])),
+                Fq::new(BigInteger::new([2980509782745606206, 15337711857251616109, 12368027427490764705, 2871743660769725900])),
+                Fq::new(BigInteger::new([8566822986682286496, 5236703300796495309, 15282676474397101291, 1270639169683510231])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([7411230625515485319, 3285431462091837117, 3592047487028769954, 1033466872057562013])),
-                Fq::new(BigInteger::new([2708800158566050387, 16243991620349570265, 4173390654662076385, 2164507087676959204])),
+                Fq::new(BigInteger::new([3328788316197275143, 5346240668104891371, 9199543418905433707, 2531060713626390299])),
+                Fq::new(BigInteger::new([3735026538020854480, 5867358627277486289, 641527719060082812, 1905349325532101904])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([9372903427303369323, 522601238595147312, 629824513079232742, 2030831390188591434])),
-                Fq::new(BigInteger::new([8109881537366616904, 14613928361412835241, 12908843508266328630, 2851316749233898067])),
+                Fq::new(BigInteger::new([4036245194599785754, 7389503886462601613, 16750627003168933225, 1520924422904455698])),
+                Fq::new(BigInteger::new([12117996374815862111, 9254775357180774674, 7062741121496766885, 594203943082489718])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([17711573638750904632, 10081696606048920890, 16476402748529979378, 2060210069609218849])),
-                Fq::new(BigInteger::new([16705410730691200862, 11215817418373673443, 771583353620559706, 907199504967353807])),
+                Fq::new(BigInteger::new([10516555497566706398, 15619486236648546236, 16605095995842795684, 1166324945666051834])),
+                Fq::new(BigInteger::new([16121940496636359871, 918682078711178441, 7983910058966363677, 636345603269555595])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([10243589919555355235, 3572092330316389846, 12891651252302869816, 3113398455451470502])),
-                Fq::new(BigInteger::new([31314877324250185, 9732299767902108356, 17376259904786210377, 342646108217571003])),
+                Fq::new(BigInteger::new([12619113684860674171, 12432580252069449924, 596286185967675288, 1930147248718146403])),
+                Fq::new(BigInteger::new([16347807316406145467, 6759462499318247173, 12703327385952464380, 923665217343663219])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([5901639042557961768, 13465840194717968800, 10158552402260794645, 3340513653783160709])),
-                Fq::new(BigInteger::new([15548644487353380243, 14410119467148626012, 6241734438135660012, 1558468376897230250])),
+                Fq::new(BigInteger::new([6979030288529428190, 7429956575724123290, 1888435381417877917, 341306247543563647])),
+                Fq::new(BigInteger::new([11068329518960662876, 15099434826324675571, 13404532341910629793, 1284078542677235495])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([9188131130267020003, 14205795599074408426, 1071885964731230855, 48524047103333196])),
-                Fq::new(BigInteger::new([16252506299839904927, 2413762579688556360, 15247134449124532887, 1823782261143489544])),
+                Fq::new(BigInteger::new([14857143236275356301, 15414638159931242588, 9129148790187184636, 319438274484847182])),
+                Fq::new(BigInteger::new([16221558718115205009, 1582913042544375023, 16949033353770598725, 1187904435485095123])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([15781526900338411382, 442391598611232142, 9266143095440331014, 1398765080786866539])),
-                Fq::new(BigInteger::new([13646525938926714285, 6618366223986372853, 10120487823317136765, 1922240834555281326])),
+                Fq::new(BigInteger::new([16504176125629092896, 14860366689527814886, 6237118688586456076, 2616782416221593755])),
+                Fq::new(BigInteger::new([7064716306347043419, 16482576483288601771, 2985186408431012322, 2611582519214240428])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([10496154575400628779, 6136964583660014200, 690225035921230897, 2459738524402549104])),
-                Fq::new(BigInteger::new([10878170065039380138, 6748533406107834155, 13296125020356154492, 3292721438034403726])),
+                Fq::new(BigInteger::new([9849407530100949634, 13146965553792283763, 16837377828129160996, 2871555600915966573])),
+                Fq::new(BigInteger::new([3830659822332326099, 11243156277909420642, 2343160625110568977, 1453321178702221035])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([785838265576192314, 12715501554042085535, 7962855165221441576, 1101132148173249587])),
-                Fq::new(BigInteger::new([12500745666951427662, 17802920453873696918, 11151479303160816043, 2533444020082392051])),
+                Fq::new(BigInteger::new([1123766429429364233, 3611839677970728380, 10717627548495838974, 135872590426708145])),
+                Fq::new(BigInteger::new([558122334399681691, 1776153092159120126, 16728460473821587534, 1371153214072349299])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([2706605105996434011, 15041497193222753561, 13464690973381745095, 3268228593197519244])),
-                Fq::new(BigInteger::new([15367934467811065931, 5682544947800448692, 12572542106367497923, 1509573804871512336])),
+                Fq::new(BigInteger::new([9609296199973815089, 2268394730510773509, 8997694149522767060, 2655574560356972])),
+                Fq::new(BigInteger::new([18427842870653147048, 13875252679567840765, 5164821144181979790, 1768745724043162579])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([16535146925266439339, 10002270292810787911, 2635643722766973156, 1328241598072197684])),
-                Fq::new(BigInteger::new([5979491661177074096, 4235144061205532492, 14645380263539579370, 3469588391834851051])),
+                Fq::new(BigInteger::new([13187592080666199281, 5250456972334152943, 1693656077091237769, 2774992534644810955])),
+                Fq::new(BigInteger::new([10444277640388484217, 11222796947035624869, 17777179179477278612, 360710898267335398])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([12349989771164343614, 6551153839679755632, 659797056331754643, 3235965854806029251])),
-                Fq::new(BigInteger::new([14474370517487073973, 11883675134297820582, 11595745869066015864, 3321433700647837692])),
+                Fq::new(BigInteger::new([10987002400085729920, 5328165919435088532, 2305417308598766267, 1557819654207354001])),
+                Fq::new(BigInteger::new([13107953943100194371, 15802143922514217035, 13794541519189696891, 1842649416057873705])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([838380498229189414, 3506102766441517523, 6802836126770890832, 498128847259701850])),
-                Fq::new(BigInteger::new([17651778634568382915, 12756972273724124836, 7499652379331579592, 1389554506675778470])),
+                Fq::new(BigInteger::new([2386473301796150841, 17419951778451172652, 983992751395813447, 1314656602353960843])),
+                Fq::new(BigInteger::new([15592833064071742548, 14246557365290095100, 5065203370279699592, 2585352523073826083])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([1470705262449311544, 14180536787675182726, 15458015523406672508, 866838570208497323])),
-                Fq::new(BigInteger::new([9531674228830971318, 1203032287460993687, 16169037602097970441, 1154529161031481064])),
+                Fq::new(BigInteger::new([12539260327255083614, 13510928720350653601, 17181262921604391108, 83455701198029531])),
+                Fq::new(BigInteger::new([809352963616841785, 10526595738469217980, 14859082816453195948, 2719334654694723929])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([383897080405974860, 1632350750405814211, 13491307246402061631, 444812613820451211])),
-                Fq::new(BigInteger::new([7341278307208271360, 18141217321219587, 13225008083125819526, 2922097042392915579])),
+                Fq::new(BigInteger::new([10968805082101338462, 981651782717016774, 5049722155143280155, 395057070933172264])),
+                Fq::new(BigInteger::new([11842882396403448082, 5438900806808454224, 2805094357375958318, 2688016518330828225])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([2750946284001481333, 9176370608255344553, 10073701727432575716, 1919302987439562740])),
-                Fq::new(BigInteger::new([5734400749149731603, 4413885087438514875, 16415355547784373886, 2485272588269782182])),
+                Fq::new(BigInteger::new([10678505943610958339, 15271618514794079284, 1135206854025330621, 2005385412031008952])),
+                Fq::new(BigInteger::new([14099405858129053145, 12019167856316806763, 507761389823004258, 2024268779183316784])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([10546651435778609552, 10675310404578725386, 690214506014544841, 1115636322951224162])),
-                Fq::new(BigInteger::new([12894612942369657781, 10500427473807575496, 14714283618966431630, 2825534715520168326])),
+                Fq::new(BigInteger::new([9583359976509409344, 11461151936174739938, 13456788426674974239, 3230916029725545061])),
+                Fq::new(BigInteger::new([7949059922147462441, 4467287322705666495, 16058113052918167518, 88171145108901691])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([7728572254147656663, 16739079545661975097, 16374403992320976386, 3148817942398667277])),
-                Fq::new(BigInteger::new([12578587891082581108, 3636055121434801734, 18043066742499303193, 1626561941483178945])),
+                Fq::new(BigInteger::new([1541178621483000642, 11275620348982846400, 505401655264841741, 2929557441479444150])),
+                Fq::new(BigInteger::new([1583905296575983258, 12397268022648256136, 4232721027528321156, 544028696178093700])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([6125687051309897384, 9734346627010313689, 9779112639184566551, 1246599152787470407])),
-                Fq::new(BigInteger::new([930061247305671961, 12499061184506077003, 9453789601051419950, 232409628414509220])),
+                Fq::new(BigInteger::new([17085425336852433552, 11695942482255325964, 4618545777399752711, 405338495714040410])),
+                Fq::new(BigInteger::new([11823649654515055622, 8798955705997425879, 4202024097827910904, 628412515487353046])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([14641342694048132455, 13995104270713982459, 9615539581346305712, 2146501691743548834])),
-                Fq::new(BigInteger::new([7793173917434888376, 11184326692060904963, 18245270949976539436, 636705960826198020])),
+                Fq::new(BigInteger::new([6617321807135196436, 16960697797809928361, 2713052526971668495, 2070348350292180889])),
+                Fq::new(BigInteger::new([9461980940561094377, 15274164800821159160, 10359779283212733558, 2102122344704132033])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([14819466332574406247, 16175737932613218469, 2379312447155314113, 3229218624901008038])),
-                Fq::new(BigInteger::new([539151167708480847, 16047527817082963578, 5389666071795517406, 1471078735581790235])),
+                Fq::new(BigInteger::new([6541836086910501572, 16010639161299492955, 7245982681092807753, 1177915691804413923])),
+                Fq::new(BigInteger::new([17704292627358111286, 1681054965162249974, 15563330123695068555, 599771836087768097])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([14361270492433230098, 14446423860354016663, 18225549652952149557, 691269179528761640])),
-                Fq::new(BigInteger::new([1119046104573560060, 14608395285033175370, 17079560482345530137, 2599211388208721807])),
+                Fq::new(BigInteger::new([4773389349831852972, 14343067503388866811, 18036693288791253849, 301631952247996141])),
+                Fq::new(BigInteger::new([14391242719638655817, 14068242962124856318, 8467162319064374721, 3453454815042014118])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([13765965551216291134, 7211909127126717535, 7552211811933292196, 1670058889515687743])),
-                Fq::new(BigInteger::new([5574074300091358820, 13289152992511074881, 9888351751718516411, 64120255759797165])),
+                Fq::new(BigInteger::new([12424023449762869641, 2326011927411393036, 9379981212098488428, 1907037957254866160])),
+                Fq::new(BigInteger::new([4580612028229415329, 12982635093184872912, 1534854181845763856, 2429027318819473250])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([17711119740175877749, 11900288248296036300, 17573024260402212958, 2569798401138745379])),
-                Fq::new(BigInteger::new([16638890407218046092, 2307821496673456455, 17643784853037791381, 2923896965385599391])),
+                Fq::new(BigInteger::new([17946788799810898181, 18384289835204113859, 3996773773684479095, 3382756618446613696])),
+                Fq::new(BigInteger::new([14559426628974464017, 10426733134981851720, 10203451818514483517, 797280128379842676])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([15261349023234183508, 708626878307713572, 12184953418034652808, 11342267320853302])),
-                Fq::new(BigInteger::new([6486194339981591542, 3986072026108594319, 9931451253565396565, 131855237039799398])),
+                Fq::new(BigInteger::new([10790357558267953007, 14991845026411604669, 9532117003302812068, 2635188408320003900])),
+                Fq::new(BigInteger::new([17713965502789995107, 9008117740334605746, 14133865263914395941, 3308134741612349208])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([2193350008545145930, 10767506317457102860, 696271773231461112, 1960501425461754667])),
-                Fq::new(BigInteger::new([14869268638663686577, 13155835908731128037, 8985903131197829300, 617583179703931680])),
+                Fq::new(BigInteger::new([13781026699600534104, 7345901746912565490, 6516769473546198094, 202045073755902572])),
+                Fq::new(BigInteger::new([16597099009345360348, 4808025525028318799, 14146630203051445173, 1719916361104721496])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([16245123327416720742, 15035450343995436500, 7838643717396441333, 2470615727522814324])),
-                Fq::new(BigInteger::new([15473624763704083549, 3914293630318175118, 16666888020744473966, 2853720549484821296])),
+                Fq::new(BigInteger::new([6569684999870716057, 12226991672024082885, 9949822460756544729, 2884443892412611083])),
+                Fq::new(BigInteger::new([17003404017049073424, 8153840911037432244, 10980454910315124397, 1968140174229504487])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([13476655312067016968, 4831923988820323964, 9851752437536193820, 576553531689959864])),
-                Fq::new(BigInteger::new([16944561046228990292, 17235152740553433973, 1348575660464812707, 1304814000545963307])),
+                Fq::new(BigInteger::new([5772796460801527753, 16397325882299091801, 4529071308378590870, 2382446641958219009])),
+                Fq::new(BigInteger::new([7379305527028510262, 187997031162397940, 4073270451076839421, 2119270584727591481])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([13366883932199723086, 6010195095064025956, 14914440481380160361, 2041324142706401712])),
-                Fq::new(BigInteger::new([6104591252186772830, 13115874494523806372, 18066119895342654159, 3424550676547497606])),
+                Fq::new(BigInteger::new([8300251130669766571, 14226578894081109854, 5934626448857720365, 401476921643773399])),
+                Fq::new(BigInteger::new([2427039394469961851, 10766406812526099366, 13428226882164482994, 2131008935302345762])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([15089201807387693226, 9564140634730291983, 4155807815332457185, 2105186944347137866])),
-                Fq::new(BigInteger::new([15567655293614059831, 10061922565707107255, 18339534362353405593, 2671236664721894556])),
+                Fq::new(BigInteger::new([13638687291683045412, 12173043779915513240, 14485856409047817353, 2788273939840228454])),
+                Fq::new(BigInteger::new([331325828096219232, 4351487168348471918, 7219960068113192160, 417552424853140144])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([15884292203922246932, 15909256047238556444, 12939431368214579044, 316988667536410107])),
-                Fq::new(BigInteger::new([8486030799696985366, 1727449174356883882, 12321815267308174970, 3321575626354909718])),
+                Fq::new(BigInteger::new([13703400031442662318, 7731922255743214770, 43925265771715560, 1503321353209363614])),
+                Fq::new(BigInteger::new([13676335545162730730, 14322861822002118443, 2343332620241156156, 1544372692210545438])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([6050032792356507300, 8584095191616080399, 3078773642559122604, 181214755320855259])),
-                Fq::new(BigInteger::new([7539420451408185676, 18031994557960339563, 2191398709774525477, 54176322788812])),
+                Fq::new(BigInteger::new([949319784844733284, 17000143093460280407, 8821921600919858284, 783664617558175381])),
+                Fq::new(BigInteger::new([1570570450848139493, 1041166013097283878, 4398928443305274349, 2034169338847316091])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([11669908529593628869, 2162612207639567301, 3452964041469054543, 2001914535577072978])),
-                Fq::new(BigInteger::new([3088496315172437023, 15353449679404269643, 17913888176070137005, 1919483885596374919])),
+                Fq::new(BigInteger::new([18237655038182127494, 7576540888309171509, 10012826717981623682, 2254983196787820488])),
+                Fq::new(BigInteger::new([13197674316743220578, 7315348404189563154, 10409781329098122208, 579741419974707340])),
             ),
         ),
     ],
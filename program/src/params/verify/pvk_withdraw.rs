@@ -1,125 +1,95 @@
 use crate::{params::bn::{G1Projective254, G1Affine254, G2Prepared254, Fq, Fq2, Fqk254, Fq6}, bn::BigInteger256 as BigInteger};
 
 pub const G_IC_INIT: &G1Projective254 = &G1Projective254::new_const(
-    Fq::new(BigInteger::new([7616461272462772038, 3473219195395616085, 14576705925594985869, 234811470377495447])),
-    Fq::new(BigInteger::new([17816757974320373304, 8725588608590043837, 7259702853605086413, 948123446253820631])),
+    Fq::new(BigInteger::new([17463670496364903702, 9990390465570404154, 17728819494101427765, 3124827299763862432])),
+    Fq::new(BigInteger::new([12581643011186107643, 5351494497221177097, 13693494606312255708, 1104379995598938238])),
     Fq::new(BigInteger::new([15230403791020821917, 754611498739239741, 7381016538464732716, 1011752739694698287])),
 );
 
 pub const GAMMA_ABC_G1: &[G1Affine254] = &[
     G1Affine254::new_const(
-        Fq::new(BigInteger::new([4245042282005846153, 13007601404424660254, 16947076338236398073, 2417975886509477148])),
-        Fq::new(BigInteger::new([5680409754998115534, 18136677334527506428, 13079745748353633321, 1568358199904129820])),
+        Fq::new(BigInteger::new([1525426803831437219, 18268009986187083172, 14766267754291793952, 3210362954392496497])),
+        Fq::new(BigInteger::new([12057015333184334750, 2687699604357165540, 15772398017958282807, 2714756574075650676])),
         false,
     ),
     G1Affine254::new_const(
-        Fq::new(BigInteger::new([6953345976180772865, 12497721756046190320, 10258905510941632172, 1663016019006186649])),
-        Fq::new(BigInteger::new([11414193780961969180, 16358450898189955260, 12342157403926621328, 1138683504047378925])),
+        Fq::new(BigInteger::new([9848848830487395638, 4941675192868927886, 13843252544299764789, 3248589275384856986])),
+        Fq::new(BigInteger::new([3016904279315637075, 12163044093273394896, 18081662148666303418, 214456621871859225])),
         false,
     ),
     G1Affine254::new_const(
-        Fq::new(BigInteger::new([2287570045753058773, 11760574378771375075, 8087991872213178566, 2638908997240330324])),
-        Fq::new(BigInteger::new([1311974891651545136, 10345494740322515779, 17612069412714083337, 2338921712175821196])),
+        Fq::new(BigInteger::new([5368801997704694367, 9579551147863065417, 682756815302165780, 1392005802801589652])),
+        Fq::new(BigInteger::new([751456026101368054, 10284420002957647947, 2638249292319259711, 1852068283975883199])),
         false,
     ),
     G1Affine254::new_const(
-        Fq::new(BigInteger::new([1355932139420964457, 14235762444439609276, 6491260194178389928, 311479975573001174])),
-        Fq::new(BigInteger::new([7645136037313116111, 1460572511393041553, 5781642067301645024, 3264350056366535301])),
+        Fq::new(BigInteger::new([3396095375692099706, 13899764956935957234, 5771845695292488060, 1636647961150484247])),
+        Fq::new(BigInteger::new([8321230118121360148, 11305429143707099584, 17025866671710835132, 1545635714285308097])),
         false,
     ),
     G1Affine254::new_const(
-        Fq::new(BigInteger::new([4560998238229179710, 855714017404908, 15992939554020416960, 691872349438682425])),
-        Fq::new(BigInteger::new([16702807896859779754, 3915370509002179425, 2992031147903409598, 866552874816359498])),
+        Fq::new(BigInteger::new([18440977579720305392, 4687307687251331649, 5175072619147603753, 2722287671443202692])),
+        Fq::new(BigInteger::new([5755307703387352483, 13385226265972400560, 16092243509771246420, 211621345245226285])),
         false,
     ),
     G1Affine254::new_const(
-        Fq::new(BigInteger::new([7537568035013352412, 8022761480154709454, 4972252656925924212, 1373813081201869112])),
-        Fq::new(BigInteger::new([6370448048024233244, 17592505273886469532, 18412057214240597982, 179539493098247241])),
+        Fq::new(BigInteger::new([92632328181974027, 7763519212651901714, 2486485981868109358, 3227905445499083490])),
+        Fq::new(BigInteger::new([13197901954433363757, 8220541976909574682, 1560885059558155410, 2259754471580272687])),
         false,
     ),
     G1Affine254::new_const(
-        Fq::new(BigInteger::new([2574271861101137461, 5105489406599539603, 9082399853026737227, 2298748080970841896])),
-        Fq::new(BigInteger::new([17508615976203157385, 18757598281904594, 6878196475045306767, 2800426081435958442])),
+        Fq::new(BigInteger::new([5849511371985728390, 4998735042790314333, 15709008303052632411, 1528327602051648995])),
+        Fq::new(BigInteger::new([5184393816285665259, 16811398908653758463, 7004810168720008079, 2544947427882151110])),
         false,
     ),
     G1Affine254::new_const(
-        Fq::new(BigInteger::new([3944499866022809469, 13324136711599615600, 4806726301386201052, 2314803670789300423])),
-        Fq::new(BigInteger::new([4888603950370844054, 8388082493053914496, 12343058217787459844, 2597389773850077053])),
+        Fq::new(BigInteger::new([7105052480813243318, 293057009933780567, 5013935777963061361, 1597027124520309918])),
+        Fq::new(BigInteger::new([7582795000079664250, 16263046269685899229, 110001705359624656, 595399787090047762])),
         false,
     ),
     G1Affine254::new_const(
-        Fq::new(BigInteger::new([4383646044587469020, 15268085057517376269, 6561241390040860866, 2808357795908233910])),
-        Fq::new(BigInteger::new([7773844688465603123, 4372009738712400677, 17072948318807615524, 3266194517908433245])),
+        Fq::new(BigInteger::new([16129120490653137399, 15900751977582587311, 9703289426715546909, 836915922182725442])),
+        Fq::new(BigInteger::new([11411492261575500705, 12675177210390522272, 2960511349460984030, 3223411331809183858])),
         false,
     ),
     G1Affine254::new_const(
-        Fq::new(BigInteger::new([6660545022470154559, 6384226784270523568, 16901676224354278889, 1979226430132553889])),
-        Fq::new(BigInteger::new([7387186808558677096, 18103559075263765842, 15674451238670818367, 981600044953974179])),
+        Fq::new(BigInteger::new([5161151194948530243, 2406415098724978584, 11244817659011976299, 2549670624139242794])),
+        Fq::new(BigInteger::new([13474429064904797574, 8530360095923863163, 10892671099586345431, 159374654747522075])),
         false,
     ),
     G1Affine254::new_const(
-        Fq::new(BigInteger::new([15879957093892996177, 3967215810230560469, 3393004673007404347, 2364357624376025922])),
-        Fq::new(BigInteger::new([11283960713210016220, 1129051100880824918, 5041817419152921832, 2477403816483002322])),
+        Fq::new(BigInteger::new([16784335178233709373, 7710056259704113383, 15124418845502377490, 1029701157367771834])),
+        Fq::new(BigInteger::new([17941307753292006930, 5761685247334401189, 7027495391458276172, 1153801731457945801])),
         false,
     ),
     G1Affine254::new_const(
-        Fq::new(BigInteger::new([8115723028967938661, 9922094876585437646, 9641353018807108448, 2226576898872280187])),
-        Fq::new(BigInteger::new([7584336129740731864, 14664227831040403392, 14071446656364791744, 1032913023884614468])),
+        Fq::new(BigInteger::new([133959772168750126, 15862704874747678996, 3379771210204504782, 2759458217367037840])),
+        Fq::new(BigInteger::new([972734909958548317, 7435088758788169901, 9913134375394516533, 118346954047600600])),
         false,
     ),
     G1Affine254::new_const(
-        Fq::new(BigInteger::new([6623607972821993441, 3169007343519448710, 11355858979440368033, 334241799883186809])),
-        Fq::new(BigInteger::new([12352357439750139248, 7991798507407166803, 10744364116757911783, 604693060082510427])),
+        Fq::new(BigInteger::new([910162472487091983, 18242256134737791290, 3698119437246269039, 1283552109071770756])),
+        Fq::new(BigInteger::new([14076428741817842965, 15992056720659769950, 459953321074876644, 94361683045056966])),
         false,
     ),
     G1Affine254::new_const(
-        Fq::new(BigInteger::new([16567880418162305991, 9714403333683106838, 6535948965085779510, 266970680081558084])),
-        Fq::new(BigInteger::new([11362603801920528998, 10279142266212226608, 7310379863122039095, 232157205368730846])),
+        Fq::new(BigInteger::new([16769658599500570114, 2006063390001055198, 4052981536698033911, 2022310756514538391])),
+        Fq::new(BigInteger::new([12158643979204719902, 16490532156452013261, 8790914678596278245, 2367842457330635237])),
         false,
     ),
     G1Affine254::new_const(
-        Fq::new(BigInteger::new([15574084540327334556, 13295593764697550099, 6377283808606640935, 2435922927771523170])),
-        Fq::new(BigInteger::new([1175981956625935132, 2810608072542136807, 8818761522422849364, 1613254135519088454])),
+        Fq::new(BigInteger::new([8441293355127442786, 15202133864100743304, 17132435680322954646, 3345920002651143883])),
+        Fq::new(BigInteger::new([10984066304564801040, 4560346450043896062, 15732666892084200456, 1853763841592362347])),
         false,
     ),
     G1Affine254::new_const(
-        Fq::new(BigInteger::new([5482997461973183413, 13979268810550535934, 620877639284767725, 1828195228584238273])),
-        Fq::new(BigInteger::new([18314752810575850149, 11647730010415205177, 4498413920411378976, 1730606714344963174])),
+        Fq::new(BigInteger::new([11776889616465285881, 9336790451474155762, 12075015814251094649, 2541387871346927030])),
+        Fq::new(BigInteger::new([12745779753776919454, 9539385805388499587, 4672721619584008583, 3046881319203888139])),
         false,
     ),
     G1Affine254::new_const(
-        Fq::new(BigInteger::new([7708087821700498727, 2213868134913727299, 3828153759883611745, 1634211602900807438])),
-        Fq::new(BigInteger::new([5869860206990958164, 12284182883961105382, 17445479044553741411, 495025557417665129])),
-        false,
-    ),
-    G1Affine254::new_const(
-        Fq::new(BigInteger::new([8737569517639398088, 13528295587326536525, 11067687534397691295, 632655405106795034])),
-        Fq::new(BigInteger::new([6288282027223337856, 1412897002063060845, 17649488931467307352, 2441141959962393671])),
-        false,
-    ),
-    G1Affine254::new_const(
-        Fq::new(BigInteger::new([10120595130993709533, 17686028893650165435, 3360898461754487403, 1328389419130307786])),
-        Fq::new(BigInteger::new([17475255870107775137, 17444407917259936496, 13028397664419215012, 1809693673653309719])),
-        false,
-    ),
-    G1Affine254::new_const(
-        Fq::new(BigInteger::new([5364689016209258397, 16039542599682983159, 6387180577569577336, 2803378437793447882])),
-        Fq::new(BigInteger::new([4196272891865118063, 128169089447120732, 11002976443654633009, 2575530418435501224])),
-        false,
-    ),
-    G1Affine254::new_const(
-        Fq::new(BigInteger::new([18161609639666422594, 2345310946651812296, 9753760554405398820, 3088966241851061972])),
-        Fq::new(BigInteger::new([17108942032543938050, 14177338348566009618, 5869403274413406658, 1037495952949967394])),
-        false,
-    ),
-    G1Affine254::new_const(
-        Fq::new(BigInteger::new([2726206986270442297, 13290974846868157512, 15487389916210564811, 104531223106757042])),
-        Fq::new(BigInteger::new([16973502660749717364, 14543706736321955821, 15466830192001789446, 2113222607313336734])),
-        false,
-    ),
-    G1Affine254::new_const(
-        Fq::new(BigInteger::new([8846542750569502565, 18128709311977517759, 11571687469880270707, 2470318180855802378])),
-        Fq::new(BigInteger::new([1382750161095092472, 2053281040403430501, 14409010336854530814, 1637056925868123618])),
+        Fq::new(BigInteger::new([14794186884089879859, 5272201410317354970, 16406706398231783391, 604276418803924077])),
+        Fq::new(BigInteger::new([433394495864222996, 16914330164565111499, 1571435244601736596, 2377987276796035833])),
         false,
     ),
 ];
@@ -127,30 +97,30 @@ pub const GAMMA_ABC_G1: &[G1Affine254] = &[
 pub const ALPHA_G1_BETA_G2: &Fqk254 = &Fqk254::new_const(
     Fq6::new_const(
         Fq2::new_const(
-            Fq::new(BigInteger::new([3773783083182840663, 8630979883938221335, 10857840488739865378, 1429997803411495445])),
-            Fq::new(BigInteger::new([10648426540231600330, 4287515461168343378, 2792833947790191490, 2395438721812840819])),
+            Fq::new(BigInteger::new([8035349878198547088, 16495477028811560057, 13258357263093636776, 3062612996566768222])),
+            Fq::new(BigInteger::new([12758260575419121125, 1365286129732351427, 5403867346039667406, 2823544379132793160])),
         ),
         Fq2::new_const(
-            Fq::new(BigInteger::new([7998300453201263020, 78049383989624510, 12668917018449330028, 2584132807978455408])),
-            Fq::new(BigInteger::new([16889850005117519686, 10962921521344879965, 7700884861912715582, 1840902355316083454])),
+            Fq::new(BigInteger::new([9248307290322242908, 15862596056335625878, 2557311625314099898, 1242209264612509145])),
+            Fq::new(BigInteger::new([6427700214587973930, 12870701350984218193, 8197910484492776192, 1309817778755941419])),
         ),
         Fq2::new_const(
-            Fq::new(BigInteger::new([2378210526523241084, 12112257846439601815, 5443761033282034074, 1688561146115737359])),
-            Fq::new(BigInteger::new([2591461789858081807, 17996576100346077797, 11055532971510520139, 1877357079404694714])),
+            Fq::new(BigInteger::new([11670891256269772308, 8410269116322331106, 5842819351701827906, 1952853329790611951])),
+            Fq::new(BigInteger::new([13534441350507716964, 17304553075550827414, 12867154692696413329, 93317604314704605])),
         ),
     ),
     Fq6::new_const(
         Fq2::new_const(
-            Fq::new(BigInteger::new([17982669280123530357, 2282897055520310691, 11056404043857176573, 2467609727952788044])),
-            Fq::new(BigInteger::new([17797542760164485834, 6766148085504073173, 10630582177949344871, 1773196431391652082])),
+            Fq::new(BigInteger::new([2989916535322296897, 12446529532568849677, 14356852947919099303, 1632133669617409385])),
+            Fq::new(BigInteger::new([9632240190118302070, 1129574792157062118, 18070063413630105129, 393366383312335248])),
         ),
         Fq2::new_const(
-            Fq::new(BigInteger::new([11224821672529076495, 18330365023412784651, 12153084578377766832, 2738175957851903592])),
-            Fq::new(BigInteger::new([858813539819154514, 8184452048418190347, 314838866661972247, 179798220493511661])),
+            Fq::new(BigInteger::new([6591261367597657465, 10961242848928014279, 11066084535489999962, 1716607512116415472])),
+            Fq::new(BigInteger::new([8851953487020489270, 15145151113586491800, 1992775490766744878, 2427219268386615893])),
         ),
         Fq2::new_const(
-            Fq::new(BigInteger::new([12311098927676521012, 14719356491626436539, 6662383899265687442, 1937988890600958077])),
-            Fq::new(BigInteger::new([17293546404626221441, 4338305996993777560, 17442116632069593112, 1630810485935914521])),
+            Fq::new(BigInteger::new([16850747671233282496, 13120606804985631924, 16750415166840217597, 1732321478824476273])),
+            Fq::new(BigInteger::new([14568953402172805665, 18295913123041594614, 9005137692908737632, 854141915765359487])),
         ),
     ),
 );
@@ -159,1276 +129,1276 @@ pub const GAMMA_G2_NEG_PC: &G2Prepared254 = &G2Prepared254 {
     ell_coeffs: &[
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([5214444645988771789, 2862287698391177373, 5136788962072848952, 976648616540291607])),
-                Fq::new(BigInteger::new([16133620238510066813, 2233800745216912743, 1099268521858052406, 2491709966200627138])),
+                Fq::new(BigInteger::new([13561823019654849787, 2343080162139748275, 12130203231125779507, 1182672306843915142])),
+                Fq::new(BigInteger::new([12280263523435487586, 2262148577765565660, 1945067216530537586, 3104281969095793937])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([2087883628701123379, 15598075970447642314, 16410583820594961168, 1738625430942905748])),
-                Fq::new(BigInteger::new([6068860287118982297, 12664966836353940256, 7581942242268806558, 2806452848394301483])),
+                Fq::new(BigInteger::new([15153157375979271419, 1354576498625907599, 10675597608744517287, 2690958214120499577])),
+                Fq::new(BigInteger::new([4089209470497461562, 2105296120094050881, 3406542843737892152, 3096880753008949352])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([14713994292397334841, 377296528990846854, 17034430362937851151, 1597219260002710375])),
-                Fq::new(BigInteger::new([13488331411369008110, 6162927904588100224, 8400543349783989685, 1671557135996744561])),
+                Fq::new(BigInteger::new([16651403534927482600, 8497976867627883551, 9483536321049421554, 3428368959652397107])),
+                Fq::new(BigInteger::new([16883123864576898037, 17297024779053182708, 1005382378001135406, 1469061584034455526])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([15210902089525607729, 8284905328850116034, 457745285557848042, 347448369627740989])),
-                Fq::new(BigInteger::new([566584374226709127, 1854540639604627745, 16232633957840861028, 916735136156392610])),
+                Fq::new(BigInteger::new([858978386848508791, 8937264803559671159, 5367146857651637103, 1535315837701984519])),
+                Fq::new(BigInteger::new([2141360901743885720, 8193419204140192019, 12799505762692374177, 3095559988657717664])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([54544337719638324, 17436751731866105188, 13841446350741634504, 455181561686476087])),
-                Fq::new(BigInteger::new([13282864128472880361, 1285441854102520579, 2682068237931702480, 1290420667468008302])),
+                Fq::new(BigInteger::new([16205511185779559310, 233533350757492271, 17332271626406966516, 2085330811162810355])),
+                Fq::new(BigInteger::new([3773283844953205274, 16601303640659360047, 11167866900965013990, 1982502904286706155])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([16975878885272539178, 1264030510618919839, 16852047632339743930, 2094978498293708748])),
-                Fq::new(BigInteger::new([11994865420670846797, 10602302289391137904, 17700900256489374244, 818004793925440611])),
+                Fq::new(BigInteger::new([3702538243535889432, 2617472740417279771, 15127969872163109767, 3067468444821479786])),
+                Fq::new(BigInteger::new([1679980083165939752, 5573081731977487723, 7943265680755104563, 3352851303018891841])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([3332038039647554474, 4264317490057622464, 6074966242786739457, 705052488626284889])),
-                Fq::new(BigInteger::new([7317074642353923645, 10783748693900216825, 3505383916672042202, 1075889244618074231])),
+                Fq::new(BigInteger::new([8922948144405200398, 13751427786450559602, 9646440971490649240, 2704331115901624008])),
+                Fq::new(BigInteger::new([10490671968436241819, 6628442198050307817, 16233408510759319604, 2754037415879654911])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([1706814951132495746, 1317475328779091987, 14324031021824422221, 2470243298361872610])),
-                Fq::new(BigInteger::new([791061714651902166, 1611482990877936548, 14483220630611269406, 2821346738734299502])),
+                Fq::new(BigInteger::new([9070450130084228140, 15739625262404602245, 2291785769080863509, 1186939966118664307])),
+                Fq::new(BigInteger::new([8502419491061801251, 8601909760788904643, 466144466967236385, 2745151706086014523])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([1023330662747025384, 10833582535409284325, 10417624950521654659, 2706413125560885498])),
-                Fq::new(BigInteger::new([1155666234459945075, 3077432479135335288, 4154516072915578813, 2101858527532978711])),
+                Fq::new(BigInteger::new([3270151763492059053, 7160073314885138969, 1775190364601539788, 2792688065001759086])),
+                Fq::new(BigInteger::new([6844590148684222559, 8766834818395747308, 15499278997793761367, 2183995852641096545])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([16612013134506890466, 4259637008935843235, 5032806850004352687, 2480283752893698737])),
-                Fq::new(BigInteger::new([6932541087884193527, 6490083673579660723, 14844803251622379770, 3469141519961556262])),
+                Fq::new(BigInteger::new([14715041743786098360, 15608549643396186532, 15464028700370606843, 313959288237014903])),
+                Fq::new(BigInteger::new([929211612194361451, 8036377266809505834, 13658360878830856422, 1369848415243723829])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([13191580974821905423, 14090803507975228600, 3689223443455063488, 2636273508272216790])),
-                Fq::new(BigInteger::new([3877665809510097290, 2299079601726002127, 13726094200910739286, 2549205302988591935])),
+                Fq::new(BigInteger::new([13948490665506024529, 3205861411018992265, 12429174313389542627, 2935517910498789710])),
+                Fq::new(BigInteger::new([16242386250926265427, 4901232149017783601, 17317878269346120662, 371983589924800873])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([3727287302733833137, 8313711836821585192, 2464423382996651133, 2830449921697139365])),
-                Fq::new(BigInteger::new([10254069904033286668, 10486420757035313810, 1309751957865873296, 2364963680909935199])),
+                Fq::new(BigInteger::new([16677737589770644871, 10799586170515711498, 5291487033287206517, 498901111503524450])),
+                Fq::new(BigInteger::new([15215988502367151447, 16663273898717976809, 1854767911411908346, 3101089234710158720])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([7349065377572758940, 15723399659075276437, 2730730950331693160, 1932785006364882976])),
-                Fq::new(BigInteger::new([9769158847227113958, 2385141547472552299, 9187728236830645506, 1732534815868088906])),
+                Fq::new(BigInteger::new([13512694168023210907, 17906427789522624350, 8484894788042296898, 3297850288889318733])),
+                Fq::new(BigInteger::new([8824988961971061193, 13556459721360498741, 2969725009439056446, 1943090643486812411])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([7820145794723084415, 12362552928270013904, 13907802700017641059, 3242776684948530631])),
-                Fq::new(BigInteger::new([1352595367248470520, 9230914598803688470, 13507734290579308300, 442264500497655674])),
+                Fq::new(BigInteger::new([12391186134992239333, 10348330791150418083, 15091990130866489302, 1557579859908815192])),
+                Fq::new(BigInteger::new([3613570370437983433, 1437154195446517473, 13072582976511371133, 199401088460613677])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([4851878796336912911, 15902654432284832518, 16571296331204520465, 960095130557673453])),
-                Fq::new(BigInteger::new([1789555595885326675, 13075148874350172875, 14760099128337152094, 3410498915245042841])),
+                Fq::new(BigInteger::new([15267054547449535728, 6785975143416605939, 5466729582344480078, 2708649382282320783])),
+                Fq::new(BigInteger::new([14025288865332549810, 172023552372792484, 5940264618735544594, 480223403268998074])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([441068767145130086, 3550528975327641578, 5261156705198480670, 2823831779317731679])),
-                Fq::new(BigInteger::new([1891199914097598686, 4049431121026032072, 1536101258892115255, 317882828864767212])),
+                Fq::new(BigInteger::new([3284356424283682632, 280688798866845890, 2376009756800095767, 3439512050665490449])),
+                Fq::new(BigInteger::new([13238208016493713813, 10109193841163973674, 13114232238746250100, 3251313490281375137])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([15425378519360174568, 14560390971498153581, 14328494930725419800, 3097877318831348528])),
-                Fq::new(BigInteger::new([6616354434273305695, 4091742304775004010, 6196548613490652482, 374481960978758010])),
+                Fq::new(BigInteger::new([9344619276148321553, 16341683069663962899, 369187329723083874, 1368469839146697548])),
+                Fq::new(BigInteger::new([10317234549245594117, 14188058534743140077, 1951783894382885468, 350433609136249681])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([622308092840731530, 16831333571448707565, 9078224413881423287, 1755497783754215716])),
-                Fq::new(BigInteger::new([14844361124687525768, 5443443737286577745, 11728679546871419496, 1553898975144507879])),
+                Fq::new(BigInteger::new([6687259505933131986, 3991685772338856733, 10593448610083843545, 517414520930017096])),
+                Fq::new(BigInteger::new([7981336061327066544, 5895180147985289569, 516011511681791251, 3400154530195872659])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([12189585606151870471, 170377458830817761, 8189217302997312683, 3004595556074970527])),
-                Fq::new(BigInteger::new([11141082014048671383, 16573307763140836517, 13394511651296077994, 2355544025065055105])),
+                Fq::new(BigInteger::new([10960160407136144170, 5578632635245936291, 6837139123303347249, 1405239017799456214])),
+                Fq::new(BigInteger::new([17194912223912990623, 14103391246863459949, 18183117675306913618, 1616329904872407899])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([7474853423145494573, 1005610564494025453, 4670360590972487466, 1380403868326475161])),
-                Fq::new(BigInteger::new([6325882763527015336, 913412948402231029, 6586441471678655670, 2009019610152517511])),
+                Fq::new(BigInteger::new([12357231857457462695, 8232519612264802931, 15035191760008382570, 2265803953407972143])),
+                Fq::new(BigInteger::new([1210471083354779992, 12570794407269137187, 6819868582789135955, 1971659492646663224])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([2242688808893728354, 17783572972605823521, 1824096470715642833, 487903279172306231])),
-                Fq::new(BigInteger::new([11425178968749184826, 9604962946705370031, 12467519471660889137, 3341136165165504469])),
+                Fq::new(BigInteger::new([17639237646050953421, 4877548423761563618, 13535982165668428783, 31952194530587298])),
+                Fq::new(BigInteger::new([11735490351995686034, 17112921975703935050, 13351999216537938482, 979764292891491756])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([17882546470725590994, 16042207592379200539, 14907564174455852302, 1304595809723609589])),
-                Fq::new(BigInteger::new([7626943654076661249, 16060154266658772209, 5801737847664250305, 1541091534477236847])),
+                Fq::new(BigInteger::new([4524766898043513393, 16300917835553398734, 14595974489069526923, 385827293736387565])),
+                Fq::new(BigInteger::new([10287403576114189427, 1854788749122257609, 8629295937090040203, 1638545562387917380])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([4344307774628113457, 1064295034778437007, 814193782135287162, 2024010167548766259])),
-                Fq::new(BigInteger::new([17060123667357845145, 3606338478782170108, 9466258410517607569, 64988586034824083])),
+                Fq::new(BigInteger::new([11307156192116711214, 15000651998633934817, 9577440403185925696, 1042224161257047567])),
+                Fq::new(BigInteger::new([17290005453857198713, 16835339198166258334, 6693187309765013120, 1442026968385260127])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([48931654813283093, 11352181653924801577, 13303183096388798584, 2049461803419929938])),
-                Fq::new(BigInteger::new([12597759956110438222, 14317576587042058654, 18315980272258567732, 1419032145904364640])),
+                Fq::new(BigInteger::new([69575189530217175, 5228698507019278269, 202706045901484874, 1777449526944397567])),
+                Fq::new(BigInteger::new([2957133711162487626, 13324743406873129483, 696148469170238070, 1416050319063769083])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([14772203563024105341, 8004747529986199393, 11557566055877598685, 2698994856109537497])),
-                Fq::new(BigInteger::new([8769831668161517647, 11369742884184136553, 16568571594040648146, 603901816036647608])),
+                Fq::new(BigInteger::new([6470317541212471999, 13468597593365002568, 15236312529939862781, 1195660881230549570])),
+                Fq::new(BigInteger::new([4706152345362125196, 8279642165326783411, 14613728469678666212, 2465819270114667440])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([8556092948074844236, 7583277271357531643, 9589791437465494156, 31742548701229751])),
-                Fq::new(BigInteger::new([12348932598565098669, 17884050641362131534, 7525338658602903170, 1388752587293383392])),
+                Fq::new(BigInteger::new([4905956322412552653, 12943036866993476121, 12824273662950755585, 2202516010118843369])),
+                Fq::new(BigInteger::new([6185837958866940712, 249741370555067992, 12122161163137308965, 1710838237443951149])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([17573042028809645168, 13448463291114892792, 9458729542035026606, 971130459148061017])),
-                Fq::new(BigInteger::new([11412988569447157739, 9940399524501763603, 15788314954877998647, 2686526444172334941])),
+                Fq::new(BigInteger::new([4737988162754853704, 17582948730589420317, 10800409062679308305, 1807706417493110003])),
+                Fq::new(BigInteger::new([4358755222359832320, 9832387021300674927, 15504074763794634576, 1413095475612516927])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([662885393370767817, 11040978733600821756, 6003282630491958788, 1126028407767400161])),
-                Fq::new(BigInteger::new([1417814973379379597, 14904186992490041476, 2422362400710526369, 3371185988396177615])),
+                Fq::new(BigInteger::new([3526558025080693059, 15066439199827250119, 4560115442872055891, 1680221240236132938])),
+                Fq::new(BigInteger::new([4559700479492305231, 13193184171580038217, 17360777965555907490, 542058901373076172])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([16731707140027091989, 8860516663806075736, 4748474534767036750, 2804057301543456264])),
-                Fq::new(BigInteger::new([10921994544415131700, 15795783893331235409, 14478062299976640461, 2799389974078177740])),
+                Fq::new(BigInteger::new([9178431650815440432, 15151206792058040706, 7223073031094194032, 988967641318277180])),
+                Fq::new(BigInteger::new([12455387436477192607, 9079967901463644557, 12629569598770373252, 1103792082790523791])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([13033751115309669579, 11515027401978969349, 12691586801344438452, 444101526796439179])),
-                Fq::new(BigInteger::new([15333867416254017013, 15764989700962773302, 13058194015747092119, 1835928862879621698])),
+                Fq::new(BigInteger::new([16360896213607909730, 9844286081359555894, 7228926957206319203, 3000985099968026914])),
+                Fq::new(BigInteger::new([1784274709725521661, 4530565098480166040, 1971598069151449630, 3247480130734932390])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([16235065102923901366, 12055977133390662950, 1868635867418356227, 1319690067720631033])),
-                Fq::new(BigInteger::new([5088212179925580164, 157766498432594797, 15535314800415495498, 1726534937071006030])),
+                Fq::new(BigInteger::new([18249945219921844679, 8223831813501706875, 1619172260030948989, 3271459623669312070])),
+                Fq::new(BigInteger::new([5290749470251305140, 7112380934077087989, 16352563440881238600, 1134538501926748610])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([10029579610193670534, 3843069601239632316, 6299366958199535874, 186465087373888550])),
-                Fq::new(BigInteger::new([1474433691895547984, 12027363048531337064, 17323088989293474214, 862759878624544277])),
+                Fq::new(BigInteger::new([7146737410604176092, 18038639199122195596, 10682862908816997091, 3217915683729405963])),
+                Fq::new(BigInteger::new([11615858020074167868, 2672371955903075963, 5308566878208951164, 1857904394650782091])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([8626975373592422496, 12091221175077312881, 7898902418984418474, 490934559315623629])),
-                Fq::new(BigInteger::new([11833536124733157335, 1084952656279273114, 5225091383563834959, 2523155356507611602])),
+                Fq::new(BigInteger::new([17028967253596877941, 18327007928466530938, 13314488130418146439, 3358552499534016587])),
+                Fq::new(BigInteger::new([1369615015943452412, 14985372317939474767, 3778983664518364422, 1850455047980178316])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([7125817112526691880, 2450213979735202047, 3690327872204417869, 1036526615128874977])),
-                Fq::new(BigInteger::new([11794789411272293567, 4492241840710794638, 5628943058284118335, 34496396816616392])),
+                Fq::new(BigInteger::new([5960580440911046159, 17820675413174106391, 12955968663964250346, 3356109599242380790])),
+                Fq::new(BigInteger::new([11207302515202934362, 14197691164868753275, 541449558908198776, 355986852335651020])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([3253197254037592021, 1008211677364000086, 17014454377512388742, 1935867649780004729])),
-                Fq::new(BigInteger::new([609028654194949142, 12180486561776459976, 1041656916650349670, 3182963172890363763])),
+                Fq::new(BigInteger::new([9642721058408004507, 11276112427300594011, 14014286936366547022, 2248935797508071147])),
+                Fq::new(BigInteger::new([14054564009937444575, 10439679060910308748, 17864452476930628577, 3112087759471020293])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([3062254157641195393, 14770787414595379053, 9618088158626026133, 1706369298034136653])),
-                Fq::new(BigInteger::new([4208748484844347220, 3693357158077678731, 13616095413467076601, 1459580508633277009])),
+                Fq::new(BigInteger::new([16043289265723138977, 7033967116076643717, 9134216998248200846, 67258635524917880])),
+                Fq::new(BigInteger::new([15538408531711659917, 15794654293241146785, 1006494577835118224, 321697420767244765])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([2822502266335245729, 1041728759689453526, 7834691753027475983, 1724603518092742422])),
-                Fq::new(BigInteger::new([9596190012772329504, 12408972815610786171, 2629316408081285987, 1606508408294371433])),
+                Fq::new(BigInteger::new([2671560735658582260, 1860699927045899662, 14059381132644950317, 3008261440202613046])),
+                Fq::new(BigInteger::new([14571235366162089657, 16044035083596849841, 15944088477198415366, 167288961158935164])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([12447812084434741713, 1766439695302965582, 13542871392835771750, 392622792902237087])),
-                Fq::new(BigInteger::new([13350616894746594326, 5996938519982435388, 9440580825835479364, 2280366559055253626])),
+                Fq::new(BigInteger::new([4444732590407464444, 6453897496190609003, 9039292526651990351, 3091747631905609601])),
+                Fq::new(BigInteger::new([7122707083871624973, 1425398244518735800, 15021145355665476155, 3135576278935713696])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([4400620589752855221, 16885949816427995075, 8977408324569680153, 2795598460291227564])),
-                Fq::new(BigInteger::new([8116002581489488686, 4040932569262948842, 12345584405385926731, 260467225372146504])),
+                Fq::new(BigInteger::new([144747779028217827, 833955928901891550, 16886624315992670018, 2181947301607856580])),
+                Fq::new(BigInteger::new([409972955873621500, 14389740309750441047, 674707523839941356, 1399265762856122702])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([1986425793070910323, 11748444369893221282, 13272742963796499882, 2270092523263056312])),
-                Fq::new(BigInteger::new([8328070059019280614, 8703985922863537968, 16497433870306152763, 2268971332863431917])),
+                Fq::new(BigInteger::new([17449893649473722193, 10962329502248482450, 14380998827131698221, 2865493893723686020])),
+                Fq::new(BigInteger::new([16793724845325220304, 18414492024426117641, 10565494163973275298, 2208827417878146987])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([15926025845460609842, 10620923382110440319, 13141596025675912715, 874939553865231451])),
-                Fq::new(BigInteger::new([1736686700258726898, 4103444410328613582, 4892719205126974347, 523916872001643219])),
+                Fq::new(BigInteger::new([4543035470139530726, 3930402043241538474, 13466736462048587527, 956938879454077261])),
+                Fq::new(BigInteger::new([7243468638755152415, 6620001297254938420, 9340731768368931964, 471918077801637318])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([14476093589516266735, 8634903006000942946, 8520179046255108894, 3124430269177199786])),
-                Fq::new(BigInteger::new([11734216548596615225, 8343027750616121454, 773884120406583016, 3030688271687349964])),
+                Fq::new(BigInteger::new([5919172967342356678, 16485641810891013298, 2932742232602374913, 109621662273539666])),
+                Fq::new(BigInteger::new([3943405938516336939, 5433234705959432394, 11132870830410227218, 3291336808124410806])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([9756398482325016847, 12001628778192667800, 7584650780783894474, 2298357901322678382])),
-                Fq::new(BigInteger::new([13758255924594039153, 5236290207073537343, 1893835610137683141, 2970454693840050899])),
+                Fq::new(BigInteger::new([11748698918912165906, 1774447923109183113, 10532958950093937931, 1533143926552133496])),
+                Fq::new(BigInteger::new([558324220425965173, 3984226775129939774, 6034114759179487993, 1214183972017476212])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([10477094835294244575, 10175588631870792593, 5039866646762085902, 2629119635577106393])),
-                Fq::new(BigInteger::new([3127390311601589234, 6240511520664054255, 7011739175345374102, 1381807177488622151])),
+                Fq::new(BigInteger::new([15698632214432233707, 163983704818594120, 2056805436091470169, 2935509886180173170])),
+                Fq::new(BigInteger::new([8332037362304337074, 17975089865985446994, 8709569906988619585, 76670938864477863])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([2541467792291013768, 7529051969104747122, 16708732278642940829, 2539801261199858771])),
-                Fq::new(BigInteger::new([14923538114117486201, 14139614150812441389, 11191981658552177913, 215604683347500707])),
+                Fq::new(BigInteger::new([2965717813543299807, 10165294512744012554, 8510674965094988232, 3348170564392244088])),
+                Fq::new(BigInteger::new([6456398662206465901, 14541904975864166715, 10104643007572861543, 2276344871341457189])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([11980676122299493103, 12926157117091524302, 8102365656340977454, 921779967261037619])),
-                Fq::new(BigInteger::new([15682071849476462054, 731908785312634973, 11453512292955468201, 1140258878704342205])),
+                Fq::new(BigInteger::new([16855205237499243148, 8088250031760695797, 13047225264176956272, 750940216627955461])),
+                Fq::new(BigInteger::new([14916577790586811754, 13719381517091934571, 9680578144779763340, 2391778743787859402])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([10845532312211831273, 11305701697959242352, 6023146009969086029, 3100477309972567699])),
-                Fq::new(BigInteger::new([1689524074595859848, 9936490865019739837, 10677574578624551022, 870640399734898569])),
+                Fq::new(BigInteger::new([2530210345721114751, 17927047565189578546, 15151987003448173895, 1673029110460983092])),
+                Fq::new(BigInteger::new([15501563000390317411, 15483519054281013317, 12607069658838591031, 1116316522273191971])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([2299663189787447525, 15718150066208650468, 1709293003779245021, 2227305302560341634])),
-                Fq::new(BigInteger::new([10424652187100279569, 107690179685621887, 13739970598075590225, 2578469623795147602])),
+                Fq::new(BigInteger::new([7086300500735363084, 12763007981002204347, 16877332116567363372, 2071073705384791195])),
+                Fq::new(BigInteger::new([12864837436192013394, 8981253587834190420, 15235931984913686631, 1807544944236921135])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([2444284795921186781, 10446789888556788988, 2453587149207541809, 570053372403676032])),
-                Fq::new(BigInteger::new([15624186051259644476, 9122070717511590863, 4614136363871920855, 1627166496802374405])),
+                Fq::new(BigInteger::new([6510600617491709023, 3793928457734963145, 14779892834218546383, 2718313657881810089])),
+                Fq::new(BigInteger::new([14145456111926786873, 5286583292010977189, 8567998578103615866, 3247785614029686018])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([7793313303329172679, 13951824333304695269, 9582862480370730765, 3285874042266336906])),
-                Fq::new(BigInteger::new([569704371841515687, 16431306687465240682, 6638739349722323301, 1092527498482693971])),
+                Fq::new(BigInteger::new([18393504910902718094, 16351632890285271908, 12971499929794547778, 2755528570819207263])),
+                Fq::new(BigInteger::new([4006647364480866897, 14944653198589199491, 833533675790701161, 2245072618442584674])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([12104750825253614136, 7908526174463553125, 17710984304961655494, 497722036761600852])),
-                Fq::new(BigInteger::new([17003512336335707387, 1765567974313094033, 2272894899911781576, 2318551477179439983])),
+                Fq::new(BigInteger::new([4641729396615923725, 16703920341722786534, 78377814197577476, 2297355245764589920])),
+                Fq::new(BigInteger::new([15558683651077298166, 17068129228117306604, 6349178513868647020, 1358980516857841217])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([8179096521739057752, 17116043667956769772, 17433213678227843951, 1478133109336917454])),
-                Fq::new(BigInteger::new([6717833265837182392, 12487327684150173252, 9887746844071832854, 654686925535933915])),
+                Fq::new(BigInteger::new([6031923826695205441, 2436535684095554819, 7855881311228747221, 738485404636777777])),
+                Fq::new(BigInteger::new([6393104500893744178, 3637259561307848540, 6914012157751505043, 991412223668197691])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([9511652572788795301, 2464050029699054716, 3507591237449516896, 2056551448176412884])),
-                Fq::new(BigInteger::new([6070271446603479141, 2186460849622721629, 2125907446133002715, 3340036701069176099])),
+                Fq::new(BigInteger::new([6138763942133174663, 3592489774467471421, 1568266310399496676, 2480579429133253237])),
+                Fq::new(BigInteger::new([6472636058001379485, 11533749784420800984, 6011518001485942201, 3232845011318159419])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([1685824238431478491, 14547763898402409610, 17042785104507303361, 2406162372071568140])),
-                Fq::new(BigInteger::new([10490033054042416295, 10751123459125611252, 12792173485985217135, 2190367099106805695])),
+                Fq::new(BigInteger::new([9260333020320105250, 2166192250344177578, 6288886563607376019, 720345624121122101])),
+                Fq::new(BigInteger::new([9072793405344986439, 5025188916299053309, 5012500711806121270, 2303271646001037426])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([15639312920302411588, 10429119127440947252, 13481920452577934841, 1725416167758854959])),
-                Fq::new(BigInteger::new([9695931758801007610, 4470578494630718870, 15056915681180105561, 447185258601937159])),
+                Fq::new(BigInteger::new([15924933256681079476, 3953670300915019923, 4199812134028063304, 3328015692113606389])),
+                Fq::new(BigInteger::new([547270579354471963, 169708407209110734, 14554632179128012262, 1791801521156529799])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([10172558095515815355, 6684485670874971964, 4498061637924791313, 971685706940214292])),
-                Fq::new(BigInteger::new([6580782784827649262, 15492300800151224142, 8046416993443883914, 2870913617237248815])),
+                Fq::new(BigInteger::new([8068802814471822200, 8683507439303910781, 10208584499065964637, 2422110147031045331])),
+                Fq::new(BigInteger::new([7862099216416099237, 10646467696894990968, 7690327413058711429, 1420777560597976693])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([11336205863388441438, 11380357273267733915, 6765130212422011889, 1863976882004175956])),
-                Fq::new(BigInteger::new([13901460399259605098, 17592840361852443949, 10082897132735121916, 3388249478117985753])),
+                Fq::new(BigInteger::new([11968163845827556663, 3272080007720308044, 12990832779901559012, 1525926115902628686])),
+                Fq::new(BigInteger::new([9150358214614073406, 16694891493713874405, 10917435403078696868, 2926759339424008852])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([2298974954006573671, 16540264699458679927, 15799889758008448955, 2686258671969092130])),
-                Fq::new(BigInteger::new([15039241618343461942, 17019315687185456354, 10762229682518690024, 2549999232788624064])),
+                Fq::new(BigInteger::new([13104119716055227045, 5368282278282495539, 2122570592818790055, 1576213023852924867])),
+                Fq::new(BigInteger::new([15179177027750870843, 7067152203832394138, 11175426857617353637, 2077788411808264324])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([6692985591379820396, 333086715808156831, 8886738689041792702, 605453151647946657])),
-                Fq::new(BigInteger::new([17372556061065035624, 2109276655393245598, 667790501966108098, 3355040391146037813])),
+                Fq::new(BigInteger::new([10500388174812834894, 12779008628544458717, 1266664920141910107, 6385549020987654])),
+                Fq::new(BigInteger::new([14525630499498114949, 3875676654624097736, 10268574992567239862, 2133497837529385075])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([6370263732679501223, 745001330621733364, 10646519232833874257, 1392189490637226338])),
-                Fq::new(BigInteger::new([15127318704195451543, 10478768080156485532, 3882561100806361783, 2897019484444190514])),
+                Fq::new(BigInteger::new([3846534757964247364, 10466785139013181911, 2529666279431776926, 1762991065140806857])),
+                Fq::new(BigInteger::new([14253844149287152017, 5709492505888733383, 8813875184290979238, 299889838933638338])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([2277816209153908476, 7451046717601297133, 14124844145668716723, 20873084305986654])),
-                Fq::new(BigInteger::new([10516024353120819349, 16971684489722144276, 14128223020687920339, 518626689116416423])),
+                Fq::new(BigInteger::new([8536750730564943559, 9923714870105764241, 10193435349498317442, 3000792745942877803])),
+                Fq::new(BigInteger::new([2179250255723402345, 13748238053837448230, 15615856158934889192, 1709174405785789361])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([2624185233781129564, 9632996001529551735, 17340994696233690564, 1614466940489766990])),
-                Fq::new(BigInteger::new([14477154090702847877, 10240933651769935152, 11636200402538856342, 2153321750357516085])),
+                Fq::new(BigInteger::new([11124659677179091037, 15602477242191620395, 4033018618286055612, 64778497818116764])),
+                Fq::new(BigInteger::new([6716879325029600212, 12937315594461061147, 11450684438893165177, 2948040424875443724])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([5424593247769104674, 10110906412747649430, 1906948328969510353, 2507791619217572810])),
-                Fq::new(BigInteger::new([7051177687574450705, 17523472893391256466, 17961089210621598783, 243135234967010794])),
+                Fq::new(BigInteger::new([7980371434197220257, 7653258786583203740, 8406702887897961620, 3130541119088974043])),
+                Fq::new(BigInteger::new([7988488881627770752, 1578329944623715710, 1525505044269620778, 2842260982642366265])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([9443928677431684563, 2787504887316463452, 5000945431442338074, 3312509113637388595])),
-                Fq::new(BigInteger::new([10964470473867423185, 118866339578569797, 17781398772434618734, 855419518092802098])),
+                Fq::new(BigInteger::new([11143436348200257591, 10731503970782773999, 17207976983163976354, 109546270034074816])),
+                Fq::new(BigInteger::new([9863448803492372275, 9030734747936436079, 12932467517907931789, 46931903658293141])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([2371641461125735305, 13460044975254936721, 1309281868219275154, 2897287591918172221])),
-                Fq::new(BigInteger::new([728592111171181446, 6050367469562837430, 13044401341051314842, 216568575640626972])),
+                Fq::new(BigInteger::new([3453551810866133255, 6832505058424334757, 3924055450049913205, 655602840595175621])),
+                Fq::new(BigInteger::new([8985435758087888017, 11834420817713558419, 13737141698423069240, 2727992481061996248])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([3092080597639169335, 13518340641663354969, 8207845361317626513, 540036167817791001])),
-                Fq::new(BigInteger::new([17437120081259379707, 17261334349668438289, 15688344497074094269, 3233963074579276821])),
+                Fq::new(BigInteger::new([15560987770718896682, 3362958368172137474, 14684743259356302078, 439585889997694391])),
+                Fq::new(BigInteger::new([256845063852517855, 7078497536999555461, 14941391509990975357, 1478621193779374135])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([8797439200439215936, 18030336575132565775, 14326414135229313834, 1869481609937599767])),
-                Fq::new(BigInteger::new([531609189837205808, 13808379616448366248, 2625385906081848437, 3323572043282285683])),
+                Fq::new(BigInteger::new([12724078712792600162, 18345259558154901684, 9811791483983735095, 2621741844677629269])),
+                Fq::new(BigInteger::new([7176094187654476874, 13342878616400494510, 10752492725712686496, 1374770969840949354])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([10928305876499507807, 1536983985106331044, 2550962533272190919, 2942791222136140674])),
-                Fq::new(BigInteger::new([7251103992594090757, 5795383136731772787, 3669232373728820978, 2999098507825330873])),
+                Fq::new(BigInteger::new([15044015385891226412, 3090517369215879755, 17222635697747715068, 1817587527400309687])),
+                Fq::new(BigInteger::new([8898262628790287288, 333912812562899159, 6964988296670940845, 2496972808479104368])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([5358044460695132552, 11027415694533597266, 12883659090931632779, 2798831106057440654])),
-                Fq::new(BigInteger::new([1879670960937816832, 2184781399990835558, 11755977642192746144, 2291831541982909080])),
+                Fq::new(BigInteger::new([18096953791146534334, 7828856885514786811, 18289771457807841868, 2938238201042353590])),
+                Fq::new(BigInteger::new([4824051458475604009, 7183767577387925975, 15437803240669131025, 1608133214101168446])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([9715944481550475990, 4800878577684959022, 11033385446330732429, 490975105951294809])),
-                Fq::new(BigInteger::new([7749361567196867873, 2757373259274260016, 12352696103296504345, 1799196932433535236])),
+                Fq::new(BigInteger::new([8664257846285571271, 12715114071414047515, 15770844764745865531, 206450545271110010])),
+                Fq::new(BigInteger::new([7733398659413187277, 530970980101801969, 8265807849253725438, 1638256545303929736])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([3732441145488423539, 13112780154862086535, 11707904666264360406, 742920777447944780])),
-                Fq::new(BigInteger::new([10544447561579122302, 11629368999777311661, 2631449840575600448, 1002710896057365306])),
+                Fq::new(BigInteger::new([14185952149304859401, 8145446167988247463, 15342070130259535520, 634399437934073474])),
+                Fq::new(BigInteger::new([3813208078887765425, 1044879494274912897, 16841428980294383053, 254278731765738375])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([7443584920266519144, 8643056130888815530, 16015802139720879099, 2332835971996772791])),
-                Fq::new(BigInteger::new([3607683118401157244, 9731178691360450949, 8493546391661457951, 3469207204307474539])),
+                Fq::new(BigInteger::new([12372115939041485789, 5825527295345239052, 176107062636513027, 2170574185808542644])),
+                Fq::new(BigInteger::new([14161637671624367672, 4842713318055646761, 6083081553227204315, 3074097905339110245])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([12292609598739892314, 2520999511192356583, 10605166454364629175, 838298159921488905])),
-                Fq::new(BigInteger::new([10515050962333419957, 3200414622034591066, 945835710541377363, 2517461638558729680])),
+                Fq::new(BigInteger::new([18237450938305874684, 8297105057720524605, 6454545689243691522, 554517379995674788])),
+                Fq::new(BigInteger::new([11275433632794851502, 3122977093622821668, 6486636955925318205, 2262917372507496474])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([2031472229358380562, 18135723984485663999, 10616121608614511620, 3106004852919857627])),
-                Fq::new(BigInteger::new([471447073876989381, 7286700602744870704, 1280635112222080560, 1735968689910460265])),
+                Fq::new(BigInteger::new([17571224083334147512, 10643148786726828529, 7234313208791921341, 723253355774912688])),
+                Fq::new(BigInteger::new([15622196004698970177, 2450318284786365188, 9008144579021462709, 2306648417565586640])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([18087994267246101377, 10244800173894164964, 16075683502133398520, 3325691015062087728])),
-                Fq::new(BigInteger::new([5422806607697306711, 10981167993085875280, 3268841641064626626, 519379953421828726])),
+                Fq::new(BigInteger::new([13086824666988169620, 4422700110672095812, 5322804240182188865, 1353082333361307378])),
+                Fq::new(BigInteger::new([16255732660423533138, 9417103800983568023, 16339142549089840971, 1309771154761108580])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([10484763755679655921, 12803325770949855704, 3430564769289854735, 2664995901527038562])),
-                Fq::new(BigInteger::new([8343276816386879499, 4086216910626521282, 15737464341209505443, 391751589914324219])),
+                Fq::new(BigInteger::new([5343460911838475575, 3803565859323228137, 10767008182122695047, 1462092333401715833])),
+                Fq::new(BigInteger::new([17605168686820999678, 8882969468093323015, 8819129672712230092, 399359067319873502])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([12597811273016211518, 12321385280823294036, 7889270020829845417, 890053829509111339])),
-                Fq::new(BigInteger::new([4286200460461875696, 9838475099980175815, 3649146686428846513, 741496979111730477])),
+                Fq::new(BigInteger::new([4148447730892713142, 1445433395864946103, 13676331551989697112, 1430531669518555547])),
+                Fq::new(BigInteger::new([11353462825317234272, 11124475175235415076, 6061746676710608296, 710647381934428746])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([9954068284836947662, 10544118952310972902, 7606638105049775108, 911567825682021682])),
-                Fq::new(BigInteger::new([8962377691101777648, 11256116713030709786, 10850358425174946750, 1028870113680969124])),
+                Fq::new(BigInteger::new([16814428355402741614, 7050227969776414842, 12143643482159716783, 3245625824416467464])),
+                Fq::new(BigInteger::new([18164324666602943634, 4892881321642823226, 13669238449237730856, 1528347346594366745])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([13828022683468426350, 3171293877880352144, 12753963133202608447, 355825978771704913])),
-                Fq::new(BigInteger::new([7454801930139763752, 8506968457389101342, 5968474232612112016, 1390258048894060722])),
+                Fq::new(BigInteger::new([16820285995482823149, 16096928115397517935, 10304682337567798119, 735677930962334698])),
+                Fq::new(BigInteger::new([16629698513244575478, 6789404198591344877, 7031129124329984017, 28132391814144639])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([17273183680510566828, 370109750269744886, 10037815596971870471, 452297258214713737])),
-                Fq::new(BigInteger::new([8037089179419896322, 5214009470429059448, 6009820217286070423, 3043968952102271754])),
+                Fq::new(BigInteger::new([1368945879352332674, 851563594407813658, 5081522401029773479, 2533446887853853661])),
+                Fq::new(BigInteger::new([17225970073475580020, 7298635776701978335, 3083299673910142581, 1014077273334740493])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([8642790848646316732, 13117831565857668205, 6224051719248210840, 1594290802008645595])),
-                Fq::new(BigInteger::new([12717347373203166432, 10242610591781215591, 16426596814189614607, 2024726545820756483])),
+                Fq::new(BigInteger::new([14741194042262469003, 10783259063645399841, 1018398011113234253, 2866275946439146897])),
+                Fq::new(BigInteger::new([1025437437547656601, 3367381428517426090, 2840060882157311451, 2242649225521831476])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([1129675453949515008, 18117202585881639739, 13997188010320331004, 3010091067071583589])),
-                Fq::new(BigInteger::new([8478405760795080208, 1807454990287826872, 4692945408412104074, 325220255625203557])),
+                Fq::new(BigInteger::new([12279151554651260759, 5998243188499446835, 4954207167926654180, 959682207052064723])),
+                Fq::new(BigInteger::new([1894767127830079265, 7546045182316797890, 18379742019056173815, 1245020024094071028])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([4453951165316583965, 5000345107060308630, 4106035545218889389, 3181230341006293215])),
-                Fq::new(BigInteger::new([4002459593762898794, 7490191762284075916, 10684090543355057432, 3375129901680255596])),
+                Fq::new(BigInteger::new([1363671878959814469, 9914129165938377847, 11637101417840395703, 3398622286788038367])),
+                Fq::new(BigInteger::new([7688571432347005468, 4024600974345448743, 3984131679840975741, 3375459787478173352])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([4200073476254225094, 17021898971936735623, 8301149406987450817, 2752793254792884007])),
-                Fq::new(BigInteger::new([5230702893384709786, 3221293156980654660, 7509153624841709650, 1765863156569679623])),
+                Fq::new(BigInteger::new([11548446592454231556, 6200817324347102839, 9745182772004622753, 2710767106079454564])),
+                Fq::new(BigInteger::new([2602627079319375983, 9107493678160205692, 16272057384397344065, 1931756710886091666])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([1559327030250611671, 15450741567657526062, 14840562253245924223, 2434128566442133193])),
-                Fq::new(BigInteger::new([16275902480246083727, 6390936513248852355, 11482932488378938484, 3455718199454448299])),
+                Fq::new(BigInteger::new([17404969497324990747, 15451254952993132962, 1239711978804938323, 2117408670801643455])),
+                Fq::new(BigInteger::new([6088926605334424698, 9937544205369686045, 13803287002150930027, 2905164268851171895])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([5762492990194860856, 12152527754754911039, 1333108150751574027, 2822644255433776599])),
-                Fq::new(BigInteger::new([10223514699165289753, 1570987648898181037, 14896102618205085536, 2994952586900939854])),
+                Fq::new(BigInteger::new([689028100017523785, 9600397852124657239, 6451746290786477086, 902098007128726191])),
+                Fq::new(BigInteger::new([3910039834463705986, 6987586244367204529, 4796980404329413802, 684261742388895072])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([15996084624603918544, 15986663814730323262, 14593972681989496711, 3064412251205080647])),
-                Fq::new(BigInteger::new([1951950860436249621, 6851838193504743674, 11754036877427514045, 1536447707802752156])),
+                Fq::new(BigInteger::new([6318897513596432794, 3077615940901328032, 5590634978668438930, 1278354177713235045])),
+                Fq::new(BigInteger::new([16121022760845568950, 7717653541412627202, 5257540238035724720, 2807791198078200065])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([15788239519167719891, 13005679037639031836, 6175698821479185512, 3252212991546734731])),
-                Fq::new(BigInteger::new([6422951260535227659, 5538733358170069583, 11237010925410886942, 2932864652938984417])),
+                Fq::new(BigInteger::new([3900460347943062604, 2344471565793219611, 16687158389332287095, 879211061910250546])),
+                Fq::new(BigInteger::new([14553541852551497391, 3952224639250376923, 16252098916851545893, 2165395642844567644])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([10474916821011596680, 13191967723303815732, 6982601272936008288, 523001747803213024])),
-                Fq::new(BigInteger::new([10230310653877751124, 8653023299224766322, 5067731781938390603, 3269757031250749087])),
+                Fq::new(BigInteger::new([7091712061156773204, 17182196712692809290, 16402724957190103371, 1115350827337741379])),
+                Fq::new(BigInteger::new([12218638155658363151, 13207715444409409243, 17526584007643004310, 3416549194984011906])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([12913881341865295168, 7257159170487126822, 12224249619513546508, 642337546364843737])),
-                Fq::new(BigInteger::new([7809873528777435592, 11318447420139851806, 13528925283524475627, 1403114887431518561])),
+                Fq::new(BigInteger::new([2927958576896249056, 5738480780197298168, 13088795800008863969, 1008807766018053421])),
+                Fq::new(BigInteger::new([8907823755946594165, 4719781861277166837, 4596455230599386972, 2838831190620768697])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([16782381623315534966, 12892574312725845021, 12520027885431270661, 1759835796508692917])),
-                Fq::new(BigInteger::new([2353151226908364684, 16827845484957934088, 975438887121884737, 3031107176511739535])),
+                Fq::new(BigInteger::new([9508152612620671441, 2523447733748609625, 10151365500237441030, 1069192211308445732])),
+                Fq::new(BigInteger::new([866113604643353875, 16904645888314608678, 16731015105918355057, 2140461184286766851])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([14397054216113470632, 11150769567271209407, 13978300384279714232, 3199005705135020439])),
-                Fq::new(BigInteger::new([1024771267468216697, 18225925446526238517, 7588281696173157489, 1023996088619662326])),
+                Fq::new(BigInteger::new([14503737105482749836, 4550460412614647205, 16153378806185391905, 794794944207175764])),
+                Fq::new(BigInteger::new([9836459047299437353, 10301040271575100999, 12641994478263311317, 1515484825835684180])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([13843855418964124129, 18146631680219400406, 14832726811680788357, 984722604127802116])),
-                Fq::new(BigInteger::new([17597167667343830950, 15134113491327131053, 16659356017258904922, 3232433643621846169])),
+                Fq::new(BigInteger::new([16860098171715356623, 14115578936485903774, 17291144658677267287, 2242396817011653546])),
+                Fq::new(BigInteger::new([12183330282075521326, 13792081702876763187, 46649795565407809, 1674004435646638619])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([1877096171135276711, 3925250331068273558, 17611322946581802422, 3316189173552485136])),
-                Fq::new(BigInteger::new([9634147547775473290, 7595173793349225602, 303544418891988832, 1540485111018013253])),
+                Fq::new(BigInteger::new([8193260629315944952, 10320381457817965539, 6683266681094850724, 471419880987178402])),
+                Fq::new(BigInteger::new([12520091042002636972, 10956275950833333324, 16016299450136282379, 998707863737763273])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([11376331565071110146, 11717762883890875174, 17232901599321831421, 341724668089284751])),
-                Fq::new(BigInteger::new([17708355461337227286, 15635989507356614702, 5757042141481756294, 2550391443070916488])),
+                Fq::new(BigInteger::new([9548079481874796398, 11604741830714488938, 13237860503452540015, 804052065076974365])),
+                Fq::new(BigInteger::new([1816769502987434153, 16414636324443256072, 6183865706539072473, 2720727999158079599])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([472145627194215738, 6120477466609273843, 8410573476469508211, 2599650554685072864])),
-                Fq::new(BigInteger::new([15054550550520824447, 8054002899349639084, 12222644574839041238, 731323633420002022])),
+                Fq::new(BigInteger::new([3793718718864808588, 8007764677671337625, 2374949237458486244, 3029776983765316153])),
+                Fq::new(BigInteger::new([1061807587419321098, 3973466673068264143, 18078486756141051043, 1367004171336759263])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([3708724586286976403, 10933570487603617646, 15035336958136159348, 2903603374761344881])),
-                Fq::new(BigInteger::new([14802063363004428327, 3577851147021494752, 10818247878545074491, 285155944047626227])),
+                Fq::new(BigInteger::new([8723181147736874196, 7543156635484397282, 11988449163206830972, 3132317168465434950])),
+                Fq::new(BigInteger::new([7977365135866460638, 16178002206133842348, 16486305232833669023, 2166927572534721615])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([9752687447754713207, 12920710787061415640, 15134416582767076850, 2622893763668417867])),
-                Fq::new(BigInteger::new([9158754904426774434, 869960573490107213, 5502405093554733277, 3196523396116550333])),
+                Fq::new(BigInteger::new([6592594922188806947, 16562273412052769709, 2731311525796961232, 492976258208691395])),
+                Fq::new(BigInteger::new([15661569026420342824, 15805627977296303628, 3156362864092154876, 3110876408249447633])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([3291297930174102570, 13814914357266105618, 9163435978489370412, 1644302099325178528])),
-                Fq::new(BigInteger::new([17875868807338870478, 3582451639009961735, 15034269977437706468, 2164802593710456065])),
+                Fq::new(BigInteger::new([13118242130756994210, 4347058880178924669, 12334017590639566048, 1311900375959223617])),
+                Fq::new(BigInteger::new([3689567005005630855, 2733704538607440146, 2626501266237776090, 1612761522244127367])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([15397455666659159770, 7308097801689897912, 5750835072481054430, 1828062786058661458])),
-                Fq::new(BigInteger::new([12296787708977146315, 11460113332001941177, 11084205590451894949, 1503619973886753837])),
+                Fq::new(BigInteger::new([12516423141052829522, 16501198842012267646, 14140022414451998876, 22298468781010527])),
+                Fq::new(BigInteger::new([11330881884586061726, 5428389903263131960, 9407902706688544699, 3113334051632245665])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([16607031506412987327, 10166705532624774708, 6405580776534705869, 378345936001149781])),
-                Fq::new(BigInteger::new([15835069896215457747, 16635002697702232580, 7621586208587567671, 2248058798244788279])),
+                Fq::new(BigInteger::new([14345871705327084629, 5739839452640449199, 8094625545577356778, 3377247976275970030])),
+                Fq::new(BigInteger::new([10771640438271476458, 2003116266228404213, 7369576514075013108, 1398263857142936957])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([12328905587907744506, 10131986332781213821, 4132855758533393796, 1470159151184690803])),
-                Fq::new(BigInteger::new([11846498513519078910, 9362903258861256051, 5020915104265884156, 3073236439966024590])),
+                Fq::new(BigInteger::new([9876346137528098003, 17571775575807216374, 7798695376011638984, 1627464436746461322])),
+                Fq::new(BigInteger::new([6166983598162520422, 8624389540179915182, 13490362330273872569, 3282579627932891749])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([14085828623624343734, 7818421968165039171, 13782927071436429627, 1396392679687699146])),
-                Fq::new(BigInteger::new([14343262816655448935, 3165137976182192735, 14689388223581409127, 2898640919390709576])),
+                Fq::new(BigInteger::new([8812970243865697658, 12643678897080261804, 13164708306532848037, 2625773697904136713])),
+                Fq::new(BigInteger::new([375967539247071935, 10008580418320847223, 7866534436212208436, 2964194274226673374])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([11885726583649769690, 8569641767239208371, 8275902086141237398, 2150224130999227834])),
-                Fq::new(BigInteger::new([17454982699024550181, 6107300639892521099, 13086168178047890379, 1554187134654679028])),
+                Fq::new(BigInteger::new([7157526161645714381, 11714071524116391343, 566476305232649556, 3272572212242019516])),
+                Fq::new(BigInteger::new([13812944454279108557, 13137143185605082247, 4393208830612547970, 1333873544855273673])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([9305066967613136299, 15238823029167387192, 12971361829717558939, 2632168887662819816])),
-                Fq::new(BigInteger::new([2555553476264894799, 15334704469453112713, 17790502327651734929, 1157968962178936517])),
+                Fq::new(BigInteger::new([11242701122482655153, 4872537698409558593, 7090443510554893963, 1442885151743925593])),
+                Fq::new(BigInteger::new([12557867240817011915, 9166091729510466941, 14283632847877311445, 2404979422923189755])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([16284410691890696992, 14476653069023249766, 8200482628864363674, 575713215630685385])),
-                Fq::new(BigInteger::new([7239830384740765438, 9840051409685652497, 9434069154901193764, 705360800190501723])),
+                Fq::new(BigInteger::new([18133198879309477697, 5553837476322796725, 18278586821083316182, 438853803759067845])),
+                Fq::new(BigInteger::new([1066312719707076875, 15708172627075336203, 14452372962913772125, 3113801987216218674])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([18360226605330169771, 12993657808036713323, 14896871786149538299, 2103803619560991782])),
-                Fq::new(BigInteger::new([9745553120886175188, 4797538481813704590, 1381073926305158224, 820540452469731553])),
+                Fq::new(BigInteger::new([9464921311952132694, 225260877242999899, 16544626584287837459, 2771984352531626869])),
+                Fq::new(BigInteger::new([6303748153850514850, 12892708390268865603, 3552322784815845830, 2005551460355127342])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([4788041623275311615, 14787275136946710485, 6092102423751807155, 881908891560952831])),
-                Fq::new(BigInteger::new([16471257713687641908, 6469209725817895705, 16737917722340368577, 3014207203918245195])),
+                Fq::new(BigInteger::new([14695842110882350664, 12747313223119583003, 4358618773169054787, 1478281423648234670])),
+                Fq::new(BigInteger::new([12039432944093557345, 191132015226251914, 10842294224282026654, 2741933464538958677])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([8461312554265578897, 5440766525757740685, 5661110597824007590, 1010509093673438690])),
-                Fq::new(BigInteger::new([3607461919480435749, 7926991637612586179, 18312594447255165389, 2031235315924276861])),
+                Fq::new(BigInteger::new([4596571429804835222, 7452051091904563237, 6539103815800496362, 2574807634301163149])),
+                Fq::new(BigInteger::new([2045224923615193101, 4145556547164068028, 1155359405618448652, 1758751180144615096])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([18062289296996936730, 1913981596223787864, 18086927075226143183, 2218147858600395950])),
-                Fq::new(BigInteger::new([10173098611484209417, 17854913058127766662, 12113681723827754367, 180100252509103242])),
+                Fq::new(BigInteger::new([10350408096569766559, 5090840671149855872, 8917677630169090403, 2886665426098888271])),
+                Fq::new(BigInteger::new([8480784422116067513, 13090611955346224056, 17647567022931912373, 2758355963585153279])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([1175206980266185854, 14618575142567470071, 2272383006379267762, 613416039496403945])),
-                Fq::new(BigInteger::new([8691275592067771452, 6282667476323213946, 13411527335702328256, 2893745217057146247])),
+                Fq::new(BigInteger::new([14353979383664197360, 863209224358892421, 11467131509773241438, 2824170529089052888])),
+                Fq::new(BigInteger::new([10613114475808735218, 4303879672299656854, 18169713422798148430, 3342470536838255814])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([10713494166296527430, 11589203406755098864, 9681251873667525352, 2906744673188455344])),
-                Fq::new(BigInteger::new([3307729786921725603, 8332741266882364451, 15625509153914572902, 2189570278077638258])),
+                Fq::new(BigInteger::new([6108208250530553012, 8019905273051501264, 18082007093438380000, 403816594587422619])),
+                Fq::new(BigInteger::new([16683396804526221078, 7660167567346395329, 7948091802184551592, 1314449713220159229])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([13331569376298817816, 9808612309495308188, 3591663855008353852, 3467545885544387735])),
-                Fq::new(BigInteger::new([14826222723893338566, 5843225118545629466, 15342219900322344147, 140350411489315134])),
+                Fq::new(BigInteger::new([14997493716942744032, 17296501563805431838, 69529584610898151, 2523243070675158044])),
+                Fq::new(BigInteger::new([6061329675510906128, 2052171293666358344, 9808898699570261026, 303244792693381760])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([11439538053133981096, 4997849705603900970, 7293153043784770552, 346692519635681026])),
-                Fq::new(BigInteger::new([15424089011253765979, 276281433060252393, 3348058581164774123, 126107645240050973])),
+                Fq::new(BigInteger::new([17444592676820846183, 1148428576761255174, 6820567804672165986, 1104276218447470036])),
+                Fq::new(BigInteger::new([10095196907485892850, 1530252679784368524, 13300377585355160287, 3365925461947658610])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([10045387698899515986, 515466993883349545, 6383436918485526420, 2056842103341282431])),
-                Fq::new(BigInteger::new([13135118980683529689, 8623732240764164595, 18225554110488461424, 304357873676737363])),
+                Fq::new(BigInteger::new([2081591636008913796, 3965048018978327469, 7304724198135353135, 1037788612388782204])),
+                Fq::new(BigInteger::new([12584989840364766750, 434272675064504887, 4293428836642994087, 1979647937276893764])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([6616240899071813275, 3181409379914765603, 9283187331867457525, 3212076822410512900])),
-                Fq::new(BigInteger::new([6864146669727518248, 8398868338816582503, 5928153581878546190, 331671086117847656])),
+                Fq::new(BigInteger::new([6716274911359127493, 10458847153375706994, 9601190970718989601, 38780871529861352])),
+                Fq::new(BigInteger::new([12607632280383005872, 7876499884249134206, 18351757274332316, 1426790100387199657])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([14357097337961186195, 447568654189980108, 1046375823908170036, 780196391497833113])),
-                Fq::new(BigInteger::new([10345647227994816364, 2952836805349163860, 15131309666445561212, 2845233230690473203])),
+                Fq::new(BigInteger::new([4141363522554601773, 16248567222552065521, 3381782990029824595, 3002383512615594226])),
+                Fq::new(BigInteger::new([11227985780511448261, 11599628254931712310, 10537048567976724239, 2372800874900546145])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([6920385039331345211, 6128809774993175875, 10241323431665280295, 1916581682528465378])),
-                Fq::new(BigInteger::new([1729373127740992077, 5155569185920737252, 16949962893439176305, 3288424708774657776])),
+                Fq::new(BigInteger::new([7524437830209776082, 10976165549506703985, 14859506254891085341, 1999177171830421721])),
+                Fq::new(BigInteger::new([16072708524257284583, 15844104373399506146, 5954989517949824408, 1516171061028041351])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([1066286884127793555, 7590699197280191598, 7332150909493186521, 906512827365442833])),
-                Fq::new(BigInteger::new([9311575036195532862, 6267085301261357893, 263855859636648344, 1652437061057009870])),
+                Fq::new(BigInteger::new([8103638924803353755, 17950717359402832032, 3967747523472402143, 730241509142415344])),
+                Fq::new(BigInteger::new([14607017604381923016, 3627406188390103481, 1317387059817336130, 3762246758148860])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([4492919581148194289, 18254432889571576248, 17419551207311550587, 1110329276986419354])),
-                Fq::new(BigInteger::new([5053175853135138413, 1488020994767560899, 13453050105185422327, 2234250695676211287])),
+                Fq::new(BigInteger::new([12868966153902050342, 11105803054461601653, 14674677521652595335, 1249015834323690717])),
+                Fq::new(BigInteger::new([3761781616782249820, 1519370798822472258, 4735670945011718825, 2739818585471695717])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([1470269560591558057, 10581857412654500726, 4958742236485719167, 2613913117919418666])),
-                Fq::new(BigInteger::new([15483157597080949317, 9428286038844105733, 4646104697374630976, 3387238777170608252])),
+                Fq::new(BigInteger::new([14579638538015783358, 2079827702820117263, 3609877315187309577, 1774473012581211418])),
+                Fq::new(BigInteger::new([17308693209211248348, 16750420647805352947, 16903960008718521794, 732643430239568746])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([8008979731441980811, 16700194710571953897, 18369374354306427942, 1758162883332630574])),
-                Fq::new(BigInteger::new([6847954651648383980, 5716523057517322008, 7838725076526904308, 2284767147056353720])),
+                Fq::new(BigInteger::new([3607368699101675393, 3768117990446736457, 15833390793340146755, 2395707070230781665])),
+                Fq::new(BigInteger::new([2408619880392194511, 6155461607501342911, 17994049699857930657, 417528462093622895])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([952874186117427175, 1181643991579883926, 10346524168148281804, 1628413975904023074])),
-                Fq::new(BigInteger::new([9312249322203335684, 792528097782604034, 16094247216630548616, 277232147213474959])),
+                Fq::new(BigInteger::new([16619470704625795472, 8572906260291258072, 910347985853521757, 2283510807875294297])),
+                Fq::new(BigInteger::new([4872552397253558582, 17861419347038449180, 4654041445638473072, 1038064558381620656])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([10962004497396636044, 16070088289678958175, 13943715924356483059, 1303942234210422254])),
-                Fq::new(BigInteger::new([4326220633560248859, 12903483609230351650, 10958482383146990000, 2913556943039240576])),
+                Fq::new(BigInteger::new([2973187346996225395, 15880201341057673, 2733766674007150569, 805967944070040190])),
+                Fq::new(BigInteger::new([4396103197074631776, 12878289389744685075, 8327566817250493665, 2169116843616923591])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([5264757268336854279, 12596963186719426376, 14729080387716971065, 1513297079579763791])),
-                Fq::new(BigInteger::new([14727780011485147921, 4311050976273286648, 17134758172655028400, 1120655596343017934])),
+                Fq::new(BigInteger::new([10182604077527385935, 2047680351076821224, 3328785167661328781, 1339702191221215177])),
+                Fq::new(BigInteger::new([12184564044098964853, 14416099572823926887, 18217082399846507238, 1067867460124773315])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([14279557464871228767, 238272774902109917, 12043222163131171395, 3362684521285090419])),
-                Fq::new(BigInteger::new([7052086728674051436, 10937306720661697858, 12559428975016340528, 3124674497139547736])),
+                Fq::new(BigInteger::new([5525334997072909903, 8167401403236370147, 14962009434280381704, 2410798069081212075])),
+                Fq::new(BigInteger::new([753099350312245151, 5342815699587872849, 11544418963050038741, 1937301517625692814])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([8397513770768229544, 14399412034991094682, 14640776285388979204, 2770573436522717575])),
-                Fq::new(BigInteger::new([7581906652337138702, 14551803086893394621, 2182348788742392975, 703932571805045041])),
+                Fq::new(BigInteger::new([17301099073708199041, 12331272222893480654, 6809698210151286802, 3039942401970976719])),
+                Fq::new(BigInteger::new([5055730359710415881, 7119654852104579304, 3202059414936002360, 1916417893177851904])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([5699559210211641884, 8537053688880039823, 5810466117585643879, 2289306328487360395])),
-                Fq::new(BigInteger::new([11425239884121032451, 932355032659190765, 782732020725543674, 1052350564243721763])),
+                Fq::new(BigInteger::new([8674544556413295451, 7344551641704846048, 2999352658271277926, 1592233985264040315])),
+                Fq::new(BigInteger::new([2302769345204311581, 7283042597463931975, 15020436714592222177, 1836593107107164600])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([13110928188549849956, 5643310918040613685, 15259128035634573907, 792339965832540802])),
-                Fq::new(BigInteger::new([7304398582322240121, 12968774200280317536, 17106145942501390921, 420914655437787536])),
+                Fq::new(BigInteger::new([13925894543848963153, 4615000475063400047, 2619283859940955220, 1797542302290357169])),
+                Fq::new(BigInteger::new([5220676369595445542, 10443799775963604013, 8013528322925874971, 2793491049667229868])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([17096806411551422196, 7744323105911711139, 1211582321715826016, 3069375373007547631])),
-                Fq::new(BigInteger::new([12927491646023795281, 13476230762763802796, 4768780439527324719, 1497521517216059881])),
+                Fq::new(BigInteger::new([10153067566404569330, 18130485764100592218, 10998390948078745432, 451586643732390188])),
+                Fq::new(BigInteger::new([2077613238178457940, 10090552062174198581, 244165948539664231, 2527562921136221715])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([17980488745310642662, 16139672352988429663, 3426555520430758175, 2126339234135780809])),
-                Fq::new(BigInteger::new([2334554285542053097, 15979649754161688064, 13905866204158288598, 709785576765213275])),
+                Fq::new(BigInteger::new([13685509224896920451, 13012496302589316952, 11748109632386607265, 2185124570153652670])),
+                Fq::new(BigInteger::new([17454036283859328919, 3043543642781042627, 8165188141191133555, 425528495989022204])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([15049605758183325008, 1772109496835095208, 16381810441830084526, 666262592071917064])),
-                Fq::new(BigInteger::new([11748814429607526378, 49893829629375117, 16664039787891810374, 2428762018206565528])),
+                Fq::new(BigInteger::new([12191173376765638233, 5195181247222699139, 598473791348013057, 2310342967904314808])),
+                Fq::new(BigInteger::new([17285818182268780831, 2580701376574985828, 8883932666360592271, 932147795553740669])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([10013097761846048559, 3203406415646553538, 16162754007091302111, 3122289313671521113])),
-                Fq::new(BigInteger::new([10516885989999868235, 6507390615874837011, 15674329931542056322, 1096963013071229039])),
+                Fq::new(BigInteger::new([468552179054484302, 16119328102680921537, 14117417563402135903, 363069692151263846])),
+                Fq::new(BigInteger::new([16748657464932852406, 17785513846159640968, 9214181479903091137, 3316307792010688937])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([6732528093085065581, 11319142054808042284, 10043626469305834546, 1560630302389163530])),
-                Fq::new(BigInteger::new([2152746974836388146, 916723622163206956, 16785887852615676067, 3022531164905783059])),
+                Fq::new(BigInteger::new([6537406720139409882, 17653852093311334783, 15014117944069903891, 570957654612572965])),
+                Fq::new(BigInteger::new([16926668731534440157, 8317539471711869644, 18239714750070757130, 1681850885290573929])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([3302700079380052828, 10645082071330562975, 12734187142634572719, 619708600864450939])),
-                Fq::new(BigInteger::new([4832799678022616513, 9224546126359811956, 14526336075254985780, 1604658796544429118])),
+                Fq::new(BigInteger::new([14122708966255225834, 18221272500436080179, 15122294454778366766, 2183511436474513713])),
+                Fq::new(BigInteger::new([12964223281217890581, 448173803515533022, 5998622858462877346, 1337548488304095237])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([2876918304217255337, 1401833960550833767, 9101860095738575850, 2253108710311424474])),
-                Fq::new(BigInteger::new([9743972548652370489, 17748147369020077078, 3870722123050661200, 3239786308304934476])),
+                Fq::new(BigInteger::new([12997036867541185573, 12726945946729526562, 17972120044446302129, 1752539236701994369])),
+                Fq::new(BigInteger::new([16724682291289569209, 9015067604804069065, 8480903122472766716, 3167502714377073650])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([12141353114122299985, 14392583067105942990, 16227713286981469559, 1419970796354233006])),
-                Fq::new(BigInteger::new([4549823941899019968, 5324996640621881707, 7831901521562554175, 2529465535011742423])),
+                Fq::new(BigInteger::new([9884645689941179365, 9540108542992140609, 6236109961106184769, 460008567264344058])),
+                Fq::new(BigInteger::new([16325920471863240214, 10357715017799903731, 17676620437695669772, 1892788773964015661])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([1687844786942114114, 16326323634960319127, 7101418702871384912, 1706853112805305230])),
-                Fq::new(BigInteger::new([18059530172872013662, 11265303633595691451, 6799758337200212676, 1068575364101937756])),
+                Fq::new(BigInteger::new([4854723428902798005, 9104617642770771763, 3181592235193349937, 2811758974754117337])),
+                Fq::new(BigInteger::new([6877588237937722973, 2393055273943245596, 8750968593993095736, 3183061151886729160])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([4437246372099000031, 12841203143500128889, 12954723387995711188, 3459729335930966285])),
-                Fq::new(BigInteger::new([14419603966983049425, 2069195232259507089, 6993422981565224181, 2602055536847571927])),
+                Fq::new(BigInteger::new([8379697745965295652, 12321228053297358230, 15554739400010229496, 564630124827073490])),
+                Fq::new(BigInteger::new([13492038540533389996, 6077322692052770395, 1328445105945267742, 570042718861731330])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([7006459020161332264, 5704058549850928656, 16292622951069958766, 2364413508382320395])),
-                Fq::new(BigInteger::new([15615830231195521246, 14412509213281147264, 9413746551360082905, 2734594005660525168])),
+                Fq::new(BigInteger::new([10078467324481424423, 2728957923336976006, 5056586543863394671, 1621172945005739349])),
+                Fq::new(BigInteger::new([14454425088341252659, 5050540748547665973, 17538926365931137279, 2954270443707471008])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([12767058155953042435, 11571002113638429710, 8186093978900628360, 698810367624533706])),
-                Fq::new(BigInteger::new([10631240098299674007, 16784342669955871903, 14513482604681867931, 556132002555302614])),
+                Fq::new(BigInteger::new([16055405210098419827, 14721831558385369338, 10256526867823058471, 2309261670158640460])),
+                Fq::new(BigInteger::new([17245014913821585685, 17397910722883001423, 6372605746829300931, 182747075749715456])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([3694077121881776344, 14349328655468259712, 16243535212683861474, 2216267595184957327])),
-                Fq::new(BigInteger::new([404019328388856075, 856319840209906555, 13068585273964293089, 1112469441305450261])),
+                Fq::new(BigInteger::new([3085717056742449434, 7078881868314454513, 3686301128467950982, 2266819471994072502])),
+                Fq::new(BigInteger::new([13972514893925583849, 7668580042847108951, 13926669502042793593, 3268233009433205654])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([13495526102986457509, 17810457321296087544, 3292140372967086916, 2909101520201418777])),
-                Fq::new(BigInteger::new([5162980819383043727, 15483638811917891175, 15562509625811002049, 2061249864380379557])),
+                Fq::new(BigInteger::new([6727249676621113998, 3050001469395735139, 1633847378830133142, 1356133897538247442])),
+                Fq::new(BigInteger::new([15533632936651283538, 8961406254141819307, 17672635878062472614, 2664490865782676028])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([4860360403733495841, 2947405101232087347, 7205502178683855476, 1946052564538639603])),
-                Fq::new(BigInteger::new([5872968058101879900, 10110919926615810058, 715512031768033085, 3364066639801721097])),
+                Fq::new(BigInteger::new([9385150229817506388, 14774165582831825743, 16160294558019573485, 2677345627856469900])),
+                Fq::new(BigInteger::new([8581201997931795945, 15926892571163227430, 15328449283615661555, 1531163126363715185])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([8004370260688921833, 1397531620335399347, 5339054914418174736, 2786977211766830008])),
-                Fq::new(BigInteger::new([6703599193747587729, 4868799780462952452, 10175281875345871164, 191955886852580696])),
+                Fq::new(BigInteger::new([2517802101806960712, 761528101127881527, 17281386923470896764, 2710151797441243256])),
+                Fq::new(BigInteger::new([620805109021079103, 14189327810102447438, 4098716979267321990, 2870550233328561716])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([3937050236294151028, 11015118215715250416, 15642173713393368556, 2270631457590969676])),
-                Fq::new(BigInteger::new([8232400287656202230, 11720547887280727544, 6339189610585487497, 3034737860539983039])),
+                Fq::new(BigInteger::new([13612798698030413789, 2939630333072701762, 14311982164713722101, 558028682318871995])),
+                Fq::new(BigInteger::new([9050919505262794854, 17982196097726870816, 13848188998224130866, 2118593165266831103])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([5349350431373272055, 16434137802507351744, 1318701067237910488, 2790256288453723483])),
-                Fq::new(BigInteger::new([6106767575639803028, 10103403313804696866, 12967162283475349813, 70090467418016982])),
+                Fq::new(BigInteger::new([17602296775999240706, 10508120007738745988, 2296365122391671849, 1387590529350703354])),
+                Fq::new(BigInteger::new([9070066253029267179, 6242700849982108385, 1613457936149591628, 1117661633589850707])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([1484101511253743082, 2526651213561601013, 11551669992083167110, 2111576613147225519])),
-                Fq::new(BigInteger::new([9571428795771721900, 14588246832816667713, 18187593645241125353, 2733942659599633630])),
+                Fq::new(BigInteger::new([6809105045530332979, 14804415597306427174, 1376053716389341978, 3083473666869108669])),
+                Fq::new(BigInteger::new([5527395831079804323, 13751381687888723980, 3389815024811847111, 2765161618760251297])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([10215556935369247849, 12402689159645276109, 10711404554888465741, 1968100030418763295])),
-                Fq::new(BigInteger::new([6635932494466961263, 4366638923647861333, 13299530202446310953, 3101706867176347497])),
+                Fq::new(BigInteger::new([14347210446992902938, 17633409484409016654, 16205705410705178869, 1794051181723490033])),
+                Fq::new(BigInteger::new([7518616299550140948, 9366115567841260292, 14669188413293319301, 2016431952099530448])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([14692757219362524419, 75016040543513796, 37371559185268012, 2557912768304079395])),
-                Fq::new(BigInteger::new([8518377971765054325, 17795732882916758494, 1526510537661064571, 2377434998328493939])),
+                Fq::new(BigInteger::new([1210427037554502305, 5099654351440275511, 6780015979444548206, 3295020814035679745])),
+                Fq::new(BigInteger::new([10891809996888504966, 1423699883513345179, 13517564647804799063, 712715497841148490])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([10238795228410538141, 15225990017970617214, 17264121442747952288, 2248593711954583530])),
-                Fq::new(BigInteger::new([9450642335364250348, 18372933196639469511, 6483854112960194736, 332694832369602853])),
+                Fq::new(BigInteger::new([12591910192469163352, 244851945580543565, 14845194204672300608, 1788648737139641748])),
+                Fq::new(BigInteger::new([4578202542200299096, 16687037060294366116, 10558086180064411566, 925307391369419797])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([5064281614220915410, 12275370465012655538, 10258247511327463627, 157792249627396640])),
-                Fq::new(BigInteger::new([367805983526396551, 4046993348212523992, 17008884043384524784, 1413856180636026591])),
+                Fq::new(BigInteger::new([14805956361149491815, 17393219696441706234, 5557066789633234432, 834604950223183463])),
+                Fq::new(BigInteger::new([9443243435154188681, 12263190721315518905, 18431389043340505967, 1062611828453130258])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([3260614005340947640, 9550434329645777608, 16678876126065320499, 759184297527482176])),
-                Fq::new(BigInteger::new([17759387838052940079, 1134748988965262898, 9570379442184112819, 3366970250877322192])),
+                Fq::new(BigInteger::new([10466097868705270418, 9781947555952706342, 6412592648407299389, 1182639211778970361])),
+                Fq::new(BigInteger::new([11901972051188255278, 164762760390338063, 11093767382369920223, 417569052783996458])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([5062174733942901985, 17529896680877991262, 9432147664623536738, 945404907464825665])),
-                Fq::new(BigInteger::new([18328644908767717011, 9645139968401180712, 1529760239215220303, 1239236563523380484])),
+                Fq::new(BigInteger::new([5388431185311201335, 5433617212347851902, 3579362420125919212, 1107076294720113860])),
+                Fq::new(BigInteger::new([5546197972123892544, 11002275653072732264, 10175641587331944671, 65732939559661087])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([15253531678778211489, 16793121006556677016, 2574574486486918764, 1053776638626581665])),
-                Fq::new(BigInteger::new([1721326496050710596, 14381738138061524412, 10566655494075125941, 1219118676467493437])),
+                Fq::new(BigInteger::new([7341573490219244352, 331012737371061781, 6787309604520013903, 634443994916190313])),
+                Fq::new(BigInteger::new([6255251807301932601, 5162454962315870383, 6280207148090194317, 2366222764533303345])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([14757694435420568923, 15814975376533250825, 8469410702023934421, 1969229348326038422])),
-                Fq::new(BigInteger::new([11326848596636695866, 2755589311226546818, 12633137070127450053, 2148959093534669635])),
+                Fq::new(BigInteger::new([10761491470701319792, 5926642387527169573, 7723215570733102207, 3421676825500894019])),
+                Fq::new(BigInteger::new([6819333908234170946, 2408929183887323187, 8045735067792071525, 1055108186061667968])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([16674509169949860539, 12491464360700858337, 16448636702502043645, 1659368800820592880])),
-                Fq::new(BigInteger::new([9554975107753601801, 10313558195860747868, 16849126253811095137, 3096333782661777344])),
+                Fq::new(BigInteger::new([2804883827655038568, 8027486267659939942, 6637529347613770487, 2005284674505703850])),
+                Fq::new(BigInteger::new([3428951877433355560, 6049807916309910953, 10107028304800489418, 1050620134794246565])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([10770352962588365685, 17685750899826815590, 8932335741730226010, 3070368713574569893])),
-                Fq::new(BigInteger::new([1635905342349797718, 14896294169938712528, 3653885141041316786, 887376404230359032])),
+                Fq::new(BigInteger::new([9840470643879775484, 4829146044183594192, 13661105307617932964, 2349267276733719554])),
+                Fq::new(BigInteger::new([16194640638971838928, 4693759886978601071, 16455151822290689726, 3008306122269246210])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([14641189062703351, 11669905043627203551, 13293420545971981565, 457712304856019837])),
-                Fq::new(BigInteger::new([6824159773589018089, 7054118452111510980, 1972687825737348194, 2245484496486191334])),
+                Fq::new(BigInteger::new([6207718068639342987, 1309556733826085154, 18414514319384344193, 3436193672149672459])),
+                Fq::new(BigInteger::new([15348118769679388791, 1737009630222730485, 680432736980909225, 2107323372275121818])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([8211800263703625124, 5145509083462448098, 1017214464367983040, 1991910134047335854])),
-                Fq::new(BigInteger::new([3707922991505036642, 15940560596693344175, 15976882758624981641, 2733167375745406958])),
+                Fq::new(BigInteger::new([17329210339506128480, 1587352142060584753, 11303018380267669858, 2650755732823713818])),
+                Fq::new(BigInteger::new([6471645956949187480, 2160366507269251924, 16509197290829470494, 3395739293562938454])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([16865739187524015636, 10618428090262662587, 9871079639258848324, 2743704722631425195])),
-                Fq::new(BigInteger::new([16844746485805623580, 4362000155750548056, 18045685326647640954, 3193467527172111442])),
+                Fq::new(BigInteger::new([4429642067259206932, 2864725098433168496, 8651982407563693474, 3486105935470173314])),
+                Fq::new(BigInteger::new([10291499041414923626, 10839299130404082924, 4619173524325197831, 888956976226276551])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([16343443302203021874, 8221169477699064712, 30124458207484539, 84704922069192903])),
-                Fq::new(BigInteger::new([8311226910976212629, 4949979547328513855, 10293711852414841730, 3380586293706266461])),
+                Fq::new(BigInteger::new([8335811491251909405, 17832921817721024182, 2489621755036944525, 1657945184506976304])),
+                Fq::new(BigInteger::new([7036232443129451839, 904265190940001082, 16742171446346980912, 1200830882988074156])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([14860777787304521566, 8933144170132523240, 2766673534132619328, 1060115189109552727])),
-                Fq::new(BigInteger::new([13044678727208478882, 17918629212379844746, 123145465625294880, 3032100147582668060])),
+                Fq::new(BigInteger::new([16574971476334976179, 13725952420730559782, 15974753855047828631, 148335938473839334])),
+                Fq::new(BigInteger::new([5002514048971663363, 8687359882791095721, 15849227755884289816, 2733953756305988880])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([9870387016691730305, 11036262200070094481, 511835077711173944, 815381132713190470])),
-                Fq::new(BigInteger::new([13850400383741341604, 3799512604342834976, 10969748840168252957, 629226900642725892])),
+                Fq::new(BigInteger::new([9191707530756503541, 17355051148686750190, 5991299511393582484, 1205030311240636158])),
+                Fq::new(BigInteger::new([15879141703191005880, 17517118412751980266, 9670957456851363350, 2644913238967146863])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([8893230830939819238, 7910761909280906967, 17205615243850641083, 1558478930680661371])),
-                Fq::new(BigInteger::new([11961266357374462404, 10283159207133761962, 7344250476029874550, 2062251893482562985])),
+                Fq::new(BigInteger::new([17773804803907468456, 17884731842095488819, 1263700832874835506, 1186484809409436598])),
+                Fq::new(BigInteger::new([4496802745102993567, 10977510805392705692, 8211839423189375641, 626257195210832297])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([3978434881292846760, 7048526623713827534, 18182716702859017222, 1907193641733267458])),
-                Fq::new(BigInteger::new([14225268926808801872, 1489842899960359906, 9081455036666044219, 2363271838821028034])),
+                Fq::new(BigInteger::new([9421275699602094181, 8384147010383113417, 15650660854612406983, 81566997118267102])),
+                Fq::new(BigInteger::new([6894897230244348405, 13052803587064883402, 6045409105432921343, 1780585338505366485])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([9236491134124454944, 14516308223026503981, 9943981623737293930, 2306349075574169346])),
-                Fq::new(BigInteger::new([4445441309438095467, 7599951649044055374, 11253957890730933272, 2418760089058234069])),
+                Fq::new(BigInteger::new([2712280129495555132, 1123725169129007640, 13669931215802911497, 2194133962836731715])),
+                Fq::new(BigInteger::new([3642001492228109985, 13816873864328186155, 8781391191429513838, 1560995828445073525])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([14617825062549989143, 15508531632723816825, 7438896822584810225, 651207417227047870])),
-                Fq::new(BigInteger::new([3570916353588862055, 5384697145753971887, 1228726896970530256, 2318597074047878751])),
+                Fq::new(BigInteger::new([11352893118708164676, 17450800075108735862, 16967003634498400340, 1393539267808215613])),
+                Fq::new(BigInteger::new([4780811149969360006, 6547449974653273965, 13981273498757243218, 2131091877593919534])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([4904671854487482434, 5732804074703008025, 5415601929100206144, 2595160798667890518])),
-                Fq::new(BigInteger::new([11807695875331624128, 7695317215218630556, 17961426462401620870, 1395175459036456632])),
+                Fq::new(BigInteger::new([1071357807057155270, 8234764586334185571, 17722957018628245177, 404034161028808650])),
+                Fq::new(BigInteger::new([18215239177913523105, 11919037941171185748, 13509759260496677231, 1036506962270641558])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([15727530799113813780, 18170062695321526911, 4212451092309603899, 1915570351168781984])),
-                Fq::new(BigInteger::new([3192177519035390973, 7154733345339489157, 4844055955801080775, 1776345850672489446])),
+                Fq::new(BigInteger::new([984264157884156157, 12234160500123568771, 1303764018368761831, 21388853442806958])),
+                Fq::new(BigInteger::new([1285823890726840594, 11937077779886969164, 3708908615717385131, 378919159249746430])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([14348672578940495808, 1531631517119205601, 13537232608429088250, 2441210865452014620])),
-                Fq::new(BigInteger::new([1934960307313032004, 16132114608174057654, 2889658638875798448, 34899593943580985])),
+                Fq::new(BigInteger::new([13553386920690152128, 12636035969360489784, 12933906424373569000, 3080184601395624990])),
+                Fq::new(BigInteger::new([3931179210608312226, 12901186972633641668, 12381548150351976666, 2583940909409968312])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([12662566065503690898, 13531408109083095516, 10642025012061039083, 2936020685130060910])),
-                Fq::new(BigInteger::new([5034878357190422230, 16543188538995159293, 15318507822606658642, 1686801489658583633])),
+                Fq::new(BigInteger::new([10024060319963571474, 17135772934454249279, 9234692095502813946, 2899799477855289863])),
+                Fq::new(BigInteger::new([2109325680789775953, 13735115146016322127, 14209659429804215985, 1621762483893935442])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([15131590965910942041, 2686031093336131916, 3535031024690479147, 3298435103935122865])),
-                Fq::new(BigInteger::new([6525722106862755249, 8840239376790967877, 7903948218623168022, 2799511467616443425])),
+                Fq::new(BigInteger::new([13495334048400125213, 9008696179238448295, 12938944225877300735, 2368074902179949473])),
+                Fq::new(BigInteger::new([12727384658307801416, 17701618016532923695, 1103872527305610255, 181417424123847645])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([11195929919316100990, 4034344714837850776, 10231327960848893320, 1923058805724428245])),
-                Fq::new(BigInteger::new([12135315201688798068, 1683244432112154972, 17555043421213822571, 1292667630409906171])),
+                Fq::new(BigInteger::new([10582245881442548410, 15201388973833599475, 17160753744468270622, 2158993586735874938])),
+                Fq::new(BigInteger::new([592587084009489693, 3071595427914852124, 15053098481424786389, 160970753486636630])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([8664629831451828014, 7388214204139947708, 365686186488508311, 3474416942551526440])),
-                Fq::new(BigInteger::new([17809298664564146601, 3756769723325609283, 3596181126085277081, 1582946653526604766])),
+                Fq::new(BigInteger::new([15634102455467719002, 13921581893595366212, 17918282241953578036, 2741846549332348675])),
+                Fq::new(BigInteger::new([7785775982890279470, 1490693645401005197, 6753068597271812655, 489560464196607504])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([5296560271033937538, 6029651168725190454, 5948616336665608736, 3059769423698409152])),
-                Fq::new(BigInteger::new([1157666758954144957, 1220016377327638931, 10470030948886600735, 538014221411217999])),
+                Fq::new(BigInteger::new([6400314454915356086, 15482917223557543174, 9944442850277284527, 1959298455529654373])),
+                Fq::new(BigInteger::new([2749784181884173102, 3852326269280074172, 15691758517033185349, 3328630200437777031])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([16174012280198788420, 15947180511160812944, 6151335226151069245, 2112149300354316403])),
-                Fq::new(BigInteger::new([12088081671735948932, 7597640704960343714, 2260539303344753667, 113304810854594339])),
+                Fq::new(BigInteger::new([17047654078304033927, 17900002650722807312, 4096721741328016992, 1235892865513510480])),
+                Fq::new(BigInteger::new([7056427168128656056, 10771904816248200688, 2175750108418525152, 1236370909856237961])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([16931766076269015001, 7215247661892795170, 11184809381823554990, 3060179944593120619])),
-                Fq::new(BigInteger::new([11679565454599844397, 3651068586477950865, 15998131064360569638, 708340111497063207])),
+                Fq::new(BigInteger::new([10581120143441097359, 4077059222330576432, 15497112522464977776, 3212120719295067790])),
+                Fq::new(BigInteger::new([11362289495652500411, 8012420710095612674, 2491968942282358266, 3291351140067278609])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([5683832311890304310, 11859332846435483735, 4906356335280578146, 817195651401215255])),
-                Fq::new(BigInteger::new([17913603466739864722, 16091563694696430497, 9914816836310632653, 1555854665013163271])),
+                Fq::new(BigInteger::new([18266975991443222196, 1994230390197958912, 16308103199885495066, 1971120817544922373])),
+                Fq::new(BigInteger::new([16098980279755601549, 15927798020727517335, 15678667728559281112, 2929349104331315052])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([1174245449916223126, 5956423897867514773, 13289304156858528924, 1938357909267051767])),
-                Fq::new(BigInteger::new([8684691901726763715, 2946249944586570423, 5461526376593235481, 1266234136584343613])),
+                Fq::new(BigInteger::new([8245492365652664442, 15611628388030477978, 15749434835599454851, 2991544548088062804])),
+                Fq::new(BigInteger::new([6381271612889548040, 10156285997723460494, 14700871437032092815, 2652665176445975615])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([11775108113057506036, 14542037136730110057, 4660789506503456731, 1358947899894591497])),
-                Fq::new(BigInteger::new([16274436203843017638, 226549474013299040, 3424082833662119796, 1596775468462433336])),
+                Fq::new(BigInteger::new([6116792117247423971, 13197154604644336961, 17521298649430806040, 272083797042113810])),
+                Fq::new(BigInteger::new([10731261530445310554, 5787587450205840411, 1727282084966072559, 871484875105240570])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([6299895970072012011, 13842662465547399996, 1156000676115644427, 810661390063720004])),
-                Fq::new(BigInteger::new([3354480590094581586, 885897894621947385, 11358458105613664847, 377761945666053553])),
+                Fq::new(BigInteger::new([9798694437055136682, 9106681269303453457, 3103695664704273828, 1970101462196155602])),
+                Fq::new(BigInteger::new([4146345982561710226, 3230323075690806105, 468156658525682192, 3323571870958686186])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([17311367635885853116, 13273877775165744493, 15206679779460787717, 395493188676221141])),
-                Fq::new(BigInteger::new([7854970185987093554, 7597553762259824709, 2155825251535014236, 2368299188642708657])),
+                Fq::new(BigInteger::new([16769293987942056739, 5096611654819154453, 9032747151458946524, 1862682904678388617])),
+                Fq::new(BigInteger::new([3239118827761542193, 1646278605127071538, 12229459916133929029, 1625888626798889143])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([11825530470296125828, 1062703053090278927, 5321469409084764813, 2283622409214687173])),
-                Fq::new(BigInteger::new([5127224717686470877, 4924413153802507368, 16964044251458326121, 3339996888801544684])),
+                Fq::new(BigInteger::new([722238916089290704, 14506037404939997154, 9993560337811803275, 1650845610492038007])),
+                Fq::new(BigInteger::new([17335757663214304068, 3258089010909092737, 12860524266352681399, 195738562988347455])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([15486496049053183439, 37058696638335004, 9508510056795176887, 2608128208806313143])),
-                Fq::new(BigInteger::new([3989532606210748853, 14265614444594099974, 5868023223388101837, 1316715943439382063])),
+                Fq::new(BigInteger::new([7933884588525869445, 12166262816646265212, 9935958202967411382, 3099874574003028766])),
+                Fq::new(BigInteger::new([17186980852703643018, 16510748861016410974, 10146019643355625184, 1225834704766537430])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([12785766739668557669, 3300388866202092761, 14651393520351346048, 1893299472897856416])),
-                Fq::new(BigInteger::new([12966885822122829792, 3168483376980365674, 11011633610477309711, 1892117030267810549])),
+                Fq::new(BigInteger::new([9869736407989751969, 15618097515300745479, 1570171963048480368, 1000207313870705727])),
+                Fq::new(BigInteger::new([6976188952661542097, 15021669713748474745, 18155862676979723222, 1262850867395398820])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([18371651075165919088, 18280730824453931188, 12531086421765275916, 509870849710883817])),
-                Fq::new(BigInteger::new([3446482169403434906, 4541688894869177516, 16742234244860026140, 2554634316624376828])),
+                Fq::new(BigInteger::new([15581282287356062640, 13276397447007008607, 16815407193774056076, 1688776386933397944])),
+                Fq::new(BigInteger::new([596640435501645065, 8166117114752968579, 10812659035470031280, 263725131885995718])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([3932697651237728502, 1425681925337100945, 13049490636491998812, 2163925721393111029])),
-                Fq::new(BigInteger::new([10686972728477409404, 180221024150323628, 7510039493870311203, 685193535498491218])),
+                Fq::new(BigInteger::new([3015323783894560867, 985294518295457664, 3474625628659806696, 2618005718111702930])),
+                Fq::new(BigInteger::new([4758087270118064143, 13541272387411751592, 17715816211412774700, 289292717154625851])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([11773724737270722176, 15647800599398820926, 6482562008045584275, 3415798343531818455])),
-                Fq::new(BigInteger::new([7751459623471351409, 13198989407017959752, 13283982219887889058, 835497993386569549])),
+                Fq::new(BigInteger::new([3490207954950515230, 14042274081570025533, 9260205917388453601, 3314223001311378319])),
+                Fq::new(BigInteger::new([6399818821870355107, 13125392374604441887, 15811221192633804615, 57821251913599898])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([12846415643748362734, 18382370171014464779, 7373317613852214735, 1648887733479649188])),
-                Fq::new(BigInteger::new([4274548110730870857, 15554595837268367146, 16314587973054002809, 198308320636384735])),
+                Fq::new(BigInteger::new([4239264126786347452, 6104266883894980711, 723333153756499398, 2960217995385398228])),
+                Fq::new(BigInteger::new([17971456312412982642, 9663471063458214493, 2499460139986165335, 2256889687566420166])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([11681493432878462016, 3513436780775789490, 9006192994184028004, 3011262688700067719])),
-                Fq::new(BigInteger::new([7202759339107555455, 9049893952282911240, 2735264139526981391, 659357739520772315])),
+                Fq::new(BigInteger::new([13159001935954194952, 544271585817922139, 7244430658826030699, 1539918229084547393])),
+                Fq::new(BigInteger::new([16143969327917473582, 3345845622789806802, 17423948113145947619, 2403772551491057669])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([1595222279155357473, 6182117293568669633, 13429998622770078152, 842284639755089971])),
-                Fq::new(BigInteger::new([9318298273932275874, 4567600076163230723, 14982199446306534782, 1038575906522705572])),
+                Fq::new(BigInteger::new([7424065032241701011, 6495620331466663750, 15974790360081877185, 1604196060718167306])),
+                Fq::new(BigInteger::new([18043624842273367148, 1506136492357904708, 1198514324896983867, 3333679949861421588])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([6671825347554961687, 7097225072771552895, 9740512445623100617, 1540004351561724478])),
-                Fq::new(BigInteger::new([15252528695955006802, 7213982721421419954, 12939431549803054950, 1931518871675505674])),
+                Fq::new(BigInteger::new([10073568520609552105, 18274449824815899636, 3501466313554169348, 3113404184125045427])),
+                Fq::new(BigInteger::new([6109704587054065890, 4965971427053772363, 3123448441611523304, 2732839025069063063])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([12330248050924632282, 10479303296915359712, 9642327482167528333, 1210144163809528544])),
-                Fq::new(BigInteger::new([9499935273450801180, 15100612604577922160, 4199930119491146450, 2011688487908484251])),
+                Fq::new(BigInteger::new([1141258918259839342, 10020255671657330641, 1401977482008764086, 1969291514512925299])),
+                Fq::new(BigInteger::new([3167189055643246014, 17928210256519768181, 16129257915331540598, 2520265668735649157])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([15551085834660986289, 17740647325757221581, 203149931117216058, 1044206950289883853])),
-                Fq::new(BigInteger::new([10844817332428507846, 11824267982965934255, 9957693808640814525, 2712098165288562929])),
+                Fq::new(BigInteger::new([12423063123837997818, 4940086409583350959, 9562294743730347991, 718000884660828399])),
+                Fq::new(BigInteger::new([3489672731064047046, 11137062578461491446, 2061729554874856248, 1773235928330523281])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([2762648477277279660, 10369093563567136828, 1310239176201156597, 2877512115899708373])),
-                Fq::new(BigInteger::new([13960409117469519275, 3427388641819608860, 13993632414060383808, 733943277775337547])),
+                Fq::new(BigInteger::new([1092874119948934749, 6966920405679340101, 12078851311011804084, 2675891588897496745])),
+                Fq::new(BigInteger::new([11237393261598491751, 12986506959096635673, 9034488829005071179, 1276083256787032639])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([6240116069210208465, 2250928241504098403, 9419519883095306623, 1767055000286782439])),
-                Fq::new(BigInteger::new([3937301568760547025, 2302774531547138850, 1408062418510657758, 2290506408640388907])),
+                Fq::new(BigInteger::new([1052073620691632731, 8575333620974088755, 11084014562338392621, 3154593487369053987])),
+                Fq::new(BigInteger::new([16976587978919803993, 16044570260050395018, 9007152552984870867, 1795893309053220057])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([18298269251078375112, 14256800723037734828, 4247865476184747620, 1008102042367898864])),
-                Fq::new(BigInteger::new([5805166105813454116, 17618139199747080297, 17493863926954315057, 3452255969067944177])),
+                Fq::new(BigInteger::new([3140651704983117589, 8027541119563518566, 12859115720563688506, 1558774257238601979])),
+                Fq::new(BigInteger::new([13966455601468245998, 8205585343369180488, 15229216623381500503, 50468581937870363])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([9869344071247614464, 9693259490908258043, 8313450659569132086, 2322842349588137155])),
-                Fq::new(BigInteger::new([8202903533284065784, 18217374805850387505, 9822677347492676145, 1093338334147730686])),
+                Fq::new(BigInteger::new([13692874096308608538, 10564435183606105188, 17067732631043372865, 643126976971923826])),
+                Fq::new(BigInteger::new([16149618185365335181, 16599058809928104247, 13595293216540986048, 2901262453463818960])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([8164080718394953313, 1554784115367159299, 8737487053358137672, 2130859219672641249])),
-                Fq::new(BigInteger::new([10895718302596995446, 13323122826530623947, 4369626562544743894, 1302141468474008244])),
+                Fq::new(BigInteger::new([18363425424493501376, 15077547200185222724, 14079895658742805671, 1545338990452354467])),
+                Fq::new(BigInteger::new([3830415238336386018, 4806037231428270980, 13160548026751245938, 1434676691417366434])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([133302993004556006, 7485701627057679113, 12442125515972788690, 2530368665910038764])),
-                Fq::new(BigInteger::new([4960080449808580028, 3729982612891908695, 3300287533380936303, 1081458389817728939])),
+                Fq::new(BigInteger::new([18152065247766761954, 2962689848769510577, 9177764506576533152, 49954332334501914])),
+                Fq::new(BigInteger::new([14679974594390323415, 9641172715477216781, 6918427200574388833, 928358829373903230])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([9208815467634204702, 1907877152422977820, 13296052431024596974, 1463011086421518467])),
-                Fq::new(BigInteger::new([7129148164256301838, 17664460141585580632, 12954914200227788624, 2357087279433992648])),
+                Fq::new(BigInteger::new([5839469628609789380, 3486937069065007785, 3214564664698894213, 2051139575585370498])),
+                Fq::new(BigInteger::new([2049799856785930320, 15680927021346302234, 857946956907764989, 1750448257658120377])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([5469757257369594983, 13854413671501877101, 17496910719094469349, 99175794018500885])),
-                Fq::new(BigInteger::new([14908052951841345650, 3749881328579239572, 8383625314613509196, 2581213935471997485])),
+                Fq::new(BigInteger::new([15576213174575898730, 17064923091573531726, 15230590516489394218, 2616635870016061856])),
+                Fq::new(BigInteger::new([4842387492683177816, 6050352402176266601, 18379923414235415256, 2103330408780611192])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([5795148593001265192, 2017741964306636135, 10510660366438657725, 1010762081870522460])),
-                Fq::new(BigInteger::new([6968018662596070303, 935821549047504657, 5758390803442918809, 2736934215643827238])),
+                Fq::new(BigInteger::new([10930743118816828822, 4836254962731845342, 13386715913880829890, 3449196626354112812])),
+                Fq::new(BigInteger::new([4115060329388657153, 2691349213772033315, 8468441734432720070, 393024560929807538])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([1208328724058420538, 17506385008440435874, 263218673105087509, 1779367392111274863])),
-                Fq::new(BigInteger::new([11430624528278807052, 6088528607019881794, 8532833911831150278, 3370882926705401358])),
+                Fq::new(BigInteger::new([17491073605300066999, 12374181787111602397, 3310614390848256054, 2566756286648357583])),
+                Fq::new(BigInteger::new([14488769294837397825, 11162575785434881858, 2354822922644698732, 2685645982368466297])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([179274104662225678, 11509280531288037012, 4493219780247385097, 3039165414468302770])),
-                Fq::new(BigInteger::new([338233539419896742, 6745871296643084024, 834525621615363425, 728889935054800815])),
+                Fq::new(BigInteger::new([16585445372579437544, 16496165642158784098, 18284688408846150395, 1366923993847707843])),
+                Fq::new(BigInteger::new([10034023539093079833, 3300284356281274465, 9634645323473715128, 822314217940182451])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([1530200215002180201, 6274434591517413697, 10142622935831114041, 1012163971997945719])),
-                Fq::new(BigInteger::new([14188183915579215194, 7337290499756655010, 6435285380437541469, 889876780512645005])),
+                Fq::new(BigInteger::new([5657919258648959496, 8330187292415859252, 14894645249492857760, 346389145328233180])),
+                Fq::new(BigInteger::new([15529457529246879615, 5203513329817159950, 2154772461150181976, 1860325840552338947])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([14644399660171572369, 15252788657799628922, 690290102841518367, 1713800239544807076])),
-                Fq::new(BigInteger::new([8981903245614988776, 11176012369391456364, 13269511498156554492, 1727780595130084560])),
+                Fq::new(BigInteger::new([17442051985158487231, 13191666045624086500, 15989000777274644039, 55938840074173085])),
+                Fq::new(BigInteger::new([6648551415028216934, 15808832679867023428, 8722783616214244735, 2616658201109705013])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([5564933781851272736, 16500248227687964781, 17531713716226562400, 2533091351895418356])),
-                Fq::new(BigInteger::new([18297090940383844765, 17105613928450983941, 15636463721796877058, 3218294726800726518])),
+                Fq::new(BigInteger::new([11413681450795363078, 9785505681245014371, 7589488964769061761, 1156153287514574608])),
+                Fq::new(BigInteger::new([5969074422209664465, 8081272480641921873, 8333012270257933379, 367191806645515428])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([15744455364288554808, 13614924933473891203, 9132718842852780339, 3048193951181070170])),
-                Fq::new(BigInteger::new([7543476054060310629, 18175553234136218334, 18013063162975282874, 3349271687431637394])),
+                Fq::new(BigInteger::new([18093287411265672136, 14054245562599139093, 13468690202277756933, 2684554296450725759])),
+                Fq::new(BigInteger::new([4416486606172104721, 156707973112158349, 12839711410282112211, 2226013071415508322])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([15342715466180175983, 1607263939159210123, 7485805128706054939, 833190922142899009])),
-                Fq::new(BigInteger::new([3272898337574231826, 3209095663300799230, 6168714672202900230, 2778956417747503101])),
+                Fq::new(BigInteger::new([12682489269962854905, 16824224915525443308, 7387117328999969873, 1782947511364345224])),
+                Fq::new(BigInteger::new([12674023883555053928, 1877155176385138230, 8129001858756855508, 174929245818636123])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([2963326352723993541, 11011326213375688136, 13116313418058477925, 77274229306386215])),
-                Fq::new(BigInteger::new([7262206914310264519, 9307554412843221507, 3214472567509881655, 2951874139274690472])),
+                Fq::new(BigInteger::new([6149303126732021826, 16871848808494456615, 18154852451912700150, 368231907608031927])),
+                Fq::new(BigInteger::new([9096201698324407486, 10647414267901836674, 5070263005623222743, 394269394853386866])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([6945553438806669581, 10501125164338746413, 7625192182787861055, 1004012875042172262])),
-                Fq::new(BigInteger::new([9032814497473668782, 6751848716925989889, 11562134988000049167, 1888375830180380562])),
+                Fq::new(BigInteger::new([3921471584801035911, 5336417050830492914, 4041225236970251175, 230596659863933236])),
+                Fq::new(BigInteger::new([10064667128112788863, 13619661021420035611, 17492908552561417994, 1588570824912994027])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([12535059499631158042, 3349259301781148101, 3868265710658403310, 2166023376291084502])),
-                Fq::new(BigInteger::new([17961706572292172813, 2947000240430899585, 6821054129575691583, 423560640856630871])),
+                Fq::new(BigInteger::new([16295727165507424234, 5442883708734855849, 9010382058913893991, 2636546353399730953])),
+                Fq::new(BigInteger::new([8153854044742194299, 17702483250707579790, 15257096923174263380, 482943164372811938])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([6721588193128151764, 16716116217136752974, 2922072312968554258, 864794930809825058])),
-                Fq::new(BigInteger::new([137363305605009875, 682353718869680150, 6201209344563109409, 148100192860852747])),
+                Fq::new(BigInteger::new([13695265491412548812, 2014739447989618551, 15442266230684103505, 2312467688795804082])),
+                Fq::new(BigInteger::new([12198769724167504159, 4695665956677054087, 16257383090424901498, 73137579632745644])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([9554990098558251184, 330720443619003403, 7243405208445231047, 1181419409377579903])),
-                Fq::new(BigInteger::new([11455622303432394090, 5435780681421880558, 17170134798928608793, 1204373850151768449])),
+                Fq::new(BigInteger::new([12604830806099919812, 10341659208638703968, 4715092694613117954, 2252212058979389689])),
+                Fq::new(BigInteger::new([4063839944820304769, 15606873841546118835, 11019661219342858171, 2412281499678895485])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([11915042868778501986, 679933233319471498, 17224870122991554680, 1083922010396939536])),
-                Fq::new(BigInteger::new([1728166040806940871, 18212157984494383148, 13628798140646322079, 2422864004710926698])),
+                Fq::new(BigInteger::new([12359356861293099989, 7541015466639871406, 202522410901918899, 2053607866280231830])),
+                Fq::new(BigInteger::new([2646422762882930171, 1703283668590915208, 7231481797953857582, 1418069008007405615])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([9033350799948144792, 4847898047455692259, 5808828166191049694, 2776609518222787697])),
-                Fq::new(BigInteger::new([16271327260233343059, 15532233356046680716, 15460944886904789443, 2230715169598814716])),
+                Fq::new(BigInteger::new([9359951728652651652, 14311923755832007955, 8095244228567246267, 297648245081398567])),
+                Fq::new(BigInteger::new([5516263468987976005, 2619328180994503975, 14612514497389101479, 1156123453738249187])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([87409664302540434, 9549784281521209717, 9023667654351863483, 3224975738105731628])),
-                Fq::new(BigInteger::new([4936318907496060562, 11141366251454187735, 9556869033478374235, 1279342316263969033])),
+                Fq::new(BigInteger::new([6392701072502177839, 16839976837721034111, 15434021158493199113, 2900506049010682213])),
+                Fq::new(BigInteger::new([8181137095504079422, 456145147625142811, 6773333392915502927, 223294458548052268])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([8182152979853221725, 12351453737494826487, 14302695888433030848, 3388112014765968087])),
-                Fq::new(BigInteger::new([462575462186670954, 16509522689949490073, 4880639004850478071, 2080794469753040])),
+                Fq::new(BigInteger::new([7668325773171527475, 11012888271552093469, 2190111795016000044, 2691248365941595921])),
+                Fq::new(BigInteger::new([7233276232624386698, 4806289138818436103, 18227073243270833238, 954961197068429040])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([18285757571646550913, 2798739860219134676, 3258662492634547285, 3071159214196581714])),
-                Fq::new(BigInteger::new([2208347688069677142, 9377570792888734975, 1031193428137728372, 3223094498092755387])),
+                Fq::new(BigInteger::new([5094392262259944328, 7538604398326339055, 11964802838651885235, 2988491052043943550])),
+                Fq::new(BigInteger::new([7677780103699655740, 12512388351282840101, 7520425191445583702, 574274982166178976])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([3848609155755919051, 10679967006954742306, 7413904816958782857, 1545246162558660898])),
-                Fq::new(BigInteger::new([7379776847350269933, 12408979697028265816, 3988331156625791737, 1608922106916335065])),
+                Fq::new(BigInteger::new([3292190221894984813, 9673687116443712799, 16182568699702063787, 1036629547291037050])),
+                Fq::new(BigInteger::new([6508189165774950755, 17304081078982674826, 1744385817396092561, 519120310734502041])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([8133241835250023026, 3964439131178501339, 5556285786434799621, 3385491638808139525])),
-                Fq::new(BigInteger::new([3524970636021688859, 12687824632470324254, 3938199132752243753, 3218085066797617144])),
+                Fq::new(BigInteger::new([1274993171084853464, 7967006124716820138, 10350847141168421168, 630651952581944564])),
+                Fq::new(BigInteger::new([2173795943083822399, 9796971699898411391, 12980083698606452397, 2301926938223365956])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([17760763084198841294, 5769816011823220310, 11187812362558904773, 3254071164529840472])),
-                Fq::new(BigInteger::new([3568504062077902456, 7577666735077523549, 14128512265514181760, 449712652667352464])),
+                Fq::new(BigInteger::new([7660748632341726483, 13765948281095166687, 1076421962536329048, 3460082009463404434])),
+                Fq::new(BigInteger::new([17017505331450601539, 8535539727270518469, 13989533118619043488, 877513886888464603])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([5374894502198265041, 11457809361367045683, 1688597148491550032, 2475178571036250518])),
-                Fq::new(BigInteger::new([2566734103790932897, 12502028951946026385, 7703978305632127792, 74884770303694983])),
+                Fq::new(BigInteger::new([16666481777049982706, 16583560073503566205, 11259108418742685048, 1211229747268271725])),
+                Fq::new(BigInteger::new([3700643420050873114, 11532215811732949542, 6529801237068298596, 1628946573736864247])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([17003352643431454503, 14705568703694182484, 4125190692292112269, 1216736838825863891])),
-                Fq::new(BigInteger::new([2467846022796130142, 2795288986299354345, 10759343209044587885, 420981707192370896])),
+                Fq::new(BigInteger::new([1941119241956067146, 9794278293805066317, 12234531242204015718, 889738302251577742])),
+                Fq::new(BigInteger::new([17655523590644634776, 18319029652694614964, 647409683181791873, 2809368160348500177])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([15881956377763484119, 10520507647976082899, 18205353405802608056, 3396817889378263061])),
-                Fq::new(BigInteger::new([3036562061992706459, 10882326830302178307, 11915683364533761870, 2151503149878072885])),
+                Fq::new(BigInteger::new([5806746188336998018, 789647083593443066, 1279225873083735973, 1857140424819040645])),
+                Fq::new(BigInteger::new([8599094410912028593, 11879892151548651811, 1975292755700893784, 1128990875913085272])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([7658536292245807938, 8129109716360315215, 16562145053871961036, 3439156904837311141])),
-                Fq::new(BigInteger::new([1451105548184625620, 10636954118937854687, 12384126147229857336, 1082133083305861192])),
+                Fq::new(BigInteger::new([12733849864550642143, 17862909503307199729, 14870877281554489306, 2232907343437304434])),
+                Fq::new(BigInteger::new([13215449913387452482, 16734020651497143762, 2510352195829308509, 784665111547340887])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([1760965211184914450, 6060703578020436928, 10447592731733515964, 765596512807998798])),
-                Fq::new(BigInteger::new([17438975280050093142, 116673863168001700, 4740008330660811282, 1072385720300146032])),
+                Fq::new(BigInteger::new([6611994683751008164, 7640655260473058785, 10855722231166561246, 2569367226792671779])),
+                Fq::new(BigInteger::new([15445105545947903081, 561012323665207414, 8903985768756512210, 620858988042531841])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([11675147074896162643, 3209324100759909412, 560084667982417667, 1310110891037075605])),
-                Fq::new(BigInteger::new([10291558591955360208, 12305477694150343831, 11522548244964030734, 1506831040251115083])),
+                Fq::new(BigInteger::new([11270433424507052921, 442030780252808190, 17344155976380380246, 387106294959485775])),
+                Fq::new(BigInteger::new([8809366779374087350, 2522401842063370842, 6723269951748485582, 3383476372147403359])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([13708010692607961662, 12559036398633894749, 17397340961246482100, 93178460733331827])),
-                Fq::new(BigInteger::new([3901483802127971280, 11411899821433048417, 8658457131597367188, 492270568309855096])),
+                Fq::new(BigInteger::new([17604561634616946814, 57872299746264540, 4649812565810666180, 2469600129948475444])),
+                Fq::new(BigInteger::new([4628969718430962779, 873162700294314455, 18260394196555508874, 2208743682327827887])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([2695998046249199659, 8993423459217374826, 14175538017596277158, 1874126645794931814])),
-                Fq::new(BigInteger::new([15125682284504242717, 14976424003722315307, 12990317889348801254, 2617864172091271981])),
+                Fq::new(BigInteger::new([426588562992612101, 1617007727872932012, 1795163317886883223, 2937454389649158949])),
+                Fq::new(BigInteger::new([13958217953706789853, 8248175911854862172, 4176543980908226469, 3142108813900168222])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([18400360872472161554, 5466408295922613973, 4203791811507973943, 3449367969371349324])),
-                Fq::new(BigInteger::new([4039716602619605106, 3231680592801830759, 391742439796069046, 261690702095917009])),
+                Fq::new(BigInteger::new([8676770541664819304, 3086791404952445954, 10960094184540135450, 112739093243890357])),
+                Fq::new(BigInteger::new([8916967501221001338, 10698546921720795233, 5561699706114413460, 1514064790037774689])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([1770286622009578914, 8431218427205618643, 9083001087049562999, 1505080595797587110])),
-                Fq::new(BigInteger::new([16238139067407458634, 13819807114577036948, 5580009439443244727, 1216161171047356122])),
+                Fq::new(BigInteger::new([3532627116620950087, 18341978503303319558, 4593400907458913871, 1767302481986814191])),
+                Fq::new(BigInteger::new([8847416875603752403, 16473008003260727374, 8983663351113356738, 2868737993513274101])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([10002138788413328170, 14137757972732563371, 7285418881017714688, 644268702442509268])),
-                Fq::new(BigInteger::new([913311463239666889, 1350404846746422302, 8276018617791338425, 1906906920118862734])),
+                Fq::new(BigInteger::new([314985360182273473, 1675909805411402683, 10840932593638610765, 300640236211401637])),
+                Fq::new(BigInteger::new([3063948398448384005, 11896849732154932812, 5967180814595909766, 2235026382970004802])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([11306299355112847162, 14776936844031847522, 1526572112439632775, 983101308204836165])),
-                Fq::new(BigInteger::new([1593761209500415040, 17381105176254716357, 3806816094864119528, 833545304293593515])),
+                Fq::new(BigInteger::new([13748820212488735974, 10272024124032019566, 1960576766929038577, 3392996676389933268])),
+                Fq::new(BigInteger::new([9499053459826052780, 12050316248250582859, 6506922594944845205, 884430117007611047])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([13671253879285022546, 15517055721165880965, 8836260549060580589, 1786928685312554426])),
-                Fq::new(BigInteger::new([416854522082227793, 10810618687911089413, 6977147378577339377, 1696064191235642480])),
+                Fq::new(BigInteger::new([8838082437195997376, 13313084447062283592, 7856073941125333077, 2392537239804881275])),
+                Fq::new(BigInteger::new([10710059788406096962, 16080163392441625095, 13974083697334749575, 804863177962472417])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([17611412394755369689, 15694740872018754817, 17917500151213987334, 3000094575113447589])),
-                Fq::new(BigInteger::new([13842417492926286660, 11734728914550425355, 9409222556387436410, 3363595187937050925])),
+                Fq::new(BigInteger::new([14811152125277519530, 10576489054696522985, 7503793982100852939, 2079757465715282986])),
+                Fq::new(BigInteger::new([4495643438333234280, 1584484331328092942, 613580444461488127, 3103512548069817952])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([2725737042807363594, 17814690796349492092, 2101323990464123498, 451616556257020293])),
-                Fq::new(BigInteger::new([6952305558298692666, 13825077347995636235, 14387536895889651220, 271501559401659612])),
+                Fq::new(BigInteger::new([10299789968609550173, 15571742597469610859, 13744929272861931047, 3479454020874653251])),
+                Fq::new(BigInteger::new([12386417264509199428, 15146987237948406455, 1856180443100173315, 1447465021045482569])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([8010348082991369995, 619646986403054120, 4959108818008380243, 2180004134165651085])),
-                Fq::new(BigInteger::new([12374287420919507041, 18027764676269502786, 12930461866941474054, 135108521459210742])),
+                Fq::new(BigInteger::new([2579333544870694226, 3927266729464904916, 13451930743777046293, 727839243239767242])),
+                Fq::new(BigInteger::new([15129236344395728848, 122950328511231567, 7163471293853996004, 1350000706817506466])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([14434426528062669200, 14046439412132326400, 5870964685643922013, 2127942293099232983])),
-                Fq::new(BigInteger::new([9206649285906100390, 5733220084631783974, 9930016270835364474, 193520815813297519])),
+                Fq::new(BigInteger::new([15508694773120801888, 15429911739215386548, 8593150427857352102, 2210764060391930500])),
+                Fq::new(BigInteger::new([2974456371645422606, 2290179130212032811, 7070758803936237200, 3239258296245867985])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([13561329633905688276, 1197752259732799502, 11060214853302906736, 1749269163931431312])),
-                Fq::new(BigInteger::new([13777484912344668407, 3078568976004400121, 17129347450381158052, 385357481550239603])),
+                Fq::new(BigInteger::new([2390608053052934075, 11119164041542914724, 12636574419094619678, 2362862994032832719])),
+                Fq::new(BigInteger::new([15158118896133991672, 16296644142241315124, 15522383403345413375, 1941468632348232381])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([13913690063228741245, 13559490002525683718, 6467401214966264679, 1399556903715602597])),
-                Fq::new(BigInteger::new([10150332173045671773, 16248972580531081139, 7810571117548636116, 49147055004754850])),
+                Fq::new(BigInteger::new([1897396394097251338, 9025969316025293975, 11962384854596512251, 2622032714378645089])),
+                Fq::new(BigInteger::new([12194246914587275576, 7141165637008832815, 6455908967632180897, 1733234519669190796])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([8095269416521943573, 1500275803776695761, 8080363950749347555, 2184541126807224266])),
-                Fq::new(BigInteger::new([5144256692281851603, 2637304193572995502, 8622490126986560785, 3206023442710339492])),
+                Fq::new(BigInteger::new([3455368417161770174, 13892514176528770358, 820214103812945114, 2403163012519191708])),
+                Fq::new(BigInteger::new([5055332886474279154, 15278045983700164154, 10369696756074161830, 1009958054762550477])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([9444243419745287677, 3560370507966315353, 3711114419663301570, 786966222221965146])),
-                Fq::new(BigInteger::new([1642655450023348012, 12433260832625652932, 15605047209162597469, 3079700128251691053])),
+                Fq::new(BigInteger::new([7380749886736613066, 7074515867297113709, 10334984355135090748, 1676763635577078667])),
+                Fq::new(BigInteger::new([15637088376823307863, 2296960185157011413, 1472266892652301536, 1662137141571040900])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([1661794332561896128, 13324123457260260629, 10083305777110057958, 1415648514590102212])),
-                Fq::new(BigInteger::new([9182066250963918951, 18276110394262656392, 1413078488653797801, 291645588793434115])),
+                Fq::new(BigInteger::new([17932474879702769204, 5588285309512284850, 3031437171908523932, 3349758087574945473])),
+                Fq::new(BigInteger::new([14030034927976884134, 919515194673366864, 3680668199929768439, 993811029797966417])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([8839644183409421118, 11717024574283042738, 12472252693364590527, 1534891283505833746])),
-                Fq::new(BigInteger::new([5479219918304562424, 16240893394417415364, 1153558019747472408, 543653415392271946])),
+                Fq::new(BigInteger::new([7440596901286161036, 9035068080079051271, 13130495495278426414, 1957967812359325700])),
+                Fq::new(BigInteger::new([11551817849558504965, 16044611170217734412, 13160720480566008637, 1755864251504968925])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([6951173638495408941, 10145148904184340912, 2643234320570724290, 374704215044903292])),
-                Fq::new(BigInteger::new([16138817609542570879, 10447018628566956645, 5306371575271569233, 935841691133271278])),
+                Fq::new(BigInteger::new([654316266674308912, 11097836519599427988, 7752201591914882070, 771659491611144231])),
+                Fq::new(BigInteger::new([2764189343336458039, 11437527084533155913, 1613068877936092030, 487792067950781907])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([6358967563471060006, 3292300097988342848, 1494459930266777678, 916729998470399266])),
-                Fq::new(BigInteger::new([10524172435840464784, 1778535827281980229, 14533260573401172126, 2703992550915861435])),
+                Fq::new(BigInteger::new([1434482683025568127, 17221526144261638531, 5929014545720597259, 1645541391928806455])),
+                Fq::new(BigInteger::new([3432785970861926088, 4167562409977918660, 18366916326911784062, 3356009141283993283])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([1964868005303088662, 6236173092974966643, 9035454995821001876, 3278502508632315895])),
-                Fq::new(BigInteger::new([9971736379888090923, 12864835526025166675, 18429904575057188940, 1410506036625829608])),
+                Fq::new(BigInteger::new([1501933410412791721, 6345181834788522482, 13728612316911790150, 1671734280484611552])),
+                Fq::new(BigInteger::new([13705566485741188681, 16872405553754183213, 5475112509313528221, 228388007130710126])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([11658794098103994940, 3154583518459301983, 11813300279725644410, 204355249804388116])),
-                Fq::new(BigInteger::new([8970264896069115408, 10249689394034359891, 1585876168812014203, 2877669925242880234])),
+                Fq::new(BigInteger::new([13247237826784337249, 9415174244682001877, 13427242099533300075, 1862105693389690865])),
+                Fq::new(BigInteger::new([11010572031913357656, 6109885308503471794, 2241417388545681417, 381324297769557773])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([7414782623315906178, 1182109710200245467, 2269453322166211306, 1015301276260879576])),
-                Fq::new(BigInteger::new([12304246558807308491, 14350488756390022350, 7033823740305888555, 395819484352169616])),
+                Fq::new(BigInteger::new([12125114179081698953, 6574900025068776111, 8564549205765013386, 741845642509381639])),
+                Fq::new(BigInteger::new([102038965350315035, 3307182705321110768, 8065149847979489380, 1721384795871698148])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([11366582000466998181, 1159239783267137974, 11350927748289975898, 484207478416659401])),
-                Fq::new(BigInteger::new([5723791094057208562, 13589414890254128259, 12422865017755461981, 1722854759208634760])),
+                Fq::new(BigInteger::new([14588267516555140427, 6917376221926316400, 16732988650094517792, 2095855430705682852])),
+                Fq::new(BigInteger::new([12553310531368712591, 553452522009244939, 2932003992137752846, 545758979004441248])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([7831391768898144923, 8841841380261880255, 3514763317509818458, 1134877977974400338])),
-                Fq::new(BigInteger::new([6786682127653361312, 16803542276758360514, 13175780644615698208, 2685633170718214259])),
+                Fq::new(BigInteger::new([17570316721623778256, 6488519816204123884, 11064745373228654611, 2889008498761137011])),
+                Fq::new(BigInteger::new([209971179363994385, 10536038731908167783, 13783517883793519274, 3352191360008908817])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([5848230658618323116, 13542613057971391430, 13874868415795568915, 1222926378655825583])),
-                Fq::new(BigInteger::new([9939419925866406836, 6915710691359258950, 3830314666379025837, 3188654131810956799])),
+                Fq::new(BigInteger::new([1457829619413321076, 8482615718345079591, 13622161563039047635, 2529631718003906165])),
+                Fq::new(BigInteger::new([15249173835149652601, 5425199488017799711, 15817422849777642897, 2648559217214154781])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([2976419227219968556, 2777627812078121932, 13226927843076413259, 1467270386295749186])),
-                Fq::new(BigInteger::new([15670309819822599113, 4684820591804262536, 8144553152813956354, 1961288177534522285])),
+                Fq::new(BigInteger::new([13486484851919800949, 374147384794764296, 12082941979776813648, 677906312503042801])),
+                Fq::new(BigInteger::new([9175225391641832434, 6725818145823063295, 4743967193110814632, 1891743665866317452])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([7209244384550801049, 7050292813524033987, 12669701995318607270, 1292517508207647338])),
-                Fq::new(BigInteger::new([17432049526160114322, 11278869092286469807, 17541001303368465773, 460442438487513920])),
+                Fq::new(BigInteger::new([11715648294912962560, 12555075985828027659, 9453571241407413416, 3459327663670364352])),
+                Fq::new(BigInteger::new([13733683113655189793, 9355805448947679046, 4612689965456070634, 1216887893751390906])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([17784066485621286914, 8484057772939743123, 3924560215638273243, 2681021251597697672])),
-                Fq::new(BigInteger::new([3872807872172259112, 930618873139325687, 8536633600308294803, 847869633242742660])),
+                Fq::new(BigInteger::new([4854495646788386445, 18347366436805889905, 17155506168231511031, 101261086203296594])),
+                Fq::new(BigInteger::new([6789006663044657591, 955656255569878383, 4428549293901320877, 1775955749488336057])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([9491995882451277203, 15342759465631574175, 15074114743402249062, 1980518444991327360])),
-                Fq::new(BigInteger::new([4542418136829975855, 7495603909195543319, 10819324110501745325, 2020247270734474502])),
+                Fq::new(BigInteger::new([15652837588151245832, 14381666282253102766, 2173307092053597354, 3388782484908258427])),
+                Fq::new(BigInteger::new([18181692999253728199, 8784927019031142049, 4118515483064860401, 1072521752223183147])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([5418200073543792240, 3235355677232675217, 10618270221827380857, 397367795181744782])),
-                Fq::new(BigInteger::new([309785560958477192, 1765652238897187949, 14633803398467485514, 1661984510628327003])),
+                Fq::new(BigInteger::new([3057228697087765224, 10042584715089142381, 11764298159600439685, 2449961333390497470])),
+                Fq::new(BigInteger::new([1092977019252747389, 14764722390384216032, 13659559770859939706, 2004386545739207658])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([15848273858815155227, 12817338964568292084, 4094773558713913394, 310902476697882768])),
-                Fq::new(BigInteger::new([8871592446727020830, 9831502096312736415, 11541275145377034350, 2061017082277245891])),
+                Fq::new(BigInteger::new([2972167712318684889, 4865616292797514883, 3157399494430765111, 2095651419831378444])),
+                Fq::new(BigInteger::new([15043013678282645661, 17463522683472977914, 13545875678393259429, 1558469275158505481])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([7163300897322929018, 6701773737639901743, 13027575834987324552, 3046983135530666682])),
-                Fq::new(BigInteger::new([12489863005124332111, 9816878905930146333, 724833965784991156, 2529310299470507068])),
+                Fq::new(BigInteger::new([14086379548647203472, 6215324132038767429, 12092861972710280414, 2691144505241864809])),
+                Fq::new(BigInteger::new([14107913999999900487, 8486200710372577839, 14953880201166914271, 2938733575627759315])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([14684894645620993492, 9618193061870378876, 18094584033351888043, 170364830560541620])),
-                Fq::new(BigInteger::new([13831196365087733219, 16166002577399103870, 217187093710798282, 3485811407739826728])),
+                Fq::new(BigInteger::new([5915517644204721575, 16819352197380383005, 14974543202147025397, 1772000684247443883])),
+                Fq::new(BigInteger::new([8140051767970127979, 4810804564892599001, 4320226048199047, 238946107738046346])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([6526247464503066958, 1823490401498818459, 6609173878903430829, 513551568995973315])),
-                Fq::new(BigInteger::new([15114407903715264879, 13879416776060003760, 4027491840266869903, 1765118813468160401])),
+                Fq::new(BigInteger::new([11910363055554051328, 12776368546499473052, 10158882995978709931, 977363801770510803])),
+                Fq::new(BigInteger::new([15280166846874469158, 8391117663263712161, 7177882353504141190, 607408410844823274])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([11193663887117703119, 11661441869064181140, 14748145173586047982, 999279630925518902])),
-                Fq::new(BigInteger::new([4408326585269580911, 17127528885096660448, 16669724662466328259, 731697374980283800])),
+                Fq::new(BigInteger::new([6026960366633317521, 2307602483828038897, 1618414043176960221, 1987364976345196001])),
+                Fq::new(BigInteger::new([11738102249825844030, 9734767274678903178, 6074755857253370096, 2244662886575828761])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([17621564290622480415, 6423712730665059227, 2774515737401070734, 1510897977904612730])),
-                Fq::new(BigInteger::new([13362715616571624068, 6941236267499919764, 1567447620701081548, 3443614753050978750])),
+                Fq::new(BigInteger::new([6465561999772094004, 1991073044729224822, 18336420922094783567, 154494035459860127])),
+                Fq::new(BigInteger::new([8695159750637307731, 13241888794593698695, 10544258862229794533, 2387063108904378971])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([17005464264062821609, 15818595255862916941, 16287800551360479886, 3002671061014502705])),
-                Fq::new(BigInteger::new([16629767514968876559, 439575953345452284, 10363490011786395868, 2777891831283352936])),
+                Fq::new(BigInteger::new([2504670031509133917, 691683907014325548, 3264716196174656524, 885029405370594106])),
+                Fq::new(BigInteger::new([17111505485710303181, 7736865561266679049, 8448725402990798718, 2127287518331231569])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([6007432226002406415, 11642084040662403528, 14176490281495084920, 700272932243416612])),
-                Fq::new(BigInteger::new([16479150346347452917, 872109035745529595, 14120769146283922394, 2278581442021162293])),
+                Fq::new(BigInteger::new([2952460502677961411, 12397554548830671409, 1038567427788641464, 3124449038642363820])),
+                Fq::new(BigInteger::new([3623710985134424862, 9422881810637794892, 18388036584980920497, 2307599082016315967])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([18119261304555174600, 9045731462463953215, 5171641472406291121, 1656993587700699870])),
-                Fq::new(BigInteger::new([2205147991241319698, 9608833290847663876, 9170170505855198503, 2782256554096140189])),
+                Fq::new(BigInteger::new([16856835167135556546, 747358480681043261, 18368889691631737510, 2702573842607961593])),
+                Fq::new(BigInteger::new([220840250728562066, 3739423577768432066, 6965636209795820962, 1274473989926767561])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([2741706483054143627, 10102701390955793717, 10482062353543511207, 1597382131871692682])),
-                Fq::new(BigInteger::new([7030445297143450622, 1224101696731004998, 15089451877503203418, 1493107186917230543])),
+                Fq::new(BigInteger::new([7793912613181744044, 16237367757895418944, 12175452610313221483, 374415163828391969])),
+                Fq::new(BigInteger::new([5131891538845296023, 7322480928085634400, 3346796089954091344, 2479761180111257051])),
             ),
        ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([15993664470153585139, 5691940427916233940, 3710691140396136673, 2835106509965165461])),
-                Fq::new(BigInteger::new([7067249915659635115, 2993553624859892877, 145016168405649630, 2056587248284640676])),
+                Fq::new(BigInteger::new([511890770297423673, 30286792583790190, 16438581147300862507, 2206385609647494606])),
+                Fq::new(BigInteger::new([8606143112190932259, 13747400949520676050, 17792444089872379779, 3000731928626707131])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([13653712556336545143, 528444111695763934, 3003531248046928973, 905714011206420357])),
-                Fq::new(BigInteger::new([5171481343724823570, 1585098018586222188, 16697443069431668821, 402409738082048992])),
+                Fq::new(BigInteger::new([412103270665968269, 10593633596390326517, 16653910048110095484, 1780919997478424303])),
+                Fq::new(BigInteger::new([10082053938147358023, 10303216608087145191, 13832077337142282000, 1873443118975171416])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([12298619249162891283, 1788194105981018471, 4948432957009907217, 2007497819345005038])),
-                Fq::new(BigInteger::new([11401296506304295590, 1703599279362915508, 12189479623313404033, 1628902865584609228])),
+                Fq::new(BigInteger::new([13019837702663802634, 9596134519385364633, 13508261406542027778, 2636482431277811936])),
+                Fq::new(BigInteger::new([453525816247757680, 5569088094233013193, 7075245904394004932, 2053880935031337516])),
             ),
        ),
     ],
@@ -1439,1276 +1409,1276 @@ pub const DELTA_G2_NEG_PC: &G2Prepared254 = &G2Prepared254 {
     ell_coeffs: &[
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([14324789117364416683, 14490262058850069162, 3653605163071257386, 60082541589079573])),
-                Fq::new(BigInteger::new([11231429134207825422, 4840767886443801565, 8057944930704388073, 626779994044673274])),
+                Fq::new(BigInteger::new([7465403023205272469, 13909528479505583793, 16359928329550713359, 736542877909539431])),
+                Fq::new(BigInteger::new([14348799136310682425, 17092593753038228891, 6252883487549491715, 3458998400691590134])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([18416472730599692375, 6638548170548693358, 5947005007532772796, 552289632534936312])),
-                Fq::new(BigInteger::new([9662848263066237396, 6021190226641776787, 8021696080693853551, 3299477481684387979])),
+                Fq::new(BigInteger::new([5805296390256089659, 14298654350944787268, 13467106669105974440, 1816890946958692249])),
+                Fq::new(BigInteger::new([16323222340165166717, 14559749602190429446, 6304507077643881031, 3164485244373099756])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([142336993628765561, 6437116530843843217, 7086186872940727723, 818667093625423803])),
-                Fq::new(BigInteger::new([6320051210825745153, 10789191824757877043, 5935824165156324094, 88508321179136496])),
+                Fq::new(BigInteger::new([8744210127329726600, 5191351205753752228, 10538658506868395536, 1773773852195046851])),
+                Fq::new(BigInteger::new([9989864185723082284, 14971663752423922282, 6246293295182653296, 1323655868231226610])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([1235075253524082698, 17691303846612411062, 16561018495799440423, 715593764790888756])),
-                Fq::new(BigInteger::new([11692269956845747028, 6101770438131544204, 10345848863279401784, 185039013578695418])),
+                Fq::new(BigInteger::new([2381971731732939810, 4824329585732994354, 395646506928656566, 932187847974115490])),
+                Fq::new(BigInteger::new([10045825009976921474, 5417944157615319580, 18190808788799454732, 1815364552213084593])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([879458928699019415, 6919115008338563142, 18356481919736519689, 601903509583898570])),
-                Fq::new(BigInteger::new([440525508514586700, 919568915018532823, 16391980817212648196, 3030324632222608986])),
+                Fq::new(BigInteger::new([986807581767740100, 2916915582915496251, 12916294185621462991, 3298134071927959517])),
+                Fq::new(BigInteger::new([10422682959134342613, 17655402570524454354, 5189180551180672468, 3053258389281237865])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([15164025458140463446, 12809267216925362047, 8002999961078524726, 1725367038175889975])),
-                Fq::new(BigInteger::new([14768266373858569345, 3344438218880615924, 11547292617646752170, 732319751652166117])),
+                Fq::new(BigInteger::new([14540242866222802676, 2832253072647102442, 2538096168251060878, 1170502506694603909])),
+                Fq::new(BigInteger::new([9583142002341803954, 17203030964549802671, 399012438318222639, 761315679165066311])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([242976328244725986, 16393171555884503691, 14940337006682079224, 2540162739052429511])),
-                Fq::new(BigInteger::new([16214298402470572952, 18339093272153192188, 6412968514940527728, 518879711668376489])),
+                Fq::new(BigInteger::new([14587383783820768937, 17583468808771536508, 5976517869926403361, 3005785102667639910])),
+                Fq::new(BigInteger::new([6521590344905733560, 16601788509023368239, 10345932312464709390, 1928319589377354169])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([14535062431032594469, 9767881687793172613, 13162105196349816746, 1334872352269856458])),
-                Fq::new(BigInteger::new([12606317561526646362, 12159406873146608641, 7227348706683954362, 1436492237459809286])),
+                Fq::new(BigInteger::new([5251157466671211439, 3795393559949237029, 9194601860152401013, 527549634281483503])),
+                Fq::new(BigInteger::new([13942002422563967253, 10124378999250545942, 4429742824164695700, 1596610134806539966])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([4126149126305877685, 4222491789902438404, 6791460606807183967, 192313580150519030])),
-                Fq::new(BigInteger::new([13373223951917976474, 3864270389337132553, 13562336788476470556, 1845573380876031889])),
+                Fq::new(BigInteger::new([1915032138594181368, 10551204756625384329, 11532709005235121338, 3174137139920727017])),
+                Fq::new(BigInteger::new([8876260403828320029, 4086395144820504466, 4821270860369293653, 3246159249872903380])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([3487476262132017235, 10995382684284547102, 11070386433222225227, 2466308900403656501])),
-                Fq::new(BigInteger::new([17264554815806099708, 253580313078587149, 2544938603847935601, 1229605968358468046])),
+                Fq::new(BigInteger::new([14393767398982741070, 3810348385550652523, 8748136106152843285, 1572821930530301280])),
+                Fq::new(BigInteger::new([8600366842442319975, 5420143257076582547, 14611978382610906126, 2674039178904984484])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([13240272000780016360, 4593489134157172137, 11140544477586104523, 2180499415742409052])),
-                Fq::new(BigInteger::new([18310837191889141606, 9998911814181340508, 7483878445099300688, 1282429418711850447])),
+                Fq::new(BigInteger::new([9229657184309772362, 15686993659241556739, 1135037280910692426, 2813768977060116068])),
+                Fq::new(BigInteger::new([3389036975521245449, 9479401062237219798, 9357127138065890017, 735835054622225449])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([2650353496925697197, 15967060914594964667, 14089302867057179369, 163321432968192829])),
-                Fq::new(BigInteger::new([2512062340776613770, 15288353617000349692, 13508312433993223086, 2407503780330026946])),
+                Fq::new(BigInteger::new([4620711783344869835, 16554455490662577914, 18100801541362097785, 2153748896749375405])),
+                Fq::new(BigInteger::new([517170851299782000, 16518565026071674392, 8761737747441046373, 930714557990575157])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([6782978194393795344, 6536136987713354336, 16175421815859522356, 2835380308673514247])),
-                Fq::new(BigInteger::new([11433819142015696765, 18148387167693476256, 16896212766688352374, 1731342539712272856])),
+                Fq::new(BigInteger::new([559142875336577297, 2601359611282031095, 17084663678195037681, 2467031668021873829])),
+                Fq::new(BigInteger::new([18227128546160851201, 8515877066426486554, 18120988628290890408, 481281574208495869])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([17273346499275153843, 12641841799087771219, 2790461482495900123, 2256854799247890158])),
-                Fq::new(BigInteger::new([4532482712845924386, 4105092460935106165, 14115694270513328773, 1796630039487037525])),
+                Fq::new(BigInteger::new([9493165135122163641, 9038231872599525496, 1297007279318590060, 3038655938204398324])),
+                Fq::new(BigInteger::new([14782507751070932902, 17248988776056313088, 7373487080545447928, 3335026330011133935])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([5690015414295180464, 584261555067562724, 14571850876893656364, 3307604102615466594])),
-                Fq::new(BigInteger::new([7269288489453294990, 6354083214766394725, 13838635065087763572, 1624447616185478564])),
+                Fq::new(BigInteger::new([6697411335293313483, 9807411664206302053, 1301536547009380759, 2659067106698187330])),
+                Fq::new(BigInteger::new([1101175830232953628, 15300931987906291743, 2361389139028629875, 214747778920878753])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([9147435371636183569, 14815099134806313936, 4138182082564333223, 2840061645949273907])),
-                Fq::new(BigInteger::new([2126755859391922211, 16825845559196818982, 7012177192388754699, 2514339183864372260])),
+                Fq::new(BigInteger::new([6915982197819532014, 701742430312633368, 8181961989492043109, 2178010322292838124])),
+                Fq::new(BigInteger::new([3438320880002564041, 12921941529037035982, 323639572693139033, 158030134491330922])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([7502788534254592785, 13896334771154309377, 14046831677461620347, 156616921596660548])),
-                Fq::new(BigInteger::new([1799376485587806200, 14358803172042158182, 15028701690136312329, 2115666538030577416])),
+                Fq::new(BigInteger::new([5368797334772015488, 4546237528694819006, 9012193717083867663, 779284777952677993])),
+                Fq::new(BigInteger::new([10035681684408557082, 13203572537694730559, 3437897989935888753, 1798822869974120270])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([12664498264133428058, 7650401771049584226, 2182096224439988515, 619756874959570920])),
-                Fq::new(BigInteger::new([14956214923225430346, 8251400486051662673, 3150545663294086977, 2030285911451173797])),
+                Fq::new(BigInteger::new([12787691444663242206, 14705675309209221546, 4494433531898640045, 3250895726578697279])),
+                Fq::new(BigInteger::new([10151642784066967865, 17446345886446608757, 14848802556860741523, 1179620465143809715])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([5032449858958155680, 6080042275501295439, 8192639391610200278, 2133269893311383491])),
-                Fq::new(BigInteger::new([4766207102735684306, 4746825532689949314, 967897898732492598, 234474851069473420])),
+                Fq::new(BigInteger::new([5077821589876766803, 6992861166724159579, 8045913799172133631, 3033214902029678867])),
+                Fq::new(BigInteger::new([18353506165298354822, 11124320260526695919, 15290565095305978035, 2764557372272989421])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([9882376173574893320, 4322544182162707487, 17189336240610171229, 447088980106729723])),
-                Fq::new(BigInteger::new([2216218254382433811, 8685200247619243158, 12266195721996782095, 2071825425327418882])),
+                Fq::new(BigInteger::new([6359155685099253609, 7657349540954778102, 2011750382904485251, 3002862714702461779])),
+                Fq::new(BigInteger::new([9814717326571379893, 14286386727618252709, 8731200977037399108, 2122597474313081297])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([10522042083330921159, 13216971678010695108, 9238205371940732251, 717116788743582770])),
-                Fq::new(BigInteger::new([15938976643053331382, 11924960317319103857, 14495734908554341234, 1549696627064644723])),
+                Fq::new(BigInteger::new([11333138058524257668, 6077503174362749494, 13075005325662043369, 826461106619441513])),
+                Fq::new(BigInteger::new([814583690943389774, 17560058486681077544, 2685072410126450974, 224071824374360613])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([10631607622525244333, 15041949933465711580, 11877234381415983612, 115114592119969935])),
-                Fq::new(BigInteger::new([6912502475784732204, 17877511896735341382, 17756976894150741140, 536444863652044819])),
+                Fq::new(BigInteger::new([12126478301524055678, 6578144228583172363, 14624034389664052404, 1121539495935823216])),
+                Fq::new(BigInteger::new([14705089242847274262, 13731036179922810873, 8833844790692865845, 913510295270125636])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([13016633244095342135, 7208788735258408849, 14906762119560089958, 1465890474193656276])),
-                Fq::new(BigInteger::new([4814336774339436460, 10201878160472058553, 1572140015383519187, 2320449563823989336])),
+                Fq::new(BigInteger::new([14888108122312554842, 8711571164250674980, 12048847713619184078, 2223619848830944958])),
+                Fq::new(BigInteger::new([14182580952708542766, 12563693092029518214, 15420843640456755446, 18199570573499933])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([5776653379110345989, 1268840216553433645, 2880107540578742537, 75148884961326827])),
-                Fq::new(BigInteger::new([11229556806691632176, 14249051897415878258, 6893451067352575276, 1856257288801278859])),
+                Fq::new(BigInteger::new([5110344208765097052, 8003816909135092790, 4458777284688366303, 401405727441527187])),
+                Fq::new(BigInteger::new([17716872824369630840, 6417147298428392746, 11070983675675822239, 628761400910371834])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([953906656321100893, 3681981693239676927, 12776150423340193836, 1127812656245433829])),
-                Fq::new(BigInteger::new([4011959536663005620, 4638230913287878547, 3007123954359750709, 2451927960053873673])),
+                Fq::new(BigInteger::new([9665155530017481570, 11175538534572899030, 18173208562247175459, 3316753459877892048])),
+                Fq::new(BigInteger::new([3477880573223233795, 453865727474497337, 1971085099738824232, 2167597765843894072])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([9407634212914310861, 10632357435166538067, 12218497082096810894, 1735811165668504819])),
-                Fq::new(BigInteger::new([119975808283148023, 14791443161227940619, 14267846588776449311, 54030948740825898])),
+                Fq::new(BigInteger::new([12079347705322902828, 9103405071560854067, 9730116104247276032, 91448331100425971])),
+                Fq::new(BigInteger::new([5961802524844398955, 12738604985920141752, 13443092536286292923, 2358232376962294524])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([7166074574984687299, 447194688953931362, 11441116964528229109, 3378807565854533922])),
-                Fq::new(BigInteger::new([17822410227952967141, 525098340570380583, 1497232714998841331, 1020501471144773581])),
+                Fq::new(BigInteger::new([13328620965857454949, 1097451931343912551, 8052078406407400338, 3475238347239142245])),
+                Fq::new(BigInteger::new([6133341174737869433, 2256247881775494860, 17234427411700269080, 2585713551927375323])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([8510454935578341176, 11638767640203323487, 15564021127025916858, 2133801391484259348])),
-                Fq::new(BigInteger::new([15328486971742158167, 5264813718086309591, 15662422453682444306, 1793748410148165181])),
+                Fq::new(BigInteger::new([3191908373494696202, 16879282369316471508, 739709972773640392, 2414416614747648310])),
+                Fq::new(BigInteger::new([11875591610956375957, 9288305223797129291, 17536023082064563743, 528817106374493354])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([16936522466304464566, 168814981742500572, 11687810360001702580, 2205786545163302758])),
-                Fq::new(BigInteger::new([9917848650890710645, 1160874957635103241, 2017763560448154436, 2136141511428807079])),
+                Fq::new(BigInteger::new([11274879117644967287, 3254741042415413042, 2682294378813112960, 1802889798323467938])),
+                Fq::new(BigInteger::new([12265855812992415357, 14088443275340154557, 16618172241270921307, 3458781650193902713])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([2174542256272624346, 9124470129895078235, 3001892416991162672, 2488470321105705319])),
-                Fq::new(BigInteger::new([7201967752678165855, 13169613121987075042, 6876852039880495845, 3054029928969800297])),
+                Fq::new(BigInteger::new([128964161796045985, 18099701519593772075, 13003602329676108567, 2839052642107389565])),
+                Fq::new(BigInteger::new([17447058655747957385, 17636779479348864398, 2276620048315947206, 750095225639722824])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([18078950686807916573, 17096953658803163202, 15629664162944988693, 1623872625751156327])),
-                Fq::new(BigInteger::new([15355731459419532108, 11372751574264598353, 9986354174713417402, 2842122468926050503])),
+                Fq::new(BigInteger::new([5132871591836181207, 3269044871380524065, 13093999576297672996, 3008273645450612574])),
+                Fq::new(BigInteger::new([13795663138339629894, 13413191051707174270, 7907808316180001768, 3054424625156939256])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([15524797192596440664, 14836551600446292357, 13689163420661841004, 1614726262446703532])),
-                Fq::new(BigInteger::new([16354035060867731789, 5906717915406973357, 12088948307096564759, 2286524477916123038])),
+                Fq::new(BigInteger::new([4450471092435593769, 764871392349624415, 16455076411424796413, 2615547771661934941])),
+                Fq::new(BigInteger::new([18187144091167114380, 16177067603605203869, 11974040410903486070, 1397508107552582430])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([7033456778826418812, 5254670709899984502, 2533044036130503054, 1434822048313222317])),
-                Fq::new(BigInteger::new([7839481857455891250, 3152706588074618257, 2632648206022388703, 2692079436098028538])),
+                Fq::new(BigInteger::new([12881147443832850353, 4422735012766279090, 15737187527087529153, 1643520214662869005])),
+                Fq::new(BigInteger::new([7877998583607036472, 6778463451682375108, 3883747256617690711, 2955262815982604970])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([3183585716097091109, 4396949349113812985, 14745213220705330721, 3378785530071517538])),
-                Fq::new(BigInteger::new([2766044852984865532, 13908635468927465248, 2254580483632044200, 3196668860794698625])),
+                Fq::new(BigInteger::new([6181237893512592155, 11059899962378680373, 2986421518022541610, 1265555921587226279])),
+                Fq::new(BigInteger::new([16433679800662119880, 15972042158266333203, 2008676211607813033, 667810506239774873])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([2349324618582522589, 14414731232120514326, 14895389404384783765, 3355546302099067086])),
-                Fq::new(BigInteger::new([15193257457494833008, 1151043064884654899, 13395361398680703936, 2702249608459408828])),
+                Fq::new(BigInteger::new([16885117386482354184, 13569472137676123355, 6292361577748798923, 3188106158367241978])),
+                Fq::new(BigInteger::new([3199400749836044039, 10720524516245827718, 2401057122241108342, 970558430815519384])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([16591387767801080022, 5091409081682331810, 3440486931285966418, 997191901215167587])),
-                Fq::new(BigInteger::new([4621597528285058538, 14791310634923924495, 7893134923910149381, 2681528204732227572])),
+                Fq::new(BigInteger::new([9890832664195408300, 1164518781082949870, 7894832952178645549, 3394447906144911293])),
+                Fq::new(BigInteger::new([783920774586502530, 12106885815567643406, 11406715459952998013, 1818404944303701028])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([9656732387999394019, 2514304629422510732, 1836495830072071006, 263827581927266682])),
-                Fq::new(BigInteger::new([13923777330575536178, 12734401323980719101, 6250190132272604990, 482979025423289112])),
+                Fq::new(BigInteger::new([10298242574299442481, 6430688491722923765, 16204347734113528167, 2072062468706261704])),
+                Fq::new(BigInteger::new([7406538784694202704, 15940160640375795742, 13614335746678474384, 3417871913021135367])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([13896221573615238642, 10861870006223257489, 14592777430227610984, 1309034467274510989])),
-                Fq::new(BigInteger::new([16751342182240836695, 9892711292202815623, 2439987039363336589, 2233266751310360330])),
+                Fq::new(BigInteger::new([7694902613207670595, 3176586932919471842, 10109675987033732996, 229794520340775677])),
+                Fq::new(BigInteger::new([11748370726135352403, 2501602488532153843, 8337389780943285716, 1702913393151311330])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([14281869318650617427, 2965963088393751621, 2342268105997177449, 3392081047572733130])),
-                Fq::new(BigInteger::new([11601561210147086714, 15459242166109783916, 16343306453995666651, 385918107371936381])),
+                Fq::new(BigInteger::new([14032433323674464168, 92923605350454566, 9448461858001283059, 361757529900846219])),
+                Fq::new(BigInteger::new([17014268886153131557, 2846767463770860643, 314892792714056917, 3016216322309768916])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([3875775527243791426, 8505048214261958878, 17311400528730113324, 1505358953505965015])),
-                Fq::new(BigInteger::new([7495662244788365620, 5432988311284380725, 2228674994111834543, 2200546438165285733])),
+                Fq::new(BigInteger::new([8327001118217919581, 17304316604601950938, 2032161838231047233, 109583433582833120])),
+                Fq::new(BigInteger::new([17464412799824261257, 11204815386604757162, 4760230105865214587, 2561703737171455642])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([17876398854762858050, 679902203699707565, 8557484401880167312, 2622110771825340958])),
-                Fq::new(BigInteger::new([17886754527373504470, 6626769657000977247, 13559842691015440291, 1812486128530234111])),
+                Fq::new(BigInteger::new([17162454351036118659, 7119597965182222675, 11825104069008636226, 939894676289903357])),
+                Fq::new(BigInteger::new([7877966409105236777, 9154224566032751510, 8849012417765966290, 2350511482299937498])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([12010684344943215028, 6230690416484348730, 9146432394745178499, 598362249523248084])),
-                Fq::new(BigInteger::new([12934031458562691104, 6177283651075806313, 5012297677309305259, 974781345340691344])),
+                Fq::new(BigInteger::new([5607243506577678311, 8379969857087309214, 1250039348953869625, 1288448394538626728])),
+                Fq::new(BigInteger::new([12717436279047444490, 6980954052911767105, 6466940135019101723, 2485571152774809220])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([8106304692036060444, 530807817188462027, 3481581788398326178, 3422934529876000817])),
-                Fq::new(BigInteger::new([10731778981747022646, 7449062796257878939, 9163661480266642178, 3290387676650136962])),
+                Fq::new(BigInteger::new([14982866992636828351, 12670314516462942893, 15024985549538433377, 1498628071832461640])),
+                Fq::new(BigInteger::new([14735061210474947771, 4023093023679641276, 17631937837722737973, 3368416500925754926])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([4310591114473028857, 10235592478412103216, 104181226275997673, 2995441509435761687])),
-                Fq::new(BigInteger::new([8130375084128522151, 817446019896167938, 92013730924576469, 2399347383912677137])),
+                Fq::new(BigInteger::new([4081238824565666194, 3989614552117312364, 11286433265037280775, 1474216756025914123])),
+                Fq::new(BigInteger::new([17827622432297850659, 16742664895463887493, 12619505726984346419, 748171743558836163])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([10042323385234737135, 7754349769852781577, 9697847394064947351, 545700322911889647])),
-                Fq::new(BigInteger::new([13410403464755324623, 9997299071284691119, 1402136507551731999, 885298659562667530])),
+                Fq::new(BigInteger::new([14175021036779920144, 2921417023606844771, 6519885835058209749, 819189301938962553])),
+                Fq::new(BigInteger::new([13574749996199402572, 5935805047237435950, 17126638079724648313, 557584257421266524])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([16855467681701264979, 6240567471942711419, 255099059556063733, 1024379506701721193])),
-                Fq::new(BigInteger::new([15772427573310211348, 3867684288738413687, 4701324253575728149, 2652772100907531540])),
+                Fq::new(BigInteger::new([15840275989288252467, 6407474783554325559, 14143833380875446765, 1399459694988116623])),
+                Fq::new(BigInteger::new([17702045766593827156, 2540588671076906105, 12715318362748917716, 1128040588803847592])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([9947943218372920662, 4362257022518112082, 10684557975892635260, 3209968262549109815])),
-                Fq::new(BigInteger::new([17795186384363568451, 7565606789026154779, 11974405993441887827, 1532332902096583754])),
+                Fq::new(BigInteger::new([15623919018618957026, 7971087015010413379, 14746298036002375799, 1125947203239896162])),
+                Fq::new(BigInteger::new([2722295853447777537, 9733714531718594711, 2257916321693467098, 2233384525852728735])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([3588474789417049159, 6927850451009169768, 1446763710930920996, 106175273415316886])),
-                Fq::new(BigInteger::new([8588687562691474881, 14282916420863876356, 14490302292378694811, 1923938192586600560])),
+                Fq::new(BigInteger::new([16020781098040327700, 16644870341668120014, 148202124501215101, 304536710273622604])),
+                Fq::new(BigInteger::new([2664020757165829734, 8643371317282818104, 8203070889784037788, 3126882526872099877])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([2265133395184279826, 919730353693505583, 16621162845791176026, 751873924309656461])),
-                Fq::new(BigInteger::new([12752242871686337232, 11112098745506181879, 18412123255809179021, 1161334678690596178])),
+                Fq::new(BigInteger::new([16024564940273431881, 15080228744420274799, 5373800922259381105, 1339806732461663760])),
+                Fq::new(BigInteger::new([7378772566210253513, 12936839695518926848, 9171496457882979564, 1333438994293065165])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([302033523371769677, 17455036468513511771, 7187931702121160277, 1651892483736508343])),
-                Fq::new(BigInteger::new([11801508294224012494, 9127832853464529950, 6458803250439835761, 792043256213443825])),
+                Fq::new(BigInteger::new([11223413455057113146, 17743938804446823985, 7485125889537826632, 2423715365846876220])),
+                Fq::new(BigInteger::new([9336456450246914278, 10004142161762390818, 10979682016082939923, 1110595221773228998])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([8865578142953610848, 2188991486739658588, 12235838075090915519, 3230807592266107458])),
-                Fq::new(BigInteger::new([9419942117873504951, 1056739971468622084, 7034746184495895304, 831332604841225264])),
+                Fq::new(BigInteger::new([9686406932527665931, 6861253133014259239, 2210275853428223964, 2873692081897263621])),
+                Fq::new(BigInteger::new([3501002177385335470, 7646928098849921088, 7107580374028462989, 2378913891202140194])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([10427687393402246391, 10547060613449888416, 6107873360617987119, 989521399112398726])),
-                Fq::new(BigInteger::new([3554404579046273037, 5849086838262847980, 7142725084319684028, 266477027896302888])),
+                Fq::new(BigInteger::new([14274448588492153835, 954260495150896250, 8975836328444118406, 3171562468894640639])),
+                Fq::new(BigInteger::new([11689762137958958829, 8959719957401393760, 5554524895453403677, 1988348245101845834])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([11502953552571728379, 16611826449398344936, 14465196328145122982, 2278276704593165007])),
-                Fq::new(BigInteger::new([7145982251905638902, 18275088097078777411, 16049202188039724826, 931844016047226298])),
+                Fq::new(BigInteger::new([17405833369749486270, 12292387580077361236, 3060540291260482708, 2087488326923196997])),
+                Fq::new(BigInteger::new([15108046972742425146, 17065714787168224897, 11696439277137030188, 1428716435962999322])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([2318696847130833659, 17056075880067577709, 5011981133660982229, 1965060478528708747])),
-                Fq::new(BigInteger::new([933218076104872594, 6373505643069727004, 11530514876882419601, 2377935810804720618])),
+                Fq::new(BigInteger::new([488583077091388577, 11253237152616447614, 10180555619922604956, 2569263992323011891])),
+                Fq::new(BigInteger::new([4394399138113721296, 7861937262079295697, 10541068830135290492, 2870513277226189373])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([13751144631338564314, 9448479609186363087, 737772079789225938, 1739400928287872362])),
-                Fq::new(BigInteger::new([14708352032033852546, 1626507374791818288, 13824604523378406992, 3252611983024224215])),
+                Fq::new(BigInteger::new([3572425638076844348, 10764656924618626069, 16439038681707439250, 610748596122108175])),
+                Fq::new(BigInteger::new([11090028454205407568, 18274029573794266784, 6211689729631605122, 1221245998612757356])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([9092779388292430056, 11974764189392038431, 7165526331933030826, 48118101029942966])),
-                Fq::new(BigInteger::new([4193846210458637030, 10592648389696533709, 18045496170400057518, 2612253773899723695])),
+                Fq::new(BigInteger::new([18354092326321453955, 1523818437228802337, 6541211788382344186, 1321153100749339699])),
+                Fq::new(BigInteger::new([468327242828789123, 15743186101359223889, 15533838542690090917, 851147901823380878])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([11668730602713948541, 11641815615403084523, 5101791914322723740, 653536776124726670])),
-                Fq::new(BigInteger::new([1920878338767206497, 4387601230650831538, 6241645730437589488, 1773672214219063718])),
+                Fq::new(BigInteger::new([6552794325234325954, 2649823110352850351, 3120272321913035273, 2577556024437868309])),
+                Fq::new(BigInteger::new([11856106359824772853, 13821268215054867107, 13841299939599402693, 1969783089800628299])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([4860930247316212614, 8600605319604223964, 11346041638518365555, 184362563727041397])),
-                Fq::new(BigInteger::new([18429011099538238925, 7977356106626845650, 5678654505475266997, 3066454961751583813])),
+                Fq::new(BigInteger::new([4890240353011681070, 13542190769313807080, 3738686194326214736, 1083311580557433289])),
+                Fq::new(BigInteger::new([685758364766884187, 6654096586452201959, 12367435229074222043, 1922956976948156480])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([16753415196413564184, 13073767751961847742, 11367245622497414601, 1082279347669032922])),
-                Fq::new(BigInteger::new([4362286090958218363, 14527291627716170499, 10630967907460146820, 2670960857112728459])),
+                Fq::new(BigInteger::new([2367495000584160002, 7354280023712457152, 7147983012565280172, 1609714476488001079])),
+                Fq::new(BigInteger::new([5372539531551991515, 13170876724127803618, 7178296386122776202, 2163020820865761037])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([15668043001132654855, 12140782144564683216, 11371727769640114919, 2874256254808490734])),
-                Fq::new(BigInteger::new([2998556563886620637, 10689137023993428521, 16383822850617968618, 1610304358433739649])),
+                Fq::new(BigInteger::new([16797214870742990813, 13466834998267476395, 17551424081609273577, 2774322482273442922])),
+                Fq::new(BigInteger::new([2255981525115770504, 10689343425898912172, 1048871974150057852, 848518807040717704])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([1650375883220861837, 11358224955114841288, 13602264637358977034, 2033360086005762534])),
-                Fq::new(BigInteger::new([5259526699781831446, 18307626136587526812, 8535200513626232203, 36048304740026063])),
+                Fq::new(BigInteger::new([1881001950325278452, 14027291972730414063, 430912602396680184, 1926809717596214069])),
+                Fq::new(BigInteger::new([17936332738206672920, 17903766828632067357, 8041333665881813477, 2884109813984320951])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([11788545204955486282, 1346204634308774684, 10534611153035287613, 2817159659042399497])),
-                Fq::new(BigInteger::new([6682318745852323820, 3313882731533581058, 12255619240432259979, 1844714015956467174])),
+                Fq::new(BigInteger::new([583013416546691777, 16331446457218641660, 9328105671605028905, 2339231651033090176])),
+                Fq::new(BigInteger::new([16451294081512922155, 14341021519473674660, 13418840526896454962, 924151640000272340])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([2294532151581143619, 10590955157945081602, 8915183510341452509, 2437829400508108393])),
-                Fq::new(BigInteger::new([11622463471715181533, 16479166043751568445, 8147436274158781965, 1722238529675875141])),
+                Fq::new(BigInteger::new([28334468542189791, 14210875672560930047, 8523360835352449184, 301379068041868142])),
+                Fq::new(BigInteger::new([15643474635679040826, 3171960808267358489, 909048796209709865, 1405122554308440253])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([17430430321753321134, 5556362640490723370, 10453000286863104132, 2607636834594743462])),
-                Fq::new(BigInteger::new([2487754381537216311, 16701064625987894214, 314415263813132724, 2434874290699889128])),
+                Fq::new(BigInteger::new([2301242503823855857, 12509678232041299226, 15555500093839644275, 2890761984578167320])),
+                Fq::new(BigInteger::new([12335109055255344477, 9449249421953982856, 4766901404824450333, 961070977951730120])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([16766642652261477675, 9143048049952267015, 12399217891853547212, 1606178006332627232])),
-                Fq::new(BigInteger::new([17628752199875908349, 3833841742818368110, 17495866528413363889, 3243096620703812481])),
+                Fq::new(BigInteger::new([12189367780958881282, 13868515360535481620, 12869276216782405590, 2133381312916080132])),
+                Fq::new(BigInteger::new([12983984035506003327, 8872042773982060286, 13086389401231439651, 2611229073630387970])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([16135467608173479178, 16527129464833393200, 17513020350360225003, 2895731461172812610])),
-                Fq::new(BigInteger::new([12381585577725076133, 17163819049934066080, 9731808509838838109, 1753375593240946837])),
+                Fq::new(BigInteger::new([5737340650551979410, 11677490819534503433, 15162111710724188609, 2217083431163922583])),
+                Fq::new(BigInteger::new([8578127640159882529, 12993463185869404585, 14566588406307318289, 615274705556045163])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([3050544351204937213, 6898680910918230070, 13529487010073404052, 175962579429874309])),
-                Fq::new(BigInteger::new([8292503658792211333, 4727795050332827310, 13237593656337189101, 1291813481823192961])),
+                Fq::new(BigInteger::new([8655116835477685097, 6922928572040637233, 3499422801124119535, 50799410877137145])),
+                Fq::new(BigInteger::new([12136892649795763971, 17426460410809581610, 4988616304027559321, 2772228711911005272])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([6266954660916018188, 17910083343336445620, 16966141317692515550, 209908917599286937])),
-                Fq::new(BigInteger::new([6017373647322909975, 2467971033424680657, 17797763502348859979, 2752632866603745894])),
+                Fq::new(BigInteger::new([1712215283794324540, 17792717211235935107, 10884357128431333002, 149927915026253659])),
+                Fq::new(BigInteger::new([4628649548325239498, 14276525436665018415, 8317587432254953605, 1104759759916751625])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([7640059415864010981, 3491650746310706110, 652628783695028327, 157227774002991743])),
-                Fq::new(BigInteger::new([15930821363941896696, 4376527394038179751, 2948860048754717771, 1199103244077577685])),
+                Fq::new(BigInteger::new([10039857603113998597, 4456774724419980197, 14000333128081613838, 888172471363418787])),
+                Fq::new(BigInteger::new([2102470925387215259, 6963927206451387258, 14600594234909552895, 1982656657741142103])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([13308092617044247111, 12912319850849727898, 17949126233231324763, 2806496771886193121])),
-                Fq::new(BigInteger::new([255965035820015398, 7187422975011248862, 3556828847184992337, 117372127366703480])),
+                Fq::new(BigInteger::new([8707175715993436289, 16168937994479961646, 9250085841285329221, 2402826301870243161])),
+                Fq::new(BigInteger::new([5141500710792421117, 5446139804323027896, 15294729123160551129, 3386363072237230188])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([8198119227702760920, 13719117920776211838, 12604876226232710183, 2139836637751674500])),
-                Fq::new(BigInteger::new([5487019391117138529, 15798166759115941486, 1460335354147497740, 1013517394775879113])),
+                Fq::new(BigInteger::new([14550437119746099837, 9167984859462985368, 16816562032441432563, 1057025515798550962])),
+                Fq::new(BigInteger::new([14319734081540279875, 6188947327225723774, 10947384693122477577, 1090069676544964807])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([17478972447470880776, 7014159577110223290, 18330946507105412782, 977244155328936526])),
-                Fq::new(BigInteger::new([11057816522034762037, 11899927866635681345, 10197253082529641421, 2983946019278709037])),
+                Fq::new(BigInteger::new([6748273661382137044, 11404626363847331198, 1349738579203728751, 1447873308181219037])),
+                Fq::new(BigInteger::new([3529852182062360901, 3160193257903911005, 10196366570066469533, 1848960700617940671])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([3912288532279850572, 870665210841250836, 13291819880638167874, 2679419108753764239])),
-                Fq::new(BigInteger::new([8754786764910464458, 6312583440552463836, 10206637756805478131, 2621251105717807391])),
+                Fq::new(BigInteger::new([1697495186313108949, 17617702428369409420, 13903357293890765085, 570175822867004345])),
+                Fq::new(BigInteger::new([8382334472793852455, 8612760833279832715, 7912439582814695342, 2177549053999274050])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([17656216761240731468, 17158317659278387731, 17371194861676686744, 229282720764441532])),
-                Fq::new(BigInteger::new([17559148584018297996, 6894471951549293251, 3696117344895746883, 3478089027436676668])),
+                Fq::new(BigInteger::new([17412141503125041747, 10799980480925890721, 13558114963763912247, 194527193473072826])),
+                Fq::new(BigInteger::new([1975091347989361872, 6940167368250489556, 5244923894681170691, 564296292256371325])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([4785595689235314512, 11252982809497202041, 11740542738971725529, 2039417442658878419])),
-                Fq::new(BigInteger::new([7938498706136895019, 2601934633110506734, 17123623211354614958, 395730828713882117])),
+                Fq::new(BigInteger::new([14719068792728338912, 2642334267751305365, 12434432759860982544, 2645241985584970340])),
+                Fq::new(BigInteger::new([15040480098397853558, 1640401229228838891, 8005985271974158253, 577843956501430317])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([14443095749712880474, 9074598619101376495, 5031073837872117545, 2727090072430477778])),
-                Fq::new(BigInteger::new([10501625834771040099, 16682082455588481200, 14030168844542305285, 3332971805310884931])),
+                Fq::new(BigInteger::new([16771491229559672992, 15641690556265669125, 16689197832106242537, 3453004929051855482])),
+                Fq::new(BigInteger::new([7957549596588864491, 9184201980629152915, 16189907253526412344, 3099180228442835222])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([4913784101858059865, 6446762965100551805, 3247090201358045651, 1178107309323833404])),
-                Fq::new(BigInteger::new([11581335508653193537, 15572453172763668656, 8921738571786513481, 472243051841930521])),
+                Fq::new(BigInteger::new([5085748336587945410, 11538926475482009973, 10281381078950056507, 19399637515990178])),
+                Fq::new(BigInteger::new([1619226636405815704, 8075308844327497964, 3981193656822714065, 3200777728724048871])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([709518349586146026, 6867639125382539213, 4874088864354422786, 448221316598396367])),
-                Fq::new(BigInteger::new([10324363622335470372, 2505844218122131939, 4939308267811067366, 2266641437239176383])),
+                Fq::new(BigInteger::new([14341110082212370359, 12276915339929919421, 3797901804749797198, 3480836957503714119])),
+                Fq::new(BigInteger::new([18180517817250597739, 14172381937644001111, 13508111220017765599, 2672297842282008191])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([17537138763162245066, 1380575065159201137, 3511615666961279696, 1876266571108010562])),
-                Fq::new(BigInteger::new([13199364437264596046, 7259816747214444788, 18370194839011447518, 1624475766785446397])),
+                Fq::new(BigInteger::new([3502951942047662171, 13133009664570286022, 2817748585483221919, 2261207473138639997])),
+                Fq::new(BigInteger::new([8823599854745040277, 10463986439686770127, 1706246851662337084, 949347374874696994])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([5584992287149255032, 2583869182457700236, 10372396962845641399, 2291397118038417817])),
-                Fq::new(BigInteger::new([3786427385184190415, 8444375700358878087, 16654806145165073572, 1571398391898886785])),
+                Fq::new(BigInteger::new([2670716442742243144, 11100832743243417765, 10404736981546430787, 1049436059380557527])),
+                Fq::new(BigInteger::new([8723956713728798840, 10693750153164789885, 11715244501481133025, 1139449130906521042])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([10181173031133756088, 2047058952633514171, 17363998533080773848, 2858806065455150639])),
-                Fq::new(BigInteger::new([18415546284538549257, 13828936240497265730, 911737811630921571, 3233182950969083681])),
+                Fq::new(BigInteger::new([364539694035004401, 10393714377994968122, 8261684889322856639, 2285564223630814446])),
+                Fq::new(BigInteger::new([14129089575612231801, 18330944924475902197, 2607983032650825591, 368975693018665342])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([1032475506109080589, 15536437647679417274, 1527534673721634260, 1104265395757458423])),
-                Fq::new(BigInteger::new([7480320358668179612, 5991297261084996838, 4269526337924033755, 2546399088232130611])),
+                Fq::new(BigInteger::new([14616156733005241553, 1460788771012978547, 11636712568460947269, 802581810902658492])),
+                Fq::new(BigInteger::new([7926521660200020037, 17126277298170039809, 7698336420123563232, 2993560011314502909])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([8580458051361559438, 9232093994570161566, 16605085100953445222, 3233894146798455613])),
-                Fq::new(BigInteger::new([3827671649111200130, 5843466600354050952, 918337038853726534, 850070522085332081])),
+                Fq::new(BigInteger::new([7974965710138459287, 13323873964567958150, 7052716833882660810, 670565382067617853])),
+                Fq::new(BigInteger::new([10174812129010119003, 7761571081266884837, 4166909755403621194, 562356750013186753])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([7154172044293998493, 13140030815981766604, 16780456091179925693, 3467403760086874806])),
-                Fq::new(BigInteger::new([9921168785941486881, 12302449800087805828, 4760143571974488303, 965720723739696919])),
+                Fq::new(BigInteger::new([6762487034398990641, 6328126706435645381, 159386729316108217, 3208598759976493069])),
+                Fq::new(BigInteger::new([18415133391226886161, 7206088086988014421, 10977042113302390050, 498726038635765804])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([3876942714369476341, 10799374038065468489, 11090628446346418832, 1156314241591772749])),
-                Fq::new(BigInteger::new([3508074864890638042, 3289868415507444602, 16995681142335040623, 3030942723137200447])),
+                Fq::new(BigInteger::new([14188157397669577364, 8899215240351637848, 14274793394729286064, 1762787585192840377])),
+                Fq::new(BigInteger::new([7133143287921253880, 15229894362467136983, 462020782686406179, 998596560462087457])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([4037227069606501739, 12776630440047248778, 7504201241270980563, 50588040714149293])),
-                Fq::new(BigInteger::new([7241423877879137914, 14743188972555588514, 12299514766674155487, 3137609192912108350])),
+                Fq::new(BigInteger::new([7331678090675661760, 4074984221031039466, 9614111614491625530, 2262670040007996057])),
+                Fq::new(BigInteger::new([13783146913182325483, 7179316941854758466, 16657901154399895319, 950591774478919167])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([2483657258978683207, 16197328343419208687, 2574243925362177638, 2766409995889563546])),
-                Fq::new(BigInteger::new([6182451727612732576, 650700664303753279, 10146691886186075998, 1124383615849320057])),
+                Fq::new(BigInteger::new([7879781991484201971, 14005984353755752002, 2394397792779360865, 2023742759367064837])),
+                Fq::new(BigInteger::new([8198004013467198975, 4248641668080390387, 188354685385210325, 752004641786902403])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([6882383723906639672, 9500505205571499980, 12261360819667110935, 2259751019715524395])),
-                Fq::new(BigInteger::new([10634250314920913949, 171788349831167093, 16719467537068652225, 1164077552374647804])),
+                Fq::new(BigInteger::new([9857750747435977208, 1596474677766303419, 4753492222594213411, 1141974066615201660])),
+                Fq::new(BigInteger::new([751261925284301437, 16590123316266788762, 15729471525681413089, 2186229939387822052])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([17061549128183771368, 1627405518766283260, 4333650044837176495, 1202331315447389562])),
-                Fq::new(BigInteger::new([11279406194420852936, 6117932541270979190, 9076438433823753073, 1773614334079148903])),
+                Fq::new(BigInteger::new([11338788426568238652, 16977921763670856422, 5090253495263930411, 592506746923567500])),
+                Fq::new(BigInteger::new([12568827952301326017, 7380439681847069903, 11564499000860354593, 3119440916239744637])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([6090284912109811559, 18045002949600340650, 8508582668603111107, 2144993698670636977])),
-                Fq::new(BigInteger::new([6713173026077991945, 17415721298630785761, 13426298182266226727, 2705942699349482442])),
+                Fq::new(BigInteger::new([10676041309207878309, 3669942307759108313, 6396174457305985174, 47807119096574500])),
+                Fq::new(BigInteger::new([9208232909968384676, 11174025918761939812, 989239138990698055, 2807124788434374493])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([17348756531091593138, 10129522561000018828, 6228179831833413117, 2020443252405356229])),
-                Fq::new(BigInteger::new([14360410610580924214, 17479290173221573776, 7324677909780034574, 1257490351793478978])),
+                Fq::new(BigInteger::new([12982735910328203010, 15227357969743960981, 15285441265402481610, 2181766018054910244])),
+                Fq::new(BigInteger::new([2307037031342411742, 179627565254493698, 1635255369183525934, 3377045571621487970])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([13746683669062528318, 3631541197359114780, 4275195084564297795, 209810821349013025])),
-                Fq::new(BigInteger::new([4220009940791011770, 12078981312457195097, 17541995471402696146, 3095920728430138351])),
+                Fq::new(BigInteger::new([3985720104839644657, 2376672064053957898, 627655293028247677, 2666626068724617705])),
+                Fq::new(BigInteger::new([13804326207415045944, 2547467276534863627, 9921550687299882537, 2682804767093693094])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([17254254971599900358, 11983332238876415758, 3658002701361816871, 2287474218054606246])),
-                Fq::new(BigInteger::new([17270882621026202892, 12724021215445357673, 5509923884234410414, 3187115556609950161])),
+                Fq::new(BigInteger::new([1426911524271513203, 5824972970902155027, 1160267341119169230, 775533448793179920])),
+                Fq::new(BigInteger::new([14730651404953469959, 3000243212234593093, 5137429152538084608, 2453637109131505991])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([6359082246460596261, 9098797852104592358, 4483675102100833845, 841060859922720397])),
-                Fq::new(BigInteger::new([1391481682670957060, 9335156010293430271, 13069432204862507194, 1236990929189885145])),
+                Fq::new(BigInteger::new([6149620642100653216, 4298254769502364765, 12410100272339456990, 828548102665277738])),
+                Fq::new(BigInteger::new([14840901033149347749, 10183913332534644811, 14719226362246894756, 2897597184954499997])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([17293618455879594025, 419253934956706070, 5682647588861083140, 3327676898775209328])),
-                Fq::new(BigInteger::new([10618757900098617736, 7693775357161641100, 16694715878036677670, 1270405885894576551])),
+                Fq::new(BigInteger::new([4558047459572331389, 4410087070208251447, 16054424411842087794, 2807046229919745838])),
+                Fq::new(BigInteger::new([17848967056657785194, 8016231380654392890, 14482126572591527052, 2956600261033457979])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([8150133554981618831, 17049212449081534582, 12712656984610577582, 3183473856177510148])),
-                Fq::new(BigInteger::new([7395600374585171574, 12713846716729047480, 11868073268554351980, 2831698847654908122])),
+                Fq::new(BigInteger::new([14198927669567930929, 15291807215954766443, 10936179087552875350, 1148345568205379506])),
+                Fq::new(BigInteger::new([7136508559294467349, 5061212254118197442, 10085361697754480017, 2123218657992668144])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([5869440125241247689, 13495570503737835477, 7644989561116000437, 3366899556162262769])),
-                Fq::new(BigInteger::new([3433609225384007491, 18075289092309224887, 14811268657974618863, 2509853796755109984])),
+                Fq::new(BigInteger::new([13794097658380244164, 9201745175945560689, 986410790431141489, 2281604388425452423])),
+                Fq::new(BigInteger::new([12865677028644488144, 4019209117718575469, 9037326801963460066, 686471936553425013])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([13470640390802388471, 976455731087632410, 12371534601272112662, 670275117466374589])),
-                Fq::new(BigInteger::new([9319475665615959289, 9709001627612374509, 12098559596134642543, 2215711020310251984])),
+                Fq::new(BigInteger::new([4791741997188768213, 6699389170666094371, 6945886859548765109, 3327620276199389983])),
+                Fq::new(BigInteger::new([13448926006779320489, 7603935982039004183, 1573738816556173229, 1664832342812510072])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([1536463816313424511, 7776244500194054133, 17853361530256281040, 1305212108613266652])),
-                Fq::new(BigInteger::new([13069167427695812654, 5791768348445596517, 16076347438722815341, 2004717007685803974])),
+                Fq::new(BigInteger::new([5070356240554912785, 12735703918790735888, 6810056977498100416, 561557927241743014])),
+                Fq::new(BigInteger::new([1142015161697483965, 879958915809892283, 11190849946505721919, 1129554095028134144])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([8630333846971405270, 1080376690403564539, 11545435373233129679, 3096693180942968019])),
-                Fq::new(BigInteger::new([1303232130999801536, 3254992904738686489, 18339341025017981490, 2961990849777023871])),
+                Fq::new(BigInteger::new([15316066769855472182, 7202324005418106987, 12667698633404789881, 2593525013844229638])),
+                Fq::new(BigInteger::new([895399636631759710, 12581920099862249489, 11271585805033580954, 2816943269928211779])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([11646706718641451559, 15978421324582138008, 14461427256698535045, 2173384158585549706])),
-                Fq::new(BigInteger::new([8232656546573707588, 18164740635206221720, 6099335035258085568, 1016739360410426884])),
+                Fq::new(BigInteger::new([9808319194354456841, 14795524304541134153, 8391876773378862547, 2554152819584289239])),
+                Fq::new(BigInteger::new([12924472501856182019, 16671618846508596701, 7119436578148462894, 878283759605037793])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([619264836578492204, 10054692742463481679, 578600370197075266, 2629445128089915139])),
-                Fq::new(BigInteger::new([3834126184038694191, 2084320636084491018, 10362320209667516294, 941300327873915602])),
+                Fq::new(BigInteger::new([12170626251764673355, 2420310821589567129, 14539421949976390101, 1197585469487164099])),
+                Fq::new(BigInteger::new([8937580838411413506, 17468860019069073649, 10800685804395554624, 535692251968891020])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([3796455698381735388, 1050130384118146412, 14839197918171970421, 3238421539620478962])),
-                Fq::new(BigInteger::new([18296365456253562014, 2057836791836158201, 11866421316428966210, 2027499607890841229])),
+                Fq::new(BigInteger::new([12168262730510397015, 3388186366163734928, 14448194778884384468, 875916773875797481])),
+                Fq::new(BigInteger::new([12360750717217228349, 3845929167291749844, 9328904045580698438, 1913224854652245056])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([10411329933915297274, 904892678434868791, 3660345370785404845, 28720545889963833])),
-                Fq::new(BigInteger::new([6079513008398790457, 14650053993517897750, 6466348845907197241, 202205773830352951])),
+                Fq::new(BigInteger::new([6639787443250207768, 5612826062485172392, 5508605830066639801, 3384905178401811007])),
+                Fq::new(BigInteger::new([3428031587946801197, 11871108360491028199, 7552602531908832383, 1272112332276055877])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([9251847905444765191, 2760133662157524910, 4014295433416745609, 1938051900501504515])),
-                Fq::new(BigInteger::new([12086110865973922386, 10930561393771262731, 11940379506456009055, 2031835259004102006])),
+                Fq::new(BigInteger::new([3997755106630147488, 671821585827735258, 16660635679593178968, 2975202486870290660])),
+                Fq::new(BigInteger::new([5362207970907189648, 13885556134368016286, 11424282521078851239, 653914697326359217])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([1795283538150855109, 6962900427307039151, 16140435276964488114, 2810309400528897295])),
-                Fq::new(BigInteger::new([2310808992339509870, 5005963874936195296, 7701125346394883079, 1363988599392348089])),
+                Fq::new(BigInteger::new([17301068182149052989, 9034493436377260010, 6060967591844743074, 2982970926455402950])),
+                Fq::new(BigInteger::new([3096534403921077462, 17550288464171652679, 614248056319968922, 454763131400897481])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([11402346297927938167, 15458288460937130811, 9347653893108373057, 2317989640837097398])),
-                Fq::new(BigInteger::new([502059443594742964, 4752876576914498541, 6796294547783295924, 2727253223901134229])),
+                Fq::new(BigInteger::new([1980044181096909332, 10515520667128512211, 11029554645726121705, 2059709063001281400])),
+                Fq::new(BigInteger::new([5034055028293935490, 16277265686123324214, 9570737136522349051, 1228982010747871158])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([15918992432401693042, 7684049979122673962, 3502551098340068774, 1936867425143558047])),
-                Fq::new(BigInteger::new([7642151241724554015, 3107460648557227195, 10103423287971783798, 3383498981492953243])),
+                Fq::new(BigInteger::new([1148015906625065533, 14935762559014303327, 15949896892242973072, 2867361586938679482])),
+                Fq::new(BigInteger::new([1918592872020506570, 11695919321970898492, 6640556270569699267, 2483399796487443872])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([6360569125864434109, 8080760006890684640, 7042323617354838424, 385589261108647662])),
-                Fq::new(BigInteger::new([2187697348161007466, 989658245392370365, 1292694082372179001, 2447603299106311012])),
+                Fq::new(BigInteger::new([2581921555445488555, 6500617709741746742, 6616271351900754499, 1253255783804722228])),
+                Fq::new(BigInteger::new([2738823595782675911, 3203013988597144412, 5807925294374216435, 2078113785545885679])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([14775161351205181749, 8958657639426203299, 10905650090091572706, 2097660987575271687])),
-                Fq::new(BigInteger::new([10969638566381891326, 5530152397081357943, 14874753674445360891, 1543259235823523613])),
+                Fq::new(BigInteger::new([10286939490402685166, 7250632652214814419, 5743867084755689092, 270265084120256158])),
+                Fq::new(BigInteger::new([9392489577343787033, 701276858569119730, 18216251805428388642, 2541173586768318503])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([18191863401767789615, 12379843406255221961, 3987471378553942563, 3179304240415003678])),
-                Fq::new(BigInteger::new([1430369200381381637, 16207864504489078182, 1029468764378696304, 2434398091175601254])),
+                Fq::new(BigInteger::new([6168718551906975238, 9770172265668712456, 5679812429617062549, 3020589353624286918])),
+                Fq::new(BigInteger::new([12298381544801932757, 16802534210946053927, 8151537484222300298, 855719121981615682])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([7979569201599663999, 6892163469609639803, 10162906683593119977, 1087508001656842566])),
-                Fq::new(BigInteger::new([6178527612830426160, 2646972874521607835, 15222203290925724641, 2655814880012544170])),
+                Fq::new(BigInteger::new([13494935421887642656, 18209009432241921025, 260791493322699944, 886007165318507140])),
+                Fq::new(BigInteger::new([8392467902069947463, 4618247837522773145, 4021545582382462431, 3118559159519517880])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([10301332317531132079, 8906146203608860097, 9803564547769943569, 3480711615119221642])),
-                Fq::new(BigInteger::new([17475150233998803614, 62140339872667783, 16819820866804211934, 3381182957736812425])),
+                Fq::new(BigInteger::new([4006536864620571896, 11604863746429178679, 13664341596893801345, 1986945130965603046])),
+                Fq::new(BigInteger::new([4964088601604068520, 6610632193081224275, 1179775041977771607, 197492762319030823])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([5311246572504068802, 1065375819410850214, 13576415527177621448, 3291040301092436520])),
-                Fq::new(BigInteger::new([15375344927408644027, 12130496068318848534, 18177098508293660442, 2392975368640776910])),
+                Fq::new(BigInteger::new([11288377670525770063, 14069816366090693171, 899023744146748988, 3172455872958823801])),
+                Fq::new(BigInteger::new([9991211056255906549, 1435385971808103714, 12267856062084336290, 430973212622977680])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([2746520542746437594, 233906832076321505, 484568027250989475, 1508724688771285853])),
-                Fq::new(BigInteger::new([4020574135053303514, 1769966396833182384, 11625842605969677886, 3348435373233354022])),
+                Fq::new(BigInteger::new([12059297285303405530, 15970745889279084138, 3943911905162247977, 2868340302393676081])),
+                Fq::new(BigInteger::new([351753236969540042, 4271158924015698257, 2084689761811441817, 3463562280357850703])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([9559514889107578221, 13310581094714866427, 6831592548300282453, 1190703464086960472])),
-                Fq::new(BigInteger::new([18424147830401545592, 6141484413353562661, 13443224473618449045, 1390918274764769425])),
+                Fq::new(BigInteger::new([14952003317333984517, 10635448707385748341, 16253617188030548547, 3478176359744927927])),
+                Fq::new(BigInteger::new([330525082626718754, 14304343880864575329, 18189806503385269581, 3172502448617242413])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([3071238730756418867, 9847813215045029210, 14700458216454755107, 1439417890199105624])),
-                Fq::new(BigInteger::new([18195451712678890961, 14295011207455179021, 107527911180331494, 925506785579242088])),
+                Fq::new(BigInteger::new([13977520067079920877, 356935743603575465, 5934409022517077374, 1468466484346526927])),
+                Fq::new(BigInteger::new([15761499760293948000, 1344597785792189873, 884766636487040842, 1280356724297298230])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([17370445322667532395, 3671295589382994855, 8753120426088952266, 2059818699238738815])),
-                Fq::new(BigInteger::new([15449492730093987213, 10011622524881410550, 5191151374578352274, 2741226093253652394])),
+                Fq::new(BigInteger::new([15614877115381493730, 4623247219895862574, 11548096416909973247, 946145945332983579])),
+                Fq::new(BigInteger::new([2033415691566488418, 16201828480717182234, 8084403067935322469, 451603369826355928])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([17753983916047453330, 10454023623886014270, 8319706669094526900, 131048065139320122])),
-                Fq::new(BigInteger::new([1522760992830149688, 3862122914382500636, 11572318918365149474, 1391031030097973251])),
+                Fq::new(BigInteger::new([12569759061060298066, 9508666045662284374, 7986884576325283966, 1521073646615718400])),
+                Fq::new(BigInteger::new([9856001893781463796, 15756739534495996855, 9372001032636439955, 688039546380030663])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([12917433664756861979, 10541432117703699331, 1854855937232544164, 2770445723943518055])),
-                Fq::new(BigInteger::new([8016858879550326000, 1704999471451375353, 9099117151086740287, 2745936571563093209])),
+                Fq::new(BigInteger::new([14904542299452905021, 8212964782110109603, 17139263522308377542, 1482097992743486074])),
+                Fq::new(BigInteger::new([13815592733216012398, 3679657010022460336, 8211093415805882489, 2251127964033672908])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([7465759671599991704, 13754153399111748352, 13087946123837827434, 2349638911340083576])),
-                Fq::new(BigInteger::new([465941318622202425, 2779172914693176699, 18360348211106749106, 1746258317201709916])),
+                Fq::new(BigInteger::new([7840376946335423861, 18324843262871770301, 15035691978539718588, 1068948336227996716])),
+                Fq::new(BigInteger::new([16165431245485331621, 11766598491438584010, 3395491497724793203, 1178546426593688897])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([4232479794571121126, 584180140056907422, 501475830157759376, 3256272676857409078])),
-                Fq::new(BigInteger::new([10676367599478974194, 14624393799142041431, 7951769376202802987, 2748550758415815924])),
+                Fq::new(BigInteger::new([10064622175616702362, 1375147503300410373, 14260519379701695514, 596870098891523152])),
+                Fq::new(BigInteger::new([13400201144864845839, 15644021143734873136, 15468491302281913494, 2707780737709628091])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([15641055487022344481, 1377564339712064726, 2492656162583417785, 2580485174859395720])),
-                Fq::new(BigInteger::new([17880065811131701823, 8198939116767691896, 12487424133368125244, 796645193312845330])),
+                Fq::new(BigInteger::new([3488901950172339566, 3305912009347549915, 8209323839581133389, 2596134315078415458])),
+                Fq::new(BigInteger::new([1029737920086059150, 4403896959069745415, 10920644812910945258, 1570782264453980038])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([18124386463768311088, 9210844276112844319, 1111334457651530915, 441145906659954280])),
-                Fq::new(BigInteger::new([6041878499495866988, 9080970092085106161, 1213368937065134896, 1636833442048689633])),
+                Fq::new(BigInteger::new([8948078184971729469, 3633966455899094455, 12740124348635776294, 2072671399868876131])),
+                Fq::new(BigInteger::new([16649039891011054376, 8730313138258420926, 9592449095171580386, 1885408506169606914])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([6219656786042132977, 9183668048722462796, 4455754118907597514, 696207900343311972])),
-                Fq::new(BigInteger::new([9584838912043957259, 10817438963235859838, 1583173871304317941, 2222472771507187321])),
+                Fq::new(BigInteger::new([1237661031144856, 16880321150944371232, 4678365094092475730, 1150581390847453999])),
+                Fq::new(BigInteger::new([14187895778322212342, 8021007496450709144, 2581503771869819652, 509141656703006297])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([9443557156861897999, 6881055230907044511, 12387901347886009022, 3152212648278548006])),
-                Fq::new(BigInteger::new([7460388305490650633, 17990755333964682672, 10514365111862627903, 2061198353573871706])),
+                Fq::new(BigInteger::new([10729256692498617103, 8008773372772309205, 16684684519267078568, 811333716320930136])),
+                Fq::new(BigInteger::new([16182242143925272609, 192202732713487274, 7348892640857342905, 1095170775081354042])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([13268522391582310096, 18431266978348813350, 17902473185111219625, 2641985459566586708])),
-                Fq::new(BigInteger::new([8871039289083899960, 10530747812365393298, 10621994145893944532, 3321421344696115441])),
+                Fq::new(BigInteger::new([574373504670645614, 533351659892396325, 10124433500624856623, 2822571517864061444])),
+                Fq::new(BigInteger::new([13927731029727973278, 3794675341338760919, 4854478896248608282, 117602530654162577])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([5909960762817462295, 2117119436269875679, 16844540203978534854, 45184945840508577])),
-                Fq::new(BigInteger::new([17049154816797530017, 12324303740181790282, 4033081334577164618, 261617191977075736])),
+                Fq::new(BigInteger::new([8585164765249448635, 1203402631107069486, 977219116574857958, 595742188770447705])),
+                Fq::new(BigInteger::new([10269028233730949965, 16824930369335965310, 10028332242406347358, 4475036332651474])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([8317975241492262491, 17317527935287956288, 952141765411714775, 3485383924595781595])),
-                Fq::new(BigInteger::new([3683839919140042823, 3707971968533596271, 12289885060282732502, 403280426530634101])),
+                Fq::new(BigInteger::new([7488728850159822632, 13129324659625795791, 14281282666643253987, 2300338393717756779])),
+                Fq::new(BigInteger::new([2755352651046662794, 11413055159945045085, 4140222320414775869, 3257726614650270792])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([18341771742903814381, 783058026991181728, 11083654288532644689, 2582706463940803019])),
-                Fq::new(BigInteger::new([15848950139646142653, 4078403927933534920, 7993628901451142805, 435219837011713173])),
+                Fq::new(BigInteger::new([14461626375024795424, 16125851086464961731, 11879967254206943948, 2968226497992580272])),
+                Fq::new(BigInteger::new([10654764721248012490, 11213656567532681789, 6481666582305865841, 703499475511583815])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([7990004693845478021, 6825385794109130569, 16349571364431606342, 2880671822285788935])),
-                Fq::new(BigInteger::new([1458588774279229557, 13678055779567967142, 11908064873886708455, 986221025137340792])),
+                Fq::new(BigInteger::new([13800994725668278242, 10058439775359742252, 13909925168839512822, 2901592775486275564])),
+                Fq::new(BigInteger::new([1316652062084882989, 2874796829800935415, 15318523332680356378, 947978150038494640])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([10147360929177946485, 8742895143386263459, 3922268163581234531, 1158200588825549287])),
-                Fq::new(BigInteger::new([16806346387122188092, 15897093990370994709, 11420626337608103713, 1194755672608213608])),
+                Fq::new(BigInteger::new([3073168425910353627, 16634445742573584160, 239552941475823031, 1049400617420750202])),
+                Fq::new(BigInteger::new([7459913494139623022, 14887101171259289911, 3362687499625749926, 2002703377690960925])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([17125026307666981904, 5600159988778945146, 8040850470089363354, 3449415926574142945])),
-                Fq::new(BigInteger::new([6799308250067758992, 11372437005198022686, 15852715240007265888, 1719990998129975480])),
+                Fq::new(BigInteger::new([10638882742772822546, 7540160476598225376, 10180706668964420569, 3210775030980058942])),
+                Fq::new(BigInteger::new([17958123768881961010, 4481355785823468219, 4507041269093534502, 416067924685823781])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([7765570272872402218, 12129659170108836689, 16693671053665979934, 2692672320799333883])),
-                Fq::new(BigInteger::new([14041141735063376971, 15656728524698522954, 17668548955404168800, 2282015166542300082])),
+                Fq::new(BigInteger::new([2835833911578368203, 15352583404129482759, 186604322354261489, 2576589019167865394])),
+                Fq::new(BigInteger::new([5234294655448726626, 1075949561603773920, 14262274185657345034, 3054357023815671013])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([3791753253906423034, 5212782314619575728, 1269229978999457347, 3318857914462427980])),
-                Fq::new(BigInteger::new([17484460670815328434, 10610589536720360018, 12598584774361471480, 1304175600476607305])),
+                Fq::new(BigInteger::new([11267603310069748634, 8821362895625749497, 3455938847887071782, 3161032056843056543])),
+                Fq::new(BigInteger::new([5543713733846054761, 9825098343531091844, 14912574280601212743, 362565790608781108])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([13528038303706687052, 649237621863014359, 16696655514189299175, 2632984578031294937])),
-                Fq::new(BigInteger::new([6226868875724622190, 5211621919218737026, 17372564680094754273, 2346760393391648804])),
+                Fq::new(BigInteger::new([11602907425669426827, 1575130017251912115, 7342378338420281039, 1641061966734462203])),
+                Fq::new(BigInteger::new([14327348426890311243, 3988158738142514589, 7546577900981014396, 1855991035749018846])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([9422118527976508541, 11574612572941223886, 243404332451330362, 2190415633793421112])),
-                Fq::new(BigInteger::new([10592117709226869373, 17516996008279734718, 17113095375698419909, 3449887049315315476])),
+                Fq::new(BigInteger::new([7893217421532044000, 9824654448079824895, 3491657636889969332, 2194595096846960863])),
+                Fq::new(BigInteger::new([300617638693839169, 17967652743748433086, 9800636439587320048, 3307219326029935404])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([10912953313206967782, 1192488202890993883, 5013512537919391791, 861522179068029755])),
-                Fq::new(BigInteger::new([5833586448484653083, 5018326185944887792, 12847381980833092662, 2721732983974690998])),
+                Fq::new(BigInteger::new([7403026766084335003, 11141696260577974276, 3121148962401876653, 355075485029167187])),
+                Fq::new(BigInteger::new([10044381242290170322, 9959219499564743650, 14467925338572116862, 3214080528034360703])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([4884452571475274945, 13762774992356385556, 14164595857120313328, 1134431644529922282])),
-                Fq::new(BigInteger::new([2430840940359368983, 5625613915526028828, 14899218563262834033, 3165979658574825044])),
+                Fq::new(BigInteger::new([16132503987171086888, 3837790513702802954, 6941859713039230601, 1981918622436057736])),
+                Fq::new(BigInteger::new([9048333353230740803, 2927681080335946413, 13299560062396100804, 2338374301230034358])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([12301845751337566766, 11721412621184462548, 3129534748272425280, 1255470720407091518])),
-                Fq::new(BigInteger::new([12598302265271182961, 10110049614210513082, 11431342984143906380, 331425679311676865])),
+                Fq::new(BigInteger::new([4738935728792809413, 18040844402667564803, 1891181512869099940, 588851500650874359])),
+                Fq::new(BigInteger::new([16589604836463233700, 7546240808681151263, 8218946094824142160, 653728778770838974])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([9822944045244700616, 17404495105181334291, 12902830216182208173, 1301937679695884629])),
-                Fq::new(BigInteger::new([52445864531236291, 7229588463495207859, 12960195595454217632, 1987117443664093595])),
+                Fq::new(BigInteger::new([7313679203304180384, 16756434091031450295, 7230451669091437169, 2104426762988813050])),
+                Fq::new(BigInteger::new([12944573798154973522, 4636744477726208604, 1832106954266952195, 2399798486872707925])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([8301970692733354672, 2493418711547754913, 10615954364666408355, 1582052315429722150])),
-                Fq::new(BigInteger::new([14877536236701418401, 16681737650294310742, 16026405610897243730, 1881786475344073653])),
+                Fq::new(BigInteger::new([14862178202606375033, 16573222788497193858, 11885256820722159481, 3398390832588523372])),
+                Fq::new(BigInteger::new([15106315835120399356, 10114232335940957133, 12630204840731385240, 3145443169658596761])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([12630815712635038242, 16655237007803249490, 7180083918274203186, 2382168416468776840])),
-                Fq::new(BigInteger::new([13267238177240895722, 16001069327743507736, 14281864132426327210, 2236209336729557513])),
+                Fq::new(BigInteger::new([7522582329970376774, 10397081908421691542, 12043571876349155082, 971448433803461657])),
+                Fq::new(BigInteger::new([2804488572692575390, 16820651413196439761, 12921273066228963510, 2386881679896937848])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([3912702154870374307, 12911826469863033052, 16521573589297904466, 1829501530837198926])),
-                Fq::new(BigInteger::new([15092749381021235080, 3885123058974786770, 9066205338816639124, 1550227797217119491])),
+                Fq::new(BigInteger::new([2748276457254005883, 12876413925557247211, 11566457584203176797, 2298627730363886077])),
+                Fq::new(BigInteger::new([899296161652581174, 9795308266793160369, 2290720914314601624, 1425515568858922147])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([7705973725429647589, 2649313019556105821, 3334666220008908641, 1043884283856022758])),
-                Fq::new(BigInteger::new([4970344859176890117, 12490595854531312665, 4535478759286360279, 1222725561190019073])),
+                Fq::new(BigInteger::new([688898589068563501, 17434427310614926261, 12298510131099276260, 2546709505998712003])),
+                Fq::new(BigInteger::new([1614377782754277461, 9130248472329113037, 15925921547113846531, 523137644592806721])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([4348049310115301452, 18172824428734208623, 13773627355096401053, 162747069954033056])),
-                Fq::new(BigInteger::new([8138292248559857848, 15256585593211152527, 13690417336436930511, 3440786690358171966])),
+                Fq::new(BigInteger::new([5810650166038412842, 4725148940982856718, 13763253509993468334, 2127685020992193225])),
+                Fq::new(BigInteger::new([3767185594573261721, 7162186614782389713, 6804871202002013186, 1862850180241362238])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([3990568514056944749, 2176245392036453481, 9636521582459832844, 1622556593652790958])),
-                Fq::new(BigInteger::new([9858981150394346114, 12906481570289020290, 15391955219389137254, 2228372669412915219])),
+                Fq::new(BigInteger::new([17673741899645350155, 11011753836409402437, 9896429285668948688, 1691854453538114054])),
+                Fq::new(BigInteger::new([4722826722022910901, 99923817798332932, 301123161014038314, 2511200801384960200])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([11209198865987268936, 3817572041929328238, 16783616467406643206, 3416960546692347178])),
-                Fq::new(BigInteger::new([14059290526635544157, 10636457537470892329, 6138459970844256335, 2691540637740575039])),
+                Fq::new(BigInteger::new([135827056096217802, 3689788984884628305, 2439770075028470999, 2471258701108121698])),
+                Fq::new(BigInteger::new([16092260644969428508, 9939551862452099313, 9889733989144181333, 428771949226384621])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([12638415990241632745, 7842777304514810151, 4148868753997513463, 2778225591140693875])),
-                Fq::new(BigInteger::new([7511166904635515305, 15320352130108812744, 2022562178869211558, 1585493740965146211])),
+                Fq::new(BigInteger::new([17043325230311044854, 12984352398015713037, 8572179977481660273, 2749530024502250197])),
+                Fq::new(BigInteger::new([453738434576420246, 926126525671454623, 10556088135588805170, 1646964831080472399])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([4384313706676147935, 18333282378394200366, 18144621952504999110, 894647375548601005])),
-                Fq::new(BigInteger::new([15234406965287031964, 6069523056653989126, 2659751284290739094, 1057987238464752826])),
+                Fq::new(BigInteger::new([15059721722644641291, 4215432510795204248, 1189586448788372775, 2192502786362131787])),
+                Fq::new(BigInteger::new([6837947423156680855, 14210239757360169444, 10410294030517553689, 2624168575916187310])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([13711357165722287979, 15459927427985941186, 14964864229984987491, 1823756559680865313])),
-                Fq::new(BigInteger::new([7738696753381231028, 13966144509086514779, 9836902079916271951, 1468910647819263063])),
+                Fq::new(BigInteger::new([10215507153820817564, 9530466503714379917, 4598166809088037080, 3397135451718858874])),
+                Fq::new(BigInteger::new([1697235471504591328, 12743232661199062348, 13229192157301354179, 3094724113480877607])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([11736220176824662450, 9782724590367532944, 998101600668700467, 1596499394132923808])),
-                Fq::new(BigInteger::new([877128977210144024, 14630280719244127457, 3420953815924616702, 1859830955852697983])),
+                Fq::new(BigInteger::new([750339342053736752, 11107412075326277991, 13695402915109780969, 187228311507342811])),
+                Fq::new(BigInteger::new([1266155738493246702, 5459113217776592830, 4240352521014520390, 178615856580786216])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([15111668802127236971, 11728913918076760697, 7214635947836060076, 88417847818271708])),
-                Fq::new(BigInteger::new([1884225953096546803, 23708415800077991, 12736149443933134111, 954221276168290447])),
+                Fq::new(BigInteger::new([4575830128330877024, 800312733889602593, 16301624998990688926, 3468188042858728269])),
+                Fq::new(BigInteger::new([17056144696381527199, 14074294467063797581, 3705574141648115637, 842048284220356952])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([4176327324393921045, 9780375634466773160, 10689989467330158672, 2876438794315817252])),
-                Fq::new(BigInteger::new([979358947505420856, 13281478873095319392, 14916594770109629676, 101427776756313397])),
+                Fq::new(BigInteger::new([18197526030217570202, 1898554872293859106, 11506631854057416805, 2241396091773047622])),
+                Fq::new(BigInteger::new([6367513230217685469, 16003576710416790376, 3419251285683591921, 143228380714255834])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([5790350748082085880, 11152808480178483911, 2347981224845461578, 1261266572356495884])),
-                Fq::new(BigInteger::new([8687585397690351158, 5136646888414689977, 1196893502110172085, 3275271080870294685])),
+                Fq::new(BigInteger::new([14900479437695703840, 16816231238787689044, 12037378909959788533, 1416896734843504874])),
+                Fq::new(BigInteger::new([10582702951929723506, 15348003731132501904, 17832543413152342271, 1013886668895913855])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([424822235415844178, 10040122665002506741, 16793590929755593904, 613978521627386625])),
-                Fq::new(BigInteger::new([10363110411018486740, 12265701289925908741, 13296650566728894710, 762174942481459267])),
+                Fq::new(BigInteger::new([8883114529688253351, 16681659598798414722, 15504478166254978989, 2158294551805984539])),
+                Fq::new(BigInteger::new([15474421610550198109, 11332053670165687010, 12866649497763931728, 2066420731853487966])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([16403434063506222135, 12333857720691887663, 15050395864650281389, 1754677936885382121])),
-                Fq::new(BigInteger::new([1805006079364864735, 9713763891718773910, 13566614944334131168, 1229287886153744634])),
+                Fq::new(BigInteger::new([14952423774089053852, 17256969650342913152, 7368718727839301389, 2568658610282757092])),
+                Fq::new(BigInteger::new([7975565815871487559, 13688258299498625023, 1000845371823688623, 2978217498659835540])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([9658128368174761865, 8129749744383503226, 2279958330117781237, 808544021841637001])),
-                Fq::new(BigInteger::new([17315902003219422501, 10079838138184271333, 5367841674854536452, 786285351612411307])),
+                Fq::new(BigInteger::new([13513611338118867682, 8213207671605996520, 17461056279121151325, 3174295837153625706])),
+                Fq::new(BigInteger::new([3061805721021393873, 16964254031889604182, 10322802940441490449, 716958295588970478])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([1214893296240652775, 18113614635677253102, 7288684601260545389, 1016390355949659435])),
-                Fq::new(BigInteger::new([5023715319850963405, 5789218885918223526, 11737890161340595100, 1329160785638093618])),
+                Fq::new(BigInteger::new([2802094407588336512, 10633054629034312303, 8733337080590055234, 1111578939685174009])),
+                Fq::new(BigInteger::new([16877855264436102066, 7098161600497965870, 2443966843427614035, 1703427904328050877])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([15645412208635525537, 13759399357514497327, 4422126347612232241, 643289905828196496])),
-                Fq::new(BigInteger::new([8140965555357623432, 8960952543014885090, 15583928260078330009, 1323200784713126137])),
+                Fq::new(BigInteger::new([14815048005088592956, 17444495519991913112, 13283385057782836410, 2853824301379437852])),
+                Fq::new(BigInteger::new([12682108313696764426, 13043933149848868551, 3136380293647618965, 206113512500537924])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([9655347219359385750, 2071014620585611570, 1146330382370923657, 314282636781843491])),
-                Fq::new(BigInteger::new([1140303093530195429, 16471052490167940424, 1591872287701978485, 261153973212370442])),
+                Fq::new(BigInteger::new([13519922303402730778, 15088533604150873939, 227607781639321291, 1929948069462018175])),
+                Fq::new(BigInteger::new([15871842489556680619, 10201460959519534831, 11180602963411529293, 452563055072203997])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([15587663855905195616, 14574776357617554278, 15583396234256678942, 2145377716279286302])),
-                Fq::new(BigInteger::new([15288149144325737829, 4460007043491702038, 1992260297702293616, 1143580674246128548])),
+                Fq::new(BigInteger::new([14709622643251885703, 6860265556309836225, 13716755643962398298, 2826156275573668347])),
+                Fq::new(BigInteger::new([3955418673689871851, 12202250972956672813, 14799024473361139511, 414182988744562817])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([12470085028029754611, 601114661112797610, 6100748830555727525, 3328204028336136433])),
-                Fq::new(BigInteger::new([17726164656423751349, 2181738409659119669, 17931759159024084579, 1078983623175751760])),
+                Fq::new(BigInteger::new([210641430249971198, 10584679418036130408, 15639326928990186335, 1733247088042648274])),
+                Fq::new(BigInteger::new([7971640731679852890, 14746206246216763982, 11909122965584886728, 2302011564680811928])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([143612909445191766, 18406992861040665848, 17985442761717264922, 3482395997392881397])),
-                Fq::new(BigInteger::new([730456964050061480, 17102337542892857410, 579114873397394424, 466181146937197739])),
+                Fq::new(BigInteger::new([15576887552896198606, 992721992957525705, 8364218115445877253, 1254093809261881315])),
+                Fq::new(BigInteger::new([11708874525997290921, 13744683932861352385, 1908417632325408454, 1685338636005418667])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([1002351483195140447, 1507795837317404547, 13911113181476524952, 228867937762017545])),
-                Fq::new(BigInteger::new([6023929458063982099, 11526841504242872123, 18383187671619662545, 111699693832055669])),
+                Fq::new(BigInteger::new([5823342506043968407, 51539339210304756, 12993264777818743601, 2655140730739939595])),
+                Fq::new(BigInteger::new([17552321103464205399, 14113678606553807122, 10651183331174705071, 2785554495424411380])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([7938789923216675368, 10424106489812935525, 3127956687085882724, 2794290759997018929])),
-                Fq::new(BigInteger::new([4483053098434954899, 4386221512338503927, 15855822933346045252, 451324728048085967])),
+                Fq::new(BigInteger::new([16099742473547623048, 3631636625385806169, 16224232896626828627, 2048500000282148141])),
+                Fq::new(BigInteger::new([16998878228674542193, 711651820129056729, 5886205979664498293, 1406135376699346553])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([1630621220541445704, 13004520792394723150, 574209590366713203, 646666871580758930])),
-                Fq::new(BigInteger::new([17385176930500937903, 7290733315674284221, 12561063688012457182, 1005475287908694227])),
+                Fq::new(BigInteger::new([12719657966547037499, 15442732563005054290, 4084456221206877433, 3387796685014122705])),
+                Fq::new(BigInteger::new([13048975678679591897, 9433774346164166957, 10258163564850799672, 1020301067422440324])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([1866940047142880372, 4151377078897571241, 18439604536046066603, 1573029922720678362])),
-                Fq::new(BigInteger::new([7833169513939067371, 4897675602736986661, 10549147220072105657, 2122685284439352069])),
+                Fq::new(BigInteger::new([12271647513138661587, 10217253364135730250, 9500572653491914658, 586442050507638672])),
+                Fq::new(BigInteger::new([13756138915836122791, 4671376427984940558, 17247285696912763316, 773305044987211777])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([3344328126167575828, 619080139448444703, 10036070770878376311, 2041232219188652173])),
-                Fq::new(BigInteger::new([7485886394563471704, 7489481346573946167, 10067145357160438794, 2715017966259971247])),
+                Fq::new(BigInteger::new([9247971540016583037, 7183322239790791926, 8320584716174978383, 960959023554192331])),
+                Fq::new(BigInteger::new([517630037268228576, 16133815782722228630, 516781927028570079, 1132661895631406827])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([8460228384859073931, 17450967436670984206, 12953789985049616067, 231628768535786213])),
-                Fq::new(BigInteger::new([12974832880969370130, 5604738699065986933, 9598247826217653471, 2878087194481996986])),
+                Fq::new(BigInteger::new([9404072631577312724, 15017476465682054634, 8736334359885848413, 2916196220018488315])),
+                Fq::new(BigInteger::new([4235960093418811481, 12432156168677120211, 9592288078032847616, 485213682499966359])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([12541464289039491508, 1773164351548145250, 3974305961996424905, 2327955132298916073])),
-                Fq::new(BigInteger::new([1917088765643215831, 5155683650784910270, 11251919633125112666, 1559207301165786313])),
+                Fq::new(BigInteger::new([14903587923562007612, 15510146532392832602, 69010575340673303, 2793406903882066364])),
+                Fq::new(BigInteger::new([5968033165672328744, 10775245320745297106, 10713470323024809417, 2561698060219278359])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([14411105444874834527, 9788230267232265738, 160647654448252736, 160650194841716860])),
-                Fq::new(BigInteger::new([8457425778304169390, 177610990420912515, 1479297365927072671, 2663167240924929161])),
+                Fq::new(BigInteger::new([12931125127240273830, 12216308053124879809, 369582289000972958, 30153107451993211])),
+                Fq::new(BigInteger::new([1626536226935153686, 3599177325027697784, 7370738730101713065, 2867294262383194734])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([149895873146131717, 17150275543832153130, 9076892299221179684, 2962551888262798334])),
-                Fq::new(BigInteger::new([4101009579103758818, 10589310448788108384, 253213006838430455, 2900060606898674318])),
+                Fq::new(BigInteger::new([8284215244941368176, 9790998079121145373, 7145281730385331310, 1502283141360284601])),
+                Fq::new(BigInteger::new([16216592302069271409, 6105867224954130648, 17407417843054838113, 3356567049963466622])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([16332697488828453756, 14607688556756864832, 14163766761789161169, 3346134947750973591])),
-                Fq::new(BigInteger::new([16482036750911744763, 12177316285509681308, 5291252359485438058, 1314173370386933837])),
+                Fq::new(BigInteger::new([15668359608328640168, 15062725525251234528, 408912291207527401, 1288993057488608544])),
+                Fq::new(BigInteger::new([13935658424239381354, 8563786274427774627, 9251713299869563061, 1464625670103660445])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([8033656904457673524, 10916529791582342165, 16380238596516048646, 734763329662752350])),
-                Fq::new(BigInteger::new([8169918952221909012, 2173057148641204774, 13717606997594966731, 758543537120792827])),
+                Fq::new(BigInteger::new([4593101851149855737, 10884623626667024443, 7140400334667849640, 2486876595440194706])),
+                Fq::new(BigInteger::new([2372646472953017062, 14630698696004932933, 12482773744282713204, 2076063074707229411])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([12332903170421125076, 13554203202809824807, 6933909906078776368, 2440037231331714464])),
-                Fq::new(BigInteger::new([15221359935591343548, 11387234009007050744, 16732180060590056462, 2503171905715424453])),
+                Fq::new(BigInteger::new([16416648816766819471, 13799817707862500118, 11837196640769555076, 658050394678389440])),
+                Fq::new(BigInteger::new([12477675635650100058, 17959390977838495504, 6359377861190592543, 15355393163650605])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([16090639605517532468, 14068844061079212110, 15981014142799407363, 3212048333147849836])),
-                Fq::new(BigInteger::new([3415269305327749110, 17807022917412597155, 9920434767433199804, 3311887157736542615])),
+                Fq::new(BigInteger::new([10902216661912858122, 2925284908538848637, 8186067751932009635, 2539065742603406735])),
+                Fq::new(BigInteger::new([2340865950887393687, 4748703442515655696, 15892903406333611302, 2094120160996264618])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([12964927761791991684, 7031751707639998222, 14048047683671806200, 648174893252131857])),
-                Fq::new(BigInteger::new([17714734918409239374, 8934324838169798417, 8005909118095440407, 385888511519042505])),
+                Fq::new(BigInteger::new([17427609131887752661, 18312072534516054266, 12368748162991362113, 2167177557339082745])),
+                Fq::new(BigInteger::new([7632016662478520045, 8813639274801352859, 17152817430669966383, 1708691249364088759])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([8702535230149825145, 9781954028626884852, 1599502881833661545, 1873722361482875980])),
-                Fq::new(BigInteger::new([4810394378633760199, 4800023610110650124, 6742689612053924849, 779250041078443993])),
+                Fq::new(BigInteger::new([5392412307076526260, 11258353358658626830, 828359708943237913, 819590074178188478])),
+                Fq::new(BigInteger::new([10062366231009540075, 10485803720065526565, 8015504856488916284, 630521424292394658])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([11359494825440141305, 15092928580597087233, 6670387054410208935, 1522531368816084976])),
-                Fq::new(BigInteger::new([13958064641939585986, 2019950039366538993, 10231148609764902741, 3306853146495476209])),
+                Fq::new(BigInteger::new([9753109657680752092, 3631046797228281390, 12755846965432853735, 1671643378912409797])),
+                Fq::new(BigInteger::new([1479939344433848301, 13297787640567392854, 7030225714530786212, 2564615723136920383])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([17055756555786175684, 5805424606332552524, 8304875049056137376, 1923316122061096804])),
-                Fq::new(BigInteger::new([15720907557027872997, 4664978364926327833, 5393369274531011251, 335534368669448378])),
+                Fq::new(BigInteger::new([11684782742384641247, 14512835242113749994, 12697501308454627581, 441580303896277251])),
+                Fq::new(BigInteger::new([17831628218912282074, 10363400355380185351, 16226558953655731365, 271093008460781446])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([9381562160757615092, 2535333174546552585, 8457661451843606352, 1786027764045779682])),
-                Fq::new(BigInteger::new([12079746176295806516, 14940779003279501027, 11592594203504843943, 978578709988512478])),
+                Fq::new(BigInteger::new([12200342820887901384, 16771001348233814604, 17544801314556427930, 3406475033196405011])),
+                Fq::new(BigInteger::new([16420754684086715728, 15562635516891766686, 8283419609034949371, 1259686936234893730])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([2830925405162386936, 15524744268093180447, 5277336364772419387, 2355592083909146321])),
-                Fq::new(BigInteger::new([5520934851693550037, 3706667005996181668, 6785614966465771173, 1886674104300092354])),
+                Fq::new(BigInteger::new([7295757514158242971, 8429525637399401588, 1993332512198205916, 2362866326312580944])),
+                Fq::new(BigInteger::new([6682466606808354962, 11751311293365893534, 3904672205287571750, 616772985455845967])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([10141803001700141918, 6914038219614194151, 13955504875407794856, 3371430368408518343])),
-                Fq::new(BigInteger::new([16053622049710366593, 11945773326693710470, 13265182210113874251, 2257079502782861208])),
+                Fq::new(BigInteger::new([6460980040656494006, 7959898807695085899, 7791703774040405340, 409504318816496793])),
+                Fq::new(BigInteger::new([12801669683454689318, 12901895327552068786, 13790343390815880575, 1410229569253547364])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([5504268647062350128, 5648916150866797377, 9697108761029374602, 2581610400211795678])),
-                Fq::new(BigInteger::new([15681248844867381619, 4944522742285009609, 9844578498680996224, 3087110887092082780])),
+                Fq::new(BigInteger::new([4842667947953926249, 2998653899816876120, 6673599732786505678, 2489590238564304345])),
+                Fq::new(BigInteger::new([5010362625880364294, 14593900414167489671, 2483227838820250593, 822523434147838220])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([4425450896034114887, 16476584815488072754, 11146265256896794052, 3297573170364087889])),
-                Fq::new(BigInteger::new([6572262544071438714, 16399482852551525645, 15908885926934521337, 338345805778354862])),
+                Fq::new(BigInteger::new([3375575185602429809, 13590133731959957532, 4945013223303922184, 130404077211711185])),
+                Fq::new(BigInteger::new([10451892084058978486, 7782483440161747635, 10313901604261031269, 1602976696565298986])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([11103293507375470801, 10625421884099926013, 3492694877393884565, 2938989223497877517])),
-                Fq::new(BigInteger::new([16031851311944387520, 13300243560532719181, 17735993516325000936, 1492182924939727267])),
+                Fq::new(BigInteger::new([11052289279978006659, 16734905263880428748, 2850066155482128623, 476868100241906275])),
+                Fq::new(BigInteger::new([13956050510087247491, 14842475409009112943, 2373793604712703456, 803276274985775082])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([8287622224226882419, 446941770865914310, 6264603247578109714, 2999551752497996573])),
-                Fq::new(BigInteger::new([9557935622474435050, 18113556573790191975, 3273429660036643862, 2909092586145029965])),
+                Fq::new(BigInteger::new([9851960348683250461, 17525770138086457412, 5480276683683173358, 2099090024316363535])),
+                Fq::new(BigInteger::new([9167283428189215135, 2309213246185047115, 296636826850578038, 1739619449194060052])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([17907027566203209917, 9351661861646209440, 4064345421718685366, 1071452387796469543])),
-                Fq::new(BigInteger::new([7006307064787258537, 6709837322444812734, 6791503281422369316, 3370553293276768122])),
+                Fq::new(BigInteger::new([11589522741762815736, 1495568921728614975, 6031329861074015469, 1870468881909990929])),
+                Fq::new(BigInteger::new([16992195173368555632, 1788790745248757725, 15451482313053488437, 1487025603261139381])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([2881418463839252228, 1855616482807665129, 14596073537141099131, 2494311896103948264])),
-                Fq::new(BigInteger::new([4541944293969794398, 17815594282165021935, 14674690696640373327, 3079440544514089098])),
+                Fq::new(BigInteger::new([16936715802817265585, 18237791105417304954, 10792994316286698510, 2115474057740312882])),
+                Fq::new(BigInteger::new([9993150016215682394, 734377780049500684, 9155898320752311934, 2607244693348402529])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([12675367137718922744, 18202091727369044379, 12444821408696599279, 1534262912257175307])),
-                Fq::new(BigInteger::new([2044425604727774262, 16580281401285775979, 14036731645145866135, 748017850048538785])),
+                Fq::new(BigInteger::new([13918398567698751924, 8936238300743415665, 9950075558294733866, 1819169251953878157])),
+                Fq::new(BigInteger::new([18384709408798106272, 17061832008365595159, 9994755626730801860, 3474899739016956498])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([5186686830802781677, 10016367442713653469, 1674483435633394301, 2859512112509240756])),
-                Fq::new(BigInteger::new([17448003823809055386, 3774847635474742250, 985817700349550824, 2592045985295768952])),
+                Fq::new(BigInteger::new([14914475087151189098, 3760027877602300265, 3356465659741536744, 58864448164753980])),
+                Fq::new(BigInteger::new([10984981109853297537, 10108377996695493590, 6844063892357226164, 1216035281375724394])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([4889422828281181673, 16223699857535738983, 16430764603462981432, 3348211087191061032])),
-                Fq::new(BigInteger::new([14480963616096308030, 1851268105385996102, 16005667638933689001, 238191996202543914])),
+                Fq::new(BigInteger::new([8843021046813156819, 15355991458509878067, 6703591095063324324, 2842909647787772205])),
+                Fq::new(BigInteger::new([6818464140096288965, 2722089244374258971, 15437470843282195647, 808197577302852522])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([18429595689700126532, 12418933376783104562, 6982097683630211996, 3334316135063643529])),
-                Fq::new(BigInteger::new([8889758273085357004, 11382729110345723764, 17023859852344556652, 1881750812034899300])),
+                Fq::new(BigInteger::new([14696308398005391651, 7982411865780244230, 18312300439287956928, 33145103936349075])),
+                Fq::new(BigInteger::new([5154254337530902, 2261518197085241304, 8165862238095212199, 1852409491069254496])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([12414054329559273417, 1503173970835092697, 13547819712388275234, 178362328631319512])),
-                Fq::new(BigInteger::new([1148811722051452487, 18205273626447258346, 8207203935673342948, 2293261394449250766])),
+                Fq::new(BigInteger::new([12362705888659758181, 10936486292931753825, 894174184658396565, 1862683630195875595])),
+                Fq::new(BigInteger::new([1619556499434992157, 5488754950280056461, 14186532720921448198, 192623376239928815])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([8055430000555555381, 1774478665758700129, 14225127684523036182, 681884923432631687])),
-                Fq::new(BigInteger::new([12776233480198348432, 12672987668667304412, 7085189447710213967, 3080924963786759279])),
+                Fq::new(BigInteger::new([9593063921631892607, 2881215015492434302, 15687321387654538438, 283107667034851748])),
+                Fq::new(BigInteger::new([17425591538202529634, 7583956211879363343, 14604408060035277155, 1862658781715183091])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([3264029909449057769, 14373535233078005774, 3288559422562322017, 32276379058134182])),
-                Fq::new(BigInteger::new([2068592751445272070, 13656393649726225108, 9405531556068698414, 1154667722250086472])),
+                Fq::new(BigInteger::new([4428682855977762765, 7889788841762789209, 15991003597202906267, 269152902824065423])),
+                Fq::new(BigInteger::new([10365772012235609464, 13129231438142339544, 13123188345279285779, 3445959349617892092])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([1456070808631545268, 10733681429605212515, 13445541042077460562, 3266324023338434480])),
-                Fq::new(BigInteger::new([11759694440316564994, 6971258726700251538, 15919000214664090094, 479453529842811555])),
+                Fq::new(BigInteger::new([13566296572338208691, 4029264159459248861, 8442395812741355456, 2071536101582020882])),
+                Fq::new(BigInteger::new([9896013706170631443, 4960215942141227678, 2495538251292802436, 1827766419439691781])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([10088392445004921979, 5965705044409375226, 5164037896390835701, 2879684274595899939])),
-                Fq::new(BigInteger::new([5094705772611822390, 16047574080375832102, 284158978377845278, 2166331421397614470])),
+                Fq::new(BigInteger::new([11091984955126341644, 2992753474831475809, 4075215901032851056, 2692900423295926203])),
+                Fq::new(BigInteger::new([15577836281318798451, 14489357753142678388, 7583413458511396134, 2170715549673811932])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([16835117445521156073, 16295346215619744087, 1421576668722772374, 2868491035992957509])),
-                Fq::new(BigInteger::new([17862322755171091741, 4841454445115109854, 17919518240797337584, 1993982918078662225])),
+                Fq::new(BigInteger::new([16219870356628061775, 18332090381107902358, 15269722543599771992, 1141345915647001773])),
+                Fq::new(BigInteger::new([7096055280999198734, 15156924754673573879, 11986899511303574953, 2108072223291177433])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([7854684243383531496, 15715318876328825646, 14523946776471447453, 2550174758410218523])),
-                Fq::new(BigInteger::new([11961020617397896052, 15738599149969681178, 17015271855920320574, 741284707875168296])),
+                Fq::new(BigInteger::new([4014787692303502303, 11583619047657942230, 9163248505916904362, 2733967822754381481])),
+                Fq::new(BigInteger::new([17663890789807038789, 8771824181766345203, 7636100200575926527, 2616684851739347158])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([15331316137041860373, 9333456802880761222, 5663229757800499084, 1353493963246817925])),
-                Fq::new(BigInteger::new([624772750899786331, 5679497587719502274, 12022805593255976096, 2149614245344064650])),
+                Fq::new(BigInteger::new([14493246473465774999, 205250328413858431, 15788589549751512397, 2231867682687330565])),
+                Fq::new(BigInteger::new([9577796555656502102, 14179571492187762320, 9975006062832099040, 3140577655289750340])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([14000965951295237721, 2678676922513090938, 12590841514094441991, 1898855260226905907])),
-                Fq::new(BigInteger::new([14927231684713206149, 3440204585378150444, 11182052141503606023, 2901370008865979093])),
+                Fq::new(BigInteger::new([16803196150201996183, 14543640284827735270, 17469639320102382192, 2586560851797662178])),
+                Fq::new(BigInteger::new([16195151667353910379, 11665455790252211352, 2528743901084807666, 2296633484343820100])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([3918853191611016551, 629762865594293114, 14147747219228292995, 2291172156494195056])),
-                Fq::new(BigInteger::new([16900457591555019798, 12565949433489910670, 11515618666922484235, 2291801005926036387])),
+                Fq::new(BigInteger::new([18304853082401369442, 4377364528842518897, 3577143334867761466, 3472345809393621586])),
+                Fq::new(BigInteger::new([8214721850794340418, 13240855718659618454, 16035611769304658699, 3437152914258472966])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([1492499169469786669, 16606285546011814834, 7306025366429486285, 1143991261255173790])),
-                Fq::new(BigInteger::new([10504112793871336791, 11406601367148336250, 682853021470299100, 1018795080935819529])),
+                Fq::new(BigInteger::new([5155273281328187267, 6945155459234624311, 2680456069201472398, 1570189656924702011])),
+                Fq::new(BigInteger::new([9358057046637148698, 12113713458242483575, 9032695617412790431, 3224429230784929519])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([6335731747883514898, 15002178734453090278, 16233970490374820871, 2113086822697517180])),
-                Fq::new(BigInteger::new([11922895510438216826, 6173960763810750952, 8664854721335528100, 601053182477948270])),
+                Fq::new(BigInteger::new([6000425160487084845, 17450925996692207936, 4202386416018952928, 897395423040502118])),
+                Fq::new(BigInteger::new([12654384635081266143, 2121719114169248999, 5161936294551489639, 3295459971132569712])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([6720483143500208206, 9403528342928765119, 7693065954160635242, 665794801106436336])),
-                Fq::new(BigInteger::new([3019836935970569497, 16027268048847228504, 7871736369342473184, 142978713179332949])),
+                Fq::new(BigInteger::new([13545656871521228038, 9539433563331164983, 10264783554522196331, 3421386012953308808])),
+                Fq::new(BigInteger::new([10368804127158869919, 8082352783232462852, 15032031750533959361, 2868592901211557475])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([16662265188751138944, 6596776562883483705, 13197852765136762533, 3234763970894427784])),
-                Fq::new(BigInteger::new([2209626267390687335, 14427229358991572048, 12786870637609990254, 1707213541631993971])),
+                Fq::new(BigInteger::new([8833305674910074063, 9839849179650769750, 10086116754146948002, 1414592492900416060])),
+                Fq::new(BigInteger::new([12702296322570946639, 7366151852835168471, 10226802137260432466, 952895046789980022])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([1649310897300725627, 11836581959590139947, 5660302540367518042, 2505193487641245276])),
-                Fq::new(BigInteger::new([3090848486173487660, 4478349419144481428, 9182693270337315049, 1534223688101400752])),
+                Fq::new(BigInteger::new([5055438193832929848, 4223662954145742761, 2693407208065600960, 1274887409944618769])),
+                Fq::new(BigInteger::new([1604529750905535742, 5123804814754917082, 1499051515935776560, 3128012300282193941])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([11257670446126376634, 16479839568277741679, 1440742728421217640, 2983083072122483245])),
-                Fq::new(BigInteger::new([1596508891528772756, 476551828255138649, 6004886703224913456, 1376912791226924219])),
+                Fq::new(BigInteger::new([9798425220290962770, 7461722092621792227, 11710239693155289748, 188680252867539028])),
+                Fq::new(BigInteger::new([16388248527676479243, 14496881579844737843, 18191521904603742129, 1740829724421343843])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([17703143241885026128, 17319729028584954451, 1290842837184459398, 2287132512323952793])),
-                Fq::new(BigInteger::new([88264128329657508, 13637023512403636346, 1346328552485234584, 3457673817475055555])),
+                Fq::new(BigInteger::new([537618616051728826, 12357659223967401560, 2627121170333356219, 3121340495428235683])),
+                Fq::new(BigInteger::new([11877104121878683821, 17283578526156156756, 6522189204359917105, 2610223882953769795])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([7599278374984329885, 18433533144795433709, 15024390256661897181, 1209837609401816912])),
-                Fq::new(BigInteger::new([3907373386951334163, 6470916181128016110, 2152864471088354295, 2989640546662868444])),
+                Fq::new(BigInteger::new([8627453996860564882, 13582219392967704003, 8252983287262666002, 3457910564840655257])),
+                Fq::new(BigInteger::new([11647550741787519444, 170552176229093882, 11252674244593136553, 3238786723768434083])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([10616629179389227909, 14554024769103264344, 16757657093726478209, 433153983706353230])),
-                Fq::new(BigInteger::new([13752493220787131389, 6602781506914933150, 7519394548388464983, 994699418484313202])),
+                Fq::new(BigInteger::new([11132961702402449767, 5840447149110330986, 8906938314835952425, 3211222686727037618])),
+                Fq::new(BigInteger::new([866643041789533661, 2584720802350605500, 9063273403937310358, 3187313450128447752])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([3027089082282281003, 6642023501435520707, 7947122305046617186, 2032528032682012002])),
-                Fq::new(BigInteger::new([4486934329722643615, 1740858886205275477, 17840692229043039305, 490475223770333647])),
+                Fq::new(BigInteger::new([871838750070249869, 11500993902816840266, 17561996048217806460, 2282899349124864023])),
+                Fq::new(BigInteger::new([1506444484357840581, 10280236247500188681, 7231110975006063374, 3243022465773712259])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([14912227380946484546, 16452014001636654545, 8126557756215153519, 2975583174240454182])),
-                Fq::new(BigInteger::new([634278053118506717, 2914883481143086629, 12731625955015835541, 1005340110489033864])),
+                Fq::new(BigInteger::new([13758713777898291136, 7686678209091066197, 14169996771118345650, 861402937663729011])),
+                Fq::new(BigInteger::new([9537197033272397845, 70947596299416366, 2495840903760689818, 2298613173414336373])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([10723444238811687846, 6970775970514164261, 17833528031893564561, 867856776820222765])),
-                Fq::new(BigInteger::new([12489419643712751370, 4292945158005325640, 5716303177995167570, 137125792930495987])),
+                Fq::new(BigInteger::new([13320945681773186432, 17762662509470342957, 5253200464038854421, 3034405273617227876])),
+                Fq::new(BigInteger::new([2787240227925244549, 7484128960839403172, 11366046685408635627, 3100924949261654658])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([9691765111433135291, 17697551113964870145, 3865579955101617266, 196807106317988454])),
-                Fq::new(BigInteger::new([5200189778545782067, 2743422860392472448, 1094536088337144422, 2046011813061284181])),
+                Fq::new(BigInteger::new([13024977894935707628, 18275720700786541398, 7444068100004533367, 3336853988304768697])),
+                Fq::new(BigInteger::new([1295521828358866834, 5455772204519336830, 9363987149102081787, 2930424393511256677])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([12743401024617014345, 13634673090466763695, 10137240676793461966, 931228740489746599])),
-                Fq::new(BigInteger::new([5594454945865992812, 3894358859377650920, 1584856945764854988, 1943412827188602305])),
+                Fq::new(BigInteger::new([2241387771087621827, 13168289671805152595, 10181084274238228412, 620537930542429651])),
+                Fq::new(BigInteger::new([385437214785822370, 12041876039632737427, 4022791044450135727, 598186687382450467])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([1496409075857497735, 2720707112243144891, 3569583149172354491, 627388410355662142])),
-                Fq::new(BigInteger::new([9479478771413798268, 4394748742714688372, 2084843969167758585, 366621983362883499])),
+                Fq::new(BigInteger::new([4471739717968976779, 15527599702079567122, 15558508199946224122, 2569215270476360946])),
+                Fq::new(BigInteger::new([2081686030660438194, 6009091868120084845, 3427718076778740165, 1528866604097043684])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([8070482081488494461, 3149771100247189384, 15078259077160947539, 3441479964703490867])),
-                Fq::new(BigInteger::new([17814089973256790147, 8104478471010324154, 4021498795644565885, 2582159838321250278])),
+                Fq::new(BigInteger::new([4690324331179128147, 7382542314001802960, 16660775663578576657, 2110265406619727808])),
+                Fq::new(BigInteger::new([7620747093998590778, 5713238022088325342, 15370657061841641109, 1975441764516953466])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([18075483810253019559, 11987968475408445603, 11825154211640957239, 2767954311969785378])),
-                Fq::new(BigInteger::new([1564278835454175646, 17207469893760960394, 11549848729942223322, 807976608299121771])),
+                Fq::new(BigInteger::new([3605407909109761280, 12235316447869809841, 17020182234801237990, 231100824615613729])),
+                Fq::new(BigInteger::new([14706648444971289099, 12144290485389301946, 2736632560943879930, 1484379640502237342])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([18179418718800743633, 15942755258378778134, 4001204875497429198, 3304820055610820367])),
-                Fq::new(BigInteger::new([9789180884510412161, 15470402963980650185, 10442182628393786312, 3184291316804317986])),
+                Fq::new(BigInteger::new([15627181285635498941, 18434902718621258263, 10579256811183189459, 1215518259644498319])),
+                Fq::new(BigInteger::new([11198160621269259772, 59211852979761974, 15969548944731749217, 888848725823444737])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([14709085978845100123, 13913365905376934127, 5910692641331716159, 2482810119172413152])),
-                Fq::new(BigInteger::new([2265192658381095548, 5485546109535520054, 14847395671584250770, 2809547145967788529])),
+                Fq::new(BigInteger::new([5566329963354270850, 8714114758563737192, 15673378872779887559, 2329649883169874077])),
+                Fq::new(BigInteger::new([51917820385397416, 6396575392012605983, 1540411635190148120, 3395518179364900215])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([13896226211638722552, 4556728501890203961, 7052089260655771232, 1720946588615884207])),
-                Fq::new(BigInteger::new([346029606922267530, 10036557982538420041, 4455894241699165900, 3348097233932533579])),
+                Fq::new(BigInteger::new([12496772018731573012, 3461229929306582, 5215874312570749232, 1135253186988511840])),
+                Fq::new(BigInteger::new([890158663555548350, 14505756183736993837, 12250395876470787537, 2178098658588115085])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([6839235627397934582, 1348722118951251282, 6048450402295566877, 1574119439265261853])),
-                Fq::new(BigInteger::new([15926872012357819144, 8864774990612370005, 3459474656340114633, 2468451712633422695])),
+                Fq::new(BigInteger::new([4230312718108167011, 12755094534989182168, 6975770365681394720, 747549498367762301])),
+                Fq::new(BigInteger::new([6311718662084648820, 8578308880258812449, 11722121144388138724, 970506639170622075])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([7803456587402293930, 3710662250580249660, 10775492948101327690, 1827913253210245739])),
-                Fq::new(BigInteger::new([17767310532123469165, 4160548998932120238, 10424188940118526243, 2764151496207438876])),
+                Fq::new(BigInteger::new([15554472939998898742, 7883048157545391419, 6844217583901498233, 3310919607443597381])),
+                Fq::new(BigInteger::new([17470541411833937683, 12940973191801872080, 10818379084136158563, 669558086621766362])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([13191346500718704934, 1019577535021825862, 594856898201486671, 2279752429797291502])),
-                Fq::new(BigInteger::new([14921700560330354088, 8893838075988906459, 2975134529634201017, 3389275176950119876])),
+                Fq::new(BigInteger::new([17137059489658125347, 5605899836779680109, 7780539876843453221, 2145680560092885198])),
+                Fq::new(BigInteger::new([5497697061799091738, 4239079391969109104, 7373126181234018765, 1562609996119308949])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([14935186155571390845, 8241668512131033266, 5683378740370843258, 3361050512611815287])),
-                Fq::new(BigInteger::new([1243680064217503381, 7427555109059735925, 15622072926996548189, 2396106376057424273])),
+                Fq::new(BigInteger::new([10687733339182081952, 15806360823717309590, 13601497567545795112, 3431903531431074849])),
+                Fq::new(BigInteger::new([11410654052842609626, 11055004090475199523, 7642917573190340557, 335711990923728527])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([11663631773017652957, 16705185306483193479, 16645567167655715909, 141546818734763031])),
-                Fq::new(BigInteger::new([8779713664182982040, 3959946311501158712, 11070518392511260962, 3224785034566803153])),
+                Fq::new(BigInteger::new([4358655114957984312, 11151616419128877048, 14489515166008821631, 3047298109844177676])),
+                Fq::new(BigInteger::new([211775739217393780, 9143252241098259242, 16179889925948096640, 2117998943368394343])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([3887631537131213797, 8044110421155622694, 18126930767633704696, 98127661565158069])),
-                Fq::new(BigInteger::new([9770807346487585500, 7013988024256206181, 10232362171088709513, 1380037322558900787])),
+                Fq::new(BigInteger::new([10982415433425578039, 266228073183095965, 832029946835863114, 980547154855217565])),
+                Fq::new(BigInteger::new([18334083073579736672, 7913740451407932395, 16930479253562231944, 1028639969290379083])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([15353600480982589041, 3203692064396355055, 12942248526706459995, 3369371173258921144])),
-                Fq::new(BigInteger::new([765458291823073838, 10633668483938141949, 7923116122925414995, 3015017132540399539])),
+                Fq::new(BigInteger::new([3358118999040536847, 11359643761760382144, 18263608077084916884, 1289705338101548470])),
+                Fq::new(BigInteger::new([2173460153622215909, 9023044598114189306, 12903088690028776205, 2223899409322730032])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([12270757751913258574, 2329744297529601943, 7959410992353575879, 3119359613852190108])),
-                Fq::new(BigInteger::new([9048349656011473044, 217855317168545492, 3007177777381950236, 850693289094574749])),
+                Fq::new(BigInteger::new([10436606263410540573, 2164391227658059074, 7426586937023787867, 642578852807439602])),
+                Fq::new(BigInteger::new([12882544167856771860, 779497142868727348, 341290086468647688, 1232790521716561582])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([9576835492296978579, 2593634318897044043, 15256588324459395741, 528237310623329293])),
-                Fq::new(BigInteger::new([17827818988808758182, 15397720986972406925, 15426880634525252934, 218882168188630627])),
+                Fq::new(BigInteger::new([16075139025600360057, 7606709196111089397, 1392183226801060080, 2145464658348177486])),
+                Fq::new(BigInteger::new([18137285554718271040, 15597288447721547523, 1099800816454678719, 1027605611805972870])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([2047458796632261739, 161212221897499584, 8598477999264507329, 2267616170434433876])),
-                Fq::new(BigInteger::new([3790701331711657144, 7347792178662072624, 7941505032588221695, 1724047393548431401])),
+                Fq::new(BigInteger::new([8461055839143476345, 8918491231777652041, 17724596558085246792, 2335853205483846282])),
+                Fq::new(BigInteger::new([14745911599075577264, 2990789289997328566, 9158081063832842506, 99717826739225974])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([1797063640998261129, 9864920498803417516, 9741879849260764667, 3221605333887959415])),
-                Fq::new(BigInteger::new([185131992914002258, 17314444269474759132, 14203868764246118443, 104603669521949267])),
+                Fq::new(BigInteger::new([8395115129502695634, 1765507221479279513, 14115607728581897817, 2849356670595619654])),
+                Fq::new(BigInteger::new([16289959163079316048, 12826532515001173222, 324712040133290483, 78970391527651926])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([3328879926106014926, 7001930932873561109, 13988745519565957084, 573557775128583260])),
-                Fq::new(BigInteger::new([441423026665388396, 9810590398396032854, 3866460954464842391, 98766401077872265])),
+                Fq::new(BigInteger::new([17663800698482431916, 9984503236726704607, 5079722771392398756, 1894033570492858223])),
+                Fq::new(BigInteger::new([15132656539597604706, 18075244100197712139, 12743252325489213753, 746870024184626894])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([11956632369359208645, 3959979221265917951, 1052675723866337888, 966474564934850138])),
-                Fq::new(BigInteger::new([9823203671377144422, 1648213461707013826, 14453934741524070206, 2043572524587650273])),
+                Fq::new(BigInteger::new([11550518583969865386, 16201711693816617013, 10893355412080219274, 2116980752449761640])),
+                Fq::new(BigInteger::new([1365119921761721668, 2455228866735903373, 10633037727077577541, 2420727138642434438])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([10030699422825223120, 8761515719226485624, 9099648416364671855, 689244366294060460])),
-                Fq::new(BigInteger::new([6826784995961478674, 11916190111869952538, 16001381250844781590, 746770708271705017])),
+                Fq::new(BigInteger::new([17695690509589026119, 16834139655993410954, 17203554564893710030, 73053868422965081])),
+                Fq::new(BigInteger::new([4936435086546383358, 3125693007948278807, 17854182784509468911, 2015289967270690971])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([17962468889619103477, 17343755426068297980, 15555536823631309534, 2688102341058394298])),
-                Fq::new(BigInteger::new([11980550882088203742, 9717403959610017116, 4400320651032052706, 767765919630972363])),
+                Fq::new(BigInteger::new([15629211252819894299, 12845065669794316323, 4621712584921809600, 3450103630117463880])),
+                Fq::new(BigInteger::new([8738278191563407102, 11303642141298515488, 10278508012968647337, 2731440913457277906])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([11532259273517358485, 14816803715580888744, 2934593111637591526, 3406068348227723788])),
-                Fq::new(BigInteger::new([921797698151028342, 5114968290141441421, 12129385243573731850, 1619655685285937153])),
+                Fq::new(BigInteger::new([2980509782745606206, 15337711857251616109, 12368027427490764705, 2871743660769725900])),
+                Fq::new(BigInteger::new([8566822986682286496, 5236703300796495309, 15282676474397101291, 1270639169683510231])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([521301737535216776, 6974600678311740846, 4476775801304173257, 585372166461463072])),
-                Fq::new(BigInteger::new([5393699658822517504, 18067015237593711859, 14966456405121464184, 747041682719082331])),
+                Fq::new(BigInteger::new([3328788316197275143, 5346240668104891371, 9199543418905433707, 2531060713626390299])),
+                Fq::new(BigInteger::new([3735026538020854480, 5867358627277486289, 641527719060082812, 1905349325532101904])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([2534945771972603717, 12505221589977891000, 4117470327587253991, 243910759649790140])),
-                Fq::new(BigInteger::new([16340690658449066158, 12386589369755202437, 1840186606393285069, 2233539051577199248])),
+                Fq::new(BigInteger::new([4036245194599785754, 7389503886462601613, 16750627003168933225, 1520924422904455698])),
+                Fq::new(BigInteger::new([12117996374815862111, 9254775357180774674, 7062741121496766885, 594203943082489718])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([17286447509317767510, 14303476615251161254, 11746384862915063279, 1708573484899774804])),
-                Fq::new(BigInteger::new([7191473077222477811, 15584840425787631738, 12920987968812879593, 2003444515244877099])),
+                Fq::new(BigInteger::new([10516555497566706398, 15619486236648546236, 16605095995842795684, 1166324945666051834])),
+                Fq::new(BigInteger::new([16121940496636359871, 918682078711178441, 7983910058966363677, 636345603269555595])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([12953043469691882072, 14482064072583060530, 14553603318900014263, 2831643118577439238])),
-                Fq::new(BigInteger::new([16401125239171478022, 6940005802450558120, 18191861241283638423, 791139114669226567])),
+                Fq::new(BigInteger::new([12619113684860674171, 12432580252069449924, 596286185967675288, 1930147248718146403])),
+                Fq::new(BigInteger::new([16347807316406145467, 6759462499318247173, 12703327385952464380, 923665217343663219])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([18355460254318359975, 4371905714438984354, 5387575312610787351, 1736689660132834088])),
-                Fq::new(BigInteger::new([13341134467271793863, 8601989430920666037, 12753083417187916925, 810820136199477305])),
+                Fq::new(BigInteger::new([6979030288529428190, 7429956575724123290, 1888435381417877917, 341306247543563647])),
+                Fq::new(BigInteger::new([11068329518960662876, 15099434826324675571, 13404532341910629793, 1284078542677235495])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([1165362593101312736, 3261380734009789365, 11706325681015250867, 3048263730592139767])),
-                Fq::new(BigInteger::new([12104435205087939606, 17220224105756693352, 16214658387374776869, 3043944249741604495])),
+                Fq::new(BigInteger::new([14857143236275356301, 15414638159931242588, 9129148790187184636, 319438274484847182])),
+                Fq::new(BigInteger::new([16221558718115205009, 1582913042544375023, 16949033353770598725, 1187904435485095123])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([13201332563323178704, 13804170630207295670, 9899971904109158063, 2488457379986385519])),
-                Fq::new(BigInteger::new([12700042746465196252, 10572706681491741119, 17271005366167960987, 3094629910528388174])),
+                Fq::new(BigInteger::new([16504176125629092896, 14860366689527814886, 6237118688586456076, 2616782416221593755])),
+                Fq::new(BigInteger::new([7064716306347043419, 16482576483288601771, 2985186408431012322, 2611582519214240428])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([13790957068039378873, 17286081214903819773, 6268001453013309670, 1347411228154787387])),
-                Fq::new(BigInteger::new([1199451128891066917, 16320651976153836719, 12466233957322307619, 952822889899686665])),
+                Fq::new(BigInteger::new([9849407530100949634, 13146965553792283763, 16837377828129160996, 2871555600915966573])),
+                Fq::new(BigInteger::new([3830659822332326099, 11243156277909420642, 2343160625110568977, 1453321178702221035])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([13156953835641938207, 9384711990394384015, 5751374860381394270, 2101318666085768662])),
-                Fq::new(BigInteger::new([8618474838630689346, 9028156410539866133, 18276373004562180018, 1598860898623050064])),
+                Fq::new(BigInteger::new([1123766429429364233, 3611839677970728380, 10717627548495838974, 135872590426708145])),
+                Fq::new(BigInteger::new([558122334399681691, 1776153092159120126, 16728460473821587534, 1371153214072349299])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([14319623491725671353, 17583253326910381518, 587742582299857183, 2575018580751885060])),
-                Fq::new(BigInteger::new([14104613361118871340, 3925264913377191436, 1311299063564931155, 312768282263681228])),
+                Fq::new(BigInteger::new([9609296199973815089, 2268394730510773509, 8997694149522767060, 2655574560356972])),
+                Fq::new(BigInteger::new([18427842870653147048, 13875252679567840765, 5164821144181979790, 1768745724043162579])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([14144685126721371922, 5084476728688869299, 8361265131489786702, 2932882565678923855])),
-                Fq::new(BigInteger::new([14312117980775074493, 9422329009231140037, 14684288641518720492, 2058799075580599781])),
+                Fq::new(BigInteger::new([13187592080666199281, 5250456972334152943, 1693656077091237769, 2774992534644810955])),
+                Fq::new(BigInteger::new([10444277640388484217, 11222796947035624869, 17777179179477278612, 360710898267335398])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([7937872003491805565, 7014539015026416367, 14635858545683577255, 2491875586325633325])),
-                Fq::new(BigInteger::new([14410032079054065922, 11709987754204243140, 1831298473282655069, 76927869886283145])),
+                Fq::new(BigInteger::new([10987002400085729920, 5328165919435088532, 2305417308598766267, 1557819654207354001])),
+                Fq::new(BigInteger::new([13107953943100194371, 15802143922514217035, 13794541519189696891, 1842649416057873705])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([8554741654538257795, 13022138407649108172, 14166165433634877453, 1246634473474424705])),
-                Fq::new(BigInteger::new([4216306737952407286, 7807901265529186084, 18108017887713148918, 2938269620160670327])),
+                Fq::new(BigInteger::new([2386473301796150841, 17419951778451172652, 983992751395813447, 1314656602353960843])),
+                Fq::new(BigInteger::new([15592833064071742548, 14246557365290095100, 5065203370279699592, 2585352523073826083])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([15911852868235495185, 12388338000901551969, 15184224458030608058, 2431794148814803811])),
-                Fq::new(BigInteger::new([17955460481792953253, 3443375445580696623, 5560688878489352226, 1239931759333214985])),
+                Fq::new(BigInteger::new([12539260327255083614, 13510928720350653601, 17181262921604391108, 83455701198029531])),
+                Fq::new(BigInteger::new([809352963616841785, 10526595738469217980, 14859082816453195948, 2719334654694723929])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([5578597729165980548, 7421282036719439667, 12478921380773377804, 69080733806937697])),
-                Fq::new(BigInteger::new([6227703251139622457, 10229111688400749780, 13343725514601445032, 2069398826428542613])),
+                Fq::new(BigInteger::new([10968805082101338462, 981651782717016774, 5049722155143280155, 395057070933172264])),
+                Fq::new(BigInteger::new([11842882396403448082, 5438900806808454224, 2805094357375958318, 2688016518330828225])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([3541671510594189919, 12618932763282477029, 6753124679493443403, 1391578514687893546])),
-                Fq::new(BigInteger::new([12959540648908314945, 14659142086689150196, 4394413140423051114, 2687623861537823540])),
+                Fq::new(BigInteger::new([10678505943610958339, 15271618514794079284, 1135206854025330621, 2005385412031008952])),
+                Fq::new(BigInteger::new([14099405858129053145, 12019167856316806763, 507761389823004258, 2024268779183316784])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([4120043076413925957, 5287305704264982673, 7155327144010345373, 983283911943994181])),
-                Fq::new(BigInteger::new([3600764557673461406, 8670477128451021500, 11037436750917194692, 1492836770625319681])),
+                Fq::new(BigInteger::new([9583359976509409344, 11461151936174739938, 13456788426674974239, 3230916029725545061])),
+                Fq::new(BigInteger::new([7949059922147462441, 4467287322705666495, 16058113052918167518, 88171145108901691])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([16447588007152809523, 17768025240692186485, 8577806503790244117, 1232818352294518699])),
-                Fq::new(BigInteger::new([10962534353050337843, 5069320632945964129, 18185437238021836928, 1570102630464571946])),
+                Fq::new(BigInteger::new([1541178621483000642, 11275620348982846400, 505401655264841741, 2929557441479444150])),
+                Fq::new(BigInteger::new([1583905296575983258, 12397268022648256136, 4232721027528321156, 544028696178093700])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([17238879373103660430, 8368863868187253007, 15359783786939364054, 1355941510014551158])),
-                Fq::new(BigInteger::new([15031103020953575046, 2442864701225595997, 14621846849035277981, 3178567385409791535])),
+                Fq::new(BigInteger::new([17085425336852433552, 11695942482255325964, 4618545777399752711, 405338495714040410])),
+                Fq::new(BigInteger::new([11823649654515055622, 8798955705997425879, 4202024097827910904, 628412515487353046])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([3834979588573835150, 12148061704917709552, 13543027311393973415, 1799238204684235646])),
-                Fq::new(BigInteger::new([10949090601456249179, 4802786748820968465, 17255482405616289015, 81662435622282440])),
+                Fq::new(BigInteger::new([6617321807135196436, 16960697797809928361, 2713052526971668495, 2070348350292180889])),
+                Fq::new(BigInteger::new([9461980940561094377, 15274164800821159160, 10359779283212733558, 2102122344704132033])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([7601012593271494236, 13547581846344804193, 16703976919747592817, 864646052740393915])),
-                Fq::new(BigInteger::new([16023695503745503634, 4825169526912276909, 14809010464657391140, 710211689156798424])),
+                Fq::new(BigInteger::new([6541836086910501572, 16010639161299492955, 7245982681092807753, 1177915691804413923])),
+                Fq::new(BigInteger::new([17704292627358111286, 1681054965162249974, 15563330123695068555, 599771836087768097])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([13090515819677970448, 13283342526880637120, 17214894992194217710, 3065186609894683837])),
-                Fq::new(BigInteger::new([9488048649216854327, 14359221805031046124, 2283971513012149627, 714533263460203410])),
+                Fq::new(BigInteger::new([4773389349831852972, 14343067503388866811, 18036693288791253849, 301631952247996141])),
+                Fq::new(BigInteger::new([14391242719638655817, 14068242962124856318, 8467162319064374721, 3453454815042014118])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([6622672940703683027, 8744725032453056212, 5850674026240813879, 2775744283747079666])),
-                Fq::new(BigInteger::new([14297444949854688611, 15192657065287359435, 13502063680480520841, 1399368572959253459])),
+                Fq::new(BigInteger::new([12424023449762869641, 2326011927411393036, 9379981212098488428, 1907037957254866160])),
+                Fq::new(BigInteger::new([4580612028229415329, 12982635093184872912, 1534854181845763856, 2429027318819473250])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([9802148814220452825, 7833755365339493379, 17279717168305961624, 2099448011681252129])),
-                Fq::new(BigInteger::new([12742124783915426051, 11746601139469055324, 8871286447883587234, 1266849411560566377])),
+                Fq::new(BigInteger::new([17946788799810898181, 18384289835204113859, 3996773773684479095, 3382756618446613696])),
+                Fq::new(BigInteger::new([14559426628974464017, 10426733134981851720, 10203451818514483517, 797280128379842676])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([8912000224192366675, 1546007329072038010, 14180486608576145066, 101450912251382988])),
-                Fq::new(BigInteger::new([2522327948864680913, 12625182306476867285, 17791789390352644656, 1023252061993240802])),
+                Fq::new(BigInteger::new([10790357558267953007, 14991845026411604669, 9532117003302812068, 2635188408320003900])),
+                Fq::new(BigInteger::new([17713965502789995107, 9008117740334605746, 14133865263914395941, 3308134741612349208])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([4831091246153377969, 13761629656858056821, 7919588134346303882, 1546741275397013236])),
-                Fq::new(BigInteger::new([17822756611578087653, 8620183596995265029, 11039925481898165487, 1999571259216615815])),
+                Fq::new(BigInteger::new([13781026699600534104, 7345901746912565490, 6516769473546198094, 202045073755902572])),
+                Fq::new(BigInteger::new([16597099009345360348, 4808025525028318799, 14146630203051445173, 1719916361104721496])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([2641981512907510517, 15317125121982351295, 16672703274154615543, 2859546104825719968])),
-                Fq::new(BigInteger::new([18425866186178734570, 7521950593833718700, 15861656268939661622, 2566080221353852230])),
+                Fq::new(BigInteger::new([6569684999870716057, 12226991672024082885, 9949822460756544729, 2884443892412611083])),
+                Fq::new(BigInteger::new([17003404017049073424, 8153840911037432244, 10980454910315124397, 1968140174229504487])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([13470504164206195687, 15400835550056566770, 4521457085104384444, 3328277036500354460])),
-                Fq::new(BigInteger::new([16671626822313173376, 295469646477907572, 7244693664645721805, 1817049121620913576])),
+                Fq::new(BigInteger::new([5772796460801527753, 16397325882299091801, 4529071308378590870, 2382446641958219009])),
+                Fq::new(BigInteger::new([7379305527028510262, 187997031162397940, 4073270451076839421, 2119270584727591481])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([15937875430282464306, 15740261185268572132, 11120817128285458555, 584780530572507944])),
-                Fq::new(BigInteger::new([2957292188007954814, 780422048158717950, 5719876984351015053, 2811617122160481700])),
+                Fq::new(BigInteger::new([8300251130669766571, 14226578894081109854, 5934626448857720365, 401476921643773399])),
+                Fq::new(BigInteger::new([2427039394469961851, 10766406812526099366, 13428226882164482994, 2131008935302345762])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([7800960668143946341, 18157938805552408465, 10117624939022301216, 3255326547498164730])),
-                Fq::new(BigInteger::new([12853784651581858966, 1031967108087640507, 11436043833409975935, 2331233026409213117])),
+                Fq::new(BigInteger::new([13638687291683045412, 12173043779915513240, 14485856409047817353, 2788273939840228454])),
+                Fq::new(BigInteger::new([331325828096219232, 4351487168348471918, 7219960068113192160, 417552424853140144])),
             ),
         ),
         (
             Fq2::new_const(
-                Fq::new(BigInteger::new([14765222860370059828, 3694097291883174401, 4229019185266015332, 1522891176422273737])),
-                Fq::new(BigInteger::new([2700802141558202390, 14439256427020777423, 1090737726326112421, 1653118623762630729])),
+                Fq::new(BigInteger::new([13703400031442662318, 7731922255743214770, 43925265771715560, 1503321353209363614])),
+                Fq::new(BigInteger::new([13676335545162730730, 14322861822002118443, 2343332620241156156, 1544372692210545438])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([17589747443084593509, 17057422960672247037, 15810778141014524860, 450567275033794579])),
-                Fq::new(BigInteger::new([9784458623220571082, 12841183071042524066, 835021133709993814, 1178073900729484206])),
+                Fq::new(BigInteger::new([949319784844733284, 17000143093460280407, 8821921600919858284, 783664617558175381])),
+                Fq::new(BigInteger::new([1570570450848139493, 1041166013097283878, 4398928443305274349, 2034169338847316091])),
             ),
             Fq2::new_const(
-                Fq::new(BigInteger::new([16225257582097806997, 13037431990062972147, 12240706423805104381, 2708528086820631058])),
-                Fq::new(BigInteger::new([3582206383340731634, 3485828491005101051, 6358243555020338046, 2568379903394231166])),
+                Fq::new(BigInteger::new([18237655038182127494, 7576540888309171509, 10012826717981623682, 2254983196787820488])),
+                Fq::new(BigInteger::new([13197674316743220578, 7315348404189563154, 10409781329098122208, 579741419974707340])),
             ),
         ),
     ],
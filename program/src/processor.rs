@@ -1,24 +1,30 @@
 use borsh::BorshDeserialize;
-use solana_program::{msg, pubkey::Pubkey, account_info::{AccountInfo, next_account_info}};
+use solana_program::{msg, pubkey::Pubkey, account_info::{AccountInfo, next_account_info}, program_error::ProgramError};
 use solana_program::entrypoint::ProgramResult;
 
 use crate::{
     Packer,
     error::MazeError,
+    params::{HEIGHT, BATCH_DEPOSIT_SIZE, AUDITORS},
     instruction::MazeInstruction,
     bn::BigInteger256 as BigInteger,
     verifier::{Proof, Verifier, get_verifier_pda},
     core::{
         VanillaData,
         EdwardsAffine,
+        is_edwards_affine_valid,
         nullifier::{get_nullifier_pda, Nullifier},
         commitment::{get_commitment_pda, Commitment, InnerCommitment},
-        credential::{get_deposit_credential_pda, get_withdraw_credential_pda},
+        credential::{get_deposit_credential_pda, get_withdraw_credential_pda, get_transfer_credential_pda, get_batch_deposit_credential_pda},
         deposit::{DepositCredential, DepositVanillaData},
+        batch_deposit::{BatchDepositCredential, BatchDepositVanillaData},
         withdraw::{WithdrawCredential, WithdrawVanillaData},
+        transfer::{TransferCredential, TransferVanillaData},
         vault::{Vault, get_vault_pda, get_vault_authority_pda},
-        node::{MerkleNode, get_merkle_node_pda, gen_merkle_path_from_leaf_index},
-        utxo::{UTXO, Amount, get_utxo_pda},
+        node::{MerkleNode, get_merkle_node_pda, gen_batch_subtree_from_leaf_index, default_node_hash, hash_two},
+        utxo::{UTXO, Amount, get_utxo_pda, check_note_cipher},
+        exclusion::{ExclusionSet, get_exclusion_set_pda},
+        allowlist::{AllowlistRegistry, get_allowlist_registry_pda},
     },
     invoke::{
         process_token_transfer,
@@ -38,10 +44,11 @@ pub fn process_instruction(
     match instruction {
         MazeInstruction::CreateDepositCredential {
             deposit_amount,
+            token_mint,
+            leaf_index,
             leaf,
-            updating_nodes,
             commitment,
-        } => process_create_deposit_credential(program_id, accounts, deposit_amount, leaf, updating_nodes, commitment),
+        } => process_create_deposit_credential(program_id, accounts, deposit_amount, token_mint, leaf_index, leaf, commitment),
         MazeInstruction::CreateDepositVerifier {
             proof,
         } => process_create_deposit_verifier(program_id, accounts, proof),
@@ -51,12 +58,17 @@ pub fn process_instruction(
         } => process_finalize_deposit(program_id, accounts, utxo),
         MazeInstruction::CreateWithdrawCredential {
             withdraw_amount,
+            token_mint,
+            fee,
             receiver,
             nullifier_point,
+            leaf_index,
             leaf,
-            updating_nodes,
+            src_root,
             commitment,
-        } => process_create_withdraw_credential(program_id, accounts, withdraw_amount, receiver, nullifier_point, leaf, updating_nodes, commitment),
+            exclusion_root,
+            allowlist_root,
+        } => process_create_withdraw_credential(program_id, accounts, withdraw_amount, token_mint, fee, receiver, nullifier_point, leaf_index, leaf, src_root, commitment, exclusion_root, allowlist_root),
         MazeInstruction::CreateWithdrawVerifier {
             proof,
         } => process_create_withdraw_verifier(program_id, accounts, proof),
@@ -65,12 +77,60 @@ pub fn process_instruction(
             utxo,
             balance_cipher,
         } => process_finalize_withdraw(program_id, accounts, utxo, balance_cipher),
+        MazeInstruction::CreateTransferCredential {
+            withdraw_amount,
+            token_mint,
+            receiver,
+            nullifier_points,
+            src_root,
+            leaf_index,
+            leaves,
+            commitments,
+        } => process_create_transfer_credential(program_id, accounts, withdraw_amount, token_mint, receiver, nullifier_points, src_root, leaf_index, leaves, commitments),
+        MazeInstruction::CreateTransferVerifier {
+            proof,
+        } => process_create_transfer_verifier(program_id, accounts, proof),
+        MazeInstruction::VerifyTransferProof => process_verify_transfer_proof(program_id, accounts),
+        MazeInstruction::FinalizeTransfer {
+            utxos,
+            balance_ciphers,
+            ephemeral_pubkey,
+        } => process_finalize_transfer(program_id, accounts, utxos, balance_ciphers, ephemeral_pubkey),
+        MazeInstruction::CreateBatchDepositCredential {
+            deposit_amounts,
+            token_mint,
+            leaf_index,
+            leaves,
+            subtree_nodes,
+            commitments,
+        } => process_create_batch_deposit_credential(program_id, accounts, deposit_amounts, token_mint, leaf_index, leaves, subtree_nodes, commitments),
+        MazeInstruction::CreateBatchDepositVerifier {
+            proof,
+        } => process_create_batch_deposit_verifier(program_id, accounts, proof),
+        MazeInstruction::VerifyBatchDepositProof => process_verify_batch_deposit_proof(program_id, accounts),
+        MazeInstruction::FinalizeBatchDeposit {
+            utxos,
+        } => process_finalize_batch_deposit(program_id, accounts, utxos),
         MazeInstruction::CreateVault {
             min_deposit,
             min_withdraw,
-            delegate_fee,
-        } => process_create_vault(program_id, accounts, min_deposit, min_withdraw, delegate_fee),
+            min_anonymity_gap,
+            multi_asset,
+            auditor_keys,
+        } => process_create_vault(program_id, accounts, min_deposit, min_withdraw, min_anonymity_gap, multi_asset, auditor_keys),
         MazeInstruction::ControlVault(enable) => process_control_vault(program_id, accounts, enable),
+        MazeInstruction::AddVaultAsset => process_add_vault_asset(program_id, accounts),
+        MazeInstruction::RotateAuditorKeys(auditor_keys) => process_rotate_auditor_keys(program_id, accounts, auditor_keys),
+        MazeInstruction::SetMinAnonymityGap(min_anonymity_gap) => process_set_min_anonymity_gap(program_id, accounts, min_anonymity_gap),
+        MazeInstruction::CreateExclusionSet { curator } => process_create_exclusion_set(program_id, accounts, curator),
+        MazeInstruction::UpdateExclusionRoot(root) => process_update_exclusion_root(program_id, accounts, root),
+        MazeInstruction::CreateAllowlistRegistry => process_create_allowlist_registry(program_id, accounts),
+        MazeInstruction::AddAllowlistRoot(root) => process_update_allowlist_registry(program_id, accounts, root, true),
+        MazeInstruction::RemoveAllowlistRoot(root) => process_update_allowlist_registry(program_id, accounts, root, false),
+        MazeInstruction::UpdateMerkleNode {
+            layer,
+            index,
+        } => process_update_merkle_node(program_id, accounts, layer, index),
     }
 }
 
@@ -81,8 +141,9 @@ fn process_create_deposit_credential(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     deposit_amount: u64,
+    token_mint: Pubkey,
+    leaf_index: u64,
     leaf: BigInteger,
-    updating_nodes: Box<Vec<BigInteger>>,
     commitment: InnerCommitment,
 ) -> ProgramResult {
     msg!("Creating deposit credential: deposit amount {}", deposit_amount);
@@ -95,9 +156,10 @@ fn process_create_deposit_credential(
     let credential_info = next_account_info(accounts_iter)?;
     let depositor_info = next_account_info(accounts_iter)?;
 
-    let vault = Vault::unpack_from_account_info(vault_info, program_id)?;
+    let mut vault = Vault::unpack_from_account_info(vault_info, program_id)?;
     vault.check_enable()?;
     vault.check_deposit(deposit_amount)?;
+    vault.check_token_mint(&token_mint)?;
 
     if !depositor_info.is_signer {
         return Err(MazeError::InvalidAuthority.into());
@@ -112,6 +174,14 @@ fn process_create_deposit_credential(
         msg!("Credential pubkey is invalid");
         return Err(MazeError::InvalidPdaPubkey.into());
     }
+    // a recreated credential keeps the leaf reserved by the abandoned one
+    match DepositCredential::unpack_from_account_info(credential_info, program_id) {
+        Ok(credential) => check_reserved_leaf_index(credential.vanilla_data.leaf_index, leaf_index)?,
+        Err(_) => {
+            vault.reserve_leaves(leaf_index, 1)?;
+            vault.pack_to_account_info(vault_info)?;
+        }
+    }
     process_optimal_create_account(
         rent_info,
         credential_info,
@@ -125,16 +195,16 @@ fn process_create_deposit_credential(
 
     let vanilla_data = DepositVanillaData::new(
         deposit_amount,
-        vault.index,
+        token_mint,
+        leaf_index,
         leaf,
-        vault.root,
-        updating_nodes,
         commitment,
     )?;
     // create credential
     let credential = DepositCredential::new(
         *vault_info.key,
         *depositor_info.key,
+        vault.auditor_keys,
         vanilla_data,
     );
     credential.pack_to_account_info(credential_info)
@@ -173,9 +243,6 @@ fn process_create_deposit_verifier(
         msg!("Depositor pubkey is invalid");
         return Err(MazeError::UnmatchedAccounts.into());
     }
-    // check consistency
-    vault.check_consistency(credential.vanilla_data.leaf_index, &credential.vanilla_data.prev_root)?;
-
     let (verifier_key, (seed_1, seed_2)) = get_verifier_pda(
         credential_info.key,
         program_id,
@@ -195,7 +262,7 @@ fn process_create_deposit_verifier(
         &[seed_1, &seed_2],
     )?;
     // create verifier
-    let verifier = credential.vanilla_data.to_verifier(proof)?;
+    let verifier = credential.vanilla_data.to_verifier(proof, &credential.auditor_keys)?;
     verifier.pack_to_account_info(verifier_info)
 }
 
@@ -219,9 +286,6 @@ fn process_verify_deposit_proof(
         msg!("Vault pubkey is invalid");
         return Err(MazeError::InvalidPdaPubkey.into());
     }
-    // check consistency
-    vault.check_consistency(credential.vanilla_data.leaf_index, &credential.vanilla_data.prev_root)?;
-
     let (verifier_key, _) = get_verifier_pda(
         credential_info.key,
         program_id,
@@ -259,14 +323,11 @@ fn process_finalize_deposit(
     let utxo_info = next_account_info(accounts_iter)?;
     let depositor_info = next_account_info(accounts_iter)?;
 
-    let mut vault = Vault::unpack_from_account_info(vault_info, program_id)?;
-    if &vault.token_account != vault_token_account_info.key {
-        msg!("Vault token account pubkey is invalid");
-        return Err(MazeError::UnmatchedAccounts.into());
-    }
+    let vault = Vault::unpack_from_account_info(vault_info, program_id)?;
     vault.check_enable()?;
 
     let credential = DepositCredential::unpack_from_account_info(credential_info, program_id)?;
+    vault.check_token_account(&credential.vanilla_data.token_mint, vault_token_account_info.key)?;
     if &credential.vault != vault_info.key {
         msg!("Vault pubkey is invalid");
         return Err(MazeError::UnmatchedAccounts.into());
@@ -275,7 +336,6 @@ fn process_finalize_deposit(
         msg!("Depositor pubkey is invalid");
         return Err(MazeError::UnmatchedAccounts.into());
     }
-    vault.check_consistency(credential.vanilla_data.leaf_index, &credential.vanilla_data.prev_root)?;
 
     let (verifier_key, _) = get_verifier_pda(
         credential_info.key,
@@ -314,53 +374,32 @@ fn process_finalize_deposit(
         msg!("UTXO pubkey is invalid");
         return Err(MazeError::InvalidPdaPubkey.into());
     }
+    let utxo = UTXO::new(credential.vanilla_data.leaf_index, Amount::Origin(credential.vanilla_data.deposit_amount));
     process_optimal_create_account(
         rent_info,
         utxo_info,
         depositor_info,
         system_program_info,
         program_id,
-        UTXO::LEN,
+        utxo.size(),
         &[],
         &[seed_1, &seed_2],
     )?;
-    let utxo = UTXO::new(credential.vanilla_data.leaf_index, Amount::Origin(credential.vanilla_data.deposit_amount));
     utxo.initialize_to_account_info(utxo_info)?;
 
-    let merkle_path = gen_merkle_path_from_leaf_index(vault.index);
-    let mut merkle_nodes = credential.vanilla_data.updating_nodes;
-    let new_root = merkle_nodes.pop().unwrap();
-    merkle_nodes.insert(0, credential.vanilla_data.leaf);
-    // check and update merkle nodes
-    merkle_nodes
-        .into_iter()
-        .zip(merkle_path)
-        .try_for_each(|(node, (layer, index))| {
-            let node_info = next_account_info(accounts_iter)?;
-            let (node_key, (seed_1, seed_2, seed_3, seed_4)) = get_merkle_node_pda(
-                vault_info.key,
-                layer,
-                index,
-                program_id,
-            );
-            if &node_key != node_info.key {
-                msg!("Node at layer {} index {} is invalid", layer, index);
-                return Err(MazeError::UnmatchedAccounts.into());
-            }
-            process_optimal_create_account(
-                rent_info,
-                node_info,
-                depositor_info,
-                system_program_info,
-                program_id,
-                MerkleNode::LEN,
-                &[],
-                &[seed_1, &seed_2, &seed_3, &seed_4],
-            )?;
-            MerkleNode::new(node).pack_to_account_info(node_info)
-        })?;
-    vault.update(new_root);
-    vault.pack_to_account_info(vault_info)?;
+    // store the leaf, its path up to the root is hashed by `UpdateMerkleNode`
+    let node_info = next_account_info(accounts_iter)?;
+    process_write_merkle_node(
+        program_id,
+        rent_info,
+        system_program_info,
+        vault_info,
+        node_info,
+        depositor_info,
+        0,
+        credential.vanilla_data.leaf_index,
+        credential.vanilla_data.leaf,
+    )?;
 
     // transfer token from user to vault
     process_token_transfer(
@@ -379,21 +418,20 @@ fn process_finalize_deposit(
     Ok(())
 }
 
-/////////////////////////////////// Withdraw Actions ////////////////////////////////////////
+/////////////////////////////////// Batch Deposit Actions ////////////////////////////////////////
 
 #[inline(never)]
-#[allow(clippy::too_many_arguments)]
-fn process_create_withdraw_credential(
+fn process_create_batch_deposit_credential(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
-    withdraw_amount: u64,
-    receiver: Pubkey,
-    nullifier_point: EdwardsAffine,
-    leaf: BigInteger,
-    updating_nodes: Box<Vec<BigInteger>>,
-    commitment: InnerCommitment,
+    deposit_amounts: Box<Vec<u64>>,
+    token_mint: Pubkey,
+    leaf_index: u64,
+    leaves: Box<Vec<BigInteger>>,
+    subtree_nodes: Box<Vec<BigInteger>>,
+    commitments: Box<Vec<InnerCommitment>>,
 ) -> ProgramResult {
-    msg!("Creating withdraw credential: withdraw amount {}", withdraw_amount);
+    msg!("Creating batch deposit credential: deposit amounts {:?}", deposit_amounts);
 
     let accounts_iter = &mut accounts.iter();
 
@@ -401,63 +439,71 @@ fn process_create_withdraw_credential(
     let rent_info = next_account_info(accounts_iter)?;
     let vault_info = next_account_info(accounts_iter)?;
     let credential_info = next_account_info(accounts_iter)?;
-    let delegator_info = next_account_info(accounts_iter)?;
+    let depositor_info = next_account_info(accounts_iter)?;
 
-    let vault = Vault::unpack_from_account_info(vault_info, program_id)?;
+    let mut vault = Vault::unpack_from_account_info(vault_info, program_id)?;
     vault.check_enable()?;
-    vault.check_withdraw(withdraw_amount)?;
+    deposit_amounts.iter().try_for_each(|amount| vault.check_deposit(*amount))?;
+    vault.check_token_mint(&token_mint)?;
 
-    if !delegator_info.is_signer {
+    if !depositor_info.is_signer {
         return Err(MazeError::InvalidAuthority.into());
     }
 
-    let (credential_key, (seed_1, seed_2, seed_3, seed_4, seed_5)) = get_withdraw_credential_pda(
+    let (credential_key, (seed_1, seed_2, seed_3, seed_4)) = get_batch_deposit_credential_pda(
         vault_info.key,
-        delegator_info.key,
-        &receiver,
+        depositor_info.key,
         program_id,
     );
     if credential_info.key != &credential_key {
         msg!("Credential pubkey is invalid");
         return Err(MazeError::InvalidPdaPubkey.into());
     }
+    // a recreated credential keeps the subtree reserved by the abandoned one
+    match BatchDepositCredential::unpack_from_account_info(credential_info, program_id) {
+        Ok(credential) => check_reserved_leaf_index(credential.vanilla_data.leaf_index, leaf_index)?,
+        Err(_) => {
+            vault.reserve_batch_leaves(leaf_index)?;
+            vault.pack_to_account_info(vault_info)?;
+        }
+    }
     process_optimal_create_account(
         rent_info,
         credential_info,
-        delegator_info,
+        depositor_info,
         system_program_info,
         program_id,
-        WithdrawCredential::LEN,
+        BatchDepositCredential::LEN,
         &[],
-        &[seed_1, seed_2, seed_3, seed_4, &seed_5],
+        &[seed_1, seed_2, seed_3, &seed_4],
     )?;
 
-    let vanilla_data = WithdrawVanillaData::new(
-        receiver,
-        withdraw_amount,
-        nullifier_point,
-        vault.index,
-        leaf,
-        vault.root,
-        updating_nodes,
-        commitment,
+    let vanilla_data = BatchDepositVanillaData::new(
+        deposit_amounts,
+        token_mint,
+        leaf_index,
+        leaves,
+        subtree_nodes,
+        commitments,
     )?;
+    vanilla_data.total_amount().ok_or(MazeError::Overflow)?;
     // create credential
-    let credential = WithdrawCredential::new(
+    let credential = BatchDepositCredential::new(
         *vault_info.key,
-        *delegator_info.key,
+        *depositor_info.key,
+        vault.auditor_keys,
         vanilla_data,
     );
     credential.pack_to_account_info(credential_info)
 }
 
 #[inline(never)]
-fn process_create_withdraw_verifier(
+fn process_create_batch_deposit_verifier(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     proof: Box<Proof>,
 ) -> ProgramResult {
-    msg!("Creating withdraw verifier");
+    msg!("Creating batch deposit verifier");
 
     let accounts_iter = &mut accounts.iter();
 
@@ -466,39 +512,36 @@ fn process_create_withdraw_verifier(
     let vault_info = next_account_info(accounts_iter)?;
     let credential_info = next_account_info(accounts_iter)?;
     let verifier_info = next_account_info(accounts_iter)?;
-    let delegator_info = next_account_info(accounts_iter)?;
+    let depositor_info = next_account_info(accounts_iter)?;
 
     let vault = Vault::unpack_from_account_info(vault_info, program_id)?;
     vault.check_enable()?;
 
-    if !delegator_info.is_signer {
+    if !depositor_info.is_signer {
         return Err(MazeError::InvalidAuthority.into());
     }
 
-    let credential = WithdrawCredential::unpack_from_account_info(credential_info, program_id)?;
+    let credential = BatchDepositCredential::unpack_from_account_info(credential_info, program_id)?;
     if &credential.vault != vault_info.key {
         msg!("Vault pubkey is invalid");
         return Err(MazeError::InvalidPdaPubkey.into());
     }
-    if &credential.owner != delegator_info.key {
-        msg!("Delegator pubkey is invalid");
+    if &credential.owner != depositor_info.key {
+        msg!("Depositor pubkey is invalid");
         return Err(MazeError::UnmatchedAccounts.into());
     }
-    // check consistency
-    vault.check_consistency(credential.vanilla_data.leaf_index, &credential.vanilla_data.prev_root)?;
-
     let (verifier_key, (seed_1, seed_2)) = get_verifier_pda(
         credential_info.key,
         program_id,
     );
     if verifier_info.key != &verifier_key {
         msg!("Verifier pubkey is invalid");
-        return Err(MazeError::UnmatchedAccounts.into());
+        return Err(MazeError::InvalidPdaPubkey.into());
     }
     process_optimal_create_account(
         rent_info,
         verifier_info,
-        delegator_info,
+        depositor_info,
         system_program_info,
         program_id,
         Verifier::LEN,
@@ -506,11 +549,11 @@ fn process_create_withdraw_verifier(
         &[seed_1, &seed_2],
     )?;
     // create verifier
-    let verifier = credential.vanilla_data.to_verifier(proof)?;
+    let verifier = credential.vanilla_data.to_verifier(proof, &credential.auditor_keys)?;
     verifier.pack_to_account_info(verifier_info)
 }
 
-fn process_verify_withdraw_proof(
+fn process_verify_batch_deposit_proof(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
 ) -> ProgramResult {
@@ -525,14 +568,11 @@ fn process_verify_withdraw_proof(
     let vault = Vault::unpack_from_account_info(vault_info, program_id)?;
     vault.check_enable()?;
 
-    let credential = WithdrawCredential::unpack_from_account_info(credential_info, program_id)?;
+    let credential = BatchDepositCredential::unpack_from_account_info(credential_info, program_id)?;
     if &credential.vault != vault_info.key {
         msg!("Vault pubkey is invalid");
         return Err(MazeError::InvalidPdaPubkey.into());
     }
-    // check consistency
-    vault.check_consistency(credential.vanilla_data.leaf_index, &credential.vanilla_data.prev_root)?;
-
     let (verifier_key, _) = get_verifier_pda(
         credential_info.key,
         program_id,
@@ -549,60 +589,43 @@ fn process_verify_withdraw_proof(
 }
 
 #[inline(never)]
-fn process_finalize_withdraw(
+fn process_finalize_batch_deposit(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
-    utxo: [u8; 32],
-    balance_cipher: u128,
+    utxos: Box<Vec<[u8; 32]>>,
 ) -> ProgramResult {
-    msg!("Finalizing withdraw");
+    msg!("Finalizing batch deposit");
 
     let accounts_iter = &mut accounts.iter();
 
     let system_program_info = next_account_info(accounts_iter)?;
     let token_program_info = next_account_info(accounts_iter)?;
-    let spl_associated_program_info = next_account_info(accounts_iter)?;
     let rent_info = next_account_info(accounts_iter)?;
-    let token_mint_info = next_account_info(accounts_iter)?;
     let vault_info = next_account_info(accounts_iter)?;
     let credential_info = next_account_info(accounts_iter)?;
     let verifier_info = next_account_info(accounts_iter)?;
-    let nullifier_info = next_account_info(accounts_iter)?;
-    let commitment_info = next_account_info(accounts_iter)?;
+    let src_token_account_info = next_account_info(accounts_iter)?;
     let vault_token_account_info = next_account_info(accounts_iter)?;
-    let dst_token_account_info = next_account_info(accounts_iter)?;
-    let delegator_token_account_info = next_account_info(accounts_iter)?;
-    let vault_signer_info = next_account_info(accounts_iter)?;
-    let receiver_info = next_account_info(accounts_iter)?;
-    let utxo_info = next_account_info(accounts_iter)?;
-    let delegator_info = next_account_info(accounts_iter)?;
+    let depositor_info = next_account_info(accounts_iter)?;
 
-    let mut vault = Vault::unpack_from_account_info(vault_info, program_id)?;
-    if &vault.token_account != vault_token_account_info.key {
-        msg!("Vault token account pubkey is invalid");
-        return Err(MazeError::UnmatchedAccounts.into());
-    }
-    if &vault.authority != vault_signer_info.key {
-        msg!("Vault authority pubkey is invalid");
-        return Err(MazeError::UnmatchedAccounts.into());
+    if utxos.len() != BATCH_DEPOSIT_SIZE {
+        msg!("UTXOs length is invalid");
+        return Err(MazeError::InvalidVanillaData.into());
     }
+
+    let vault = Vault::unpack_from_account_info(vault_info, program_id)?;
     vault.check_enable()?;
 
-    let credential = WithdrawCredential::unpack_from_account_info(credential_info, program_id)?;
+    let credential = BatchDepositCredential::unpack_from_account_info(credential_info, program_id)?;
+    vault.check_token_account(&credential.vanilla_data.token_mint, vault_token_account_info.key)?;
     if &credential.vault != vault_info.key {
         msg!("Vault pubkey is invalid");
         return Err(MazeError::UnmatchedAccounts.into());
     }
-    if &credential.owner != delegator_info.key {
-        msg!("Delegator pubkey is invalid");
-        return Err(MazeError::UnmatchedAccounts.into());
-    }
-    if &credential.vanilla_data.receiver != receiver_info.key {
-        msg!("Receiver pubkey is invalid");
+    if &credential.owner != depositor_info.key {
+        msg!("Depositor pubkey is invalid");
         return Err(MazeError::UnmatchedAccounts.into());
     }
-    // check if leaf index and root is matched
-    vault.check_consistency(credential.vanilla_data.leaf_index, &credential.vanilla_data.prev_root)?;
 
     let (verifier_key, _) = get_verifier_pda(
         credential_info.key,
@@ -616,160 +639,1072 @@ fn process_finalize_withdraw(
     verifier.check_consistency(&credential.vanilla_data)?;
     verifier.program.check_verified()?;
 
-    let (nullifier_key, (seed_1, seed_2, seed_3)) = get_nullifier_pda(
-        &credential.vanilla_data.nullifier_point,
-        program_id,
-    );
-    if &nullifier_key != nullifier_info.key {
-        msg!("Nullifier pubkey is invalid");
-        return Err(MazeError::InvalidPdaPubkey.into());
-    }
-    process_optimal_create_account(
-        rent_info,
-        nullifier_info,
-        delegator_info,
-        system_program_info,
-        program_id,
-        Nullifier::LEN,
-        &[],
-        &[&seed_1, &seed_2, &seed_3],
-    )?;
-    Nullifier::new(credential.vanilla_data.receiver).initialize_to_account_info(nullifier_info)?;
-
-    let (commitment_key, (seed_1, seed_2)) = get_commitment_pda(
-        &credential.vanilla_data.leaf,
-        program_id,
-    );
-    if &commitment_key != commitment_info.key {
-        return Err(MazeError::InvalidPdaPubkey.into());
-    }
-    process_optimal_create_account(
-        rent_info,
-        commitment_info,
-        delegator_info,
-        system_program_info,
-        program_id,
-        Commitment::LEN,
-        &[],
-        &[&seed_1, &seed_2],
-    )?;
-    Commitment::new(credential.vanilla_data.commitment).initialize_to_account_info(commitment_info)?;
-
-    // store uxto on chain
-    let (utxo_pubkey, (seed_1, seed_2)) = get_utxo_pda(&utxo, program_id);
-    if &utxo_pubkey != utxo_info.key {
-        msg!("UTXO pubkey is invalid");
-        return Err(MazeError::InvalidPdaPubkey.into());
-    }
-    process_optimal_create_account(
-        rent_info,
-        utxo_info,
-        delegator_info,
-        system_program_info,
-        program_id,
-        UTXO::LEN,
-        &[],
-        &[seed_1, &seed_2],
-    )?;
-    let utxo = UTXO::new(credential.vanilla_data.leaf_index, Amount::Cipher(balance_cipher));
-    utxo.initialize_to_account_info(utxo_info)?;
-
-    let merkle_path = gen_merkle_path_from_leaf_index(vault.index);
-    let mut merkle_nodes = credential.vanilla_data.updating_nodes;
-    let new_root = merkle_nodes.pop().unwrap();
-    merkle_nodes.insert(0, credential.vanilla_data.leaf);
-    // check and update merkle nodes
-    merkle_nodes
-        .into_iter()
-        .zip(merkle_path)
-        .try_for_each(|(node, (layer, index))| {
-            let node_info = next_account_info(accounts_iter)?;
-            let (node_key, (seed_1, seed_2, seed_3, seed_4)) = get_merkle_node_pda(
-                vault_info.key,
-                layer,
-                index,
+    let leaf_index = credential.vanilla_data.leaf_index;
+    let total_amount = credential.vanilla_data.total_amount().ok_or(MazeError::Overflow)?;
+    let vanilla_data = credential.vanilla_data;
+
+    // store commitments on chain
+    vanilla_data.leaves
+        .iter()
+        .zip(vanilla_data.commitments.iter())
+        .try_for_each(|(leaf, commitment)| {
+            let commitment_info = next_account_info(accounts_iter)?;
+            let (commitment_key, (seed_1, seed_2)) = get_commitment_pda(leaf, program_id);
+            if &commitment_key != commitment_info.key {
+                return Err(MazeError::InvalidPdaPubkey.into());
+            }
+            process_optimal_create_account(
+                rent_info,
+                commitment_info,
+                depositor_info,
+                system_program_info,
                 program_id,
-            );
-            if &node_key != node_info.key {
-                msg!("Node at layer {} index {} is invalid", layer, index);
-                return Err(MazeError::UnmatchedAccounts.into());
+                Commitment::LEN,
+                &[],
+                &[&seed_1, &seed_2],
+            )?;
+            Commitment::new(commitment.clone()).initialize_to_account_info(commitment_info)
+        })?;
+
+    // store uxtos on chain
+    utxos
+        .iter()
+        .zip(vanilla_data.deposit_amounts.iter())
+        .enumerate()
+        .try_for_each(|(i, (utxo, amount))| {
+            let utxo_info = next_account_info(accounts_iter)?;
+            let (utxo_pubkey, (seed_1, seed_2)) = get_utxo_pda(utxo, program_id);
+            if &utxo_pubkey != utxo_info.key {
+                msg!("UTXO pubkey is invalid");
+                return Err(MazeError::InvalidPdaPubkey.into());
             }
+            let utxo = UTXO::new(leaf_index + i as u64, Amount::Origin(*amount));
             process_optimal_create_account(
                 rent_info,
-                node_info,
-                delegator_info,
+                utxo_info,
+                depositor_info,
                 system_program_info,
                 program_id,
-                MerkleNode::LEN,
+                utxo.size(),
                 &[],
-                &[seed_1, &seed_2, &seed_3, &seed_4],
+                &[seed_1, &seed_2],
             )?;
-            MerkleNode::new(node).pack_to_account_info(node_info)
+            utxo.initialize_to_account_info(utxo_info)
         })?;
-    vault.update(new_root);
-    vault.pack_to_account_info(vault_info)?;
 
-    process_optimal_create_token_account(
-        rent_info,
-        token_mint_info,
-        dst_token_account_info,
-        delegator_info,
-        receiver_info,
-        token_program_info,
-        system_program_info,
-        spl_associated_program_info,
-        &[],
-    )?;
+    // store the subtree proven by the circuit, its path up to the root is hashed by `UpdateMerkleNode`
+    let subtree_path = gen_batch_subtree_from_leaf_index(leaf_index);
+    vanilla_data.leaves
+        .iter()
+        .chain(vanilla_data.subtree_nodes.iter())
+        .zip(subtree_path)
+        .try_for_each(|(node, (layer, index))| {
+            let node_info = next_account_info(accounts_iter)?;
+            process_write_merkle_node(
+                program_id,
+                rent_info,
+                system_program_info,
+                vault_info,
+                node_info,
+                depositor_info,
+                layer,
+                index,
+                *node,
+            )
+        })?;
 
-    let receive_amount = credential.vanilla_data.withdraw_amount
-        .checked_sub(vault.delegate_fee)
-        .ok_or(MazeError::Overflow)?;
-    // transfer token from vault to receiver
-    process_token_transfer(
-        token_program_info,
-        vault_token_account_info,
-        dst_token_account_info,
-        vault_signer_info,
-        &vault.signer_seeds(vault_info.key),
-        receive_amount,
-    )?;
-    // transfer token from vault to delegator
+    // transfer token from user to vault
     process_token_transfer(
         token_program_info,
+        src_token_account_info,
         vault_token_account_info,
-        delegator_token_account_info,
-        vault_signer_info,
-        &vault.signer_seeds(vault_info.key),
-        vault.delegate_fee,
+        depositor_info,
+        &[],
+        total_amount,
     )?;
-
-    // transfer `SOL` as fee from delegator to owner if there is less balance.
-    const FEE: u64 = 1_000_000;
-    if receiver_info.try_lamports()? < FEE {
-        let lamports = FEE - receiver_info.try_lamports()?;
-        process_transfer(delegator_info, receiver_info, system_program_info, &[], lamports)?;
-    }
     // clear verifier
-    process_rent_refund(verifier_info, delegator_info);
+    process_rent_refund(verifier_info, depositor_info);
     // clear credential
-    process_rent_refund(credential_info, delegator_info);
+    process_rent_refund(credential_info, depositor_info);
 
     Ok(())
 }
 
-/////////////////////////////////////////////////// admin authority ///////////////////////////////////////////////////
+/////////////////////////////////// Withdraw Actions ////////////////////////////////////////
 
 #[inline(never)]
-fn process_create_vault(
+#[allow(clippy::too_many_arguments)]
+fn process_create_withdraw_credential(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
-    min_deposit: u64,
-    min_withdraw: u64,
-    delegate_fee: u64,
-) -> ProgramResult {
-    msg!("Creating the vault");
-
-    let accounts_iter = &mut accounts.iter();
+    withdraw_amount: u64,
+    token_mint: Pubkey,
+    fee: u64,
+    receiver: Pubkey,
+    nullifier_point: EdwardsAffine,
+    leaf_index: u64,
+    leaf: BigInteger,
+    src_root: BigInteger,
+    commitment: InnerCommitment,
+    exclusion_root: Option<BigInteger>,
+    allowlist_root: Option<BigInteger>,
+) -> ProgramResult {
+    msg!("Creating withdraw credential: withdraw amount {}, fee {}", withdraw_amount, fee);
+
+    let accounts_iter = &mut accounts.iter();
+
+    let system_program_info = next_account_info(accounts_iter)?;
+    let rent_info = next_account_info(accounts_iter)?;
+    let vault_info = next_account_info(accounts_iter)?;
+    let credential_info = next_account_info(accounts_iter)?;
+    let delegator_info = next_account_info(accounts_iter)?;
+    let exclusion_set_info = next_account_info(accounts_iter)?;
+    let allowlist_registry_info = next_account_info(accounts_iter)?;
+
+    let mut vault = Vault::unpack_from_account_info(vault_info, program_id)?;
+    vault.check_enable()?;
+    vault.check_withdraw(withdraw_amount)?;
+    vault.check_token_mint(&token_mint)?;
+    vault.check_known_root(&src_root)?;
+    check_exclusion_root(program_id, vault_info.key, &vault, exclusion_set_info, exclusion_root.as_ref())?;
+    check_allowlist_root(program_id, vault_info.key, &vault, allowlist_registry_info, allowlist_root.as_ref())?;
+
+    if !delegator_info.is_signer {
+        return Err(MazeError::InvalidAuthority.into());
+    }
+
+    let (credential_key, (seed_1, seed_2, seed_3, seed_4, seed_5)) = get_withdraw_credential_pda(
+        vault_info.key,
+        delegator_info.key,
+        &receiver,
+        program_id,
+    );
+    if credential_info.key != &credential_key {
+        msg!("Credential pubkey is invalid");
+        return Err(MazeError::InvalidPdaPubkey.into());
+    }
+    // a recreated credential keeps the leaf reserved by the abandoned one
+    match WithdrawCredential::unpack_from_account_info(credential_info, program_id) {
+        Ok(credential) => check_reserved_leaf_index(credential.vanilla_data.leaf_index, leaf_index)?,
+        Err(_) => {
+            vault.reserve_leaves(leaf_index, 1)?;
+            vault.pack_to_account_info(vault_info)?;
+        }
+    }
+    process_optimal_create_account(
+        rent_info,
+        credential_info,
+        delegator_info,
+        system_program_info,
+        program_id,
+        WithdrawCredential::LEN,
+        &[],
+        &[seed_1, seed_2, seed_3, seed_4, &seed_5],
+    )?;
+
+    // the delegator is the relayer bound to the proof
+    let vanilla_data = WithdrawVanillaData::new(
+        receiver,
+        *delegator_info.key,
+        withdraw_amount,
+        token_mint,
+        fee,
+        nullifier_point,
+        leaf_index,
+        vault.min_anonymity_gap,
+        leaf,
+        src_root,
+        commitment,
+        exclusion_root,
+        allowlist_root,
+    )?;
+    // create credential
+    let credential = WithdrawCredential::new(
+        *vault_info.key,
+        *delegator_info.key,
+        vault.auditor_keys,
+        vanilla_data,
+    );
+    credential.pack_to_account_info(credential_info)
+}
+
+/// A vault with an exclusion set only takes withdrawals proven against the current root of its curator,
+/// it is checked again at every step of the withdrawal, so an update of the curator applies to credentials in flight.
+fn check_exclusion_root(
+    program_id: &Pubkey,
+    vault_key: &Pubkey,
+    vault: &Vault,
+    exclusion_set_info: &AccountInfo,
+    exclusion_root: Option<&BigInteger>,
+) -> ProgramResult {
+    let (exclusion_set_key, _) = get_exclusion_set_pda(vault_key, program_id);
+    if exclusion_set_info.key != &exclusion_set_key {
+        msg!("Exclusion set pubkey is invalid");
+        return Err(MazeError::InvalidPdaPubkey.into());
+    }
+
+    match (vault.exclusion, exclusion_root) {
+        (true, Some(root)) => ExclusionSet::unpack_from_account_info(exclusion_set_info, program_id)?.check_root(root),
+        (false, None) => Ok(()),
+        _ => {
+            msg!("Exclusion root of vanilla data does not match with vault");
+            Err(MazeError::InvalidVanillaData.into())
+        }
+    }
+}
+
+/// A vault with an allowlist registry only takes withdrawals proven against one of its accepted roots,
+/// it is checked again when the verifier is created and when the withdrawal is finalized.
+fn check_allowlist_root(
+    program_id: &Pubkey,
+    vault_key: &Pubkey,
+    vault: &Vault,
+    allowlist_registry_info: &AccountInfo,
+    allowlist_root: Option<&BigInteger>,
+) -> ProgramResult {
+    let (allowlist_registry_key, _) = get_allowlist_registry_pda(vault_key, program_id);
+    if allowlist_registry_info.key != &allowlist_registry_key {
+        msg!("Allowlist registry pubkey is invalid");
+        return Err(MazeError::InvalidPdaPubkey.into());
+    }
+
+    match (vault.allowlist, allowlist_root) {
+        (true, Some(root)) => AllowlistRegistry::unpack_from_account_info(allowlist_registry_info, program_id)?.check_root(root),
+        (false, None) => Ok(()),
+        _ => {
+            msg!("Allowlist root of vanilla data does not match with vault");
+            Err(MazeError::InvalidVanillaData.into())
+        }
+    }
+}
+
+#[inline(never)]
+fn process_create_withdraw_verifier(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    proof: Box<Proof>,
+) -> ProgramResult {
+    msg!("Creating withdraw verifier");
+
+    let accounts_iter = &mut accounts.iter();
+
+    let system_program_info = next_account_info(accounts_iter)?;
+    let rent_info = next_account_info(accounts_iter)?;
+    let vault_info = next_account_info(accounts_iter)?;
+    let credential_info = next_account_info(accounts_iter)?;
+    let verifier_info = next_account_info(accounts_iter)?;
+    let delegator_info = next_account_info(accounts_iter)?;
+    let exclusion_set_info = next_account_info(accounts_iter)?;
+    let allowlist_registry_info = next_account_info(accounts_iter)?;
+
+    let vault = Vault::unpack_from_account_info(vault_info, program_id)?;
+    vault.check_enable()?;
+
+    if !delegator_info.is_signer {
+        return Err(MazeError::InvalidAuthority.into());
+    }
+
+    let credential = WithdrawCredential::unpack_from_account_info(credential_info, program_id)?;
+    if &credential.vault != vault_info.key {
+        msg!("Vault pubkey is invalid");
+        return Err(MazeError::InvalidPdaPubkey.into());
+    }
+    if &credential.owner != delegator_info.key {
+        msg!("Delegator pubkey is invalid");
+        return Err(MazeError::UnmatchedAccounts.into());
+    }
+    // check consistency
+    vault.check_known_root(&credential.vanilla_data.src_root)?;
+    vault.check_anonymity_gap(credential.vanilla_data.min_anonymity_gap)?;
+    // the curator may have blocked the src leaf and the admin may have dropped the allowlist since the credential is created
+    check_exclusion_root(program_id, vault_info.key, &vault, exclusion_set_info, credential.vanilla_data.exclusion_root.as_ref())?;
+    check_allowlist_root(program_id, vault_info.key, &vault, allowlist_registry_info, credential.vanilla_data.allowlist_root.as_ref())?;
+
+    let (verifier_key, (seed_1, seed_2)) = get_verifier_pda(
+        credential_info.key,
+        program_id,
+    );
+    if verifier_info.key != &verifier_key {
+        msg!("Verifier pubkey is invalid");
+        return Err(MazeError::UnmatchedAccounts.into());
+    }
+    process_optimal_create_account(
+        rent_info,
+        verifier_info,
+        delegator_info,
+        system_program_info,
+        program_id,
+        Verifier::LEN,
+        &[],
+        &[seed_1, &seed_2],
+    )?;
+    // create verifier
+    let verifier = credential.vanilla_data.to_verifier(proof, &credential.auditor_keys)?;
+    verifier.pack_to_account_info(verifier_info)
+}
+
+fn process_verify_withdraw_proof(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    msg!("Verifying proof");
+
+    let accounts_iter = &mut accounts.iter();
+
+    let vault_info = next_account_info(accounts_iter)?;
+    let credential_info = next_account_info(accounts_iter)?;
+    let verifier_info = next_account_info(accounts_iter)?;
+    let exclusion_set_info = next_account_info(accounts_iter)?;
+
+    let vault = Vault::unpack_from_account_info(vault_info, program_id)?;
+    vault.check_enable()?;
+
+    let credential = WithdrawCredential::unpack_from_account_info(credential_info, program_id)?;
+    if &credential.vault != vault_info.key {
+        msg!("Vault pubkey is invalid");
+        return Err(MazeError::InvalidPdaPubkey.into());
+    }
+    // check consistency
+    vault.check_known_root(&credential.vanilla_data.src_root)?;
+    check_exclusion_root(program_id, vault_info.key, &vault, exclusion_set_info, credential.vanilla_data.exclusion_root.as_ref())?;
+
+    let (verifier_key, _) = get_verifier_pda(
+        credential_info.key,
+        program_id,
+    );
+    if verifier_info.key != &verifier_key {
+        msg!("Verifier pubkey is invalid");
+        return Err(MazeError::InvalidPdaPubkey.into());
+    }
+    let verifier = Verifier::unpack_from_account_info(verifier_info, program_id)?;
+    verifier.check_consistency(&credential.vanilla_data)?;
+
+    let verifier = verifier.process();
+    verifier.pack_to_account_info(verifier_info)
+}
+
+#[inline(never)]
+fn process_finalize_withdraw(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    utxo: [u8; 32],
+    balance_cipher: Vec<u8>,
+) -> ProgramResult {
+    msg!("Finalizing withdraw");
+
+    let accounts_iter = &mut accounts.iter();
+
+    let system_program_info = next_account_info(accounts_iter)?;
+    let token_program_info = next_account_info(accounts_iter)?;
+    let spl_associated_program_info = next_account_info(accounts_iter)?;
+    let rent_info = next_account_info(accounts_iter)?;
+    let token_mint_info = next_account_info(accounts_iter)?;
+    let vault_info = next_account_info(accounts_iter)?;
+    let credential_info = next_account_info(accounts_iter)?;
+    let verifier_info = next_account_info(accounts_iter)?;
+    let nullifier_info = next_account_info(accounts_iter)?;
+    let commitment_info = next_account_info(accounts_iter)?;
+    let vault_token_account_info = next_account_info(accounts_iter)?;
+    let dst_token_account_info = next_account_info(accounts_iter)?;
+    let delegator_token_account_info = next_account_info(accounts_iter)?;
+    let vault_signer_info = next_account_info(accounts_iter)?;
+    let receiver_info = next_account_info(accounts_iter)?;
+    let utxo_info = next_account_info(accounts_iter)?;
+    let delegator_info = next_account_info(accounts_iter)?;
+    let exclusion_set_info = next_account_info(accounts_iter)?;
+    let allowlist_registry_info = next_account_info(accounts_iter)?;
+
+    let vault = Vault::unpack_from_account_info(vault_info, program_id)?;
+    if &vault.authority != vault_signer_info.key {
+        msg!("Vault authority pubkey is invalid");
+        return Err(MazeError::UnmatchedAccounts.into());
+    }
+    vault.check_enable()?;
+
+    let credential = WithdrawCredential::unpack_from_account_info(credential_info, program_id)?;
+    vault.check_token_account(&credential.vanilla_data.token_mint, vault_token_account_info.key)?;
+    if &credential.vault != vault_info.key {
+        msg!("Vault pubkey is invalid");
+        return Err(MazeError::UnmatchedAccounts.into());
+    }
+    if &credential.owner != delegator_info.key {
+        msg!("Delegator pubkey is invalid");
+        return Err(MazeError::UnmatchedAccounts.into());
+    }
+    if &credential.vanilla_data.receiver != receiver_info.key {
+        msg!("Receiver pubkey is invalid");
+        return Err(MazeError::UnmatchedAccounts.into());
+    }
+    // check if src root is still in the history
+    vault.check_known_root(&credential.vanilla_data.src_root)?;
+    check_exclusion_root(program_id, vault_info.key, &vault, exclusion_set_info, credential.vanilla_data.exclusion_root.as_ref())?;
+    check_allowlist_root(program_id, vault_info.key, &vault, allowlist_registry_info, credential.vanilla_data.allowlist_root.as_ref())?;
+
+    let (verifier_key, _) = get_verifier_pda(
+        credential_info.key,
+        program_id,
+    );
+    if verifier_info.key != &verifier_key {
+        msg!("Verifier pubkey is invalid");
+        return Err(MazeError::InvalidPdaPubkey.into());
+    }
+    let verifier = Verifier::unpack_from_account_info(verifier_info, program_id)?;
+    verifier.check_consistency(&credential.vanilla_data)?;
+    verifier.program.check_verified()?;
+
+    let (nullifier_key, (seed_1, seed_2, seed_3)) = get_nullifier_pda(
+        &credential.vanilla_data.nullifier_point,
+        program_id,
+    );
+    if &nullifier_key != nullifier_info.key {
+        msg!("Nullifier pubkey is invalid");
+        return Err(MazeError::InvalidPdaPubkey.into());
+    }
+    process_optimal_create_account(
+        rent_info,
+        nullifier_info,
+        delegator_info,
+        system_program_info,
+        program_id,
+        Nullifier::LEN,
+        &[],
+        &[&seed_1, &seed_2, &seed_3],
+    )?;
+    Nullifier::new(credential.vanilla_data.receiver).initialize_to_account_info(nullifier_info)?;
+
+    let (commitment_key, (seed_1, seed_2)) = get_commitment_pda(
+        &credential.vanilla_data.leaf,
+        program_id,
+    );
+    if &commitment_key != commitment_info.key {
+        return Err(MazeError::InvalidPdaPubkey.into());
+    }
+    process_optimal_create_account(
+        rent_info,
+        commitment_info,
+        delegator_info,
+        system_program_info,
+        program_id,
+        Commitment::LEN,
+        &[],
+        &[&seed_1, &seed_2],
+    )?;
+    Commitment::new(credential.vanilla_data.commitment).initialize_to_account_info(commitment_info)?;
+
+    // store uxto on chain
+    let (utxo_pubkey, (seed_1, seed_2)) = get_utxo_pda(&utxo, program_id);
+    if &utxo_pubkey != utxo_info.key {
+        msg!("UTXO pubkey is invalid");
+        return Err(MazeError::InvalidPdaPubkey.into());
+    }
+    check_note_cipher(&balance_cipher)?;
+    let utxo = UTXO::new(credential.vanilla_data.leaf_index, Amount::Cipher(balance_cipher));
+    process_optimal_create_account(
+        rent_info,
+        utxo_info,
+        delegator_info,
+        system_program_info,
+        program_id,
+        utxo.size(),
+        &[],
+        &[seed_1, &seed_2],
+    )?;
+    utxo.initialize_to_account_info(utxo_info)?;
+
+    // store the leaf, its path up to the root is hashed by `UpdateMerkleNode`
+    let node_info = next_account_info(accounts_iter)?;
+    process_write_merkle_node(
+        program_id,
+        rent_info,
+        system_program_info,
+        vault_info,
+        node_info,
+        delegator_info,
+        0,
+        credential.vanilla_data.leaf_index,
+        credential.vanilla_data.leaf,
+    )?;
+
+    process_optimal_create_token_account(
+        rent_info,
+        token_mint_info,
+        dst_token_account_info,
+        delegator_info,
+        receiver_info,
+        token_program_info,
+        system_program_info,
+        spl_associated_program_info,
+        &[],
+    )?;
+
+    let fee = credential.vanilla_data.fee;
+    let receive_amount = credential.vanilla_data.withdraw_amount
+        .checked_sub(fee)
+        .ok_or(MazeError::Overflow)?;
+    // transfer token from vault to receiver
+    process_token_transfer(
+        token_program_info,
+        vault_token_account_info,
+        dst_token_account_info,
+        vault_signer_info,
+        &vault.signer_seeds(vault_info.key),
+        receive_amount,
+    )?;
+    // transfer fee from vault to relayer
+    if fee > 0 {
+        process_token_transfer(
+            token_program_info,
+            vault_token_account_info,
+            delegator_token_account_info,
+            vault_signer_info,
+            &vault.signer_seeds(vault_info.key),
+            fee,
+        )?;
+    }
+
+    // transfer `SOL` as fee from delegator to owner if there is less balance.
+    const FEE: u64 = 1_000_000;
+    if receiver_info.try_lamports()? < FEE {
+        let lamports = FEE - receiver_info.try_lamports()?;
+        process_transfer(delegator_info, receiver_info, system_program_info, &[], lamports)?;
+    }
+    // clear verifier
+    process_rent_refund(verifier_info, delegator_info);
+    // clear credential
+    process_rent_refund(credential_info, delegator_info);
+
+    Ok(())
+}
+
+/////////////////////////////////// Transfer Actions ////////////////////////////////////////
+
+#[inline(never)]
+#[allow(clippy::too_many_arguments)]
+fn process_create_transfer_credential(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    withdraw_amount: u64,
+    token_mint: Pubkey,
+    receiver: Pubkey,
+    nullifier_points: [EdwardsAffine; 2],
+    src_root: BigInteger,
+    leaf_index: u64,
+    leaves: [BigInteger; 2],
+    commitments: Box<Vec<InnerCommitment>>,
+) -> ProgramResult {
+    msg!("Creating transfer credential: withdraw amount {}", withdraw_amount);
+
+    let accounts_iter = &mut accounts.iter();
+
+    let system_program_info = next_account_info(accounts_iter)?;
+    let rent_info = next_account_info(accounts_iter)?;
+    let vault_info = next_account_info(accounts_iter)?;
+    let credential_info = next_account_info(accounts_iter)?;
+    let owner_info = next_account_info(accounts_iter)?;
+
+    let mut vault = Vault::unpack_from_account_info(vault_info, program_id)?;
+    vault.check_enable()?;
+    vault.check_transfer(withdraw_amount)?;
+    vault.check_token_mint(&token_mint)?;
+    vault.check_known_root(&src_root)?;
+
+    if !owner_info.is_signer {
+        return Err(MazeError::InvalidAuthority.into());
+    }
+
+    let (credential_key, (seed_1, seed_2, seed_3, seed_4)) = get_transfer_credential_pda(
+        vault_info.key,
+        owner_info.key,
+        program_id,
+    );
+    if credential_info.key != &credential_key {
+        msg!("Credential pubkey is invalid");
+        return Err(MazeError::InvalidPdaPubkey.into());
+    }
+    // a recreated credential keeps the leaves reserved by the abandoned one
+    match TransferCredential::unpack_from_account_info(credential_info, program_id) {
+        Ok(credential) => check_reserved_leaf_index(credential.vanilla_data.leaf_index, leaf_index)?,
+        Err(_) => {
+            vault.reserve_leaves(leaf_index, 2)?;
+            vault.pack_to_account_info(vault_info)?;
+        }
+    }
+    process_optimal_create_account(
+        rent_info,
+        credential_info,
+        owner_info,
+        system_program_info,
+        program_id,
+        TransferCredential::LEN,
+        &[],
+        &[seed_1, seed_2, seed_3, &seed_4],
+    )?;
+
+    let vanilla_data = TransferVanillaData::new(
+        receiver,
+        withdraw_amount,
+        token_mint,
+        nullifier_points,
+        src_root,
+        leaf_index,
+        leaves,
+        commitments,
+    )?;
+    // create credential
+    let credential = TransferCredential::new(
+        *vault_info.key,
+        *owner_info.key,
+        vault.auditor_keys,
+        vanilla_data,
+    );
+    credential.pack_to_account_info(credential_info)
+}
+
+#[inline(never)]
+fn process_create_transfer_verifier(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    proof: Box<Proof>,
+) -> ProgramResult {
+    msg!("Creating transfer verifier");
+
+    let accounts_iter = &mut accounts.iter();
+
+    let system_program_info = next_account_info(accounts_iter)?;
+    let rent_info = next_account_info(accounts_iter)?;
+    let vault_info = next_account_info(accounts_iter)?;
+    let credential_info = next_account_info(accounts_iter)?;
+    let verifier_info = next_account_info(accounts_iter)?;
+    let owner_info = next_account_info(accounts_iter)?;
+
+    let vault = Vault::unpack_from_account_info(vault_info, program_id)?;
+    vault.check_enable()?;
+
+    if !owner_info.is_signer {
+        return Err(MazeError::InvalidAuthority.into());
+    }
+
+    let credential = TransferCredential::unpack_from_account_info(credential_info, program_id)?;
+    if &credential.vault != vault_info.key {
+        msg!("Vault pubkey is invalid");
+        return Err(MazeError::InvalidPdaPubkey.into());
+    }
+    if &credential.owner != owner_info.key {
+        msg!("Owner pubkey is invalid");
+        return Err(MazeError::UnmatchedAccounts.into());
+    }
+    // check consistency
+    vault.check_known_root(&credential.vanilla_data.src_root)?;
+    // the policy could be set after the credential is created
+    vault.check_transfer(credential.vanilla_data.withdraw_amount)?;
+
+    let (verifier_key, (seed_1, seed_2)) = get_verifier_pda(
+        credential_info.key,
+        program_id,
+    );
+    if verifier_info.key != &verifier_key {
+        msg!("Verifier pubkey is invalid");
+        return Err(MazeError::UnmatchedAccounts.into());
+    }
+    process_optimal_create_account(
+        rent_info,
+        verifier_info,
+        owner_info,
+        system_program_info,
+        program_id,
+        Verifier::LEN,
+        &[],
+        &[seed_1, &seed_2],
+    )?;
+    // create verifier
+    let verifier = credential.vanilla_data.to_verifier(proof, &credential.auditor_keys)?;
+    verifier.pack_to_account_info(verifier_info)
+}
+
+fn process_verify_transfer_proof(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    msg!("Verifying proof");
+
+    let accounts_iter = &mut accounts.iter();
+
+    let vault_info = next_account_info(accounts_iter)?;
+    let credential_info = next_account_info(accounts_iter)?;
+    let verifier_info = next_account_info(accounts_iter)?;
+
+    let vault = Vault::unpack_from_account_info(vault_info, program_id)?;
+    vault.check_enable()?;
+
+    let credential = TransferCredential::unpack_from_account_info(credential_info, program_id)?;
+    if &credential.vault != vault_info.key {
+        msg!("Vault pubkey is invalid");
+        return Err(MazeError::InvalidPdaPubkey.into());
+    }
+    // check consistency
+    vault.check_known_root(&credential.vanilla_data.src_root)?;
+
+    let (verifier_key, _) = get_verifier_pda(
+        credential_info.key,
+        program_id,
+    );
+    if verifier_info.key != &verifier_key {
+        msg!("Verifier pubkey is invalid");
+        return Err(MazeError::InvalidPdaPubkey.into());
+    }
+    let verifier = Verifier::unpack_from_account_info(verifier_info, program_id)?;
+    verifier.check_consistency(&credential.vanilla_data)?;
+
+    let verifier = verifier.process();
+    verifier.pack_to_account_info(verifier_info)
+}
+
+#[inline(never)]
+fn process_finalize_transfer(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    utxos: [[u8; 32]; 2],
+    balance_ciphers: Box<Vec<Vec<u8>>>,
+    ephemeral_pubkey: Option<EdwardsAffine>,
+) -> ProgramResult {
+    msg!("Finalizing transfer");
+
+    let accounts_iter = &mut accounts.iter();
+
+    let system_program_info = next_account_info(accounts_iter)?;
+    let token_program_info = next_account_info(accounts_iter)?;
+    let spl_associated_program_info = next_account_info(accounts_iter)?;
+    let rent_info = next_account_info(accounts_iter)?;
+    let token_mint_info = next_account_info(accounts_iter)?;
+    let vault_info = next_account_info(accounts_iter)?;
+    let credential_info = next_account_info(accounts_iter)?;
+    let verifier_info = next_account_info(accounts_iter)?;
+    let nullifier_infos = [next_account_info(accounts_iter)?, next_account_info(accounts_iter)?];
+    let commitment_infos = [next_account_info(accounts_iter)?, next_account_info(accounts_iter)?];
+    let vault_token_account_info = next_account_info(accounts_iter)?;
+    let dst_token_account_info = next_account_info(accounts_iter)?;
+    let vault_signer_info = next_account_info(accounts_iter)?;
+    let receiver_info = next_account_info(accounts_iter)?;
+    let utxo_infos = [next_account_info(accounts_iter)?, next_account_info(accounts_iter)?];
+    let owner_info = next_account_info(accounts_iter)?;
+
+    if balance_ciphers.len() != 2 {
+        msg!("Balance ciphers length is invalid");
+        return Err(MazeError::InvalidNoteCipher.into());
+    }
+    balance_ciphers.iter().try_for_each(|cipher| check_note_cipher(cipher))?;
+
+    let vault = Vault::unpack_from_account_info(vault_info, program_id)?;
+    if &vault.authority != vault_signer_info.key {
+        msg!("Vault authority pubkey is invalid");
+        return Err(MazeError::UnmatchedAccounts.into());
+    }
+    vault.check_enable()?;
+
+    let credential = TransferCredential::unpack_from_account_info(credential_info, program_id)?;
+    vault.check_token_account(&credential.vanilla_data.token_mint, vault_token_account_info.key)?;
+    if &credential.vault != vault_info.key {
+        msg!("Vault pubkey is invalid");
+        return Err(MazeError::UnmatchedAccounts.into());
+    }
+    if &credential.owner != owner_info.key {
+        msg!("Owner pubkey is invalid");
+        return Err(MazeError::UnmatchedAccounts.into());
+    }
+    if &credential.vanilla_data.receiver != receiver_info.key {
+        msg!("Receiver pubkey is invalid");
+        return Err(MazeError::UnmatchedAccounts.into());
+    }
+    // check if src root is still in the history
+    vault.check_known_root(&credential.vanilla_data.src_root)?;
+
+    let (verifier_key, _) = get_verifier_pda(
+        credential_info.key,
+        program_id,
+    );
+    if verifier_info.key != &verifier_key {
+        msg!("Verifier pubkey is invalid");
+        return Err(MazeError::InvalidPdaPubkey.into());
+    }
+    let verifier = Verifier::unpack_from_account_info(verifier_info, program_id)?;
+    verifier.check_consistency(&credential.vanilla_data)?;
+    verifier.program.check_verified()?;
+
+    // spend both src leaves
+    for (nullifier_point, nullifier_info) in credential.vanilla_data.nullifier_points.iter().zip(nullifier_infos) {
+        let (nullifier_key, (seed_1, seed_2, seed_3)) = get_nullifier_pda(nullifier_point, program_id);
+        if &nullifier_key != nullifier_info.key {
+            msg!("Nullifier pubkey is invalid");
+            return Err(MazeError::InvalidPdaPubkey.into());
+        }
+        process_optimal_create_account(
+            rent_info,
+            nullifier_info,
+            owner_info,
+            system_program_info,
+            program_id,
+            Nullifier::LEN,
+            &[],
+            &[&seed_1, &seed_2, &seed_3],
+        )?;
+        Nullifier::new(credential.vanilla_data.receiver).initialize_to_account_info(nullifier_info)?;
+    }
+
+    for (i, node_info) in [next_account_info(accounts_iter)?, next_account_info(accounts_iter)?].into_iter().enumerate() {
+        let leaf_index = credential.vanilla_data.leaf_index + i as u64;
+        let leaf = credential.vanilla_data.leaves[i];
+
+        let (commitment_key, (seed_1, seed_2)) = get_commitment_pda(&leaf, program_id);
+        if &commitment_key != commitment_infos[i].key {
+            return Err(MazeError::InvalidPdaPubkey.into());
+        }
+        process_optimal_create_account(
+            rent_info,
+            commitment_infos[i],
+            owner_info,
+            system_program_info,
+            program_id,
+            Commitment::LEN,
+            &[],
+            &[&seed_1, &seed_2],
+        )?;
+        Commitment::new(credential.vanilla_data.commitments[i].clone()).initialize_to_account_info(commitment_infos[i])?;
+
+        // store uxto on chain
+        let (utxo_pubkey, (seed_1, seed_2)) = get_utxo_pda(&utxos[i], program_id);
+        if &utxo_pubkey != utxo_infos[i].key {
+            msg!("UTXO pubkey is invalid");
+            return Err(MazeError::InvalidPdaPubkey.into());
+        }
+        // the first output can be paid to another shielded address
+        let amount = match ephemeral_pubkey {
+            Some(ephemeral_pubkey) if i == 0 => Amount::Shielded {
+                ephemeral_pubkey,
+                cipher: balance_ciphers[i].clone(),
+            },
+            _ => Amount::Cipher(balance_ciphers[i].clone()),
+        };
+        let utxo = UTXO::new(leaf_index, amount);
+        process_optimal_create_account(
+            rent_info,
+            utxo_infos[i],
+            owner_info,
+            system_program_info,
+            program_id,
+            utxo.size(),
+            &[],
+            &[seed_1, &seed_2],
+        )?;
+        utxo.initialize_to_account_info(utxo_infos[i])?;
+
+        // store the leaf, its path up to the root is hashed by `UpdateMerkleNode`
+        process_write_merkle_node(
+            program_id,
+            rent_info,
+            system_program_info,
+            vault_info,
+            node_info,
+            owner_info,
+            0,
+            leaf_index,
+            leaf,
+        )?;
+    }
+
+    let withdraw_amount = credential.vanilla_data.withdraw_amount;
+    if withdraw_amount > 0 {
+        process_optimal_create_token_account(
+            rent_info,
+            token_mint_info,
+            dst_token_account_info,
+            owner_info,
+            receiver_info,
+            token_program_info,
+            system_program_info,
+            spl_associated_program_info,
+            &[],
+        )?;
+        // transfer token from vault to receiver
+        process_token_transfer(
+            token_program_info,
+            vault_token_account_info,
+            dst_token_account_info,
+            vault_signer_info,
+            &vault.signer_seeds(vault_info.key),
+            withdraw_amount,
+        )?;
+    }
+    // clear verifier
+    process_rent_refund(verifier_info, owner_info);
+    // clear credential
+    process_rent_refund(credential_info, owner_info);
+
+    Ok(())
+}
+
+/////////////////////////////////// Merkle Tree Actions ////////////////////////////////////////
+
+#[inline]
+fn check_reserved_leaf_index(reserved_leaf_index: u64, leaf_index: u64) -> ProgramResult {
+    if reserved_leaf_index != leaf_index {
+        msg!("Leaf index of vanilla data does not match with the one reserved by credential");
+        Err(MazeError::InvalidVanillaData.into())
+    } else {
+        Ok(())
+    }
+}
+
+/// Nodes proven by a circuit are written once, when the proof is finalized.
+#[allow(clippy::too_many_arguments)]
+fn process_write_merkle_node<'a>(
+    program_id: &Pubkey,
+    rent_info: &AccountInfo<'a>,
+    system_program_info: &AccountInfo<'a>,
+    vault_info: &AccountInfo<'a>,
+    node_info: &AccountInfo<'a>,
+    payer_info: &AccountInfo<'a>,
+    layer: u8,
+    index: u64,
+    hash: BigInteger,
+) -> ProgramResult {
+    let (node_key, (seed_1, seed_2, seed_3, seed_4)) = get_merkle_node_pda(
+        vault_info.key,
+        layer,
+        index,
+        program_id,
+    );
+    if &node_key != node_info.key {
+        msg!("Node at layer {} index {} is invalid", layer, index);
+        return Err(MazeError::UnmatchedAccounts.into());
+    }
+    process_optimal_create_account(
+        rent_info,
+        node_info,
+        payer_info,
+        system_program_info,
+        program_id,
+        MerkleNode::LEN,
+        &[],
+        &[seed_1, &seed_2, &seed_3, &seed_4],
+    )?;
+    MerkleNode::new(hash).initialize_to_account_info(node_info)
+}
+
+/// Hash of the node at `layer` and `index`, a node never written is empty.
+fn get_merkle_node_hash(
+    program_id: &Pubkey,
+    vault_key: &Pubkey,
+    node_info: &AccountInfo,
+    layer: u8,
+    index: u64,
+) -> Result<BigInteger, ProgramError> {
+    let (node_key, _) = get_merkle_node_pda(
+        vault_key,
+        layer,
+        index,
+        program_id,
+    );
+    if &node_key != node_info.key {
+        msg!("Node at layer {} index {} is invalid", layer, index);
+        return Err(MazeError::UnmatchedAccounts.into());
+    }
+
+    if node_info.data_is_empty() {
+        Ok(default_node_hash(layer))
+    } else {
+        Ok(MerkleNode::unpack_from_account_info(node_info, program_id)?.hash)
+    }
+}
+
+/// Anyone can hash up the path of a finalized leaf, a node at the top layer becomes the root of the vault.
+#[inline(never)]
+fn process_update_merkle_node(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    layer: u8,
+    index: u64,
+) -> ProgramResult {
+    msg!("Updating merkle node: layer {}, index {}", layer, index);
+
+    let accounts_iter = &mut accounts.iter();
+
+    let system_program_info = next_account_info(accounts_iter)?;
+    let rent_info = next_account_info(accounts_iter)?;
+    let vault_info = next_account_info(accounts_iter)?;
+    let left_info = next_account_info(accounts_iter)?;
+    let right_info = next_account_info(accounts_iter)?;
+
+    if layer == 0 || layer as usize > HEIGHT || index >= 1 << (HEIGHT - layer as usize) {
+        msg!("Node at layer {} index {} is out of tree", layer, index);
+        return Err(MazeError::InvalidVanillaData.into());
+    }
+
+    let mut vault = Vault::unpack_from_account_info(vault_info, program_id)?;
+    vault.check_enable()?;
+
+    let left = get_merkle_node_hash(program_id, vault_info.key, left_info, layer - 1, index << 1)?;
+    let right = get_merkle_node_hash(program_id, vault_info.key, right_info, layer - 1, (index << 1) + 1)?;
+    let hash = hash_two(&left, &right).ok_or(MazeError::InvalidVanillaData)?;
+
+    if layer as usize == HEIGHT {
+        vault.update_root(hash);
+        return vault.pack_to_account_info(vault_info);
+    }
+
+    let node_info = next_account_info(accounts_iter)?;
+    let payer_info = next_account_info(accounts_iter)?;
+
+    if !payer_info.is_signer {
+        return Err(MazeError::InvalidAuthority.into());
+    }
+
+    let (node_key, (seed_1, seed_2, seed_3, seed_4)) = get_merkle_node_pda(
+        vault_info.key,
+        layer,
+        index,
+        program_id,
+    );
+    if &node_key != node_info.key {
+        msg!("Node at layer {} index {} is invalid", layer, index);
+        return Err(MazeError::UnmatchedAccounts.into());
+    }
+    process_optimal_create_account(
+        rent_info,
+        node_info,
+        payer_info,
+        system_program_info,
+        program_id,
+        MerkleNode::LEN,
+        &[],
+        &[seed_1, &seed_2, &seed_3, &seed_4],
+    )?;
+    MerkleNode::new(hash).pack_to_account_info(node_info)
+}
+
+/////////////////////////////////////////////////// admin authority ///////////////////////////////////////////////////
+
+#[inline(never)]
+fn process_create_vault(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    min_deposit: u64,
+    min_withdraw: u64,
+    min_anonymity_gap: u64,
+    multi_asset: bool,
+    auditor_keys: [EdwardsAffine; AUDITORS],
+) -> ProgramResult {
+    msg!("Creating the vault: multi asset {}", multi_asset);
+
+    if !auditor_keys.iter().all(is_edwards_affine_valid) {
+        msg!("Auditor key is invalid");
+        return Err(MazeError::InvalidVanillaData.into());
+    }
+    if min_anonymity_gap >= 1 << HEIGHT {
+        msg!("Anonymity gap is too large");
+        return Err(MazeError::InvalidVanillaData.into());
+    }
+
+    let accounts_iter = &mut accounts.iter();
 
     let system_program_info = next_account_info(accounts_iter)?;
     let token_program_info = next_account_info(accounts_iter)?;
@@ -829,12 +1764,14 @@ fn process_create_vault(
 
     let vault = Vault::new(
         *admin_info.key,
-        *vault_token_account_info.key,
+        multi_asset,
+        *token_mint_info.key,
         vault_signer_key,
         seed_2,
         min_deposit,
         min_withdraw,
-        delegate_fee,
+        min_anonymity_gap,
+        auditor_keys,
     );
     vault.initialize_to_account_info(vault_info)
 }
@@ -865,3 +1802,278 @@ fn process_control_vault(
 
     Ok(())
 }
+
+fn process_add_vault_asset(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    msg!("Adding an asset to the vault");
+
+    let accounts_iter = &mut accounts.iter();
+
+    let system_program_info = next_account_info(accounts_iter)?;
+    let token_program_info = next_account_info(accounts_iter)?;
+    let spl_associated_program_info = next_account_info(accounts_iter)?;
+    let rent_info = next_account_info(accounts_iter)?;
+    let token_mint_info = next_account_info(accounts_iter)?;
+    let vault_info = next_account_info(accounts_iter)?;
+    let vault_signer_info = next_account_info(accounts_iter)?;
+    let vault_token_account_info = next_account_info(accounts_iter)?;
+    let admin_info = next_account_info(accounts_iter)?;
+
+    if !admin_info.is_signer {
+        return Err(MazeError::InvalidAuthority.into());
+    }
+
+    let mut vault = Vault::unpack_from_account_info(vault_info, program_id)?;
+    if &vault.admin != admin_info.key {
+        msg!("Admin in vault is invalid");
+        return Err(MazeError::UnmatchedAccounts.into());
+    }
+    if &vault.authority != vault_signer_info.key {
+        msg!("Vault signer pubkey is invalid");
+        return Err(MazeError::UnmatchedAccounts.into());
+    }
+
+    vault.add_token_mint(*token_mint_info.key)?;
+    vault.check_token_account(token_mint_info.key, vault_token_account_info.key)?;
+
+    process_optimal_create_token_account(
+        rent_info,
+        token_mint_info,
+        vault_token_account_info,
+        admin_info,
+        vault_signer_info,
+        token_program_info,
+        system_program_info,
+        spl_associated_program_info,
+        &[],
+    )?;
+
+    vault.pack_to_account_info(vault_info)
+}
+
+fn process_rotate_auditor_keys(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    auditor_keys: [EdwardsAffine; AUDITORS],
+) -> ProgramResult {
+    msg!("Rotating auditor keys of the vault");
+
+    let accounts_iter = &mut accounts.iter();
+
+    let vault_info = next_account_info(accounts_iter)?;
+    let admin_info = next_account_info(accounts_iter)?;
+
+    if !admin_info.is_signer {
+        return Err(MazeError::InvalidAuthority.into());
+    }
+
+    let mut vault = Vault::unpack_from_account_info(vault_info, program_id)?;
+    if &vault.admin != admin_info.key {
+        msg!("Admin in vault is invalid");
+        return Err(MazeError::UnmatchedAccounts.into());
+    }
+
+    // credentials created before rotation keep a snapshot of the old keys for their public inputs
+    vault.rotate_auditor_keys(auditor_keys)?;
+
+    vault.pack_to_account_info(vault_info)
+}
+
+fn process_set_min_anonymity_gap(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    min_anonymity_gap: u64,
+) -> ProgramResult {
+    msg!("Setting minimum anonymity gap of the vault: {}", min_anonymity_gap);
+
+    let accounts_iter = &mut accounts.iter();
+
+    let vault_info = next_account_info(accounts_iter)?;
+    let admin_info = next_account_info(accounts_iter)?;
+
+    if !admin_info.is_signer {
+        return Err(MazeError::InvalidAuthority.into());
+    }
+
+    let mut vault = Vault::unpack_from_account_info(vault_info, program_id)?;
+    if &vault.admin != admin_info.key {
+        msg!("Admin in vault is invalid");
+        return Err(MazeError::UnmatchedAccounts.into());
+    }
+
+    // credentials created before the change are rejected when their verifiers are created
+    vault.set_min_anonymity_gap(min_anonymity_gap)?;
+
+    vault.pack_to_account_info(vault_info)
+}
+
+fn process_create_exclusion_set(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    curator: Pubkey,
+) -> ProgramResult {
+    msg!("Creating exclusion set of the vault: curator {}", curator);
+
+    let accounts_iter = &mut accounts.iter();
+
+    let system_program_info = next_account_info(accounts_iter)?;
+    let rent_info = next_account_info(accounts_iter)?;
+    let vault_info = next_account_info(accounts_iter)?;
+    let exclusion_set_info = next_account_info(accounts_iter)?;
+    let admin_info = next_account_info(accounts_iter)?;
+
+    if !admin_info.is_signer {
+        return Err(MazeError::InvalidAuthority.into());
+    }
+
+    let mut vault = Vault::unpack_from_account_info(vault_info, program_id)?;
+    if &vault.admin != admin_info.key {
+        msg!("Admin in vault is invalid");
+        return Err(MazeError::UnmatchedAccounts.into());
+    }
+
+    let (exclusion_set_key, (seed_1, seed_2, seed_3)) = get_exclusion_set_pda(vault_info.key, program_id);
+    if exclusion_set_info.key != &exclusion_set_key {
+        msg!("Exclusion set pubkey is invalid");
+        return Err(MazeError::InvalidPdaPubkey.into());
+    }
+    // recreating would reset the root to an empty set
+    if exclusion_set_info.owner == program_id {
+        msg!("Exclusion set is already initialized");
+        return Err(MazeError::AlreadyInitialized.into());
+    }
+    process_optimal_create_account(
+        rent_info,
+        exclusion_set_info,
+        admin_info,
+        system_program_info,
+        program_id,
+        ExclusionSet::LEN,
+        &[],
+        &[seed_1, seed_2, &seed_3],
+    )?;
+
+    let exclusion_set = ExclusionSet::new(*vault_info.key, curator);
+    exclusion_set.pack_to_account_info(exclusion_set_info)?;
+
+    // withdrawals from now on are proven against the exclusion root
+    vault.exclusion = true;
+    vault.pack_to_account_info(vault_info)
+}
+
+fn process_update_exclusion_root(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    root: BigInteger,
+) -> ProgramResult {
+    msg!("Updating exclusion root");
+
+    let accounts_iter = &mut accounts.iter();
+
+    let exclusion_set_info = next_account_info(accounts_iter)?;
+    let curator_info = next_account_info(accounts_iter)?;
+
+    if !curator_info.is_signer {
+        return Err(MazeError::InvalidAuthority.into());
+    }
+
+    let mut exclusion_set = ExclusionSet::unpack_from_account_info(exclusion_set_info, program_id)?;
+    exclusion_set.check_curator(curator_info.key)?;
+    exclusion_set.update(root)?;
+
+    exclusion_set.pack_to_account_info(exclusion_set_info)
+}
+
+fn process_create_allowlist_registry(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    msg!("Creating allowlist registry of the vault");
+
+    let accounts_iter = &mut accounts.iter();
+
+    let system_program_info = next_account_info(accounts_iter)?;
+    let rent_info = next_account_info(accounts_iter)?;
+    let vault_info = next_account_info(accounts_iter)?;
+    let allowlist_registry_info = next_account_info(accounts_iter)?;
+    let admin_info = next_account_info(accounts_iter)?;
+
+    if !admin_info.is_signer {
+        return Err(MazeError::InvalidAuthority.into());
+    }
+
+    let mut vault = Vault::unpack_from_account_info(vault_info, program_id)?;
+    if &vault.admin != admin_info.key {
+        msg!("Admin in vault is invalid");
+        return Err(MazeError::UnmatchedAccounts.into());
+    }
+
+    let (allowlist_registry_key, (seed_1, seed_2, seed_3)) = get_allowlist_registry_pda(vault_info.key, program_id);
+    if allowlist_registry_info.key != &allowlist_registry_key {
+        msg!("Allowlist registry pubkey is invalid");
+        return Err(MazeError::InvalidPdaPubkey.into());
+    }
+    // recreating would drop the accepted roots
+    if allowlist_registry_info.owner == program_id {
+        msg!("Allowlist registry is already initialized");
+        return Err(MazeError::AlreadyInitialized.into());
+    }
+    process_optimal_create_account(
+        rent_info,
+        allowlist_registry_info,
+        admin_info,
+        system_program_info,
+        program_id,
+        AllowlistRegistry::LEN,
+        &[],
+        &[seed_1, seed_2, &seed_3],
+    )?;
+
+    let allowlist_registry = AllowlistRegistry::new(*vault_info.key);
+    allowlist_registry.pack_to_account_info(allowlist_registry_info)?;
+
+    // withdrawals from now on are proven against an accepted allowlist root
+    vault.allowlist = true;
+    vault.pack_to_account_info(vault_info)
+}
+
+fn process_update_allowlist_registry(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    root: BigInteger,
+    accept: bool,
+) -> ProgramResult {
+    msg!("Updating allowlist registry: accept {}", accept);
+
+    let accounts_iter = &mut accounts.iter();
+
+    let vault_info = next_account_info(accounts_iter)?;
+    let allowlist_registry_info = next_account_info(accounts_iter)?;
+    let admin_info = next_account_info(accounts_iter)?;
+
+    if !admin_info.is_signer {
+        return Err(MazeError::InvalidAuthority.into());
+    }
+
+    let vault = Vault::unpack_from_account_info(vault_info, program_id)?;
+    if &vault.admin != admin_info.key {
+        msg!("Admin in vault is invalid");
+        return Err(MazeError::UnmatchedAccounts.into());
+    }
+
+    let mut allowlist_registry = AllowlistRegistry::unpack_from_account_info(allowlist_registry_info, program_id)?;
+    if &allowlist_registry.vault != vault_info.key {
+        msg!("Vault of allowlist registry is invalid");
+        return Err(MazeError::UnmatchedAccounts.into());
+    }
+
+    if accept {
+        allowlist_registry.add_root(root)?;
+    } else {
+        allowlist_registry.remove_root(&root)?;
+    }
+
+    allowlist_registry.pack_to_account_info(allowlist_registry_info)
+}
@@ -9,6 +9,12 @@ use crate::verifier::Proof;
 use super::program::Program;
 use super::miller_loop::MillerLoop;
 
+const MAX_UNITS: usize = 1300000;
+const DOUBLE_UNITS: usize = 13000;
+const ADD_UNITS: usize = 21000;
+const INPUT_END_UNITS: usize = 13000;
+const NEXT_INPUT_UNITS: usize = 1000;
+
 #[derive(Clone, BorshSerialize, BorshDeserialize)]
 pub struct PrepareInputs {
     pub input_index: u8,
@@ -31,26 +37,33 @@ impl PrepareInputs {
         }
     }
 
+    /// Upper bound of the steps to prepare `inputs_len` public inputs. Every bit of a full input
+    /// is doubled and added, and a step only returns before the end after spending `MAX_UNITS`.
+    pub const fn max_steps(inputs_len: usize) -> usize {
+        const MAX_INPUT_UNITS: usize = 254 * (DOUBLE_UNITS + ADD_UNITS) + INPUT_END_UNITS + NEXT_INPUT_UNITS;
+
+        inputs_len * MAX_INPUT_UNITS / MAX_UNITS + 1
+    }
+
     pub fn process(mut self, pvk: &PreparedVerifyingKey) -> Program {
         let mut public_input = self.public_inputs[self.input_index as usize];
         let mut bits_iter = BitIteratorBE::without_leading_zeros(public_input)
             .skip(self.bit_index as usize);
 
-        const MAX_UNITS: usize = 1300000;
         let mut used_units = 0;
         loop {
             if let Some(bit) = bits_iter.next() {
                 self.tmp.double_in_place();
-                used_units += 13000;
+                used_units += DOUBLE_UNITS;
                 if bit {
                     self.tmp.add_assign_mixed(&pvk.gamma_abc_g1[self.input_index as usize]);
-                    used_units += 21000;
+                    used_units += ADD_UNITS;
                 }
                 self.bit_index += 1;
             } else {
                 self.g_ic.add_assign(&self.tmp);
                 self.input_index += 1;
-                used_units += 13000;
+                used_units += INPUT_END_UNITS;
 
                 if self.input_index as usize >= self.public_inputs.len() {
                     let prepared_input = G1Affine254::from(*self.g_ic);
@@ -61,7 +74,7 @@ impl PrepareInputs {
 
                     public_input = self.public_inputs[self.input_index as usize];
                     bits_iter = BitIteratorBE::without_leading_zeros(public_input).skip(0);
-                    used_units += 1000;
+                    used_units += NEXT_INPUT_UNITS;
                 }
             }
 
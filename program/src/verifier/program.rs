@@ -7,6 +7,9 @@ use super::prepare_inputs::*;
 use super::miller_loop::*;
 use super::final_exponent::*;
 
+/// Steps from the miller loop to the end, the same for every proof.
+pub const PAIRING_STEPS: usize = 54;
+
 #[derive(BorshSerialize, BorshDeserialize)]
 pub enum Program {
     PrepareInputs(PrepareInputs),
@@ -21,6 +24,12 @@ pub enum Program {
 }
 
 impl Program {
+    /// Upper bound of the verify instructions bringing a proof with `inputs_len` public inputs
+    /// to the end, the instructions past it leave the result as it is.
+    pub const fn max_steps(inputs_len: usize) -> usize {
+        PrepareInputs::max_steps(inputs_len) + PAIRING_STEPS
+    }
+
     #[inline(never)]
     pub fn process(self, pvk: &PreparedVerifyingKey) -> Self {
         match self {
@@ -81,4 +90,67 @@ impl Program {
             Err(MazeError::ProofNotVerified.into())
         }
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use crate::bn::BigInteger256 as BigInteger;
+    use crate::params::{bn::{Fq, Fq2, G1Affine254, G2Affine254}, verify::ProofType};
+    use crate::verifier::{Proof, prepare_inputs::PrepareInputs};
+    use super::{Program, PAIRING_STEPS};
+
+    fn get_proof() -> Proof {
+        use ark_ec::AffineCurve;
+        use ark_bn254::{G1Affine, G2Affine};
+
+        let g1 = G1Affine::prime_subgroup_generator();
+        let g2 = G2Affine::prime_subgroup_generator();
+        let a = G1Affine254::new(
+            Fq::new(BigInteger::new(g1.x.0.0)),
+            Fq::new(BigInteger::new(g1.y.0.0)),
+            false,
+        );
+        let b = G2Affine254::new(
+            Fq2::new(Fq::new(BigInteger::new(g2.x.c0.0.0)), Fq::new(BigInteger::new(g2.x.c1.0.0))),
+            Fq2::new(Fq::new(BigInteger::new(g2.y.c0.0.0)), Fq::new(BigInteger::new(g2.y.c1.0.0))),
+            false,
+        );
+
+        Proof { a, b, c: a }
+    }
+
+    #[test]
+    fn test_max_steps() {
+        // the scalar below the field modulus with the most bits set
+        let input = BigInteger::new([u64::MAX, u64::MAX, u64::MAX, 0x2fffffffffffffff]);
+
+        for proof_type in [
+            ProofType::Deposit,
+            ProofType::Withdraw,
+            ProofType::Transfer,
+            ProofType::BatchDeposit,
+            ProofType::WithdrawExclusion,
+            ProofType::WithdrawAllowlist,
+            ProofType::WithdrawExclusionAllowlist,
+        ] {
+            let pvk = proof_type.pvk();
+            let inputs_len = pvk.gamma_abc_g1.len();
+            let mut program = Program::PrepareInputs(PrepareInputs::new(
+                pvk,
+                Box::new(vec![input; inputs_len]),
+                Box::new(get_proof()),
+            ));
+
+            let mut steps = 0;
+            let mut pairing_steps = 0;
+            while !matches!(program, Program::Finish(_)) {
+                if !matches!(program, Program::PrepareInputs(_)) {
+                    pairing_steps += 1;
+                }
+                program = program.process(pvk);
+                steps += 1;
+            }
+            assert_eq!(pairing_steps, PAIRING_STEPS);
+            assert!(steps <= Program::max_steps(inputs_len), "{:?}", proof_type);
+        }
+    }
+}
@@ -0,0 +1,211 @@
+use anyhow::{anyhow, Result};
+use ark_ec::PairingEngine;
+use ark_groth16::VerifyingKey;
+use ark_serialize::CanonicalSerialize;
+use borsh::{BorshSerialize, BorshDeserialize};
+use sha2::{Digest, Sha256};
+
+pub const ARTIFACT_MAGIC: [u8; 4] = *b"maze";
+pub const ARTIFACT_VERSION: u16 = 1;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum CircuitKind {
+    Deposit,
+    BatchDeposit,
+    Withdraw,
+    Transfer,
+    /// withdraw that also proves its src leaf index is not in the exclusion set
+    WithdrawExclusion,
+    /// withdraw that also proves its src leaf index is in an allowlist
+    WithdrawAllowlist,
+    WithdrawExclusionAllowlist,
+}
+
+impl CircuitKind {
+    /// Withdraw circuits differ by the index set proofs they carry.
+    pub fn withdraw(exclusion: bool, allowlist: bool) -> Self {
+        match (exclusion, allowlist) {
+            (false, false) => Self::Withdraw,
+            (true, false) => Self::WithdrawExclusion,
+            (false, true) => Self::WithdrawAllowlist,
+            (true, true) => Self::WithdrawExclusionAllowlist,
+        }
+    }
+}
+
+#[cfg(not(any(feature = "poseidon", feature = "poseidon2", feature = "rescue", feature = "mimc")))]
+compile_error!("one of the hasher features `poseidon`, `poseidon2`, `rescue` or `mimc` must be enabled");
+#[cfg(any(
+    all(feature = "poseidon", any(feature = "poseidon2", feature = "rescue", feature = "mimc")),
+    all(feature = "poseidon2", any(feature = "rescue", feature = "mimc")),
+    all(feature = "rescue", feature = "mimc"),
+))]
+compile_error!("only one hasher feature can be enabled, try `--no-default-features`");
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum HasherKind {
+    Poseidon,
+    Poseidon2,
+    Rescue,
+    Mimc,
+}
+
+impl HasherKind {
+    #[cfg(feature = "poseidon")]
+    pub const CURRENT: Self = Self::Poseidon;
+    #[cfg(feature = "poseidon2")]
+    pub const CURRENT: Self = Self::Poseidon2;
+    #[cfg(feature = "rescue")]
+    pub const CURRENT: Self = Self::Rescue;
+    #[cfg(feature = "mimc")]
+    pub const CURRENT: Self = Self::Mimc;
+}
+
+#[cfg(not(any(feature = "bn254", feature = "bls12-381")))]
+compile_error!("one of the curve features `bn254` or `bls12-381` must be enabled");
+#[cfg(all(feature = "bn254", feature = "bls12-381"))]
+compile_error!("only one curve feature can be enabled, try `--no-default-features`");
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum CurveKind {
+    Bn254,
+    Bls12_381,
+}
+
+impl CurveKind {
+    #[cfg(feature = "bn254")]
+    pub const CURRENT: Self = Self::Bn254;
+    #[cfg(feature = "bls12-381")]
+    pub const CURRENT: Self = Self::Bls12_381;
+}
+
+/// The circuit an artifact is generated for, or a loader is going to use it for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CircuitInfo {
+    pub circuit: CircuitKind,
+    pub height: usize,
+    /// number of auditor viewing keys, the keys themselves are public inputs read from the vault
+    pub auditors: usize,
+}
+
+/// Written in front of every key file, so that keys of another circuit or build are rejected.
+#[derive(Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct ArtifactHeader {
+    pub magic: [u8; 4],
+    pub version: u16,
+    pub circuit: CircuitKind,
+    pub height: u32,
+    pub hasher: HasherKind,
+    pub curve: CurveKind,
+    /// number of auditor keys the circuit takes, the keys themselves are public inputs read from the vault
+    /// and rotate without a new setup, so a key file is not tied to them
+    pub auditors: u32,
+    pub vk_hash: [u8; 32],
+}
+
+impl ArtifactHeader {
+    pub fn new(info: &CircuitInfo, vk_hash: [u8; 32]) -> Self {
+        Self {
+            magic: ARTIFACT_MAGIC,
+            version: ARTIFACT_VERSION,
+            circuit: info.circuit,
+            height: info.height as u32,
+            hasher: HasherKind::CURRENT,
+            curve: CurveKind::CURRENT,
+            auditors: info.auditors as u32,
+            vk_hash,
+        }
+    }
+
+    pub fn validate(&self, info: &CircuitInfo) -> Result<()> {
+        if self.magic != ARTIFACT_MAGIC {
+            return Err(anyhow!("not a maze artifact, or written before artifacts had a header"));
+        }
+        if self.version != ARTIFACT_VERSION {
+            return Err(anyhow!("artifact format version {} is not supported, expected {}", self.version, ARTIFACT_VERSION));
+        }
+        if self.circuit != info.circuit {
+            return Err(anyhow!("artifact is for the {:?} circuit, expected {:?}", self.circuit, info.circuit));
+        }
+        if self.height as usize != info.height {
+            return Err(anyhow!("artifact is for merkle height {}, expected {}", self.height, info.height));
+        }
+        if self.hasher != HasherKind::CURRENT {
+            return Err(anyhow!("artifact is for the {:?} hasher, expected {:?}", self.hasher, HasherKind::CURRENT));
+        }
+        if self.curve != CurveKind::CURRENT {
+            return Err(anyhow!("artifact is for the {:?} curve, expected {:?}", self.curve, CurveKind::CURRENT));
+        }
+        if self.auditors as usize != info.auditors {
+            return Err(anyhow!("artifact is for {} auditor keys, expected {}", self.auditors, info.auditors));
+        }
+
+        Ok(())
+    }
+
+    pub fn validate_vk_hash(&self, vk_hash: [u8; 32]) -> Result<()> {
+        if self.vk_hash != vk_hash {
+            return Err(anyhow!("verifying key does not match the hash in the artifact header"));
+        }
+
+        Ok(())
+    }
+}
+
+/// Artifacts of a setup, identified by the hash of its verifying key.
+pub trait Artifact {
+    fn vk_hash(&self) -> [u8; 32];
+}
+
+impl<E: PairingEngine> Artifact for VerifyingKey<E> {
+    fn vk_hash(&self) -> [u8; 32] {
+        let mut buf = Vec::new();
+        self.serialize(&mut buf).expect("serialize verifying key to a buffer");
+
+        Sha256::digest(&buf).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::{BorshSerialize, BorshDeserialize};
+
+    use super::{ArtifactHeader, CircuitInfo, CircuitKind, ARTIFACT_VERSION};
+
+    const INFO: CircuitInfo = CircuitInfo { circuit: CircuitKind::Withdraw, height: 21, auditors: 2 };
+
+    #[test]
+    fn test_header_round_trip() {
+        let header = ArtifactHeader::new(&INFO, [7; 32]);
+        let mut buf = header.try_to_vec().unwrap();
+        // the key follows the header in the file
+        buf.extend([1, 2, 3]);
+
+        let mut data = buf.as_slice();
+        let parsed = ArtifactHeader::deserialize(&mut data).unwrap();
+        assert_eq!(parsed, header);
+        assert_eq!(data, &[1, 2, 3]);
+        assert!(parsed.validate(&INFO).is_ok());
+        assert!(parsed.validate_vk_hash([7; 32]).is_ok());
+        assert!(parsed.validate_vk_hash([8; 32]).is_err());
+    }
+
+    #[test]
+    fn test_header_mismatch() {
+        let header = ArtifactHeader::new(&INFO, [7; 32]);
+        for info in [
+            CircuitInfo { circuit: CircuitKind::WithdrawExclusion, ..INFO },
+            CircuitInfo { height: 20, ..INFO },
+            CircuitInfo { auditors: 1, ..INFO },
+        ] {
+            assert!(header.validate(&info).is_err());
+        }
+
+        let mut bad = header.clone();
+        bad.magic = *b"mazz";
+        assert!(bad.validate(&INFO).is_err());
+        let mut bad = header;
+        bad.version = ARTIFACT_VERSION + 1;
+        assert!(bad.validate(&INFO).is_err());
+    }
+}
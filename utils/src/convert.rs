@@ -3,11 +3,16 @@ use soda_maze_program::params::bn::{G1Affine254 as MazeG1Affine, G2Affine254 as
 use soda_maze_program::bn::BigInteger256 as MazeBigInteger;
 use soda_maze_program::core::EdwardsAffine as MazeEdwardsAffine;
 use soda_maze_program::verifier::Proof as MazeProof;
-use ark_ff::{PrimeField, BigInteger256 as BigInteger};
-use ark_bn254::{Bn254, G1Affine, G2Affine, Fr, Fq, Fq2};
+use anyhow::{anyhow, Result};
+use ark_ec::PairingEngine;
+use ark_ff::{PrimeField, One, Zero, BigInteger256 as BigInteger};
+use ark_bn254::{Bn254, G1Affine, G2Affine, Fr, Fq, Fq2, Fq6};
 use ark_ed_on_bn254::EdwardsAffine;
 use ark_groth16::{VerifyingKey, ProvingKey, Proof};
 
+use crate::artifact::Artifact;
+use crate::parser::{ProofJson, PublicJson, VerificationKeyJson, from_decimal_string, to_decimal_string};
+
 #[inline]
 pub fn to_maze_fr_repr(fr: Fr) -> MazeBigInteger {
     MazeBigInteger::new(fr.into_repr().0)
@@ -134,6 +139,13 @@ impl Into<VerifyingKey<Bn254>> for MazeVerifyingKey {
     }
 }
 
+impl Artifact for MazeVerifyingKey {
+    fn vk_hash(&self) -> [u8; 32] {
+        let vk: VerifyingKey<Bn254> = self.clone().into();
+        vk.vk_hash()
+    }
+}
+
 #[derive(Clone, BorshSerialize, BorshDeserialize)]
 pub struct MazeProvingKey {
     /// The underlying verification key.
@@ -201,3 +213,137 @@ pub fn from_maze_proof(p: MazeProof) -> Proof<Bn254> {
         c: from_maze_g1_affine(p.c),
     }
 }
+
+fn parse_g1_affine(p: &[String; 3]) -> Result<G1Affine> {
+    let z = from_decimal_string::<Fq>(&p[2])?;
+    if z.is_zero() {
+        return Ok(G1Affine::zero());
+    }
+    if !z.is_one() {
+        return Err(anyhow!("g1 point is not normalized"));
+    }
+    let point = G1Affine::new(from_decimal_string(&p[0])?, from_decimal_string(&p[1])?, false);
+    if !point.is_on_curve() || !point.is_in_correct_subgroup_assuming_on_curve() {
+        return Err(anyhow!("invalid g1 point"));
+    }
+
+    Ok(point)
+}
+
+fn format_g1_affine(p: &G1Affine) -> [String; 3] {
+    if p.infinity {
+        return ["0".to_string(), "1".to_string(), "0".to_string()];
+    }
+
+    [to_decimal_string(&p.x), to_decimal_string(&p.y), "1".to_string()]
+}
+
+fn parse_fq2(p: &[String; 2]) -> Result<Fq2> {
+    Ok(Fq2::new(from_decimal_string(&p[0])?, from_decimal_string(&p[1])?))
+}
+
+fn format_fq2(p: &Fq2) -> [String; 2] {
+    [to_decimal_string(&p.c0), to_decimal_string(&p.c1)]
+}
+
+fn parse_g2_affine(p: &[[String; 2]; 3]) -> Result<G2Affine> {
+    let z = parse_fq2(&p[2])?;
+    if z.is_zero() {
+        return Ok(G2Affine::zero());
+    }
+    if !z.is_one() {
+        return Err(anyhow!("g2 point is not normalized"));
+    }
+    let point = G2Affine::new(parse_fq2(&p[0])?, parse_fq2(&p[1])?, false);
+    if !point.is_on_curve() || !point.is_in_correct_subgroup_assuming_on_curve() {
+        return Err(anyhow!("invalid g2 point"));
+    }
+
+    Ok(point)
+}
+
+fn format_g2_affine(p: &G2Affine) -> [[String; 2]; 3] {
+    if p.infinity {
+        return [format_fq2(&Fq2::zero()), format_fq2(&Fq2::one()), format_fq2(&Fq2::zero())];
+    }
+
+    [format_fq2(&p.x), format_fq2(&p.y), format_fq2(&Fq2::one())]
+}
+
+fn format_fq6(p: &Fq6) -> [[String; 2]; 3] {
+    [format_fq2(&p.c0), format_fq2(&p.c1), format_fq2(&p.c2)]
+}
+
+#[inline]
+fn check_snarkjs_curve(protocol: &str, curve: &str) -> Result<()> {
+    if protocol != "groth16" || curve != "bn128" {
+        return Err(anyhow!("unsupported {} on {}", protocol, curve));
+    }
+
+    Ok(())
+}
+
+/// Import a groth16 proof generated by an external prover such as snarkjs or rapidsnark.
+pub fn from_proof_json(p: &ProofJson) -> Result<MazeProof> {
+    check_snarkjs_curve(&p.protocol, &p.curve)?;
+
+    Ok(to_maze_proof(Proof {
+        a: parse_g1_affine(&p.pi_a)?,
+        b: parse_g2_affine(&p.pi_b)?,
+        c: parse_g1_affine(&p.pi_c)?,
+    }))
+}
+
+pub fn to_proof_json(p: MazeProof) -> ProofJson {
+    let p = from_maze_proof(p);
+
+    ProofJson {
+        pi_a: format_g1_affine(&p.a),
+        pi_b: format_g2_affine(&p.b),
+        pi_c: format_g1_affine(&p.c),
+        protocol: "groth16".to_string(),
+        curve: "bn128".to_string(),
+    }
+}
+
+pub fn from_public_json(p: &PublicJson) -> Result<Vec<Fr>> {
+    p.0.iter().map(|input| from_decimal_string(input)).collect()
+}
+
+pub fn to_public_json(inputs: &[Fr]) -> PublicJson {
+    PublicJson(inputs.iter().map(to_decimal_string).collect())
+}
+
+pub fn from_verification_key_json(vk: &VerificationKeyJson) -> Result<MazeVerifyingKey> {
+    check_snarkjs_curve(&vk.protocol, &vk.curve)?;
+    if vk.ic.len() != vk.n_public + 1 {
+        return Err(anyhow!("{} public inputs but {} IC points", vk.n_public, vk.ic.len()));
+    }
+
+    let vk = VerifyingKey::<Bn254> {
+        alpha_g1: parse_g1_affine(&vk.vk_alpha_1)?,
+        beta_g2: parse_g2_affine(&vk.vk_beta_2)?,
+        gamma_g2: parse_g2_affine(&vk.vk_gamma_2)?,
+        delta_g2: parse_g2_affine(&vk.vk_delta_2)?,
+        gamma_abc_g1: vk.ic.iter().map(parse_g1_affine).collect::<Result<_>>()?,
+    };
+
+    Ok(vk.into())
+}
+
+pub fn to_verification_key_json(vk: MazeVerifyingKey) -> VerificationKeyJson {
+    let vk: VerifyingKey<Bn254> = vk.into();
+    let alphabeta = Bn254::pairing(vk.alpha_g1, vk.beta_g2);
+
+    VerificationKeyJson {
+        protocol: "groth16".to_string(),
+        curve: "bn128".to_string(),
+        n_public: vk.gamma_abc_g1.len() - 1,
+        vk_alpha_1: format_g1_affine(&vk.alpha_g1),
+        vk_beta_2: format_g2_affine(&vk.beta_g2),
+        vk_gamma_2: format_g2_affine(&vk.gamma_g2),
+        vk_delta_2: format_g2_affine(&vk.delta_g2),
+        vk_alphabeta_12: Some([format_fq6(&alphabeta.c0), format_fq6(&alphabeta.c1)]),
+        ic: vk.gamma_abc_g1.iter().map(format_g1_affine).collect(),
+    }
+}
@@ -0,0 +1,137 @@
+use std::path::{Path, PathBuf};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ff::{Field, One, PrimeField, UniformRand, Zero};
+use rand_core::{CryptoRng, RngCore};
+use serde::{Serialize, Deserialize};
+
+use crate::parser::{JsonParser, from_hex_string, to_hex_string};
+
+#[cfg(feature = "bn254")]
+use ark_ed_on_bn254::{EdwardsAffine, EdwardsProjective, Fr as Frr};
+#[cfg(feature = "bls12-381")]
+use ark_ed_on_bls12_381::{EdwardsAffine, EdwardsProjective, Fr as Frr};
+
+// Pedersen DKG over Feldman VSS, every round is exchanged as files in a shared directory:
+// 1. each dealer publishes `dealing-{i}.json` and sends `share-{i}-{j}.json` to auditor j privately.
+// 2. auditor j publishes `complaint-{i}-{j}.json` against dealer i if the share does not match the dealing.
+// 3. dealer i answers with `response-{i}-{j}.json` revealing the share, otherwise it is disqualified.
+
+/// Feldman commitments a_k * G of the dealer polynomial coefficients.
+#[derive(Serialize, Deserialize)]
+pub struct Dealing {
+    pub dealer: u64,
+    pub commitments: Vec<String>,
+}
+
+impl JsonParser for Dealing {}
+
+/// Evaluation f_i(j) of dealer i's polynomial for auditor j.
+#[derive(Serialize, Deserialize)]
+pub struct DealtShare {
+    pub dealer: u64,
+    pub receiver: u64,
+    pub share: String,
+}
+
+impl JsonParser for DealtShare {}
+
+#[derive(Serialize, Deserialize)]
+pub struct Complaint {
+    pub dealer: u64,
+    pub complainer: u64,
+}
+
+impl JsonParser for Complaint {}
+
+/// Viewing key share of an auditor, the public share is checked against decryption evidence.
+#[derive(Serialize, Deserialize)]
+pub struct KeyShare {
+    pub index: u64,
+    pub threshold: u64,
+    pub private_share: String,
+    pub public_share: String,
+    pub pubkey: String,
+}
+
+impl JsonParser for KeyShare {}
+
+pub fn dealing_path(dir: &Path, dealer: u64) -> PathBuf {
+    dir.join(format!("dealing-{}.json", dealer))
+}
+
+pub fn share_path(dir: &Path, dealer: u64, receiver: u64) -> PathBuf {
+    dir.join(format!("share-{}-{}.json", dealer, receiver))
+}
+
+pub fn complaint_path(dir: &Path, dealer: u64, complainer: u64) -> PathBuf {
+    dir.join(format!("complaint-{}-{}.json", dealer, complainer))
+}
+
+pub fn response_path(dir: &Path, dealer: u64, complainer: u64) -> PathBuf {
+    dir.join(format!("response-{}-{}.json", dealer, complainer))
+}
+
+pub fn gen_polynomial<R: RngCore + CryptoRng>(rng: &mut R, threshold: usize) -> Vec<Frr> {
+    (0..threshold).map(|_| Frr::rand(rng)).collect()
+}
+
+pub fn eval_polynomial(coeffs: &[Frr], x: u64) -> Frr {
+    let x = Frr::from(x);
+    coeffs.iter().rev().fold(Frr::zero(), |acc, coeff| acc * x + coeff)
+}
+
+pub fn commit_polynomial(coeffs: &[Frr]) -> Vec<EdwardsAffine> {
+    let generator = EdwardsAffine::prime_subgroup_generator();
+    coeffs.iter().map(|coeff| generator.mul(*coeff).into_affine()).collect()
+}
+
+/// Public key share of auditor x, i.e. f(x) * G, evaluated on the commitments.
+pub fn eval_commitments(commitments: &[EdwardsAffine], x: u64) -> EdwardsAffine {
+    let x = Frr::from(x);
+    commitments.iter().rev().fold(EdwardsProjective::zero(), |acc, commitment| {
+        acc.mul(x.into_repr()).add_mixed(commitment)
+    }).into_affine()
+}
+
+pub fn verify_share(commitments: &[EdwardsAffine], receiver: u64, share: Frr) -> bool {
+    EdwardsAffine::prime_subgroup_generator().mul(share).into_affine() == eval_commitments(commitments, receiver)
+}
+
+/// Lagrange coefficients at 0 of distinct nonzero indexes, ∑λ_j * f(j) = f(0).
+pub fn lagrange_coefficients(indexes: &[u64]) -> Vec<Frr> {
+    indexes.iter().map(|&j| {
+        let (num, den) = indexes.iter()
+            .filter(|&&m| m != j)
+            .fold((Frr::one(), Frr::one()), |(num, den), &m| {
+                (num * Frr::from(m), den * (Frr::from(m) - Frr::from(j)))
+            });
+        num * den.inverse().expect("duplicated indexes")
+    }).collect()
+}
+
+impl Dealing {
+    pub fn new(dealer: u64, commitments: &[EdwardsAffine]) -> Self {
+        Self {
+            dealer,
+            commitments: commitments.iter().map(|c| to_hex_string(c).unwrap()).collect(),
+        }
+    }
+
+    pub fn parse(&self) -> anyhow::Result<Vec<EdwardsAffine>> {
+        self.commitments.iter().map(|c| from_hex_string(c.clone())).collect()
+    }
+}
+
+impl DealtShare {
+    pub fn new(dealer: u64, receiver: u64, share: Frr) -> Self {
+        Self {
+            dealer,
+            receiver,
+            share: to_hex_string(&share).unwrap(),
+        }
+    }
+
+    pub fn parse(&self) -> anyhow::Result<Frr> {
+        from_hex_string(self.share.clone())
+    }
+}
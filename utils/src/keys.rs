@@ -0,0 +1,233 @@
+use anyhow::Result;
+use ark_ec::ProjectiveCurve;
+use ark_ed_on_bn254::{EdwardsAffine, EdwardsParameters, EdwardsProjective, Fq as Fr, Fr as Frr};
+use ark_ff::{BigInteger, PrimeField};
+use serde::{Serialize, Deserialize};
+use sha2::{Digest, Sha256};
+use soda_maze_lib::vanilla::withdraw::{gen_owner_key, gen_nullifier_key};
+use soda_maze_lib::vanilla::commit::{Ciphertext, gen_escrow};
+
+use crate::params::{Hasher, get_bn254_for_nullifier};
+use crate::parser::{JsonParser, from_hex_string, to_hex_string};
+
+// Key hierarchy of a wallet, every arrow is one-way:
+//   master seed -> spending key -> owner key (notes are bound to it, published in the shielded address)
+//   master seed -> spending key -> nullifier key (nullifiers of notes are computed from it)
+//   master seed -> spending key -> escrow nonces at path [auditor index]
+//   master seed -> incoming viewing key -> note keys at path [note index]
+// The viewing keys, which are the owner key, the nullifier key and the incoming viewing key, find,
+// decrypt and track the spending of notes, but can not spend them. The owner key alone reveals neither.
+
+const DOMAIN: &[u8] = b"soda-maze keys";
+
+// every part of the path is length prefixed, so that different paths never hash the same bytes
+fn derive(parent: &[u8], path: &[&[u8]]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(DOMAIN);
+    hasher.update(parent);
+    for part in path {
+        hasher.update((part.len() as u32).to_le_bytes());
+        hasher.update(part);
+    }
+
+    hasher.finalize().into()
+}
+
+pub struct MasterSeed([u8; 32]);
+
+impl MasterSeed {
+    /// Entropy could be a wallet signature over a fixed message, or random bytes kept by the wallet.
+    pub fn new(entropy: &[u8]) -> Self {
+        Self(derive(entropy, &[b"master"]))
+    }
+
+    /// Keys of an account, notes of different vaults are not linkable by their keys.
+    pub fn account_keys(&self, vault: &[u8]) -> SpendingKeys {
+        let spending_key = Fr::from_le_bytes_mod_order(&derive(&self.0, &[b"spending", vault]));
+        let incoming_viewing_key = Frr::from_le_bytes_mod_order(&derive(&self.0, &[b"viewing", vault]));
+        let ref params = get_bn254_for_nullifier();
+        let owner_key = gen_owner_key::<_, Hasher<Fr>>(params, spending_key)
+            .expect("hash of a single field element");
+        let nullifier_key = gen_nullifier_key::<_, Hasher<Fr>>(params, spending_key)
+            .expect("hash of two field elements");
+
+        SpendingKeys {
+            spending_key,
+            viewing_keys: ViewingKeys {
+                owner_key,
+                nullifier_key,
+                incoming_viewing_key,
+            },
+        }
+    }
+}
+
+pub struct SpendingKeys {
+    /// the `secret` proven in withdraw and transfer circuits
+    pub spending_key: Fr,
+    pub viewing_keys: ViewingKeys,
+}
+
+impl SpendingKeys {
+    /// Nonces of the escrow to each auditor key, they open the escrow when a note is spent.
+    pub fn escrow_nonces(&self, num: usize) -> Vec<Frr> {
+        let spending_key = self.spending_key.into_repr().to_bytes_le();
+
+        (0..num as u32)
+            .map(|index| Frr::from_le_bytes_mod_order(&derive(&spending_key, &[b"escrow", &index.to_le_bytes()])))
+            .collect()
+    }
+
+    /// Escrow of the nullifier key to the auditor keys, published in the shielded address.
+    pub fn escrow(&self, pubkeys: &[EdwardsAffine]) -> Result<Vec<Ciphertext<EdwardsParameters>>> {
+        let nonces = self.escrow_nonces(pubkeys.len());
+
+        Ok(gen_escrow(pubkeys, self.viewing_keys.nullifier_key, &nonces)?)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ViewingKeys {
+    /// owner key = hash(spending key), notes are bound to it
+    pub owner_key: Fr,
+    /// nullifier key = hash(spending key | 1), nullifiers of notes are computed from it
+    pub nullifier_key: Fr,
+    /// decrypts notes sent to the shielded address and derives the note keys
+    pub incoming_viewing_key: Frr,
+}
+
+impl ViewingKeys {
+    pub fn viewing_pubkey(&self) -> EdwardsAffine {
+        EdwardsProjective::prime_subgroup_generator()
+            .mul(self.incoming_viewing_key.into_repr())
+            .into_affine()
+    }
+
+    pub fn note_keys(&self, index: u64) -> NoteKeys {
+        let ivk = self.incoming_viewing_key.into_repr().to_bytes_le();
+        let note = derive(&ivk, &[b"note", &index.to_le_bytes()]);
+
+        NoteKeys {
+            utxo_key: derive(&note, &[b"utxo"]),
+            balance_key: derive(&note, &[b"balance"]),
+        }
+    }
+
+    pub fn to_data(&self) -> Result<ViewingKeysData> {
+        Ok(ViewingKeysData {
+            owner_key: to_hex_string(&self.owner_key)?,
+            nullifier_key: to_hex_string(&self.nullifier_key)?,
+            incoming_viewing_key: to_hex_string(&self.incoming_viewing_key)?,
+        })
+    }
+
+    pub fn from_data(data: &ViewingKeysData) -> Result<Self> {
+        Ok(Self {
+            owner_key: from_hex_string(data.owner_key.clone())?,
+            nullifier_key: from_hex_string(data.nullifier_key.clone())?,
+            incoming_viewing_key: from_hex_string(data.incoming_viewing_key.clone())?,
+        })
+    }
+}
+
+/// Keys of the note at a derivation path, which is the nonce of its UTXO account.
+pub struct NoteKeys {
+    /// seed of the UTXO account
+    pub utxo_key: [u8; 32],
+    /// encrypts the amount of a note the wallet keeps for itself
+    pub balance_key: [u8; 32],
+}
+
+/// Hex encoded viewing keys, exported to a view-only wallet.
+#[derive(Serialize, Deserialize)]
+pub struct ViewingKeysData {
+    pub owner_key: String,
+    pub nullifier_key: String,
+    pub incoming_viewing_key: String,
+}
+
+impl JsonParser for ViewingKeysData {}
+
+#[cfg(test)]
+mod tests {
+    use ark_ec::{AffineCurve, ProjectiveCurve};
+    use ark_ed_on_bn254::{EdwardsParameters, EdwardsProjective, Fr as Frr};
+    use ark_ff::PrimeField;
+    use soda_maze_lib::vanilla::commit::gen_nullifier_key_point;
+
+    use super::{MasterSeed, ViewingKeys, derive};
+    use crate::parser::JsonParser;
+
+    #[test]
+    fn test_derive_paths() {
+        // length prefixes keep the split of the path in the hashed bytes
+        assert_ne!(derive(b"seed", &[b"ab", b"c"]), derive(b"seed", &[b"a", b"bc"]));
+        assert_ne!(derive(b"seed", &[b"abc"]), derive(b"seed", &[b"abc", b""]));
+        assert_eq!(derive(b"seed", &[b"note", &1u64.to_le_bytes()]), derive(b"seed", &[b"note", &1u64.to_le_bytes()]));
+    }
+
+    #[test]
+    fn test_account_keys() {
+        let seed = MasterSeed::new(b"signature");
+        let keys = seed.account_keys(b"vault");
+        let viewing_keys = keys.viewing_keys;
+
+        // deterministic per vault, unlinkable across vaults
+        assert_eq!(seed.account_keys(b"vault").viewing_keys, viewing_keys);
+        let other = seed.account_keys(b"other vault");
+        assert_ne!(other.spending_key, keys.spending_key);
+        assert_ne!(other.viewing_keys.owner_key, viewing_keys.owner_key);
+        assert_ne!(other.viewing_keys.incoming_viewing_key, viewing_keys.incoming_viewing_key);
+
+        // the published owner key is not the nullifier key, nor the spending key
+        assert_ne!(viewing_keys.owner_key, viewing_keys.nullifier_key);
+        assert_ne!(viewing_keys.owner_key, keys.spending_key);
+        assert_ne!(viewing_keys.nullifier_key, keys.spending_key);
+
+        // note keys differ by path and by purpose
+        let note_0 = viewing_keys.note_keys(0);
+        let note_1 = viewing_keys.note_keys(1);
+        assert_ne!(note_0.utxo_key, note_1.utxo_key);
+        assert_ne!(note_0.balance_key, note_1.balance_key);
+        assert_ne!(note_0.utxo_key, note_0.balance_key);
+    }
+
+    #[test]
+    fn test_escrow() {
+        let keys = MasterSeed::new(b"signature").account_keys(b"vault");
+        let privkeys = [Frr::from(3u64), Frr::from(5u64)];
+        let pubkeys = privkeys
+            .iter()
+            .map(|privkey| EdwardsProjective::prime_subgroup_generator().mul(privkey.into_repr()).into_affine())
+            .collect::<Vec<_>>();
+
+        let nonces = keys.escrow_nonces(2);
+        assert_eq!(keys.escrow_nonces(2), nonces);
+        assert_ne!(nonces[0], nonces[1]);
+
+        // each auditor decrypts the nullifier key point from its escrow
+        let escrow = keys.escrow(&pubkeys).unwrap();
+        let nullifier_key_point = gen_nullifier_key_point::<EdwardsParameters>(keys.viewing_keys.nullifier_key);
+        for (privkey, (escrow_0, escrow_1)) in privkeys.iter().zip(escrow.iter()) {
+            assert_eq!(escrow_1.into_projective() - escrow_0.mul(privkey.into_repr()), nullifier_key_point.into_projective());
+        }
+    }
+
+    #[test]
+    fn test_viewing_keys_data() {
+        let viewing_keys = MasterSeed::new(b"signature").account_keys(b"vault").viewing_keys;
+        let data = viewing_keys.to_data().unwrap();
+        assert_eq!(ViewingKeys::from_data(&data).unwrap(), viewing_keys);
+
+        // through the JSON exported to a view-only wallet
+        let path = std::env::temp_dir().join(format!("soda-maze-viewing-keys-{}.json", std::process::id()));
+        data.to_file(&path).unwrap();
+        let parsed = super::ViewingKeysData::from_file(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(ViewingKeys::from_data(&parsed).unwrap(), viewing_keys);
+
+        let mut data = data;
+        data.nullifier_key = "not hex".into();
+        assert!(ViewingKeys::from_data(&data).is_err());
+    }
+}
@@ -1,5 +1,10 @@
+pub mod artifact;
 pub mod parser;
 #[cfg(feature = "bn254")]
 pub mod convert;
+#[cfg(feature = "bn254")]
+pub mod keys;
 pub mod params;
-pub mod rand;
\ No newline at end of file
+pub mod pk;
+pub mod rand;
+pub mod dkg;
\ No newline at end of file
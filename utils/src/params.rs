@@ -1,7 +1,8 @@
 use std::rc::Rc;
-use soda_maze_lib::vanilla::hasher::poseidon::PoseidonHasher;
 use soda_maze_lib::vanilla::deposit::DepositConstParams;
+use soda_maze_lib::vanilla::batch_deposit::BatchDepositConstParams;
 use soda_maze_lib::vanilla::withdraw::WithdrawConstParams;
+use soda_maze_lib::vanilla::transfer::TransferConstParams;
 use soda_maze_lib::vanilla::commit::CommitConstParams;
 
 #[cfg(feature = "bn254")]
@@ -9,44 +10,124 @@ use ark_ed_on_bn254::{EdwardsParameters, EdwardsAffine, Fq as Fr};
 #[cfg(feature = "bls12-381")]
 use ark_ed_on_bls12_381::{EdwardsParameters, EdwardsAffine, Fq as Fr};
 
-#[cfg(all(feature = "bn254", feature = "poseidon"))]
+#[cfg(feature = "poseidon")]
+pub use soda_maze_lib::{
+    vanilla::hasher::poseidon::PoseidonHasher as Hasher,
+    circuits::poseidon::PoseidonHasherGadget as HasherGadget,
+    params::poseidon::{
+        get_poseidon_bn254_for_leaf as get_bn254_for_leaf,
+        get_poseidon_bn254_for_merkle as get_bn254_for_merkle,
+        get_poseidon_bn254_for_nullifier as get_bn254_for_nullifier,
+    },
+};
+#[cfg(feature = "poseidon2")]
+pub use soda_maze_lib::{
+    vanilla::hasher::poseidon2::Poseidon2Hasher as Hasher,
+    circuits::poseidon2::Poseidon2HasherGadget as HasherGadget,
+    params::poseidon2::{
+        get_poseidon2_bn254_for_leaf as get_bn254_for_leaf,
+        get_poseidon2_bn254_for_merkle as get_bn254_for_merkle,
+        get_poseidon2_bn254_for_nullifier as get_bn254_for_nullifier,
+    },
+};
+#[cfg(feature = "rescue")]
+pub use soda_maze_lib::{
+    vanilla::hasher::rescue::RescueHasher as Hasher,
+    circuits::rescue::RescueHasherGadget as HasherGadget,
+    params::rescue::{
+        get_rescue_bn254_for_leaf as get_bn254_for_leaf,
+        get_rescue_bn254_for_merkle as get_bn254_for_merkle,
+        get_rescue_bn254_for_nullifier as get_bn254_for_nullifier,
+    },
+};
+#[cfg(feature = "mimc")]
+pub use soda_maze_lib::{
+    vanilla::hasher::mimc::MIMCHasher as Hasher,
+    circuits::mimc::MIMCHasherGadget as HasherGadget,
+    params::mimc::{
+        get_mimc_bn254_for_leaf as get_bn254_for_leaf,
+        get_mimc_bn254_for_merkle as get_bn254_for_merkle,
+        get_mimc_bn254_for_nullifier as get_bn254_for_nullifier,
+    },
+};
+
+#[cfg(feature = "bn254")]
 pub fn gen_deposit_const_params(
     height: usize,
-    pubkey: Option<EdwardsAffine>,
-) -> DepositConstParams<EdwardsParameters, PoseidonHasher<Fr>> {
-    use soda_maze_lib::params::poseidon::*;
-
+    pubkeys: Option<Vec<EdwardsAffine>>,
+) -> DepositConstParams<EdwardsParameters, Hasher<Fr>> {
     DepositConstParams {
-        leaf_params: Rc::new(get_poseidon_bn254_for_leaf()),
-        inner_params: Rc::new(get_poseidon_bn254_for_merkle()),
+        leaf_params: Rc::new(get_bn254_for_leaf()),
         height,
-        commit: pubkey.map(|pubkey| {
+        commit: pubkeys.map(|pubkeys| {
             CommitConstParams {
-                nullifier_params: Rc::new(get_poseidon_bn254_for_nullifier()),
-                pubkey,
+                nullifier_params: Rc::new(get_bn254_for_nullifier()),
+                pubkeys,
             }
         }),
     }
 }
 
-#[cfg(all(feature = "bn254", feature = "poseidon"))]
+#[cfg(feature = "bn254")]
+pub fn gen_batch_deposit_const_params(
+    height: usize,
+    depth: usize,
+    pubkeys: Option<Vec<EdwardsAffine>>,
+) -> BatchDepositConstParams<EdwardsParameters, Hasher<Fr>> {
+    BatchDepositConstParams {
+        leaf_params: Rc::new(get_bn254_for_leaf()),
+        inner_params: Rc::new(get_bn254_for_merkle()),
+        height,
+        depth,
+        commit: pubkeys.map(|pubkeys| {
+            CommitConstParams {
+                nullifier_params: Rc::new(get_bn254_for_nullifier()),
+                pubkeys,
+            }
+        }),
+    }
+}
+
+#[cfg(feature = "bn254")]
 pub fn gen_withdraw_const_params(
     height: usize,
-    pubkey: Option<EdwardsAffine>,
-) -> WithdrawConstParams<EdwardsParameters, PoseidonHasher<Fr>> {
-    use soda_maze_lib::params::poseidon::*;
-    
-    let nullifier_params = Rc::new(get_poseidon_bn254_for_nullifier());
+    pubkeys: Option<Vec<EdwardsAffine>>,
+    exclusion: bool,
+    allowlist: bool,
+) -> WithdrawConstParams<EdwardsParameters, Hasher<Fr>> {
+    let nullifier_params = Rc::new(get_bn254_for_nullifier());
     WithdrawConstParams {
         nullifier_params: nullifier_params.clone(),
-        leaf_params: Rc::new(get_poseidon_bn254_for_leaf()),
-        inner_params: Rc::new(get_poseidon_bn254_for_merkle()),
+        leaf_params: Rc::new(get_bn254_for_leaf()),
+        inner_params: Rc::new(get_bn254_for_merkle()),
         height,
-        commit: pubkey.map(|pubkey| {
+        exclusion,
+        allowlist,
+        commit: pubkeys.map(|pubkeys| {
             CommitConstParams {
                 nullifier_params,
-                pubkey,
+                pubkeys,
             }
         }),
     }
-}
\ No newline at end of file
+}
+
+#[cfg(feature = "bn254")]
+pub fn gen_transfer_const_params(
+    height: usize,
+    pubkeys: Option<Vec<EdwardsAffine>>,
+) -> TransferConstParams<EdwardsParameters, Hasher<Fr>> {
+    let nullifier_params = Rc::new(get_bn254_for_nullifier());
+    TransferConstParams {
+        nullifier_params: nullifier_params.clone(),
+        leaf_params: Rc::new(get_bn254_for_leaf()),
+        inner_params: Rc::new(get_bn254_for_merkle()),
+        height,
+        commit: pubkeys.map(|pubkeys| {
+            CommitConstParams {
+                nullifier_params,
+                pubkeys,
+            }
+        }),
+    }
+}
@@ -1,10 +1,15 @@
-use anyhow::Result;
+use anyhow::{anyhow, Result};
+use ark_ff::{PrimeField, FpParameters, BigInteger};
 use ark_serialize::{CanonicalSerialize, CanonicalDeserialize};
 use borsh::{BorshSerialize, BorshDeserialize};
-use std::{path::PathBuf, fs::OpenOptions, io::Read};
-use serde::{Serialize, de::DeserializeOwned};
+use num_bigint::BigUint;
+use soda_maze_lib::proof::R1CSExport;
+use std::{path::PathBuf, fs::OpenOptions, io::{Read, Write}, collections::BTreeMap, str::FromStr};
+use serde::{Serialize, Deserialize, de::DeserializeOwned};
 use serde_json::{from_reader, to_writer_pretty};
 
+use crate::artifact::{Artifact, ArtifactHeader, CircuitInfo};
+
 pub trait JsonParser: Serialize + DeserializeOwned {
     fn from_file(path: &PathBuf) -> Result<Self> {
         let file = OpenOptions::new()
@@ -26,24 +31,32 @@ pub trait JsonParser: Serialize + DeserializeOwned {
     }
 }
 
-pub fn borsh_de_from_file<D: BorshDeserialize>(path: &PathBuf) -> Result<D> {
+/// Read an artifact written by `borsh_se_to_file`, failing if it is not for the given circuit.
+pub fn borsh_de_from_file<D: BorshDeserialize + Artifact>(path: &PathBuf, info: &CircuitInfo) -> Result<D> {
     let mut file = OpenOptions::new()
         .read(true)
-        .open(path)
-        .unwrap();
+        .open(path)?;
     let mut buffer = Vec::new();
     file.read_to_end(&mut buffer)?;
-    let res = BorshDeserialize::deserialize(&mut &buffer[..])?;
+
+    let mut data = &buffer[..];
+    let header = ArtifactHeader::deserialize(&mut data)
+        .map_err(|_| anyhow!("{} has no artifact header", path.display()))?;
+    header.validate(info)?;
+    let res = D::deserialize(&mut data)?;
+    header.validate_vk_hash(res.vk_hash())?;
 
     Ok(res)
 }
 
-pub fn borsh_se_to_file<S: BorshSerialize>(se: &S, path: &PathBuf) -> Result<()> {
+/// Write an artifact behind a header describing the circuit it is generated for.
+pub fn borsh_se_to_file<S: BorshSerialize + Artifact>(se: &S, info: &CircuitInfo, path: &PathBuf) -> Result<()> {
     let mut file = OpenOptions::new()
         .write(true)
         .create(true)
-        .open(path)
-        .unwrap();
+        .truncate(true)
+        .open(path)?;
+    ArtifactHeader::new(info, se.vk_hash()).serialize(&mut file)?;
     se.serialize(&mut file)?;
 
     Ok(())
@@ -62,3 +75,222 @@ pub fn to_hex_string<S: CanonicalSerialize>(se: &S) -> Result<String> {
     
     Ok(hex::encode(buf))
 }
+
+#[derive(Serialize, Deserialize)]
+pub struct R1CSJson {
+    pub n8: u32,
+    pub prime: String,
+    #[serde(rename = "nVars")]
+    pub n_vars: usize,
+    #[serde(rename = "nOutputs")]
+    pub n_outputs: usize,
+    #[serde(rename = "nPubInputs")]
+    pub n_pub_inputs: usize,
+    #[serde(rename = "nPrvInputs")]
+    pub n_prv_inputs: usize,
+    #[serde(rename = "nLabels")]
+    pub n_labels: usize,
+    #[serde(rename = "nConstraints")]
+    pub n_constraints: usize,
+    /// `[a, b, c]` of every constraint, as maps from the wire to its coefficient
+    pub constraints: Vec<[BTreeMap<usize, String>; 3]>,
+    pub map: Vec<usize>,
+}
+
+impl JsonParser for R1CSJson {}
+
+/// Full witness of the iden3 format, starting with the constant one.
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct WitnessJson(pub Vec<String>);
+
+impl JsonParser for WitnessJson {}
+
+/// Groth16 proof as written by snarkjs and rapidsnark, points are in projective coordinates.
+#[derive(Serialize, Deserialize)]
+pub struct ProofJson {
+    pub pi_a: [String; 3],
+    pub pi_b: [[String; 2]; 3],
+    pub pi_c: [String; 3],
+    pub protocol: String,
+    pub curve: String,
+}
+
+impl JsonParser for ProofJson {}
+
+/// Public inputs as written by snarkjs, without the constant one.
+#[derive(Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct PublicJson(pub Vec<String>);
+
+impl JsonParser for PublicJson {}
+
+/// Groth16 verifying key as written by snarkjs.
+#[derive(Serialize, Deserialize)]
+pub struct VerificationKeyJson {
+    pub protocol: String,
+    pub curve: String,
+    #[serde(rename = "nPublic")]
+    pub n_public: usize,
+    pub vk_alpha_1: [String; 3],
+    pub vk_beta_2: [[String; 2]; 3],
+    pub vk_gamma_2: [[String; 2]; 3],
+    pub vk_delta_2: [[String; 2]; 3],
+    /// pairing of alpha and beta, only written for the tools that expect it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub vk_alphabeta_12: Option<[[[String; 2]; 3]; 2]>,
+    #[serde(rename = "IC")]
+    pub ic: Vec<[String; 3]>,
+}
+
+impl JsonParser for VerificationKeyJson {}
+
+#[inline]
+fn field_size<F: PrimeField>() -> usize {
+    F::size_in_bits().div_ceil(64) * 8
+}
+
+#[inline]
+fn write_field<F: PrimeField, W: Write>(w: &mut W, f: &F) -> Result<()> {
+    let mut bytes = f.into_repr().to_bytes_le();
+    bytes.resize(field_size::<F>(), 0);
+    w.write_all(&bytes)?;
+
+    Ok(())
+}
+
+#[inline]
+fn write_field_header<F: PrimeField, W: Write>(w: &mut W) -> Result<()> {
+    let mut prime = F::Params::MODULUS.to_bytes_le();
+    prime.resize(field_size::<F>(), 0);
+    w.write_all(&(field_size::<F>() as u32).to_le_bytes())?;
+    w.write_all(&prime)?;
+
+    Ok(())
+}
+
+#[inline]
+fn write_section<W: Write>(w: &mut W, section_type: u32, content: &[u8]) -> Result<()> {
+    w.write_all(&section_type.to_le_bytes())?;
+    w.write_all(&(content.len() as u64).to_le_bytes())?;
+    w.write_all(content)?;
+
+    Ok(())
+}
+
+#[inline]
+pub fn to_decimal_string<F: PrimeField>(f: &F) -> String {
+    let n: BigUint = (*f).into();
+    n.to_string()
+}
+
+pub fn from_decimal_string<F: PrimeField>(s: &str) -> Result<F> {
+    let n = BigUint::from_str(s)?;
+    if n >= F::Params::MODULUS.into() {
+        return Err(anyhow!("{} is not in the field", s));
+    }
+
+    Ok(F::from(n))
+}
+
+/// Write the constraints in the iden3 `.r1cs` binary format.
+pub fn write_r1cs_to_file<F: PrimeField>(r1cs: &R1CSExport<F>, path: &PathBuf) -> Result<()> {
+    let matrices = &r1cs.matrices;
+    let n_wires = matrices.num_instance_variables + matrices.num_witness_variables;
+
+    let mut header = Vec::new();
+    write_field_header::<F, _>(&mut header)?;
+    header.write_all(&(n_wires as u32).to_le_bytes())?;
+    header.write_all(&0u32.to_le_bytes())?;
+    header.write_all(&(matrices.num_instance_variables as u32 - 1).to_le_bytes())?;
+    header.write_all(&0u32.to_le_bytes())?;
+    header.write_all(&(n_wires as u64).to_le_bytes())?;
+    header.write_all(&(matrices.num_constraints as u32).to_le_bytes())?;
+
+    let mut constraints = Vec::new();
+    for i in 0..matrices.num_constraints {
+        for row in [&matrices.a[i], &matrices.b[i], &matrices.c[i]] {
+            constraints.write_all(&(row.len() as u32).to_le_bytes())?;
+            for (coeff, wire) in row {
+                constraints.write_all(&(*wire as u32).to_le_bytes())?;
+                write_field(&mut constraints, coeff)?;
+            }
+        }
+    }
+
+    let mut labels = Vec::new();
+    for wire in 0..n_wires as u64 {
+        labels.write_all(&wire.to_le_bytes())?;
+    }
+
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(path)?;
+    file.write_all(b"r1cs")?;
+    file.write_all(&1u32.to_le_bytes())?;
+    file.write_all(&3u32.to_le_bytes())?;
+    write_section(&mut file, 1, &header)?;
+    write_section(&mut file, 2, &constraints)?;
+    write_section(&mut file, 3, &labels)?;
+
+    Ok(())
+}
+
+/// Write the full assignment in the iden3 `.wtns` binary format.
+pub fn write_wtns_to_file<F: PrimeField>(r1cs: &R1CSExport<F>, path: &PathBuf) -> Result<()> {
+    let mut header = Vec::new();
+    write_field_header::<F, _>(&mut header)?;
+    header.write_all(&(r1cs.assignment.len() as u32).to_le_bytes())?;
+
+    let mut values = Vec::new();
+    for value in r1cs.assignment.iter() {
+        write_field(&mut values, value)?;
+    }
+
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(path)?;
+    file.write_all(b"wtns")?;
+    file.write_all(&2u32.to_le_bytes())?;
+    file.write_all(&2u32.to_le_bytes())?;
+    write_section(&mut file, 1, &header)?;
+    write_section(&mut file, 2, &values)?;
+
+    Ok(())
+}
+
+impl<F: PrimeField> From<&R1CSExport<F>> for R1CSJson {
+    fn from(r1cs: &R1CSExport<F>) -> Self {
+        let matrices = &r1cs.matrices;
+        let n_vars = matrices.num_instance_variables + matrices.num_witness_variables;
+        let to_map = |row: &Vec<(F, usize)>| {
+            row.iter().map(|(coeff, wire)| (*wire, to_decimal_string(coeff))).collect()
+        };
+        let modulus: BigUint = F::Params::MODULUS.into();
+
+        Self {
+            n8: field_size::<F>() as u32,
+            prime: modulus.to_string(),
+            n_vars,
+            n_outputs: 0,
+            n_pub_inputs: matrices.num_instance_variables - 1,
+            n_prv_inputs: 0,
+            n_labels: n_vars,
+            n_constraints: matrices.num_constraints,
+            constraints: (0..matrices.num_constraints)
+                .map(|i| [to_map(&matrices.a[i]), to_map(&matrices.b[i]), to_map(&matrices.c[i])])
+                .collect(),
+            map: (0..n_vars).collect(),
+        }
+    }
+}
+
+impl<F: PrimeField> From<&R1CSExport<F>> for WitnessJson {
+    fn from(r1cs: &R1CSExport<F>) -> Self {
+        Self(r1cs.assignment.iter().map(to_decimal_string).collect())
+    }
+}
@@ -0,0 +1,137 @@
+use anyhow::{anyhow, Result};
+use ark_ec::PairingEngine;
+use ark_groth16::ProvingKey;
+use ark_serialize::{CanonicalSerialize, CanonicalDeserialize};
+use borsh::{BorshSerialize, BorshDeserialize};
+use std::{path::PathBuf, fs::OpenOptions, io::{Read, Write}};
+
+use crate::artifact::{Artifact, ArtifactHeader, CircuitInfo};
+
+const FLAG_COMPRESSED: u8 = 1;
+
+/// Write the proving key as the artifact header, a flags byte and the arkworks serialization of the key.
+pub fn write_pk_to_file<E: PairingEngine>(
+    pk: &ProvingKey<E>,
+    info: &CircuitInfo,
+    path: &PathBuf,
+    compressed: bool,
+) -> Result<()> {
+    let mut file = OpenOptions::new()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(path)?;
+    ArtifactHeader::new(info, pk.vk.vk_hash()).serialize(&mut file)?;
+    if compressed {
+        file.write_all(&[FLAG_COMPRESSED])?;
+        pk.serialize(&mut file)?;
+    } else {
+        file.write_all(&[0])?;
+        pk.serialize_uncompressed(&mut file)?;
+    }
+
+    Ok(())
+}
+
+/// Read a proving key written by `write_pk_to_file`, points of a trusted uncompressed key are not checked.
+pub fn read_pk_from_bytes<E: PairingEngine>(bytes: &[u8], info: &CircuitInfo, trusted: bool) -> Result<ProvingKey<E>> {
+    let mut data = bytes;
+    let header = ArtifactHeader::deserialize(&mut data).map_err(|_| anyhow!("proving key has no artifact header"))?;
+    header.validate(info)?;
+    let (flags, mut data) = data.split_first().ok_or_else(|| anyhow!("proving key is truncated"))?;
+    // compressed points are always checked when they are decompressed
+    let pk = match (flags & FLAG_COMPRESSED != 0, trusted) {
+        (true, _) => ProvingKey::<E>::deserialize(&mut data)?,
+        (false, false) => ProvingKey::deserialize_uncompressed(&mut data)?,
+        (false, true) => ProvingKey::deserialize_unchecked(&mut data)?,
+    };
+    header.validate_vk_hash(pk.vk.vk_hash())?;
+
+    Ok(pk)
+}
+
+pub fn read_pk_from_file<E: PairingEngine>(path: &PathBuf, info: &CircuitInfo, trusted: bool) -> Result<ProvingKey<E>> {
+    let mut file = OpenOptions::new()
+        .read(true)
+        .open(path)?;
+    let mut buffer = Vec::new();
+    file.read_to_end(&mut buffer)?;
+
+    read_pk_from_bytes(&buffer, info, trusted)
+}
+
+#[cfg(all(test, feature = "bn254"))]
+mod tests {
+    use ark_bn254::{Bn254, Fr, G1Affine, G2Affine};
+    use ark_ec::{AffineCurve, ProjectiveCurve};
+    use ark_groth16::{ProvingKey, VerifyingKey};
+    use ark_ff::UniformRand;
+    use rand_core::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+    use std::path::PathBuf;
+
+    use borsh::BorshSerialize;
+
+    use crate::artifact::{ArtifactHeader, CircuitInfo, CircuitKind};
+    use super::{write_pk_to_file, read_pk_from_file};
+
+    const INFO: CircuitInfo = CircuitInfo { circuit: CircuitKind::Deposit, height: 4, auditors: 1 };
+
+    fn random_pk() -> ProvingKey<Bn254> {
+        let rng = &mut XorShiftRng::seed_from_u64(0);
+        let mut g1 = || G1Affine::prime_subgroup_generator().mul(Fr::rand(rng)).into_affine();
+        let vk = VerifyingKey {
+            alpha_g1: g1(),
+            beta_g2: G2Affine::prime_subgroup_generator(),
+            gamma_g2: G2Affine::prime_subgroup_generator(),
+            delta_g2: G2Affine::prime_subgroup_generator(),
+            gamma_abc_g1: vec![g1(), g1()],
+        };
+
+        ProvingKey {
+            vk,
+            beta_g1: g1(),
+            delta_g1: g1(),
+            a_query: vec![g1(), g1()],
+            b_g1_query: vec![g1(), g1()],
+            b_g2_query: vec![G2Affine::prime_subgroup_generator(); 2],
+            h_query: vec![g1()],
+            l_query: vec![g1()],
+        }
+    }
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("soda-maze-{}-{}", name, std::process::id()))
+    }
+
+    #[test]
+    fn test_read_pk() {
+        let pk = random_pk();
+        for (name, compressed, trusted) in [("compressed", true, false), ("checked", false, false), ("unchecked", false, true)] {
+            let path = temp_path(name);
+            write_pk_to_file(&pk, &INFO, &path, compressed).unwrap();
+
+            assert_eq!(read_pk_from_file::<Bn254>(&path, &INFO, trusted).unwrap(), pk);
+            // keys of another circuit are rejected by the header
+            let info = CircuitInfo { circuit: CircuitKind::Withdraw, ..INFO };
+            assert!(read_pk_from_file::<Bn254>(&path, &info, trusted).is_err());
+
+            std::fs::remove_file(path).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_read_tampered_pk() {
+        let path = temp_path("tampered");
+        write_pk_to_file(&random_pk(), &INFO, &path, false).unwrap();
+        // swap the coordinates of alpha_g1, the first point of the key after the header and the flags byte,
+        // so the verifying key no longer matches the hash in the header
+        let mut bytes = std::fs::read(&path).unwrap();
+        let start = ArtifactHeader::new(&INFO, [0; 32]).try_to_vec().unwrap().len() + 1;
+        bytes[start..start + 64].rotate_left(32);
+        std::fs::write(&path, &bytes).unwrap();
+
+        assert!(read_pk_from_file::<Bn254>(&path, &INFO, true).is_err());
+        std::fs::remove_file(path).unwrap();
+    }
+}
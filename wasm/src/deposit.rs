@@ -2,18 +2,20 @@ use ark_ff::UniformRand;
 use ark_bn254::Bn254;
 use ark_ed_on_bn254::{Fq as Fr, Fr as Frr, EdwardsParameters};
 use ark_groth16::{Groth16, Proof};
-use js_sys::{Uint8Array, Array};
+use js_sys::Uint8Array;
 use rand_core::OsRng;
 use solana_sdk::signature::Signature;
 use wasm_bindgen::{JsValue, prelude::*};
 use serde::{Serialize, Deserialize};
 use solana_program::{pubkey::Pubkey, instruction::Instruction};
-use soda_maze_program::{Packer, params::HEIGHT, core::node::MerkleNode};
+use soda_maze_program::{params::AUDITORS, core::pubkey_to_fr_repr};
+use soda_maze_program::{core::{VanillaData, deposit::DepositVanillaData}, verifier::program::Program};
 use soda_maze_lib::circuits::poseidon::PoseidonHasherGadget;
 use soda_maze_lib::proof::{ProofScheme, scheme::DepositProof};
 use soda_maze_lib::vanilla::deposit::{DepositVanillaProof, DepositOriginInputs, DepositPublicInputs};
 use soda_maze_lib::vanilla::{hasher::poseidon::PoseidonHasher, commit::CommitOriginInputs, VanillaProof};
 use soda_maze_utils::convert::{to_maze_fr_repr, to_maze_edwards_affine, to_maze_proof, from_maze_fr_repr};
+use soda_maze_utils::keys::ViewingKeys;
 
 use crate::info;
 use crate::utils::*;
@@ -36,35 +38,36 @@ fn gen_deposit_instructions(
     depositor: Pubkey,
     proof: Proof<Bn254>,
     pub_in: DepositPublicInputs<EdwardsParameters>,
-    sig: &Signature,
+    keys: &ViewingKeys,
     nonce: u64,
 ) -> Instructions {
     use soda_maze_program::instruction::*;
 
     let leaf = to_maze_fr_repr(pub_in.leaf);
-    let updating_nodes = pub_in.update_nodes.into_iter().map(|node| {
-        to_maze_fr_repr(node)
-    }).collect::<Vec<_>>();
     let commitment = pub_in.commit.map(|commit| {
-        (to_maze_edwards_affine(commit.commitment.0), to_maze_edwards_affine(commit.commitment.1))
+        commit.commitments.into_iter().map(|c| {
+            (to_maze_edwards_affine(c.0), to_maze_edwards_affine(c.1))
+        }).collect::<Vec<_>>()
     }).unwrap();
     let credential = create_deposit_credential(
         vault,
         depositor,
         pub_in.deposit_amount,
+        token_mint,
+        pub_in.leaf_index,
         leaf,
-        Box::new(updating_nodes),
         commitment,
     ).unwrap();
 
     let proof = to_maze_proof(proof);
     let verifier = create_deposit_verifier(vault, depositor, Box::new(proof)).unwrap();
 
-    let verify = (0..145u8).into_iter().map(|i| {
-        verify_deposit_proof(vault, depositor, vec![i]).unwrap()
+    // padded with the index so that every verify transaction is distinct
+    let verify = (0..Program::max_steps(DepositVanillaData::INPUTS_LEN) as u16).map(|i| {
+        verify_deposit_proof(vault, depositor, i.to_le_bytes().to_vec()).unwrap()
     }).collect::<Vec<_>>();
 
-    let utxo = gen_utxo_key(sig, &vault, nonce);
+    let utxo = keys.note_keys(nonce).utxo_key;
     let finalize = finalize_deposit(vault, token_mint, depositor, pub_in.leaf_index, leaf, utxo).unwrap();
 
     Instructions {
@@ -79,11 +82,11 @@ fn gen_deposit_instructions(
 #[allow(clippy::too_many_arguments)]
 pub fn gen_deposit_proof(
     vault: Pubkey,
+    vault_data: Uint8Array, // vault account data, auditor keys are read from it
     token_mint: Pubkey,
     depositor: Pubkey,
     leaf_index: u64, // from vault info
     deposit_amount: u64,
-    neighbors: Array, // get_merkle_neighbor_nodes(vault, leaf_index)
     sig: Uint8Array,
     nonce: u64,
 ) -> JsValue {
@@ -94,34 +97,26 @@ pub fn gen_deposit_proof(
     let rng = &mut OsRng;
     
     let sig = Signature::new(&sig.to_vec());
-    let secret = gen_secret(&sig, &vault);
-
-    let ref nodes_hashes = get_default_node_hashes();
-    let neighbor_nodes = neighbors.iter().enumerate().map(|(layer, neighbor)| {
-        let data = Uint8Array::from(neighbor).to_vec();
-        if data.is_empty() {
-            nodes_hashes[layer]
-        } else {
-            let node = MerkleNode::unpack(&data).expect("Error: merkle node data can not unpack");
-            from_maze_fr_repr(node.hash).expect("Error: invalid node hash")
-        }
-    }).collect::<Vec<_>>();
-    assert_eq!(neighbor_nodes.len(), HEIGHT, "Error: invalid neighbors array length");
+    let keys = get_account_keys(&sig, &vault);
+    let owner = keys.viewing_keys.owner_key;
 
-    let const_params = get_deposit_const_params();
+    let auditor_keys = get_auditor_keys(vault_data);
+    let escrow = keys.escrow(&auditor_keys).expect("Error: invalid auditor keys");
+    let const_params = get_deposit_const_params(auditor_keys);
 
+    let asset_id = from_maze_fr_repr(pubkey_to_fr_repr(&token_mint)).unwrap();
     let origin_inputs = DepositOriginInputs {
         leaf_index,
         deposit_amount,
-        secret,
-        neighbor_nodes,
+        asset_id,
+        owner,
         commit: Some(CommitOriginInputs {
-            nonce: Frr::rand(rng),
+            nonces: (0..AUDITORS).map(|_| Frr::rand(rng)).collect(),
+            escrow,
         }),
     };
 
     let pk = get_deposit_pk();
-    let pk = pk.into();
 
     info("Generating vanilla proof...");
 
@@ -144,7 +139,7 @@ pub fn gen_deposit_proof(
         depositor,
         proof,
         pub_in,
-        &sig,
+        &keys.viewing_keys,
         nonce,
     );
 
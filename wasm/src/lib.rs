@@ -1,5 +1,6 @@
 pub mod deposit;
 pub mod withdraw;
+pub mod transfer;
 pub mod params;
 pub mod utils;
 
@@ -10,8 +11,11 @@ use solana_program::pubkey::Pubkey;
 use solana_sdk::signature::Signature;
 use soda_maze_program::{Packer, ID, params::HEIGHT};
 use soda_maze_program::core::{vault::Vault, node::get_merkle_node_pda};
+use soda_maze_program::instruction::update_merkle_node;
 use soda_maze_program::core::{nullifier::Nullifier, utxo::{UTXO, Amount, get_utxo_pda}};
-use utils::{decrypt_balance, gen_utxo_key, gen_secret, get_nullifier_pubkey};
+use soda_maze_utils::{convert::from_maze_edwards_affine, parser::to_hex_string, keys::{ViewingKeys, ViewingKeysData}};
+use utils::{Note, decrypt_balance, decrypt_note, get_account_keys, get_nullifier_pubkey};
+use params::get_auditor_keys;
 
 #[wasm_bindgen]
 extern "C" {
@@ -27,6 +31,17 @@ struct Utxo {
     leaf_index: u64,
     amount: u64,
     nullifier: Pubkey,
+    /// mint and memo sealed in the note, deposits carry neither
+    token_mint: Option<Pubkey>,
+    memo: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct ShieldedAddress {
+    pub owner: String,
+    pub viewing_pubkey: String,
+    /// escrow of the nullifier key to each auditor key of the vault
+    pub escrow: Vec<String>,
 }
 
 #[wasm_bindgen]
@@ -58,14 +73,41 @@ pub fn get_merkle_neighbor_nodes(vault: &Pubkey, leaf_index: u64) -> Array {
     Array::from_iter(neighbors)
 }
 
+/// Instructions hashing up the path of a finalized leaf to the root, each one is sent
+/// in its own transaction with a compute budget request.
 #[wasm_bindgen]
-pub fn get_utxo_keys(sig: Uint8Array, vault: &Pubkey, num: u64) -> Array {
+pub fn gen_update_merkle_nodes(vault: &Pubkey, payer: &Pubkey, leaf_index: u64) -> JsValue {
     console_error_panic_hook::set_once();
 
+    let instructions = (1..=HEIGHT)
+        .map(|layer| update_merkle_node(*vault, *payer, layer as u8, leaf_index >> layer).unwrap())
+        .collect::<Vec<_>>();
+
+    JsValue::from_serde(&instructions).unwrap()
+}
+
+fn get_viewing_keys_from_sig(sig: Uint8Array, vault: &Pubkey) -> ViewingKeys {
     let sig = Signature::new(&sig.to_vec());
+    get_account_keys(&sig, vault).viewing_keys
+}
 
+fn parse_viewing_keys(viewing_keys: JsValue) -> ViewingKeys {
+    let data: ViewingKeysData = viewing_keys.into_serde().expect("Error: invalid viewing keys");
+    ViewingKeys::from_data(&data).expect("Error: invalid viewing keys")
+}
+
+/// Viewing keys of the wallet in a vault, they find and decrypt its notes but can not spend them.
+#[wasm_bindgen]
+pub fn get_viewing_keys(sig: Uint8Array, vault: &Pubkey) -> JsValue {
+    console_error_panic_hook::set_once();
+
+    let data = get_viewing_keys_from_sig(sig, vault).to_data().unwrap();
+    JsValue::from_serde(&data).unwrap()
+}
+
+fn utxo_keys(keys: &ViewingKeys, num: u64) -> Array {
     let pubkeys = (0..num).map(|nonce| {
-        let key = gen_utxo_key(&sig, &vault, nonce);
+        let key = keys.note_keys(nonce).utxo_key;
         let (pubkey, _) = get_utxo_pda(key.as_ref(), &ID);
         
         JsValue::from_serde(&pubkey).unwrap()
@@ -75,29 +117,89 @@ pub fn get_utxo_keys(sig: Uint8Array, vault: &Pubkey, num: u64) -> Array {
 }
 
 #[wasm_bindgen]
-pub fn parse_utxo(sig: Uint8Array, vault: &Pubkey, utxo: Uint8Array) -> JsValue {
+pub fn get_utxo_keys(sig: Uint8Array, vault: &Pubkey, num: u64) -> Array {
+    console_error_panic_hook::set_once();
+
+    utxo_keys(&get_viewing_keys_from_sig(sig, vault), num)
+}
+
+#[wasm_bindgen]
+pub fn get_utxo_keys_with_viewing_keys(viewing_keys: JsValue, num: u64) -> Array {
+    console_error_panic_hook::set_once();
+
+    utxo_keys(&parse_viewing_keys(viewing_keys), num)
+}
+
+/// Shielded address of the wallet in a vault, it has to be renewed when the auditor keys rotate.
+#[wasm_bindgen]
+pub fn get_shielded_address(sig: Uint8Array, vault: &Pubkey, vault_data: Uint8Array) -> JsValue {
     console_error_panic_hook::set_once();
 
     let sig = Signature::new(&sig.to_vec());
+    let keys = get_account_keys(&sig, vault);
+    let escrow = keys.escrow(&get_auditor_keys(vault_data)).expect("Error: invalid auditor keys");
+    let address = ShieldedAddress {
+        owner: to_hex_string(&keys.viewing_keys.owner_key).unwrap(),
+        viewing_pubkey: to_hex_string(&keys.viewing_keys.viewing_pubkey()).unwrap(),
+        escrow: escrow.iter().map(|e| to_hex_string(e).unwrap()).collect(),
+    };
+
+    JsValue::from_serde(&address).unwrap()
+}
 
+fn parse_utxo_inner(keys: &ViewingKeys, nonce: u64, utxo: Uint8Array) -> JsValue {
     let utxo = UTXO::unpack(&utxo.to_vec())
         .expect("Error: UTXO data can not unpack");
-    let amount = match utxo.amount {
-        Amount::Cipher(cipher) => decrypt_balance(&sig, &vault, cipher),
-        Amount::Origin(amount) => amount,
+    let note = match utxo.amount {
+        Amount::Origin(amount) => Note {
+            amount,
+            leaf_index: utxo.leaf_index,
+            token_mint: Pubkey::default(),
+            memo: Vec::new(),
+        },
+        Amount::Cipher(cipher) => {
+            decrypt_balance(keys.note_keys(nonce).balance_key, utxo.leaf_index, &cipher)
+                .expect("Error: note cipher is not authentic")
+        }
+        Amount::Shielded { ephemeral_pubkey, cipher } => {
+            let ephemeral_pubkey = from_maze_edwards_affine(ephemeral_pubkey)
+                .expect("Error: invalid ephemeral pubkey");
+            match decrypt_note(keys.incoming_viewing_key, ephemeral_pubkey, utxo.leaf_index, &cipher) {
+                Some(note) => note,
+                None => return JsValue::NULL,
+            }
+        }
     };
-    let secret = gen_secret(&sig, &vault);
-    let nullifier = get_nullifier_pubkey(utxo.leaf_index, secret);
+    let nullifier = get_nullifier_pubkey(utxo.leaf_index, keys.owner_key, keys.nullifier_key);
 
     let utxo = Utxo {
         leaf_index: utxo.leaf_index,
-        amount,
+        amount: note.amount,
         nullifier,
+        token_mint: Some(note.token_mint).filter(|mint| mint != &Pubkey::default()),
+        memo: String::from_utf8_lossy(&note.memo).into_owned(),
     };
 
     JsValue::from_serde(&utxo).unwrap()
 }
 
+/// Parse UTXO of this wallet at `nonce` of get_utxo_keys, returns null for shielded UTXO that is not sent to this wallet.
+/// Throws if the note cipher of the wallet fails authentication.
+#[wasm_bindgen]
+pub fn parse_utxo(sig: Uint8Array, vault: &Pubkey, nonce: u64, utxo: Uint8Array) -> JsValue {
+    console_error_panic_hook::set_once();
+
+    parse_utxo_inner(&get_viewing_keys_from_sig(sig, vault), nonce, utxo)
+}
+
+/// Parse UTXO for a view-only wallet holding the viewing keys of get_viewing_keys.
+#[wasm_bindgen]
+pub fn parse_utxo_with_viewing_keys(viewing_keys: JsValue, nonce: u64, utxo: Uint8Array) -> JsValue {
+    console_error_panic_hook::set_once();
+
+    parse_utxo_inner(&parse_viewing_keys(viewing_keys), nonce, utxo)
+}
+
 #[wasm_bindgen]
 pub fn get_nullifier(data: Uint8Array) -> bool {
     console_error_panic_hook::set_once();
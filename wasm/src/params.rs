@@ -1,43 +1,75 @@
-use ark_bn254::Fr;
-use ark_ed_on_bn254::EdwardsParameters;
-use borsh::BorshDeserialize;
+use ark_bn254::{Bn254, Fr};
+use ark_ed_on_bn254::{EdwardsParameters, EdwardsAffine};
+use ark_groth16::ProvingKey;
 use rust_embed::RustEmbed;
-use soda_maze_program::params::HEIGHT;
+use js_sys::Uint8Array;
+use soda_maze_program::{Packer, params::{HEIGHT, AUDITORS}, core::vault::Vault};
 use soda_maze_lib::vanilla::hasher::{FieldHasher, poseidon::PoseidonHasher};
 use soda_maze_lib::vanilla::withdraw::WithdrawConstParams;
 use soda_maze_lib::vanilla::deposit::DepositConstParams;
-use soda_maze_utils::params::{gen_deposit_const_params, gen_withdraw_const_params};
-use soda_maze_utils::{convert::MazeProvingKey, parser::from_hex_string};
+use soda_maze_lib::vanilla::transfer::TransferConstParams;
+use soda_maze_utils::params::{gen_deposit_const_params, gen_withdraw_const_params, gen_transfer_const_params};
+use soda_maze_utils::convert::from_maze_edwards_affine;
+use soda_maze_utils::pk::read_pk_from_bytes;
+use soda_maze_utils::artifact::{CircuitInfo, CircuitKind};
 
 #[derive(RustEmbed)]
 #[folder = "resources/"]
 pub struct Params;
 
-const VIEWING_PUBKEY: &str = "6242b1fcf0aa720c570854ae38e17f48cd24dd4d2a6ae359eb654c0059605098";
+/// Auditor keys are rotated by the vault admin, so they are read from the vault account.
+pub fn get_auditor_keys(vault_data: Uint8Array) -> Vec<EdwardsAffine> {
+    let vault = Vault::unpack(&vault_data.to_vec()).expect("Error: vault data can not unpack");
+    vault.auditor_keys
+        .into_iter()
+        .map(|key| from_maze_edwards_affine(key).expect("Error: invalid auditor key"))
+        .collect()
+}
 
-pub fn get_deposit_pk() -> MazeProvingKey {
+pub fn get_min_anonymity_gap(vault_data: &Uint8Array) -> u64 {
+    let vault = Vault::unpack(&vault_data.to_vec()).expect("Error: vault data can not unpack");
+    vault.min_anonymity_gap
+}
+
+// proving keys are embedded at build time, so they are trusted and loaded without point checks
+pub fn get_deposit_pk() -> ProvingKey<Bn254> {
     let params = Params::get("pk-deposit").unwrap();
-    BorshDeserialize::deserialize(&mut params.data.as_ref()).unwrap()
+    let info = CircuitInfo { circuit: CircuitKind::Deposit, height: HEIGHT, auditors: AUDITORS };
+    read_pk_from_bytes(params.data.as_ref(), &info, true).unwrap()
 }
 
-pub fn get_withdraw_pk() -> MazeProvingKey {
+pub fn get_withdraw_pk() -> ProvingKey<Bn254> {
     let params = Params::get("pk-withdraw").unwrap();
-    BorshDeserialize::deserialize(&mut params.data.as_ref()).unwrap()
+    let info = CircuitInfo { circuit: CircuitKind::Withdraw, height: HEIGHT, auditors: AUDITORS };
+    read_pk_from_bytes(params.data.as_ref(), &info, true).unwrap()
+}
+
+pub fn get_transfer_pk() -> ProvingKey<Bn254> {
+    let params = Params::get("pk-transfer").unwrap();
+    let info = CircuitInfo { circuit: CircuitKind::Transfer, height: HEIGHT, auditors: AUDITORS };
+    read_pk_from_bytes(params.data.as_ref(), &info, true).unwrap()
 }
 
-pub fn get_deposit_const_params() -> DepositConstParams<EdwardsParameters, PoseidonHasher<Fr>> {
-    let pubkey = from_hex_string(VIEWING_PUBKEY.to_string()).unwrap();
+pub fn get_deposit_const_params(auditor_keys: Vec<EdwardsAffine>) -> DepositConstParams<EdwardsParameters, PoseidonHasher<Fr>> {
     gen_deposit_const_params(
         HEIGHT,
-        Some(pubkey),
+        Some(auditor_keys),
     )
 }
 
-pub fn get_withdraw_const_params() -> WithdrawConstParams<EdwardsParameters, PoseidonHasher<Fr>> {
-    let pubkey = from_hex_string(VIEWING_PUBKEY.to_string()).unwrap();
+pub fn get_withdraw_const_params(auditor_keys: Vec<EdwardsAffine>) -> WithdrawConstParams<EdwardsParameters, PoseidonHasher<Fr>> {
     gen_withdraw_const_params(
         HEIGHT,
-        Some(pubkey),
+        Some(auditor_keys),
+        false,
+        false,
+    )
+}
+
+pub fn get_transfer_const_params(auditor_keys: Vec<EdwardsAffine>) -> TransferConstParams<EdwardsParameters, PoseidonHasher<Fr>> {
+    gen_transfer_const_params(
+        HEIGHT,
+        Some(auditor_keys),
     )
 }
 
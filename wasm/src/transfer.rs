@@ -0,0 +1,264 @@
+use ark_ff::UniformRand;
+use ark_bn254::Bn254;
+use ark_ed_on_bn254::{Fq as Fr, Fr as Frr, EdwardsAffine, EdwardsParameters};
+use ark_groth16::{Groth16, Proof};
+use serde::{Serialize, Deserialize};
+use js_sys::{Uint8Array, Array};
+use rand_core::OsRng;
+use solana_sdk::signature::Signature;
+use wasm_bindgen::{JsValue, prelude::*};
+use solana_program::{pubkey::Pubkey, instruction::Instruction};
+use soda_maze_program::{Packer, params::{HEIGHT, AUDITORS}, core::node::MerkleNode, core::pubkey_to_fr_repr};
+use soda_maze_program::{core::{VanillaData, transfer::TransferVanillaData}, verifier::program::Program};
+use soda_maze_lib::circuits::poseidon::PoseidonHasherGadget;
+use soda_maze_lib::proof::{ProofScheme, scheme::TransferProof};
+use soda_maze_lib::vanilla::transfer::{TransferVanillaProof, TransferOriginInputs, TransferPublicInputs};
+use soda_maze_lib::vanilla::{hasher::poseidon::PoseidonHasher, commit::{Ciphertext, CommitOriginInputs, EscrowOriginInputs}, VanillaProof};
+use soda_maze_utils::convert::{to_maze_fr_repr, to_maze_edwards_affine, to_maze_proof, from_maze_fr_repr};
+use soda_maze_utils::parser::from_hex_string;
+use soda_maze_utils::keys::ViewingKeys;
+
+use crate::{info, ShieldedAddress};
+use crate::utils::*;
+use crate::params::*;
+
+type TransferVanillaInstant = TransferVanillaProof::<EdwardsParameters, PoseidonHasher<Fr>>;
+type TransferInstant = TransferProof::<EdwardsParameters, PoseidonHasher<Fr>, PoseidonHasherGadget<Fr>, Groth16<Bn254>>;
+
+#[derive(Serialize, Deserialize)]
+struct Instructions {
+    pub credential: Instruction,
+    pub verifier: Instruction,
+    pub verify: Vec<Instruction>,
+    pub finalize: Instruction,
+}
+
+fn parse_neighbor_nodes(neighbors: Array, nodes_hashes: &[Fr]) -> Vec<Fr> {
+    let neighbor_nodes = neighbors.iter().enumerate().map(|(layer, neighbor)| {
+        let data = Uint8Array::from(neighbor).to_vec();
+        if data.is_empty() {
+            nodes_hashes[layer]
+        } else {
+            let node = MerkleNode::unpack(&data).expect("Error: node data can not unpack");
+            from_maze_fr_repr(node.hash).expect("Error: invalid node hash")
+        }
+    }).collect::<Vec<_>>();
+    assert_eq!(neighbor_nodes.len(), HEIGHT, "Error: invalid neighbors array length");
+
+    neighbor_nodes
+}
+
+#[allow(clippy::too_many_arguments)]
+fn gen_transfer_instructions(
+    vault: Pubkey,
+    token_mint: Pubkey,
+    receiver: Pubkey,
+    owner: Pubkey,
+    proof: Proof<Bn254>,
+    pub_in: TransferPublicInputs<EdwardsParameters>,
+    keys: &ViewingKeys,
+    nonce: u64,
+    amounts: [u64; 2],
+    viewing_pubkey: Option<EdwardsAffine>,
+    memo: Vec<u8>,
+) -> Instructions {
+    use soda_maze_program::instruction::*;
+
+    let dst_leaves = pub_in.dst_leaves.map(to_maze_fr_repr);
+    let src_root = to_maze_fr_repr(pub_in.src_root);
+    let nullifier_points = pub_in.nullifier_points.map(to_maze_edwards_affine);
+    let commitments = pub_in.commit.map(|commit| {
+        commit.into_iter().map(|commit| {
+            commit.commitments.into_iter().map(|c| {
+                (to_maze_edwards_affine(c.0), to_maze_edwards_affine(c.1))
+            }).collect::<Vec<_>>()
+        }).collect::<Vec<_>>()
+    }).unwrap();
+    let credential = create_transfer_credential(
+        vault,
+        receiver,
+        owner,
+        pub_in.withdraw_amount,
+        token_mint,
+        nullifier_points,
+        src_root,
+        pub_in.dst_leaf_index,
+        dst_leaves,
+        Box::new(commitments),
+    ).unwrap();
+
+    let proof = to_maze_proof(proof);
+    let verifier = create_transfer_verifier(vault, owner, Box::new(proof)).unwrap();
+
+    // padded with the index so that every verify transaction is distinct
+    let verify = (0..Program::max_steps(TransferVanillaData::INPUTS_LEN) as u16).map(|i| {
+        verify_transfer_proof(vault, owner, i.to_le_bytes().to_vec()).unwrap()
+    }).collect::<Vec<_>>();
+
+    let note_keys = [keys.note_keys(nonce), keys.note_keys(nonce + 1)];
+    // the second output is appended right after the first one
+    let notes = [
+        Note {
+            amount: amounts[0],
+            leaf_index: pub_in.dst_leaf_index,
+            token_mint,
+            memo,
+        },
+        Note {
+            amount: amounts[1],
+            leaf_index: pub_in.dst_leaf_index + 1,
+            token_mint,
+            memo: Vec::new(),
+        },
+    ];
+    // the first output is encrypted to the recipient if it is paid to another shielded address
+    let (balance_cipher_0, ephemeral_pubkey) = if let Some(viewing_pubkey) = viewing_pubkey {
+        let (ephemeral_pubkey, cipher) = encrypt_note(viewing_pubkey, &notes[0]);
+        (cipher, Some(to_maze_edwards_affine(ephemeral_pubkey)))
+    } else {
+        (encrypt_balance(note_keys[0].balance_key, &notes[0]), None)
+    };
+    let balance_cipher_1 = encrypt_balance(note_keys[1].balance_key, &notes[1]);
+    let utxos = [note_keys[0].utxo_key, note_keys[1].utxo_key];
+    let finalize = finalize_transfer(
+        vault,
+        token_mint,
+        receiver,
+        owner,
+        pub_in.dst_leaf_index,
+        dst_leaves,
+        nullifier_points,
+        utxos,
+        [balance_cipher_0, balance_cipher_1],
+        ephemeral_pubkey,
+    ).unwrap();
+
+    Instructions {
+        credential,
+        verifier,
+        verify,
+        finalize,
+    }
+}
+
+#[wasm_bindgen]
+#[allow(clippy::too_many_arguments)]
+pub fn gen_transfer_proof(
+    vault: Pubkey,
+    vault_data: Uint8Array, // vault account data, auditor keys are read from it
+    token_mint: Pubkey,
+    receiver: Pubkey, // receiver of the public withdrawal
+    owner: Pubkey,
+    src_leaf_index_0: u64, // selected utxo index
+    balance_0: u64, // selected utxo balance
+    src_leaf_index_1: u64, // another selected utxo index
+    balance_1: u64, // another selected utxo balance
+    dst_leaf_index: u64, // from vault info
+    pay_amount: u64, // amount of the first output
+    withdraw_amount: u64,
+    recipient: JsValue, // get_shielded_address of the payee, null to pay to self
+    memo: String, // attached to the note of the first output
+    sig: Uint8Array,
+    src_neighbors_0: Array, // get_merkle_neighbor_nodes(vault, src_leaf_index_0)
+    src_neighbors_1: Array, // get_merkle_neighbor_nodes(vault, src_leaf_index_1)
+    nonce: u64,
+) -> JsValue {
+    console_error_panic_hook::set_once();
+
+    info("Preparing parameters and inputs...");
+
+    let rng = &mut OsRng;
+
+    let sig = Signature::new(&sig.to_vec());
+    let keys = get_account_keys(&sig, &vault);
+    let secret = keys.spending_key;
+
+    assert!(memo.len() <= MAX_NOTE_MEMO_SIZE, "Error: memo is too long");
+
+    let auditor_keys = get_auditor_keys(vault_data);
+    let escrow = keys.escrow(&auditor_keys).expect("Error: invalid auditor keys");
+
+    let recipient: Option<ShieldedAddress> = recipient.into_serde().expect("Error: invalid recipient address");
+    let (recipient, viewing_pubkey, recipient_escrow) = if let Some(address) = recipient {
+        let owner = from_hex_string::<Fr>(address.owner).expect("Error: invalid recipient owner key");
+        let viewing_pubkey = from_hex_string::<EdwardsAffine>(address.viewing_pubkey)
+            .expect("Error: invalid recipient viewing pubkey");
+        let escrow = address.escrow
+            .into_iter()
+            .map(|e| from_hex_string::<Ciphertext<EdwardsParameters>>(e).expect("Error: invalid recipient escrow"))
+            .collect::<Vec<_>>();
+        assert_eq!(escrow.len(), AUDITORS, "Error: recipient address is not of the current auditor keys");
+        (owner, Some(viewing_pubkey), escrow)
+    } else {
+        (keys.viewing_keys.owner_key, None, escrow.clone())
+    };
+
+    let ref nodes_hashes = get_default_node_hashes();
+    let src_neighbor_nodes = [
+        parse_neighbor_nodes(src_neighbors_0, nodes_hashes),
+        parse_neighbor_nodes(src_neighbors_1, nodes_hashes),
+    ];
+
+    let rest_amount = (balance_0 + balance_1)
+        .checked_sub(pay_amount + withdraw_amount)
+        .expect("Error: insufficient balance");
+    let dst_amounts = [pay_amount, rest_amount];
+
+    let const_params = get_transfer_const_params(auditor_keys.clone());
+
+    let asset_id = from_maze_fr_repr(pubkey_to_fr_repr(&token_mint)).unwrap();
+    let receiver_fr = from_maze_fr_repr(pubkey_to_fr_repr(&receiver)).unwrap();
+    let origin_inputs = TransferOriginInputs {
+        src_balances: [balance_0, balance_1],
+        src_leaf_indexes: [src_leaf_index_0, src_leaf_index_1],
+        dst_amounts,
+        withdraw_amount,
+        asset_id,
+        dst_leaf_index,
+        receiver: receiver_fr,
+        secret,
+        recipient,
+        src_neighbor_nodes,
+        commit: Some([
+            CommitOriginInputs { nonces: (0..AUDITORS).map(|_| Frr::rand(rng)).collect(), escrow: recipient_escrow },
+            CommitOriginInputs { nonces: (0..AUDITORS).map(|_| Frr::rand(rng)).collect(), escrow },
+        ]),
+        // both src notes are opened with the current auditor keys, as in withdraw
+        src_escrow: Some([
+            EscrowOriginInputs { pubkeys: auditor_keys.clone(), nonces: keys.escrow_nonces(AUDITORS) },
+            EscrowOriginInputs { pubkeys: auditor_keys, nonces: keys.escrow_nonces(AUDITORS) },
+        ]),
+    };
+
+    let pk = get_transfer_pk();
+
+    info("Generating vanilla proof...");
+
+    let (pub_in, priv_in) =
+        TransferVanillaInstant::generate_vanilla_proof(&const_params, &origin_inputs)
+            .expect("Error: generate vanilla proof failed");
+
+    info("Generating snark proof...");
+
+    let proof =
+        TransferInstant::generate_snark_proof(rng, &const_params, &pub_in, &priv_in, &pk)
+            .expect("Error: generate snark proof failed");
+
+    info("Generating solana instructions...");
+
+    let instructions = gen_transfer_instructions(
+        vault,
+        token_mint,
+        receiver,
+        owner,
+        proof,
+        pub_in,
+        &keys.viewing_keys,
+        nonce,
+        dst_amounts,
+        viewing_pubkey,
+        memo.into_bytes(),
+    );
+
+    JsValue::from_serde(&instructions).unwrap()
+}
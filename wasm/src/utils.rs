@@ -1,71 +1,121 @@
-use aes::{Aes256Enc, Aes256Dec, cipher::{generic_array::GenericArray, KeyInit, BlockEncrypt, BlockDecrypt}};
-use ark_ec::ProjectiveCurve;
-use ark_ed_on_bn254::{Fq as Fr, Fr as Frr, EdwardsProjective};
-use ark_ff::{PrimeField, BigInteger, BigInteger256, FpParameters, UniformRand};
-use rand_core::OsRng;
+use aes_gcm::{Aes256Gcm, Nonce, aead::{Aead, KeyInit, Payload}};
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ed_on_bn254::{Fq as Fr, Fr as Frr, EdwardsAffine, EdwardsProjective, EdwardsParameters};
+use ark_ff::{PrimeField, BigInteger, UniformRand};
+use borsh::{BorshSerialize, BorshDeserialize};
+use rand_core::{OsRng, RngCore};
 use solana_program::{pubkey::Pubkey, hash::hash};
-use soda_maze_utils::convert::to_maze_edwards_affine;
+use soda_maze_program::params::MAX_NOTE_CIPHER_SIZE;
+use soda_maze_utils::{convert::to_maze_edwards_affine, keys::{MasterSeed, SpendingKeys}};
 use solana_sdk::signature::Signature;
 
-pub fn get_nullifier_pubkey(leaf_index: u64, secret: Fr) -> Pubkey {
+/// Keys of the wallet in a vault, the master seed is derived from the signature of the wallet.
+pub fn get_account_keys(sig: &Signature, vault: &Pubkey) -> SpendingKeys {
+    MasterSeed::new(sig.as_ref()).account_keys(vault.as_ref())
+}
+
+pub fn get_nullifier_pubkey(leaf_index: u64, owner: Fr, nullifier_key: Fr) -> Pubkey {
     use soda_maze_lib::params::poseidon::get_poseidon_bn254_for_nullifier;
-    use soda_maze_lib::vanilla::hasher::{FieldHasher, poseidon::PoseidonHasher};
+    use soda_maze_lib::vanilla::{hasher::poseidon::PoseidonHasher, withdraw::gen_nullifier_point};
     use soda_maze_program::{core::nullifier::get_nullifier_pda, ID};
 
     let ref params = get_poseidon_bn254_for_nullifier();
-    let nullifier = PoseidonHasher::hash(params, &[Fr::from(leaf_index), secret]).unwrap();
-    let nullifier: <Fr as PrimeField>::BigInt = nullifier.into();
-    let mut nullifier_bits = nullifier.to_bits_le();
-    nullifier_bits.truncate(<<Frr as PrimeField>::Params as FpParameters>::CAPACITY as usize);
-    let nullifier: <Frr as PrimeField>::BigInt = <<Frr as PrimeField>::BigInt as BigInteger>::from_bits_le(&nullifier_bits);
-    // nullifier_point = nullifier * G
-    let nullifier_point = EdwardsProjective::prime_subgroup_generator().mul(nullifier).into_affine();
+    // nullifier_point = hash(leaf_index | owner) * nullifier_key * G
+    let nullifier_point = gen_nullifier_point::<EdwardsParameters, PoseidonHasher<Fr>>(
+        params,
+        leaf_index,
+        owner,
+        nullifier_key,
+    ).unwrap();
 
     let nullifier_point = to_maze_edwards_affine(nullifier_point);
     let (nullifier, _) = get_nullifier_pda(&nullifier_point, &ID);
     nullifier
 }
 
-pub fn encrypt_balance(sig: &Signature, vault: &Pubkey, balance: u64) -> u128 {
-    let key = hash(&[sig.as_ref(), vault.as_ref()].concat()).to_bytes();
-    let key = GenericArray::from(key);
-    let encryptor = Aes256Enc::new(&key);
+/// Version of the note cipher layout: `version | nonce | AES-256-GCM(note) | tag`.
+const NOTE_CIPHER_VERSION: u8 = 1;
+const NOTE_NONCE_SIZE: usize = 12;
+const NOTE_TAG_SIZE: usize = 16;
+// borsh encoded amount, leaf index, token mint and memo length
+const NOTE_FIELDS_SIZE: usize = 8 + 8 + 32 + 4;
+
+/// Longest memo that keeps the note cipher within the UTXO account layout.
+pub const MAX_NOTE_MEMO_SIZE: usize =
+    MAX_NOTE_CIPHER_SIZE - 1 - NOTE_NONCE_SIZE - NOTE_TAG_SIZE - NOTE_FIELDS_SIZE;
+
+/// Plaintext of a note cipher stored in a UTXO account.
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct Note {
+    pub amount: u64,
+    pub leaf_index: u64,
+    pub token_mint: Pubkey,
+    pub memo: Vec<u8>,
+}
+
+// the leaf index is also bound as associated data, so a cipher copied to another leaf fails to open
+fn note_aad(leaf_index: u64) -> [u8; 9] {
+    let mut aad = [NOTE_CIPHER_VERSION; 9];
+    aad[1..].copy_from_slice(&leaf_index.to_le_bytes());
+    aad
+}
+
+fn seal_note(key: &[u8; 32], note: &Note) -> Vec<u8> {
+    assert!(note.memo.len() <= MAX_NOTE_MEMO_SIZE, "Error: memo is too long");
+
+    let mut nonce = [0u8; NOTE_NONCE_SIZE];
+    OsRng.fill_bytes(&mut nonce);
+    let text = note.try_to_vec().unwrap();
+    let cipher = Aes256Gcm::new(key.into())
+        .encrypt(Nonce::from_slice(&nonce), Payload { msg: &text, aad: &note_aad(note.leaf_index) })
+        .expect("Error: encrypt note failed");
+
+    [&[NOTE_CIPHER_VERSION][..], &nonce, &cipher].concat()
+}
+
+/// Returns `None` if the cipher is not sealed with the key, has been tampered with,
+/// or is not bound to `leaf_index`.
+fn open_note(key: &[u8; 32], leaf_index: u64, cipher: &[u8]) -> Option<Note> {
+    if cipher.len() < 1 + NOTE_NONCE_SIZE + NOTE_TAG_SIZE || cipher[0] != NOTE_CIPHER_VERSION {
+        return None;
+    }
+    let (nonce, cipher) = cipher[1..].split_at(NOTE_NONCE_SIZE);
+    let text = Aes256Gcm::new(key.into())
+        .decrypt(Nonce::from_slice(nonce), Payload { msg: cipher, aad: &note_aad(leaf_index) })
+        .ok()?;
 
-    let padding = u64::rand(&mut OsRng);
-    let text = (balance as u128) + ((padding as u128) << 64);
-    let mut block = GenericArray::from(text.to_le_bytes());
-    encryptor.encrypt_block(&mut block);
-    u128::from_le_bytes(<[u8; 16]>::try_from(block.as_ref()).unwrap())
+    Note::try_from_slice(&text).ok().filter(|note| note.leaf_index == leaf_index)
 }
 
-pub fn decrypt_balance(sig: &Signature, vault: &Pubkey, cipher: u128) -> u64 {
-    let key = hash(&[sig.as_ref(), vault.as_ref()].concat()).to_bytes();
-    let key = GenericArray::from(key);
-    let decryptor = Aes256Dec::new(&key);
-    let mut block = GenericArray::from(cipher.to_le_bytes());
-    decryptor.decrypt_block(&mut block);
-    
-    let text = u128::from_le_bytes(<[u8; 16]>::try_from(block.as_ref()).unwrap());
-    text as u64
+/// Encrypt a note the wallet keeps for itself with the balance key of its derivation path.
+pub fn encrypt_balance(balance_key: [u8; 32], note: &Note) -> Vec<u8> {
+    seal_note(&balance_key, note)
 }
 
-pub fn gen_secret(sig: &Signature, vault: &Pubkey) -> Fr {
-    let mut secret = hash(&[sig.as_ref(), vault.as_ref()].concat()).to_bytes();
-    // strip 3 last bits to make sure secret is in Fr
-    secret[31] &= 0b0001_1111;
-    let secret = [
-        u64::from_le_bytes([secret[0], secret[1], secret[2], secret[3], secret[4], secret[5], secret[6], secret[7]]),
-        u64::from_le_bytes([secret[8], secret[9], secret[10], secret[11], secret[12], secret[13], secret[14], secret[15]]),
-        u64::from_le_bytes([secret[16], secret[17], secret[18], secret[19], secret[20], secret[21], secret[22], secret[23]]),
-        u64::from_le_bytes([secret[24], secret[25], secret[26], secret[27], secret[28], secret[29], secret[30], secret[31]]),
-    ];
-    Fr::from_repr(BigInteger256::new(secret)).unwrap()
+pub fn decrypt_balance(balance_key: [u8; 32], leaf_index: u64, cipher: &[u8]) -> Option<Note> {
+    open_note(&balance_key, leaf_index, cipher)
 }
 
-#[inline]
-pub fn gen_utxo_key(sig: &Signature, vault: &Pubkey, nonce: u64) -> [u8; 32] {
-    let key = hash(&[sig.as_ref(), vault.as_ref(), &nonce.to_le_bytes()].concat());
-    key.to_bytes()
+fn gen_note_key(shared: EdwardsAffine) -> [u8; 32] {
+    let x = shared.x.into_repr().to_bytes_le();
+    let y = shared.y.into_repr().to_bytes_le();
+    hash(&[x, y].concat()).to_bytes()
+}
+
+/// Encrypt a note to the viewing pubkey of a shielded address,
+/// returns the ephemeral pubkey and the cipher.
+pub fn encrypt_note(viewing_pubkey: EdwardsAffine, note: &Note) -> (EdwardsAffine, Vec<u8>) {
+    let ephemeral_key = Frr::rand(&mut OsRng);
+    let ephemeral_pubkey = EdwardsProjective::prime_subgroup_generator().mul(ephemeral_key.into_repr()).into_affine();
+    let shared = viewing_pubkey.mul(ephemeral_key).into_affine();
+
+    (ephemeral_pubkey, seal_note(&gen_note_key(shared), note))
+}
+
+/// Decrypt a note with the viewing key, returns `None` if the note is not sent to this key.
+pub fn decrypt_note(viewing_key: Frr, ephemeral_pubkey: EdwardsAffine, leaf_index: u64, cipher: &[u8]) -> Option<Note> {
+    let shared = ephemeral_pubkey.mul(viewing_key).into_affine();
+    open_note(&gen_note_key(shared), leaf_index, cipher)
 }
 
 // #[inline]
@@ -140,6 +190,30 @@ pub fn gen_utxo_key(sig: &Signature, vault: &Pubkey, nonce: u64) -> [u8; 32] {
 
 #[cfg(test)]
 mod test {
+    use solana_program::pubkey::Pubkey;
+
+    use super::{Note, encrypt_balance, decrypt_balance};
+
+    #[test]
+    fn test_note_cipher() {
+        let key = [7u8; 32];
+        let note = Note {
+            amount: 7000000,
+            leaf_index: 5,
+            token_mint: Pubkey::new_unique(),
+            memo: b"rent".to_vec(),
+        };
+
+        let mut cipher = encrypt_balance(key, &note);
+        assert_eq!(decrypt_balance(key, 5, &cipher), Some(note));
+        assert_eq!(decrypt_balance(key, 6, &cipher), None);
+        assert_eq!(decrypt_balance([8u8; 32], 5, &cipher), None);
+
+        let last = cipher.len() - 1;
+        cipher[last] ^= 1;
+        assert_eq!(decrypt_balance(key, 5, &cipher), None);
+    }
+
     #[test]
     fn test() {
         let a = 257u32;
@@ -8,12 +8,14 @@ use rand_core::OsRng;
 use solana_sdk::signature::Signature;
 use wasm_bindgen::{JsValue, prelude::*};
 use solana_program::{pubkey::Pubkey, instruction::Instruction};
-use soda_maze_program::{Packer, params::HEIGHT, core::node::MerkleNode, core::pubkey_to_fr_repr};
+use soda_maze_program::{Packer, params::{HEIGHT, AUDITORS}, core::node::MerkleNode, core::pubkey_to_fr_repr};
+use soda_maze_program::{core::{VanillaData, withdraw::WithdrawVanillaData}, verifier::program::Program};
 use soda_maze_lib::circuits::poseidon::PoseidonHasherGadget;
 use soda_maze_lib::proof::{ProofScheme, scheme::WithdrawProof};
 use soda_maze_lib::vanilla::withdraw::{WithdrawVanillaProof, WithdrawOriginInputs, WithdrawPublicInputs};
-use soda_maze_lib::vanilla::{hasher::poseidon::PoseidonHasher, commit::CommitOriginInputs, VanillaProof};
+use soda_maze_lib::vanilla::{hasher::poseidon::PoseidonHasher, commit::{CommitOriginInputs, EscrowOriginInputs}, VanillaProof};
 use soda_maze_utils::convert::{to_maze_fr_repr, to_maze_edwards_affine, to_maze_proof, from_maze_fr_repr};
+use soda_maze_utils::keys::ViewingKeys;
 
 use crate::info;
 use crate::utils::*;
@@ -38,40 +40,59 @@ fn gen_withdraw_instructions(
     delegator: Pubkey,
     proof: Proof<Bn254>,
     pub_in: WithdrawPublicInputs<EdwardsParameters>,
-    sig: &Signature,
+    keys: &ViewingKeys,
     nonce: u64,
     balance: u64,
 ) -> Instructions {
     use soda_maze_program::instruction::*;
 
     let dst_leaf = to_maze_fr_repr(pub_in.dst_leaf);
+    let src_root = to_maze_fr_repr(pub_in.src_root);
     let nullifier_point = to_maze_edwards_affine(pub_in.nullifier_point);
-    let updating_nodes = pub_in.update_nodes.into_iter().map(|node| {
-        to_maze_fr_repr(node)
-    }).collect::<Vec<_>>();
     let commitment = pub_in.commit.map(|commit| {
-        (to_maze_edwards_affine(commit.commitment.0), to_maze_edwards_affine(commit.commitment.1))
+        commit.commitments.into_iter().map(|c| {
+            (to_maze_edwards_affine(c.0), to_maze_edwards_affine(c.1))
+        }).collect::<Vec<_>>()
     }).unwrap();
+    let exclusion_root = pub_in.exclusion_root.map(to_maze_fr_repr);
+    let allowlist_root = pub_in.allowlist_root.map(to_maze_fr_repr);
+    // the roots of index sets follow the other public inputs
+    let inputs_len = WithdrawVanillaData::INPUTS_LEN
+        + exclusion_root.is_some() as usize
+        + allowlist_root.is_some() as usize;
     let credential = create_withdraw_credential(
         vault,
         receiver,
         delegator,
         pub_in.withdraw_amount,
-        nullifier_point.clone(),
+        token_mint,
+        pub_in.fee,
+        nullifier_point,
+        pub_in.dst_leaf_index,
         dst_leaf,
-        Box::new(updating_nodes),
+        src_root,
         commitment,
+        exclusion_root,
+        allowlist_root,
     ).unwrap();
 
     let proof = to_maze_proof(proof);
     let verifier = create_withdraw_verifier(vault, receiver, delegator, Box::new(proof)).unwrap();
 
-    let verify = (0..155u8).into_iter().map(|i| {
-        verify_withdraw_proof(vault, &delegator, receiver, vec![i]).unwrap()
+    // padded with the index so that every verify transaction is distinct
+    let verify = (0..Program::max_steps(inputs_len) as u16).map(|i| {
+        verify_withdraw_proof(vault, &delegator, receiver, i.to_le_bytes().to_vec()).unwrap()
     }).collect::<Vec<_>>();
 
-    let balance_cipher = encrypt_balance(sig, &vault, balance);
-    let utxo = gen_utxo_key(sig, &vault, nonce);
+    let note_keys = keys.note_keys(nonce);
+    let note = Note {
+        amount: balance,
+        leaf_index: pub_in.dst_leaf_index,
+        token_mint,
+        memo: Vec::new(),
+    };
+    let balance_cipher = encrypt_balance(note_keys.balance_key, &note);
+    let utxo = note_keys.utxo_key;
     let finalize = finalize_withdraw(
         vault,
         token_mint,
@@ -96,16 +117,17 @@ fn gen_withdraw_instructions(
 #[allow(clippy::too_many_arguments)]
 pub fn gen_withdraw_proof(
     vault: Pubkey,
+    vault_data: Uint8Array, // vault account data, auditor keys and anonymity gap are read from it
     token_mint: Pubkey,
     receiver: Pubkey,
-    delegator: Pubkey,
+    delegator: Pubkey, // relayer who submits the withdrawal
     src_leaf_index: u64, // selected utxo index
     balance: u64, // selected utxo balance
     dst_leaf_index: u64, // from vault info
     withdraw_amount: u64,
+    fee: u64, // paid to the delegator out of withdraw amount
     sig: Uint8Array,
     src_neighbors: Array, // get_merkle_neighbor_nodes(vault, src_leaf_index)
-    dst_neighbors: Array, // get_merkle_neighbor_nodes(vault, dst_leaf_index)
     nonce: u64,
 ) -> JsValue {
     console_error_panic_hook::set_once();
@@ -115,7 +137,8 @@ pub fn gen_withdraw_proof(
     let rng = &mut OsRng;
     
     let sig = Signature::new(&sig.to_vec());
-    let secret = gen_secret(&sig, &vault);
+    let keys = get_account_keys(&sig, &vault);
+    let secret = keys.spending_key;
 
     let ref nodes_hashes = get_default_node_hashes();
     let src_neighbor_nodes = src_neighbors.iter().enumerate().map(|(layer, neighbor)| {
@@ -129,36 +152,41 @@ pub fn gen_withdraw_proof(
     }).collect::<Vec<_>>();
     assert_eq!(src_neighbor_nodes.len(), HEIGHT, "Error: invalid src neighbors array length");
 
-    let dst_neighbor_nodes = dst_neighbors.iter().enumerate().map(|(layer, neighbor)| {
-        let data = Uint8Array::from(neighbor).to_vec();
-        if data.is_empty() {
-            nodes_hashes[layer]
-        } else {
-            let node = MerkleNode::unpack(&data).expect("Error: node data can not unpack");
-            from_maze_fr_repr(node.hash).expect("Error: invalid node hash")
-        }
-    }).collect::<Vec<_>>();
-    assert_eq!(dst_neighbor_nodes.len(), HEIGHT, "Error: invalid dst neighbors array length");
-
-    let const_params = get_withdraw_const_params();
+    let min_anonymity_gap = get_min_anonymity_gap(&vault_data);
+    let auditor_keys = get_auditor_keys(vault_data);
+    let escrow = keys.escrow(&auditor_keys).expect("Error: invalid auditor keys");
+    let const_params = get_withdraw_const_params(auditor_keys.clone());
 
+    let asset_id = from_maze_fr_repr(pubkey_to_fr_repr(&token_mint)).unwrap();
     let receiver_fr = from_maze_fr_repr(pubkey_to_fr_repr(&receiver)).unwrap();
+    let relayer_fr = from_maze_fr_repr(pubkey_to_fr_repr(&delegator)).unwrap();
     let origin_inputs = WithdrawOriginInputs {
         balance,
         withdraw_amount,
+        asset_id,
         src_leaf_index,
         dst_leaf_index,
+        min_anonymity_gap,
         receiver: receiver_fr,
+        relayer: relayer_fr,
+        fee,
         secret,
         src_neighbor_nodes,
-        dst_neighbor_nodes,
+        exclusion_neighbor_nodes: None,
+        allowlist_neighbor_nodes: None,
         commit: Some(CommitOriginInputs {
-            nonce: Frr::rand(rng),
+            nonces: (0..AUDITORS).map(|_| Frr::rand(rng)).collect(),
+            escrow,
+        }),
+        // the src note is opened with the current auditor keys, a note committed before they
+        // rotated needs the keys of its commit time
+        src_escrow: Some(EscrowOriginInputs {
+            pubkeys: auditor_keys,
+            nonces: keys.escrow_nonces(AUDITORS),
         }),
     };
 
     let pk = get_withdraw_pk();
-    let pk = pk.into();
 
     info("Generating vanilla proof...");
 
@@ -181,7 +209,7 @@ pub fn gen_withdraw_proof(
         delegator,
         proof,
         pub_in,
-        &sig,
+        &keys.viewing_keys,
         nonce,
         balance - withdraw_amount,
     );